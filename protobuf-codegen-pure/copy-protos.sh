@@ -0,0 +1,15 @@
+#!/bin/sh -ex
+
+# Copy `.proto` files bundled with `BundledSourceProvider` from `proto` directory
+# of repository, so they are included in published crate
+
+cd $(dirname $0)
+
+rm -rf src/proto
+mkdir -p src/proto/google/protobuf/compiler
+
+cp ../proto/rustproto.proto src/proto/
+cp ../proto/google/protobuf/*.proto src/proto/google/protobuf/
+cp ../proto/google/protobuf/compiler/plugin.proto src/proto/google/protobuf/compiler/
+
+# vim: set ts=4 sw=4 et:
@@ -0,0 +1,5 @@
+extern crate protobuf_codegen_pure;
+
+fn main() {
+    protobuf_codegen_pure::breaking::breaking_main();
+}
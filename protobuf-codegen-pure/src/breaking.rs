@@ -0,0 +1,841 @@
+//! Detect incompatible changes between two versions of a schema.
+//!
+//! Schemas are compared by fully qualified names of messages and enums,
+//! so types can move between files of the same package. Fields and enum
+//! values are compared by number.
+//!
+//! ```ignore
+//! let old = protobuf_codegen_pure::parse_and_typecheck(&includes, &inputs)?.file_descriptors;
+//! for change in protobuf_codegen_pure::breaking::compare(&old, &new) {
+//!     println!("{}", change);
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+
+use protobuf::descriptor::field_descriptor_proto::Label;
+use protobuf::descriptor::field_descriptor_proto::Type;
+use protobuf::descriptor::DescriptorProto;
+use protobuf::descriptor::EnumDescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::json::json_name;
+
+use crate::decode;
+use crate::parse_and_typecheck_impl;
+
+/// Which compatibility a change breaks.
+///
+/// Levels are ordered: a change breaking wire compatibility usually breaks
+/// JSON and generated sources too, and a change breaking JSON breaks sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Code using generated sources no longer compiles,
+    /// but serialized data is compatible.
+    Source,
+    /// Data serialized to JSON by one version cannot be read by another.
+    Json,
+    /// Binary data serialized by one version is misinterpreted by another.
+    Wire,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Source => write!(f, "source"),
+            Severity::Json => write!(f, "json"),
+            Severity::Wire => write!(f, "wire"),
+        }
+    }
+}
+
+impl FromStr for Severity {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Severity> {
+        match s {
+            "source" => Ok(Severity::Source),
+            "json" => Ok(Severity::Json),
+            "wire" => Ok(Severity::Wire),
+            _ => Err(err_usage(format!("unknown severity: {}", s))),
+        }
+    }
+}
+
+/// Kind of incompatible change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// File declares different package.
+    PackageChanged {
+        /// Package in old version
+        old: String,
+        /// Package in new version
+        new: String,
+    },
+    /// Message is removed.
+    MessageRemoved,
+    /// Enum is removed.
+    EnumRemoved,
+    /// Field is removed.
+    FieldRemoved {
+        /// Number of removed field
+        number: i32,
+        /// Number is `reserved` in new version
+        number_reserved: bool,
+        /// Name is `reserved` in new version
+        name_reserved: bool,
+    },
+    /// Field uses number `reserved` in old version.
+    FieldNumberReused {
+        /// Field number
+        number: i32,
+    },
+    /// Field with the same name has different number.
+    FieldNumberChanged {
+        /// Number in old version
+        old: i32,
+        /// Number in new version
+        new: i32,
+    },
+    /// Field with the same number has different name.
+    FieldRenamed {
+        /// Name in new version
+        new: String,
+    },
+    /// Field has different type.
+    FieldTypeChanged {
+        /// Type in old version
+        old: String,
+        /// Type in new version
+        new: String,
+        /// Values of both types have the same wire encoding
+        wire_compatible: bool,
+    },
+    /// Field changed between `optional`, `required` and `repeated`.
+    FieldLabelChanged {
+        /// Label in old version
+        old: String,
+        /// Label in new version
+        new: String,
+    },
+    /// Field moved into, out of or between oneofs.
+    FieldOneofChanged {
+        /// Oneof in old version
+        old: Option<String>,
+        /// Oneof in new version
+        new: Option<String>,
+    },
+    /// Field has different JSON name.
+    FieldJsonNameChanged {
+        /// JSON name in old version
+        old: String,
+        /// JSON name in new version
+        new: String,
+    },
+    /// Enum value is removed.
+    EnumValueRemoved {
+        /// Number of removed value
+        number: i32,
+        /// Number is `reserved` in new version
+        number_reserved: bool,
+        /// Name is `reserved` in new version
+        name_reserved: bool,
+    },
+    /// Enum value with the same number has different name.
+    EnumValueRenamed {
+        /// Name in new version
+        new: String,
+    },
+}
+
+impl ChangeKind {
+    /// Most severe compatibility broken by this change.
+    pub fn severity(&self) -> Severity {
+        match self {
+            ChangeKind::PackageChanged { .. } => Severity::Wire,
+            ChangeKind::MessageRemoved | ChangeKind::EnumRemoved => Severity::Source,
+            ChangeKind::FieldRemoved {
+                number_reserved,
+                name_reserved,
+                ..
+            }
+            | ChangeKind::EnumValueRemoved {
+                number_reserved,
+                name_reserved,
+                ..
+            } => match (number_reserved, name_reserved) {
+                (false, _) => Severity::Wire,
+                (true, false) => Severity::Json,
+                (true, true) => Severity::Source,
+            },
+            ChangeKind::FieldNumberReused { .. } => Severity::Wire,
+            ChangeKind::FieldNumberChanged { .. } => Severity::Wire,
+            ChangeKind::FieldRenamed { .. } => Severity::Json,
+            ChangeKind::FieldTypeChanged {
+                wire_compatible: true,
+                ..
+            } => Severity::Json,
+            ChangeKind::FieldTypeChanged { .. } => Severity::Wire,
+            ChangeKind::FieldLabelChanged { .. } => Severity::Wire,
+            ChangeKind::FieldOneofChanged { .. } => Severity::Wire,
+            ChangeKind::FieldJsonNameChanged { .. } => Severity::Json,
+            ChangeKind::EnumValueRenamed { .. } => Severity::Json,
+        }
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn reserved(number_reserved: bool, name_reserved: bool) -> &'static str {
+            match (number_reserved, name_reserved) {
+                (false, false) => "without reserving number and name",
+                (false, true) => "without reserving number",
+                (true, false) => "without reserving name",
+                (true, true) => "with reserved number and name",
+            }
+        }
+        fn oneof(oneof: &Option<String>) -> String {
+            match oneof {
+                Some(oneof) => format!("oneof {}", oneof),
+                None => "no oneof".to_owned(),
+            }
+        }
+
+        match self {
+            ChangeKind::PackageChanged { old, new } => {
+                write!(f, "package changed from \"{}\" to \"{}\"", old, new)
+            }
+            ChangeKind::MessageRemoved => write!(f, "message removed"),
+            ChangeKind::EnumRemoved => write!(f, "enum removed"),
+            ChangeKind::FieldRemoved {
+                number,
+                number_reserved,
+                name_reserved,
+            } => write!(
+                f,
+                "field {} removed {}",
+                number,
+                reserved(*number_reserved, *name_reserved)
+            ),
+            ChangeKind::FieldNumberReused { number } => {
+                write!(f, "field uses reserved number {}", number)
+            }
+            ChangeKind::FieldNumberChanged { old, new } => {
+                write!(f, "field number changed from {} to {}", old, new)
+            }
+            ChangeKind::FieldRenamed { new } => write!(f, "field renamed to \"{}\"", new),
+            ChangeKind::FieldTypeChanged { old, new, .. } => {
+                write!(f, "field type changed from {} to {}", old, new)
+            }
+            ChangeKind::FieldLabelChanged { old, new } => {
+                write!(f, "field label changed from {} to {}", old, new)
+            }
+            ChangeKind::FieldOneofChanged { old, new } => {
+                write!(f, "field moved from {} to {}", oneof(old), oneof(new))
+            }
+            ChangeKind::FieldJsonNameChanged { old, new } => {
+                write!(f, "field JSON name changed from \"{}\" to \"{}\"", old, new)
+            }
+            ChangeKind::EnumValueRemoved {
+                number,
+                number_reserved,
+                name_reserved,
+            } => write!(
+                f,
+                "enum value {} removed {}",
+                number,
+                reserved(*number_reserved, *name_reserved)
+            ),
+            ChangeKind::EnumValueRenamed { new } => {
+                write!(f, "enum value renamed to \"{}\"", new)
+            }
+        }
+    }
+}
+
+/// Incompatible change between two versions of a schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakingChange {
+    /// Name of the file in old version.
+    pub file: String,
+    /// Fully qualified name of the changed element in old version,
+    /// e. g. `foo.Bar.baz` for a field or `foo.proto` for a file.
+    pub element: String,
+    /// What has changed.
+    pub kind: ChangeKind,
+}
+
+impl BreakingChange {
+    /// Most severe compatibility broken by this change.
+    pub fn severity(&self) -> Severity {
+        self.kind.severity()
+    }
+}
+
+impl fmt::Display for BreakingChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}: {}: {}",
+            self.file,
+            self.severity(),
+            self.element,
+            self.kind
+        )
+    }
+}
+
+/// Messages and enums of a schema by fully qualified names without leading dot.
+#[derive(Default)]
+struct Index<'a> {
+    messages: HashMap<String, &'a DescriptorProto>,
+    enums: HashMap<String, &'a EnumDescriptorProto>,
+}
+
+impl<'a> Index<'a> {
+    fn new(files: &'a [FileDescriptorProto]) -> Index<'a> {
+        let mut index = Index::default();
+        for file in files {
+            let package = file.get_package();
+            index.add(package, &file.message_type, &file.enum_type);
+        }
+        index
+    }
+
+    fn add(
+        &mut self,
+        scope: &str,
+        messages: &'a [DescriptorProto],
+        enums: &'a [EnumDescriptorProto],
+    ) {
+        for e in enums {
+            self.enums.insert(full_name(scope, e.get_name()), e);
+        }
+        for m in messages {
+            let name = full_name(scope, m.get_name());
+            self.add(&name, &m.nested_type, &m.enum_type);
+            self.messages.insert(name, m);
+        }
+    }
+}
+
+fn full_name(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", scope, name)
+    }
+}
+
+fn type_description(field: &FieldDescriptorProto) -> String {
+    match field.get_field_type() {
+        Type::TYPE_MESSAGE | Type::TYPE_ENUM | Type::TYPE_GROUP => {
+            field.get_type_name().trim_start_matches('.').to_owned()
+        }
+        t => format!("{:?}", t)["TYPE_".len()..].to_lowercase(),
+    }
+}
+
+fn label_description(label: Label) -> String {
+    format!("{:?}", label)["LABEL_".len()..].to_lowercase()
+}
+
+/// Types which values are encoded identically on the wire.
+fn wire_compatible(old: Type, new: Type) -> bool {
+    fn group(t: Type) -> u32 {
+        match t {
+            Type::TYPE_INT32
+            | Type::TYPE_UINT32
+            | Type::TYPE_INT64
+            | Type::TYPE_UINT64
+            | Type::TYPE_BOOL
+            | Type::TYPE_ENUM => 1,
+            Type::TYPE_SINT32 | Type::TYPE_SINT64 => 2,
+            Type::TYPE_FIXED32 | Type::TYPE_SFIXED32 => 3,
+            Type::TYPE_FIXED64 | Type::TYPE_SFIXED64 => 4,
+            Type::TYPE_STRING | Type::TYPE_BYTES => 5,
+            Type::TYPE_FLOAT => 6,
+            Type::TYPE_DOUBLE => 7,
+            Type::TYPE_MESSAGE => 8,
+            Type::TYPE_GROUP => 9,
+        }
+    }
+    group(old) == group(new)
+}
+
+fn effective_json_name(field: &FieldDescriptorProto) -> String {
+    if field.has_json_name() {
+        field.get_json_name().to_owned()
+    } else {
+        json_name(field.get_name())
+    }
+}
+
+/// Real oneof containing the field, proto3 `optional` fields are not in oneof.
+fn oneof_name(message: &DescriptorProto, field: &FieldDescriptorProto) -> Option<String> {
+    if field.has_oneof_index() && !field.get_proto3_optional() {
+        message
+            .oneof_decl
+            .get(field.get_oneof_index() as usize)
+            .map(|o| o.get_name().to_owned())
+    } else {
+        None
+    }
+}
+
+fn message_number_reserved(message: &DescriptorProto, number: i32) -> bool {
+    message
+        .reserved_range
+        .iter()
+        .any(|r| r.get_start() <= number && number < r.get_end())
+}
+
+fn enum_number_reserved(e: &EnumDescriptorProto, number: i32) -> bool {
+    e.reserved_range
+        .iter()
+        .any(|r| r.get_start() <= number && number <= r.get_end())
+}
+
+struct Comparator<'a> {
+    new: Index<'a>,
+    changes: Vec<BreakingChange>,
+    file: String,
+}
+
+impl<'a> Comparator<'a> {
+    fn change(&mut self, element: &str, kind: ChangeKind) {
+        self.changes.push(BreakingChange {
+            file: self.file.clone(),
+            element: element.to_owned(),
+            kind,
+        });
+    }
+
+    /// Compare declarations of old file or message with new schema.
+    ///
+    /// `new_scope` differs from `scope` if package of file changed.
+    fn scope(
+        &mut self,
+        scope: &str,
+        new_scope: &str,
+        messages: &[DescriptorProto],
+        enums: &[EnumDescriptorProto],
+    ) {
+        for e in enums {
+            let name = full_name(scope, e.get_name());
+            match self.new.enums.get(&full_name(new_scope, e.get_name())) {
+                Some(new) => self.enumeration(&name, e, new),
+                None => self.change(&name, ChangeKind::EnumRemoved),
+            }
+        }
+        for m in messages {
+            let name = full_name(scope, m.get_name());
+            let new_name = full_name(new_scope, m.get_name());
+            match self.new.messages.get(&new_name) {
+                Some(new) => self.message(&name, m, new),
+                // Map entry is removed together with map field
+                None if m.options.get_or_default().get_map_entry() => {}
+                None => self.change(&name, ChangeKind::MessageRemoved),
+            }
+            self.scope(&name, &new_name, &m.nested_type, &m.enum_type);
+        }
+    }
+
+    fn enumeration(&mut self, name: &str, old: &EnumDescriptorProto, new: &EnumDescriptorProto) {
+        for value in &old.value {
+            let element = full_name(name, value.get_name());
+            let number = value.get_number();
+            let new_values: Vec<_> = new
+                .value
+                .iter()
+                .filter(|v| v.get_number() == number)
+                .collect();
+            if new_values.is_empty() {
+                self.change(
+                    &element,
+                    ChangeKind::EnumValueRemoved {
+                        number,
+                        number_reserved: enum_number_reserved(new, number),
+                        name_reserved: new.reserved_name.iter().any(|n| n == value.get_name()),
+                    },
+                );
+            } else if new_values.iter().all(|v| v.get_name() != value.get_name()) {
+                // Name is still valid if it is kept as an alias
+                self.change(
+                    &element,
+                    ChangeKind::EnumValueRenamed {
+                        new: new_values[0].get_name().to_owned(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn message(&mut self, name: &str, old: &DescriptorProto, new: &DescriptorProto) {
+        for old_field in &old.field {
+            let element = full_name(name, old_field.get_name());
+            let number = old_field.get_number();
+            let new_field = match new.field.iter().find(|f| f.get_number() == number) {
+                Some(new_field) => new_field,
+                None => {
+                    let renumbered = new
+                        .field
+                        .iter()
+                        .find(|f| f.get_name() == old_field.get_name());
+                    let kind = match renumbered {
+                        Some(f) => ChangeKind::FieldNumberChanged {
+                            old: number,
+                            new: f.get_number(),
+                        },
+                        None => ChangeKind::FieldRemoved {
+                            number,
+                            number_reserved: message_number_reserved(new, number),
+                            name_reserved: new
+                                .reserved_name
+                                .iter()
+                                .any(|n| n == old_field.get_name()),
+                        },
+                    };
+                    self.change(&element, kind);
+                    continue;
+                }
+            };
+
+            if new_field.get_name() != old_field.get_name() {
+                let renumbered = new
+                    .field
+                    .iter()
+                    .find(|f| f.get_name() == old_field.get_name());
+                let kind = match renumbered {
+                    Some(f) => ChangeKind::FieldNumberChanged {
+                        old: number,
+                        new: f.get_number(),
+                    },
+                    None => ChangeKind::FieldRenamed {
+                        new: new_field.get_name().to_owned(),
+                    },
+                };
+                self.change(&element, kind);
+            } else if effective_json_name(old_field) != effective_json_name(new_field) {
+                self.change(
+                    &element,
+                    ChangeKind::FieldJsonNameChanged {
+                        old: effective_json_name(old_field),
+                        new: effective_json_name(new_field),
+                    },
+                );
+            }
+
+            let (old_type, new_type) = (type_description(old_field), type_description(new_field));
+            if old_type != new_type {
+                self.change(
+                    &element,
+                    ChangeKind::FieldTypeChanged {
+                        old: old_type,
+                        new: new_type,
+                        wire_compatible: wire_compatible(
+                            old_field.get_field_type(),
+                            new_field.get_field_type(),
+                        ) && old_field.get_field_type() != Type::TYPE_MESSAGE
+                            && old_field.get_field_type() != Type::TYPE_GROUP,
+                    },
+                );
+            }
+
+            if old_field.get_label() != new_field.get_label() {
+                self.change(
+                    &element,
+                    ChangeKind::FieldLabelChanged {
+                        old: label_description(old_field.get_label()),
+                        new: label_description(new_field.get_label()),
+                    },
+                );
+            }
+
+            let (old_oneof, new_oneof) = (oneof_name(old, old_field), oneof_name(new, new_field));
+            if old_oneof != new_oneof {
+                self.change(
+                    &element,
+                    ChangeKind::FieldOneofChanged {
+                        old: old_oneof,
+                        new: new_oneof,
+                    },
+                );
+            }
+        }
+
+        for new_field in &new.field {
+            let number = new_field.get_number();
+            if message_number_reserved(old, number) {
+                self.change(
+                    &full_name(name, new_field.get_name()),
+                    ChangeKind::FieldNumberReused { number },
+                );
+            }
+        }
+    }
+}
+
+/// Find incompatible changes between old and new versions of a schema.
+///
+/// Both versions are sets of files, e. g. from a `FileDescriptorSet`
+/// or returned by `parse_and_typecheck`. Dependencies may be included.
+pub fn compare(old: &[FileDescriptorProto], new: &[FileDescriptorProto]) -> Vec<BreakingChange> {
+    let mut comparator = Comparator {
+        new: Index::new(new),
+        changes: Vec::new(),
+        file: String::new(),
+    };
+    for old_file in old {
+        comparator.file = old_file.get_name().to_owned();
+        let package = old_file.get_package();
+        let new_package = match new.iter().find(|f| f.get_name() == old_file.get_name()) {
+            Some(new_file) if new_file.get_package() != package => {
+                comparator.change(
+                    old_file.get_name(),
+                    ChangeKind::PackageChanged {
+                        old: package.to_owned(),
+                        new: new_file.get_package().to_owned(),
+                    },
+                );
+                new_file.get_package()
+            }
+            _ => package,
+        };
+        comparator.scope(
+            package,
+            new_package,
+            &old_file.message_type,
+            &old_file.enum_type,
+        );
+    }
+    comparator.changes
+}
+
+const USAGE: &str = "\
+Usage: protobuf-breaking [OPTION] OLD NEW
+Report incompatible changes between OLD and NEW versions of a schema.
+OLD and NEW are either files containing a FileDescriptorSet (written by
+`protoc -o`) or directories with .proto files.
+  --level=LEVEL               Report only changes breaking LEVEL or more
+                              severe compatibility: source (default),
+                              json or wire.
+  -h, --help                  Show this text and exit.
+Exit status is 1 if changes are found and 2 on errors.
+";
+
+fn err_usage(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message.into())
+}
+
+/// Parsed command line.
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    inputs: Vec<PathBuf>,
+    level: Option<Severity>,
+    help: bool,
+}
+
+impl Args {
+    fn parse(args: impl IntoIterator<Item = OsString>) -> io::Result<Args> {
+        let mut r = Args::default();
+        for arg in args {
+            let arg = arg
+                .into_string()
+                .map_err(|a| err_usage(format!("argument is not valid UTF-8: {:?}", a)))?;
+            if arg == "-h" || arg == "--help" {
+                r.help = true;
+            } else if arg.starts_with("--level=") {
+                r.level = Some(arg["--level=".len()..].parse()?);
+            } else if arg.starts_with('-') {
+                return Err(err_usage(format!("unknown flag: {}", arg)));
+            } else {
+                r.inputs.push(PathBuf::from(arg));
+            }
+        }
+        if !r.help && r.inputs.len() != 2 {
+            return Err(err_usage("expecting exactly two inputs"));
+        }
+        Ok(r)
+    }
+}
+
+fn proto_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            proto_files(&path, files)?;
+        } else if path.extension().map_or(false, |e| e == "proto") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Read descriptor set or parse all `.proto` files in a directory.
+fn read_schema(path: &Path) -> io::Result<Vec<FileDescriptorProto>> {
+    if path.is_dir() {
+        let mut files = Vec::new();
+        proto_files(path, &mut files)?;
+        files.sort();
+        let parsed = parse_and_typecheck_impl(&[path.to_owned()], &[], &files, false)?;
+        Ok(parsed.file_descriptors)
+    } else {
+        decode::read_descriptor_sets(&[path.to_owned()])
+    }
+}
+
+/// Entry point of `protobuf-breaking` command.
+pub fn breaking_main() {
+    let args = match Args::parse(env::args_os().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprint!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    if args.help {
+        print!("{}", USAGE);
+        return;
+    }
+
+    let (old, new) = match (read_schema(&args.inputs[0]), read_schema(&args.inputs[1])) {
+        (Ok(old), Ok(new)) => (old, new),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    let level = args.level.unwrap_or(Severity::Source);
+    let changes: Vec<_> = compare(&old, &new)
+        .into_iter()
+        .filter(|c| c.severity() >= level)
+        .collect();
+    for change in &changes {
+        println!("{}", change);
+    }
+    if !changes.is_empty() {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::parse_and_typecheck_with_sources;
+    use crate::MemorySourceProvider;
+
+    fn parse(content: &str) -> Vec<FileDescriptorProto> {
+        let mut sources = MemorySourceProvider::new();
+        sources.add("t.proto", content);
+        parse_and_typecheck_with_sources(&[], &[&sources], &[PathBuf::from("t.proto")])
+            .unwrap()
+            .file_descriptors
+    }
+
+    fn changes(old: &str, new: &str) -> Vec<String> {
+        compare(&parse(old), &parse(new))
+            .iter()
+            .map(|c| format!("{}", c))
+            .collect()
+    }
+
+    #[test]
+    fn no_changes() {
+        let schema = "syntax = \"proto3\"; package p; message M { int32 a = 1; }";
+        assert_eq!(Vec::<String>::new(), changes(schema, schema));
+    }
+
+    #[test]
+    fn fields() {
+        assert_eq!(
+            vec![
+                "t.proto: wire: p.M.a: field 1 removed without reserving number and name",
+                "t.proto: json: p.M.b: field 2 removed without reserving name",
+                "t.proto: source: p.M.c: field 3 removed with reserved number and name",
+                "t.proto: json: p.M.d: field renamed to \"dd\"",
+                "t.proto: wire: p.M.e: field number changed from 5 to 15",
+                "t.proto: json: p.M.f: field type changed from int32 to int64",
+                "t.proto: wire: p.M.g: field type changed from string to p.M",
+                "t.proto: wire: p.M.h: field label changed from optional to repeated",
+                "t.proto: wire: p.M.i: field moved from no oneof to oneof o",
+                "t.proto: json: p.M.j: field JSON name changed from \"j\" to \"jj\"",
+                "t.proto: wire: p.M.k: field uses reserved number 20",
+            ],
+            changes(
+                "syntax = \"proto3\"; package p; message M {
+                    int32 a = 1; int32 b = 2; int32 c = 3; int32 d = 4; int32 e = 5;
+                    int32 f = 6; string g = 7; int32 h = 8; int32 i = 9; int32 j = 10;
+                    reserved 20;
+                }",
+                "syntax = \"proto3\"; package p; message M {
+                    reserved 2, 3; reserved \"c\";
+                    int32 dd = 4; int32 e = 15;
+                    int64 f = 6; M g = 7; repeated int32 h = 8;
+                    oneof o { int32 i = 9; }
+                    int32 j = 10 [json_name = \"jj\"];
+                    int32 k = 20;
+                }"
+            )
+        );
+    }
+
+    #[test]
+    fn enums() {
+        assert_eq!(
+            vec![
+                "t.proto: wire: E.B: enum value 1 removed without reserving number and name",
+                "t.proto: json: E.C: enum value renamed to \"CC\"",
+                "t.proto: source: F: enum removed",
+                "t.proto: source: M: message removed",
+            ],
+            changes(
+                "syntax = \"proto3\"; enum E { A = 0; B = 1; C = 2; D = 3; }
+                 enum F { X = 0; } message M {}",
+                "syntax = \"proto3\"; enum E {
+                    option allow_alias = true; A = 0; CC = 2; D = 3; DD = 3;
+                 }",
+            )
+        );
+    }
+
+    #[test]
+    fn package() {
+        assert_eq!(
+            vec![
+                "t.proto: wire: t.proto: package changed from \"a\" to \"b\"",
+                "t.proto: json: a.M.x: field renamed to \"y\"",
+            ],
+            changes(
+                "syntax = \"proto3\"; package a; message M { int32 x = 1; }",
+                "syntax = \"proto3\"; package b; message M { int32 y = 1; }",
+            )
+        );
+    }
+
+    #[test]
+    fn parse_args() {
+        let args =
+            Args::parse(["--level=json", "old.pb", "new"].iter().map(OsString::from)).unwrap();
+        assert_eq!(Some(Severity::Json), args.level);
+        assert_eq!(
+            vec![PathBuf::from("old.pb"), PathBuf::from("new")],
+            args.inputs
+        );
+        assert!(Args::parse(vec![OsString::from("--level=x")]).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 //! Convert parser model to rust-protobuf model
 
+use std::fmt;
 use std::iter;
 use std::path::Path;
 
@@ -8,25 +9,32 @@ use crate::model;
 use protobuf;
 use protobuf::descriptor::field_descriptor_proto;
 use protobuf::json::json_name;
-use protobuf::Message;
+use protobuf::CodedOutputStream;
 use protobuf::UnknownFields;
 use protobuf::UnknownValue;
 
+use crate::features;
 use crate::model::ProtobufOptionName;
 use crate::path::fs_path_to_proto_path;
 use crate::protobuf_codegen::case_convert::camel_case;
 use crate::protobuf_codegen::ProtobufAbsolutePath;
 use crate::protobuf_codegen::ProtobufIdent;
 use crate::protobuf_codegen::ProtobufRelativePath;
+use crate::validate;
+use crate::validate::ValidationError;
 use protobuf::descriptor::descriptor_proto::ReservedRange;
+use protobuf::descriptor::enum_descriptor_proto::EnumReservedRange;
 use protobuf::descriptor::field_descriptor_proto::Type;
+use protobuf::reflect::features::OptionsWithFeatures;
+use protobuf::reflect::features::RepeatedFieldEncoding;
 use protobuf::reflect::RuntimeTypeBox;
+use protobuf::text_format::lexer::Loc;
 use protobuf::text_format::lexer::StrLitDecodeError;
 use protobuf::text_format::quote_bytes_to;
+use protobuf::wire_format::WireType;
 
 #[derive(Debug)]
 pub enum ConvertError {
-    UnsupportedOption(String),
     ExtensionNotFound(String),
     WrongExtensionType(String, String),
     UnsupportedExtensionType(String, String, model::ProtobufConstant),
@@ -41,8 +49,85 @@ pub enum ConvertError {
     ExpectingEnum(ProtobufAbsolutePath),
     UnknownEnumValue(String),
     UnknownFieldName(String),
+    OptionValueOutOfRange(String, String, model::ProtobufConstant),
+    Validation(ValidationError),
+    /// `features` option in `proto2` or `proto3` file.
+    FeaturesNotAllowed,
+    /// Unknown feature or feature value.
+    UnknownFeature(String),
+    /// Error in declaration at given location
+    WithLoc(Loc, Box<ConvertError>),
 }
 
+impl ConvertError {
+    /// Attach location unless error already has more precise location.
+    pub(crate) fn with_loc(self, loc: Loc) -> ConvertError {
+        match self {
+            e @ ConvertError::WithLoc(..) => e,
+            e => ConvertError::WithLoc(loc, Box::new(e)),
+        }
+    }
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::ExtensionNotFound(e) => write!(f, "extension \"{}\" not found", e),
+            ConvertError::WrongExtensionType(option, extendee) => write!(
+                f,
+                "option \"{}\" is not an extension of \"{}\"",
+                option, extendee
+            ),
+            ConvertError::UnsupportedExtensionType(option, field, value) => write!(
+                f,
+                "option \"{}\" of field \"{}\" has unsupported value {}",
+                option,
+                field,
+                value.format()
+            ),
+            ConvertError::StrLitDecodeError(e) => write!(f, "{}", e),
+            ConvertError::DefaultValueIsNotStringLiteral => {
+                write!(f, "default value is not a string literal")
+            }
+            ConvertError::WrongOptionType => write!(f, "wrong option type"),
+            ConvertError::InconvertibleValue(t, value) => {
+                write!(f, "cannot convert value {} to type {}", value.format(), t)
+            }
+            ConvertError::ConstantsOfTypeMessageEnumGroupNotImplemented => {
+                write!(
+                    f,
+                    "constants of message, enum or group type are not implemented"
+                )
+            }
+            ConvertError::NotFoundByAbsPath(path) => write!(f, "unknown type \"{}\"", path),
+            ConvertError::NotFoundByRelPath(path, _scope) => {
+                write!(f, "unknown type \"{}\"", path)
+            }
+            ConvertError::ExpectingMessage(path) => write!(f, "\"{}\" is not a message", path),
+            ConvertError::ExpectingEnum(path) => write!(f, "\"{}\" is not an enum", path),
+            ConvertError::UnknownEnumValue(v) => write!(f, "unknown enum value \"{}\"", v),
+            ConvertError::UnknownFieldName(n) => write!(f, "unknown field name \"{}\"", n),
+            ConvertError::OptionValueOutOfRange(option, t, value) => write!(
+                f,
+                "value {} of option \"{}\" is out of range of type {}",
+                value.format(),
+                option,
+                t
+            ),
+            ConvertError::Validation(e) => write!(f, "{}", e),
+            ConvertError::FeaturesNotAllowed => {
+                write!(f, "features are only valid under editions")
+            }
+            ConvertError::UnknownFeature(feature) => {
+                write!(f, "unknown feature \"{}\"", feature)
+            }
+            ConvertError::WithLoc(_, e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ConvertError {}
+
 impl From<StrLitDecodeError> for ConvertError {
     fn from(e: StrLitDecodeError) -> Self {
         ConvertError::StrLitDecodeError(e)
@@ -51,7 +136,7 @@ impl From<StrLitDecodeError> for ConvertError {
 
 pub type ConvertResult<T> = Result<T, ConvertError>;
 
-trait ProtobufOptions {
+pub(crate) trait ProtobufOptions {
     fn by_name(&self, name: &str) -> Option<&model::ProtobufConstant>;
 
     fn by_name_bool(&self, name: &str) -> ConvertResult<Option<bool>> {
@@ -86,6 +171,105 @@ impl<'a> ProtobufOptions for &'a [model::ProtobufOption] {
     }
 }
 
+/// Field of option or of message literal in option value.
+struct OptionField<'a> {
+    field: &'a model::Field,
+    /// File where field is declared
+    file: &'a model::FileDescriptor,
+    /// Scope to resolve field type in
+    scope: ProtobufAbsolutePath,
+    /// Message containing or extended by the field
+    extendee: ProtobufAbsolutePath,
+}
+
+/// Wire value of a field in option value.
+enum OptionFieldValue {
+    Unknown(UnknownValue),
+    /// Element of packed repeated field
+    Packed(UnknownValue),
+    /// Fields of a group
+    Group(Vec<(u32, OptionFieldValue)>),
+}
+
+/// Merge values of packed fields, fields are sorted by number.
+fn pack_option_fields(mut fields: Vec<(u32, OptionFieldValue)>) -> Vec<(u32, OptionFieldValue)> {
+    fields.sort_by_key(|(number, ..)| *number);
+    let mut r: Vec<(u32, OptionFieldValue)> = Vec::new();
+    let mut packed_number = None;
+    for (number, value) in fields {
+        let value = match value {
+            OptionFieldValue::Packed(value) => value,
+            value => {
+                packed_number = None;
+                r.push((number, value));
+                continue;
+            }
+        };
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            os.write_unknown_no_tag(value.get_ref())
+                .expect("write to Vec");
+            os.flush().expect("write to Vec");
+        }
+        match r.last_mut() {
+            Some((_, OptionFieldValue::Unknown(UnknownValue::LengthDelimited(packed))))
+                if packed_number == Some(number) =>
+            {
+                packed.extend(bytes);
+            }
+            _ => r.push((
+                number,
+                OptionFieldValue::Unknown(UnknownValue::LengthDelimited(bytes)),
+            )),
+        }
+        packed_number = Some(number);
+    }
+    r
+}
+
+/// Serialize fields of message literal in field number order, like `protoc` does.
+fn option_fields_to_bytes(fields: Vec<(u32, OptionFieldValue)>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        for (number, value) in pack_option_fields(fields) {
+            match value {
+                OptionFieldValue::Unknown(value) | OptionFieldValue::Packed(value) => {
+                    os.write_unknown(number, value.get_ref())
+                        .expect("write to Vec");
+                }
+                OptionFieldValue::Group(group) => {
+                    os.write_tag(number, WireType::WireTypeStartGroup)
+                        .expect("write to Vec");
+                    os.write_raw_bytes(&option_fields_to_bytes(group))
+                        .expect("write to Vec");
+                    os.write_tag(number, WireType::WireTypeEndGroup)
+                        .expect("write to Vec");
+                }
+            }
+        }
+        os.flush().expect("write to Vec");
+    }
+    bytes
+}
+
+/// Store option fields as unknown fields of options message.
+fn add_option_fields(unknown_fields: &mut UnknownFields, fields: Vec<(u32, OptionFieldValue)>) {
+    for (number, value) in pack_option_fields(fields) {
+        match value {
+            OptionFieldValue::Unknown(value) | OptionFieldValue::Packed(value) => {
+                unknown_fields.add_value(number, value);
+            }
+            OptionFieldValue::Group(fields) => {
+                let mut group = UnknownFields::new();
+                add_option_fields(&mut group, fields);
+                unknown_fields.add_group(number, group);
+            }
+        }
+    }
+}
+
 enum MessageOrEnum<'a> {
     Message(&'a model::Message),
     Enum(&'a model::Enumeration),
@@ -133,7 +317,7 @@ impl<'a> LookupScope<'a> {
             .map(|m| &m.t)
     }
 
-    fn enums(&self) -> &'a [model::Enumeration] {
+    fn enums(&self) -> &'a [model::WithLoc<model::Enumeration>] {
         match self {
             &LookupScope::File(file) => &file.enums,
             &LookupScope::Message(messasge, _) => &messasge.enums,
@@ -142,11 +326,12 @@ impl<'a> LookupScope<'a> {
 
     fn members(&self) -> Vec<(ProtobufIdent, MessageOrEnum<'a>)> {
         let mut r = Vec::new();
-        r.extend(
-            self.enums()
-                .into_iter()
-                .map(|e| (ProtobufIdent::from(&e.name[..]), MessageOrEnum::Enum(e))),
-        );
+        r.extend(self.enums().into_iter().map(|e| {
+            (
+                ProtobufIdent::from(&e.t.name[..]),
+                MessageOrEnum::Enum(&e.t),
+            )
+        }));
         r.extend(self.messages().into_iter().map(|m| {
             (
                 ProtobufIdent::from(&m.t.name[..]),
@@ -225,6 +410,16 @@ enum TypeResolved {
 }
 
 impl TypeResolved {
+    fn is_packable(&self) -> bool {
+        match self {
+            TypeResolved::String
+            | TypeResolved::Bytes
+            | TypeResolved::Message(..)
+            | TypeResolved::Group(..) => false,
+            _ => true,
+        }
+    }
+
     fn type_enum(&self) -> Type {
         match self {
             TypeResolved::Bool => Type::TYPE_BOOL,
@@ -319,18 +514,20 @@ impl<'a> Resolver<'a> {
 
     fn group_message(
         &self,
-        name: &str,
-        fields: &[model::WithLoc<model::Field>],
+        group: &model::Group,
         path_in_file: &ProtobufRelativePath,
     ) -> ConvertResult<protobuf::descriptor::DescriptorProto> {
         let mut output = protobuf::descriptor::DescriptorProto::new();
 
-        output.set_name(name.to_owned());
+        output.set_name(group.name.clone());
 
-        for f in fields {
+        for f in &group.fields {
             output.field.push(self.field(f, None, path_in_file)?);
         }
 
+        output.reserved_range = reserved_ranges(&group.reserved_nums);
+        output.reserved_name = group.reserved_names.clone();
+
         Ok(output)
     }
 
@@ -355,7 +552,9 @@ impl<'a> Resolver<'a> {
         let mut nested_messages = Vec::new();
 
         for m in &input.messages {
-            let message = self.message(&m.t, &nested_path_in_file)?;
+            let message = self
+                .message(&m.t, &nested_path_in_file)
+                .map_err(|e| e.with_loc(m.loc))?;
             nested_messages.push(model::WithLoc {
                 t: message,
                 loc: m.loc,
@@ -371,12 +570,8 @@ impl<'a> Resolver<'a> {
                         loc: f.loc,
                     });
                 }
-                model::FieldType::Group(model::Group {
-                    name: group_name,
-                    fields,
-                    ..
-                }) => {
-                    let message = self.group_message(group_name, fields, &nested_path_in_file)?;
+                model::FieldType::Group(group) => {
+                    let message = self.group_message(group, &nested_path_in_file)?;
                     nested_messages.push(model::WithLoc {
                         t: message,
                         loc: f.loc,
@@ -386,6 +581,25 @@ impl<'a> Resolver<'a> {
             }
         }
 
+        // Extensions are declared in the scope of this message
+        let mut extensions = Vec::new();
+        for ext in &input.extensions {
+            let mut extension = self.field(&ext.t.field, None, &nested_path_in_file)?;
+            extension.set_extendee(
+                self.extendee(&ext.t.extendee, &ext.t.field, &nested_path_in_file)
+                    .map_err(|e| e.with_loc(ext.loc))?
+                    .path,
+            );
+            extensions.push(extension);
+            if let model::FieldType::Group(g) = &ext.t.field.t.typ {
+                let message = self.group_message(g, &nested_path_in_file)?;
+                nested_messages.push(model::WithLoc {
+                    t: message,
+                    loc: ext.loc,
+                });
+            }
+        }
+
         // Preserve declaration order
         nested_messages.sort_by_key(|m| m.loc);
         output.nested_type = nested_messages
@@ -396,7 +610,10 @@ impl<'a> Resolver<'a> {
         output.enum_type = input
             .enums
             .iter()
-            .map(|e| self.enumeration(e, path_in_file))
+            .map(|e| {
+                self.enumeration(&e.t, path_in_file)
+                    .map_err(|err| err.with_loc(e.loc))
+            })
             .collect::<Result<_, _>>()?;
 
         {
@@ -432,22 +649,9 @@ impl<'a> Resolver<'a> {
             extension_range.set_end(ext.to + 1);
             output.extension_range.push(extension_range);
         }
-        for ext in &input.extensions {
-            let mut extension = self.field(&ext.t.field, None, path_in_file)?;
-            extension.set_extendee(
-                self.resolve_message_or_enum_leg(&ext.t.extendee, path_in_file)?
-                    .0
-                    .path,
-            );
-            output.extension.push(extension);
-        }
+        output.extension = extensions;
 
-        for reserved in &input.reserved_nums {
-            let mut reserved_range = ReservedRange::new();
-            reserved_range.set_start(reserved.from);
-            reserved_range.set_end(reserved.to + 1);
-            output.reserved_range.push(reserved_range);
-        }
+        output.reserved_range = reserved_ranges(&input.reserved_nums);
         output.reserved_name = input.reserved_names.clone().into();
 
         Ok(output)
@@ -459,14 +663,19 @@ impl<'a> Resolver<'a> {
         path_in_file: &ProtobufRelativePath,
     ) -> ConvertResult<M>
     where
-        M: Message,
+        M: OptionsWithFeatures,
     {
+        let mut scope =
+            ProtobufAbsolutePath::from_package_path(self.current_file.package.as_deref());
+        scope.push_relative(path_in_file);
+
         let mut options = M::new();
+        options.set_features(&features::feature_set(self.current_file.syntax, input)?);
         let extendee = M::descriptor_static().full_name().to_owned();
 
         for option in input {
-            match option.name.get_simple() {
-                Some(simple) => {
+            let (extension_name, rem) = match &option.name.components[..] {
+                [model::ProtobufOptionNameComponent::Direct(simple)] => {
                     if let Some(field) = M::descriptor_static().get_field_by_name(simple.get()) {
                         if field.is_repeated_or_map() {
                             continue;
@@ -479,41 +688,40 @@ impl<'a> Resolver<'a> {
                     }
                     continue;
                 }
-                None => {
-                    // ?
+                [model::ProtobufOptionNameComponent::Ext(name), rem @ ..] => (name, rem),
+                _ => {
+                    // Fields of builtin options of message type
+                    continue;
+                }
+            };
+
+            // `(ext).a.b = v` is the same as `(ext) = { a { b: v } }`
+            let mut value = option.value.clone();
+            for component in rem.iter().rev() {
+                let mut message = model::ProtobufConstantMessage::default();
+                match component {
+                    model::ProtobufOptionNameComponent::Direct(n) => {
+                        message.fields.push((n.get().to_owned(), value))
+                    }
+                    model::ProtobufOptionNameComponent::Ext(n) => {
+                        message.extensions.push((n.clone(), value))
+                    }
                 }
+                value = model::ProtobufConstant::Message(message);
             }
 
-            let extension = match self.find_extension(&option.name.full_name()) {
-                Ok(e) => e,
-                // TODO: return error
-                Err(_) => continue,
-            };
-            if extension.extendee != extendee {
+            let option_name_for_diag = format!("{}", option.name);
+            let extension = self.find_extension(extension_name, &scope)?;
+            if extension.extendee.path != format!(".{}", extendee) {
                 return Err(ConvertError::WrongExtensionType(
-                    format!("{}", option.name),
+                    option_name_for_diag,
                     extendee,
                 ));
             }
 
-            let value = match self.option_value_to_unknown_value_leg(
-                &option.value,
-                &extension.field.t.name,
-                &extension.field.t.typ,
-                &format!("{}", option.name),
-                path_in_file,
-            ) {
-                Ok(value) => value,
-                Err(ConvertError::ConstantsOfTypeMessageEnumGroupNotImplemented) => {
-                    // TODO: return error
-                    continue;
-                }
-                Err(e) => return Err(e),
-            };
-
-            options
-                .mut_unknown_fields()
-                .add_value(extension.field.t.number as u32, value);
+            let mut fields = Vec::new();
+            self.option_field_values(&extension, &value, &option_name_for_diag, &mut fields)?;
+            add_option_fields(options.mut_unknown_fields(), fields);
         }
         Ok(options)
     }
@@ -531,6 +739,16 @@ impl<'a> Resolver<'a> {
         input: &model::WithLoc<model::Field>,
         oneof_index: Option<i32>,
         path_in_file: &ProtobufRelativePath,
+    ) -> ConvertResult<protobuf::descriptor::FieldDescriptorProto> {
+        self.field_no_loc(input, oneof_index, path_in_file)
+            .map_err(|e| e.with_loc(input.loc))
+    }
+
+    fn field_no_loc(
+        &self,
+        input: &model::WithLoc<model::Field>,
+        oneof_index: Option<i32>,
+        path_in_file: &ProtobufRelativePath,
     ) -> ConvertResult<protobuf::descriptor::FieldDescriptorProto> {
         let mut output = protobuf::descriptor::FieldDescriptorProto::new();
         output.set_name(input.t.name.clone());
@@ -590,13 +808,6 @@ impl<'a> Resolver<'a> {
         iter::once(self.current_file).chain(self.deps).collect()
     }
 
-    fn package_files(&self, package: Option<&str>) -> Vec<&model::FileDescriptor> {
-        self.all_files()
-            .into_iter()
-            .filter(|f| f.package.as_deref() == package)
-            .collect()
-    }
-
     fn find_message_or_enum_by_abs_name(
         &self,
         absolute_path: &ProtobufAbsolutePath,
@@ -613,14 +824,24 @@ impl<'a> Resolver<'a> {
         return Err(ConvertError::NotFoundByAbsPath(absolute_path.clone()));
     }
 
-    fn find_message_by_abs_name(
+    fn find_message_with_file_by_abs_name(
         &self,
         abs_path: &ProtobufAbsolutePath,
-    ) -> ConvertResult<&'a model::Message> {
-        match self.find_message_or_enum_by_abs_name(abs_path)? {
-            MessageOrEnum::Message(m) => Ok(m),
-            MessageOrEnum::Enum(..) => Err(ConvertError::ExpectingMessage(abs_path.clone())),
+    ) -> ConvertResult<(&'a model::FileDescriptor, &'a model::Message)> {
+        for file in self.all_files() {
+            let file_package = ProtobufAbsolutePath::from_package_path(file.package.as_deref());
+            if let Some(relative) = abs_path.remove_prefix(&file_package) {
+                match LookupScope::File(file).find_message_or_enum(&relative) {
+                    Some((_, MessageOrEnum::Message(m))) => return Ok((file, m)),
+                    Some((_, MessageOrEnum::Enum(..))) => {
+                        return Err(ConvertError::ExpectingMessage(abs_path.clone()))
+                    }
+                    None => {}
+                }
+            }
         }
+
+        Err(ConvertError::NotFoundByAbsPath(abs_path.clone()))
     }
 
     fn find_enum_by_abs_name(
@@ -788,9 +1009,19 @@ impl<'a> Resolver<'a> {
         output.value = input
             .values
             .iter()
-            .map(|v| self.enum_value(&v, path_in_file))
+            .map(|v| {
+                self.enum_value(&v.t, path_in_file)
+                    .map_err(|e| e.with_loc(v.loc))
+            })
             .collect::<Result<_, _>>()?;
         output.options = Some(self.enum_options(&input.options, path_in_file)?).into();
+        for reserved in &input.reserved_nums {
+            let mut reserved_range = EnumReservedRange::new();
+            reserved_range.set_start(reserved.from);
+            reserved_range.set_end(reserved.to);
+            output.reserved_range.push(reserved_range);
+        }
+        output.reserved_name = input.reserved_names.clone().into();
         Ok(output)
     }
 
@@ -813,173 +1044,364 @@ impl<'a> Resolver<'a> {
         Ok(output)
     }
 
-    fn find_extension_by_path(&self, path: &str) -> ConvertResult<&model::Extension> {
-        let (package, name) = match path.rfind('.') {
-            Some(dot) => (Some(&path[..dot]), &path[dot + 1..]),
-            None => (self.current_file.package.as_deref(), path),
-        };
-
-        for file in self.package_files(package) {
-            for ext in &file.extensions {
-                if ext.t.field.t.name == name {
-                    return Ok(&ext.t);
+    /// Find extension declared in any message or at top level of a file.
+    fn find_extension_by_abs_path(&self, path: &ProtobufAbsolutePath) -> Option<OptionField<'a>> {
+        for file in self.all_files() {
+            let file_package = ProtobufAbsolutePath::from_package_path(file.package.as_deref());
+            let relative = match path.remove_prefix(&file_package) {
+                Some(relative) => relative,
+                None => continue,
+            };
+            let (scope, extensions, name) = match relative.path.rfind('.') {
+                Some(dot) => {
+                    let message_path = ProtobufRelativePath::from(&relative.path[..dot]);
+                    match LookupScope::File(file).find_message_or_enum(&message_path) {
+                        Some((scope, MessageOrEnum::Message(m))) => {
+                            (scope, &m.extensions, &relative.path[dot + 1..])
+                        }
+                        _ => continue,
+                    }
+                }
+                None => (file_package, &file.extensions, &relative.path[..]),
+            };
+            for ext in extensions {
+                if ext.t.field.t.name != name {
+                    continue;
                 }
+                let extendee = match self.resolve_message_or_enum(&ext.t.extendee, &scope) {
+                    Ok((extendee, MessageOrEnum::Message(..))) => extendee,
+                    _ => continue,
+                };
+                return Some(OptionField {
+                    field: &ext.t.field.t,
+                    file,
+                    scope,
+                    extendee,
+                });
             }
         }
-
-        Err(ConvertError::ExtensionNotFound(path.to_owned()))
+        None
     }
 
-    fn find_extension(&self, option_name: &str) -> ConvertResult<&model::Extension> {
-        if !option_name.starts_with('(') || !option_name.ends_with(')') {
-            return Err(ConvertError::UnsupportedOption(option_name.to_owned()));
+    /// Find extension by name relative to given scope, like `protoc` does.
+    fn find_extension(
+        &self,
+        name: &str,
+        scope: &ProtobufAbsolutePath,
+    ) -> ConvertResult<OptionField<'a>> {
+        if ProtobufAbsolutePath::is_abs(name) {
+            let path = ProtobufAbsolutePath::new(name.to_owned());
+            return self
+                .find_extension_by_abs_path(&path)
+                .ok_or_else(|| ConvertError::ExtensionNotFound(name.to_owned()));
         }
-        let path = &option_name[1..option_name.len() - 1];
-        self.find_extension_by_path(path)
+
+        for mut path in scope.self_and_parents() {
+            path.push_relative(&ProtobufRelativePath::from(name));
+            if let Some(ext) = self.find_extension_by_abs_path(&path) {
+                return Ok(ext);
+            }
+        }
+        Err(ConvertError::ExtensionNotFound(name.to_owned()))
     }
 
-    fn option_value_to_unknown_value_leg(
+    /// Convert option value of a field to wire values.
+    fn option_field_values(
         &self,
+        field: &OptionField,
         value: &model::ProtobufConstant,
-        name: &str,
-        field_type: &model::FieldType,
         option_name_for_diag: &str,
-        path_in_file: &ProtobufRelativePath,
-    ) -> ConvertResult<UnknownValue> {
-        let mut scope = ProtobufAbsolutePath::from_package_path(
-            self.current_file.package.as_ref().map(|s| s.as_ref()),
-        );
-        scope.push_relative(path_in_file);
+        output: &mut Vec<(u32, OptionFieldValue)>,
+    ) -> ConvertResult<()> {
+        let number = field.field.number as u32;
+        match &field.field.typ {
+            model::FieldType::Map(types) => {
+                let entry = match value {
+                    model::ProtobufConstant::Message(entry) => entry,
+                    _ => {
+                        return Err(ConvertError::UnsupportedExtensionType(
+                            option_name_for_diag.to_owned(),
+                            field.field.name.clone(),
+                            value.clone(),
+                        ))
+                    }
+                };
+                let bytes = self.option_map_entry_to_bytes(
+                    entry,
+                    types,
+                    option_name_for_diag,
+                    &field.scope,
+                )?;
+                output.push((
+                    number,
+                    OptionFieldValue::Unknown(UnknownValue::LengthDelimited(bytes)),
+                ));
+            }
+            model::FieldType::Group(g) => {
+                let message = match value {
+                    model::ProtobufConstant::Message(message) => message,
+                    _ => {
+                        return Err(ConvertError::UnsupportedExtensionType(
+                            option_name_for_diag.to_owned(),
+                            field.field.name.clone(),
+                            value.clone(),
+                        ))
+                    }
+                };
+                let mut group_path = field.scope.clone();
+                group_path.push_simple(ProtobufIdent::from(g.name.as_str()));
+                let fields = self.option_fields_literal(
+                    message,
+                    g.fields.iter().map(|f| &f.t).collect(),
+                    field.file,
+                    &group_path,
+                    option_name_for_diag,
+                )?;
+                output.push((number, OptionFieldValue::Group(fields)));
+            }
+            typ => {
+                let resolved = self.field_type(&field.field.name, typ, &field.scope)?;
+                let value =
+                    self.option_value_to_unknown_value(value, &resolved, option_name_for_diag)?;
+                let packed = field.field.rule == model::Rule::Repeated
+                    && resolved.is_packable()
+                    && match field.field.options.as_slice().by_name_bool("packed")? {
+                        Some(packed) => packed,
+                        None => {
+                            let field_features =
+                                features::feature_set(field.file.syntax, &field.field.options)?;
+                            features::file_features(field.file)?
+                                .merge(&field_features)
+                                .repeated_field_encoding
+                                == RepeatedFieldEncoding::Packed
+                        }
+                    };
+                output.push((
+                    number,
+                    match packed {
+                        true => OptionFieldValue::Packed(value),
+                        false => OptionFieldValue::Unknown(value),
+                    },
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn option_map_entry_to_bytes(
+        &self,
+        entry: &model::ProtobufConstantMessage,
+        types: &(model::FieldType, model::FieldType),
+        option_name_for_diag: &str,
+        scope: &ProtobufAbsolutePath,
+    ) -> ConvertResult<Vec<u8>> {
+        let mut fields = Vec::new();
+        for (n, v) in &entry.fields {
+            let (number, typ) = match n.as_str() {
+                "key" => (1, &types.0),
+                "value" => (2, &types.1),
+                _ => return Err(ConvertError::UnknownFieldName(n.clone())),
+            };
+            let resolved = self.field_type(n, typ, scope)?;
+            let value = self.option_value_to_unknown_value(v, &resolved, option_name_for_diag)?;
+            fields.push((number, OptionFieldValue::Unknown(value)));
+        }
+        Ok(option_fields_to_bytes(fields))
+    }
 
-        self.option_value_to_unknown_value(value, name, field_type, option_name_for_diag, &scope)
+    fn option_message_to_bytes(
+        &self,
+        input: &model::ProtobufConstantMessage,
+        message_path: &ProtobufAbsolutePath,
+        option_name_for_diag: &str,
+    ) -> ConvertResult<Vec<u8>> {
+        let (file, message) = self.find_message_with_file_by_abs_name(message_path)?;
+        let fields = message
+            .regular_fields_including_in_oneofs()
+            .into_iter()
+            .map(|f| &f.t)
+            .collect();
+        self.option_fields_literal_to_bytes(input, fields, file, message_path, option_name_for_diag)
+    }
+
+    /// Serialize message or group literal given fields of the message.
+    fn option_fields_literal_to_bytes(
+        &self,
+        input: &model::ProtobufConstantMessage,
+        message_fields: Vec<&model::Field>,
+        file: &model::FileDescriptor,
+        message_path: &ProtobufAbsolutePath,
+        option_name_for_diag: &str,
+    ) -> ConvertResult<Vec<u8>> {
+        let fields = self.option_fields_literal(
+            input,
+            message_fields,
+            file,
+            message_path,
+            option_name_for_diag,
+        )?;
+        Ok(option_fields_to_bytes(fields))
+    }
+
+    fn option_fields_literal(
+        &self,
+        input: &model::ProtobufConstantMessage,
+        message_fields: Vec<&model::Field>,
+        file: &model::FileDescriptor,
+        message_path: &ProtobufAbsolutePath,
+        option_name_for_diag: &str,
+    ) -> ConvertResult<Vec<(u32, OptionFieldValue)>> {
+        let mut fields = Vec::new();
+        for (n, v) in &input.fields {
+            let field = message_fields.iter().find(|f| match &f.typ {
+                // Text format refers to groups by type name or field name
+                model::FieldType::Group(g) => g.name == *n || f.name == *n,
+                _ => f.name == *n,
+            });
+            let field = match field {
+                Some(field) => field,
+                None => return Err(ConvertError::UnknownFieldName(n.clone())),
+            };
+            let field = OptionField {
+                field,
+                file,
+                scope: message_path.clone(),
+                extendee: message_path.clone(),
+            };
+            self.option_field_values(&field, v, option_name_for_diag, &mut fields)?;
+        }
+        for (n, v) in &input.extensions {
+            let extension = self.find_extension(n, message_path)?;
+            if &extension.extendee != message_path {
+                return Err(ConvertError::WrongExtensionType(
+                    n.clone(),
+                    message_path.path.clone(),
+                ));
+            }
+            self.option_field_values(&extension, v, option_name_for_diag, &mut fields)?;
+        }
+        Ok(fields)
     }
 
     fn option_value_to_unknown_value(
         &self,
         value: &model::ProtobufConstant,
-        name: &str,
-        field_type: &model::FieldType,
+        field_type: &TypeResolved,
         option_name_for_diag: &str,
-        scope: &ProtobufAbsolutePath,
     ) -> ConvertResult<UnknownValue> {
-        let field_type = self.field_type(name, field_type, &scope)?;
+        let out_of_range = || {
+            ConvertError::OptionValueOutOfRange(
+                option_name_for_diag.to_owned(),
+                format!("{:?}", field_type),
+                value.clone(),
+            )
+        };
 
-        match value {
-            &model::ProtobufConstant::Bool(b) => {
-                if field_type != TypeResolved::Bool {
-                    {}
-                } else {
-                    return Ok(UnknownValue::Varint(if b { 1 } else { 0 }));
-                }
+        match (value, field_type) {
+            (model::ProtobufConstant::Message(m), TypeResolved::Message(path)) => {
+                return Ok(UnknownValue::LengthDelimited(
+                    self.option_message_to_bytes(m, path, option_name_for_diag)?,
+                ));
             }
-            // TODO: check overflow
-            &model::ProtobufConstant::U64(v) => match field_type {
-                TypeResolved::Fixed64 | TypeResolved::Sfixed64 => {
-                    return Ok(UnknownValue::Fixed64(v))
-                }
-                TypeResolved::Fixed32 | TypeResolved::Sfixed32 => {
-                    return Ok(UnknownValue::Fixed32(v as u32))
-                }
-                TypeResolved::Int64
-                | TypeResolved::Int32
-                | TypeResolved::Uint64
-                | TypeResolved::Uint32 => return Ok(UnknownValue::Varint(v)),
-                TypeResolved::Sint64 => return Ok(UnknownValue::sint64(v as i64)),
-                TypeResolved::Sint32 => return Ok(UnknownValue::sint32(v as i32)),
-                TypeResolved::Float => return Ok(UnknownValue::float(v as f32)),
-                TypeResolved::Double => return Ok(UnknownValue::double(v as f64)),
-                _ => {}
-            },
-            &model::ProtobufConstant::I64(v) => match field_type {
-                TypeResolved::Fixed64 | TypeResolved::Sfixed64 => {
-                    return Ok(UnknownValue::Fixed64(v as u64))
-                }
-                TypeResolved::Fixed32 | TypeResolved::Sfixed32 => {
-                    return Ok(UnknownValue::Fixed32(v as u32))
-                }
-                TypeResolved::Int64
-                | TypeResolved::Int32
-                | TypeResolved::Uint64
-                | TypeResolved::Uint32 => return Ok(UnknownValue::Varint(v as u64)),
-                TypeResolved::Sint64 => return Ok(UnknownValue::sint64(v as i64)),
-                TypeResolved::Sint32 => return Ok(UnknownValue::sint32(v as i32)),
-                TypeResolved::Float => return Ok(UnknownValue::float(v as f32)),
-                TypeResolved::Double => return Ok(UnknownValue::double(v as f64)),
-                _ => {}
-            },
-            &model::ProtobufConstant::F64(f) => match field_type {
-                TypeResolved::Float => return Ok(UnknownValue::float(f as f32)),
-                TypeResolved::Double => return Ok(UnknownValue::double(f)),
-                TypeResolved::Fixed32 => return Ok(UnknownValue::Fixed32(f as u32)),
-                TypeResolved::Fixed64 => return Ok(UnknownValue::Fixed64(f as u64)),
-                TypeResolved::Sfixed32 => return Ok(UnknownValue::sfixed32(f as i32)),
-                TypeResolved::Sfixed64 => return Ok(UnknownValue::sfixed64(f as i64)),
-                TypeResolved::Int32 | TypeResolved::Int64 => {
-                    return Ok(UnknownValue::int64(f as i64))
-                }
-                TypeResolved::Uint32 | TypeResolved::Uint64 => {
-                    return Ok(UnknownValue::Varint(f as u64))
-                }
-                TypeResolved::Sint64 => return Ok(UnknownValue::sint64(f as i64)),
-                TypeResolved::Sint32 => return Ok(UnknownValue::sint32(f as i32)),
-                _ => {}
-            },
-            &model::ProtobufConstant::String(ref s) => match field_type {
-                TypeResolved::String => {
-                    return Ok(UnknownValue::LengthDelimited(s.decode_utf8()?.into_bytes()))
-                }
-                TypeResolved::Bytes => return Ok(UnknownValue::LengthDelimited(s.decode_bytes()?)),
-                _ => {}
-            },
-            model::ProtobufConstant::Ident(ident) => match &field_type {
-                TypeResolved::Enum(e) => {
-                    let e = self.find_enum_by_abs_name(e)?;
-                    let n = match e.values.iter().find(|v| v.name == *ident).map(|v| v.number) {
-                        Some(n) => n,
-                        None => return Err(ConvertError::UnknownEnumValue(ident.clone())),
-                    };
-                    return Ok(UnknownValue::int32(n));
-                }
-                _ => {}
-            },
-            model::ProtobufConstant::Message(mo) => match &field_type {
-                TypeResolved::Message(ma) => {
-                    let m = self.find_message_by_abs_name(ma)?;
-                    let mut unknown_fields = UnknownFields::new();
-                    for (n, v) in &mo.fields {
-                        let f = match m.field_by_name(n.as_str()) {
-                            Some(f) => f,
-                            None => return Err(ConvertError::UnknownFieldName(n.clone())),
-                        };
-                        let u = self.option_value_to_unknown_value(
-                            v,
-                            n,
-                            &f.typ,
-                            option_name_for_diag,
-                            ma,
-                        )?;
-                        unknown_fields.add_value(f.number as u32, u);
-                    }
-                    for (_n, _v) in &mo.extensions {
-                        // TODO
+            (model::ProtobufConstant::Ident(ident), TypeResolved::Enum(e)) => {
+                let e = self.find_enum_by_abs_name(e)?;
+                let n = match e
+                    .values
+                    .iter()
+                    .find(|v| v.t.name == *ident)
+                    .map(|v| v.t.number)
+                {
+                    Some(n) => n,
+                    None => return Err(ConvertError::UnknownEnumValue(ident.clone())),
+                };
+                return Ok(UnknownValue::int32(n));
+            }
+            (model::ProtobufConstant::Ident(ident), TypeResolved::Float)
+            | (model::ProtobufConstant::Ident(ident), TypeResolved::Double) => {
+                let f = match ident.to_lowercase().as_str() {
+                    "inf" | "infinity" => f64::INFINITY,
+                    "nan" => f64::NAN,
+                    _ => return Err(ConvertError::UnknownEnumValue(ident.clone())),
+                };
+                return Ok(match field_type {
+                    TypeResolved::Float => UnknownValue::float(f as f32),
+                    _ => UnknownValue::double(f),
+                });
+            }
+            (&model::ProtobufConstant::Bool(b), TypeResolved::Bool) => {
+                return Ok(UnknownValue::Varint(b as u64));
+            }
+            (&model::ProtobufConstant::F64(f), TypeResolved::Float) => {
+                return Ok(UnknownValue::float(f as f32));
+            }
+            (&model::ProtobufConstant::F64(f), TypeResolved::Double) => {
+                return Ok(UnknownValue::double(f));
+            }
+            (model::ProtobufConstant::String(s), TypeResolved::String) => {
+                return Ok(UnknownValue::LengthDelimited(s.decode_utf8()?.into_bytes()));
+            }
+            (model::ProtobufConstant::String(s), TypeResolved::Bytes) => {
+                return Ok(UnknownValue::LengthDelimited(s.decode_bytes()?));
+            }
+            _ => {}
+        }
+
+        let v = match *value {
+            model::ProtobufConstant::U64(v) => v as i128,
+            model::ProtobufConstant::I64(v) => v as i128,
+            _ => {
+                return Err(match field_type {
+                    TypeResolved::Group(..) => {
+                        ConvertError::ConstantsOfTypeMessageEnumGroupNotImplemented
                     }
-                    return Ok(UnknownValue::LengthDelimited(
-                        unknown_fields.write_to_bytes(),
-                    ));
-                }
-                _ => {}
-            },
+                    _ => ConvertError::UnsupportedExtensionType(
+                        option_name_for_diag.to_owned(),
+                        format!("{:?}", field_type),
+                        value.clone(),
+                    ),
+                })
+            }
         };
 
-        Err(match field_type {
-            TypeResolved::Message(..) | TypeResolved::Enum(..) | TypeResolved::Group(..) => {
-                ConvertError::ConstantsOfTypeMessageEnumGroupNotImplemented
+        let (min, max) = match field_type {
+            TypeResolved::Int32 | TypeResolved::Sint32 | TypeResolved::Sfixed32 => {
+                (i32::min_value() as i128, i32::max_value() as i128)
             }
-            _ => ConvertError::UnsupportedExtensionType(
-                option_name_for_diag.to_owned(),
-                format!("{:?}", field_type),
-                value.clone(),
-            ),
+            TypeResolved::Int64 | TypeResolved::Sint64 | TypeResolved::Sfixed64 => {
+                (i64::min_value() as i128, i64::max_value() as i128)
+            }
+            TypeResolved::Uint32 | TypeResolved::Fixed32 => (0, u32::max_value() as i128),
+            TypeResolved::Uint64 | TypeResolved::Fixed64 => (0, u64::max_value() as i128),
+            TypeResolved::Enum(..) => (i32::min_value() as i128, i32::max_value() as i128),
+            TypeResolved::Bool => (0, 1),
+            TypeResolved::Float | TypeResolved::Double => (i128::min_value(), i128::max_value()),
+            _ => {
+                return Err(ConvertError::UnsupportedExtensionType(
+                    option_name_for_diag.to_owned(),
+                    format!("{:?}", field_type),
+                    value.clone(),
+                ))
+            }
+        };
+        if v < min || v > max {
+            return Err(out_of_range());
+        }
+
+        Ok(match field_type {
+            TypeResolved::Int32 | TypeResolved::Enum(..) => UnknownValue::int32(v as i32),
+            TypeResolved::Int64 => UnknownValue::int64(v as i64),
+            TypeResolved::Uint32 | TypeResolved::Uint64 | TypeResolved::Bool => {
+                UnknownValue::Varint(v as u64)
+            }
+            TypeResolved::Sint32 => UnknownValue::sint32(v as i32),
+            TypeResolved::Sint64 => UnknownValue::sint64(v as i64),
+            TypeResolved::Fixed32 => UnknownValue::Fixed32(v as u32),
+            TypeResolved::Fixed64 => UnknownValue::Fixed64(v as u64),
+            TypeResolved::Sfixed32 => UnknownValue::sfixed32(v as i32),
+            TypeResolved::Sfixed64 => UnknownValue::sfixed64(v as i64),
+            TypeResolved::Float => UnknownValue::float(v as f32),
+            TypeResolved::Double => UnknownValue::double(v as f64),
+            _ => unreachable!(),
         })
     }
 
@@ -991,6 +1413,33 @@ impl<'a> Resolver<'a> {
         self.custom_options(input, path_in_file)
     }
 
+    /// Resolve extendee and check it declares extension number.
+    fn extendee(
+        &self,
+        extendee: &str,
+        field: &model::WithLoc<model::Field>,
+        path_in_file: &ProtobufRelativePath,
+    ) -> ConvertResult<ProtobufAbsolutePath> {
+        let (path, t) = self.resolve_message_or_enum_leg(extendee, path_in_file)?;
+        let message = match t {
+            MessageOrEnum::Message(m) => m,
+            MessageOrEnum::Enum(..) => return Err(ConvertError::ExpectingMessage(path)),
+        };
+        let number = field.t.number;
+        if !message
+            .extension_ranges
+            .iter()
+            .any(|r| r.from <= number && number <= r.to)
+        {
+            let error = ValidationError::ExtensionNumberNotDeclared {
+                extendee: path.path.trim_start_matches('.').to_owned(),
+                number,
+            };
+            return Err(ConvertError::Validation(error).with_loc(field.loc));
+        }
+        Ok(path)
+    }
+
     fn extension(
         &self,
         input: &model::Extension,
@@ -1001,12 +1450,12 @@ impl<'a> Resolver<'a> {
         let relative_path = ProtobufRelativePath::new("".to_owned());
         let mut field = self.field(&input.field, None, &relative_path)?;
         field.set_extendee(
-            self.resolve_message_or_enum_leg(&input.extendee, &relative_path)?
-                .0
+            self.extendee(&input.extendee, &input.field, &relative_path)
+                .map_err(|e| e.with_loc(input.field.loc))?
                 .path,
         );
         let group_messages = if let model::FieldType::Group(g) = &input.field.t.typ {
-            Some(self.group_message(&g.name, &g.fields, &ProtobufRelativePath::empty())?)
+            Some(self.group_message(g, &ProtobufRelativePath::empty())?)
         } else {
             None
         };
@@ -1014,11 +1463,17 @@ impl<'a> Resolver<'a> {
     }
 }
 
-fn syntax(input: model::Syntax) -> String {
-    match input {
-        model::Syntax::Proto2 => "proto2".to_owned(),
-        model::Syntax::Proto3 => "proto3".to_owned(),
-    }
+/// Message reserved ranges, end is exclusive in descriptor.
+fn reserved_ranges(input: &[model::FieldNumberRange]) -> Vec<ReservedRange> {
+    input
+        .iter()
+        .map(|reserved| {
+            let mut reserved_range = ReservedRange::new();
+            reserved_range.set_start(reserved.from);
+            reserved_range.set_end(reserved.to + 1);
+            reserved_range
+        })
+        .collect()
 }
 
 fn label(input: model::Rule) -> protobuf::descriptor::field_descriptor_proto::Label {
@@ -1040,6 +1495,8 @@ pub fn file_descriptor(
     input: &model::FileDescriptor,
     deps: &[model::FileDescriptor],
 ) -> ConvertResult<protobuf::descriptor::FileDescriptorProto> {
+    validate::file(input)?;
+
     let resolver = Resolver {
         current_file: &input,
         deps,
@@ -1047,13 +1504,14 @@ pub fn file_descriptor(
 
     let mut output = protobuf::descriptor::FileDescriptorProto::new();
     output.set_name(fs_path_to_proto_path(name));
-    output.set_syntax(syntax(input.syntax));
+    input.syntax.edition().set_on_file(&mut output);
 
     if let Some(package) = &input.package {
         output.set_package(package.clone());
     }
 
     for import in &input.imports {
+        let import = &import.t;
         if import.vis == model::ImportVis::Public {
             output
                 .public_dependency
@@ -1068,14 +1526,18 @@ pub fn file_descriptor(
 
     let mut extensions = Vec::new();
     for e in &input.extensions {
-        let (ext, group_messages) = resolver.extension(&e.t)?;
+        let (ext, group_messages) = resolver
+            .extension(&e.t)
+            .map_err(|err| err.with_loc(e.loc))?;
         extensions.push(ext);
         messages.extend(group_messages.map(model::WithLoc::with_loc(e.loc)));
     }
     output.extension = extensions;
 
     for m in &input.messages {
-        let message = resolver.message(&m.t, &ProtobufRelativePath::empty())?;
+        let message = resolver
+            .message(&m.t, &ProtobufRelativePath::empty())
+            .map_err(|e| e.with_loc(m.loc))?;
         messages.push(model::WithLoc {
             t: message,
             loc: m.loc,
@@ -1092,7 +1554,11 @@ pub fn file_descriptor(
     output.enum_type = input
         .enums
         .iter()
-        .map(|e| resolver.enumeration(e, &ProtobufRelativePath::empty()))
+        .map(|e| {
+            resolver
+                .enumeration(&e.t, &ProtobufRelativePath::empty())
+                .map_err(|err| err.with_loc(e.loc))
+        })
         .collect::<Result<_, _>>()?;
 
     output.options =
@@ -1100,3 +1566,144 @@ pub fn file_descriptor(
 
     Ok(output)
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use protobuf::descriptor::MessageOptions;
+    use protobuf::Message;
+
+    use crate::linked_hash_map::LinkedHashMap;
+    use crate::Run;
+
+    fn message_options(content: &str) -> crate::Result<MessageOptions> {
+        let mut run = Run {
+            parsed_files: LinkedHashMap::new(),
+            includes: &[],
+            sources: &[],
+            include_source_info: false,
+        };
+        run.add_file_content(Path::new("t.proto"), Path::new("t.proto"), content)?;
+        let descriptor = &run
+            .parsed_files
+            .get(Path::new("t.proto"))
+            .unwrap()
+            .descriptor;
+        Ok(descriptor
+            .message_type
+            .last()
+            .unwrap()
+            .options
+            .get_or_default()
+            .clone())
+    }
+
+    fn message_options_50000(content: &str) -> crate::Result<Vec<Vec<u8>>> {
+        Ok(message_options(content)?
+            .get_unknown_fields()
+            .get(50000)
+            .map(|v| v.length_delimited.clone())
+            .unwrap_or_default())
+    }
+
+    const RULES_PROTO: &str = r#"
+        syntax = "proto2";
+        package t;
+        import "google/protobuf/descriptor.proto";
+        message Rules {
+            optional string s = 1;
+            repeated int32 r = 2;
+            repeated int32 p = 3 [packed = true];
+            optional Kind k = 4;
+            map<string, int32> m = 5;
+            optional Rules nested = 6;
+            optional int32 i = 7;
+            optional group G = 8 { optional int32 g = 1; }
+            extensions 100 to 200;
+        }
+        enum Kind { A = 0; B = 1; }
+        extend Rules { optional uint32 ext = 100; }
+        extend google.protobuf.MessageOptions { optional Rules rules = 50000; }
+    "#;
+
+    #[test]
+    fn aggregate_option() {
+        let content = format!(
+            "{}{}",
+            RULES_PROTO,
+            r#"message M {
+                option (rules) = {
+                    s: "x" r: [1, 2] r: 3 p: [4, 5] k: B
+                    m { key: "a" value: 1 } nested < s: "y" >, G { g: 2 } [t.ext]: 7
+                };
+                option (t.rules).nested.s = "z";
+                option (t.rules).g.g = 3;
+            }"#
+        );
+        assert_eq!(
+            vec![
+                vec![
+                    0x0a, 1, b'x', // s
+                    0x10, 1, 0x10, 2, 0x10, 3, // r
+                    0x1a, 2, 4, 5, // p
+                    0x20, 1, // k
+                    0x2a, 5, 0x0a, 1, b'a', 0x10, 1, // m
+                    0x32, 3, 0x0a, 1, b'y', // nested
+                    0x43, 0x08, 2, 0x44, // G
+                    0xa0, 0x06, 7, // ext
+                ],
+                vec![0x32, 3, 0x0a, 1, b'z'],
+                vec![0x43, 0x08, 3, 0x44],
+            ],
+            message_options_50000(&content).unwrap()
+        );
+    }
+
+    #[test]
+    fn aggregate_option_errors() {
+        let error = |option: &str| {
+            let content = format!("{}message M {{ option {}; }}", RULES_PROTO, option);
+            format!("{}", message_options_50000(&content).unwrap_err())
+        };
+        assert_eq!(
+            "t.proto:19:5: value 3000000000 of option \"(rules)\" is out of range of type Int32",
+            error("(rules) = { i: 3000000000 }")
+        );
+        assert_eq!(
+            "t.proto:19:5: unknown enum value \"C\"",
+            error("(rules) = { k: C }")
+        );
+        assert_eq!(
+            "t.proto:19:5: unknown field name \"x\"",
+            error("(rules).x = 1")
+        );
+        assert_eq!(
+            "t.proto:19:5: extension \"unknown\" not found",
+            error("(unknown) = 1")
+        );
+    }
+
+    #[test]
+    fn group_option() {
+        let content = r#"
+            syntax = "proto2";
+            import "google/protobuf/descriptor.proto";
+            extend google.protobuf.MessageOptions {
+                optional group Rules = 50000 { optional int32 a = 1; }
+            }
+            message M { option (rules) = { a: 1 }; }
+        "#;
+        assert_eq!(
+            Vec::<Vec<u8>>::new(),
+            message_options_50000(content).unwrap()
+        );
+        let options = message_options(content).unwrap();
+        let group = &options.get_unknown_fields().get(50000).unwrap().groups[0];
+        assert_eq!(vec![0x08, 1], group.write_to_bytes());
+        let bytes = options.write_to_bytes().unwrap();
+        assert_eq!(vec![0x83, 0xb5, 0x18, 0x08, 1, 0x84, 0xb5, 0x18], bytes);
+        // Group survives parsing
+        assert_eq!(options, MessageOptions::parse_from_bytes(&bytes).unwrap());
+    }
+}
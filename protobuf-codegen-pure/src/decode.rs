@@ -0,0 +1,414 @@
+//! Implementation of `--decode`, `--encode` and `--decode_raw` flags of `pure-protoc`.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::str;
+use std::str::FromStr;
+
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::FileDescriptorSet;
+use protobuf::reflect::FileDescriptor;
+use protobuf::reflect::MessageDescriptor;
+use protobuf::text_format;
+use protobuf::wire_format::WireType;
+use protobuf::CodedInputStream;
+use protobuf::Message;
+use protobuf::MessageDyn;
+use protobuf_codegen::amend_io_error;
+
+fn err_other(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, message.into())
+}
+
+/// Serialization format of messages.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Format {
+    /// Protobuf binary format
+    Binary,
+    /// Sequence of binary messages each prefixed with varint length
+    Delimited,
+    /// Proto3 JSON mapping
+    Json,
+    /// Text format
+    Text,
+}
+
+impl FromStr for Format {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Format> {
+        match s {
+            "binary" => Ok(Format::Binary),
+            "delimited" => Ok(Format::Delimited),
+            "json" => Ok(Format::Json),
+            "text" => Ok(Format::Text),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unknown format: {}, expecting one of: binary, delimited, json, text",
+                    s
+                ),
+            )),
+        }
+    }
+}
+
+/// Files which are resolved to generated descriptors, so well-known types
+/// get their special JSON representation.
+fn generated_file_descriptor(name: &str) -> Option<FileDescriptor> {
+    use protobuf::well_known_types::file_descriptors;
+
+    Some(match name {
+        "google/protobuf/any.proto" => file_descriptors::any(),
+        "google/protobuf/api.proto" => file_descriptors::api(),
+        "google/protobuf/descriptor.proto" => protobuf::descriptor::file_descriptor(),
+        "google/protobuf/duration.proto" => file_descriptors::duration(),
+        "google/protobuf/empty.proto" => file_descriptors::empty(),
+        "google/protobuf/field_mask.proto" => file_descriptors::field_mask(),
+        "google/protobuf/source_context.proto" => file_descriptors::source_context(),
+        "google/protobuf/struct.proto" => file_descriptors::struct_pb(),
+        "google/protobuf/timestamp.proto" => file_descriptors::timestamp(),
+        "google/protobuf/type.proto" => file_descriptors::type_pb(),
+        "google/protobuf/wrappers.proto" => file_descriptors::wrappers(),
+        _ => return None,
+    })
+}
+
+/// Build file descriptors, files may be listed in any order.
+fn build_file_descriptors(protos: Vec<FileDescriptorProto>) -> io::Result<Vec<FileDescriptor>> {
+    let mut built: HashMap<String, FileDescriptor> = HashMap::new();
+    let mut unprocessed = protos;
+    while !unprocessed.is_empty() {
+        let ready = unprocessed
+            .iter()
+            .position(|p| p.dependency.iter().all(|d| built.contains_key(d)));
+        let proto = match ready {
+            Some(index) => unprocessed.remove(index),
+            None => {
+                return Err(err_other(format!(
+                    "unsatisfied dependencies of {}",
+                    unprocessed[0].get_name()
+                )))
+            }
+        };
+        let file = match generated_file_descriptor(proto.get_name()) {
+            Some(file) => file,
+            None => {
+                let deps = proto.dependency.iter().map(|d| built[d].clone()).collect();
+                FileDescriptor::new_dynamic(proto, deps)
+            }
+        };
+        built.insert(file.proto().get_name().to_owned(), file);
+    }
+    Ok(built.into_iter().map(|(_, f)| f).collect())
+}
+
+/// Read `FileDescriptorSet` files.
+pub(crate) fn read_descriptor_sets(paths: &[PathBuf]) -> io::Result<Vec<FileDescriptorProto>> {
+    let mut r = Vec::new();
+    for path in paths {
+        let bytes = fs::read(path)
+            .map_err(|e| amend_io_error(e, format!("failed to read {}", path.display())))?;
+        let set = FileDescriptorSet::parse_from_bytes(&bytes)?;
+        r.extend(set.file);
+    }
+    Ok(r)
+}
+
+/// Find message by fully-qualified name in given files.
+pub(crate) fn find_message(
+    protos: Vec<FileDescriptorProto>,
+    name: &str,
+) -> io::Result<MessageDescriptor> {
+    let full_name = if name.starts_with('.') {
+        name.to_owned()
+    } else {
+        format!(".{}", name)
+    };
+    build_file_descriptors(protos)?
+        .iter()
+        .filter_map(|f| f.message_by_full_name(&full_name))
+        .next()
+        .ok_or_else(|| err_other(format!("type not defined: {}", name)))
+}
+
+fn read_delimited(input: &[u8]) -> io::Result<Vec<&[u8]>> {
+    let mut r = Vec::new();
+    let mut rem = input;
+    while !rem.is_empty() {
+        let mut is = CodedInputStream::from_bytes(rem);
+        let len = is.read_raw_varint32()? as usize;
+        let start = is.pos() as usize;
+        if rem.len() - start < len {
+            return Err(err_other("truncated length-delimited message"));
+        }
+        r.push(&rem[start..start + len]);
+        rem = &rem[start + len..];
+    }
+    Ok(r)
+}
+
+/// Parse messages from input.
+pub(crate) fn parse_messages(
+    descriptor: &MessageDescriptor,
+    format: Format,
+    input: &[u8],
+) -> io::Result<Vec<Box<dyn MessageDyn>>> {
+    let parse_binary = |bytes: &[u8]| -> io::Result<Box<dyn MessageDyn>> {
+        let mut message = descriptor.new_instance();
+        message.merge_from_bytes_dyn(bytes)?;
+        Ok(message)
+    };
+
+    match format {
+        Format::Binary => Ok(vec![parse_binary(input)?]),
+        Format::Delimited => read_delimited(input)?
+            .into_iter()
+            .map(parse_binary)
+            .collect(),
+        Format::Json | Format::Text => {
+            let input = str::from_utf8(input).map_err(|e| err_other(format!("{}", e)))?;
+            let mut message = descriptor.new_instance();
+            if format == Format::Json {
+                protobuf::json::merge_from_str(&mut *message, input)
+                    .map_err(|e| err_other(format!("failed to parse JSON: {}", e)))?;
+            } else {
+                text_format::merge_from_str(&mut *message, input)
+                    .map_err(|e| err_other(format!("failed to parse text format: {}", e)))?;
+            }
+            Ok(vec![message])
+        }
+    }
+}
+
+/// Serialize messages, multiple JSON or text messages are separated by newline.
+pub(crate) fn print_messages(
+    messages: &[Box<dyn MessageDyn>],
+    format: Format,
+) -> io::Result<Vec<u8>> {
+    let mut r = Vec::new();
+    for (i, message) in messages.iter().enumerate() {
+        match format {
+            Format::Binary => {
+                if messages.len() != 1 {
+                    return Err(err_other(
+                        "multiple messages cannot be written in binary format, use delimited",
+                    ));
+                }
+                message.write_to_vec_dyn(&mut r)?;
+            }
+            Format::Delimited => message.write_length_delimited_to_vec_dyn(&mut r)?,
+            Format::Json => {
+                let json = protobuf::json::print_to_string(&**message)
+                    .map_err(|e| err_other(format!("failed to print JSON: {}", e)))?;
+                r.extend_from_slice(json.as_bytes());
+                r.push(b'\n');
+            }
+            Format::Text => {
+                if i != 0 {
+                    r.push(b'\n');
+                }
+                r.extend_from_slice(text_format::print_to_string_pretty(&**message).as_bytes());
+            }
+        }
+    }
+    Ok(r)
+}
+
+/// Print fields of binary message without schema like `protoc --decode_raw`.
+pub(crate) fn decode_raw(input: &[u8], format: Format) -> io::Result<Vec<u8>> {
+    let messages = match format {
+        Format::Binary => vec![input],
+        Format::Delimited => read_delimited(input)?,
+        _ => return Err(err_other("--decode_raw input must be binary or delimited")),
+    };
+
+    let mut r = String::new();
+    for (i, message) in messages.into_iter().enumerate() {
+        if i != 0 {
+            r.push('\n');
+        }
+        let mut is = CodedInputStream::from_bytes(message);
+        if !print_raw(&mut is, None, 0, &mut r) {
+            return Err(err_other("failed to parse input"));
+        }
+    }
+    Ok(r.into_bytes())
+}
+
+/// Nesting limit of messages and groups, same as default recursion limit
+/// of `CodedInputStream`.
+const RECURSION_LIMIT: usize = 100;
+
+/// Print raw fields until the end of input or end of group.
+///
+/// Return `false` if input is not a valid message
+/// or it is nested deeper than [`RECURSION_LIMIT`].
+fn print_raw(
+    is: &mut CodedInputStream,
+    group: Option<u32>,
+    indent: usize,
+    buf: &mut String,
+) -> bool {
+    if indent > RECURSION_LIMIT {
+        return false;
+    }
+    loop {
+        match is.eof() {
+            Ok(true) => return group.is_none(),
+            Ok(false) => {}
+            Err(_) => return false,
+        }
+        let (number, wire_type) = match is.read_tag_unpack() {
+            Ok(tag) => tag,
+            Err(_) => return false,
+        };
+        if wire_type == WireType::WireTypeEndGroup {
+            return group == Some(number);
+        }
+
+        for _ in 0..indent {
+            buf.push_str("  ");
+        }
+        let ok = match wire_type {
+            WireType::WireTypeVarint => is
+                .read_raw_varint64()
+                .map(|v| writeln!(buf, "{}: {}", number, v).unwrap())
+                .is_ok(),
+            WireType::WireTypeFixed64 => is
+                .read_fixed64()
+                .map(|v| writeln!(buf, "{}: 0x{:016x}", number, v).unwrap())
+                .is_ok(),
+            WireType::WireTypeFixed32 => is
+                .read_fixed32()
+                .map(|v| writeln!(buf, "{}: 0x{:08x}", number, v).unwrap())
+                .is_ok(),
+            WireType::WireTypeLengthDelimited => match is.read_bytes() {
+                Ok(bytes) => {
+                    // Print as nested message if bytes can be parsed as message
+                    let mut nested = String::new();
+                    let mut nested_is = CodedInputStream::from_bytes(&bytes);
+                    if !bytes.is_empty() && print_raw(&mut nested_is, None, indent + 1, &mut nested)
+                    {
+                        writeln!(buf, "{} {{", number).unwrap();
+                        buf.push_str(&nested);
+                        for _ in 0..indent {
+                            buf.push_str("  ");
+                        }
+                        buf.push_str("}\n");
+                    } else {
+                        writeln!(
+                            buf,
+                            "{}: {}",
+                            number,
+                            text_format::quote_escape_bytes(&bytes)
+                        )
+                        .unwrap();
+                    }
+                    true
+                }
+                Err(_) => false,
+            },
+            WireType::WireTypeStartGroup => {
+                writeln!(buf, "{} {{", number).unwrap();
+                if !print_raw(is, Some(number), indent + 1, buf) {
+                    return false;
+                }
+                for _ in 0..indent {
+                    buf.push_str("  ");
+                }
+                buf.push_str("}\n");
+                true
+            }
+            WireType::WireTypeEndGroup => unreachable!(),
+        };
+        if !ok {
+            return false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use protobuf::well_known_types::Duration;
+    use protobuf::CodedOutputStream;
+
+    #[test]
+    fn decode_raw_nested() {
+        // 1: 150, 2 { 1: "ab" }, 3: "x"
+        let input = b"\x08\x96\x01\x12\x04\x0a\x02ab\x1a\x01x";
+        let output = decode_raw(input, Format::Binary).unwrap();
+        assert_eq!(
+            "1: 150\n2 {\n  1: \"ab\"\n}\n3: \"x\"\n",
+            str::from_utf8(&output).unwrap()
+        );
+    }
+
+    #[test]
+    fn decode_raw_invalid() {
+        assert!(decode_raw(b"\x08", Format::Binary).is_err());
+    }
+
+    #[test]
+    fn decode_raw_recursion_limit() {
+        let mut groups = vec![0x0b; 1000];
+        groups.extend(vec![0x0c; 1000]);
+        assert!(decode_raw(&groups, Format::Binary).is_err());
+
+        // Too deep nested messages are printed as bytes
+        let mut message = b"\x08\x01".to_vec();
+        for _ in 0..1000 {
+            let mut m = Vec::new();
+            let mut os = CodedOutputStream::vec(&mut m);
+            os.write_bytes(1, &message).unwrap();
+            os.flush().unwrap();
+            drop(os);
+            message = m;
+        }
+        let output = decode_raw(&message, Format::Binary).unwrap();
+        assert!(str::from_utf8(&output).unwrap().contains(": \""));
+    }
+
+    #[test]
+    fn convert() {
+        let descriptor = find_message(
+            vec![protobuf::well_known_types::file_descriptors::duration()
+                .proto()
+                .clone()],
+            "google.protobuf.Duration",
+        )
+        .unwrap();
+
+        let mut duration = Duration::new();
+        duration.seconds = 3;
+        duration.nanos = 500_000_000;
+        let mut input = Vec::new();
+        for _ in 0..2 {
+            duration.write_length_delimited_to_vec(&mut input).unwrap();
+        }
+
+        let messages = parse_messages(&descriptor, Format::Delimited, &input).unwrap();
+        assert_eq!(2, messages.len());
+        let json = print_messages(&messages, Format::Json).unwrap();
+        assert_eq!(
+            "\"3.500000000s\"\n\"3.500000000s\"\n",
+            str::from_utf8(&json).unwrap()
+        );
+
+        let messages = parse_messages(&descriptor, Format::Json, b"\"3.500000000s\"").unwrap();
+        let text = print_messages(&messages, Format::Text).unwrap();
+        assert_eq!(
+            "seconds: 3\nnanos: 500000000\n",
+            str::from_utf8(&text).unwrap()
+        );
+
+        let messages = parse_messages(&descriptor, Format::Text, &text).unwrap();
+        let binary = print_messages(&messages, Format::Binary).unwrap();
+        assert_eq!(duration.write_to_bytes().unwrap(), binary);
+    }
+}
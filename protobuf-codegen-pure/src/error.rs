@@ -0,0 +1,333 @@
+//! Errors returned by pure rust codegen.
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::text_format::lexer::Loc;
+
+use crate::convert::ConvertError;
+use crate::parser::ParserError;
+use crate::parser::ParserErrorWithLocation;
+use crate::validate::ValidationError;
+
+/// Syntax error in `.proto` file.
+#[derive(Debug)]
+pub struct ParseError(pub(crate) ParserError);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl StdError for ParseError {}
+
+/// Semantic error in `.proto` file, like invalid option or default value.
+#[derive(Debug)]
+pub struct TypeError(pub(crate) ConvertError);
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl StdError for TypeError {}
+
+/// Cause of [`Error`].
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// I/O error, e. g. file cannot be read or written.
+    Io(io::Error),
+    /// `.proto` file cannot be parsed.
+    Parse(ParseError),
+    /// Imported file is not found in include path.
+    ImportNotFound(PathBuf),
+    /// Input file does not reside in any include directory.
+    NotInIncludePath(PathBuf),
+    /// Type referenced from field or extension is not defined.
+    UnknownType(String),
+    /// Violation of rules `protoc` enforces, like duplicate field numbers.
+    Validation(ValidationError),
+    /// Other semantic error.
+    Type(TypeError),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Io(e) => write!(f, "{}", e),
+            ErrorKind::Parse(e) => write!(f, "{}", e),
+            ErrorKind::ImportNotFound(p) => {
+                write!(f, "import \"{}\" was not found", p.display())
+            }
+            ErrorKind::NotInIncludePath(p) => write!(
+                f,
+                "file \"{}\" does not reside in any include path",
+                p.display()
+            ),
+            ErrorKind::UnknownType(t) => write!(f, "unknown type \"{}\"", t),
+            ErrorKind::Validation(e) => write!(f, "{}", e),
+            ErrorKind::Type(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// Error returned by [`Codegen::run`](crate::Codegen::run).
+///
+/// Errors in `.proto` files carry file path and, when known, location of the
+/// declaration; `Display` formats them like compiler diagnostics:
+/// `foo.proto:12:5: unknown type "Bar"`.
+///
+/// When a file has several syntax errors, this is the first one,
+/// and the rest are available with [`Error::related`].
+#[derive(Debug)]
+pub struct Error {
+    file: Option<PathBuf>,
+    loc: Option<Loc>,
+    kind: ErrorKind,
+    related: Vec<Error>,
+    partial_descriptor: Option<Box<FileDescriptorProto>>,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind) -> Error {
+        Error {
+            file: None,
+            loc: None,
+            kind,
+            related: Vec::new(),
+            partial_descriptor: None,
+        }
+    }
+
+    pub(crate) fn in_file(file: &Path, loc: Option<Loc>, kind: ErrorKind) -> Error {
+        Error {
+            file: Some(file.to_owned()),
+            loc,
+            kind,
+            related: Vec::new(),
+            partial_descriptor: None,
+        }
+    }
+
+    pub(crate) fn parse(file: &Path, loc: Loc, error: ParserError) -> Error {
+        Error::in_file(file, Some(loc), ErrorKind::Parse(ParseError(error)))
+    }
+
+    /// Error for syntax errors in file, there must be at least one.
+    ///
+    /// `partial_descriptor` is the descriptor of statements parsed successfully.
+    pub(crate) fn parse_all(
+        file: &Path,
+        errors: Vec<ParserErrorWithLocation>,
+        partial_descriptor: Option<FileDescriptorProto>,
+    ) -> Error {
+        let mut errors = errors.into_iter().map(|e| {
+            let loc = Loc {
+                line: e.line,
+                col: e.col,
+            };
+            Error::parse(file, loc, e.error)
+        });
+        let mut first = errors.next().expect("at least one error");
+        first.related = errors.collect();
+        first.partial_descriptor = partial_descriptor.map(Box::new);
+        first
+    }
+
+    pub(crate) fn convert(file: &Path, error: ConvertError) -> Error {
+        let (loc, error) = match error {
+            ConvertError::WithLoc(loc, error) => (Some(loc), *error),
+            error => (None, error),
+        };
+        let kind = match error {
+            ConvertError::NotFoundByAbsPath(path) => ErrorKind::UnknownType(path.path),
+            ConvertError::NotFoundByRelPath(path, _) => ErrorKind::UnknownType(path.path),
+            ConvertError::Validation(e) => ErrorKind::Validation(e),
+            error => ErrorKind::Type(TypeError(error)),
+        };
+        Error::in_file(file, loc, kind)
+    }
+
+    /// Path of `.proto` file where error occurred.
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_ref().map(|p| p.as_path())
+    }
+
+    /// 1-based line number of the error.
+    pub fn line(&self) -> Option<u32> {
+        self.loc.map(|l| l.line)
+    }
+
+    /// 1-based column number of the error.
+    pub fn col(&self) -> Option<u32> {
+        self.loc.map(|l| l.col)
+    }
+
+    /// Cause of the error.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// Errors found in the same file after this one.
+    pub fn related(&self) -> &[Error] {
+        &self.related
+    }
+
+    /// Descriptor of the file with syntax errors built from statements
+    /// which were parsed, statements with errors are skipped.
+    ///
+    /// `None` if the error is not a syntax error, or if the rest of the file
+    /// is not valid either, e. g. it references a type with syntax error.
+    pub fn partial_descriptor(&self) -> Option<&FileDescriptorProto> {
+        self.partial_descriptor.as_deref()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+            if let Some(loc) = &self.loc {
+                write!(f, "{}:", loc)?;
+            }
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.kind)?;
+        for related in &self.related {
+            write!(f, "\n{}", related)?;
+        }
+        Ok(())
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(e) => Some(e),
+            ErrorKind::Parse(e) => Some(e),
+            ErrorKind::Validation(e) => Some(e),
+            ErrorKind::Type(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::new(ErrorKind::Io(e))
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        match e {
+            Error {
+                file: None,
+                kind: ErrorKind::Io(e),
+                ..
+            } => e,
+            e => io::Error::new(io::ErrorKind::Other, e),
+        }
+    }
+}
+
+/// Result of pure rust codegen.
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::*;
+    use crate::linked_hash_map::LinkedHashMap;
+    use crate::Run;
+
+    fn error(content: &str) -> Error {
+        let mut run = Run {
+            parsed_files: LinkedHashMap::new(),
+            includes: &[],
+            sources: &[],
+            include_source_info: false,
+        };
+        run.add_file_content(Path::new("foo.proto"), Path::new("dir/foo.proto"), content)
+            .unwrap_err()
+    }
+
+    #[test]
+    fn parse_error() {
+        let e = error("syntax = \"proto3\";\nmessage Foo {\n  int32 a = ;\n}\n");
+        match e.kind() {
+            ErrorKind::Parse(..) => {}
+            k => panic!("{:?}", k),
+        }
+        assert_eq!(Some(Path::new("dir/foo.proto")), e.file());
+        assert_eq!(Some(3), e.line());
+    }
+
+    #[test]
+    fn unknown_type() {
+        let e = error("syntax = \"proto3\";\nmessage Foo {\n    Bar b = 1;\n}\n");
+        assert_eq!("dir/foo.proto:3:5: unknown type \"Bar\"", format!("{}", e));
+        match e.kind() {
+            ErrorKind::UnknownType(t) => assert_eq!("Bar", t),
+            k => panic!("{:?}", k),
+        }
+    }
+
+    #[test]
+    fn import_not_found() {
+        let e = error("syntax = \"proto3\";\n\nimport \"bar.proto\";\n");
+        assert_eq!(
+            "dir/foo.proto:3:1: import \"bar.proto\" was not found",
+            format!("{}", e)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = error(
+            "syntax = \"proto3\";\n\
+             message Foo {\n  int32 a = ;\n  int32 b = 2;\n  enum E { X = ; }\n  Bar c = 3;\n}\n\
+             message Baz {\n  int32 d = 1\n}\n",
+        );
+        assert_eq!(
+            "dir/foo.proto:3:13: Incorrect input\n\
+             dir/foo.proto:5:16: Incorrect input\n\
+             dir/foo.proto:10:1: Expecting char ;",
+            format!("{}", e)
+        );
+        assert_eq!(2, e.related().len());
+        assert!(e.partial_descriptor().is_none());
+    }
+
+    #[test]
+    fn partial_descriptor() {
+        let e = error(
+            "syntax = \"proto3\";\n\
+             message Foo {\n  int32 a = ;\n  int32 b = 2;\n}\n\
+             message Bar {\n  int32 c = 1;\n}\n",
+        );
+        let descriptor = e.partial_descriptor().unwrap();
+        assert_eq!("foo.proto", descriptor.get_name());
+        let messages: Vec<_> = descriptor
+            .message_type
+            .iter()
+            .map(|m| (m.get_name(), m.field.len()))
+            .collect();
+        assert_eq!(vec![("Foo", 1), ("Bar", 1)], messages);
+    }
+
+    #[test]
+    fn into_io_error() {
+        let e = error("syntax = \"proto3\";\nmessage Foo {\n    Bar b = 1;\n}\n");
+        let e = io::Error::from(e);
+        let e = e.get_ref().unwrap().downcast_ref::<Error>().unwrap();
+        assert_eq!(Some(3), e.line());
+    }
+}
@@ -0,0 +1,253 @@
+//! `features.xxx` options of editions files.
+
+use protobuf::reflect::features::EnumType;
+use protobuf::reflect::features::FeatureSet;
+use protobuf::reflect::features::FieldPresence;
+use protobuf::reflect::features::JsonFormat;
+use protobuf::reflect::features::MessageEncoding;
+use protobuf::reflect::features::RepeatedFieldEncoding;
+use protobuf::reflect::features::ResolvedFeatures;
+use protobuf::reflect::features::Utf8Validation;
+
+use crate::convert::ConvertError;
+use crate::convert::ConvertResult;
+use crate::model;
+
+/// Set a feature by names used in `.proto` file, e. g.
+/// `field_presence` and `IMPLICIT`.
+fn set_feature(
+    features: &mut FeatureSet,
+    name: &str,
+    value: &model::ProtobufConstant,
+) -> ConvertResult<()> {
+    let value = match value {
+        model::ProtobufConstant::Ident(value) => value.as_str(),
+        _ => return Err(ConvertError::WrongOptionType),
+    };
+    let unknown = || ConvertError::UnknownFeature(format!("{} = {}", name, value));
+    match name {
+        "field_presence" => {
+            features.field_presence = Some(match value {
+                "EXPLICIT" => FieldPresence::Explicit,
+                "IMPLICIT" => FieldPresence::Implicit,
+                "LEGACY_REQUIRED" => FieldPresence::LegacyRequired,
+                _ => return Err(unknown()),
+            })
+        }
+        "enum_type" => {
+            features.enum_type = Some(match value {
+                "OPEN" => EnumType::Open,
+                "CLOSED" => EnumType::Closed,
+                _ => return Err(unknown()),
+            })
+        }
+        "repeated_field_encoding" => {
+            features.repeated_field_encoding = Some(match value {
+                "PACKED" => RepeatedFieldEncoding::Packed,
+                "EXPANDED" => RepeatedFieldEncoding::Expanded,
+                _ => return Err(unknown()),
+            })
+        }
+        "utf8_validation" => {
+            features.utf8_validation = Some(match value {
+                "VERIFY" => Utf8Validation::Verify,
+                "NONE" => Utf8Validation::None,
+                _ => return Err(unknown()),
+            })
+        }
+        "message_encoding" => {
+            features.message_encoding = Some(match value {
+                "LENGTH_PREFIXED" => MessageEncoding::LengthPrefixed,
+                "DELIMITED" => MessageEncoding::Delimited,
+                _ => return Err(unknown()),
+            })
+        }
+        "json_format" => {
+            features.json_format = Some(match value {
+                "ALLOW" => JsonFormat::Allow,
+                "LEGACY_BEST_EFFORT" => JsonFormat::LegacyBestEffort,
+                _ => return Err(unknown()),
+            })
+        }
+        _ => return Err(unknown()),
+    }
+    Ok(())
+}
+
+/// Features set by `features.xxx` options.
+///
+/// Features are only allowed in editions files.
+/// Language-specific features like `features.(pb.cpp).xxx` are ignored.
+pub(crate) fn feature_set(
+    syntax: model::Syntax,
+    options: &[model::ProtobufOption],
+) -> ConvertResult<FeatureSet> {
+    let mut features = FeatureSet::default();
+    for option in options {
+        let name = match &option.name.components[..] {
+            [model::ProtobufOptionNameComponent::Direct(features), rem @ ..]
+                if features.get() == "features" =>
+            {
+                rem
+            }
+            _ => continue,
+        };
+        if let model::Syntax::Proto2 | model::Syntax::Proto3 = syntax {
+            return Err(ConvertError::FeaturesNotAllowed);
+        }
+        match name {
+            [model::ProtobufOptionNameComponent::Direct(name)] => {
+                set_feature(&mut features, name.get(), &option.value)?
+            }
+            [model::ProtobufOptionNameComponent::Ext(..), ..] => {}
+            _ => return Err(ConvertError::UnknownFeature(format!("{}", option.name))),
+        }
+    }
+    Ok(features)
+}
+
+/// Features of a file.
+pub(crate) fn file_features(file: &model::FileDescriptor) -> ConvertResult<ResolvedFeatures> {
+    let features = ResolvedFeatures::defaults(file.syntax.edition());
+    Ok(features.merge(&feature_set(file.syntax, &file.options)?))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use protobuf::reflect::features::Edition;
+    use protobuf::reflect::features::FieldPresence;
+    use protobuf::reflect::features::RepeatedFieldEncoding;
+    use protobuf::reflect::FileDescriptor;
+
+    use crate::parse_and_typecheck_with_sources;
+    use crate::MemorySourceProvider;
+
+    fn file(content: &str) -> crate::Result<FileDescriptor> {
+        let mut sources = MemorySourceProvider::new();
+        sources.add("t.proto", content);
+        let parsed =
+            parse_and_typecheck_with_sources(&[], &[&sources], &[PathBuf::from("t.proto")])?;
+        Ok(FileDescriptor::new_dynamic_fds(parsed.file_descriptors)
+            .pop()
+            .unwrap())
+    }
+
+    #[test]
+    fn resolve() {
+        let file = file(
+            r#"
+            edition = "2023";
+            option features.field_presence = IMPLICIT;
+            message M {
+                option features.repeated_field_encoding = EXPANDED;
+                int32 a = 1;
+                int32 b = 2 [features.field_presence = EXPLICIT];
+                repeated int32 c = 3;
+                repeated int32 d = 4 [features.repeated_field_encoding = PACKED];
+                M m = 5;
+                enum E { option features.enum_type = CLOSED; X = 1; }
+            }
+            enum F { Y = 0; }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(Edition::Edition2023, file.edition());
+        assert_eq!("editions", file.proto().get_syntax());
+        let m = file.message_by_package_relative_name("M").unwrap();
+        let field = |name| m.get_field_by_name(name).unwrap();
+        assert_eq!(
+            FieldPresence::Implicit,
+            field("a").features().field_presence
+        );
+        assert!(!field("a").has_presence());
+        assert!(field("b").has_presence());
+        assert!(field("m").has_presence());
+        assert_eq!(
+            RepeatedFieldEncoding::Expanded,
+            field("c").features().repeated_field_encoding
+        );
+        assert!(!field("c").is_packed());
+        assert!(field("d").is_packed());
+        assert!(!m.get_enums()[0].is_open());
+        assert!(file.enum_by_package_relative_name("F").unwrap().is_open());
+    }
+
+    #[test]
+    fn delimited() {
+        let mut sources = MemorySourceProvider::new();
+        sources.add(
+            "t.proto",
+            r#"
+            edition = "2023";
+            message M {
+                M child = 1 [features.message_encoding = DELIMITED];
+                repeated M children = 2 [features.message_encoding = DELIMITED];
+                map<int32, M> map = 3 [features.message_encoding = DELIMITED];
+            }
+            "#,
+        );
+        let parsed =
+            parse_and_typecheck_with_sources(&[], &[&sources], &[PathBuf::from("t.proto")])
+                .unwrap();
+        let file = FileDescriptor::new_dynamic_fds(parsed.file_descriptors.clone())
+            .pop()
+            .unwrap();
+        let m = file.message_by_package_relative_name("M").unwrap();
+        assert!(m.get_field_by_name("child").unwrap().is_delimited());
+        assert!(!m.get_field_by_name("map").unwrap().is_delimited());
+
+        let generated = protobuf_codegen::gen(
+            &parsed.file_descriptors,
+            "pure",
+            &[PathBuf::from("t.proto")],
+            &Default::default(),
+        );
+        let generated = String::from_utf8(generated[0].content.clone()).unwrap();
+        for expected in &[
+            "read_singular_group_into_field(1, wire_type, is, &mut self.child)",
+            "read_repeated_group_into_vec(2, wire_type, is, &mut self.children)",
+            "write_group_field_with_cached_size(1, ",
+            "write_group_field_with_cached_size(2, ",
+            "read_map_into::<",
+        ] {
+            assert!(generated.contains(expected), "{}", expected);
+        }
+    }
+
+    #[test]
+    fn legacy() {
+        let file =
+            file("syntax = \"proto3\"; message M { repeated int32 a = 1; int32 b = 2; }").unwrap();
+        let m = file.message_by_package_relative_name("M").unwrap();
+        assert!(m.get_field_by_name("a").unwrap().is_packed());
+        assert!(!m.get_field_by_name("b").unwrap().has_presence());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            "t.proto:1:32: features are only valid under editions",
+            format!(
+                "{}",
+                file(
+                    "syntax = \"proto3\"; message M { int32 a = 1 \
+                      [features.field_presence = EXPLICIT]; }"
+                )
+                .unwrap_err()
+            )
+        );
+        assert_eq!(
+            "t.proto:1:31: unknown feature \"field_presence = SOMETIMES\"",
+            format!(
+                "{}",
+                file(
+                    "edition = \"2023\"; message M { int32 a = 1 \
+                      [features.field_presence = SOMETIMES]; }"
+                )
+                .unwrap_err()
+            )
+        );
+    }
+}
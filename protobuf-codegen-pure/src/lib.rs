@@ -26,12 +26,14 @@
 extern crate protobuf;
 extern crate protobuf_codegen;
 
+pub mod breaking;
 mod convert;
+mod decode;
+mod error;
+mod features;
 
-use std::error::Error;
-use std::fmt;
 use std::fs;
-use std::io;
+use std::iter;
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -39,13 +41,33 @@ mod linked_hash_map;
 mod model;
 mod parser;
 mod path;
+mod protoc;
+mod source_info;
+mod source_provider;
+mod validate;
 
 use linked_hash_map::LinkedHashMap;
-use protobuf_codegen::amend_io_error;
 pub use protobuf_codegen::Customize;
+pub use protobuf_codegen::CustomizeConfig;
+use source_provider::SourceProviders;
+
+pub use error::Error;
+pub use error::ErrorKind;
+pub use error::ParseError;
+pub use error::Result;
+pub use error::TypeError;
+pub use protoc::protoc_main;
+pub use source_provider::BundledSourceProvider;
+pub use source_provider::FsSourceProvider;
+pub use source_provider::MemorySourceProvider;
+pub use source_provider::SourceFile;
+pub use source_provider::SourceProvider;
+pub use validate::ValidationError;
 
 #[cfg(test)]
 mod test_against_protobuf_protos;
+#[cfg(test)]
+mod test_proto_source;
 
 /// Invoke pure rust codegen. See [crate docs](crate) for example.
 // TODO: merge with protoc-rust def
@@ -59,6 +81,11 @@ pub struct Codegen {
     inputs: Vec<PathBuf>,
     /// Customize code generation
     customize: Customize,
+    /// Config file with per-file and per-element customization
+    #[cfg(feature = "with-customize-config")]
+    customize_config_file: Option<PathBuf>,
+    /// Sources searched after include directories
+    sources: SourceProviders,
 }
 
 impl Codegen {
@@ -87,6 +114,26 @@ impl Codegen {
         self
     }
 
+    /// Add a source of `.proto` files, searched after include directories
+    /// and before files bundled with this crate.
+    ///
+    /// Inputs not found in any include directory are looked up in sources
+    /// by path relative to source root:
+    ///
+    /// ```ignore
+    /// let mut sources = protobuf_codegen_pure::MemorySourceProvider::new();
+    /// sources.add("foo/bar.proto", generated_proto_text);
+    /// protobuf_codegen_pure::Codegen::new()
+    ///     .out_dir("src/protos")
+    ///     .source_provider(sources)
+    ///     .input("foo/bar.proto")
+    ///     .run()?;
+    /// ```
+    pub fn source_provider(&mut self, provider: impl SourceProvider + 'static) -> &mut Self {
+        self.sources.0.push(Box::new(provider));
+        self
+    }
+
     /// Add an input (`.proto` file).
     pub fn input(&mut self, input: impl AsRef<Path>) -> &mut Self {
         self.inputs.push(input.as_ref().to_owned());
@@ -107,18 +154,39 @@ impl Codegen {
         self
     }
 
+    /// Load [`CustomizeConfig`] from a `.toml` or `.json` file.
+    ///
+    /// Config assigns customize options to files, messages and fields by globs,
+    /// which is useful when `.proto` files cannot be annotated with `rustproto` options.
+    #[cfg(feature = "with-customize-config")]
+    pub fn customize_config_file(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        self.customize_config_file = Some(path.as_ref().to_owned());
+        self
+    }
+
     /// Like `protoc --rust_out=...` but without requiring `protoc` or `protoc-gen-rust`
     /// commands in `$PATH`.
-    pub fn run(&self) -> io::Result<()> {
-        let p = parse_and_typecheck(&self.includes, &self.inputs)?;
+    ///
+    /// Errors in `.proto` files are reported with file name and location,
+    /// see [`Error`].
+    pub fn run(&self) -> Result<()> {
+        #[cfg(feature = "with-customize-config")]
+        let customize = self
+            .customize
+            .with_config_file(self.customize_config_file.as_deref())?;
+        #[cfg(not(feature = "with-customize-config"))]
+        let customize = self.customize.clone();
+        let sources: Vec<&dyn SourceProvider> = self.sources.0.iter().map(|s| &**s).collect();
+        let p = parse_and_typecheck_impl(&self.includes, &sources, &self.inputs, false)?;
 
         protobuf_codegen::gen_and_write(
             &p.file_descriptors,
             &format!("protobuf-codegen-pure={}", env!("CARGO_PKG_VERSION")),
             &p.relative_paths,
             &self.out_dir,
-            &self.customize,
-        )
+            &customize,
+        )?;
+        Ok(())
     }
 }
 
@@ -128,45 +196,12 @@ struct FileDescriptorPair {
     descriptor: protobuf::descriptor::FileDescriptorProto,
 }
 
-#[derive(Debug)]
-enum CodegenError {
-    ParserErrorWithLocation(parser::ParserErrorWithLocation),
-    ConvertError(convert::ConvertError),
-}
-
-impl From<parser::ParserErrorWithLocation> for CodegenError {
-    fn from(e: parser::ParserErrorWithLocation) -> Self {
-        CodegenError::ParserErrorWithLocation(e)
-    }
-}
-
-impl From<convert::ConvertError> for CodegenError {
-    fn from(e: convert::ConvertError) -> Self {
-        CodegenError::ConvertError(e)
-    }
-}
-
-#[derive(Debug)]
-struct WithFileError {
-    file: String,
-    error: CodegenError,
-}
-
-impl fmt::Display for WithFileError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "WithFileError")
-    }
-}
-
-impl Error for WithFileError {
-    fn description(&self) -> &str {
-        "WithFileError"
-    }
-}
-
 struct Run<'a> {
     parsed_files: LinkedHashMap<PathBuf, FileDescriptorPair>,
     includes: &'a [PathBuf],
+    /// Searched after include directories
+    sources: &'a [&'a dyn SourceProvider],
+    include_source_info: bool,
 }
 
 impl<'a> Run<'a> {
@@ -194,17 +229,17 @@ impl<'a> Run<'a> {
         result: &mut LinkedHashMap<PathBuf, FileDescriptorPair>,
     ) {
         for import in &parsed.imports {
-            self.get_file_and_all_deps_already_parsed(Path::new(&import.path), result);
+            self.get_file_and_all_deps_already_parsed(Path::new(&import.t.path), result);
         }
     }
 
-    fn add_file(&mut self, protobuf_path: &Path, fs_path: &Path) -> io::Result<()> {
+    fn add_file(&mut self, protobuf_path: &Path, fs_path: &Path) -> Result<()> {
         if let Some(_) = self.parsed_files.get(protobuf_path) {
             return Ok(());
         }
 
         let content = fs::read_to_string(fs_path)
-            .map_err(|e| amend_io_error(e, format!("failed to read {:?}", fs_path)))?;
+            .map_err(|e| Error::in_file(fs_path, None, ErrorKind::Io(e)))?;
 
         self.add_file_content(protobuf_path, fs_path, &content)
     }
@@ -214,86 +249,96 @@ impl<'a> Run<'a> {
         protobuf_path: &Path,
         fs_path: &Path,
         content: &str,
-    ) -> io::Result<()> {
-        let parsed = model::FileDescriptor::parse(content).map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::Other,
-                WithFileError {
-                    file: format!("{}", fs_path.display()),
-                    error: e.into(),
-                },
-            )
-        })?;
+    ) -> Result<()> {
+        let (parsed, errors) = model::FileDescriptor::parse_recover(content);
+        if !errors.is_empty() {
+            let partial = self.file_descriptor(protobuf_path, fs_path, &parsed).ok();
+            return Err(Error::parse_all(fs_path, errors, partial));
+        }
 
+        let descriptor = self.file_descriptor(protobuf_path, fs_path, &parsed)?;
+
+        self.parsed_files.insert(
+            protobuf_path.to_owned(),
+            FileDescriptorPair { parsed, descriptor },
+        );
+
+        Ok(())
+    }
+
+    /// Resolve imports of parsed file and convert it to descriptor.
+    fn file_descriptor(
+        &mut self,
+        protobuf_path: &Path,
+        fs_path: &Path,
+        parsed: &model::FileDescriptor,
+    ) -> Result<protobuf::descriptor::FileDescriptorProto> {
         for import in &parsed.imports {
-            self.add_imported_file(Path::new(&import.path))?;
+            let protobuf_path = Path::new(&import.t.path);
+            if !self.add_imported_file(protobuf_path)? {
+                return Err(Error::in_file(
+                    fs_path,
+                    Some(import.loc),
+                    ErrorKind::ImportNotFound(protobuf_path.to_owned()),
+                ));
+            }
         }
 
         let mut this_file_deps = LinkedHashMap::new();
-        self.get_all_deps_already_parsed(&parsed, &mut this_file_deps);
+        self.get_all_deps_already_parsed(parsed, &mut this_file_deps);
 
         let this_file_deps: Vec<_> = this_file_deps.into_iter().map(|(_, v)| v.parsed).collect();
 
-        let descriptor = convert::file_descriptor(protobuf_path, &parsed, &this_file_deps)
-            .map_err(|e| {
-                io::Error::new(
-                    io::ErrorKind::Other,
-                    WithFileError {
-                        file: format!("{}", fs_path.display()),
-                        error: e.into(),
-                    },
-                )
-            })?;
+        let mut descriptor = convert::file_descriptor(protobuf_path, parsed, &this_file_deps)
+            .map_err(|e| Error::convert(fs_path, e))?;
 
-        self.parsed_files.insert(
-            protobuf_path.to_owned(),
-            FileDescriptorPair { parsed, descriptor },
-        );
+        if self.include_source_info {
+            descriptor.source_code_info =
+                protobuf::MessageField::some(source_info::source_code_info(parsed, &descriptor));
+        }
 
-        Ok(())
+        Ok(descriptor)
     }
 
-    fn add_imported_file(&mut self, protobuf_path: &Path) -> io::Result<()> {
-        for include_dir in self.includes {
-            let fs_path = include_dir.join(protobuf_path);
-            if fs_path.exists() {
-                return self.add_file(protobuf_path, &fs_path);
+    /// Find file in include directories, sources or bundled files.
+    fn read_source(&self, protobuf_path: &Path) -> Result<Option<SourceFile>> {
+        let includes = FsSourceProvider::new(self.includes);
+        let sources = iter::once(&includes as &dyn SourceProvider)
+            .chain(self.sources.iter().cloned())
+            .chain(iter::once(&BundledSourceProvider as &dyn SourceProvider));
+        for source in sources {
+            let file = source
+                .read(protobuf_path)
+                .map_err(|e| Error::in_file(protobuf_path, None, ErrorKind::Io(e)))?;
+            if let Some(file) = file {
+                return Ok(Some(file));
             }
         }
+        Ok(None)
+    }
+
+    /// Returns `false` if file is not found in include path, sources or bundled files.
+    fn add_imported_file(&mut self, protobuf_path: &Path) -> Result<bool> {
+        if self.parsed_files.contains_key(protobuf_path) {
+            return Ok(true);
+        }
 
-        let embedded = match protobuf_path.to_str() {
-            Some("rustproto.proto") => Some(RUSTPROTO_PROTO),
-            Some("google/protobuf/any.proto") => Some(ANY_PROTO),
-            Some("google/protobuf/api.proto") => Some(API_PROTO),
-            Some("google/protobuf/descriptor.proto") => Some(DESCRIPTOR_PROTO),
-            Some("google/protobuf/duration.proto") => Some(DURATION_PROTO),
-            Some("google/protobuf/empty.proto") => Some(EMPTY_PROTO),
-            Some("google/protobuf/field_mask.proto") => Some(FIELD_MASK_PROTO),
-            Some("google/protobuf/source_context.proto") => Some(SOURCE_CONTEXT_PROTO),
-            Some("google/protobuf/struct.proto") => Some(STRUCT_PROTO),
-            Some("google/protobuf/timestamp.proto") => Some(TIMESTAMP_PROTO),
-            Some("google/protobuf/type.proto") => Some(TYPE_PROTO),
-            Some("google/protobuf/wrappers.proto") => Some(WRAPPERS_PROTO),
-            _ => None,
-        };
-
-        match embedded {
-            Some(content) => self.add_file_content(protobuf_path, protobuf_path, content),
-            None => Err(io::Error::new(
-                io::ErrorKind::Other,
-                format!(
-                    "protobuf path {:?} is not found in import path {:?}",
-                    protobuf_path, self.includes
-                ),
-            )),
+        match self.read_source(protobuf_path)? {
+            Some(file) => {
+                self.add_file_content(protobuf_path, &file.display_path, &file.content)?;
+                Ok(true)
+            }
+            None => Ok(false),
         }
     }
 
-    fn add_fs_file(&mut self, fs_path: &Path) -> io::Result<PathBuf> {
+    fn add_fs_file(&mut self, fs_path: &Path) -> Result<PathBuf> {
         let relative_path = self
             .includes
             .iter()
             .filter_map(|include_dir| fs_path.strip_prefix(include_dir).ok())
+            // Empty include dir is a prefix of absolute paths too
+            .filter(|relative_path| relative_path.is_relative())
             .next();
 
         match relative_path {
@@ -302,20 +347,21 @@ impl<'a> Run<'a> {
                 self.add_file(relative_path, fs_path)?;
                 Ok(relative_path.to_owned())
             }
-            None => Err(io::Error::new(
-                io::ErrorKind::Other,
-                format!(
-                    "file {:?} must reside in include path {:?}",
-                    fs_path, self.includes
-                ),
-            )),
+            // Like `protoc`, treat input as path relative to source root
+            None if fs_path.is_relative() && self.add_imported_file(fs_path)? => {
+                Ok(fs_path.to_owned())
+            }
+            None => Err(Error::new(ErrorKind::NotInIncludePath(fs_path.to_owned()))),
         }
     }
 }
 
-#[doc(hidden)]
+/// Descriptors of parsed `.proto` files.
 pub struct ParsedAndTypechecked {
+    /// Paths of input files relative to include directories or sources.
     pub relative_paths: Vec<PathBuf>,
+    /// Descriptors of input files and all their dependencies,
+    /// dependencies precede files which import them.
     pub file_descriptors: Vec<protobuf::descriptor::FileDescriptorProto>,
 }
 
@@ -323,10 +369,42 @@ pub struct ParsedAndTypechecked {
 pub fn parse_and_typecheck(
     includes: &[PathBuf],
     input: &[PathBuf],
-) -> io::Result<ParsedAndTypechecked> {
+) -> Result<ParsedAndTypechecked> {
+    parse_and_typecheck_impl(includes, &[], input, false)
+}
+
+/// Like [`parse_and_typecheck`], but also fill `source_code_info`.
+#[doc(hidden)]
+pub fn parse_and_typecheck_with_source_info(
+    includes: &[PathBuf],
+    input: &[PathBuf],
+) -> Result<ParsedAndTypechecked> {
+    parse_and_typecheck_impl(includes, &[], input, true)
+}
+
+/// Parse `.proto` files and resolve types without generating code.
+///
+/// Input files and their imports are searched in `includes` directories,
+/// then in `sources`, then in [`BundledSourceProvider`].
+pub fn parse_and_typecheck_with_sources(
+    includes: &[PathBuf],
+    sources: &[&dyn SourceProvider],
+    input: &[PathBuf],
+) -> Result<ParsedAndTypechecked> {
+    parse_and_typecheck_impl(includes, sources, input, false)
+}
+
+fn parse_and_typecheck_impl(
+    includes: &[PathBuf],
+    sources: &[&dyn SourceProvider],
+    input: &[PathBuf],
+    include_source_info: bool,
+) -> Result<ParsedAndTypechecked> {
     let mut run = Run {
         parsed_files: LinkedHashMap::new(),
         includes: includes,
+        sources,
+        include_source_info,
     };
 
     let mut relative_paths = Vec::new();
@@ -346,17 +424,3 @@ pub fn parse_and_typecheck(
         file_descriptors,
     })
 }
-
-// TODO: these include don't work when publishing to crates
-const RUSTPROTO_PROTO: &str = include_str!("../../proto/rustproto.proto");
-const ANY_PROTO: &str = include_str!("../../proto/google/protobuf/any.proto");
-const API_PROTO: &str = include_str!("../../proto/google/protobuf/api.proto");
-const DESCRIPTOR_PROTO: &str = include_str!("../../proto/google/protobuf/descriptor.proto");
-const DURATION_PROTO: &str = include_str!("../../proto/google/protobuf/duration.proto");
-const EMPTY_PROTO: &str = include_str!("../../proto/google/protobuf/empty.proto");
-const FIELD_MASK_PROTO: &str = include_str!("../../proto/google/protobuf/field_mask.proto");
-const SOURCE_CONTEXT_PROTO: &str = include_str!("../../proto/google/protobuf/source_context.proto");
-const STRUCT_PROTO: &str = include_str!("../../proto/google/protobuf/struct.proto");
-const TIMESTAMP_PROTO: &str = include_str!("../../proto/google/protobuf/timestamp.proto");
-const TYPE_PROTO: &str = include_str!("../../proto/google/protobuf/type.proto");
-const WRAPPERS_PROTO: &str = include_str!("../../proto/google/protobuf/wrappers.proto");
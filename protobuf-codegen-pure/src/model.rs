@@ -12,9 +12,8 @@ use std::fmt::Write;
 
 use crate::convert::ConvertError;
 use crate::convert::ConvertResult;
-use crate::linked_hash_map::LinkedHashMap;
-pub use crate::parser::ParserError;
 pub use crate::parser::ParserErrorWithLocation;
+use protobuf::reflect::features::Edition;
 use protobuf::reflect::ReflectValueBox;
 use protobuf::reflect::RuntimeTypeBox;
 use protobuf_codegen::ProtobufIdent;
@@ -42,6 +41,19 @@ pub enum Syntax {
     Proto2,
     /// Protobuf syntax [3](https://developers.google.com/protocol-buffers/docs/proto3)
     Proto3,
+    /// Protobuf [editions](https://protobuf.dev/editions/overview/), e. g. `edition = "2023";`
+    Editions(Edition),
+}
+
+impl Syntax {
+    /// Edition with the same defaults as this syntax.
+    pub fn edition(&self) -> Edition {
+        match self {
+            Syntax::Proto2 => Edition::Proto2,
+            Syntax::Proto3 => Edition::Proto3,
+            Syntax::Editions(edition) => *edition,
+        }
+    }
 }
 
 impl Default for Syntax {
@@ -68,6 +80,10 @@ pub struct Group {
     /// Group name
     pub name: String,
     pub fields: Vec<WithLoc<Field>>,
+    /// Reserved field numbers
+    pub reserved_nums: Vec<FieldNumberRange>,
+    /// Reserved field names
+    pub reserved_names: Vec<String>,
 }
 
 /// Protobuf supported field types
@@ -210,7 +226,7 @@ pub struct Message {
     /// Nested messages
     pub messages: Vec<WithLoc<Message>>,
     /// Nested enums
-    pub enums: Vec<Enumeration>,
+    pub enums: Vec<WithLoc<Enumeration>>,
     /// Non-builtin options
     pub options: Vec<ProtobufOption>,
     /// Extension field numbers
@@ -230,14 +246,6 @@ impl Message {
             .collect()
     }
 
-    /** Find a field by name. */
-    pub fn field_by_name(&self, name: &str) -> Option<&Field> {
-        self.regular_fields_including_in_oneofs()
-            .iter()
-            .find(|f| f.t.name == name)
-            .map(|f| &f.t)
-    }
-
     pub fn _nested_extensions(&self) -> Vec<&Group> {
         self.regular_fields_including_in_oneofs()
             .into_iter()
@@ -283,14 +291,18 @@ pub struct EnumValue {
 }
 
 /// A protobuf enumerator
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Enumeration {
     /// enum name
     pub name: String,
     /// enum values
-    pub values: Vec<EnumValue>,
+    pub values: Vec<WithLoc<EnumValue>>,
     /// enum options
     pub options: Vec<ProtobufOption>,
+    /// enum reserved numbers
+    pub reserved_nums: Vec<FieldNumberRange>,
+    /// enum reserved names
+    pub reserved_names: Vec<String>,
 }
 
 /// A OneOf
@@ -338,10 +350,13 @@ pub struct Service {
     pub options: Vec<ProtobufOption>,
 }
 
+/// Message literal in text format, e. g. value of aggregate option
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct ProtobufConstantMessage {
-    pub fields: LinkedHashMap<String, ProtobufConstant>,
-    pub extensions: LinkedHashMap<String, ProtobufConstantMessage>,
+    /// Field values in declaration order, repeated field has several entries
+    pub fields: Vec<(String, ProtobufConstant)>,
+    /// Extension values by extension full name
+    pub extensions: Vec<(String, ProtobufConstant)>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -359,7 +374,12 @@ impl ProtobufConstantMessage {
     pub fn format(&self) -> String {
         let mut s = String::new();
         write!(s, "{{").unwrap();
-        for (n, v) in &self.fields {
+        let fields = self.fields.iter().map(|(n, v)| (n.clone(), v));
+        let extensions = self.extensions.iter().map(|(n, v)| (format!("[{}]", n), v));
+        for (i, (n, v)) in fields.chain(extensions).enumerate() {
+            if i != 0 {
+                write!(s, " ").unwrap();
+            }
             match v {
                 ProtobufConstant::Message(m) => write!(s, "{} {}", n, m.format()).unwrap(),
                 v => write!(s, "{}: {}", n, v.format()).unwrap(),
@@ -434,18 +454,6 @@ impl ProtobufOptionName {
             ))],
         }
     }
-
-    pub fn get_simple(&self) -> Option<&ProtobufIdent> {
-        match &self.components[..] {
-            [ProtobufOptionNameComponent::Direct(n)] => Some(&n),
-            _ => None,
-        }
-    }
-
-    // TODO: get rid of it
-    pub fn full_name(&self) -> String {
-        format!("{}", self)
-    }
 }
 
 impl fmt::Display for ProtobufOptionName {
@@ -491,7 +499,7 @@ pub struct Import {
 #[derive(Debug, Default, Clone)]
 pub struct FileDescriptor {
     /// Imports
-    pub imports: Vec<Import>,
+    pub imports: Vec<WithLoc<Import>>,
     /// Package
     pub package: Option<String>,
     /// Protobuf Syntax
@@ -499,7 +507,7 @@ pub struct FileDescriptor {
     /// Top level messages
     pub messages: Vec<WithLoc<Message>>,
     /// Enums
-    pub enums: Vec<Enumeration>,
+    pub enums: Vec<WithLoc<Enumeration>>,
     /// Extensions
     pub extensions: Vec<WithLoc<Extension>>,
     /// Services
@@ -509,15 +517,21 @@ pub struct FileDescriptor {
 }
 
 impl FileDescriptor {
-    /// Parses a .proto file content into a `FileDescriptor`
-    pub fn parse<S: AsRef<str>>(file: S) -> Result<Self, ParserErrorWithLocation> {
-        let mut parser = Parser::new(file.as_ref());
-        match parser.next_proto() {
-            Ok(r) => Ok(r),
-            Err(error) => {
-                let Loc { line, col } = parser.tokenizer.loc();
-                Err(ParserErrorWithLocation { error, line, col })
-            }
+    /// Parses a .proto file content, skipping statements with syntax errors.
+    ///
+    /// Returns partially parsed `FileDescriptor` along with all errors found
+    /// in order of occurrence; errors are empty if file is correct.
+    pub fn parse_recover<S: AsRef<str>>(file: S) -> (Self, Vec<ParserErrorWithLocation>) {
+        Parser::new(file.as_ref()).next_proto()
+    }
+
+    /// Parses a correct .proto file content, panics on syntax errors.
+    #[cfg(test)]
+    pub(crate) fn parse_correct(file: &str) -> FileDescriptor {
+        let (r, errors) = FileDescriptor::parse_recover(file);
+        if let Some(e) = errors.first() {
+            panic!("{}", e);
         }
+        r
     }
 }
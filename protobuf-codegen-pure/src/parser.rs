@@ -1,6 +1,9 @@
+use std::fmt;
 use std::str;
 
+use protobuf::reflect::features::Edition;
 use protobuf::text_format::lexer::LexerError;
+use protobuf::text_format::lexer::Loc;
 use protobuf::text_format::lexer::NumLit;
 use protobuf::text_format::lexer::ParserLanguage;
 use protobuf::text_format::lexer::StrLitDecodeError;
@@ -20,10 +23,12 @@ pub enum ParserError {
     NotUtf8,
     ExpectConstant,
     UnknownSyntax,
+    UnknownEdition,
     IntegerOverflow,
     LabelNotAllowed,
     LabelRequired,
     GroupNameShouldStartWithUpperCase,
+    GroupNotAllowed,
     MapFieldNotAllowed,
     StrLitDecodeError(StrLitDecodeError),
     LexerError(LexerError),
@@ -32,6 +37,37 @@ pub enum ParserError {
     OneOfInExtend,
 }
 
+impl fmt::Display for ParserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParserError::TokenizerError(e) => write!(f, "{}", e),
+            ParserError::IncorrectInput => write!(f, "Incorrect input"),
+            ParserError::NotUtf8 => write!(f, "Not UTF-8"),
+            ParserError::ExpectConstant => write!(f, "Expecting a constant"),
+            ParserError::UnknownSyntax => write!(f, "Unknown syntax"),
+            ParserError::UnknownEdition => write!(f, "Unknown edition"),
+            ParserError::IntegerOverflow => write!(f, "Integer overflow"),
+            ParserError::LabelNotAllowed => write!(f, "Label not allowed"),
+            ParserError::LabelRequired => write!(f, "Label required"),
+            ParserError::GroupNameShouldStartWithUpperCase => {
+                write!(f, "Group name should start with upper case")
+            }
+            ParserError::GroupNotAllowed => write!(
+                f,
+                "Group not allowed, use `features.message_encoding = DELIMITED`"
+            ),
+            ParserError::MapFieldNotAllowed => write!(f, "Map field not allowed"),
+            ParserError::StrLitDecodeError(e) => write!(f, "{}", e),
+            ParserError::LexerError(e) => write!(f, "{}", e),
+            ParserError::OneOfInGroup => write!(f, "Oneof not allowed in group"),
+            ParserError::OneOfInOneOf => write!(f, "Oneof not allowed in oneof"),
+            ParserError::OneOfInExtend => write!(f, "Oneof not allowed in extend"),
+        }
+    }
+}
+
+impl std::error::Error for ParserError {}
+
 impl From<TokenizerError> for ParserError {
     fn from(e: TokenizerError) -> Self {
         ParserError::TokenizerError(e)
@@ -65,6 +101,12 @@ pub struct ParserErrorWithLocation {
     pub col: u32,
 }
 
+impl fmt::Display for ParserErrorWithLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.col, self.error)
+    }
+}
+
 pub type ParserResult<T> = Result<T, ParserError>;
 
 trait ToU8 {
@@ -133,10 +175,13 @@ impl ToU8 for u32 {
     }
 }
 
-#[derive(Clone)]
 pub(crate) struct Parser<'a> {
     pub tokenizer: Tokenizer<'a>,
     syntax: Syntax,
+    /// Errors parser recovered from
+    errors: Vec<ParserErrorWithLocation>,
+    /// Parser cannot skip to the next statement, e. g. because EOF is reached
+    recovery_failed: bool,
 }
 
 #[derive(Copy, Clone)]
@@ -211,7 +256,7 @@ pub struct MessageBody {
     pub reserved_nums: Vec<FieldNumberRange>,
     pub reserved_names: Vec<String>,
     pub messages: Vec<WithLoc<Message>>,
-    pub enums: Vec<Enumeration>,
+    pub enums: Vec<WithLoc<Enumeration>>,
     pub options: Vec<ProtobufOption>,
     pub extension_ranges: Vec<FieldNumberRange>,
     pub extensions: Vec<WithLoc<Extension>>,
@@ -237,7 +282,75 @@ impl<'a> Parser<'a> {
         Parser {
             tokenizer: Tokenizer::new(input, ParserLanguage::Proto),
             syntax: Syntax::Proto2,
+            errors: Vec::new(),
+            recovery_failed: false,
+        }
+    }
+
+    // Error recovery
+
+    /// Record the error and skip the rest of the statement where it occurred,
+    /// so parsing can continue with the next statement.
+    ///
+    /// Fails if there is no next statement, e. g. on EOF.
+    fn recover(&mut self, error: ParserError) -> ParserResult<()> {
+        if self.recovery_failed {
+            return Err(error);
+        }
+        let Loc { line, col } = self.tokenizer.loc();
+        self.errors
+            .push(ParserErrorWithLocation { error, line, col });
+        match self.skip_statement() {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                self.recovery_failed = true;
+                Err(e)
+            }
+        }
+    }
+
+    /// Skip tokens until `;` or until the end of the block, whichever comes
+    /// first; `}` closing the enclosing block is not consumed.
+    fn skip_statement(&mut self) -> ParserResult<()> {
+        let mut depth = 0;
+        loop {
+            match self.tokenizer.lookahead_some()?.clone() {
+                Token::Symbol(';') if depth == 0 => {
+                    self.tokenizer.advance()?;
+                    return Ok(());
+                }
+                Token::Symbol('{') => depth += 1,
+                Token::Symbol('}') if depth == 0 => return Ok(()),
+                Token::Symbol('}') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.tokenizer.advance()?;
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+            self.tokenizer.advance()?;
+        }
+    }
+
+    /// Parse statements up to and including `}` closing the block.
+    ///
+    /// Statements with errors are skipped; if parsing cannot continue,
+    /// block is considered closed to keep what was parsed.
+    fn next_block_statements<F>(&mut self, mut statement: F) -> ParserResult<()>
+    where
+        F: FnMut(&mut Self) -> ParserResult<()>,
+    {
+        while self.tokenizer.lookahead_if_symbol()? != Some('}') {
+            if let Err(e) = statement(self) {
+                if self.recover(e).is_err() {
+                    return Ok(());
+                }
+            }
         }
+        self.tokenizer.next_symbol_expect_eq('}')?;
+        Ok(())
     }
 
     // Protobuf grammar
@@ -286,12 +399,12 @@ impl<'a> Parser<'a> {
     // groupName = capitalLetter { letter | decimalDigit | "_" }
     fn next_group_name(&mut self) -> ParserResult<String> {
         // lexer cannot distinguish between group name and other ident
-        let mut clone = self.clone();
-        let ident = clone.tokenizer.next_ident()?;
+        let mut clone = self.tokenizer.clone();
+        let ident = clone.next_ident()?;
         if !ident.chars().next().unwrap().is_ascii_uppercase() {
             return Err(ParserError::GroupNameShouldStartWithUpperCase);
         }
-        *self = clone;
+        self.tokenizer = clone;
         Ok(ident)
     }
 
@@ -315,29 +428,64 @@ impl<'a> Parser<'a> {
             .next_token_check_map(|token| Ok(token.to_num_lit()?))
     }
 
+    // Message literal in text format:
+    // message = ( "{" | "<" ) { field [ "," | ";" ] } ( "}" | ">" )
+    // field = ( ident | "[" fullIdent "]" ) ( ":" ( value | "[" [ value { "," value } ] "]" ) | message )
     fn next_message_constant(&mut self) -> ParserResult<ProtobufConstantMessage> {
         let mut r = ProtobufConstantMessage::default();
-        self.tokenizer.next_symbol_expect_eq('{')?;
-        while !self.tokenizer.lookahead_is_symbol('}')? {
+        let close = if self.tokenizer.next_symbol_if_eq('<')? {
+            '>'
+        } else {
+            self.tokenizer.next_symbol_expect_eq('{')?;
+            '}'
+        };
+        while !self.tokenizer.lookahead_is_symbol(close)? {
             if self.tokenizer.next_symbol_if_eq('[')? {
                 let n = self.next_full_ident()?;
                 self.tokenizer.next_symbol_expect_eq(']')?;
-                let v = self.next_message_constant()?;
-                r.extensions.insert(n, v);
+                for v in self.next_message_constant_field_values()? {
+                    r.extensions.push((n.clone(), v));
+                }
             } else {
                 let n = self.tokenizer.next_ident()?;
-                let v = if self.tokenizer.next_symbol_if_eq(':')? {
-                    self.next_constant()?
-                } else {
-                    ProtobufConstant::Message(self.next_message_constant()?)
-                };
-                r.fields.insert(n, v);
+                for v in self.next_message_constant_field_values()? {
+                    r.fields.push((n.clone(), v));
+                }
+            }
+            if !self.tokenizer.next_symbol_if_eq(',')? {
+                self.tokenizer.next_symbol_if_eq(';')?;
             }
         }
-        self.tokenizer.next_symbol_expect_eq('}')?;
+        self.tokenizer.next_symbol_expect_eq(close)?;
         Ok(r)
     }
 
+    fn next_message_constant_field_values(&mut self) -> ParserResult<Vec<ProtobufConstant>> {
+        if !self.tokenizer.next_symbol_if_eq(':')? {
+            return Ok(vec![ProtobufConstant::Message(
+                self.next_message_constant()?,
+            )]);
+        }
+        if !self.tokenizer.next_symbol_if_eq('[')? {
+            return Ok(vec![self.next_message_constant_field_value()?]);
+        }
+        let mut values = Vec::new();
+        while !self.tokenizer.next_symbol_if_eq(']')? {
+            if !values.is_empty() {
+                self.tokenizer.next_symbol_expect_eq(',')?;
+            }
+            values.push(self.next_message_constant_field_value()?);
+        }
+        Ok(values)
+    }
+
+    fn next_message_constant_field_value(&mut self) -> ParserResult<ProtobufConstant> {
+        if self.tokenizer.lookahead_is_symbol('<')? {
+            return Ok(ProtobufConstant::Message(self.next_message_constant()?));
+        }
+        self.next_constant()
+    }
+
     // constant = fullIdent | ( [ "-" | "+" ] intLit ) | ( [ "-" | "+" ] floatLit ) |
     //            strLit | boolLit
     fn next_constant(&mut self) -> ParserResult<ProtobufConstant> {
@@ -389,8 +537,15 @@ impl<'a> Parser<'a> {
 
     // syntax = "syntax" "=" quote "proto2" quote ";"
     // syntax = "syntax" "=" quote "proto3" quote ";"
+    // edition = "edition" "=" quote "2023" quote ";"
     fn next_syntax(&mut self) -> ParserResult<Option<Syntax>> {
-        if self.tokenizer.next_ident_if_eq("syntax")? {
+        if self.tokenizer.next_ident_if_eq("edition")? {
+            self.tokenizer.next_symbol_expect_eq('=')?;
+            let edition_str = self.tokenizer.next_str_lit()?.decode_utf8()?;
+            let edition = Edition::from_name(&edition_str).ok_or(ParserError::UnknownEdition)?;
+            self.tokenizer.next_symbol_expect_eq(';')?;
+            Ok(Some(Syntax::Editions(edition)))
+        } else if self.tokenizer.next_ident_if_eq("syntax")? {
             self.tokenizer.next_symbol_expect_eq('=')?;
             let syntax_str = self.tokenizer.next_str_lit()?.decode_utf8()?;
             let syntax = if syntax_str == "proto2" {
@@ -491,13 +646,13 @@ impl<'a> Parser<'a> {
             ("repeated", Rule::Repeated),
         ];
         for &(name, value) in map {
-            let mut clone = self.clone();
-            if clone.tokenizer.next_ident_if_eq(name)? {
+            let mut clone = self.tokenizer.clone();
+            if clone.next_ident_if_eq(name)? {
                 if !mode.label_allowed(value) {
                     return Err(ParserError::LabelNotAllowed);
                 }
 
-                *self = clone;
+                self.tokenizer = clone;
                 return Ok(value);
             }
         }
@@ -574,7 +729,7 @@ impl<'a> Parser<'a> {
     // group = label "group" groupName "=" fieldNumber messageBody
     fn next_field(&mut self, mode: MessageBodyParseMode) -> ParserResult<WithLoc<Field>> {
         let loc = self.tokenizer.lookahead_loc();
-        let rule = if self.clone().tokenizer.next_ident_if_eq("map")? {
+        let rule = if self.tokenizer.clone().next_ident_if_eq("map")? {
             if !mode.map_allowed() {
                 return Err(ParserError::MapFieldNotAllowed);
             }
@@ -583,16 +738,24 @@ impl<'a> Parser<'a> {
             self.next_label(mode)?
         };
         if self.tokenizer.next_ident_if_eq("group")? {
+            if let Syntax::Editions(..) = self.syntax {
+                return Err(ParserError::GroupNotAllowed);
+            }
             let name = self.next_group_name()?.to_owned();
             self.tokenizer.next_symbol_expect_eq('=')?;
             let number = self.next_field_number()?;
 
             let mode = match self.syntax {
                 Syntax::Proto2 => MessageBodyParseMode::MessageProto2,
-                Syntax::Proto3 => MessageBodyParseMode::MessageProto3,
+                Syntax::Proto3 | Syntax::Editions(..) => MessageBodyParseMode::MessageProto3,
             };
 
-            let MessageBody { fields, .. } = self.next_message_body(mode)?;
+            let MessageBody {
+                fields,
+                reserved_nums,
+                reserved_names,
+                ..
+            } = self.next_message_body(mode)?;
 
             let fields = fields
                 .into_iter()
@@ -608,7 +771,12 @@ impl<'a> Parser<'a> {
                 // https://git.io/JvxAP
                 name: name.to_ascii_lowercase(),
                 rule,
-                typ: FieldType::Group(Group { name: name, fields }),
+                typ: FieldType::Group(Group {
+                    name,
+                    fields,
+                    reserved_nums,
+                    reserved_names,
+                }),
                 number,
                 options: Vec::new(),
             };
@@ -724,17 +892,36 @@ impl<'a> Parser<'a> {
     // Grammar is incorrect: https://github.com/google/protobuf/issues/4558
     // reserved = "reserved" ( ranges | fieldNames ) ";"
     // fieldNames = fieldName { "," fieldName }
-    fn next_reserved_opt(&mut self) -> ParserResult<Option<(Vec<FieldNumberRange>, Vec<String>)>> {
+    // Editions use identifiers instead of string literals in `fieldNames`.
+    fn next_reserved_name(&mut self) -> ParserResult<String> {
+        if let &Token::StrLit(..) = self.tokenizer.lookahead_some()? {
+            Ok(self.tokenizer.next_str_lit()?.decode_utf8()?)
+        } else {
+            Ok(self.tokenizer.next_ident()?)
+        }
+    }
+
+    fn next_reserved_opt_with(
+        &mut self,
+        next_range: fn(&mut Self) -> ParserResult<FieldNumberRange>,
+    ) -> ParserResult<Option<(Vec<FieldNumberRange>, Vec<String>)>> {
         if self.tokenizer.next_ident_if_eq("reserved")? {
-            let (ranges, names) = if let &Token::StrLit(..) = self.tokenizer.lookahead_some()? {
+            let (ranges, names) = if let &Token::IntLit(..) | &Token::Symbol('-') =
+                self.tokenizer.lookahead_some()?
+            {
+                let mut ranges = Vec::new();
+                ranges.push(next_range(self)?);
+                while self.tokenizer.next_symbol_if_eq(',')? {
+                    ranges.push(next_range(self)?);
+                }
+                (ranges, Vec::new())
+            } else {
                 let mut names = Vec::new();
-                names.push(self.tokenizer.next_str_lit()?.decode_utf8()?);
+                names.push(self.next_reserved_name()?);
                 while self.tokenizer.next_symbol_if_eq(',')? {
-                    names.push(self.tokenizer.next_str_lit()?.decode_utf8()?);
+                    names.push(self.next_reserved_name()?);
                 }
                 (Vec::new(), names)
-            } else {
-                (self.next_ranges()?, Vec::new())
             };
 
             self.tokenizer.next_symbol_expect_eq(';')?;
@@ -745,6 +932,10 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn next_reserved_opt(&mut self) -> ParserResult<Option<(Vec<FieldNumberRange>, Vec<String>)>> {
+        self.next_reserved_opt_with(Parser::next_range)
+    }
+
     // Top Level definitions
 
     // Enum definition
@@ -772,8 +963,24 @@ impl<'a> Parser<'a> {
         })
     }
 
+    // enumRange = enumValue [ "to" ( enumValue | "max" ) ]
+    fn next_enum_range(&mut self) -> ParserResult<FieldNumberRange> {
+        let from = self.next_enum_value()?;
+        let to = if self.tokenizer.next_ident_if_eq("to")? {
+            if self.tokenizer.next_ident_if_eq("max")? {
+                i32::MAX
+            } else {
+                self.next_enum_value()?
+            }
+        } else {
+            from
+        };
+        Ok(FieldNumberRange { from, to })
+    }
+
     // enumField = ident "=" intLit [ "[" enumValueOption { ","  enumValueOption } "]" ]";"
-    fn next_enum_field(&mut self) -> ParserResult<EnumValue> {
+    fn next_enum_field(&mut self) -> ParserResult<WithLoc<EnumValue>> {
+        let loc = self.tokenizer.lookahead_loc();
         let name = self.tokenizer.next_ident()?.to_owned();
         self.tokenizer.next_symbol_expect_eq('=')?;
         let number = self.next_enum_value()?;
@@ -786,42 +993,54 @@ impl<'a> Parser<'a> {
             self.tokenizer.next_symbol_expect_eq(']')?;
         }
 
-        Ok(EnumValue {
-            name,
-            number,
-            options,
+        Ok(WithLoc {
+            t: EnumValue {
+                name,
+                number,
+                options,
+            },
+            loc,
         })
     }
 
+    // option | enumField | reserved | emptyStatement
+    fn next_enum_body_statement(&mut self, e: &mut Enumeration) -> ParserResult<()> {
+        // emptyStatement
+        if self.tokenizer.next_symbol_if_eq(';')? {
+            return Ok(());
+        }
+
+        if let Some(o) = self.next_option_opt()? {
+            e.options.push(o);
+            return Ok(());
+        }
+
+        if let Some((nums, names)) = self.next_reserved_opt_with(Parser::next_enum_range)? {
+            e.reserved_nums.extend(nums);
+            e.reserved_names.extend(names);
+            return Ok(());
+        }
+
+        e.values.push(self.next_enum_field()?);
+        Ok(())
+    }
+
     // enum = "enum" enumName enumBody
-    // enumBody = "{" { option | enumField | emptyStatement } "}"
-    fn next_enum_opt(&mut self) -> ParserResult<Option<Enumeration>> {
+    // enumBody = "{" { option | enumField | reserved | emptyStatement } "}"
+    fn next_enum_opt(&mut self) -> ParserResult<Option<WithLoc<Enumeration>>> {
+        let loc = self.tokenizer.lookahead_loc();
+
         if self.tokenizer.next_ident_if_eq("enum")? {
             let name = self.tokenizer.next_ident()?.to_owned();
 
-            let mut values = Vec::new();
-            let mut options = Vec::new();
+            let mut e = Enumeration {
+                name,
+                ..Default::default()
+            };
 
             self.tokenizer.next_symbol_expect_eq('{')?;
-            while self.tokenizer.lookahead_if_symbol()? != Some('}') {
-                // emptyStatement
-                if self.tokenizer.next_symbol_if_eq(';')? {
-                    continue;
-                }
-
-                if let Some(o) = self.next_option_opt()? {
-                    options.push(o);
-                    continue;
-                }
-
-                values.push(self.next_enum_field()?);
-            }
-            self.tokenizer.next_symbol_expect_eq('}')?;
-            Ok(Some(Enumeration {
-                name,
-                values,
-                options,
-            }))
+            self.next_block_statements(|p| p.next_enum_body_statement(&mut e))?;
+            Ok(Some(WithLoc { t: e, loc }))
         } else {
             Ok(None)
         }
@@ -836,71 +1055,78 @@ impl<'a> Parser<'a> {
 
         let mut r = MessageBody::default();
 
-        while self.tokenizer.lookahead_if_symbol()? != Some('}') {
-            let loc = self.tokenizer.lookahead_loc();
+        self.next_block_statements(|p| p.next_message_body_statement(mode, &mut r))?;
 
-            // emptyStatement
-            if self.tokenizer.next_symbol_if_eq(';')? {
-                continue;
-            }
+        Ok(r)
+    }
 
-            if mode.is_most_non_fields_allowed() {
-                if let Some((field_nums, field_names)) = self.next_reserved_opt()? {
-                    r.reserved_nums.extend(field_nums);
-                    r.reserved_names.extend(field_names);
-                    continue;
-                }
+    // field | enum | message | extend | extensions | group | option | oneof |
+    // mapField | reserved | emptyStatement
+    fn next_message_body_statement(
+        &mut self,
+        mode: MessageBodyParseMode,
+        r: &mut MessageBody,
+    ) -> ParserResult<()> {
+        let loc = self.tokenizer.lookahead_loc();
 
-                if let Some(oneof) = self.next_oneof_opt()? {
-                    let one_of = FieldOrOneOf::OneOf(oneof);
-                    r.fields.push(WithLoc { t: one_of, loc });
-                    continue;
-                }
+        // emptyStatement
+        if self.tokenizer.next_symbol_if_eq(';')? {
+            return Ok(());
+        }
 
-                if let Some(extension_ranges) = self.next_extensions_opt()? {
-                    r.extension_ranges.extend(extension_ranges);
-                    continue;
-                }
+        if mode.is_most_non_fields_allowed() {
+            if let Some((field_nums, field_names)) = self.next_reserved_opt()? {
+                r.reserved_nums.extend(field_nums);
+                r.reserved_names.extend(field_names);
+                return Ok(());
+            }
 
-                if let Some(extensions) = self.next_extend_opt()? {
-                    r.extensions.extend(extensions);
-                    continue;
-                }
+            if let Some(oneof) = self.next_oneof_opt()? {
+                let one_of = FieldOrOneOf::OneOf(oneof);
+                r.fields.push(WithLoc { t: one_of, loc });
+                return Ok(());
+            }
 
-                if let Some(nested_message) = self.next_message_opt()? {
-                    r.messages.push(nested_message);
-                    continue;
-                }
+            if let Some(extension_ranges) = self.next_extensions_opt()? {
+                r.extension_ranges.extend(extension_ranges);
+                return Ok(());
+            }
 
-                if let Some(nested_enum) = self.next_enum_opt()? {
-                    r.enums.push(nested_enum);
-                    continue;
-                }
-            } else {
-                self.tokenizer.next_ident_if_eq_error("reserved")?;
-                self.tokenizer.next_ident_if_eq_error("oneof")?;
-                self.tokenizer.next_ident_if_eq_error("extensions")?;
-                self.tokenizer.next_ident_if_eq_error("extend")?;
-                self.tokenizer.next_ident_if_eq_error("message")?;
-                self.tokenizer.next_ident_if_eq_error("enum")?;
+            if let Some(extensions) = self.next_extend_opt()? {
+                r.extensions.extend(extensions);
+                return Ok(());
             }
 
-            if mode.is_option_allowed() {
-                if let Some(option) = self.next_option_opt()? {
-                    r.options.push(option);
-                    continue;
-                }
-            } else {
-                self.tokenizer.next_ident_if_eq_error("option")?;
+            if let Some(nested_message) = self.next_message_opt()? {
+                r.messages.push(nested_message);
+                return Ok(());
             }
 
-            let field = FieldOrOneOf::Field(self.next_field(mode)?);
-            r.fields.push(WithLoc { t: field, loc });
+            if let Some(nested_enum) = self.next_enum_opt()? {
+                r.enums.push(nested_enum);
+                return Ok(());
+            }
+        } else {
+            self.tokenizer.next_ident_if_eq_error("reserved")?;
+            self.tokenizer.next_ident_if_eq_error("oneof")?;
+            self.tokenizer.next_ident_if_eq_error("extensions")?;
+            self.tokenizer.next_ident_if_eq_error("extend")?;
+            self.tokenizer.next_ident_if_eq_error("message")?;
+            self.tokenizer.next_ident_if_eq_error("enum")?;
         }
 
-        self.tokenizer.next_symbol_expect_eq('}')?;
+        if mode.is_option_allowed() {
+            if let Some(option) = self.next_option_opt()? {
+                r.options.push(option);
+                return Ok(());
+            }
+        } else {
+            self.tokenizer.next_ident_if_eq_error("option")?;
+        }
 
-        Ok(r)
+        let field = FieldOrOneOf::Field(self.next_field(mode)?);
+        r.fields.push(WithLoc { t: field, loc });
+        Ok(())
     }
 
     // message = "message" messageName messageBody
@@ -912,7 +1138,7 @@ impl<'a> Parser<'a> {
 
             let mode = match self.syntax {
                 Syntax::Proto2 => MessageBodyParseMode::MessageProto2,
-                Syntax::Proto3 => MessageBodyParseMode::MessageProto3,
+                Syntax::Proto3 | Syntax::Editions(..) => MessageBodyParseMode::MessageProto3,
             };
 
             let MessageBody {
@@ -947,18 +1173,15 @@ impl<'a> Parser<'a> {
 
     // extend = "extend" messageType "{" {field | group | emptyStatement} "}"
     fn next_extend_opt(&mut self) -> ParserResult<Option<Vec<WithLoc<Extension>>>> {
-        let mut clone = self.clone();
-        if clone.tokenizer.next_ident_if_eq("extend")? {
+        if self.tokenizer.next_ident_if_eq("extend")? {
             // According to spec `extend` is only for `proto2`, but it is used in `proto3`
             // https://github.com/google/protobuf/issues/4610
 
-            *self = clone;
-
             let extendee = self.next_message_or_enum_type()?;
 
             let mode = match self.syntax {
                 Syntax::Proto2 => MessageBodyParseMode::ExtendProto2,
-                Syntax::Proto3 => MessageBodyParseMode::ExtendProto3,
+                Syntax::Proto3 | Syntax::Editions(..) => MessageBodyParseMode::ExtendProto3,
             };
 
             let MessageBody { fields, .. } = self.next_message_body(mode)?;
@@ -1067,6 +1290,36 @@ impl<'a> Parser<'a> {
         }
     }
 
+    // option | rpc | stream | emptyStatement
+    fn next_service_body_statement(
+        &mut self,
+        methods: &mut Vec<Method>,
+        options: &mut Vec<ProtobufOption>,
+    ) -> ParserResult<()> {
+        if let Some(method) = self.next_rpc_opt()? {
+            methods.push(method);
+            return Ok(());
+        }
+
+        if self.syntax == Syntax::Proto2 {
+            if let Some(method) = self.next_stream_opt()? {
+                methods.push(method);
+                return Ok(());
+            }
+        }
+
+        if let Some(o) = self.next_option_opt()? {
+            options.push(o);
+            return Ok(());
+        }
+
+        if let Some(()) = self.next_empty_statement_opt()? {
+            return Ok(());
+        }
+
+        Err(ParserError::IncorrectInput)
+    }
+
     // proto2:
     // service = "service" serviceName "{" { option | rpc | stream | emptyStatement } "}"
     //
@@ -1078,31 +1331,9 @@ impl<'a> Parser<'a> {
             let mut methods = Vec::new();
             let mut options = Vec::new();
             self.tokenizer.next_symbol_expect_eq('{')?;
-            while self.tokenizer.lookahead_if_symbol()? != Some('}') {
-                if let Some(method) = self.next_rpc_opt()? {
-                    methods.push(method);
-                    continue;
-                }
-
-                if self.syntax == Syntax::Proto2 {
-                    if let Some(method) = self.next_stream_opt()? {
-                        methods.push(method);
-                        continue;
-                    }
-                }
-
-                if let Some(o) = self.next_option_opt()? {
-                    options.push(o);
-                    continue;
-                }
-
-                if let Some(()) = self.next_empty_statement_opt()? {
-                    continue;
-                }
-
-                return Err(ParserError::IncorrectInput);
-            }
-            self.tokenizer.next_symbol_expect_eq('}')?;
+            self.next_block_statements(|p| {
+                p.next_service_body_statement(&mut methods, &mut options)
+            })?;
             Ok(Some(Service {
                 name,
                 methods,
@@ -1115,73 +1346,87 @@ impl<'a> Parser<'a> {
 
     // Proto file
 
-    // proto = syntax { import | package | option | topLevelDef | emptyStatement }
+    // import | package | option | topLevelDef | emptyStatement
     // topLevelDef = message | enum | extend | service
-    pub fn next_proto(&mut self) -> ParserResult<FileDescriptor> {
-        let syntax = self.next_syntax()?.unwrap_or(Syntax::Proto2);
-        self.syntax = syntax;
-
-        let mut imports = Vec::new();
-        let mut package = None;
-        let mut messages = Vec::new();
-        let mut enums = Vec::new();
-        let mut extensions = Vec::new();
-        let mut options = Vec::new();
-        let mut services = Vec::new();
+    fn next_proto_statement(&mut self, r: &mut FileDescriptor) -> ParserResult<()> {
+        let loc = self.tokenizer.lookahead_loc();
+        if let Some(import) = self.next_import_opt()? {
+            r.imports.push(WithLoc { t: import, loc });
+            return Ok(());
+        }
 
-        while !self.tokenizer.syntax_eof()? {
-            if let Some(import) = self.next_import_opt()? {
-                imports.push(import);
-                continue;
-            }
+        if let Some(next_package) = self.next_package_opt()? {
+            r.package = Some(next_package);
+            return Ok(());
+        }
 
-            if let Some(next_package) = self.next_package_opt()? {
-                package = Some(next_package);
-                continue;
-            }
+        if let Some(option) = self.next_option_opt()? {
+            r.options.push(option);
+            return Ok(());
+        }
 
-            if let Some(option) = self.next_option_opt()? {
-                options.push(option);
-                continue;
-            }
+        if let Some(message) = self.next_message_opt()? {
+            r.messages.push(message);
+            return Ok(());
+        }
 
-            if let Some(message) = self.next_message_opt()? {
-                messages.push(message);
-                continue;
-            }
+        if let Some(enumeration) = self.next_enum_opt()? {
+            r.enums.push(enumeration);
+            return Ok(());
+        }
 
-            if let Some(enumeration) = self.next_enum_opt()? {
-                enums.push(enumeration);
-                continue;
-            }
+        if let Some(more_extensions) = self.next_extend_opt()? {
+            r.extensions.extend(more_extensions);
+            return Ok(());
+        }
 
-            if let Some(more_extensions) = self.next_extend_opt()? {
-                extensions.extend(more_extensions);
-                continue;
-            }
+        if let Some(service) = self.next_service_opt()? {
+            r.services.push(service);
+            return Ok(());
+        }
 
-            if let Some(service) = self.next_service_opt()? {
-                services.push(service);
-                continue;
-            }
+        if self.tokenizer.next_symbol_if_eq(';')? {
+            return Ok(());
+        }
+
+        Err(ParserError::IncorrectInput)
+    }
 
-            if self.tokenizer.next_symbol_if_eq(';')? {
-                continue;
+    // proto = syntax { import | package | option | topLevelDef | emptyStatement }
+    fn next_proto_statements(&mut self, r: &mut FileDescriptor) -> ParserResult<()> {
+        r.syntax = match self.next_syntax() {
+            Ok(syntax) => syntax.unwrap_or(Syntax::Proto2),
+            Err(e) => {
+                self.recover(e)?;
+                Syntax::Proto2
             }
+        };
+        self.syntax = r.syntax;
 
-            return Err(ParserError::IncorrectInput);
+        while !self.tokenizer.syntax_eof()? {
+            if let Err(e) = self.next_proto_statement(r) {
+                self.recover(e)?;
+                // Unbalanced `}` is not skipped by `recover`
+                self.tokenizer.next_symbol_if_eq('}')?;
+            }
         }
-
-        Ok(FileDescriptor {
-            imports,
-            package,
-            syntax,
-            messages,
-            enums,
-            extensions,
-            services,
-            options,
-        })
+        Ok(())
+    }
+
+    /// Parse whole file recovering from syntax errors at statement boundaries.
+    ///
+    /// Returns what was parsed (statements with errors are skipped)
+    /// and all errors found.
+    pub fn next_proto(&mut self) -> (FileDescriptor, Vec<ParserErrorWithLocation>) {
+        let mut r = FileDescriptor::default();
+        if let Err(error) = self.next_proto_statements(&mut r) {
+            if !self.recovery_failed {
+                let Loc { line, col } = self.tokenizer.loc();
+                self.errors
+                    .push(ParserErrorWithLocation { error, line, col });
+            }
+        }
+        (r, std::mem::take(&mut self.errors))
     }
 }
 
@@ -1226,6 +1471,42 @@ mod test {
         assert_eq!(Syntax::Proto3, mess);
     }
 
+    #[test]
+    fn test_edition() {
+        let msg = r#"
+            edition = "2023";
+            message Foo {
+                int32 a = 1 [features.field_presence = IMPLICIT];
+                repeated int32 b = 2;
+                optional int32 c = 3;
+                group G = 4 {}
+            }
+        "#;
+
+        let (desc, errors) = FileDescriptor::parse_recover(msg);
+        assert_eq!(Syntax::Editions(Edition::Edition2023), desc.syntax);
+        let errors: Vec<_> = errors
+            .iter()
+            .map(|e| (e.line, format!("{}", e.error)))
+            .collect();
+        assert_eq!(
+            vec![
+                (6, "Label not allowed".to_owned()),
+                (
+                    7,
+                    "Group not allowed, use `features.message_encoding = DELIMITED`".to_owned()
+                ),
+            ],
+            errors
+        );
+        let fields = desc.messages[0].t.regular_fields_for_test();
+        assert_eq!(2, fields.len());
+        assert_eq!(Rule::Repeated, fields[1].rule);
+
+        let (_, errors) = FileDescriptor::parse_recover("edition = \"2077\";");
+        assert_eq!("Unknown edition", format!("{}", errors[0].error));
+    }
+
     #[test]
     fn test_field_default_value_int() {
         let msg = r#"  optional int64 f = 4 [default = 12];  "#;
@@ -1280,7 +1561,7 @@ mod test {
     }"#;
 
         let enumeration = parse_opt(msg, |p| p.next_enum_opt());
-        assert_eq!(4, enumeration.values.len());
+        assert_eq!(4, enumeration.t.values.len());
     }
 
     #[test]
@@ -1301,11 +1582,14 @@ mod test {
         ContainerForNested.NestedEnum e = 2;
     }
     "#;
-        let desc = parse(msg, |p| p.next_proto());
+        let desc = FileDescriptor::parse_correct(msg);
 
         assert_eq!(
             vec!["test_import_nested_imported_pb.proto"],
-            desc.imports.into_iter().map(|i| i.path).collect::<Vec<_>>()
+            desc.imports
+                .into_iter()
+                .map(|i| i.t.path)
+                .collect::<Vec<_>>()
         );
     }
 
@@ -1319,7 +1603,7 @@ mod test {
         optional ContainerForNested.NestedEnum e = 2;
     }
     "#;
-        let desc = parse(msg, |p| p.next_proto());
+        let desc = FileDescriptor::parse_correct(msg);
         assert_eq!(Some("foo.bar".to_string()), desc.package);
     }
 
@@ -1330,7 +1614,7 @@ mod test {
         optional int32 a = 1;
     }
     "#;
-        let desc = parse(msg, |p| p.next_proto());
+        let desc = FileDescriptor::parse_correct(msg);
         assert_eq!(None, desc.package);
     }
 
@@ -1411,6 +1695,30 @@ mod test {
         assert_eq!(2, mess.t.fields.len());
     }
 
+    #[test]
+    fn test_enum_reserved() {
+        let msg = r#"enum E {
+       reserved -3, 2 to 4, 10 to max;
+       reserved "X", Y;
+       A = 0;
+    }"#;
+
+        let en = parse_opt(msg, |p| p.next_enum_opt());
+        assert_eq!(
+            vec![
+                FieldNumberRange { from: -3, to: -3 },
+                FieldNumberRange { from: 2, to: 4 },
+                FieldNumberRange {
+                    from: 10,
+                    to: i32::MAX
+                },
+            ],
+            en.t.reserved_nums
+        );
+        assert_eq!(vec!["X".to_string(), "Y".to_string()], en.t.reserved_names);
+        assert_eq!(1, en.t.values.len());
+    }
+
     #[test]
     fn test_default_value_int() {
         let msg = r#"message Sample {
@@ -1509,8 +1817,42 @@ mod test {
             dfgdg
         "#;
 
-        let err = FileDescriptor::parse(msg).err().expect("err");
-        assert_eq!(4, err.line);
+        let (_, errors) = FileDescriptor::parse_recover(msg);
+        assert_eq!(4, errors[0].line);
+    }
+
+    #[test]
+    fn test_recover() {
+        let msg = r#"
+            message Foo {
+                int32 a = ;
+                optional int32 b = 2;
+                message Bar { optional int32 c = 3 }
+                enum E { X = ; Y = 1; }
+            }
+            dfgdg;
+            }
+            service S { rpc }
+            message Baz {}
+        "#;
+
+        let (desc, errors) = FileDescriptor::parse_recover(msg);
+        let locs: Vec<_> = errors.iter().map(|e| (e.line, e.col)).collect();
+        assert_eq!(vec![(3, 17), (5, 52), (6, 30), (8, 13), (10, 29)], locs);
+
+        let foo = &desc.messages[0].t;
+        assert_eq!("b", foo.regular_fields_for_test()[0].name);
+        assert_eq!("Bar", foo.messages[0].t.name);
+        assert_eq!("Y", foo.enums[0].t.values[0].t.name);
+        assert_eq!("S", desc.services[0].name);
+        assert_eq!("Baz", desc.messages[1].t.name);
+    }
+
+    #[test]
+    fn test_recover_eof() {
+        let (desc, errors) = FileDescriptor::parse_recover("message Foo { optional int32 a = 1;");
+        assert_eq!(1, errors.len());
+        assert_eq!("Foo", desc.messages[0].t.name);
     }
 
     #[test]
@@ -1528,7 +1870,7 @@ mod test {
             }
         "#;
 
-        let fd = FileDescriptor::parse(proto).expect("fd");
+        let fd = FileDescriptor::parse_correct(proto);
         assert_eq!(3, fd.extensions.len());
         assert_eq!("google.protobuf.FileOptions", fd.extensions[0].t.extendee);
         assert_eq!("google.protobuf.FileOptions", fd.extensions[1].t.extendee);
@@ -1538,4 +1880,16 @@ mod test {
         );
         assert_eq!(17003, fd.extensions[2].t.field.t.number);
     }
+
+    #[test]
+    fn test_message_constant() {
+        let constant = parse(
+            r#"{ a: [1, 2], a: 3; b < c: "x" >, [p.ext] { d: E } [p.ext]: {} e: [] }"#,
+            |p| p.next_message_constant(),
+        );
+        assert_eq!(
+            r#"{a: 1 a: 2 a: 3 b {c: "x"} [p.ext] {d: E} [p.ext] {}}"#,
+            constant.format()
+        );
+    }
 }
@@ -0,0 +1,701 @@
+//! `protoc`-compatible command line interface.
+
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+use std::thread;
+
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::FileDescriptorSet;
+use protobuf::plugin::CodeGeneratorRequest;
+use protobuf::plugin::CodeGeneratorResponse;
+use protobuf::Message;
+use protobuf::MessageField;
+use protobuf_codegen::amend_io_error;
+use protobuf_codegen::Customize;
+
+use crate::decode;
+use crate::decode::Format;
+use crate::parse_and_typecheck_impl;
+use crate::path::fs_path_to_proto_path;
+use crate::ParsedAndTypechecked;
+
+const USAGE: &str = "\
+Usage: pure-protoc [OPTION] PROTO_FILES
+Parse PROTO_FILES and generate output based on the options given:
+  -IPATH, --proto_path=PATH   Specify the directory in which to search for
+                              imports.  May be specified multiple times;
+                              directories will be searched in order.  If not
+                              given, the current working directory is used.
+  --version                   Show version info and exit.
+  -h, --help                  Show this text and exit.
+  -oFILE,                     Writes a FileDescriptorSet (a protocol buffer,
+    --descriptor_set_out=FILE defined in descriptor.proto) containing all of
+                              the input files to FILE.
+  --include_imports           When using --descriptor_set_out, also include
+                              all dependencies of the input files in the
+                              set, so that the set is self-contained.
+  --include_source_info       When using --descriptor_set_out, do not strip
+                              SourceCodeInfo from the FileDescriptorProto.
+                              Only declaration start positions are recorded.
+  --plugin=EXECUTABLE         Specifies a plugin executable to use.
+                              Normally, protoc searches the PATH for
+                              plugins, but you may specify additional
+                              executables not in the path using this flag.
+                              Use --plugin=NAME=PATH to specify plugin name
+                              explicitly.
+  --rust_out=OUT_DIR          Generate Rust source file.
+  --NAME_out=[PARAM:]OUT_DIR  Generate output with plugin protoc-gen-NAME.
+  --NAME_opt=PARAM            Pass parameter to plugin protoc-gen-NAME.
+  --descriptor_set_in=FILES   Specifies a delimited list of FILES each
+                              containing a FileDescriptorSet to use as
+                              schema instead of (or in addition to)
+                              PROTO_FILES.
+  --encode=MESSAGE_TYPE       Read a text-format message of the given type
+                              from standard input and write it in binary
+                              to standard output.
+  --decode=MESSAGE_TYPE       Read a binary message of the given type from
+                              standard input and write it in text format
+                              to standard output.
+  --decode_raw                Read an arbitrary protocol message from
+                              standard input and write the raw tag/value
+                              pairs in text format to standard output.
+  --input_format=FORMAT       Override input format of --encode, --decode
+                              or --decode_raw: binary, delimited (messages
+                              prefixed with varint length), json or text.
+  --output_format=FORMAT      Override output format of --encode or
+                              --decode. Multiple delimited messages are
+                              written one per line in json format and
+                              separated by empty line in text format.
+";
+
+fn err_usage(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message.into())
+}
+
+fn err_other(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, message.into())
+}
+
+/// `--NAME_out` command line flag.
+#[derive(Debug, PartialEq)]
+struct LangOut {
+    lang: String,
+    parameter: String,
+    out_dir: PathBuf,
+}
+
+/// Parsed command line.
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    includes: Vec<PathBuf>,
+    inputs: Vec<PathBuf>,
+    descriptor_set_out: Option<PathBuf>,
+    include_imports: bool,
+    include_source_info: bool,
+    /// Plugin name (e. g. `protoc-gen-foo`) to executable
+    plugins: HashMap<String, PathBuf>,
+    outs: Vec<LangOut>,
+    /// Params from `--NAME_opt` flags
+    opts: HashMap<String, Vec<String>>,
+    descriptor_set_in: Vec<PathBuf>,
+    encode: Option<String>,
+    decode: Option<String>,
+    decode_raw: bool,
+    input_format: Option<Format>,
+    output_format: Option<Format>,
+    help: bool,
+    version: bool,
+}
+
+impl Args {
+    fn parse(args: impl IntoIterator<Item = OsString>) -> io::Result<Args> {
+        let mut r = Args::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let arg = arg
+                .into_string()
+                .map_err(|a| err_usage(format!("argument is not valid UTF-8: {:?}", a)))?;
+
+            if !arg.starts_with('-') {
+                r.inputs.push(PathBuf::from(arg));
+                continue;
+            }
+
+            if let Some(path) = short_flag_value(&arg, "-I", &mut args)? {
+                r.includes.push(PathBuf::from(path));
+                continue;
+            }
+            if let Some(path) = short_flag_value(&arg, "-o", &mut args)? {
+                r.descriptor_set_out = Some(PathBuf::from(path));
+                continue;
+            }
+
+            let (name, value) = match arg.find('=') {
+                Some(eq) => (&arg[..eq], Some(arg[eq + 1..].to_owned())),
+                None => (&arg[..], None),
+            };
+
+            match name {
+                "-h" | "--help" => r.help = true,
+                "--version" => r.version = true,
+                "--include_imports" => r.include_imports = true,
+                "--include_source_info" => r.include_source_info = true,
+                "--decode_raw" => r.decode_raw = true,
+                "--encode" => r.encode = Some(long_flag_value(name, value, &mut args)?),
+                "--decode" => r.decode = Some(long_flag_value(name, value, &mut args)?),
+                "--descriptor_set_in" => {
+                    let value = long_flag_value(name, value, &mut args)?;
+                    r.descriptor_set_in.extend(env::split_paths(&value));
+                }
+                "--input_format" => {
+                    r.input_format = Some(long_flag_value(name, value, &mut args)?.parse()?);
+                }
+                "--output_format" => {
+                    r.output_format = Some(long_flag_value(name, value, &mut args)?.parse()?);
+                }
+                "--proto_path" => {
+                    let value = long_flag_value(name, value, &mut args)?;
+                    r.includes.push(PathBuf::from(value));
+                }
+                "--descriptor_set_out" => {
+                    let value = long_flag_value(name, value, &mut args)?;
+                    r.descriptor_set_out = Some(PathBuf::from(value));
+                }
+                "--plugin" => {
+                    let value = long_flag_value(name, value, &mut args)?;
+                    let (name, path) = match value.find('=') {
+                        Some(eq) => (value[..eq].to_owned(), PathBuf::from(&value[eq + 1..])),
+                        None => {
+                            let path = PathBuf::from(&value);
+                            let name = match path.file_stem().and_then(|s| s.to_str()) {
+                                Some(name) => name.to_owned(),
+                                None => {
+                                    return Err(err_usage(format!(
+                                        "cannot determine plugin name from {}",
+                                        value
+                                    )))
+                                }
+                            };
+                            (name, path)
+                        }
+                    };
+                    r.plugins.insert(name, path);
+                }
+                _ if name.starts_with("--") && name.ends_with("_out") => {
+                    let lang = name["--".len()..name.len() - "_out".len()].to_owned();
+                    let value = long_flag_value(name, value, &mut args)?;
+                    let (parameter, out_dir) = match value.find(':') {
+                        // Do not treat windows drive letter as parameter
+                        Some(colon) if !starts_with_windows_drive(&value) => {
+                            (value[..colon].to_owned(), &value[colon + 1..])
+                        }
+                        _ => (String::new(), &value[..]),
+                    };
+                    r.outs.push(LangOut {
+                        lang,
+                        parameter,
+                        out_dir: PathBuf::from(out_dir),
+                    });
+                }
+                _ if name.starts_with("--") && name.ends_with("_opt") => {
+                    let lang = name["--".len()..name.len() - "_opt".len()].to_owned();
+                    let value = long_flag_value(name, value, &mut args)?;
+                    r.opts.entry(lang).or_insert_with(Vec::new).push(value);
+                }
+                _ => return Err(err_usage(format!("unknown flag: {}", arg))),
+            }
+        }
+
+        Ok(r)
+    }
+
+    /// Parameter for the plugin: both from `--NAME_out` and `--NAME_opt` flags,
+    /// joined with comma like `protoc` does.
+    ///
+    /// Builtin `--rust_out` parses it with `Customize::parse_from_parameter`,
+    /// which splits options on commas.
+    fn parameter(&self, out: &LangOut) -> String {
+        let mut params = Vec::new();
+        if !out.parameter.is_empty() {
+            params.push(out.parameter.clone());
+        }
+        if let Some(opts) = self.opts.get(&out.lang) {
+            params.extend(opts.iter().cloned());
+        }
+        params.join(",")
+    }
+}
+
+fn starts_with_windows_drive(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() >= 3 && b[0].is_ascii_alphabetic() && b[1] == b':' && (b[2] == b'\\' || b[2] == b'/')
+}
+
+/// Value of `-Xvalue` or `-X value` flag.
+fn short_flag_value(
+    arg: &str,
+    flag: &str,
+    args: &mut impl Iterator<Item = OsString>,
+) -> io::Result<Option<String>> {
+    if !arg.starts_with(flag) {
+        return Ok(None);
+    }
+    if arg.len() > flag.len() {
+        return Ok(Some(arg[flag.len()..].to_owned()));
+    }
+    long_flag_value(flag, None, args).map(Some)
+}
+
+/// Value of `--flag=value` or `--flag value` flag.
+fn long_flag_value(
+    flag: &str,
+    value: Option<String>,
+    args: &mut impl Iterator<Item = OsString>,
+) -> io::Result<String> {
+    match value {
+        Some(value) => Ok(value),
+        None => match args.next() {
+            Some(value) => value
+                .into_string()
+                .map_err(|a| err_usage(format!("argument is not valid UTF-8: {:?}", a))),
+            None => Err(err_usage(format!("missing value for flag {}", flag))),
+        },
+    }
+}
+
+struct Protoc {
+    args: Args,
+    parsed: ParsedAndTypechecked,
+    /// Names of input files as in `FileDescriptorProto`
+    input_names: Vec<String>,
+}
+
+impl Protoc {
+    fn write_descriptor_set(&self, path: &Path) -> io::Result<()> {
+        let mut set = FileDescriptorSet::new();
+        if self.args.include_imports {
+            set.file = self.parsed.file_descriptors.clone();
+        } else {
+            for name in &self.input_names {
+                set.file.push(self.file_descriptor(name).clone());
+            }
+        }
+        if !self.args.include_source_info {
+            for file in &mut set.file {
+                file.source_code_info = MessageField::none();
+            }
+        }
+
+        let bytes = set.write_to_bytes()?;
+        fs::write(path, bytes)
+            .map_err(|e| amend_io_error(e, format!("failed to write {}", path.display())))
+    }
+
+    fn file_descriptor(&self, name: &str) -> &FileDescriptorProto {
+        self.parsed
+            .file_descriptors
+            .iter()
+            .find(|f| f.get_name() == name)
+            .expect("input file descriptor")
+    }
+
+    fn gen_rust(&self, out: &LangOut) -> io::Result<()> {
+        let customize = Customize::parse_from_parameter(&self.args.parameter(out))
+            .map_err(|e| err_usage(format!("--rust_out: {}", e)))?;
+
+        protobuf_codegen::gen_and_write(
+            &self.parsed.file_descriptors,
+            &format!("protobuf-codegen-pure={}", env!("CARGO_PKG_VERSION")),
+            &self.parsed.relative_paths,
+            &out.out_dir,
+            &customize,
+        )
+    }
+
+    fn plugin_path(&self, lang: &str) -> PathBuf {
+        let name = format!("protoc-gen-{}", lang);
+        match self.args.plugins.get(&name) {
+            Some(path) => path.clone(),
+            // Search in `$PATH`
+            None => PathBuf::from(name),
+        }
+    }
+
+    fn run_plugin(&self, out: &LangOut) -> io::Result<()> {
+        let plugin = self.plugin_path(&out.lang);
+
+        let mut req = CodeGeneratorRequest::new();
+        req.file_to_generate = self.input_names.clone();
+        let parameter = self.args.parameter(out);
+        if !parameter.is_empty() {
+            req.set_parameter(parameter);
+        }
+        req.proto_file = self.parsed.file_descriptors.clone();
+        let req = req.write_to_bytes()?;
+
+        let mut child = process::Command::new(&plugin)
+            .stdin(process::Stdio::piped())
+            .stdout(process::Stdio::piped())
+            .stderr(process::Stdio::inherit())
+            .spawn()
+            .map_err(|e| {
+                amend_io_error(e, format!("failed to spawn plugin {}", plugin.display()))
+            })?;
+
+        // Write from another thread: plugin may start writing
+        // before it finished reading the request
+        let mut stdin = child.stdin.take().unwrap();
+        let writer = thread::spawn(move || stdin.write_all(&req));
+
+        let output = child.wait_with_output()?;
+        writer
+            .join()
+            .expect("writer thread panicked")
+            .map_err(|e| {
+                amend_io_error(
+                    e,
+                    format!("failed to write request to plugin {}", plugin.display()),
+                )
+            })?;
+        if !output.status.success() {
+            return Err(err_other(format!(
+                "plugin {} exited with {}",
+                plugin.display(),
+                output.status
+            )));
+        }
+
+        let resp = CodeGeneratorResponse::parse_from_bytes(&output.stdout)?;
+        if resp.has_error() {
+            return Err(err_other(format!(
+                "--{}_out: {}",
+                out.lang,
+                resp.get_error()
+            )));
+        }
+
+        write_response_files(&resp, &out.out_dir)
+    }
+
+    fn run(&self) -> io::Result<()> {
+        if let Some(path) = &self.args.descriptor_set_out {
+            self.write_descriptor_set(path)?;
+        }
+
+        for out in &self.args.outs {
+            if out.lang == "rust" && !self.args.plugins.contains_key("protoc-gen-rust") {
+                self.gen_rust(out)?;
+            } else {
+                self.run_plugin(out)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check file name from plugin response is a relative path
+/// which does not escape output directory.
+fn check_response_file_name(name: &str) -> io::Result<()> {
+    let path = Path::new(name);
+    let escapes = path.components().any(|c| match c {
+        Component::Normal(..) | Component::CurDir => false,
+        Component::Prefix(..) | Component::RootDir | Component::ParentDir => true,
+    });
+    if escapes {
+        return Err(err_other(format!(
+            "{}: plugin output file name must be a relative path without `..`",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Write files of plugin response, files with empty name are appended
+/// to the previous file like `protoc` does.
+fn write_response_files(resp: &CodeGeneratorResponse, out_dir: &Path) -> io::Result<()> {
+    let mut files: Vec<(String, String)> = Vec::new();
+    for file in &resp.file {
+        if file.has_insertion_point() {
+            return Err(err_other(format!(
+                "{}: insertion points are not supported",
+                file.get_name()
+            )));
+        }
+        check_response_file_name(file.get_name())?;
+        if file.get_name().is_empty() {
+            match files.last_mut() {
+                Some((_, content)) => content.push_str(file.get_content()),
+                None => return Err(err_other("first file in plugin response has no name")),
+            }
+        } else {
+            files.push((file.get_name().to_owned(), file.get_content().to_owned()));
+        }
+    }
+
+    for (name, content) in files {
+        let path = out_dir.join(&name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| amend_io_error(e, format!("failed to create {}", parent.display())))?;
+        }
+        fs::write(&path, content)
+            .map_err(|e| amend_io_error(e, format!("failed to write {}", path.display())))?;
+    }
+    Ok(())
+}
+
+/// Run `--encode`, `--decode` or `--decode_raw`.
+fn run_convert(args: Args) -> io::Result<()> {
+    let modes =
+        args.encode.is_some() as u32 + args.decode.is_some() as u32 + args.decode_raw as u32;
+    if modes != 1 {
+        return Err(err_usage(
+            "only one of --encode, --decode and --decode_raw can be specified",
+        ));
+    }
+    if !args.outs.is_empty() || args.descriptor_set_out.is_some() {
+        return Err(err_usage(
+            "--encode, --decode and --decode_raw cannot be used with output directives",
+        ));
+    }
+
+    let mut input = Vec::new();
+    io::stdin().read_to_end(&mut input)?;
+
+    let output = if args.decode_raw {
+        if args.output_format.map_or(false, |f| f != Format::Text) {
+            return Err(err_usage("--decode_raw output format must be text"));
+        }
+        decode::decode_raw(&input, args.input_format.unwrap_or(Format::Binary))?
+    } else {
+        let (message_type, input_format, output_format) = match &args.encode {
+            Some(message_type) => (message_type, Format::Text, Format::Binary),
+            None => (args.decode.as_ref().unwrap(), Format::Binary, Format::Text),
+        };
+        let input_format = args.input_format.unwrap_or(input_format);
+        let output_format = args.output_format.unwrap_or(output_format);
+
+        let mut protos = decode::read_descriptor_sets(&args.descriptor_set_in)?;
+        if !args.inputs.is_empty() {
+            let includes = if args.includes.is_empty() {
+                vec![PathBuf::new()]
+            } else {
+                args.includes.clone()
+            };
+            let parsed = parse_and_typecheck_impl(&includes, &[], &args.inputs, false)?;
+            // Files from descriptor sets take precedence
+            for file in parsed.file_descriptors {
+                if protos.iter().all(|p| p.get_name() != file.get_name()) {
+                    protos.push(file);
+                }
+            }
+        }
+        let descriptor = decode::find_message(protos, message_type)?;
+        let messages = decode::parse_messages(&descriptor, input_format, &input)?;
+        decode::print_messages(&messages, output_format)?
+    };
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    stdout.write_all(&output)?;
+    stdout.flush()
+}
+
+fn run(args: Args) -> io::Result<()> {
+    if args.encode.is_some() || args.decode.is_some() || args.decode_raw {
+        return run_convert(args);
+    }
+
+    if args.inputs.is_empty() {
+        return Err(err_usage("missing input file"));
+    }
+    if args.outs.is_empty() && args.descriptor_set_out.is_none() {
+        return Err(err_usage("missing output directives"));
+    }
+
+    let includes = if args.includes.is_empty() {
+        // Empty path is a prefix of any relative path
+        vec![PathBuf::new()]
+    } else {
+        args.includes.clone()
+    };
+
+    // Plugins get source info like from `protoc`
+    let include_source_info = args.include_source_info || !args.outs.is_empty();
+    let parsed = parse_and_typecheck_impl(&includes, &[], &args.inputs, include_source_info)?;
+    let input_names = parsed
+        .relative_paths
+        .iter()
+        .map(|p| fs_path_to_proto_path(p))
+        .collect();
+
+    Protoc {
+        args,
+        parsed,
+        input_names,
+    }
+    .run()
+}
+
+/// Entry point of `pure-protoc` command, `protoc` replacement which does not
+/// require C++ `protoc` binary.
+///
+/// Supports `-I`, `--descriptor_set_out`, `--include_imports`, `--include_source_info`,
+/// builtin `--rust_out` and `--NAME_out` invoking `protoc-gen-NAME` plugins.
+pub fn protoc_main() {
+    let args = match Args::parse(env::args_os().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprint!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    if args.help {
+        print!("{}", USAGE);
+        return;
+    }
+    if args.version {
+        println!(
+            "libprotoc 3.0.0 (protobuf-codegen-pure {})",
+            env!("CARGO_PKG_VERSION")
+        );
+        return;
+    }
+
+    if let Err(e) = run(args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Args {
+        Args::parse(args.iter().map(OsString::from)).unwrap()
+    }
+
+    #[test]
+    fn parse_args() {
+        let args = parse(&[
+            "-Ia",
+            "-I",
+            "b",
+            "--proto_path=c",
+            "--descriptor_set_out",
+            "fds.bin",
+            "--include_imports",
+            "--plugin=protoc-gen-foo=/bin/foo",
+            "--plugin=/bin/protoc-gen-bar",
+            "--rust_out=src",
+            "--foo_out=x=1:gen",
+            "--foo_opt=y=2",
+            "a/x.proto",
+        ]);
+        assert_eq!(
+            vec![PathBuf::from("a"), PathBuf::from("b"), PathBuf::from("c")],
+            args.includes
+        );
+        assert_eq!(vec![PathBuf::from("a/x.proto")], args.inputs);
+        assert_eq!(Some(PathBuf::from("fds.bin")), args.descriptor_set_out);
+        assert!(args.include_imports);
+        assert!(!args.include_source_info);
+        assert_eq!(
+            Some(&PathBuf::from("/bin/foo")),
+            args.plugins.get("protoc-gen-foo")
+        );
+        assert_eq!(
+            Some(&PathBuf::from("/bin/protoc-gen-bar")),
+            args.plugins.get("protoc-gen-bar")
+        );
+        assert_eq!(
+            vec![
+                LangOut {
+                    lang: "rust".to_owned(),
+                    parameter: String::new(),
+                    out_dir: PathBuf::from("src"),
+                },
+                LangOut {
+                    lang: "foo".to_owned(),
+                    parameter: "x=1".to_owned(),
+                    out_dir: PathBuf::from("gen"),
+                },
+            ],
+            args.outs
+        );
+        assert_eq!("x=1,y=2", args.parameter(&args.outs[1]));
+    }
+
+    #[test]
+    fn parse_args_errors() {
+        assert!(Args::parse(vec![OsString::from("--unknown")]).is_err());
+        assert!(Args::parse(vec![OsString::from("-I")]).is_err());
+        assert!(Args::parse(vec![OsString::from("--rust_out")]).is_err());
+    }
+
+    #[test]
+    fn windows_drive_is_not_parameter() {
+        let args = parse(&["--rust_out=C:\\out"]);
+        assert_eq!("", args.outs[0].parameter);
+        assert_eq!(PathBuf::from("C:\\out"), args.outs[0].out_dir);
+    }
+
+    #[test]
+    fn response_files() {
+        let dir = env::temp_dir().join(format!("pure-protoc-test-{}", process::id()));
+        let mut resp = CodeGeneratorResponse::new();
+        for (name, content) in &[("a/b.txt", "x"), ("", "y"), ("c.txt", "z")] {
+            let mut file = protobuf::plugin::code_generator_response::File::new();
+            if !name.is_empty() {
+                file.set_name(name.to_string());
+            }
+            file.set_content(content.to_string());
+            resp.file.push(file);
+        }
+        write_response_files(&resp, &dir).unwrap();
+        assert_eq!("xy", fs::read_to_string(dir.join("a/b.txt")).unwrap());
+        assert_eq!("z", fs::read_to_string(dir.join("c.txt")).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn response_file_outside_out_dir() {
+        let dir = env::temp_dir().join(format!("pure-protoc-test-escape-{}", process::id()));
+        for name in &["../x.txt", "a/../../x.txt", "/tmp/x.txt"] {
+            let mut resp = CodeGeneratorResponse::new();
+            let mut file = protobuf::plugin::code_generator_response::File::new();
+            file.set_name(name.to_string());
+            file.set_content("x".to_owned());
+            resp.file.push(file);
+            assert!(write_response_files(&resp, &dir).is_err(), "{}", name);
+        }
+        assert!(!dir.exists());
+
+        assert!(check_response_file_name("a/./b.rs").is_ok());
+    }
+
+    #[test]
+    fn rust_out_parameter_parsed() {
+        let args = parse(&[
+            "--rust_out=expose_oneof=true:src",
+            "--rust_opt=serde_derive=true",
+        ]);
+        let c = Customize::parse_from_parameter(&args.parameter(&args.outs[0])).unwrap();
+        assert_eq!(Some(true), c.expose_oneof);
+        assert_eq!(Some(true), c.serde_derive);
+    }
+}
@@ -0,0 +1,147 @@
+//! Build `SourceCodeInfo` from locations remembered by the parser.
+//!
+//! Parser does not preserve comments and only remembers where declarations start,
+//! so generated locations contain neither comments nor declaration ends.
+
+use protobuf::descriptor::source_code_info::Location;
+use protobuf::descriptor::DescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::SourceCodeInfo;
+use protobuf::text_format::lexer::Loc;
+
+use crate::model;
+
+// Field numbers from `descriptor.proto`
+const FILE_MESSAGE_TYPE: i32 = 4;
+const FILE_EXTENSION: i32 = 7;
+const MESSAGE_FIELD: i32 = 2;
+const MESSAGE_NESTED_TYPE: i32 = 3;
+const MESSAGE_EXTENSION: i32 = 6;
+
+struct SourceInfoBuilder {
+    locations: Vec<Location>,
+}
+
+impl SourceInfoBuilder {
+    fn add(&mut self, path: &[i32], loc: Loc) {
+        let line = loc.line as i32 - 1;
+        let col = loc.col as i32 - 1;
+        let mut location = Location::new();
+        location.path = path.to_vec();
+        // Three-element span means start and end are on the same line
+        location.span = vec![line, col, col];
+        self.locations.push(location);
+    }
+
+    fn fields<'a>(
+        &mut self,
+        path: &[i32],
+        descriptors: &[FieldDescriptorProto],
+        parsed: impl Iterator<Item = &'a model::WithLoc<model::Field>> + Clone,
+    ) {
+        for (i, field) in descriptors.iter().enumerate() {
+            let found = parsed.clone().find(|f| f.t.name == field.get_name());
+            if let Some(f) = found {
+                self.add(&[path, &[i as i32]].concat(), f.loc);
+            }
+        }
+    }
+
+    fn message(&mut self, path: &[i32], descriptor: &DescriptorProto, parsed: &model::Message) {
+        let fields = parsed.regular_fields_including_in_oneofs();
+        self.fields(
+            &[path, &[MESSAGE_FIELD]].concat(),
+            &descriptor.field,
+            fields.iter().cloned(),
+        );
+        self.fields(
+            &[path, &[MESSAGE_EXTENSION]].concat(),
+            &descriptor.extension,
+            parsed.extensions.iter().map(|e| &e.t.field),
+        );
+        self.messages(
+            &[path, &[MESSAGE_NESTED_TYPE]].concat(),
+            &descriptor.nested_type,
+            &parsed.messages,
+        );
+    }
+
+    fn messages(
+        &mut self,
+        path: &[i32],
+        descriptors: &[DescriptorProto],
+        parsed: &[model::WithLoc<model::Message>],
+    ) {
+        for (i, message) in descriptors.iter().enumerate() {
+            // Synthetic messages like map entries are not found
+            let found = parsed.iter().find(|m| m.t.name == message.get_name());
+            if let Some(m) = found {
+                let path = [path, &[i as i32]].concat();
+                self.add(&path, m.loc);
+                self.message(&path, message, &m.t);
+            }
+        }
+    }
+}
+
+/// Generate source info for messages, fields and extensions of the file.
+pub(crate) fn source_code_info(
+    parsed: &model::FileDescriptor,
+    descriptor: &FileDescriptorProto,
+) -> SourceCodeInfo {
+    let mut builder = SourceInfoBuilder {
+        locations: Vec::new(),
+    };
+    builder.messages(
+        &[FILE_MESSAGE_TYPE],
+        &descriptor.message_type,
+        &parsed.messages,
+    );
+    builder.fields(
+        &[FILE_EXTENSION],
+        &descriptor.extension,
+        parsed.extensions.iter().map(|e| &e.t.field),
+    );
+    let mut r = SourceCodeInfo::new();
+    r.location = builder.locations;
+    r
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::*;
+    use crate::convert;
+
+    #[test]
+    fn locations() {
+        let content = "\
+syntax = \"proto2\";
+message Foo {
+  optional int32 a = 1;
+  message Bar {
+    map<string, int32> m = 1;
+  }
+}
+";
+        let parsed = model::FileDescriptor::parse_correct(content);
+        let descriptor = convert::file_descriptor(Path::new("foo.proto"), &parsed, &[]).unwrap();
+        let info = source_code_info(&parsed, &descriptor);
+        let locations: Vec<(Vec<i32>, i32)> = info
+            .location
+            .iter()
+            .map(|l| (l.path.clone(), l.span[0]))
+            .collect();
+        assert_eq!(
+            vec![
+                (vec![4, 0], 1),
+                (vec![4, 0, 2, 0], 2),
+                (vec![4, 0, 3, 0], 3),
+                (vec![4, 0, 3, 0, 2, 0], 4),
+            ],
+            locations
+        );
+    }
+}
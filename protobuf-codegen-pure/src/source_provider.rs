@@ -0,0 +1,295 @@
+//! Sources of `.proto` files.
+//!
+//! Files are identified by path relative to source root, the same path
+//! which is used in `import` statements, e. g. `google/protobuf/any.proto`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// `.proto` file returned by [`SourceProvider`].
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    /// Path shown in error messages, e. g. path on disk.
+    pub display_path: PathBuf,
+    /// File content.
+    pub content: String,
+}
+
+/// Provider of `.proto` files used to resolve imports and inputs.
+///
+/// Implemented by:
+/// * [`FsSourceProvider`] for include directories on disk,
+/// * [`MemorySourceProvider`] for files generated or embedded in binary,
+/// * functions `Fn(&Path) -> io::Result<Option<String>>`.
+pub trait SourceProvider {
+    /// Read file by path relative to source root.
+    ///
+    /// Returns `None` if this provider has no such file.
+    fn read(&self, path: &Path) -> io::Result<Option<SourceFile>>;
+}
+
+impl<F> SourceProvider for F
+where
+    F: Fn(&Path) -> io::Result<Option<String>>,
+{
+    fn read(&self, path: &Path) -> io::Result<Option<SourceFile>> {
+        Ok(self(path)?.map(|content| SourceFile {
+            display_path: path.to_owned(),
+            content,
+        }))
+    }
+}
+
+/// Files in include directories on disk, like `protoc -I`.
+#[derive(Debug, Clone, Default)]
+pub struct FsSourceProvider {
+    includes: Vec<PathBuf>,
+}
+
+impl FsSourceProvider {
+    /// Provider searching files in given directories in order.
+    pub fn new(includes: impl IntoIterator<Item = impl AsRef<Path>>) -> FsSourceProvider {
+        FsSourceProvider {
+            includes: includes
+                .into_iter()
+                .map(|i| i.as_ref().to_owned())
+                .collect(),
+        }
+    }
+}
+
+impl SourceProvider for FsSourceProvider {
+    fn read(&self, path: &Path) -> io::Result<Option<SourceFile>> {
+        for include in &self.includes {
+            let fs_path = include.join(path);
+            if fs_path.exists() {
+                let content = fs::read_to_string(&fs_path)?;
+                return Ok(Some(SourceFile {
+                    display_path: fs_path,
+                    content,
+                }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Files stored in memory, e. g. generated at build time
+/// or embedded with `include_str!`.
+#[derive(Debug, Clone, Default)]
+pub struct MemorySourceProvider {
+    files: HashMap<PathBuf, String>,
+}
+
+impl MemorySourceProvider {
+    /// Empty provider.
+    pub fn new() -> MemorySourceProvider {
+        Default::default()
+    }
+
+    /// Add or replace a file.
+    pub fn add(&mut self, path: impl AsRef<Path>, content: impl Into<String>) -> &mut Self {
+        self.files.insert(path.as_ref().to_owned(), content.into());
+        self
+    }
+}
+
+impl<P: AsRef<Path>, S: Into<String>> std::iter::FromIterator<(P, S)> for MemorySourceProvider {
+    fn from_iter<I: IntoIterator<Item = (P, S)>>(iter: I) -> Self {
+        let mut provider = MemorySourceProvider::new();
+        for (path, content) in iter {
+            provider.add(path, content);
+        }
+        provider
+    }
+}
+
+impl SourceProvider for MemorySourceProvider {
+    fn read(&self, path: &Path) -> io::Result<Option<SourceFile>> {
+        Ok(self.files.get(path).map(|content| SourceFile {
+            display_path: path.to_owned(),
+            content: content.clone(),
+        }))
+    }
+}
+
+/// Files bundled with this crate: `rustproto.proto` and well-known types
+/// `google/protobuf/*.proto`.
+///
+/// Codegen searches these files after all other providers,
+/// so they resolve without any include path.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BundledSourceProvider;
+
+impl BundledSourceProvider {
+    /// Content of bundled file.
+    pub fn get(path: &Path) -> Option<&'static str> {
+        let path = path.to_str()?;
+        BUNDLED
+            .iter()
+            .find(|(p, _)| *p == path)
+            .map(|(_, content)| *content)
+    }
+
+    /// Paths of all bundled files.
+    pub fn paths() -> impl Iterator<Item = &'static str> {
+        BUNDLED.iter().map(|(p, _)| *p)
+    }
+}
+
+impl SourceProvider for BundledSourceProvider {
+    fn read(&self, path: &Path) -> io::Result<Option<SourceFile>> {
+        Ok(BundledSourceProvider::get(path).map(|content| SourceFile {
+            display_path: path.to_owned(),
+            content: content.to_owned(),
+        }))
+    }
+}
+
+/// List of additional source providers in [`Codegen`](crate::Codegen).
+#[derive(Default)]
+pub(crate) struct SourceProviders(pub Vec<Box<dyn SourceProvider>>);
+
+impl fmt::Debug for SourceProviders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SourceProviders({})", self.0.len())
+    }
+}
+
+// Copies of files from `proto` directory of repository,
+// updated by `copy-protos.sh`
+const BUNDLED: &[(&str, &str)] = &[
+    ("rustproto.proto", include_str!("proto/rustproto.proto")),
+    (
+        "google/protobuf/any.proto",
+        include_str!("proto/google/protobuf/any.proto"),
+    ),
+    (
+        "google/protobuf/api.proto",
+        include_str!("proto/google/protobuf/api.proto"),
+    ),
+    (
+        "google/protobuf/descriptor.proto",
+        include_str!("proto/google/protobuf/descriptor.proto"),
+    ),
+    (
+        "google/protobuf/duration.proto",
+        include_str!("proto/google/protobuf/duration.proto"),
+    ),
+    (
+        "google/protobuf/empty.proto",
+        include_str!("proto/google/protobuf/empty.proto"),
+    ),
+    (
+        "google/protobuf/field_mask.proto",
+        include_str!("proto/google/protobuf/field_mask.proto"),
+    ),
+    (
+        "google/protobuf/source_context.proto",
+        include_str!("proto/google/protobuf/source_context.proto"),
+    ),
+    (
+        "google/protobuf/struct.proto",
+        include_str!("proto/google/protobuf/struct.proto"),
+    ),
+    (
+        "google/protobuf/timestamp.proto",
+        include_str!("proto/google/protobuf/timestamp.proto"),
+    ),
+    (
+        "google/protobuf/type.proto",
+        include_str!("proto/google/protobuf/type.proto"),
+    ),
+    (
+        "google/protobuf/wrappers.proto",
+        include_str!("proto/google/protobuf/wrappers.proto"),
+    ),
+    (
+        "google/protobuf/compiler/plugin.proto",
+        include_str!("proto/google/protobuf/compiler/plugin.proto"),
+    ),
+];
+
+#[cfg(test)]
+mod test {
+    use std::io;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::parse_and_typecheck_with_sources;
+
+    fn names(sources: &[&dyn SourceProvider], inputs: &[&str]) -> crate::Result<Vec<String>> {
+        let inputs: Vec<PathBuf> = inputs.iter().map(PathBuf::from).collect();
+        let parsed = parse_and_typecheck_with_sources(&[], sources, &inputs)?;
+        Ok(parsed
+            .file_descriptors
+            .iter()
+            .map(|f| f.get_name().to_owned())
+            .collect())
+    }
+
+    #[test]
+    fn bundled_files_up_to_date() {
+        let proto_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../proto");
+        for path in BundledSourceProvider::paths() {
+            let expected = fs::read_to_string(proto_dir.join(path)).unwrap();
+            assert!(
+                BundledSourceProvider::get(Path::new(path)) == Some(&expected[..]),
+                "bundled {} differs from repository file, run copy-protos.sh",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn memory() {
+        let memory: MemorySourceProvider = vec![
+            (
+                "a/a.proto",
+                "syntax = \"proto3\"; import \"b.proto\"; \
+                 import \"google/protobuf/timestamp.proto\"; \
+                 message A { B b = 1; google.protobuf.Timestamp t = 2; }",
+            ),
+            ("b.proto", "syntax = \"proto3\"; message B {}"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            vec!["b.proto", "google/protobuf/timestamp.proto", "a/a.proto"],
+            names(&[&memory], &["a/a.proto"]).unwrap()
+        );
+    }
+
+    #[test]
+    fn function() {
+        let f = |path: &Path| -> io::Result<Option<String>> {
+            Ok(match path.to_str() {
+                Some("a.proto") => Some("message A { optional B b = 1; }".to_owned()),
+                _ => None,
+            })
+        };
+        let e = names(&[&f], &["a.proto"]).unwrap_err();
+        assert_eq!("a.proto:1:13: unknown type \"B\"", format!("{}", e));
+    }
+
+    #[test]
+    fn not_found() {
+        let e = names(&[], &["a.proto"]).unwrap_err();
+        assert_eq!(
+            "file \"a.proto\" does not reside in any include path",
+            format!("{}", e)
+        );
+    }
+
+    #[test]
+    fn bundled() {
+        for path in BundledSourceProvider::paths() {
+            names(&[], &[path]).unwrap();
+        }
+    }
+}
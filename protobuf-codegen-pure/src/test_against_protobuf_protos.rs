@@ -21,7 +21,7 @@ fn parse_recursively(path: &Path) {
             .expect("open")
             .read_to_string(&mut content)
             .expect("read");
-        model::FileDescriptor::parse(&content).expect("parse");
+        model::FileDescriptor::parse_correct(&content);
     }
 }
 
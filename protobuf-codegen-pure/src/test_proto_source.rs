@@ -0,0 +1,254 @@
+//! Test `.proto` source printed from descriptors is parsed back to the same descriptors.
+
+use std::path::PathBuf;
+
+use protobuf::descriptor::source_code_info::Location;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::SourceCodeInfo;
+use protobuf::reflect::FileDescriptor;
+
+use crate::parse_and_typecheck_with_sources;
+use crate::BundledSourceProvider;
+use crate::MemorySourceProvider;
+
+fn parse(sources: &MemorySourceProvider, path: &str) -> Vec<FileDescriptorProto> {
+    parse_and_typecheck_with_sources(&[], &[sources], &[PathBuf::from(path)])
+        .unwrap()
+        .file_descriptors
+}
+
+/// Print the file, parse the output and compare descriptors.
+fn round_trip(mut sources: MemorySourceProvider, path: &str) -> String {
+    let protos = parse(&sources, path);
+    let expected = protos.last().unwrap().clone();
+    let file = FileDescriptor::new_dynamic_fds(protos).pop().unwrap();
+    let printed = file.to_proto_source();
+    sources.add(path, printed.clone());
+    let reparsed = parse(&sources, path).pop().unwrap();
+    assert_eq!(expected, reparsed, "{}", printed);
+    printed
+}
+
+#[test]
+fn bundled() {
+    for path in BundledSourceProvider::paths() {
+        round_trip(MemorySourceProvider::new(), path);
+    }
+}
+
+#[test]
+fn proto2() {
+    let mut sources = MemorySourceProvider::new();
+    sources.add(
+        "opts.proto",
+        r#"
+        syntax = "proto2";
+        package opts;
+        import "google/protobuf/descriptor.proto";
+        message Custom {
+            optional string a = 1;
+            repeated int32 b = 2;
+            optional Custom nested = 3;
+        }
+        enum Color { RED = 0; GREEN = 1; }
+        extend google.protobuf.FileOptions { optional string file_opt = 50000; }
+        extend google.protobuf.MessageOptions { optional bool msg_flag = 50000; }
+        extend google.protobuf.FieldOptions {
+            optional int32 int_opt = 50000;
+            repeated sint64 list_opt = 50001;
+            optional Custom msg_opt = 50002;
+            optional Color color_opt = 50003;
+            optional double double_opt = 50004;
+        }
+        "#,
+    );
+    sources.add(
+        "t.proto",
+        r#"
+        syntax = "proto2";
+        package t.p;
+        import "opts.proto";
+        option java_package = "com.example";
+        option optimize_for = CODE_SIZE;
+        option (opts.file_opt) = "f";
+
+        enum E {
+            option allow_alias = true;
+            A = 0;
+            B = 1;
+            C = 1 [deprecated = true];
+            reserved 5 to 10, 20 to max;
+            reserved "X";
+        }
+
+        message M {
+            option (opts.msg_flag) = true;
+            optional int32 a = 1 [default = -5, (opts.int_opt) = 7,
+                (opts.list_opt) = -1, (opts.list_opt) = 2];
+            required string s = 2 [default = "a\"b\n", json_name = "sss"];
+            optional bytes b = 3 [default = "\001x"];
+            optional E e = 4 [default = B, (opts.color_opt) = GREEN];
+            optional double d = 5 [default = -inf, (opts.double_opt) = 1.5];
+            map<string, M> m = 6 [(opts.msg_opt) = { a: "x" b: 1 b: 2 nested { a: "y" } }];
+            message Nested { optional int32 x = 1; }
+            repeated group G = 7 { optional int32 y = 1; }
+            oneof o {
+                int32 oa = 8;
+                string ob = 9;
+                group OG = 10 { optional int32 z = 1; }
+            }
+            map<int32, E> m2 = 11;
+            repeated int32 packed = 12 [packed = true];
+            extensions 100 to 199, 1000 to max;
+            reserved 13, 15 to 17;
+            reserved "foo";
+            extend M {
+                optional int32 ext = 100;
+                optional group EG = 101 { optional int32 w = 1; }
+            }
+            optional Nested n = 14;
+        }
+
+        extend M {
+            repeated M ext_m = 102;
+        }
+
+        service S {
+            option deprecated = true;
+            rpc A(M) returns (M);
+            rpc B(stream M) returns (stream M) { option deprecated = true; }
+        }
+        "#,
+    );
+    let printed = round_trip(sources, "t.proto");
+    assert!(
+        printed.contains("option (.opts.file_opt) = \"f\";"),
+        "{}",
+        printed
+    );
+    assert!(printed.contains("map<string, .t.p.M> m = 6"), "{}", printed);
+    assert!(printed.contains("repeated group G = 7 {"), "{}", printed);
+}
+
+#[test]
+fn proto3() {
+    let mut sources = MemorySourceProvider::new();
+    sources.add(
+        "t.proto",
+        r#"
+        syntax = "proto3";
+        import "google/protobuf/any.proto";
+        message A {
+            map<string, int32> m1 = 1;
+            message B {}
+            map<int32, A.B> m2 = 2;
+            int32 o = 3;
+            oneof x {
+                string s = 4;
+                B b = 5;
+            }
+            repeated int32 r = 6;
+            google.protobuf.Any any = 7;
+        }
+        enum E { Z = 0; reserved 1; }
+        "#,
+    );
+    let printed = round_trip(sources, "t.proto");
+    assert_eq!(
+        "\
+syntax = \"proto3\";
+
+import \"google/protobuf/any.proto\";
+
+enum E {
+  Z = 0;
+  reserved 1;
+}
+message A {
+  map<string, int32> m1 = 1;
+  message B {
+  }
+  map<int32, .A.B> m2 = 2;
+  int32 o = 3;
+  oneof x {
+    string s = 4;
+    .A.B b = 5;
+  }
+  repeated int32 r = 6;
+  .google.protobuf.Any any = 7;
+}
+",
+        printed
+    );
+}
+
+#[test]
+fn editions() {
+    let mut sources = MemorySourceProvider::new();
+    sources.add(
+        "t.proto",
+        r#"
+        edition = "2023";
+        package ed;
+        option features.field_presence = IMPLICIT;
+        message M {
+            int32 a = 1 [features.field_presence = EXPLICIT];
+            M d = 2 [features.message_encoding = DELIMITED];
+            repeated int32 r = 3 [features.repeated_field_encoding = EXPANDED];
+            reserved foo, bar;
+            enum E { option features.enum_type = CLOSED; X = 1; }
+        }
+        "#,
+    );
+    let printed = round_trip(sources, "t.proto");
+    assert!(printed.starts_with("edition = \"2023\";"), "{}", printed);
+    assert!(printed.contains("reserved foo, bar;"), "{}", printed);
+}
+
+#[test]
+fn comments() {
+    let mut sources = MemorySourceProvider::new();
+    sources.add(
+        "t.proto",
+        "syntax = \"proto3\"; package p; message M { int32 a = 1; }",
+    );
+    let mut proto = parse(&sources, "t.proto").pop().unwrap();
+    let location = |path: &[i32], leading: &str, trailing: &str, detached: &[&str]| {
+        let mut location = Location::new();
+        location.path = path.to_vec();
+        if !leading.is_empty() {
+            location.set_leading_comments(leading.to_owned());
+        }
+        if !trailing.is_empty() {
+            location.set_trailing_comments(trailing.to_owned());
+        }
+        location.leading_detached_comments = detached.iter().map(|c| c.to_string()).collect();
+        location
+    };
+    let mut source_code_info = SourceCodeInfo::new();
+    source_code_info.location = vec![
+        location(&[12], " header\n", "", &[]),
+        location(&[4, 0], " M\n doc\n", "", &[" detached\n"]),
+        location(&[4, 0, 2, 0], "", " a doc\n", &[]),
+    ];
+    proto.source_code_info = Some(source_code_info).into();
+    let file = FileDescriptor::new_dynamic(proto, Vec::new());
+    assert_eq!(
+        "\
+// header
+syntax = \"proto3\";
+
+package p;
+
+// detached
+
+// M
+// doc
+message M {
+  int32 a = 1;
+  // a doc
+}
+",
+        file.to_proto_source()
+    );
+}
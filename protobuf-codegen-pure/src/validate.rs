@@ -0,0 +1,580 @@
+//! Semantic checks performed by `protoc` which are not enforced by the parser.
+//!
+//! Extension numbers are checked during conversion, where extendee is resolved.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use protobuf::reflect::features::EnumType;
+use protobuf::reflect::features::ResolvedFeatures;
+use protobuf::text_format::lexer::Loc;
+
+use crate::convert::ConvertError;
+use crate::convert::ConvertResult;
+use crate::convert::ProtobufOptions;
+use crate::features;
+use crate::model;
+
+/// Max field number.
+pub(crate) const FIELD_NUMBER_MAX: i32 = 0x20000000 - 1;
+/// Field numbers reserved for protobuf implementation.
+const RESERVED_FIELD_NUMBERS: (i32, i32) = (19000, 19999);
+
+/// Violation of `.proto` file semantic rules.
+#[derive(Debug)]
+pub enum ValidationError {
+    /// Field number is not in range `1..=536870911`.
+    FieldNumberOutOfRange(String, i32),
+    /// Field number is in range `19000..=19999` reserved for protobuf implementation.
+    FieldNumberReservedForImplementation(String, i32),
+    /// Field number is already used by another field of the message.
+    DuplicateFieldNumber {
+        /// Field name
+        field: String,
+        /// Name of field which uses the number
+        previous: String,
+        /// Field number
+        number: i32,
+    },
+    /// Symbol is already defined in the scope.
+    DuplicateName {
+        /// Symbol name
+        name: String,
+        /// Package or message full name
+        scope: String,
+    },
+    /// Field uses number declared `reserved`.
+    ReservedNumber(String, i32),
+    /// Field uses name declared `reserved`.
+    ReservedName(String),
+    /// Enum value uses number declared `reserved`.
+    EnumValueReservedNumber(String, i32),
+    /// Enum value uses name declared `reserved`.
+    EnumValueReservedName(String),
+    /// Extension number is not in `extensions` ranges of extendee.
+    ExtensionNumberNotDeclared {
+        /// Extendee full name
+        extendee: String,
+        /// Extension number
+        number: i32,
+    },
+    /// Enum has no values.
+    EmptyEnum(String),
+    /// First value of open enum (e. g. proto3 enum) is not zero.
+    FirstEnumValueNotZero(String),
+    /// Enum value number is used by another value while `allow_alias` is not set.
+    EnumValueAlias {
+        /// Value name
+        value: String,
+        /// Name of value with the same number
+        alias_of: String,
+    },
+    /// Enum sets `allow_alias` but has no aliases.
+    UnnecessaryAllowAlias(String),
+    /// Map key is floating point, bytes, message or enum.
+    InvalidMapKeyType(String),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::FieldNumberOutOfRange(field, number) => write!(
+                f,
+                "field \"{}\" number {} is not in range 1 to {}",
+                field, number, FIELD_NUMBER_MAX
+            ),
+            ValidationError::FieldNumberReservedForImplementation(field, number) => write!(
+                f,
+                "field \"{}\" number {} is in range {} to {} reserved for protocol buffer library implementation",
+                field, number, RESERVED_FIELD_NUMBERS.0, RESERVED_FIELD_NUMBERS.1
+            ),
+            ValidationError::DuplicateFieldNumber {
+                field,
+                previous,
+                number,
+            } => write!(
+                f,
+                "field \"{}\" number {} has already been used by field \"{}\"",
+                field, number, previous
+            ),
+            ValidationError::DuplicateName { name, scope } if scope.is_empty() => {
+                write!(f, "\"{}\" is already defined", name)
+            }
+            ValidationError::DuplicateName { name, scope } => {
+                write!(f, "\"{}\" is already defined in \"{}\"", name, scope)
+            }
+            ValidationError::ReservedNumber(field, number) => {
+                write!(f, "field \"{}\" uses reserved number {}", field, number)
+            }
+            ValidationError::ReservedName(field) => {
+                write!(f, "field name \"{}\" is reserved", field)
+            }
+            ValidationError::EnumValueReservedNumber(value, number) => {
+                write!(f, "enum value \"{}\" uses reserved number {}", value, number)
+            }
+            ValidationError::EnumValueReservedName(value) => {
+                write!(f, "enum value name \"{}\" is reserved", value)
+            }
+            ValidationError::ExtensionNumberNotDeclared { extendee, number } => write!(
+                f,
+                "\"{}\" does not declare {} as an extension number",
+                extendee, number
+            ),
+            ValidationError::EmptyEnum(e) => write!(f, "enum \"{}\" must contain a value", e),
+            ValidationError::FirstEnumValueNotZero(e) => {
+                write!(f, "first value of open enum \"{}\" must be zero", e)
+            }
+            ValidationError::EnumValueAlias { value, alias_of } => write!(
+                f,
+                "\"{}\" uses the same enum value as \"{}\"; \
+                 set `option allow_alias = true;` if this is intended",
+                value, alias_of
+            ),
+            ValidationError::UnnecessaryAllowAlias(e) => write!(
+                f,
+                "enum \"{}\" sets `allow_alias` but no values share numbers",
+                e
+            ),
+            ValidationError::InvalidMapKeyType(field) => write!(
+                f,
+                "key of map field \"{}\" cannot be float, double, bytes, message or enum",
+                field
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+fn error<T>(loc: Loc, e: ValidationError) -> ConvertResult<T> {
+    Err(ConvertError::Validation(e).with_loc(loc))
+}
+
+fn join_name(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", scope, name)
+    }
+}
+
+/// Names defined in package or message.
+struct Scope<'a> {
+    name: String,
+    symbols: HashMap<&'a str, ()>,
+}
+
+impl<'a> Scope<'a> {
+    fn new(name: String) -> Scope<'a> {
+        Scope {
+            name,
+            symbols: HashMap::new(),
+        }
+    }
+
+    fn define(&mut self, name: &'a str, loc: Loc) -> ConvertResult<()> {
+        if self.symbols.insert(name, ()).is_some() {
+            return error(
+                loc,
+                ValidationError::DuplicateName {
+                    name: name.to_owned(),
+                    scope: self.name.clone(),
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Enum values are siblings of their enum, like in C++.
+    fn define_enum(&mut self, e: &'a model::WithLoc<model::Enumeration>) -> ConvertResult<()> {
+        self.define(&e.t.name, e.loc)?;
+        for v in &e.t.values {
+            self.define(&v.t.name, v.loc)?;
+        }
+        Ok(())
+    }
+}
+
+fn field_number(field: &model::WithLoc<model::Field>) -> ConvertResult<()> {
+    let number = field.t.number;
+    if number < 1 || number > FIELD_NUMBER_MAX {
+        return error(
+            field.loc,
+            ValidationError::FieldNumberOutOfRange(field.t.name.clone(), number),
+        );
+    }
+    if number >= RESERVED_FIELD_NUMBERS.0 && number <= RESERVED_FIELD_NUMBERS.1 {
+        return error(
+            field.loc,
+            ValidationError::FieldNumberReservedForImplementation(field.t.name.clone(), number),
+        );
+    }
+    Ok(())
+}
+
+fn map_key_type(field: &model::WithLoc<model::Field>) -> ConvertResult<()> {
+    if let model::FieldType::Map(ref t) = field.t.typ {
+        match t.0 {
+            model::FieldType::Float
+            | model::FieldType::Double
+            | model::FieldType::Bytes
+            | model::FieldType::MessageOrEnum(..)
+            | model::FieldType::Map(..)
+            | model::FieldType::Group(..) => {
+                return error(
+                    field.loc,
+                    ValidationError::InvalidMapKeyType(field.t.name.clone()),
+                );
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Fields of message or group.
+struct Fields<'a> {
+    scope: Scope<'a>,
+    numbers: HashMap<i32, &'a str>,
+    reserved_nums: &'a [model::FieldNumberRange],
+    reserved_names: &'a [String],
+}
+
+impl<'a> Fields<'a> {
+    fn field(&mut self, field: &'a model::WithLoc<model::Field>) -> ConvertResult<()> {
+        let name = &field.t.name;
+        let number = field.t.number;
+        self.scope.define(name, field.loc)?;
+        field_number(field)?;
+        if let Some(previous) = self.numbers.insert(number, name) {
+            return error(
+                field.loc,
+                ValidationError::DuplicateFieldNumber {
+                    field: name.clone(),
+                    previous: previous.to_owned(),
+                    number,
+                },
+            );
+        }
+        if self
+            .reserved_nums
+            .iter()
+            .any(|r| r.from <= number && number <= r.to)
+        {
+            return error(
+                field.loc,
+                ValidationError::ReservedNumber(name.clone(), number),
+            );
+        }
+        if self.reserved_names.iter().any(|n| n == name) {
+            return error(field.loc, ValidationError::ReservedName(name.clone()));
+        }
+        map_key_type(field)?;
+
+        if let model::FieldType::Group(ref g) = field.t.typ {
+            self.scope.define(&g.name, field.loc)?;
+            let mut group = Fields {
+                scope: Scope::new(join_name(&self.scope.name, &g.name)),
+                numbers: HashMap::new(),
+                reserved_nums: &g.reserved_nums,
+                reserved_names: &g.reserved_names,
+            };
+            for f in &g.fields {
+                group.field(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn enumeration(
+    e: &model::WithLoc<model::Enumeration>,
+    scope: &str,
+    file: &model::FileDescriptor,
+    parent_features: &ResolvedFeatures,
+) -> ConvertResult<()> {
+    let name = join_name(scope, &e.t.name);
+    let first = match e.t.values.first() {
+        Some(first) => first,
+        None => return error(e.loc, ValidationError::EmptyEnum(name)),
+    };
+    let features = parent_features.merge(
+        &features::feature_set(file.syntax, &e.t.options).map_err(|err| err.with_loc(e.loc))?,
+    );
+    if features.enum_type == EnumType::Open && first.t.number != 0 {
+        return error(first.loc, ValidationError::FirstEnumValueNotZero(name));
+    }
+
+    let allow_alias =
+        e.t.options
+            .as_slice()
+            .by_name_bool("allow_alias")
+            .map_err(|err| err.with_loc(e.loc))?
+            .unwrap_or(false);
+    let mut numbers = HashMap::new();
+    let mut has_aliases = false;
+    for v in &e.t.values {
+        if e.t
+            .reserved_nums
+            .iter()
+            .any(|r| r.from <= v.t.number && v.t.number <= r.to)
+        {
+            return error(
+                v.loc,
+                ValidationError::EnumValueReservedNumber(v.t.name.clone(), v.t.number),
+            );
+        }
+        if e.t.reserved_names.contains(&v.t.name) {
+            return error(
+                v.loc,
+                ValidationError::EnumValueReservedName(v.t.name.clone()),
+            );
+        }
+        if let Some(alias_of) = numbers.insert(v.t.number, &v.t.name) {
+            if !allow_alias {
+                return error(
+                    v.loc,
+                    ValidationError::EnumValueAlias {
+                        value: v.t.name.clone(),
+                        alias_of: alias_of.clone(),
+                    },
+                );
+            }
+            has_aliases = true;
+        }
+    }
+    if allow_alias && !has_aliases {
+        return error(e.loc, ValidationError::UnnecessaryAllowAlias(name));
+    }
+    Ok(())
+}
+
+fn message(
+    m: &model::WithLoc<model::Message>,
+    scope: &str,
+    file: &model::FileDescriptor,
+    parent_features: &ResolvedFeatures,
+) -> ConvertResult<()> {
+    let name = join_name(scope, &m.t.name);
+    let features = parent_features.merge(
+        &features::feature_set(file.syntax, &m.t.options).map_err(|err| err.with_loc(m.loc))?,
+    );
+    let mut fields = Fields {
+        scope: Scope::new(name.clone()),
+        numbers: HashMap::new(),
+        reserved_nums: &m.t.reserved_nums,
+        reserved_names: &m.t.reserved_names,
+    };
+
+    for f in &m.t.fields {
+        match &f.t {
+            model::FieldOrOneOf::Field(field) => fields.field(field)?,
+            model::FieldOrOneOf::OneOf(oneof) => {
+                fields.scope.define(&oneof.name, f.loc)?;
+                for field in &oneof.fields {
+                    fields.field(field)?;
+                }
+            }
+        }
+    }
+
+    let scope = &mut fields.scope;
+    for nested in &m.t.messages {
+        scope.define(&nested.t.name, nested.loc)?;
+        message(nested, &name, file, &features)?;
+    }
+    for e in &m.t.enums {
+        scope.define_enum(e)?;
+        enumeration(e, &name, file, &features)?;
+    }
+    for ext in &m.t.extensions {
+        scope.define(&ext.t.field.t.name, ext.t.field.loc)?;
+        field_number(&ext.t.field)?;
+    }
+    Ok(())
+}
+
+/// Check rules `protoc` enforces on a parsed file.
+pub(crate) fn file(input: &model::FileDescriptor) -> ConvertResult<()> {
+    let package = input.package.clone().unwrap_or_default();
+    let mut scope = Scope::new(package.clone());
+    let features = features::file_features(input)?;
+
+    for m in &input.messages {
+        scope.define(&m.t.name, m.loc)?;
+        message(m, &package, input, &features)?;
+    }
+    for e in &input.enums {
+        scope.define_enum(e)?;
+        enumeration(e, &package, input, &features)?;
+    }
+    for ext in &input.extensions {
+        scope.define(&ext.t.field.t.name, ext.t.field.loc)?;
+        field_number(&ext.t.field)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::*;
+
+    fn validate(content: &str) -> Result<(), String> {
+        let parsed = model::FileDescriptor::parse_correct(content);
+        file(&parsed).map_err(|e| match e {
+            ConvertError::WithLoc(loc, e) => format!("{}: {}", loc, e),
+            e => format!("{}", e),
+        })
+    }
+
+    #[test]
+    fn valid() {
+        validate(
+            r#"syntax = "proto2";
+            package p;
+            message A {
+                reserved 5 to 10;
+                reserved "x";
+                optional int32 a = 1;
+                oneof o { string b = 2; }
+                map<string, A> m = 3;
+                optional group G = 4 { optional int32 a = 1; }
+                enum E { option allow_alias = true; X = 1; Y = 1; }
+                extensions 100 to max;
+            }
+            enum F { Z = 1; }
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn duplicates() {
+        assert_eq!(
+            Err("4:17: field \"b\" number 1 has already been used by field \"a\"".to_owned()),
+            validate(
+                "syntax = \"proto2\";
+                message A {
+                optional int32 a = 1;
+                optional int32 b = 1;
+                }"
+            )
+        );
+        assert_eq!(
+            Err("3:40: \"a\" is already defined in \"p.A\"".to_owned()),
+            validate(
+                "syntax = \"proto3\"; package p;
+                message A {
+                int32 a = 1; oneof o { string a = 2; }
+                }"
+            )
+        );
+        assert_eq!(
+            Err("2:51: \"X\" is already defined".to_owned()),
+            validate(
+                "syntax = \"proto3\";
+                enum E { X = 0; } enum F { Y = 0; X = 1; }"
+            )
+        );
+    }
+
+    #[test]
+    fn field_numbers() {
+        assert_eq!(
+            Err("1:32: field \"a\" number 0 is not in range 1 to 536870911".to_owned()),
+            validate("syntax = \"proto3\"; message A { int32 a = 0; }")
+        );
+        assert_eq!(
+            Err("1:32: field \"a\" number 19500 is in range 19000 to 19999 \
+                 reserved for protocol buffer library implementation"
+                .to_owned()),
+            validate("syntax = \"proto3\"; message A { int32 a = 19500; }")
+        );
+        assert_eq!(
+            Err("1:49: field \"a\" uses reserved number 3".to_owned()),
+            validate("syntax = \"proto3\"; message A { reserved 2 to 4; int32 a = 3; }")
+        );
+        assert_eq!(
+            Err("1:46: field name \"a\" is reserved".to_owned()),
+            validate("syntax = \"proto3\"; message A { reserved \"a\"; int32 a = 1; }")
+        );
+        assert_eq!(
+            Err("1:67: field \"b\" uses reserved number 2".to_owned()),
+            validate(
+                "syntax = \"proto2\"; message A { \
+                 optional group G = 1 { reserved 2; optional int32 b = 2; } }"
+            )
+        );
+        assert_eq!(
+            Err("1:69: field name \"b\" is reserved".to_owned()),
+            validate(
+                "syntax = \"proto2\"; message A { \
+                 optional group G = 1 { reserved \"b\"; optional int32 b = 2; } }"
+            )
+        );
+    }
+
+    #[test]
+    fn enums() {
+        assert_eq!(
+            Err("1:29: first value of open enum \"E\" must be zero".to_owned()),
+            validate("syntax = \"proto3\"; enum E { A = 1; }")
+        );
+        assert_eq!(
+            Err("1:36: \"B\" uses the same enum value as \"A\"; \
+                 set `option allow_alias = true;` if this is intended"
+                .to_owned()),
+            validate("syntax = \"proto3\"; enum E { A = 0; B = 0; }")
+        );
+        assert_eq!(
+            Err("1:20: enum \"E\" sets `allow_alias` but no values share numbers".to_owned()),
+            validate("syntax = \"proto3\"; enum E { option allow_alias = true; A = 0; }")
+        );
+        assert_eq!(
+            Err("1:53: enum value \"B\" uses reserved number 2".to_owned()),
+            validate("syntax = \"proto3\"; enum E { reserved 1 to 3; A = 0; B = 2; }")
+        );
+        assert_eq!(
+            Err("1:50: enum value name \"B\" is reserved".to_owned()),
+            validate("syntax = \"proto3\"; enum E { reserved \"B\"; A = 0; B = 1; }")
+        );
+        assert_eq!(
+            Err("1:28: first value of open enum \"E\" must be zero".to_owned()),
+            validate("edition = \"2023\"; enum E { A = 1; }")
+        );
+        assert_eq!(
+            Ok(()),
+            validate(
+                "edition = \"2023\"; option features.enum_type = CLOSED; \
+                 message M { enum E { A = 1; } }"
+            )
+        );
+    }
+
+    #[test]
+    fn map_key() {
+        assert_eq!(
+            Err(
+                "1:32: key of map field \"m\" cannot be float, double, bytes, message or enum"
+                    .to_owned()
+            ),
+            validate("syntax = \"proto3\"; message A { map<double, int32> m = 1; }")
+        );
+    }
+
+    #[test]
+    fn extension_number() {
+        let parsed = model::FileDescriptor::parse_correct(
+            "syntax = \"proto2\"; package p;
+            message A { extensions 10 to 20; }
+            extend A { optional int32 x = 21; }",
+        );
+        let e = crate::convert::file_descriptor(Path::new("a.proto"), &parsed, &[]).unwrap_err();
+        assert_eq!(
+            "\"p.A\" does not declare 21 as an extension number",
+            format!("{}", e)
+        );
+    }
+}
@@ -1,8 +1,18 @@
+use std::collections::BTreeMap;
+use std::fmt;
+#[cfg(feature = "with-customize-config")]
+use std::io;
+#[cfg(feature = "with-customize-config")]
+use std::path::Path;
+use std::sync::Arc;
+
 use protobuf::descriptor::FieldOptions;
 use protobuf::descriptor::FileOptions;
 use protobuf::descriptor::MessageOptions;
 use protobuf::rustproto;
 
+use crate::customize_config::CustomizeConfig;
+
 /// Specifies style of generated code.
 /// Generated files can be customized using this proto
 /// or using `rustproto.proto` options.
@@ -24,6 +34,13 @@ pub struct Customize {
     pub serde_derive: Option<bool>,
     /// When `serde_derive` is set, serde annotations will be guarded with `#[cfg(cfg, ...)]`.
     pub serde_derive_cfg: Option<String>,
+    /// Implement `serde::Serialize` and `serde::Deserialize` for messages
+    /// following proto3 JSON mapping (like `protobuf::json` does)
+    /// instead of deriving them. `protobuf` must be built with `with-serde` feature.
+    ///
+    /// Serde implementations are guarded with `serde_derive_cfg` if it is set.
+    /// This option is ignored with lite runtime.
+    pub serde_json_mapping: Option<bool>,
     /// Enable lite runtime
     pub lite_runtime: Option<bool>,
     /// Generate `mod.rs` in the output directory.
@@ -35,8 +52,19 @@ pub struct Customize {
     /// Used internally to generate protos bundled in protobuf crate
     /// like `descriptor.proto`
     pub inside_protobuf: Option<bool>,
+    /// Store fields of given message types as custom Rust types.
+    ///
+    /// Keys are fully qualified message names (e.g. `google.protobuf.Timestamp`),
+    /// values are Rust type paths (e.g. `std::time::SystemTime`).
+    /// Rust types must implement `protobuf::MessageConvert` for that message.
+    pub type_mapping: Option<BTreeMap<String, String>>,
+    /// Customization of individual files, messages and fields
+    /// without changing `.proto` files, usually loaded from a config file.
+    ///
+    /// Rules are applied after `rustproto` options of the same level.
+    pub config: Option<Arc<CustomizeConfig>>,
 
-    // When adding more options please keep in sync with `parse_from_parameter` below.
+    // When adding more options please keep in sync with `set_option` below.
     /// Make sure `Customize` is always used with `..Default::default()`
     /// for future compatibility.
     pub _future_options: (),
@@ -49,6 +77,20 @@ pub enum CustomizeParseParameterError {
     UnknownOptionName(String),
 }
 
+impl fmt::Display for CustomizeParseParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CustomizeParseParameterError::EqNotFound => write!(f, "`=` not found"),
+            CustomizeParseParameterError::CannotParseBool => write!(f, "cannot parse bool"),
+            CustomizeParseParameterError::UnknownOptionName(n) => {
+                write!(f, "unknown option name: {}", n)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CustomizeParseParameterError {}
+
 pub type CustomizeParseParameterResult<T> = Result<T, CustomizeParseParameterError>;
 
 impl Customize {
@@ -78,6 +120,9 @@ impl Customize {
         if let Some(ref v) = that.serde_derive_cfg {
             self.serde_derive_cfg = Some(v.clone());
         }
+        if let Some(v) = that.serde_json_mapping {
+            self.serde_json_mapping = Some(v);
+        }
         if let Some(v) = that.lite_runtime {
             self.lite_runtime = Some(v);
         }
@@ -87,6 +132,23 @@ impl Customize {
         if let Some(v) = that.inside_protobuf {
             self.inside_protobuf = Some(v);
         }
+        if let Some(ref v) = that.type_mapping {
+            self.type_mapping
+                .get_or_insert_with(BTreeMap::new)
+                .extend(v.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        if let Some(ref v) = that.config {
+            self.config = Some(v.clone());
+        }
+    }
+
+    /// Rust type configured for a message with given fully qualified name.
+    pub(crate) fn mapped_type(&self, message_full_name: &str) -> Option<&str> {
+        let name = message_full_name.trim_start_matches('.');
+        self.type_mapping
+            .as_ref()
+            .and_then(|m| m.get(name))
+            .map(|s| s.as_str())
     }
 
     /// Update unset fields of self with fields from other customize
@@ -96,15 +158,33 @@ impl Customize {
         *self = tmp;
     }
 
-    /// Parse customize options from a string passed via protoc flag.
-    pub fn parse_from_parameter(parameter: &str) -> CustomizeParseParameterResult<Customize> {
-        fn parse_bool(v: &str) -> CustomizeParseParameterResult<bool> {
-            v.parse()
-                .map_err(|_| CustomizeParseParameterError::CannotParseBool)
+    /// Copy of this customize with [`config`](Customize::config) loaded
+    /// from a `.toml` or `.json` file, if the file is specified.
+    #[cfg(feature = "with-customize-config")]
+    pub fn with_config_file(&self, config_file: Option<&Path>) -> io::Result<Customize> {
+        let mut customize = self.clone();
+        if let Some(path) = config_file {
+            let config = CustomizeConfig::from_file(path).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("failed to load {}: {}", path.display(), e),
+                )
+            })?;
+            customize.config = Some(Arc::new(config));
         }
+        Ok(customize)
+    }
 
+    /// Parse customize options from a string passed via protoc flag.
+    ///
+    /// Options are separated by commas, like `protoc` joins parameters
+    /// of `--rust_out` and `--rust_opt` flags, or by whitespace.
+    pub fn parse_from_parameter(parameter: &str) -> CustomizeParseParameterResult<Customize> {
         let mut r = Customize::default();
-        for nv in parameter.split_whitespace() {
+        for nv in parameter
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|nv| !nv.is_empty())
+        {
             let eq = match nv.find('=') {
                 Some(eq) => eq,
                 None => return Err(CustomizeParseParameterError::EqNotFound),
@@ -113,36 +193,59 @@ impl Customize {
             let n = &nv[..eq];
             let v = &nv[eq + 1..];
 
-            if n == "expose_oneof" {
-                r.expose_oneof = Some(parse_bool(v)?);
-            } else if n == "expose_fields" {
-                r.expose_fields = Some(parse_bool(v)?);
-            } else if n == "generate_accessors" {
-                r.generate_accessors = Some(parse_bool(v)?);
-            } else if n == "generate_getter" {
-                r.generate_getter = Some(parse_bool(v)?);
-            } else if n == "carllerche_bytes_for_bytes" {
-                r.carllerche_bytes_for_bytes = Some(parse_bool(v)?);
-            } else if n == "carllerche_bytes_for_string" {
-                r.carllerche_bytes_for_string = Some(parse_bool(v)?);
-            } else if n == "serde_derive" {
-                r.serde_derive = Some(parse_bool(v)?);
-            } else if n == "serde_derive_cfg" {
-                r.serde_derive_cfg = Some(v.to_owned());
-            } else if n == "lite_runtime" {
-                r.lite_runtime = Some(parse_bool(v)?);
-            } else if n == "gen_mod_rs" {
-                r.gen_mod_rs = Some(parse_bool(v)?);
-            } else if n == "inside_protobuf" {
-                r.inside_protobuf = Some(parse_bool(v)?);
-            } else {
-                return Err(CustomizeParseParameterError::UnknownOptionName(
-                    n.to_owned(),
-                ));
-            }
+            r.set_option(n, v)?;
         }
         Ok(r)
     }
+
+    /// Set option by name, value is parsed like in `parse_from_parameter`.
+    pub(crate) fn set_option(&mut self, n: &str, v: &str) -> CustomizeParseParameterResult<()> {
+        fn parse_bool(v: &str) -> CustomizeParseParameterResult<bool> {
+            v.parse()
+                .map_err(|_| CustomizeParseParameterError::CannotParseBool)
+        }
+
+        if n == "expose_oneof" {
+            self.expose_oneof = Some(parse_bool(v)?);
+        } else if n == "expose_fields" {
+            self.expose_fields = Some(parse_bool(v)?);
+        } else if n == "generate_accessors" {
+            self.generate_accessors = Some(parse_bool(v)?);
+        } else if n == "generate_getter" {
+            self.generate_getter = Some(parse_bool(v)?);
+        } else if n == "carllerche_bytes_for_bytes" {
+            self.carllerche_bytes_for_bytes = Some(parse_bool(v)?);
+        } else if n == "carllerche_bytes_for_string" {
+            self.carllerche_bytes_for_string = Some(parse_bool(v)?);
+        } else if n == "serde_derive" {
+            self.serde_derive = Some(parse_bool(v)?);
+        } else if n == "serde_derive_cfg" {
+            self.serde_derive_cfg = Some(v.to_owned());
+        } else if n == "serde_json_mapping" {
+            self.serde_json_mapping = Some(parse_bool(v)?);
+        } else if n == "lite_runtime" {
+            self.lite_runtime = Some(parse_bool(v)?);
+        } else if n == "gen_mod_rs" {
+            self.gen_mod_rs = Some(parse_bool(v)?);
+        } else if n == "inside_protobuf" {
+            self.inside_protobuf = Some(parse_bool(v)?);
+        } else if n == "type_mapping" {
+            // `type_mapping=google.protobuf.Timestamp=std::time::SystemTime`
+            let eq = match v.find('=') {
+                Some(eq) => eq,
+                None => return Err(CustomizeParseParameterError::EqNotFound),
+            };
+            self.type_mapping.get_or_insert_with(BTreeMap::new).insert(
+                v[..eq].trim_start_matches('.').to_owned(),
+                v[eq + 1..].to_owned(),
+            );
+        } else {
+            return Err(CustomizeParseParameterError::UnknownOptionName(
+                n.to_owned(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 pub fn customize_from_rustproto_for_message(source: &MessageOptions) -> Customize {
@@ -154,9 +257,12 @@ pub fn customize_from_rustproto_for_message(source: &MessageOptions) -> Customiz
     let carllerche_bytes_for_string = rustproto::exts::carllerche_bytes_for_string.get(source);
     let serde_derive = rustproto::exts::serde_derive.get(source);
     let serde_derive_cfg = rustproto::exts::serde_derive_cfg.get(source);
+    let serde_json_mapping = None;
     let lite_runtime = None;
     let gen_mod_rs = None;
     let inside_protobuf = None;
+    let type_mapping = None;
+    let config = None;
     Customize {
         expose_oneof,
         expose_fields,
@@ -166,9 +272,12 @@ pub fn customize_from_rustproto_for_message(source: &MessageOptions) -> Customiz
         carllerche_bytes_for_string,
         serde_derive,
         serde_derive_cfg,
+        serde_json_mapping,
         lite_runtime,
         gen_mod_rs,
         inside_protobuf,
+        type_mapping,
+        config,
         _future_options: (),
     }
 }
@@ -183,9 +292,12 @@ pub fn customize_from_rustproto_for_field(source: &FieldOptions) -> Customize {
         rustproto::exts::carllerche_bytes_for_string_field.get(source);
     let serde_derive = None;
     let serde_derive_cfg = None;
+    let serde_json_mapping = None;
     let lite_runtime = None;
     let gen_mod_rs = None;
     let inside_protobuf = None;
+    let type_mapping = None;
+    let config = None;
     Customize {
         expose_oneof,
         expose_fields,
@@ -195,9 +307,12 @@ pub fn customize_from_rustproto_for_field(source: &FieldOptions) -> Customize {
         carllerche_bytes_for_string,
         serde_derive,
         serde_derive_cfg,
+        serde_json_mapping,
         lite_runtime,
         gen_mod_rs,
         inside_protobuf,
+        type_mapping,
+        config,
         _future_options: (),
     }
 }
@@ -211,9 +326,12 @@ pub fn customize_from_rustproto_for_file(source: &FileOptions) -> Customize {
     let carllerche_bytes_for_string = rustproto::exts::carllerche_bytes_for_string_all.get(source);
     let serde_derive = rustproto::exts::serde_derive_all.get(source);
     let serde_derive_cfg = rustproto::exts::serde_derive_cfg_all.get(source);
+    let serde_json_mapping = None;
     let lite_runtime = rustproto::exts::lite_runtime_all.get(source);
     let gen_mod_rs = None;
     let inside_protobuf = None;
+    let type_mapping = None;
+    let config = None;
     Customize {
         expose_oneof,
         expose_fields,
@@ -223,9 +341,50 @@ pub fn customize_from_rustproto_for_file(source: &FileOptions) -> Customize {
         carllerche_bytes_for_string,
         serde_derive,
         serde_derive_cfg,
+        serde_json_mapping,
         lite_runtime,
         inside_protobuf,
         gen_mod_rs,
+        type_mapping,
+        config,
         _future_options: (),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Customize;
+
+    #[test]
+    fn parse_type_mapping() {
+        let c = Customize::parse_from_parameter(
+            "type_mapping=google.protobuf.Timestamp=std::time::SystemTime \
+             type_mapping=.google.protobuf.Duration=std::time::Duration",
+        )
+        .unwrap();
+        assert_eq!(
+            Some("std::time::SystemTime"),
+            c.mapped_type(".google.protobuf.Timestamp")
+        );
+        assert_eq!(
+            Some("std::time::Duration"),
+            c.mapped_type("google.protobuf.Duration")
+        );
+        assert_eq!(None, c.mapped_type("google.protobuf.Any"));
+
+        assert!(Customize::parse_from_parameter("type_mapping=foo.Bar").is_err());
+    }
+
+    #[test]
+    fn parse_separators() {
+        let c = Customize::parse_from_parameter("expose_oneof=true,serde_derive=false").unwrap();
+        assert_eq!(Some(true), c.expose_oneof);
+        assert_eq!(Some(false), c.serde_derive);
+
+        let c = Customize::parse_from_parameter(" lite_runtime=true  gen_mod_rs=false ,").unwrap();
+        assert_eq!(Some(true), c.lite_runtime);
+        assert_eq!(Some(false), c.gen_mod_rs);
+
+        assert!(Customize::parse_from_parameter("expose_oneof=true,x").is_err());
+    }
+}
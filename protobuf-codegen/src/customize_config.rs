@@ -0,0 +1,404 @@
+//! Codegen customization loaded from a TOML or JSON file.
+
+use std::error::Error;
+use std::fmt;
+#[cfg(feature = "with-customize-config")]
+use std::fs;
+use std::io;
+#[cfg(feature = "with-customize-config")]
+use std::path::Path;
+
+use crate::customize::Customize;
+
+/// Error loading [`CustomizeConfig`].
+#[derive(Debug)]
+pub enum CustomizeConfigError {
+    /// Failed to read config file.
+    Io(io::Error),
+    /// Config file is not valid TOML.
+    #[cfg(feature = "with-customize-config")]
+    Toml(toml::de::Error),
+    /// Config file is not valid JSON.
+    #[cfg(feature = "with-customize-config")]
+    Json(serde_json::Error),
+    /// File extension is neither `.toml` nor `.json`.
+    UnknownFormat(String),
+    /// Config file is well-formed, but does not describe customize rules.
+    Invalid(String),
+}
+
+impl fmt::Display for CustomizeConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CustomizeConfigError::Io(e) => write!(f, "{}", e),
+            #[cfg(feature = "with-customize-config")]
+            CustomizeConfigError::Toml(e) => write!(f, "{}", e),
+            #[cfg(feature = "with-customize-config")]
+            CustomizeConfigError::Json(e) => write!(f, "{}", e),
+            CustomizeConfigError::UnknownFormat(file) => write!(
+                f,
+                "cannot detect config format of {}, expecting .toml or .json file",
+                file
+            ),
+            CustomizeConfigError::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for CustomizeConfigError {}
+
+impl From<io::Error> for CustomizeConfigError {
+    fn from(e: io::Error) -> Self {
+        CustomizeConfigError::Io(e)
+    }
+}
+
+#[cfg(feature = "with-customize-config")]
+impl From<toml::de::Error> for CustomizeConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        CustomizeConfigError::Toml(e)
+    }
+}
+
+#[cfg(feature = "with-customize-config")]
+impl From<serde_json::Error> for CustomizeConfigError {
+    fn from(e: serde_json::Error) -> Self {
+        CustomizeConfigError::Json(e)
+    }
+}
+
+impl From<CustomizeConfigError> for io::Error {
+    fn from(e: CustomizeConfigError) -> Self {
+        match e {
+            CustomizeConfigError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::Other, e),
+        }
+    }
+}
+
+#[cfg(feature = "with-customize-config")]
+type CustomizeConfigResult<T> = Result<T, CustomizeConfigError>;
+
+#[derive(Debug, Clone)]
+struct CustomizeRule {
+    file: Option<String>,
+    path: Option<String>,
+    customize: Customize,
+}
+
+/// Customize options assigned to files, messages and fields by globs.
+///
+/// Rules can be added with [`add_rule`](CustomizeConfig::add_rule), or loaded
+/// from a file when `with-customize-config` feature is enabled.
+///
+/// Config file is a list of rules. Each rule optionally selects files
+/// by a glob over `.proto` paths (relative to include directory, separated by `/`)
+/// and protobuf elements by a glob over fully qualified names (without leading dot,
+/// separated by `.`). Other keys are [`Customize`] options, with the same names
+/// as in `Customize::parse_from_parameter`.
+///
+/// ```toml
+/// [[customize]]
+/// file = "vendor/**/*.proto"
+/// expose_fields = true
+/// generate_accessors = false
+///
+/// [[customize]]
+/// path = "google.protobuf.*"
+/// serde_derive = true
+/// serde_derive_cfg = 'feature = "serde"'
+///
+/// [[customize]]
+/// path = "foo.Bar.timestamp"
+/// type_mapping = { "google.protobuf.Timestamp" = "std::time::SystemTime" }
+/// ```
+///
+/// In globs `*` and `?` do not match separators, and `**` matches anything.
+///
+/// Rule without `path` applies to files like `rustproto` file options do.
+/// Rule with `path` applies to messages, fields, enums and oneofs
+/// (e. g. `foo.Bar.my_oneof`) like `rustproto` message and field options do,
+/// and also requires file to match if `file` is specified.
+/// Rules are applied after `rustproto` options of the same level, in the order
+/// they are specified in config.
+#[derive(Debug, Clone, Default)]
+pub struct CustomizeConfig {
+    rules: Vec<CustomizeRule>,
+}
+
+impl CustomizeConfig {
+    /// Empty config.
+    pub fn new() -> CustomizeConfig {
+        CustomizeConfig::default()
+    }
+
+    /// Load config from a `.toml` or `.json` file.
+    #[cfg(feature = "with-customize-config")]
+    pub fn from_file(path: impl AsRef<Path>) -> CustomizeConfigResult<CustomizeConfig> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => CustomizeConfig::parse_toml(&content),
+            Some("json") => CustomizeConfig::parse_json(&content),
+            _ => Err(CustomizeConfigError::UnknownFormat(
+                path.display().to_string(),
+            )),
+        }
+    }
+
+    /// Parse config in TOML format.
+    #[cfg(feature = "with-customize-config")]
+    pub fn parse_toml(content: &str) -> CustomizeConfigResult<CustomizeConfig> {
+        let value: toml::Value = toml::from_str(content)?;
+        CustomizeConfig::from_value(serde_json::to_value(value)?)
+    }
+
+    /// Parse config in JSON format.
+    #[cfg(feature = "with-customize-config")]
+    pub fn parse_json(content: &str) -> CustomizeConfigResult<CustomizeConfig> {
+        CustomizeConfig::from_value(serde_json::from_str(content)?)
+    }
+
+    /// Add a rule.
+    ///
+    /// `file` is a glob over `.proto` file paths, and `path` is a glob over
+    /// fully qualified names of messages and fields. `None` matches everything.
+    pub fn add_rule(
+        &mut self,
+        file: Option<&str>,
+        path: Option<&str>,
+        customize: Customize,
+    ) -> &mut Self {
+        self.rules.push(CustomizeRule {
+            file: file.map(|s| s.to_owned()),
+            path: path.map(|s| s.trim_start_matches('.').to_owned()),
+            customize,
+        });
+        self
+    }
+
+    #[cfg(feature = "with-customize-config")]
+    fn from_value(value: serde_json::Value) -> CustomizeConfigResult<CustomizeConfig> {
+        let invalid = |m: String| CustomizeConfigError::Invalid(m);
+
+        let mut root = match value {
+            serde_json::Value::Object(root) => root,
+            _ => return Err(invalid(format!("config must be an object"))),
+        };
+        let rules = match root.remove("customize") {
+            Some(serde_json::Value::Array(rules)) => rules,
+            Some(_) => return Err(invalid(format!("`customize` must be an array"))),
+            None => Vec::new(),
+        };
+        if let Some(key) = root.keys().next() {
+            return Err(invalid(format!("unknown config key: {}", key)));
+        }
+
+        let mut config = CustomizeConfig::new();
+        for rule in rules {
+            let rule = match rule {
+                serde_json::Value::Object(rule) => rule,
+                _ => return Err(invalid(format!("customize rule must be an object"))),
+            };
+            let mut file = None;
+            let mut path = None;
+            let mut customize = Customize::default();
+            for (name, value) in &rule {
+                match (name.as_str(), value) {
+                    ("file", serde_json::Value::String(v)) => file = Some(v.as_str()),
+                    ("path", serde_json::Value::String(v)) => path = Some(v.as_str()),
+                    ("file", _) | ("path", _) => {
+                        return Err(invalid(format!("`{}` must be a string", name)));
+                    }
+                    ("type_mapping", serde_json::Value::Object(m)) => {
+                        for (message, rust_type) in m {
+                            let rust_type = rust_type.as_str().ok_or_else(|| {
+                                invalid(format!("type_mapping values must be strings"))
+                            })?;
+                            customize
+                                .set_option(name, &format!("{}={}", message, rust_type))
+                                .map_err(|e| invalid(format!("{}: {}", name, e)))?;
+                        }
+                    }
+                    (_, serde_json::Value::Bool(v)) => {
+                        customize
+                            .set_option(name, &v.to_string())
+                            .map_err(|e| invalid(format!("{}: {}", name, e)))?
+                    }
+                    (_, serde_json::Value::String(v)) => customize
+                        .set_option(name, v)
+                        .map_err(|e| invalid(format!("{}: {}", name, e)))?,
+                    (_, v) => return Err(invalid(format!("unexpected value of {}: {}", name, v))),
+                }
+            }
+            config.add_rule(file, path, customize);
+        }
+        Ok(config)
+    }
+
+    /// Options for a file, applied after `rustproto` file options.
+    pub(crate) fn for_file(&self, file: &str) -> Customize {
+        let mut r = Customize::default();
+        for rule in &self.rules {
+            if rule.path.is_none() && rule.matches_file(file) {
+                r.update_with(&rule.customize);
+            }
+        }
+        r
+    }
+
+    /// Options for a message or a field, applied after `rustproto` options.
+    pub(crate) fn for_path(&self, file: &str, path: &str) -> Customize {
+        let path = path.trim_start_matches('.');
+        let mut r = Customize::default();
+        for rule in &self.rules {
+            let path_matches = match &rule.path {
+                Some(pattern) => glob_match(pattern, path, '.'),
+                None => false,
+            };
+            if path_matches && rule.matches_file(file) {
+                r.update_with(&rule.customize);
+            }
+        }
+        r
+    }
+}
+
+impl CustomizeRule {
+    fn matches_file(&self, file: &str) -> bool {
+        match &self.file {
+            Some(pattern) => glob_match(pattern, file, '/'),
+            None => true,
+        }
+    }
+}
+
+/// Match text against a glob pattern.
+///
+/// `*` matches any sequence of characters except `separator`,
+/// `?` matches any single character except `separator`,
+/// `**` matches any sequence of characters, `**` followed by separator
+/// also matches empty sequence.
+fn glob_match(pattern: &str, text: &str, separator: char) -> bool {
+    fn matches(pattern: &[char], text: &[char], separator: char) -> bool {
+        match pattern.first() {
+            None => text.is_empty(),
+            Some('*') if pattern.get(1) == Some(&'*') => {
+                let rest = &pattern[2..];
+                if rest.first() == Some(&separator) && matches(&rest[1..], text, separator) {
+                    return true;
+                }
+                (0..=text.len()).any(|i| matches(rest, &text[i..], separator))
+            }
+            Some('*') => {
+                let rest = &pattern[1..];
+                for i in 0..=text.len() {
+                    if matches(rest, &text[i..], separator) {
+                        return true;
+                    }
+                    if text.get(i) == Some(&separator) {
+                        break;
+                    }
+                }
+                false
+            }
+            Some('?') => match text.first() {
+                Some(&c) if c != separator => matches(&pattern[1..], &text[1..], separator),
+                _ => false,
+            },
+            Some(&p) => match text.first() {
+                Some(&c) if c == p => matches(&pattern[1..], &text[1..], separator),
+                _ => false,
+            },
+        }
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    matches(&pattern, &text, separator)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn glob() {
+        assert!(glob_match("foo/*.proto", "foo/bar.proto", '/'));
+        assert!(!glob_match("foo/*.proto", "foo/bar/baz.proto", '/'));
+        assert!(glob_match("foo/**/*.proto", "foo/bar.proto", '/'));
+        assert!(glob_match("foo/**/*.proto", "foo/bar/baz.proto", '/'));
+        assert!(glob_match("**", "foo/bar.proto", '/'));
+        assert!(glob_match(
+            "google.protobuf.*",
+            "google.protobuf.Timestamp",
+            '.'
+        ));
+        assert!(!glob_match(
+            "google.protobuf.*",
+            "google.protobuf.Timestamp.seconds",
+            '.'
+        ));
+        assert!(glob_match(
+            "google.**",
+            "google.protobuf.Timestamp.seconds",
+            '.'
+        ));
+        assert!(glob_match("foo.Ba?", "foo.Bar", '.'));
+        assert!(!glob_match("foo.Ba?", "foo.Barr", '.'));
+    }
+
+    #[cfg(feature = "with-customize-config")]
+    #[test]
+    fn parse_toml() {
+        let config = CustomizeConfig::parse_toml(
+            r#"
+                [[customize]]
+                file = "vendor/**"
+                expose_fields = true
+
+                [[customize]]
+                path = "foo.Bar.*"
+                expose_fields = false
+                serde_derive_cfg = 'feature = "serde"'
+                type_mapping = { "google.protobuf.Timestamp" = "std::time::SystemTime" }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(Some(true), config.for_file("vendor/a.proto").expose_fields);
+        assert_eq!(None, config.for_file("a.proto").expose_fields);
+        // rules with path do not apply to files
+        assert_eq!(None, config.for_file("vendor/a.proto").serde_derive_cfg);
+
+        let field = config.for_path("vendor/a.proto", ".foo.Bar.baz");
+        assert_eq!(Some(false), field.expose_fields);
+        assert_eq!(
+            Some("feature = \"serde\""),
+            field.serde_derive_cfg.as_deref()
+        );
+        assert_eq!(
+            Some("std::time::SystemTime"),
+            field.mapped_type("google.protobuf.Timestamp")
+        );
+        assert_eq!(None, config.for_path("a.proto", "foo.Bar").expose_fields);
+    }
+
+    #[cfg(feature = "with-customize-config")]
+    #[test]
+    fn parse_json() {
+        let config = CustomizeConfig::parse_json(
+            r#"{"customize": [{"path": "foo.*", "file": "a.proto", "generate_getter": false}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            Some(false),
+            config.for_path("a.proto", "foo.Bar").generate_getter
+        );
+        assert_eq!(None, config.for_path("b.proto", "foo.Bar").generate_getter);
+
+        assert!(CustomizeConfig::parse_json(r#"{"customize": [{"foo": true}]}"#).is_err());
+        assert!(CustomizeConfig::parse_json(r#"{"rules": []}"#).is_err());
+        assert!(CustomizeConfig::parse_json(r#"{"customize": [{"expose_fields": 1}]}"#).is_err());
+    }
+}
@@ -36,6 +36,11 @@ impl<'a> EnumValueGen<'a> {
         self.value.proto.get_proto().get_number()
     }
 
+    // name of enum value in .proto file
+    fn proto_name(&self) -> &str {
+        self.value.proto.get_proto().get_name()
+    }
+
     // name of enum variant in generated rust code
     pub fn rust_name_inner(&self) -> RustIdent {
         self.value.rust_name()
@@ -68,6 +73,14 @@ impl<'a> EnumGen<'a> {
         path: &'a [i32],
         info: Option<&'a SourceCodeInfo>,
     ) -> EnumGen<'a> {
+        let mut customize = customize.clone();
+        if let Some(config) = customize.config.clone() {
+            customize.update_with(&config.for_path(
+                enum_with_scope.get_file_descriptor().get_name(),
+                enum_with_scope.en.full_name(),
+            ));
+        }
+
         let lite_runtime = customize.lite_runtime.unwrap_or_else(|| {
             enum_with_scope
                 .get_scope()
@@ -82,7 +95,7 @@ impl<'a> EnumGen<'a> {
             enum_with_scope,
             type_name: enum_with_scope.rust_name().to_path(),
             lite_runtime,
-            customize: customize.clone(),
+            customize,
             path,
             info,
             file_index,
@@ -140,14 +153,138 @@ impl<'a> EnumGen<'a> {
         self.write_impl_value(w);
         w.write_line("");
         self.write_impl_self(w);
+        w.write_line("");
+        self.write_impl_from_str(w);
+        w.write_line("");
+        self.write_impl_display(w);
+        w.write_line("");
+        self.write_impl_try_from(w);
     }
 
     fn write_impl_self(&self, w: &mut CodeWriter) {
         w.impl_self_block(&format!("{}", self.type_name), |w| {
+            // variant named `ALL` would conflict with the constant
+            if self
+                .values_all()
+                .iter()
+                .all(|v| v.rust_name_inner().get() != "ALL")
+            {
+                w.write_line("/// All enum values in the order of declaration.");
+                w.write_line(&format!(
+                    "pub const ALL: &'static [{}] = &[",
+                    self.type_name
+                ));
+                w.indented(|w| {
+                    for value in self.values_all() {
+                        w.write_line(&format!("{},", value.rust_name_outer()));
+                    }
+                });
+                w.write_line("];");
+                w.write_line("");
+            }
             self.write_generated_enum_descriptor_data(w);
         });
     }
 
+    fn full_name(&self) -> &str {
+        self.enum_with_scope.en.full_name()
+    }
+
+    fn write_impl_from_str(&self, w: &mut CodeWriter) {
+        w.impl_for_block(
+            "::std::str::FromStr",
+            &format!("{}", self.type_name),
+            |w| {
+                w.write_line(&format!(
+                    "type Err = {}::ProtobufEnumParseError;",
+                    protobuf_crate_path(&self.customize)
+                ));
+                w.write_line("");
+                w.def_fn(
+                    "from_str(s: &str) -> ::std::result::Result<Self, Self::Err>",
+                    |w| {
+                        w.match_expr("s", |w| {
+                            // aliases are parsed too
+                            for value in self.values_all() {
+                                w.case_expr(
+                                    &format!("\"{}\"", value.proto_name()),
+                                    &format!(
+                                        "::std::result::Result::Ok({})",
+                                        value.rust_name_outer()
+                                    ),
+                                );
+                            }
+                            w.case_expr(
+                                "_",
+                                &format!(
+                                    "::std::result::Result::Err({}::ProtobufEnumParseError::new(\"{}\", s))",
+                                    protobuf_crate_path(&self.customize),
+                                    self.full_name(),
+                                ),
+                            );
+                        });
+                    },
+                );
+            },
+        );
+    }
+
+    fn write_impl_display(&self, w: &mut CodeWriter) {
+        w.impl_for_block("::std::fmt::Display", &format!("{}", self.type_name), |w| {
+            w.def_fn(
+                "fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result",
+                |w| {
+                    w.write_line("f.write_str(match *self {");
+                    w.indented(|w| {
+                        // aliases are displayed as the first value with the same number
+                        let unique = self.values_unique();
+                        for value in self.values_all() {
+                            let canonical = unique
+                                .iter()
+                                .find(|u| u.number() == value.number())
+                                .unwrap();
+                            w.case_expr(
+                                &format!("{}", value.rust_name_outer()),
+                                &format!("\"{}\"", canonical.proto_name()),
+                            );
+                        }
+                    });
+                    w.write_line("})");
+                },
+            );
+        });
+    }
+
+    fn write_impl_try_from(&self, w: &mut CodeWriter) {
+        w.impl_for_block(
+            "::std::convert::TryFrom<i32>",
+            &format!("{}", self.type_name),
+            |w| {
+                w.write_line(&format!(
+                    "type Error = {}::ProtobufEnumFromI32Error;",
+                    protobuf_crate_path(&self.customize)
+                ));
+                w.write_line("");
+                w.def_fn(
+                    "try_from(value: i32) -> ::std::result::Result<Self, Self::Error>",
+                    |w| {
+                        w.write_line(&format!(
+                            "<Self as {}::ProtobufEnum>::from_i32(value)",
+                            protobuf_crate_path(&self.customize)
+                        ));
+                        w.indented(|w| {
+                            w.write_line(&format!(
+                                ".ok_or_else(|| {}::ProtobufEnumFromI32Error::new(\"{}\", value))",
+                                protobuf_crate_path(&self.customize),
+                                self.full_name(),
+                            ));
+                        });
+                    },
+                );
+            },
+        );
+    }
+
     fn write_enum(&self, w: &mut CodeWriter) {
         w.all_documentation(self.info, self.path);
 
@@ -69,22 +69,24 @@ impl FieldGen<'_> {
         ]
     }
 
-    fn make_accessor_fns_has_get_set(&self) -> Vec<String> {
+    fn make_accessor_fns_has_get_set_clear(&self) -> Vec<String> {
         let message = self.proto_field.message.rust_name();
         vec![
             format!("{}::has_{}", message, self.rust_name),
             format!("{}::get_{}", message, self.rust_name),
             format!("{}::set_{}", message, self.rust_name),
+            format!("{}::clear_{}", message, self.rust_name),
         ]
     }
 
-    fn make_accessor_fns_has_get_mut_set(&self) -> Vec<String> {
+    fn make_accessor_fns_has_get_mut_set_clear(&self) -> Vec<String> {
         let message = self.proto_field.message.rust_name();
         vec![
             format!("{}::has_{}", message, self.rust_name),
             format!("{}::get_{}", message, self.rust_name),
             format!("{}::mut_{}", message, self.rust_name),
             format!("{}::set_{}", message, self.rust_name),
+            format!("{}::clear_{}", message, self.rust_name),
         ]
     }
 
@@ -181,37 +183,55 @@ impl FieldGen<'_> {
 
         if let FieldElem::Enum(..) = oneof.elem {
             return AccessorFn {
-                name: "make_oneof_copy_has_get_set_simpler_accessors".to_owned(),
+                name: "make_oneof_copy_has_get_set_clear_simpler_accessors".to_owned(),
                 type_params: vec![format!("_")],
-                callback_params: self.make_accessor_fns_has_get_set(),
+                callback_params: self.make_accessor_fns_has_get_set_clear(),
             };
         }
 
         if elem.is_copy() {
             return AccessorFn {
-                name: "make_oneof_copy_has_get_set_simpler_accessors".to_owned(),
+                name: "make_oneof_copy_has_get_set_clear_simpler_accessors".to_owned(),
                 type_params: vec![format!("_")],
-                callback_params: self.make_accessor_fns_has_get_set(),
+                callback_params: self.make_accessor_fns_has_get_set_clear(),
             };
         }
 
         if let RustType::Message(name) = elem.rust_storage_elem_type(&reference) {
             return AccessorFn {
-                name: "make_oneof_message_has_get_mut_set_accessor".to_owned(),
+                name: "make_oneof_message_has_get_mut_set_clear_accessor".to_owned(),
                 type_params: vec![format!("{}", name)],
-                callback_params: self.make_accessor_fns_has_get_mut_set(),
+                callback_params: self.make_accessor_fns_has_get_mut_set_clear(),
             };
         }
 
         // string or bytes
         AccessorFn {
-            name: "make_oneof_deref_has_get_set_simpler_accessor".to_owned(),
+            name: "make_oneof_deref_has_get_set_clear_simpler_accessor".to_owned(),
             type_params: vec![format!("_")],
-            callback_params: self.make_accessor_fns_has_get_set(),
+            callback_params: self.make_accessor_fns_has_get_set_clear(),
+        }
+    }
+
+    fn accessor_fn_mapped(&self, mapped_type: &str) -> AccessorFn {
+        let name = match self.kind {
+            FieldKind::Singular(..) => "make_option_converted_message_accessor",
+            _ => "make_vec_converted_message_accessor",
+        };
+        AccessorFn {
+            name: name.to_owned(),
+            type_params: vec![
+                self.mapped_message_type().unwrap().to_string(),
+                mapped_type.to_owned(),
+            ],
+            callback_params: self.make_accessor_fns_lambda(),
         }
     }
 
     fn accessor_fn(&self) -> AccessorFn {
+        if let Some(mapped_type) = self.mapped_type() {
+            return self.accessor_fn_mapped(mapped_type);
+        }
         match self.kind {
             FieldKind::Repeated(ref repeated_field) => self.accessor_fn_repeated(repeated_field),
             FieldKind::Map(ref map_field) => self.accessor_fn_map(map_field),
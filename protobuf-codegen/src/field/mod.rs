@@ -25,10 +25,12 @@ use crate::scope::FieldWithContext;
 use crate::scope::MessageOrEnumWithScope;
 use crate::scope::MessageWithScope;
 use crate::scope::RootScope;
-use crate::scope::WithScope;
 use crate::serde;
-use crate::syntax::Syntax;
+use protobuf::reflect::features::Edition;
+use protobuf::reflect::features::FieldPresence;
 use protobuf::reflect::ReflectValueRef;
+use protobuf::reflect::RuntimeFieldType;
+use protobuf::reflect::RuntimeTypeBox;
 use protobuf::wire_format::WireType;
 
 mod accessor;
@@ -94,7 +96,7 @@ fn field_type_wire_type(field_type: field_descriptor_proto::Type) -> WireType {
         Type::TYPE_STRING => WireType::WireTypeLengthDelimited,
         Type::TYPE_BYTES => WireType::WireTypeLengthDelimited,
         Type::TYPE_MESSAGE => WireType::WireTypeLengthDelimited,
-        Type::TYPE_GROUP => WireType::WireTypeLengthDelimited, // not true
+        Type::TYPE_GROUP => WireType::WireTypeStartGroup,
     }
 }
 
@@ -387,6 +389,8 @@ impl<'a> FieldElemEnum<'a> {
 pub(crate) struct FieldElemMessage<'a> {
     map_entry: Option<Box<EntryKeyValue<'a>>>,
     pub message: MessageWithScope<'a>,
+    /// Rust type stored instead of the message (`Customize::type_mapping`)
+    pub mapped_type: Option<String>,
 }
 
 impl<'a> FieldElemMessage<'a> {
@@ -395,7 +399,10 @@ impl<'a> FieldElemMessage<'a> {
     }
 
     fn rust_type(&self, reference: &FileAndMod) -> RustType {
-        RustType::Message(self.rust_name_relative(reference))
+        match self.mapped_type {
+            Some(ref t) => RustType::Custom(t.clone()),
+            None => RustType::Message(self.rust_name_relative(reference)),
+        }
     }
 }
 
@@ -503,6 +510,7 @@ fn field_elem<'a>(
                 FieldElem::Message(FieldElemMessage {
                     map_entry: entry_key_value,
                     message: message.clone(),
+                    mapped_type: None,
                 })
             }
             (
@@ -553,11 +561,15 @@ fn field_elem<'a>(
 #[derive(Clone)]
 pub(crate) struct FieldGen<'a> {
     root_scope: &'a RootScope<'a>,
-    syntax: Syntax,
+    edition: Edition,
     pub proto_field: FieldWithContext<'a>,
     // field name in generated code
     pub rust_name: RustIdent,
     pub proto_type: field_descriptor_proto::Type,
+    // message field is written like a group
+    delimited: bool,
+    // unknown values of enum are stored in unknown fields
+    closed_enum: bool,
     wire_type: wire_format::WireType,
     serde_name: String,
     pub kind: FieldKind<'a>,
@@ -581,6 +593,16 @@ impl<'a> FieldGen<'a> {
         customize.update_with(&customize_from_rustproto_for_field(
             field.field.get_proto().options.get_or_default(),
         ));
+        if let Some(config) = customize.config.clone() {
+            customize.update_with(&config.for_path(
+                field.message.message.file_descriptor_proto().get_name(),
+                &format!(
+                    "{}.{}",
+                    field.message.message.full_name(),
+                    field.field.get_name()
+                ),
+            ));
+        }
 
         let elem = field_elem(
             &field,
@@ -590,25 +612,56 @@ impl<'a> FieldGen<'a> {
             &field.message.scope.rust_path_to_file(),
         );
 
-        let syntax = field.message.scope.file_scope.syntax();
+        let edition = field.message.scope.file_scope.edition();
+        let features = field.field.features();
+
+        let delimited = field.field.is_delimited();
+
+        let closed_enum = match field.field.runtime_field_type() {
+            RuntimeFieldType::Singular(RuntimeTypeBox::Enum(e))
+            | RuntimeFieldType::Repeated(RuntimeTypeBox::Enum(e))
+            | RuntimeFieldType::Map(_, RuntimeTypeBox::Enum(e)) => !e.is_open(),
+            _ => false,
+        };
+
+        // Oneof, map and delimited fields are always stored as messages
+        let mapped_type = match elem {
+            FieldElem::Message(FieldElemMessage {
+                map_entry: None, ..
+            }) if !field.is_oneof() && !delimited => customize
+                .mapped_type(field.field.get_proto().get_type_name())
+                .map(|t| t.to_owned()),
+            _ => None,
+        };
+        let elem = match elem {
+            FieldElem::Message(m) => FieldElem::Message(FieldElemMessage {
+                mapped_type: mapped_type.clone(),
+                ..m
+            }),
+            elem => elem,
+        };
 
-        let field_may_have_custom_default_value = syntax == Syntax::PROTO2
+        let field_may_have_custom_default_value = edition != Edition::Proto3
+            && features.field_presence != FieldPresence::Implicit
             && field.field.get_proto().get_label() != field_descriptor_proto::Label::LABEL_REPEATED
             && field.field.get_proto().get_field_type()
                 != field_descriptor_proto::Type::TYPE_MESSAGE;
 
         let default_expose_field = !field_may_have_custom_default_value;
-        let expose_field = customize.expose_fields.unwrap_or(default_expose_field);
+        let expose_field =
+            customize.expose_fields.unwrap_or(default_expose_field) || mapped_type.is_some();
 
         let default_generate_accessors = !expose_field;
-        let generate_accessors = customize
+        let generate_accessors = (customize
             .generate_accessors
             .unwrap_or(default_generate_accessors)
-            || field.is_oneof();
+            || field.is_oneof())
+            && mapped_type.is_none();
 
         let default_generate_getter = generate_accessors || field_may_have_custom_default_value;
-        let generate_getter =
-            customize.generate_getter.unwrap_or(default_generate_getter) || field.is_oneof();
+        let generate_getter = (customize.generate_getter.unwrap_or(default_generate_getter)
+            || field.is_oneof())
+            && mapped_type.is_none();
 
         let kind = if field.field.get_proto().get_label()
             == field_descriptor_proto::Label::LABEL_REPEATED
@@ -627,27 +680,20 @@ impl<'a> FieldGen<'a> {
                 // regular repeated field
                 elem => FieldKind::Repeated(RepeatedField {
                     elem,
-                    packed: field
-                        .field
-                        .get_proto()
-                        .options
-                        .get_or_default()
-                        .get_packed(),
+                    packed: field.field.is_packed(),
                 }),
             }
         } else if let Some(oneof) = field.oneof() {
             FieldKind::Oneof(OneofField::parse(&oneof, &field, elem, root_scope))
         } else {
-            let flag = if field.message.scope.file_scope.syntax() == Syntax::PROTO3
-                && field.field.get_proto().get_field_type()
-                    != field_descriptor_proto::Type::TYPE_MESSAGE
-            {
+            let flag = if !field.field.has_presence() {
                 SingularFieldFlag::WithoutFlag
             } else {
-                let required = field.field.get_proto().get_label()
-                    == field_descriptor_proto::Label::LABEL_REQUIRED;
+                let required = field.field.is_required();
                 let option_kind = match field.field.get_proto().get_field_type() {
-                    field_descriptor_proto::Type::TYPE_MESSAGE => OptionKind::MessageField,
+                    field_descriptor_proto::Type::TYPE_MESSAGE if mapped_type.is_none() => {
+                        OptionKind::MessageField
+                    }
                     _ => OptionKind::Option,
                 };
 
@@ -661,10 +707,16 @@ impl<'a> FieldGen<'a> {
 
         FieldGen {
             root_scope,
-            syntax: field.message.get_scope().file_scope.syntax(),
+            edition,
             rust_name: rust_field_name_for_protobuf_field_name(&field.field.get_name()),
             proto_type: field.field.get_proto().get_field_type(),
-            wire_type: field_type_wire_type(field.field.get_proto().get_field_type()),
+            delimited,
+            closed_enum,
+            wire_type: if delimited {
+                WireType::WireTypeStartGroup
+            } else {
+                field_type_wire_type(field.field.get_proto().get_field_type())
+            },
             serde_name: field.field.get_name().to_string(),
             proto_field: field,
             kind,
@@ -734,6 +786,35 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    fn mapped_elem(&self) -> Option<&FieldElemMessage> {
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                elem: FieldElem::Message(ref m),
+                ..
+            })
+            | FieldKind::Repeated(RepeatedField {
+                elem: FieldElem::Message(ref m),
+                ..
+            }) if m.mapped_type.is_some() => Some(m),
+            _ => None,
+        }
+    }
+
+    /// Rust type stored instead of message if this field is mapped
+    /// with `Customize::type_mapping`
+    pub fn mapped_type(&self) -> Option<&str> {
+        self.mapped_elem()
+            .and_then(|m| m.mapped_type.as_ref())
+            .map(|t| t.as_str())
+    }
+
+    /// Message type of the field if this field is mapped
+    /// with `Customize::type_mapping`
+    pub(crate) fn mapped_message_type(&self) -> Option<RustTypeMessage> {
+        self.mapped_elem()
+            .map(|m| m.rust_name_relative(&self.get_file_and_mod()))
+    }
+
     // type of field in struct
     pub fn full_storage_type(&self, reference: &FileAndMod) -> RustType {
         match self.kind {
@@ -958,9 +1039,9 @@ impl<'a> FieldGen<'a> {
     }
 
     pub fn reconstruct_def(&self) -> String {
-        let prefix = match (self.proto_field.field.get_proto().get_label(), self.syntax) {
+        let prefix = match (self.proto_field.field.get_proto().get_label(), self.edition) {
             (field_descriptor_proto::Label::LABEL_REPEATED, _) => "repeated ",
-            (_, Syntax::PROTO3) => "",
+            (_, Edition::Proto3) | (_, Edition::Edition2023) => "",
             (field_descriptor_proto::Label::LABEL_OPTIONAL, _) => "optional ",
             (field_descriptor_proto::Label::LABEL_REQUIRED, _) => "required ",
         };
@@ -1073,9 +1154,15 @@ impl<'a> FieldGen<'a> {
                     ),
                 ));
 
+                let write_fn = if self.delimited {
+                    "write_group_field_with_cached_size"
+                } else {
+                    "write_message_field_with_cached_size"
+                };
                 w.write_line(&format!(
-                    "{}::rt::write_message_field_with_cached_size({}, {}, {})?;",
+                    "{}::rt::{}({}, {}, {})?;",
                     protobuf_crate_path(&self.customize),
+                    write_fn,
                     self.proto_field.number(),
                     v.into_type(param_type, &self.customize).value,
                     os
@@ -1313,6 +1400,19 @@ impl<'a> FieldGen<'a> {
         w.if_stmt(self_field_is_none, cb)
     }
 
+    // `v` is a reference to each stored custom type value
+    fn write_for_mapped_self_field<F>(&self, w: &mut CodeWriter, cb: F)
+    where
+        F: Fn(&mut CodeWriter),
+    {
+        match self.kind {
+            FieldKind::Singular(..) => {
+                w.if_let_stmt("Some(v)", &format!("&{}", self.self_field()), cb)
+            }
+            _ => self.write_for_self_field(w, "v", |w, _v_type| cb(w)),
+        }
+    }
+
     // repeated or singular
     pub fn write_for_self_field<F>(&self, w: &mut CodeWriter, varn: &str, cb: F)
     where
@@ -1658,15 +1758,120 @@ impl<'a> FieldGen<'a> {
         );
     }
 
+    // Expression reading closed enum value into `Option`,
+    // unknown values are stored in unknown fields
+    fn read_closed_enum(&self) -> String {
+        format!(
+            "{}::rt::read_closed_enum_or_unknown(is, {}, &mut self.unknown_fields)?",
+            protobuf_crate_path(&self.customize),
+            self.proto_field.number(),
+        )
+    }
+
+    // Write `merge_from_message` part for this field
+    pub fn write_merge_from_message_field(&self, w: &mut CodeWriter) {
+        let self_field = self.self_field();
+        let other_field = format!("other.{}", self.rust_name);
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                flag:
+                    SingularFieldFlag::WithFlag {
+                        option_kind: OptionKind::MessageField,
+                        ..
+                    },
+                ..
+            }) => {
+                w.if_let_stmt(
+                    "::std::option::Option::Some(v)",
+                    &format!("{}.as_ref()", other_field),
+                    |w| {
+                        w.write_line(&format!(
+                            "{}.mut_or_default().merge_from_message(v);",
+                            self_field
+                        ));
+                    },
+                );
+            }
+            // Custom types are replaced rather than merged
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                ..
+            }) => {
+                w.if_stmt(format!("{}.is_some()", other_field), |w| {
+                    w.write_line(&format!("{} = {}.clone();", self_field, other_field));
+                });
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                ref elem,
+            }) => {
+                let cond = match *elem {
+                    FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, ..)
+                    | FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, ..) => {
+                        format!("!{}.is_empty()", other_field)
+                    }
+                    _ => format!(
+                        "{} != {}",
+                        other_field,
+                        self.full_storage_type(&self.get_file_and_mod())
+                            .default_value(&self.customize, false)
+                    ),
+                };
+                let clone = if elem.is_copy() { "" } else { ".clone()" };
+                w.if_stmt(cond, |w| {
+                    w.write_line(&format!("{} = {}{};", self_field, other_field, clone));
+                });
+            }
+            FieldKind::Repeated(..) => {
+                w.write_line(&format!(
+                    "{}.extend({}.iter().cloned());",
+                    self_field, other_field
+                ));
+            }
+            FieldKind::Map(..) => {
+                w.write_line(&format!(
+                    "{}.extend({}.iter().map(|(k, v)| (k.clone(), v.clone())));",
+                    self_field, other_field
+                ));
+            }
+            FieldKind::Oneof(..) => unreachable!(),
+        }
+    }
+
     // Write `merge_from` part for this oneof field
     fn write_merge_from_oneof(&self, o: &OneofField, wire_type_var: &str, w: &mut CodeWriter) {
         self.write_assert_wire_type(wire_type_var, w);
 
+        if self.closed_enum {
+            w.if_let_stmt(
+                "::std::option::Option::Some(v)",
+                &self.read_closed_enum(),
+                |w| {
+                    w.write_line(&format!(
+                        "self.{} = ::std::option::Option::Some({}(v));",
+                        o.oneof_field_name,
+                        o.variant_path(
+                            &self
+                                .proto_field
+                                .message
+                                .scope
+                                .rust_path_to_file()
+                                .clone()
+                                .into_path()
+                        ),
+                    ));
+                },
+            );
+            return;
+        }
+
+        let read = if self.delimited {
+            format!("is.read_group({})", self.proto_field.number())
+        } else {
+            self.proto_type.read("is", o.elem.primitive_type_variant())
+        };
         let typed = RustValueTyped {
-            value: format!(
-                "{}?",
-                self.proto_type.read("is", o.elem.primitive_type_variant())
-            ),
+            value: format!("{}?", read),
             rust_type: self.full_storage_iter_elem_type(
                 &self
                     .proto_field
@@ -1703,6 +1908,16 @@ impl<'a> FieldGen<'a> {
         let &MapField {
             ref key, ref value, ..
         } = self.map();
+        if self.closed_enum {
+            w.write_line(&format!(
+                "{}::rt::read_closed_enum_map_into::<{}, _>(wire_type, is, &mut {}, {}, &mut self.unknown_fields)?;",
+                protobuf_crate_path(&self.customize),
+                key.lib_protobuf_type(&self.get_file_and_mod()),
+                self.self_field(),
+                self.proto_field.number(),
+            ));
+            return;
+        }
         w.write_line(&format!(
             "{}::rt::read_map_into::<{}, {}>(wire_type, is, &mut {})?;",
             protobuf_crate_path(&self.customize),
@@ -1712,6 +1927,24 @@ impl<'a> FieldGen<'a> {
         ));
     }
 
+    // Write `merge_from` part for this singular or repeated message field
+    // with delimited encoding
+    fn write_merge_from_group(&self, wire_type_var: &str, w: &mut CodeWriter) {
+        let read_fn = match self.kind {
+            FieldKind::Singular(..) => "read_singular_group_into_field",
+            FieldKind::Repeated(..) => "read_repeated_group_into_vec",
+            FieldKind::Map(..) | FieldKind::Oneof(..) => unreachable!(),
+        };
+        w.write_line(&format!(
+            "{}::rt::{}({}, {}, is, &mut self.{})?;",
+            protobuf_crate_path(&self.customize),
+            read_fn,
+            self.proto_field.number(),
+            wire_type_var,
+            self.rust_name,
+        ));
+    }
+
     // Write `merge_from` part for this singular field
     fn write_merge_from_singular(
         &self,
@@ -1720,9 +1953,20 @@ impl<'a> FieldGen<'a> {
         w: &mut CodeWriter,
     ) {
         match s.elem {
+            FieldElem::Message(..) if self.delimited => {
+                self.write_merge_from_group(wire_type_var, w);
+            }
             FieldElem::Message(..) => {
                 self.write_merge_from_field_message_string_bytes(w);
             }
+            FieldElem::Enum(..) if self.closed_enum => {
+                self.write_assert_wire_type(wire_type_var, w);
+                w.if_let_stmt(
+                    "::std::option::Option::Some(v)",
+                    &self.read_closed_enum(),
+                    |w| self.write_self_field_assign_some(w, s, "v"),
+                );
+            }
             _ => {
                 self.write_assert_wire_type(wire_type_var, w);
                 let read_proc = format!(
@@ -1742,11 +1986,23 @@ impl<'a> FieldGen<'a> {
         };
 
         match field.elem {
+            FieldElem::Message(..) if self.delimited => {
+                self.write_merge_from_group(wire_type_var, w);
+            }
             FieldElem::Message(..)
             | FieldElem::Primitive(field_descriptor_proto::Type::TYPE_STRING, ..)
             | FieldElem::Primitive(field_descriptor_proto::Type::TYPE_BYTES, ..) => {
                 self.write_merge_from_field_message_string_bytes(w);
             }
+            FieldElem::Enum(..) if self.closed_enum => {
+                w.write_line(&format!(
+                    "{}::rt::read_repeated_closed_enum_or_unknown_into({}, is, &mut self.{}, {}, &mut self.unknown_fields)?;",
+                    protobuf_crate_path(&self.customize),
+                    wire_type_var,
+                    self.rust_name,
+                    self.proto_field.number(),
+                ));
+            }
             FieldElem::Enum(..) => {
                 w.write_line(&format!(
                     "{}::rt::read_repeated_enum_or_unknown_into({}, is, &mut self.{})?",
@@ -1769,6 +2025,21 @@ impl<'a> FieldGen<'a> {
 
     // Write `merge_from` part for this field
     pub fn write_merge_from_field(&self, wire_type_var: &str, w: &mut CodeWriter) {
+        if self.mapped_type().is_some() {
+            let read_fn = match self.kind {
+                FieldKind::Singular(..) => "read_singular_converted_message_into_option",
+                _ => "read_repeated_converted_message_into_vec",
+            };
+            w.write_line(&format!(
+                "{}::rt::{}::<{}, _>({}, is, &mut self.{})?;",
+                protobuf_crate_path(&self.customize),
+                read_fn,
+                self.mapped_message_type().unwrap(),
+                wire_type_var,
+                self.rust_name
+            ));
+            return;
+        }
         match self.kind {
             FieldKind::Oneof(ref f) => self.write_merge_from_oneof(&f, wire_type_var, w),
             FieldKind::Map(..) => self.write_merge_from_map(w),
@@ -1803,6 +2074,14 @@ impl<'a> FieldGen<'a> {
         assert!(!self.is_repeated_packed());
 
         match self.proto_type {
+            field_descriptor_proto::Type::TYPE_MESSAGE if self.delimited => {
+                // start and end group tags
+                let tag_size = self.tag_size() * 2;
+                w.write_line(&format!(
+                    "{} += {} + {}.compute_size();",
+                    sum_var, tag_size, item_var
+                ));
+            }
             field_descriptor_proto::Type::TYPE_MESSAGE => {
                 w.write_line(&format!("let len = {}.compute_size();", item_var));
                 let tag_size = self.tag_size();
@@ -1824,6 +2103,17 @@ impl<'a> FieldGen<'a> {
     }
 
     pub fn write_message_write_field(&self, w: &mut CodeWriter) {
+        if self.mapped_type().is_some() {
+            self.write_for_mapped_self_field(w, |w| {
+                w.write_line(&format!(
+                    "{}::rt::write_converted_message_field::<{}, _>({}, v, os)?;",
+                    protobuf_crate_path(&self.customize),
+                    self.mapped_message_type().unwrap(),
+                    self.proto_field.number()
+                ));
+            });
+            return;
+        }
         match self.kind {
             FieldKind::Singular(ref s) => {
                 self.write_if_let_self_field_is_some(s, w, |v, w| {
@@ -1879,6 +2169,18 @@ impl<'a> FieldGen<'a> {
     }
 
     pub fn write_message_compute_field_size(&self, sum_var: &str, w: &mut CodeWriter) {
+        if self.mapped_type().is_some() {
+            self.write_for_mapped_self_field(w, |w| {
+                w.write_line(&format!(
+                    "{} += {}::rt::converted_message_size::<{}, _>({}, v);",
+                    sum_var,
+                    protobuf_crate_path(&self.customize),
+                    self.mapped_message_type().unwrap(),
+                    self.proto_field.number()
+                ));
+            });
+            return;
+        }
         match self.kind {
             FieldKind::Singular(ref s) => {
                 self.write_if_let_self_field_is_some(s, w, |v, w| {
@@ -17,6 +17,7 @@ mod amend_io_error_util;
 pub mod case_convert;
 mod compiler_plugin;
 mod customize;
+mod customize_config;
 mod enums;
 mod extensions;
 mod field;
@@ -37,10 +38,11 @@ mod well_known_types;
 pub(crate) mod rust;
 pub(crate) mod scope;
 pub(crate) mod strx;
-pub(crate) mod syntax;
 
 use customize::customize_from_rustproto_for_file;
 pub use customize::Customize;
+pub use customize_config::CustomizeConfig;
+pub use customize_config::CustomizeConfigError;
 
 pub mod code_writer;
 
@@ -261,6 +263,9 @@ fn gen_file(
     customize.update_with(&customize_from_rustproto_for_file(
         file_descriptor.proto().options.get_or_default(),
     ));
+    if let Some(config) = customize.config.clone() {
+        customize.update_with(&config.for_file(file_descriptor.proto().get_name()));
+    }
 
     let file_scope = FileScope { file_descriptor };
     let scope = file_scope.to_scope();
@@ -85,6 +85,12 @@ impl<'a> MessageGen<'a> {
         customize.update_with(&customize_from_rustproto_for_message(
             message.message.get_proto().options.get_or_default(),
         ));
+        if let Some(config) = customize.config.clone() {
+            customize.update_with(&config.for_path(
+                file_descriptor.proto().get_name(),
+                message_descriptor.full_name(),
+            ));
+        }
 
         static FIELD_NUMBER: protobuf::rt::LazyV2<i32> = protobuf::rt::LazyV2::INIT;
         let field_number = *FIELD_NUMBER.get(|| {
@@ -162,6 +168,8 @@ impl<'a> MessageGen<'a> {
         self.fields
             .iter()
             .filter(|f| f.proto_type == field_descriptor_proto::Type::TYPE_MESSAGE)
+            // custom types are checked by conversion
+            .filter(|f| f.mapped_type().is_none())
             .collect()
     }
 
@@ -407,6 +415,56 @@ impl<'a> MessageGen<'a> {
         });
     }
 
+    fn write_merge_from_message(&self, w: &mut CodeWriter) {
+        w.def_fn("merge_from_message(&mut self, other: &Self)", |w| {
+            for f in self.fields_except_oneof_and_group() {
+                f.write_merge_from_message_field(w);
+            }
+            for oneof in self.oneofs() {
+                let variants = oneof.variants_except_group();
+                if variants.is_empty() {
+                    continue;
+                }
+                let field_name = oneof.oneof.field_name();
+                w.match_block(&format!("other.{}", field_name), |w| {
+                    // Set message variant of the same field is merged
+                    for variant in variants {
+                        if variant.field.proto_type != field_descriptor_proto::Type::TYPE_MESSAGE {
+                            continue;
+                        }
+                        let path = variant.path(&self.get_file_and_mod());
+                        w.case_block(
+                            format!("::std::option::Option::Some({}(ref v))", path),
+                            |w| {
+                                w.write_line(&format!(
+                                    "if let ::std::option::Option::Some({}(ref mut m)) = self.{} {{",
+                                    path, field_name
+                                ));
+                                w.indented(|w| w.write_line("m.merge_from_message(v);"));
+                                w.write_line("} else {");
+                                w.indented(|w| {
+                                    w.write_line(&format!(
+                                        "self.{} = ::std::option::Option::Some({}(v.clone()));",
+                                        field_name, path
+                                    ))
+                                });
+                                w.write_line("}");
+                            },
+                        );
+                    }
+                    w.case_block("::std::option::Option::Some(ref v)", |w| {
+                        w.write_line(&format!(
+                            "self.{} = ::std::option::Option::Some(v.clone());",
+                            field_name
+                        ));
+                    });
+                    w.case_expr("::std::option::Option::None", "{}");
+                });
+            }
+            w.write_line("self.unknown_fields.merge_from(&other.unknown_fields);");
+        });
+    }
+
     fn write_descriptor_static_new(&self, w: &mut CodeWriter) {
         let sig = format!(
             "descriptor_static() -> {}::reflect::MessageDescriptor",
@@ -493,6 +551,8 @@ impl<'a> MessageGen<'a> {
                 w.write_line("");
                 self.write_merge_from(w);
                 w.write_line("");
+                self.write_merge_from_message(w);
+                w.write_line("");
                 self.write_compute_size(w);
                 w.write_line("");
                 self.write_write_to_with_cached_sizes(w);
@@ -666,6 +726,14 @@ impl<'a> MessageGen<'a> {
         }
         w.write_line("");
         self.write_impl_value(w);
+        if !self.lite_runtime {
+            // JSON mapping requires reflection
+            serde::write_serde_json_mapping_impls(
+                w,
+                &self.customize,
+                &format!("{}", self.type_name),
+            );
+        }
 
         let mod_name = message_name_to_nested_mod_name(&self.message.message.get_name());
 
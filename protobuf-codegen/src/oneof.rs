@@ -166,11 +166,23 @@ impl<'a> OneofGen<'a> {
         oneof: OneofWithContext<'a>,
         customize: &Customize,
     ) -> OneofGen<'a> {
+        let mut customize = customize.clone();
+        if let Some(config) = customize.config.clone() {
+            customize.update_with(&config.for_path(
+                oneof.message.get_file_descriptor().get_name(),
+                &format!(
+                    "{}.{}",
+                    oneof.message.message.full_name(),
+                    oneof.oneof.get_name()
+                ),
+            ));
+        }
+
         OneofGen {
             message,
             oneof,
             lite_runtime: message.lite_runtime,
-            customize: customize.clone(),
+            customize,
         }
     }
 
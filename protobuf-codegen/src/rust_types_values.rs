@@ -51,6 +51,8 @@ pub(crate) enum RustType {
     Chars,
     // group
     Group,
+    // custom type stored instead of message, see `Customize::type_mapping`
+    Custom(String),
 }
 
 impl RustType {
@@ -90,6 +92,7 @@ impl RustType {
             RustType::Group => format!("<group>"),
             RustType::Bytes => format!("::bytes::Bytes"),
             RustType::Chars => format!("{}::Chars", protobuf_crate_path(customize)),
+            RustType::Custom(ref name) => format!("{}", name),
         }
     }
 }
@@ -371,6 +374,7 @@ impl RustType {
             &RustType::Bytes => RustType::Slice(Box::new(RustType::u8())),
             &RustType::Message(ref p) => RustType::Message(p.clone()),
             &RustType::Uniq(ref p) => RustType::Uniq(p.clone()),
+            &RustType::Custom(ref p) => RustType::Custom(p.clone()),
             x => panic!("no ref type for {:?}", x),
         }))
     }
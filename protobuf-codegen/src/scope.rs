@@ -13,8 +13,8 @@ use crate::rust_name::RustIdent;
 use crate::rust_name::RustIdentWithPath;
 use crate::rust_name::RustRelativePath;
 use crate::strx::capitalize;
-use crate::syntax::Syntax;
 use protobuf::descriptor::FileDescriptorProto;
+use protobuf::reflect::features::Edition;
 use protobuf::reflect::EnumDescriptor;
 use protobuf::reflect::EnumValueDescriptor;
 use protobuf::reflect::FieldDescriptor;
@@ -76,8 +76,8 @@ impl<'a> FileScope<'a> {
         ProtobufRelativePath::from(self.file_descriptor.proto().get_package()).into_absolute()
     }
 
-    pub fn syntax(&self) -> Syntax {
-        Syntax::parse(self.file_descriptor.proto().get_syntax())
+    pub fn edition(&self) -> Edition {
+        self.file_descriptor.edition()
     }
 
     pub fn to_scope(&self) -> Scope<'a> {
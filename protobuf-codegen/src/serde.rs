@@ -1,9 +1,10 @@
 use crate::code_writer::CodeWriter;
 use crate::customize::Customize;
+use crate::inside::protobuf_crate_path;
 
 /// Write serde attr according to specified codegen option.
 pub fn write_serde_attr(w: &mut CodeWriter, customize: &Customize, attr: &str) {
-    if customize.serde_derive.unwrap_or(false) {
+    if customize.serde_derive.unwrap_or(false) && !serde_json_mapping(customize) {
         if let Some(ref cfg) = customize.serde_derive_cfg {
             w.write_line(&format!("#[cfg_attr({}, {})]", cfg, attr));
         } else {
@@ -11,3 +12,99 @@ pub fn write_serde_attr(w: &mut CodeWriter, customize: &Customize, attr: &str) {
         }
     }
 }
+
+fn serde_json_mapping(customize: &Customize) -> bool {
+    customize.serde_json_mapping.unwrap_or(false)
+}
+
+fn write_serde_cfg(w: &mut CodeWriter, customize: &Customize) {
+    if let Some(ref cfg) = customize.serde_derive_cfg {
+        w.write_line(&format!("#[cfg({})]", cfg));
+    }
+}
+
+/// Write `Serialize` and `Deserialize` implementations for message
+/// which follow proto3 JSON mapping, if enabled by codegen option.
+pub fn write_serde_json_mapping_impls(w: &mut CodeWriter, customize: &Customize, type_name: &str) {
+    if !serde_json_mapping(customize) {
+        return;
+    }
+
+    w.write_line("");
+    write_serde_cfg(w, customize);
+    w.impl_for_block("::serde::Serialize", type_name, |w| {
+        w.def_fn(
+            "serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>",
+            |w| {
+                w.write_line(&format!(
+                    "{}::json::serialize_message(self, serializer)",
+                    protobuf_crate_path(customize)
+                ));
+            },
+        );
+    });
+
+    w.write_line("");
+    write_serde_cfg(w, customize);
+    w.impl_args_for_block(&["'de"], "::serde::Deserialize<'de>", type_name, |w| {
+        w.def_fn(
+            "deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error>",
+            |w| {
+                w.write_line(&format!(
+                    "{}::json::deserialize_message(deserializer)",
+                    protobuf_crate_path(customize)
+                ));
+            },
+        );
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn gen(customize: &Customize) -> String {
+        let mut v = Vec::new();
+        {
+            let mut w = CodeWriter::new(&mut v);
+            write_serde_attr(&mut w, customize, "derive(Serialize, Deserialize)");
+            write_serde_json_mapping_impls(&mut w, customize, "Foo");
+        }
+        String::from_utf8(v).unwrap()
+    }
+
+    #[test]
+    fn json_mapping_disabled() {
+        let customize = Customize {
+            serde_derive: Some(true),
+            ..Default::default()
+        };
+        assert_eq!("#[derive(Serialize, Deserialize)]\n", gen(&customize));
+    }
+
+    #[test]
+    fn json_mapping() {
+        let customize = Customize {
+            serde_derive: Some(true),
+            serde_derive_cfg: Some("serde".to_owned()),
+            serde_json_mapping: Some(true),
+            ..Default::default()
+        };
+        let expected = "
+#[cfg(serde)]
+impl ::serde::Serialize for Foo {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+        ::protobuf::json::serialize_message(self, serializer)
+    }
+}
+
+#[cfg(serde)]
+impl<'de> ::serde::Deserialize<'de> for Foo {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+        ::protobuf::json::deserialize_message(deserializer)
+    }
+}
+";
+        assert_eq!(expected, gen(&customize));
+    }
+}
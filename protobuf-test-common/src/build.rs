@@ -2,6 +2,7 @@
 
 pub use protobuf_codegen::Customize;
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs;
 use std::io::BufRead;
@@ -224,8 +225,16 @@ where
         dir, protos
     );
 
+    let mut type_mapping = BTreeMap::new();
+    // used in `v2/test_type_mapping.rs`
+    type_mapping.insert(
+        "test_type_mapping.Point".to_owned(),
+        "(i32, i32)".to_owned(),
+    );
+
     let customize = Customize {
         serde_derive_cfg: Some("serde".to_owned()),
+        type_mapping: Some(type_mapping),
         ..Default::default()
     };
 
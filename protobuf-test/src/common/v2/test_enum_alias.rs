@@ -26,3 +26,12 @@ fn test_enum_in_message() {
     m.set_en(EnumWithAlias::A);
     test_serialize_deserialize("08 0a", &m);
 }
+
+#[test]
+fn test_enum_alias_from_str_display() {
+    assert_eq!(Ok(EnumWithAlias::A), "A".parse());
+    assert_eq!(Ok(EnumWithAlias::A), "A_AGAIN".parse());
+    // aliases are displayed as the first value with the same number
+    assert_eq!("A", EnumWithAlias::A_AGAIN.to_string());
+    assert_eq!("B", EnumWithAlias::B.to_string());
+}
@@ -13,3 +13,34 @@ fn test_enum_values() {
     ];
     assert_eq!(expected, TestEnumValuesEnum::values());
 }
+
+#[test]
+fn test_enum_all() {
+    assert_eq!(TestEnumValuesEnum::values(), TestEnumValuesEnum::ALL);
+}
+
+#[test]
+fn test_enum_from_str_display() {
+    for &value in TestEnumValuesEnum::ALL {
+        let name = value.to_string();
+        assert_eq!(value.descriptor().get_name(), name);
+        assert_eq!(Ok(value), name.parse());
+    }
+
+    let error = "WINTER2".parse::<TestEnumValuesEnum>().unwrap_err();
+    assert_eq!("WINTER2", error.name());
+    // names are case-sensitive
+    assert!("winter".parse::<TestEnumValuesEnum>().is_err());
+}
+
+#[test]
+fn test_enum_try_from() {
+    use std::convert::TryFrom;
+
+    assert_eq!(
+        Ok(TestEnumValuesEnum::SPRING),
+        TestEnumValuesEnum::try_from(22)
+    );
+    let error = TestEnumValuesEnum::try_from(23).unwrap_err();
+    assert_eq!(23, error.value());
+}
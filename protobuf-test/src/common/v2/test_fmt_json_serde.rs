@@ -0,0 +1,276 @@
+#![cfg(feature = "with-serde")]
+
+use serde::de::DeserializeSeed;
+use serde_json;
+
+use protobuf::descriptor::field_descriptor_proto;
+use protobuf::descriptor::DescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::json;
+use protobuf::json::MessageMergeSeed;
+use protobuf::json::MessageSeed;
+use protobuf::json::MessageSerialize;
+use protobuf::reflect::FileDescriptor;
+use protobuf::reflect::MessageRef;
+use protobuf::well_known_types::value;
+use protobuf::well_known_types::BytesValue;
+use protobuf::well_known_types::Duration;
+use protobuf::well_known_types::Int64Value;
+use protobuf::well_known_types::ListValue;
+use protobuf::well_known_types::NullValue;
+use protobuf::well_known_types::Struct;
+use protobuf::well_known_types::Value;
+use protobuf::Message;
+use protobuf::MessageDyn;
+
+use super::test_fmt_json_serde_pb::*;
+
+fn to_json_value(message: &dyn MessageDyn) -> serde_json::Value {
+    serde_json::to_value(MessageSerialize::new(message)).unwrap()
+}
+
+fn from_json<M: Message>(json: &str) -> Result<M, serde_json::Error> {
+    json::deserialize_message(&mut serde_json::Deserializer::from_str(json))
+}
+
+fn test_same_as_printer(message: &dyn MessageDyn) {
+    let printed: serde_json::Value =
+        serde_json::from_str(&json::print_to_string(message).unwrap()).unwrap();
+    assert_eq!(printed, to_json_value(message));
+}
+
+fn test_round_trip<M: Message + PartialEq>(message: &M) {
+    test_same_as_printer(message);
+    let json = serde_json::to_string(&MessageSerialize::new(message)).unwrap();
+    assert_eq!(*message, from_json::<M>(&json).unwrap());
+}
+
+fn wk_value(kind: value::Kind) -> Value {
+    let mut value = Value::new();
+    value.kind = Some(kind);
+    value
+}
+
+#[test]
+fn test_regular_message() {
+    let mut m = TestTypes::new();
+    m.set_int64_singular(-17);
+    m.set_uint64_singular(u64::max_value());
+    m.set_double_singular(1.5);
+    m.set_string_singular("a \"quoted\"\n string".to_owned());
+    m.set_test_enum_singular(TestEnum::DARK);
+    m.mut_test_message_singular().set_value(10);
+    m.set_string_repeated(vec!["x".to_owned(), "y".to_owned()].into());
+    m.mut_int64_map_field().insert(1 << 60, -1);
+    m.mut_message_map_field()
+        .insert("k".to_owned(), TestMessage::new());
+    test_round_trip(&m);
+
+    assert_eq!(
+        serde_json::json!({"int64Singular": "-17", "testEnumSingular": "DARK"}),
+        to_json_value(&{
+            let mut m = TestTypes::new();
+            m.set_int64_singular(-17);
+            m.set_test_enum_singular(TestEnum::DARK);
+            m
+        })
+    );
+}
+
+#[test]
+fn test_float_special_values() {
+    let mut m = TestTypes::new();
+    m.set_double_singular(f64::INFINITY);
+    m.set_float_singular(f32::NEG_INFINITY);
+    test_round_trip(&m);
+    assert_eq!(
+        serde_json::json!({"doubleSingular": "Infinity", "floatSingular": "-Infinity"}),
+        to_json_value(&m)
+    );
+
+    let mut m = TestTypes::new();
+    m.set_double_singular(f64::NAN);
+    assert_eq!(
+        serde_json::json!({"doubleSingular": "NaN"}),
+        to_json_value(&m)
+    );
+}
+
+#[test]
+fn test_large_integer_numbers() {
+    // Numbers are not converted to `f64`
+    let m: TestTypes =
+        from_json(r#"{"int64Singular": 9007199254740993, "uint64Singular": 18446744073709551615}"#)
+            .unwrap();
+    assert_eq!(9007199254740993, m.get_int64_singular());
+    assert_eq!(u64::max_value(), m.get_uint64_singular());
+}
+
+#[test]
+fn test_well_known_types() {
+    let mut value = Int64Value::new();
+    value.value = -17;
+    assert_eq!(serde_json::json!("-17"), to_json_value(&value));
+
+    let mut duration = Duration::new();
+    duration.seconds = 3;
+    duration.nanos = 500_000_000;
+    test_round_trip(&duration);
+
+    let mut s = Struct::new();
+    s.fields.insert(
+        "a".to_owned(),
+        wk_value(value::Kind::list_value(ListValue {
+            values: vec![
+                wk_value(value::Kind::number_value(1.5)),
+                wk_value(value::Kind::null_value(NullValue::NULL_VALUE.into())),
+            ],
+            ..Default::default()
+        })),
+    );
+    test_round_trip(&s);
+    assert_eq!(serde_json::json!({"a": [1.5, null]}), to_json_value(&s));
+}
+
+#[test]
+fn test_embedded_in_larger_document() {
+    let mut m = TestTypes::new();
+    m.set_int32_singular(3);
+    let json = serde_json::to_string(&(1, MessageSerialize::new(&m))).unwrap();
+    assert_eq!(r#"[1,{"int32Singular":3}]"#, json);
+
+    let mut parsed = TestTypes::new();
+    let mut de = serde_json::Deserializer::from_str(r#"{"int32Singular": 3}"#);
+    MessageMergeSeed::new(&mut parsed)
+        .deserialize(&mut de)
+        .unwrap();
+    assert_eq!(m, parsed);
+}
+
+#[test]
+fn test_errors() {
+    assert!(from_json::<TestTypes>(r#"{"unknownField": 1}"#).is_err());
+    assert!(from_json::<TestTypes>(r#"{"int32Singular": "abc"}"#).is_err());
+    assert!(from_json::<TestTypes>(r#"{"int32Singular": 1.5}"#).is_err());
+    assert!(from_json::<TestTypes>(r#"{"testEnumSingular": "NO_SUCH_VALUE"}"#).is_err());
+    assert!(from_json::<TestTypes>(r#"{"stringSingular": 1}"#).is_err());
+    assert!(from_json::<TestTypes>(r#"{"int64MapField": {"x": 1}}"#).is_err());
+    assert!(from_json::<Duration>(r#""1 second""#).is_err());
+    assert!(from_json::<TestTypes>(r#"[]"#).is_err());
+}
+
+#[test]
+fn test_bytes_from_binary_format() {
+    // Formats other than JSON may pass bytes as is instead of base64 strings
+    let mut value = BytesValue::new();
+    MessageMergeSeed::new(&mut value)
+        .deserialize(serde::de::value::BytesDeserializer::<serde_json::Error>::new(b"\x00\xff"))
+        .unwrap();
+    assert_eq!(b"\x00\xff", &value.value[..]);
+
+    assert_eq!(serde_json::json!("AP8="), to_json_value(&value));
+    test_round_trip(&value);
+}
+
+#[test]
+fn test_ignore_unknown_fields() {
+    let mut m = TestTypes::new();
+    MessageMergeSeed::with_options(
+        &mut m,
+        &json::ParseOptions {
+            ignore_unknown_fields: true,
+            ..Default::default()
+        },
+    )
+    .deserialize(&mut serde_json::Deserializer::from_str(
+        r#"{"int32Singular": 1, "unknownField": {"a": [1, null]}}"#,
+    ))
+    .unwrap();
+    assert_eq!(1, m.get_int32_singular());
+}
+
+fn field(
+    name: &str,
+    number: i32,
+    label: field_descriptor_proto::Label,
+    t: field_descriptor_proto::Type,
+) -> FieldDescriptorProto {
+    let mut field = FieldDescriptorProto::new();
+    field.set_name(name.to_owned());
+    field.set_number(number);
+    field.set_label(label);
+    field.set_field_type(t);
+    field
+}
+
+#[test]
+fn test_dynamic_message() {
+    use field_descriptor_proto::Label;
+    use field_descriptor_proto::Type;
+
+    // Dynamic copy of `duration.proto` to check well-known types
+    // are detected without generated code.
+    let duration_file = FileDescriptor::new_dynamic(
+        Duration::descriptor_static()
+            .file_descriptor_proto()
+            .clone(),
+        Vec::new(),
+    );
+
+    let mut timeout = field("timeout", 3, Label::LABEL_OPTIONAL, Type::TYPE_MESSAGE);
+    timeout.set_type_name(".google.protobuf.Duration".to_owned());
+
+    let mut message = DescriptorProto::new();
+    message.set_name("Foo".to_owned());
+    message.field.push(field(
+        "item_count",
+        1,
+        Label::LABEL_OPTIONAL,
+        Type::TYPE_INT64,
+    ));
+    message
+        .field
+        .push(field("tags", 2, Label::LABEL_REPEATED, Type::TYPE_STRING));
+    message.field.push(timeout);
+
+    let mut file = FileDescriptorProto::new();
+    file.set_name("foo.proto".to_owned());
+    file.set_package("test".to_owned());
+    file.set_syntax("proto3".to_owned());
+    file.dependency
+        .push("google/protobuf/duration.proto".to_owned());
+    file.message_type.push(message);
+
+    let file = FileDescriptor::new_dynamic(file, vec![duration_file]);
+    let descriptor = file.message_by_package_relative_name("Foo").unwrap();
+
+    let json = r#"{"itemCount": "10", "tags": ["a", "b"], "timeout": "1.5s"}"#;
+    let message = MessageSeed::new(&descriptor)
+        .deserialize(&mut serde_json::Deserializer::from_str(json))
+        .unwrap();
+
+    let timeout = descriptor.get_field_by_name("timeout").unwrap();
+    let timeout = timeout.get_message(&*message);
+    assert_eq!(
+        "google.protobuf.Duration",
+        timeout.descriptor_dyn().full_name()
+    );
+    assert!(timeout.downcast_ref::<Duration>().is_none());
+
+    let expected =
+        serde_json::json!({"itemCount": "10", "tags": ["a", "b"], "timeout": "1.500000000s"});
+    assert_eq!(
+        expected,
+        serde_json::to_value(MessageRef::new(&*message)).unwrap()
+    );
+    let printed: serde_json::Value =
+        serde_json::from_str(&json::print_to_string(&*message).unwrap()).unwrap();
+    assert_eq!(expected, printed);
+
+    assert!(MessageSeed::new(&descriptor)
+        .deserialize(&mut serde_json::Deserializer::from_str(
+            r#"{"timeout": {"seconds": 1}}"#
+        ))
+        .is_err());
+}
@@ -11,3 +11,24 @@ fn test_lite_runtime() {
     // test it doesn't crash
     format!("{:?}", m);
 }
+
+#[test]
+fn test_lite_runtime_enum() {
+    use std::convert::TryFrom;
+
+    assert_eq!(
+        &[
+            EnumTestLiteRuntime::UNKNOWN,
+            EnumTestLiteRuntime::ONE,
+            EnumTestLiteRuntime::TWO,
+        ],
+        EnumTestLiteRuntime::ALL
+    );
+    assert_eq!("TWO", EnumTestLiteRuntime::TWO.to_string());
+    assert_eq!(Ok(EnumTestLiteRuntime::ONE), "ONE".parse());
+    assert_eq!(
+        Ok(EnumTestLiteRuntime::TWO),
+        EnumTestLiteRuntime::try_from(2)
+    );
+    assert!(EnumTestLiteRuntime::try_from(3).is_err());
+}
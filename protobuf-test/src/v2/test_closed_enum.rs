@@ -0,0 +1,43 @@
+use super::test_closed_enum_pb::*;
+
+use protobuf::Message;
+use protobuf::ProtobufEnumOrUnknown;
+use protobuf_test_common::*;
+
+#[test]
+fn known_values() {
+    let mut m = TestClosedEnum::new();
+    m.set_singular(ClosedEnum::ONE);
+    m.repeated.push(ProtobufEnumOrUnknown::new(ClosedEnum::ONE));
+    m.packed.push(ProtobufEnumOrUnknown::new(ClosedEnum::ONE));
+    m.map
+        .insert("a".to_owned(), ProtobufEnumOrUnknown::new(ClosedEnum::ONE));
+    m.set_oneof_enum(ClosedEnum::ONE);
+    test_serialize_deserialize_no_hex(&m);
+    assert!(m.get_unknown_fields().iter().next().is_none());
+}
+
+#[test]
+fn unknown_values_stored_in_unknown_fields() {
+    let bytes = hex::decode_hex("08 05 10 07 10 01 1a 02 01 09 22 05 0a 01 61 10 03 28 0b");
+    let m = TestClosedEnum::parse_from_bytes(&bytes).unwrap();
+
+    assert!(!m.has_singular());
+    assert_eq!(
+        vec![ProtobufEnumOrUnknown::new(ClosedEnum::ONE)],
+        m.repeated
+    );
+    assert_eq!(vec![ProtobufEnumOrUnknown::new(ClosedEnum::ONE)], m.packed);
+    assert!(m.map.is_empty());
+    assert!(!m.has_oneof_enum());
+
+    let unknown_fields = m.get_unknown_fields();
+    assert_eq!(&[5], &unknown_fields.get(1).unwrap().varint[..]);
+    assert_eq!(&[7], &unknown_fields.get(2).unwrap().varint[..]);
+    assert_eq!(&[9], &unknown_fields.get(3).unwrap().varint[..]);
+    assert_eq!(
+        &[vec![0x0a, 0x01, 0x61, 0x10, 0x03]],
+        &unknown_fields.get(4).unwrap().length_delimited[..]
+    );
+    assert_eq!(&[11], &unknown_fields.get(5).unwrap().varint[..]);
+}
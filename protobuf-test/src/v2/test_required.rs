@@ -20,3 +20,22 @@ fn test_is_initialized_is_recursive() {
     m.inner.as_mut().unwrap().set_b(false);
     assert!(m.is_initialized());
 }
+
+#[cfg(feature = "with-serde")]
+#[test]
+fn test_serde_deserialize_missing_required() {
+    use protobuf::json;
+
+    let mut de = serde_json::Deserializer::from_str("{}");
+    let e = json::deserialize_message::<TestRequired, _>(&mut de).unwrap_err();
+    assert!(
+        e.to_string().contains("not all message fields set"),
+        "{}",
+        e
+    );
+
+    let mut de = serde_json::Deserializer::from_str(r#"{"b": true}"#);
+    assert!(json::deserialize_message::<TestRequired, _>(&mut de)
+        .unwrap()
+        .get_b());
+}
@@ -0,0 +1,177 @@
+use protobuf::reflect::field_path::FieldPath;
+use protobuf::reflect::visit::walk_mut;
+use protobuf::reflect::visit::MessageVisitorMut;
+use protobuf::reflect::visit::Visit;
+use protobuf::reflect::FieldDescriptor;
+use protobuf::reflect::ReflectValueBox;
+use protobuf::reflect::ReflectValueRef;
+use protobuf::well_known_types::FieldMask;
+use protobuf::FieldMaskMergeOptions;
+use protobuf::Message;
+use protobuf::MessageConvert;
+use protobuf::MessageDyn;
+use protobuf::ProtobufError;
+use protobuf::ProtobufResult;
+
+use protobuf_test_common::*;
+
+use super::test_type_mapping_pb::*;
+
+impl MessageConvert<Point> for (i32, i32) {
+    fn from_message(message: Point) -> ProtobufResult<(i32, i32)> {
+        if !message.has_x() || !message.has_y() {
+            return Err(ProtobufError::MessageConvert("x and y required".to_owned()));
+        }
+        Ok((message.get_x(), message.get_y()))
+    }
+
+    fn to_message(&self) -> Point {
+        let mut point = Point::new();
+        point.set_x(self.0);
+        point.set_y(self.1);
+        point
+    }
+}
+
+#[test]
+fn test_write_read() {
+    let mut shape = Shape::new();
+    shape.origin = Some((1, 2));
+    test_serialize_deserialize("0a 04 08 01 10 02", &shape);
+
+    let mut shape = Shape::new();
+    shape.vertices = vec![(3, 4), (5, 6)];
+    test_serialize_deserialize("12 04 08 03 10 04 12 04 08 05 10 06", &shape);
+}
+
+#[test]
+fn test_parse_merges() {
+    let shape = Shape::parse_from_bytes(&hex::decode_hex("0a 04 08 01 10 02 0a 02 10 03")).unwrap();
+    assert_eq!(Some((1, 3)), shape.origin);
+}
+
+#[test]
+fn test_parse_conversion_error() {
+    assert!(Shape::parse_from_bytes(&hex::decode_hex("0a 02 08 01")).is_err());
+    assert!(Shape::parse_from_bytes(&hex::decode_hex("12 02 08 01")).is_err());
+}
+
+#[test]
+fn test_reflect_singular() {
+    let field = Shape::descriptor_static()
+        .get_field_by_name("origin")
+        .unwrap();
+
+    let mut shape = Shape::new();
+    shape.origin = Some((1, 2));
+    match field.get_singular_field_or_default(&shape) {
+        ReflectValueRef::Message(m) => {
+            assert_eq!(&(1, 2).to_message(), m.downcast_ref::<Point>().unwrap())
+        }
+        v => panic!("wrong value: {:?}", v),
+    }
+
+    field.set_singular_field(
+        &mut shape,
+        ReflectValueBox::Message(Box::new((3, 4).to_message())),
+    );
+    assert_eq!(Some((3, 4)), shape.origin);
+
+    assert!(field
+        .try_set_singular_field(&mut shape, ReflectValueBox::Message(Box::new(Point::new())))
+        .is_err());
+    assert_eq!(Some((3, 4)), shape.origin);
+
+    {
+        let mut point = field.mut_converted_message(&mut shape);
+        let y = point.descriptor_dyn().get_field_by_name("y").unwrap();
+        y.set_singular_field(&mut *point, ReflectValueBox::I32(5));
+    }
+    assert_eq!(Some((3, 5)), shape.origin);
+
+    let mut point = field.mut_converted_message(&mut shape);
+    point.clear_dyn();
+    assert!(point.finish().is_err());
+    assert_eq!(Some((3, 5)), shape.origin);
+}
+
+#[test]
+fn test_reflect_repeated() {
+    let field = Shape::descriptor_static()
+        .get_field_by_name("vertices")
+        .unwrap();
+
+    let mut shape = Shape::new();
+    let mut repeated = field.mut_repeated(&mut shape);
+    repeated.push(ReflectValueBox::Message(Box::new((1, 2).to_message())));
+    assert!(repeated
+        .try_push(ReflectValueBox::Message(Box::new(Point::new())))
+        .is_err());
+    assert!(repeated.try_push(ReflectValueBox::I32(1)).is_err());
+    assert_eq!(1, repeated.len());
+    assert_eq!(vec![(1, 2)], shape.vertices);
+}
+
+#[test]
+fn test_field_mask_merge() {
+    let mut source = Shape::new();
+    source.origin = Some((1, 2));
+    let mut destination = Shape::new();
+    destination.origin = Some((3, 4));
+
+    let mut mask = FieldMask::new();
+    mask.paths.push("origin.y".to_owned());
+    mask.merge_message(&source, &mut destination, &FieldMaskMergeOptions::default())
+        .unwrap();
+    assert_eq!(Some((3, 2)), destination.origin);
+
+    destination.vertices.push((5, 6));
+    mask.paths.push("origin.x".to_owned());
+    mask.trim_message(&mut destination);
+    assert_eq!(Some((3, 2)), destination.origin);
+    assert!(destination.vertices.is_empty());
+}
+
+#[test]
+fn test_field_path() {
+    let mut shape = Shape::new();
+    shape.origin = Some((1, 2));
+    FieldPath::parse("origin.y")
+        .unwrap()
+        .set(&mut shape, ReflectValueBox::I32(5))
+        .unwrap();
+    assert_eq!(Some((1, 5)), shape.origin);
+
+    // `Point` without `x` cannot be converted back
+    assert!(FieldPath::parse("origin.x")
+        .unwrap()
+        .clear(&mut shape)
+        .is_err());
+    assert_eq!(Some((1, 5)), shape.origin);
+}
+
+#[test]
+fn test_walk_mut() {
+    struct SetY;
+
+    impl MessageVisitorMut for SetY {
+        fn enter_field(
+            &mut self,
+            _path: &FieldPath,
+            field: &FieldDescriptor,
+            message: &mut dyn MessageDyn,
+        ) -> Visit {
+            if field.get_name() == "y" {
+                field.set_singular_field(message, ReflectValueBox::I32(0));
+            }
+            Visit::Continue
+        }
+    }
+
+    let mut shape = Shape::new();
+    shape.origin = Some((1, 2));
+    shape.vertices = vec![(3, 4), (5, 6)];
+    walk_mut(&mut shape, &mut SetY);
+    assert_eq!(Some((1, 0)), shape.origin);
+    assert_eq!(vec![(3, 0), (5, 0)], shape.vertices);
+}
@@ -24,7 +24,7 @@
 ///  The protocol compiler can output a FileDescriptorSet containing the .proto
 ///  files it parses.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct FileDescriptorSet {
     // message fields
     pub file: ::std::vec::Vec<FileDescriptorProto>,
@@ -86,6 +86,11 @@ impl crate::Message for FileDescriptorSet {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        self.file.extend(other.file.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -156,7 +161,7 @@ impl crate::reflect::ProtobufValue for FileDescriptorSet {
 
 ///  Describes a complete .proto file.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct FileDescriptorProto {
     // message fields
     name: ::std::option::Option<::std::string::String>,
@@ -180,8 +185,12 @@ pub struct FileDescriptorProto {
     ///  development tools.
     pub source_code_info: crate::MessageField<SourceCodeInfo>,
     ///  The syntax of the proto file.
-    ///  The supported values are "proto2" and "proto3".
+    ///  The supported values are "proto2", "proto3", and "editions".
+    ///
+    ///  If `edition` is present, this value must be "editions".
     syntax: ::std::option::Option<::std::string::String>,
+    ///  The edition of the proto file.
+    edition: ::std::option::Option<crate::ProtobufEnumOrUnknown<Edition>>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
     pub unknown_fields: crate::UnknownFields,
@@ -308,6 +317,28 @@ impl FileDescriptorProto {
         self.syntax.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
+    // optional .google.protobuf.Edition edition = 14;
+
+    pub fn get_edition(&self) -> Edition {
+        match self.edition {
+            Some(e) => e.enum_value_or(Edition::EDITION_UNKNOWN),
+            None => Edition::EDITION_UNKNOWN,
+        }
+    }
+
+    pub fn clear_edition(&mut self) {
+        self.edition = ::std::option::Option::None;
+    }
+
+    pub fn has_edition(&self) -> bool {
+        self.edition.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edition(&mut self, v: Edition) {
+        self.edition = ::std::option::Option::Some(crate::ProtobufEnumOrUnknown::new(v));
+    }
+
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::new();
         fields.push(crate::reflect::rt::v2::make_option_get_ref_simpler_accessor::<_, _>(
@@ -373,6 +404,12 @@ impl FileDescriptorProto {
             |m: &mut FileDescriptorProto| { &mut m.syntax },
             FileDescriptorProto::get_syntax,
         ));
+        fields.push(crate::reflect::rt::v2::make_option_enum_accessor::<_, Edition>(
+            "edition",
+            |m: &FileDescriptorProto| { &m.edition },
+            |m: &mut FileDescriptorProto| { &mut m.edition },
+            Edition::EDITION_UNKNOWN,
+        ));
         crate::reflect::GeneratedMessageDescriptorData::new_2::<FileDescriptorProto>(
             "FileDescriptorProto",
             1,
@@ -465,6 +502,14 @@ impl crate::Message for FileDescriptorProto {
                     }
                     self.syntax = ::std::option::Option::Some(is.read_string()?);
                 },
+                14 => {
+                    if wire_type != crate::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
+                    }
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 14, &mut self.unknown_fields)? {
+                        self.edition = ::std::option::Option::Some(v);
+                    }
+                },
                 _ => {
                     crate::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -473,6 +518,35 @@ impl crate::Message for FileDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if other.package.is_some() {
+            self.package = other.package.clone();
+        }
+        self.dependency.extend(other.dependency.iter().cloned());
+        self.public_dependency.extend(other.public_dependency.iter().cloned());
+        self.weak_dependency.extend(other.weak_dependency.iter().cloned());
+        self.message_type.extend(other.message_type.iter().cloned());
+        self.enum_type.extend(other.enum_type.iter().cloned());
+        self.service.extend(other.service.iter().cloned());
+        self.extension.extend(other.extension.iter().cloned());
+        if let ::std::option::Option::Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        if let ::std::option::Option::Some(v) = other.source_code_info.as_ref() {
+            self.source_code_info.mut_or_default().merge_from_message(v);
+        }
+        if other.syntax.is_some() {
+            self.syntax = other.syntax.clone();
+        }
+        if other.edition.is_some() {
+            self.edition = other.edition.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -519,6 +593,9 @@ impl crate::Message for FileDescriptorProto {
         if let Some(v) = self.syntax.as_ref() {
             my_size += crate::rt::string_size(12, &v);
         }
+        if let Some(v) = self.edition {
+            my_size += crate::rt::enum_or_unknown_size(14, v);
+        }
         my_size += crate::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -561,6 +638,9 @@ impl crate::Message for FileDescriptorProto {
         if let Some(v) = self.syntax.as_ref() {
             os.write_string(12, v)?;
         }
+        if let Some(v) = self.edition {
+            os.write_enum(14, crate::ProtobufEnumOrUnknown::value(&v))?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -599,6 +679,7 @@ impl crate::Message for FileDescriptorProto {
             options: crate::MessageField::none(),
             source_code_info: crate::MessageField::none(),
             syntax: ::std::option::Option::None,
+            edition: ::std::option::Option::None,
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
         };
@@ -620,6 +701,7 @@ impl crate::Clear for FileDescriptorProto {
         self.options.clear();
         self.source_code_info.clear();
         self.syntax = ::std::option::Option::None;
+        self.edition = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -636,7 +718,7 @@ impl crate::reflect::ProtobufValue for FileDescriptorProto {
 
 ///  Describes a message type.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct DescriptorProto {
     // message fields
     name: ::std::option::Option<::std::string::String>,
@@ -856,6 +938,24 @@ impl crate::Message for DescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        self.field.extend(other.field.iter().cloned());
+        self.extension.extend(other.extension.iter().cloned());
+        self.nested_type.extend(other.nested_type.iter().cloned());
+        self.enum_type.extend(other.enum_type.iter().cloned());
+        self.extension_range.extend(other.extension_range.iter().cloned());
+        self.oneof_decl.extend(other.oneof_decl.iter().cloned());
+        if let ::std::option::Option::Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.reserved_range.extend(other.reserved_range.iter().cloned());
+        self.reserved_name.extend(other.reserved_name.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1006,7 +1106,7 @@ impl crate::reflect::ProtobufValue for DescriptorProto {
 /// Nested message and enums of message `DescriptorProto`
 pub mod descriptor_proto {
     #[derive(PartialEq,Clone,Default)]
-    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    #[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
     pub struct ExtensionRange {
         // message fields
         start: ::std::option::Option<i32>,
@@ -1089,7 +1189,7 @@ pub mod descriptor_proto {
             ));
             crate::reflect::GeneratedMessageDescriptorData::new_2::<ExtensionRange>(
                 "DescriptorProto.ExtensionRange",
-                21,
+                22,
                 fields,
             )
         }
@@ -1132,6 +1232,19 @@ pub mod descriptor_proto {
             ::std::result::Result::Ok(())
         }
 
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.start.is_some() {
+                self.start = other.start.clone();
+            }
+            if other.end.is_some() {
+                self.end = other.end.clone();
+            }
+            if let ::std::option::Option::Some(v) = other.options.as_ref() {
+                self.options.mut_or_default().merge_from_message(v);
+            }
+            self.unknown_fields.merge_from(&other.unknown_fields);
+        }
+
         // Compute sizes of nested messages
         #[allow(unused_variables)]
         fn compute_size(&self) -> u32 {
@@ -1220,7 +1333,7 @@ pub mod descriptor_proto {
     ///  fields or extension ranges in the same message. Reserved ranges may
     ///  not overlap.
     #[derive(PartialEq,Clone,Default)]
-    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    #[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
     pub struct ReservedRange {
         // message fields
         start: ::std::option::Option<i32>,
@@ -1297,7 +1410,7 @@ pub mod descriptor_proto {
             ));
             crate::reflect::GeneratedMessageDescriptorData::new_2::<ReservedRange>(
                 "DescriptorProto.ReservedRange",
-                22,
+                23,
                 fields,
             )
         }
@@ -1332,6 +1445,16 @@ pub mod descriptor_proto {
             ::std::result::Result::Ok(())
         }
 
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.start.is_some() {
+                self.start = other.start.clone();
+            }
+            if other.end.is_some() {
+                self.end = other.end.clone();
+            }
+            self.unknown_fields.merge_from(&other.unknown_fields);
+        }
+
         // Compute sizes of nested messages
         #[allow(unused_variables)]
         fn compute_size(&self) -> u32 {
@@ -1409,7 +1532,7 @@ pub mod descriptor_proto {
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct ExtensionRangeOptions {
     // message fields
     ///  The parser stores options it doesn't recognize here. See above.
@@ -1472,6 +1595,11 @@ impl crate::Message for ExtensionRangeOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1542,7 +1670,7 @@ impl crate::reflect::ProtobufValue for ExtensionRangeOptions {
 
 ///  Describes a field within a message.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct FieldDescriptorProto {
     // message fields
     name: ::std::option::Option<::std::string::String>,
@@ -1550,7 +1678,7 @@ pub struct FieldDescriptorProto {
     label: ::std::option::Option<crate::ProtobufEnumOrUnknown<field_descriptor_proto::Label>>,
     ///  If type_name is set, this need not be set.  If both this and type_name
     ///  are set, this must be one of TYPE_ENUM, TYPE_MESSAGE or TYPE_GROUP.
-    #[cfg_attr(serde, serde(alias="type"))]
+    #[cfg_attr(serde, serde(rename(serialize="type",deserialize="type")))]
     field_type: ::std::option::Option<crate::ProtobufEnumOrUnknown<field_descriptor_proto::Type>>,
     ///  For message and enum types, this is the name of the type.  If the name
     ///  starts with a '.', it is fully-qualified.  Otherwise, C++-like scoping
@@ -2002,13 +2130,17 @@ impl crate::Message for FieldDescriptorProto {
                     if wire_type != crate::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
                     }
-                    self.label = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 4, &mut self.unknown_fields)? {
+                        self.label = ::std::option::Option::Some(v);
+                    }
                 },
                 5 => {
                     if wire_type != crate::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
                     }
-                    self.field_type = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 5, &mut self.unknown_fields)? {
+                        self.field_type = ::std::option::Option::Some(v);
+                    }
                 },
                 6 => {
                     if wire_type != crate::wire_format::WireTypeLengthDelimited {
@@ -2057,6 +2189,43 @@ impl crate::Message for FieldDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if other.number.is_some() {
+            self.number = other.number.clone();
+        }
+        if other.label.is_some() {
+            self.label = other.label.clone();
+        }
+        if other.field_type.is_some() {
+            self.field_type = other.field_type.clone();
+        }
+        if other.type_name.is_some() {
+            self.type_name = other.type_name.clone();
+        }
+        if other.extendee.is_some() {
+            self.extendee = other.extendee.clone();
+        }
+        if other.default_value.is_some() {
+            self.default_value = other.default_value.clone();
+        }
+        if other.oneof_index.is_some() {
+            self.oneof_index = other.oneof_index.clone();
+        }
+        if other.json_name.is_some() {
+            self.json_name = other.json_name.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        if other.proto3_optional.is_some() {
+            self.proto3_optional = other.proto3_optional.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -2284,7 +2453,7 @@ pub mod field_descriptor_proto {
         }
 
         fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
-            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 0)
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 1)
         }
     }
 
@@ -2300,8 +2469,92 @@ pub mod field_descriptor_proto {
     }
 
     impl Type {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [Type] = &[
+            Type::TYPE_DOUBLE,
+            Type::TYPE_FLOAT,
+            Type::TYPE_INT64,
+            Type::TYPE_UINT64,
+            Type::TYPE_INT32,
+            Type::TYPE_FIXED64,
+            Type::TYPE_FIXED32,
+            Type::TYPE_BOOL,
+            Type::TYPE_STRING,
+            Type::TYPE_GROUP,
+            Type::TYPE_MESSAGE,
+            Type::TYPE_BYTES,
+            Type::TYPE_UINT32,
+            Type::TYPE_ENUM,
+            Type::TYPE_SFIXED32,
+            Type::TYPE_SFIXED64,
+            Type::TYPE_SINT32,
+            Type::TYPE_SINT64,
+        ];
+
         pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
-            crate::reflect::GeneratedEnumDescriptorData::new_2::<Type>("FieldDescriptorProto.Type", 0)
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<Type>("FieldDescriptorProto.Type", 1)
+        }
+    }
+
+    impl ::std::str::FromStr for Type {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "TYPE_DOUBLE" => ::std::result::Result::Ok(Type::TYPE_DOUBLE),
+                "TYPE_FLOAT" => ::std::result::Result::Ok(Type::TYPE_FLOAT),
+                "TYPE_INT64" => ::std::result::Result::Ok(Type::TYPE_INT64),
+                "TYPE_UINT64" => ::std::result::Result::Ok(Type::TYPE_UINT64),
+                "TYPE_INT32" => ::std::result::Result::Ok(Type::TYPE_INT32),
+                "TYPE_FIXED64" => ::std::result::Result::Ok(Type::TYPE_FIXED64),
+                "TYPE_FIXED32" => ::std::result::Result::Ok(Type::TYPE_FIXED32),
+                "TYPE_BOOL" => ::std::result::Result::Ok(Type::TYPE_BOOL),
+                "TYPE_STRING" => ::std::result::Result::Ok(Type::TYPE_STRING),
+                "TYPE_GROUP" => ::std::result::Result::Ok(Type::TYPE_GROUP),
+                "TYPE_MESSAGE" => ::std::result::Result::Ok(Type::TYPE_MESSAGE),
+                "TYPE_BYTES" => ::std::result::Result::Ok(Type::TYPE_BYTES),
+                "TYPE_UINT32" => ::std::result::Result::Ok(Type::TYPE_UINT32),
+                "TYPE_ENUM" => ::std::result::Result::Ok(Type::TYPE_ENUM),
+                "TYPE_SFIXED32" => ::std::result::Result::Ok(Type::TYPE_SFIXED32),
+                "TYPE_SFIXED64" => ::std::result::Result::Ok(Type::TYPE_SFIXED64),
+                "TYPE_SINT32" => ::std::result::Result::Ok(Type::TYPE_SINT32),
+                "TYPE_SINT64" => ::std::result::Result::Ok(Type::TYPE_SINT64),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FieldDescriptorProto.Type", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for Type {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                Type::TYPE_DOUBLE => "TYPE_DOUBLE",
+                Type::TYPE_FLOAT => "TYPE_FLOAT",
+                Type::TYPE_INT64 => "TYPE_INT64",
+                Type::TYPE_UINT64 => "TYPE_UINT64",
+                Type::TYPE_INT32 => "TYPE_INT32",
+                Type::TYPE_FIXED64 => "TYPE_FIXED64",
+                Type::TYPE_FIXED32 => "TYPE_FIXED32",
+                Type::TYPE_BOOL => "TYPE_BOOL",
+                Type::TYPE_STRING => "TYPE_STRING",
+                Type::TYPE_GROUP => "TYPE_GROUP",
+                Type::TYPE_MESSAGE => "TYPE_MESSAGE",
+                Type::TYPE_BYTES => "TYPE_BYTES",
+                Type::TYPE_UINT32 => "TYPE_UINT32",
+                Type::TYPE_ENUM => "TYPE_ENUM",
+                Type::TYPE_SFIXED32 => "TYPE_SFIXED32",
+                Type::TYPE_SFIXED64 => "TYPE_SFIXED64",
+                Type::TYPE_SINT32 => "TYPE_SINT32",
+                Type::TYPE_SINT64 => "TYPE_SINT64",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for Type {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FieldDescriptorProto.Type", value))
         }
     }
 
@@ -2337,7 +2590,7 @@ pub mod field_descriptor_proto {
         }
 
         fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
-            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 1)
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 2)
         }
     }
 
@@ -2353,15 +2606,54 @@ pub mod field_descriptor_proto {
     }
 
     impl Label {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [Label] = &[
+            Label::LABEL_OPTIONAL,
+            Label::LABEL_REQUIRED,
+            Label::LABEL_REPEATED,
+        ];
+
         pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
-            crate::reflect::GeneratedEnumDescriptorData::new_2::<Label>("FieldDescriptorProto.Label", 1)
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<Label>("FieldDescriptorProto.Label", 2)
+        }
+    }
+
+    impl ::std::str::FromStr for Label {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "LABEL_OPTIONAL" => ::std::result::Result::Ok(Label::LABEL_OPTIONAL),
+                "LABEL_REQUIRED" => ::std::result::Result::Ok(Label::LABEL_REQUIRED),
+                "LABEL_REPEATED" => ::std::result::Result::Ok(Label::LABEL_REPEATED),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FieldDescriptorProto.Label", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for Label {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                Label::LABEL_OPTIONAL => "LABEL_OPTIONAL",
+                Label::LABEL_REQUIRED => "LABEL_REQUIRED",
+                Label::LABEL_REPEATED => "LABEL_REPEATED",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for Label {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FieldDescriptorProto.Label", value))
         }
     }
 }
 
 ///  Describes a oneof.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct OneofDescriptorProto {
     // message fields
     name: ::std::option::Option<::std::string::String>,
@@ -2472,6 +2764,16 @@ impl crate::Message for OneofDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -2550,7 +2852,7 @@ impl crate::reflect::ProtobufValue for OneofDescriptorProto {
 
 ///  Describes an enum type.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct EnumDescriptorProto {
     // message fields
     name: ::std::option::Option<::std::string::String>,
@@ -2703,6 +3005,19 @@ impl crate::Message for EnumDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        self.value.extend(other.value.iter().cloned());
+        if let ::std::option::Option::Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.reserved_range.extend(other.reserved_range.iter().cloned());
+        self.reserved_name.extend(other.reserved_name.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -2814,7 +3129,7 @@ pub mod enum_descriptor_proto {
     ///  is inclusive such that it can appropriately represent the entire int32
     ///  domain.
     #[derive(PartialEq,Clone,Default)]
-    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    #[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
     pub struct EnumReservedRange {
         // message fields
         start: ::std::option::Option<i32>,
@@ -2891,7 +3206,7 @@ pub mod enum_descriptor_proto {
             ));
             crate::reflect::GeneratedMessageDescriptorData::new_2::<EnumReservedRange>(
                 "EnumDescriptorProto.EnumReservedRange",
-                23,
+                24,
                 fields,
             )
         }
@@ -2926,6 +3241,16 @@ pub mod enum_descriptor_proto {
             ::std::result::Result::Ok(())
         }
 
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.start.is_some() {
+                self.start = other.start.clone();
+            }
+            if other.end.is_some() {
+                self.end = other.end.clone();
+            }
+            self.unknown_fields.merge_from(&other.unknown_fields);
+        }
+
         // Compute sizes of nested messages
         #[allow(unused_variables)]
         fn compute_size(&self) -> u32 {
@@ -3004,7 +3329,7 @@ pub mod enum_descriptor_proto {
 
 ///  Describes a value within an enum.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct EnumValueDescriptorProto {
     // message fields
     name: ::std::option::Option<::std::string::String>,
@@ -3147,6 +3472,19 @@ impl crate::Message for EnumValueDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if other.number.is_some() {
+            self.number = other.number.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -3233,7 +3571,7 @@ impl crate::reflect::ProtobufValue for EnumValueDescriptorProto {
 
 ///  Describes a service.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct ServiceDescriptorProto {
     // message fields
     name: ::std::option::Option<::std::string::String>,
@@ -3358,6 +3696,17 @@ impl crate::Message for ServiceDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        self.method.extend(other.method.iter().cloned());
+        if let ::std::option::Option::Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -3445,7 +3794,7 @@ impl crate::reflect::ProtobufValue for ServiceDescriptorProto {
 
 ///  Describes a method of a service.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct MethodDescriptorProto {
     // message fields
     name: ::std::option::Option<::std::string::String>,
@@ -3722,6 +4071,28 @@ impl crate::Message for MethodDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if other.input_type.is_some() {
+            self.input_type = other.input_type.clone();
+        }
+        if other.output_type.is_some() {
+            self.output_type = other.output_type.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.options.as_ref() {
+            self.options.mut_or_default().merge_from_message(v);
+        }
+        if other.client_streaming.is_some() {
+            self.client_streaming = other.client_streaming.clone();
+        }
+        if other.server_streaming.is_some() {
+            self.server_streaming = other.server_streaming.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -3831,7 +4202,7 @@ impl crate::reflect::ProtobufValue for MethodDescriptorProto {
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct FileOptions {
     // message fields
     ///  Sets the Java package where classes generated from this .proto will be
@@ -3915,6 +4286,8 @@ pub struct FileOptions {
     ///  is empty. When this option is not set, the package name will be used for
     ///  determining the ruby package.
     ruby_package: ::std::option::Option<::std::string::String>,
+    ///  Any features defined in the specific edition.
+    pub features: crate::MessageField<FeatureSet>,
     ///  The parser stores options it doesn't recognize here.
     ///  See the documentation for the "Options" section above.
     pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
@@ -4611,6 +4984,11 @@ impl FileOptions {
             |m: &mut FileOptions| { &mut m.ruby_package },
             FileOptions::get_ruby_package,
         ));
+        fields.push(crate::reflect::rt::v2::make_message_field_accessor::<_, FeatureSet>(
+            "features",
+            |m: &FileOptions| { &m.features },
+            |m: &mut FileOptions| { &mut m.features },
+        ));
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &FileOptions| { &m.uninterpreted_option },
@@ -4626,6 +5004,11 @@ impl FileOptions {
 
 impl crate::Message for FileOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -4672,7 +5055,9 @@ impl crate::Message for FileOptions {
                     if wire_type != crate::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
                     }
-                    self.optimize_for = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 9, &mut self.unknown_fields)? {
+                        self.optimize_for = ::std::option::Option::Some(v);
+                    }
                 },
                 11 => {
                     if wire_type != crate::wire_format::WireTypeLengthDelimited {
@@ -4758,6 +5143,9 @@ impl crate::Message for FileOptions {
                     }
                     self.ruby_package = ::std::option::Option::Some(is.read_string()?);
                 },
+                50 => {
+                    crate::rt::read_singular_message_into_field(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -4769,6 +5157,74 @@ impl crate::Message for FileOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.java_package.is_some() {
+            self.java_package = other.java_package.clone();
+        }
+        if other.java_outer_classname.is_some() {
+            self.java_outer_classname = other.java_outer_classname.clone();
+        }
+        if other.java_multiple_files.is_some() {
+            self.java_multiple_files = other.java_multiple_files.clone();
+        }
+        if other.java_generate_equals_and_hash.is_some() {
+            self.java_generate_equals_and_hash = other.java_generate_equals_and_hash.clone();
+        }
+        if other.java_string_check_utf8.is_some() {
+            self.java_string_check_utf8 = other.java_string_check_utf8.clone();
+        }
+        if other.optimize_for.is_some() {
+            self.optimize_for = other.optimize_for.clone();
+        }
+        if other.go_package.is_some() {
+            self.go_package = other.go_package.clone();
+        }
+        if other.cc_generic_services.is_some() {
+            self.cc_generic_services = other.cc_generic_services.clone();
+        }
+        if other.java_generic_services.is_some() {
+            self.java_generic_services = other.java_generic_services.clone();
+        }
+        if other.py_generic_services.is_some() {
+            self.py_generic_services = other.py_generic_services.clone();
+        }
+        if other.php_generic_services.is_some() {
+            self.php_generic_services = other.php_generic_services.clone();
+        }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if other.cc_enable_arenas.is_some() {
+            self.cc_enable_arenas = other.cc_enable_arenas.clone();
+        }
+        if other.objc_class_prefix.is_some() {
+            self.objc_class_prefix = other.objc_class_prefix.clone();
+        }
+        if other.csharp_namespace.is_some() {
+            self.csharp_namespace = other.csharp_namespace.clone();
+        }
+        if other.swift_prefix.is_some() {
+            self.swift_prefix = other.swift_prefix.clone();
+        }
+        if other.php_class_prefix.is_some() {
+            self.php_class_prefix = other.php_class_prefix.clone();
+        }
+        if other.php_namespace.is_some() {
+            self.php_namespace = other.php_namespace.clone();
+        }
+        if other.php_metadata_namespace.is_some() {
+            self.php_metadata_namespace = other.php_metadata_namespace.clone();
+        }
+        if other.ruby_package.is_some() {
+            self.ruby_package = other.ruby_package.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.features.as_ref() {
+            self.features.mut_or_default().merge_from_message(v);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -4833,6 +5289,10 @@ impl crate::Message for FileOptions {
         if let Some(v) = self.ruby_package.as_ref() {
             my_size += crate::rt::string_size(45, &v);
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
@@ -4903,6 +5363,9 @@ impl crate::Message for FileOptions {
         if let Some(v) = self.ruby_package.as_ref() {
             os.write_string(45, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            crate::rt::write_message_field_with_cached_size(50, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -4952,6 +5415,7 @@ impl crate::Message for FileOptions {
             php_namespace: ::std::option::Option::None,
             php_metadata_namespace: ::std::option::Option::None,
             ruby_package: ::std::option::Option::None,
+            features: crate::MessageField::none(),
             uninterpreted_option: ::std::vec::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -4982,6 +5446,7 @@ impl crate::Clear for FileOptions {
         self.php_namespace = ::std::option::Option::None;
         self.php_metadata_namespace = ::std::option::Option::None;
         self.ruby_package = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
@@ -5032,7 +5497,7 @@ pub mod file_options {
         }
 
         fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
-            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 2)
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 3)
         }
     }
 
@@ -5048,14 +5513,53 @@ pub mod file_options {
     }
 
     impl OptimizeMode {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [OptimizeMode] = &[
+            OptimizeMode::SPEED,
+            OptimizeMode::CODE_SIZE,
+            OptimizeMode::LITE_RUNTIME,
+        ];
+
         pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
-            crate::reflect::GeneratedEnumDescriptorData::new_2::<OptimizeMode>("FileOptions.OptimizeMode", 2)
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<OptimizeMode>("FileOptions.OptimizeMode", 3)
+        }
+    }
+
+    impl ::std::str::FromStr for OptimizeMode {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "SPEED" => ::std::result::Result::Ok(OptimizeMode::SPEED),
+                "CODE_SIZE" => ::std::result::Result::Ok(OptimizeMode::CODE_SIZE),
+                "LITE_RUNTIME" => ::std::result::Result::Ok(OptimizeMode::LITE_RUNTIME),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FileOptions.OptimizeMode", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for OptimizeMode {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                OptimizeMode::SPEED => "SPEED",
+                OptimizeMode::CODE_SIZE => "CODE_SIZE",
+                OptimizeMode::LITE_RUNTIME => "LITE_RUNTIME",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for OptimizeMode {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FileOptions.OptimizeMode", value))
         }
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct MessageOptions {
     // message fields
     message_set_wire_format: ::std::option::Option<bool>,
@@ -5069,6 +5573,8 @@ pub struct MessageOptions {
     ///  this is a formalization for deprecating messages.
     deprecated: ::std::option::Option<bool>,
     map_entry: ::std::option::Option<bool>,
+    ///  Any features defined in the specific edition.
+    pub features: crate::MessageField<FeatureSet>,
     ///  The parser stores options it doesn't recognize here. See above.
     pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
     // special fields
@@ -5191,6 +5697,11 @@ impl MessageOptions {
             |m: &mut MessageOptions| { &mut m.map_entry },
             MessageOptions::get_map_entry,
         ));
+        fields.push(crate::reflect::rt::v2::make_message_field_accessor::<_, FeatureSet>(
+            "features",
+            |m: &MessageOptions| { &m.features },
+            |m: &mut MessageOptions| { &mut m.features },
+        ));
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &MessageOptions| { &m.uninterpreted_option },
@@ -5206,6 +5717,11 @@ impl MessageOptions {
 
 impl crate::Message for MessageOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -5242,6 +5758,9 @@ impl crate::Message for MessageOptions {
                     }
                     self.map_entry = ::std::option::Option::Some(is.read_bool()?);
                 },
+                12 => {
+                    crate::rt::read_singular_message_into_field(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -5253,6 +5772,26 @@ impl crate::Message for MessageOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.message_set_wire_format.is_some() {
+            self.message_set_wire_format = other.message_set_wire_format.clone();
+        }
+        if other.no_standard_descriptor_accessor.is_some() {
+            self.no_standard_descriptor_accessor = other.no_standard_descriptor_accessor.clone();
+        }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if other.map_entry.is_some() {
+            self.map_entry = other.map_entry.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.features.as_ref() {
+            self.features.mut_or_default().merge_from_message(v);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -5269,6 +5808,10 @@ impl crate::Message for MessageOptions {
         if let Some(v) = self.map_entry {
             my_size += 2;
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + crate::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
@@ -5291,6 +5834,9 @@ impl crate::Message for MessageOptions {
         if let Some(v) = self.map_entry {
             os.write_bool(7, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            crate::rt::write_message_field_with_cached_size(12, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -5324,6 +5870,7 @@ impl crate::Message for MessageOptions {
             no_standard_descriptor_accessor: ::std::option::Option::None,
             deprecated: ::std::option::Option::None,
             map_entry: ::std::option::Option::None,
+            features: crate::MessageField::none(),
             uninterpreted_option: ::std::vec::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -5338,6 +5885,7 @@ impl crate::Clear for MessageOptions {
         self.no_standard_descriptor_accessor = ::std::option::Option::None;
         self.deprecated = ::std::option::Option::None;
         self.map_entry = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
@@ -5354,7 +5902,7 @@ impl crate::reflect::ProtobufValue for MessageOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct FieldOptions {
     // message fields
     ///  The ctype option instructs the C++ code generator to use a different
@@ -5416,6 +5964,8 @@ pub struct FieldOptions {
     deprecated: ::std::option::Option<bool>,
     ///  For Google-internal migration only. Do not use.
     weak: ::std::option::Option<bool>,
+    ///  Any features defined in the specific edition.
+    pub features: crate::MessageField<FeatureSet>,
     ///  The parser stores options it doesn't recognize here. See above.
     pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
     // special fields
@@ -5594,6 +6144,11 @@ impl FieldOptions {
             |m: &mut FieldOptions| { &mut m.weak },
             FieldOptions::get_weak,
         ));
+        fields.push(crate::reflect::rt::v2::make_message_field_accessor::<_, FeatureSet>(
+            "features",
+            |m: &FieldOptions| { &m.features },
+            |m: &mut FieldOptions| { &mut m.features },
+        ));
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &FieldOptions| { &m.uninterpreted_option },
@@ -5609,6 +6164,11 @@ impl FieldOptions {
 
 impl crate::Message for FieldOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -5625,7 +6185,9 @@ impl crate::Message for FieldOptions {
                     if wire_type != crate::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
                     }
-                    self.ctype = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 1, &mut self.unknown_fields)? {
+                        self.ctype = ::std::option::Option::Some(v);
+                    }
                 },
                 2 => {
                     if wire_type != crate::wire_format::WireTypeVarint {
@@ -5637,7 +6199,9 @@ impl crate::Message for FieldOptions {
                     if wire_type != crate::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
                     }
-                    self.jstype = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 6, &mut self.unknown_fields)? {
+                        self.jstype = ::std::option::Option::Some(v);
+                    }
                 },
                 5 => {
                     if wire_type != crate::wire_format::WireTypeVarint {
@@ -5657,6 +6221,9 @@ impl crate::Message for FieldOptions {
                     }
                     self.weak = ::std::option::Option::Some(is.read_bool()?);
                 },
+                21 => {
+                    crate::rt::read_singular_message_into_field(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -5668,6 +6235,32 @@ impl crate::Message for FieldOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.ctype.is_some() {
+            self.ctype = other.ctype.clone();
+        }
+        if other.packed.is_some() {
+            self.packed = other.packed.clone();
+        }
+        if other.jstype.is_some() {
+            self.jstype = other.jstype.clone();
+        }
+        if other.lazy.is_some() {
+            self.lazy = other.lazy.clone();
+        }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if other.weak.is_some() {
+            self.weak = other.weak.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.features.as_ref() {
+            self.features.mut_or_default().merge_from_message(v);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -5690,6 +6283,10 @@ impl crate::Message for FieldOptions {
         if let Some(v) = self.weak {
             my_size += 2;
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
@@ -5718,6 +6315,9 @@ impl crate::Message for FieldOptions {
         if let Some(v) = self.weak {
             os.write_bool(10, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            crate::rt::write_message_field_with_cached_size(21, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -5753,6 +6353,7 @@ impl crate::Message for FieldOptions {
             lazy: ::std::option::Option::None,
             deprecated: ::std::option::Option::None,
             weak: ::std::option::Option::None,
+            features: crate::MessageField::none(),
             uninterpreted_option: ::std::vec::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -5769,6 +6370,7 @@ impl crate::Clear for FieldOptions {
         self.lazy = ::std::option::Option::None;
         self.deprecated = ::std::option::Option::None;
         self.weak = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
@@ -5818,7 +6420,7 @@ pub mod field_options {
         }
 
         fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
-            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 3)
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 4)
         }
     }
 
@@ -5833,8 +6435,47 @@ pub mod field_options {
     }
 
     impl CType {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [CType] = &[
+            CType::STRING,
+            CType::CORD,
+            CType::STRING_PIECE,
+        ];
+
         pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
-            crate::reflect::GeneratedEnumDescriptorData::new_2::<CType>("FieldOptions.CType", 3)
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<CType>("FieldOptions.CType", 4)
+        }
+    }
+
+    impl ::std::str::FromStr for CType {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "STRING" => ::std::result::Result::Ok(CType::STRING),
+                "CORD" => ::std::result::Result::Ok(CType::CORD),
+                "STRING_PIECE" => ::std::result::Result::Ok(CType::STRING_PIECE),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FieldOptions.CType", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for CType {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                CType::STRING => "STRING",
+                CType::CORD => "CORD",
+                CType::STRING_PIECE => "STRING_PIECE",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for CType {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FieldOptions.CType", value))
         }
     }
 
@@ -5870,7 +6511,7 @@ pub mod field_options {
         }
 
         fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
-            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 4)
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 5)
         }
     }
 
@@ -5885,16 +6526,57 @@ pub mod field_options {
     }
 
     impl JSType {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [JSType] = &[
+            JSType::JS_NORMAL,
+            JSType::JS_STRING,
+            JSType::JS_NUMBER,
+        ];
+
         pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
-            crate::reflect::GeneratedEnumDescriptorData::new_2::<JSType>("FieldOptions.JSType", 4)
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<JSType>("FieldOptions.JSType", 5)
+        }
+    }
+
+    impl ::std::str::FromStr for JSType {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "JS_NORMAL" => ::std::result::Result::Ok(JSType::JS_NORMAL),
+                "JS_STRING" => ::std::result::Result::Ok(JSType::JS_STRING),
+                "JS_NUMBER" => ::std::result::Result::Ok(JSType::JS_NUMBER),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FieldOptions.JSType", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for JSType {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                JSType::JS_NORMAL => "JS_NORMAL",
+                JSType::JS_STRING => "JS_STRING",
+                JSType::JS_NUMBER => "JS_NUMBER",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for JSType {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FieldOptions.JSType", value))
         }
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct OneofOptions {
     // message fields
+    ///  Any features defined in the specific edition.
+    pub features: crate::MessageField<FeatureSet>,
     ///  The parser stores options it doesn't recognize here. See above.
     pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
     // special fields
@@ -5917,6 +6599,11 @@ impl OneofOptions {
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::new();
+        fields.push(crate::reflect::rt::v2::make_message_field_accessor::<_, FeatureSet>(
+            "features",
+            |m: &OneofOptions| { &m.features },
+            |m: &mut OneofOptions| { &mut m.features },
+        ));
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &OneofOptions| { &m.uninterpreted_option },
@@ -5932,7 +6619,12 @@ impl OneofOptions {
 
 impl crate::Message for OneofOptions {
     fn is_initialized(&self) -> bool {
-        for v in &self.uninterpreted_option {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
             }
@@ -5944,6 +6636,9 @@ impl crate::Message for OneofOptions {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    crate::rt::read_singular_message_into_field(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -5955,10 +6650,22 @@ impl crate::Message for OneofOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if let ::std::option::Option::Some(v) = other.features.as_ref() {
+            self.features.mut_or_default().merge_from_message(v);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + crate::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
@@ -5969,6 +6676,9 @@ impl crate::Message for OneofOptions {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::ProtobufResult<()> {
+        if let Some(v) = self.features.as_ref() {
+            crate::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -5998,6 +6708,7 @@ impl crate::Message for OneofOptions {
 
     fn default_instance() -> &'static OneofOptions {
         static instance: OneofOptions = OneofOptions {
+            features: crate::MessageField::none(),
             uninterpreted_option: ::std::vec::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -6008,6 +6719,7 @@ impl crate::Message for OneofOptions {
 
 impl crate::Clear for OneofOptions {
     fn clear(&mut self) {
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
@@ -6024,7 +6736,7 @@ impl crate::reflect::ProtobufValue for OneofOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct EnumOptions {
     // message fields
     ///  Set this option to true to allow mapping different tag names to the same
@@ -6035,6 +6747,8 @@ pub struct EnumOptions {
     ///  for the enum, or it will be completely ignored; in the very least, this
     ///  is a formalization for deprecating enums.
     deprecated: ::std::option::Option<bool>,
+    ///  Any features defined in the specific edition.
+    pub features: crate::MessageField<FeatureSet>,
     ///  The parser stores options it doesn't recognize here. See above.
     pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
     // special fields
@@ -6107,6 +6821,11 @@ impl EnumOptions {
             |m: &mut EnumOptions| { &mut m.deprecated },
             EnumOptions::get_deprecated,
         ));
+        fields.push(crate::reflect::rt::v2::make_message_field_accessor::<_, FeatureSet>(
+            "features",
+            |m: &EnumOptions| { &m.features },
+            |m: &mut EnumOptions| { &mut m.features },
+        ));
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &EnumOptions| { &m.uninterpreted_option },
@@ -6122,6 +6841,11 @@ impl EnumOptions {
 
 impl crate::Message for EnumOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -6146,6 +6870,9 @@ impl crate::Message for EnumOptions {
                     }
                     self.deprecated = ::std::option::Option::Some(is.read_bool()?);
                 },
+                7 => {
+                    crate::rt::read_singular_message_into_field(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -6157,6 +6884,20 @@ impl crate::Message for EnumOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.allow_alias.is_some() {
+            self.allow_alias = other.allow_alias.clone();
+        }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.features.as_ref() {
+            self.features.mut_or_default().merge_from_message(v);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -6167,6 +6908,10 @@ impl crate::Message for EnumOptions {
         if let Some(v) = self.deprecated {
             my_size += 2;
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + crate::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
@@ -6183,6 +6928,9 @@ impl crate::Message for EnumOptions {
         if let Some(v) = self.deprecated {
             os.write_bool(3, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            crate::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -6214,6 +6962,7 @@ impl crate::Message for EnumOptions {
         static instance: EnumOptions = EnumOptions {
             allow_alias: ::std::option::Option::None,
             deprecated: ::std::option::Option::None,
+            features: crate::MessageField::none(),
             uninterpreted_option: ::std::vec::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -6226,6 +6975,7 @@ impl crate::Clear for EnumOptions {
     fn clear(&mut self) {
         self.allow_alias = ::std::option::Option::None;
         self.deprecated = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
@@ -6242,7 +6992,7 @@ impl crate::reflect::ProtobufValue for EnumOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct EnumValueOptions {
     // message fields
     ///  Is this enum value deprecated?
@@ -6250,6 +7000,8 @@ pub struct EnumValueOptions {
     ///  for the enum value, or it will be completely ignored; in the very least,
     ///  this is a formalization for deprecating enum values.
     deprecated: ::std::option::Option<bool>,
+    ///  Any features defined in the specific edition.
+    pub features: crate::MessageField<FeatureSet>,
     ///  The parser stores options it doesn't recognize here. See above.
     pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
     // special fields
@@ -6297,6 +7049,11 @@ impl EnumValueOptions {
             |m: &mut EnumValueOptions| { &mut m.deprecated },
             EnumValueOptions::get_deprecated,
         ));
+        fields.push(crate::reflect::rt::v2::make_message_field_accessor::<_, FeatureSet>(
+            "features",
+            |m: &EnumValueOptions| { &m.features },
+            |m: &mut EnumValueOptions| { &mut m.features },
+        ));
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &EnumValueOptions| { &m.uninterpreted_option },
@@ -6312,6 +7069,11 @@ impl EnumValueOptions {
 
 impl crate::Message for EnumValueOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -6330,6 +7092,9 @@ impl crate::Message for EnumValueOptions {
                     }
                     self.deprecated = ::std::option::Option::Some(is.read_bool()?);
                 },
+                2 => {
+                    crate::rt::read_singular_message_into_field(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -6341,6 +7106,17 @@ impl crate::Message for EnumValueOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.features.as_ref() {
+            self.features.mut_or_default().merge_from_message(v);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -6348,6 +7124,10 @@ impl crate::Message for EnumValueOptions {
         if let Some(v) = self.deprecated {
             my_size += 2;
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + crate::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
@@ -6361,6 +7141,9 @@ impl crate::Message for EnumValueOptions {
         if let Some(v) = self.deprecated {
             os.write_bool(1, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            crate::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -6391,6 +7174,7 @@ impl crate::Message for EnumValueOptions {
     fn default_instance() -> &'static EnumValueOptions {
         static instance: EnumValueOptions = EnumValueOptions {
             deprecated: ::std::option::Option::None,
+            features: crate::MessageField::none(),
             uninterpreted_option: ::std::vec::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -6402,6 +7186,7 @@ impl crate::Message for EnumValueOptions {
 impl crate::Clear for EnumValueOptions {
     fn clear(&mut self) {
         self.deprecated = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
@@ -6418,7 +7203,7 @@ impl crate::reflect::ProtobufValue for EnumValueOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct ServiceOptions {
     // message fields
     ///  Is this service deprecated?
@@ -6426,6 +7211,8 @@ pub struct ServiceOptions {
     ///  for the service, or it will be completely ignored; in the very least,
     ///  this is a formalization for deprecating services.
     deprecated: ::std::option::Option<bool>,
+    ///  Any features defined in the specific edition.
+    pub features: crate::MessageField<FeatureSet>,
     ///  The parser stores options it doesn't recognize here. See above.
     pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
     // special fields
@@ -6473,6 +7260,11 @@ impl ServiceOptions {
             |m: &mut ServiceOptions| { &mut m.deprecated },
             ServiceOptions::get_deprecated,
         ));
+        fields.push(crate::reflect::rt::v2::make_message_field_accessor::<_, FeatureSet>(
+            "features",
+            |m: &ServiceOptions| { &m.features },
+            |m: &mut ServiceOptions| { &mut m.features },
+        ));
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &ServiceOptions| { &m.uninterpreted_option },
@@ -6488,6 +7280,11 @@ impl ServiceOptions {
 
 impl crate::Message for ServiceOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -6506,6 +7303,9 @@ impl crate::Message for ServiceOptions {
                     }
                     self.deprecated = ::std::option::Option::Some(is.read_bool()?);
                 },
+                34 => {
+                    crate::rt::read_singular_message_into_field(wire_type, is, &mut self.features)?;
+                },
                 999 => {
                     crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.uninterpreted_option)?;
                 },
@@ -6517,6 +7317,17 @@ impl crate::Message for ServiceOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.features.as_ref() {
+            self.features.mut_or_default().merge_from_message(v);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -6524,6 +7335,10 @@ impl crate::Message for ServiceOptions {
         if let Some(v) = self.deprecated {
             my_size += 3;
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
@@ -6537,6 +7352,9 @@ impl crate::Message for ServiceOptions {
         if let Some(v) = self.deprecated {
             os.write_bool(33, v)?;
         }
+        if let Some(v) = self.features.as_ref() {
+            crate::rt::write_message_field_with_cached_size(34, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -6567,6 +7385,7 @@ impl crate::Message for ServiceOptions {
     fn default_instance() -> &'static ServiceOptions {
         static instance: ServiceOptions = ServiceOptions {
             deprecated: ::std::option::Option::None,
+            features: crate::MessageField::none(),
             uninterpreted_option: ::std::vec::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -6578,6 +7397,7 @@ impl crate::Message for ServiceOptions {
 impl crate::Clear for ServiceOptions {
     fn clear(&mut self) {
         self.deprecated = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
@@ -6594,7 +7414,7 @@ impl crate::reflect::ProtobufValue for ServiceOptions {
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct MethodOptions {
     // message fields
     ///  Is this method deprecated?
@@ -6603,6 +7423,8 @@ pub struct MethodOptions {
     ///  this is a formalization for deprecating methods.
     deprecated: ::std::option::Option<bool>,
     idempotency_level: ::std::option::Option<crate::ProtobufEnumOrUnknown<method_options::IdempotencyLevel>>,
+    ///  Any features defined in the specific edition.
+    pub features: crate::MessageField<FeatureSet>,
     ///  The parser stores options it doesn't recognize here. See above.
     pub uninterpreted_option: ::std::vec::Vec<UninterpretedOption>,
     // special fields
@@ -6678,6 +7500,11 @@ impl MethodOptions {
             |m: &mut MethodOptions| { &mut m.idempotency_level },
             method_options::IdempotencyLevel::IDEMPOTENCY_UNKNOWN,
         ));
+        fields.push(crate::reflect::rt::v2::make_message_field_accessor::<_, FeatureSet>(
+            "features",
+            |m: &MethodOptions| { &m.features },
+            |m: &mut MethodOptions| { &mut m.features },
+        ));
         fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
             "uninterpreted_option",
             |m: &MethodOptions| { &m.uninterpreted_option },
@@ -6693,6 +7520,11 @@ impl MethodOptions {
 
 impl crate::Message for MethodOptions {
     fn is_initialized(&self) -> bool {
+        for v in &self.features {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         for v in &self.uninterpreted_option {
             if !v.is_initialized() {
                 return false;
@@ -6715,7 +7547,12 @@ impl crate::Message for MethodOptions {
                     if wire_type != crate::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
                     }
-                    self.idempotency_level = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 34, &mut self.unknown_fields)? {
+                        self.idempotency_level = ::std::option::Option::Some(v);
+                    }
+                },
+                35 => {
+                    crate::rt::read_singular_message_into_field(wire_type, is, &mut self.features)?;
                 },
                 999 => {
                     crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.uninterpreted_option)?;
@@ -6728,6 +7565,20 @@ impl crate::Message for MethodOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if other.idempotency_level.is_some() {
+            self.idempotency_level = other.idempotency_level.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.features.as_ref() {
+            self.features.mut_or_default().merge_from_message(v);
+        }
+        self.uninterpreted_option.extend(other.uninterpreted_option.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -6738,6 +7589,10 @@ impl crate::Message for MethodOptions {
         if let Some(v) = self.idempotency_level {
             my_size += crate::rt::enum_or_unknown_size(34, v);
         }
+        if let Some(v) = self.features.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
+        }
         for value in &self.uninterpreted_option {
             let len = value.compute_size();
             my_size += 2 + crate::rt::compute_raw_varint32_size(len) + len;
@@ -6754,6 +7609,9 @@ impl crate::Message for MethodOptions {
         if let Some(v) = self.idempotency_level {
             os.write_enum(34, crate::ProtobufEnumOrUnknown::value(&v))?;
         }
+        if let Some(v) = self.features.as_ref() {
+            crate::rt::write_message_field_with_cached_size(35, v, os)?;
+        }
         for v in &self.uninterpreted_option {
             crate::rt::write_message_field_with_cached_size(999, v, os)?;
         };
@@ -6785,6 +7643,7 @@ impl crate::Message for MethodOptions {
         static instance: MethodOptions = MethodOptions {
             deprecated: ::std::option::Option::None,
             idempotency_level: ::std::option::Option::None,
+            features: crate::MessageField::none(),
             uninterpreted_option: ::std::vec::Vec::new(),
             unknown_fields: crate::UnknownFields::new(),
             cached_size: crate::rt::CachedSize::new(),
@@ -6797,6 +7656,7 @@ impl crate::Clear for MethodOptions {
     fn clear(&mut self) {
         self.deprecated = ::std::option::Option::None;
         self.idempotency_level = ::std::option::Option::None;
+        self.features.clear();
         self.uninterpreted_option.clear();
         self.unknown_fields.clear();
     }
@@ -6849,7 +7709,7 @@ pub mod method_options {
         }
 
         fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
-            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 5)
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 6)
         }
     }
 
@@ -6864,8 +7724,47 @@ pub mod method_options {
     }
 
     impl IdempotencyLevel {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [IdempotencyLevel] = &[
+            IdempotencyLevel::IDEMPOTENCY_UNKNOWN,
+            IdempotencyLevel::NO_SIDE_EFFECTS,
+            IdempotencyLevel::IDEMPOTENT,
+        ];
+
         pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
-            crate::reflect::GeneratedEnumDescriptorData::new_2::<IdempotencyLevel>("MethodOptions.IdempotencyLevel", 5)
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<IdempotencyLevel>("MethodOptions.IdempotencyLevel", 6)
+        }
+    }
+
+    impl ::std::str::FromStr for IdempotencyLevel {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "IDEMPOTENCY_UNKNOWN" => ::std::result::Result::Ok(IdempotencyLevel::IDEMPOTENCY_UNKNOWN),
+                "NO_SIDE_EFFECTS" => ::std::result::Result::Ok(IdempotencyLevel::NO_SIDE_EFFECTS),
+                "IDEMPOTENT" => ::std::result::Result::Ok(IdempotencyLevel::IDEMPOTENT),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.MethodOptions.IdempotencyLevel", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for IdempotencyLevel {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                IdempotencyLevel::IDEMPOTENCY_UNKNOWN => "IDEMPOTENCY_UNKNOWN",
+                IdempotencyLevel::NO_SIDE_EFFECTS => "NO_SIDE_EFFECTS",
+                IdempotencyLevel::IDEMPOTENT => "IDEMPOTENT",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for IdempotencyLevel {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.MethodOptions.IdempotencyLevel", value))
         }
     }
 }
@@ -6877,7 +7776,7 @@ pub mod method_options {
 ///  or produced by Descriptor::CopyTo()) will never have UninterpretedOptions
 ///  in them.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct UninterpretedOption {
     // message fields
     pub name: ::std::vec::Vec<uninterpreted_option::NamePart>,
@@ -7184,6 +8083,29 @@ impl crate::Message for UninterpretedOption {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        self.name.extend(other.name.iter().cloned());
+        if other.identifier_value.is_some() {
+            self.identifier_value = other.identifier_value.clone();
+        }
+        if other.positive_int_value.is_some() {
+            self.positive_int_value = other.positive_int_value.clone();
+        }
+        if other.negative_int_value.is_some() {
+            self.negative_int_value = other.negative_int_value.clone();
+        }
+        if other.double_value.is_some() {
+            self.double_value = other.double_value.clone();
+        }
+        if other.string_value.is_some() {
+            self.string_value = other.string_value.clone();
+        }
+        if other.aggregate_value.is_some() {
+            self.aggregate_value = other.aggregate_value.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -7308,7 +8230,7 @@ pub mod uninterpreted_option {
     ///  E.g.,{ ["foo", false], ["bar.baz", true], ["qux", false] } represents
     ///  "foo.(bar.baz).qux".
     #[derive(PartialEq,Clone,Default)]
-    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    #[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
     pub struct NamePart {
         // message fields
         name_part: ::std::option::Option<::std::string::String>,
@@ -7402,7 +8324,7 @@ pub mod uninterpreted_option {
             ));
             crate::reflect::GeneratedMessageDescriptorData::new_2::<NamePart>(
                 "UninterpretedOption.NamePart",
-                24,
+                25,
                 fields,
             )
         }
@@ -7443,6 +8365,16 @@ pub mod uninterpreted_option {
             ::std::result::Result::Ok(())
         }
 
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.name_part.is_some() {
+                self.name_part = other.name_part.clone();
+            }
+            if other.is_extension.is_some() {
+                self.is_extension = other.is_extension.clone();
+            }
+            self.unknown_fields.merge_from(&other.unknown_fields);
+        }
+
         // Compute sizes of nested messages
         #[allow(unused_variables)]
         fn compute_size(&self) -> u32 {
@@ -7519,13 +8451,19 @@ pub mod uninterpreted_option {
     }
 }
 
-///  Encapsulates information about the original source file from which a
-///  FileDescriptorProto was generated.
+///  Features are settings of elements of `.proto` files which are inherited
+///  by nested elements: file features are defaults for messages, message
+///  features are defaults for fields, and so on.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
-pub struct SourceCodeInfo {
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
+pub struct FeatureSet {
     // message fields
-    pub location: ::std::vec::Vec<source_code_info::Location>,
+    field_presence: ::std::option::Option<crate::ProtobufEnumOrUnknown<feature_set::FieldPresence>>,
+    enum_type: ::std::option::Option<crate::ProtobufEnumOrUnknown<feature_set::EnumType>>,
+    repeated_field_encoding: ::std::option::Option<crate::ProtobufEnumOrUnknown<feature_set::RepeatedFieldEncoding>>,
+    utf8_validation: ::std::option::Option<crate::ProtobufEnumOrUnknown<feature_set::Utf8Validation>>,
+    message_encoding: ::std::option::Option<crate::ProtobufEnumOrUnknown<feature_set::MessageEncoding>>,
+    json_format: ::std::option::Option<crate::ProtobufEnumOrUnknown<feature_set::JsonFormat>>,
     // special fields
     #[cfg_attr(serde, serde(skip))]
     pub unknown_fields: crate::UnknownFields,
@@ -7533,170 +8471,1127 @@ pub struct SourceCodeInfo {
     pub cached_size: crate::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SourceCodeInfo {
-    fn default() -> &'a SourceCodeInfo {
-        <SourceCodeInfo as crate::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a FeatureSet {
+    fn default() -> &'a FeatureSet {
+        <FeatureSet as crate::Message>::default_instance()
     }
 }
 
-impl SourceCodeInfo {
-    pub fn new() -> SourceCodeInfo {
+impl FeatureSet {
+    pub fn new() -> FeatureSet {
         ::std::default::Default::default()
     }
 
-    fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::new();
-        fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "location",
-            |m: &SourceCodeInfo| { &m.location },
-            |m: &mut SourceCodeInfo| { &mut m.location },
-        ));
-        crate::reflect::GeneratedMessageDescriptorData::new_2::<SourceCodeInfo>(
-            "SourceCodeInfo",
-            19,
-            fields,
-        )
-    }
-}
+    // optional .google.protobuf.FeatureSet.FieldPresence field_presence = 1;
 
-impl crate::Message for SourceCodeInfo {
-    fn is_initialized(&self) -> bool {
-        for v in &self.location {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        true
+    pub fn get_field_presence(&self) -> feature_set::FieldPresence {
+        match self.field_presence {
+            Some(e) => e.enum_value_or(feature_set::FieldPresence::FIELD_PRESENCE_UNKNOWN),
+            None => feature_set::FieldPresence::FIELD_PRESENCE_UNKNOWN,
+        }
     }
 
-    fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.location)?;
-                },
-                _ => {
-                    crate::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    pub fn clear_field_presence(&mut self) {
+        self.field_presence = ::std::option::Option::None;
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        for value in &self.location {
-            let len = value.compute_size();
-            my_size += 1 + crate::rt::compute_raw_varint32_size(len) + len;
-        };
-        my_size += crate::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    pub fn has_field_presence(&self) -> bool {
+        self.field_presence.is_some()
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::ProtobufResult<()> {
-        for v in &self.location {
-            crate::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
+    // Param is passed by value, moved
+    pub fn set_field_presence(&mut self, v: feature_set::FieldPresence) {
+        self.field_presence = ::std::option::Option::Some(crate::ProtobufEnumOrUnknown::new(v));
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // optional .google.protobuf.FeatureSet.EnumType enum_type = 2;
+
+    pub fn get_enum_type(&self) -> feature_set::EnumType {
+        match self.enum_type {
+            Some(e) => e.enum_value_or(feature_set::EnumType::ENUM_TYPE_UNKNOWN),
+            None => feature_set::EnumType::ENUM_TYPE_UNKNOWN,
+        }
     }
 
-    fn get_unknown_fields(&self) -> &crate::UnknownFields {
-        &self.unknown_fields
+    pub fn clear_enum_type(&mut self) {
+        self.enum_type = ::std::option::Option::None;
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut crate::UnknownFields {
-        &mut self.unknown_fields
+    pub fn has_enum_type(&self) -> bool {
+        self.enum_type.is_some()
     }
 
-    fn new() -> SourceCodeInfo {
-        SourceCodeInfo::new()
+    // Param is passed by value, moved
+    pub fn set_enum_type(&mut self, v: feature_set::EnumType) {
+        self.enum_type = ::std::option::Option::Some(crate::ProtobufEnumOrUnknown::new(v));
     }
 
-    fn descriptor_static() -> crate::reflect::MessageDescriptor {
-        crate::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 23)
+    // optional .google.protobuf.FeatureSet.RepeatedFieldEncoding repeated_field_encoding = 3;
+
+    pub fn get_repeated_field_encoding(&self) -> feature_set::RepeatedFieldEncoding {
+        match self.repeated_field_encoding {
+            Some(e) => e.enum_value_or(feature_set::RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN),
+            None => feature_set::RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN,
+        }
     }
 
-    fn default_instance() -> &'static SourceCodeInfo {
-        static instance: SourceCodeInfo = SourceCodeInfo {
-            location: ::std::vec::Vec::new(),
-            unknown_fields: crate::UnknownFields::new(),
-            cached_size: crate::rt::CachedSize::new(),
-        };
-        &instance
+    pub fn clear_repeated_field_encoding(&mut self) {
+        self.repeated_field_encoding = ::std::option::Option::None;
     }
-}
 
-impl crate::Clear for SourceCodeInfo {
-    fn clear(&mut self) {
-        self.location.clear();
-        self.unknown_fields.clear();
+    pub fn has_repeated_field_encoding(&self) -> bool {
+        self.repeated_field_encoding.is_some()
     }
-}
 
-impl ::std::fmt::Debug for SourceCodeInfo {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        crate::text_format::fmt(self, f)
+    // Param is passed by value, moved
+    pub fn set_repeated_field_encoding(&mut self, v: feature_set::RepeatedFieldEncoding) {
+        self.repeated_field_encoding = ::std::option::Option::Some(crate::ProtobufEnumOrUnknown::new(v));
     }
-}
 
-impl crate::reflect::ProtobufValue for SourceCodeInfo {
-    type RuntimeType = crate::reflect::runtime_types::RuntimeTypeMessage<Self>;
-}
+    // optional .google.protobuf.FeatureSet.Utf8Validation utf8_validation = 4;
 
-/// Nested message and enums of message `SourceCodeInfo`
-pub mod source_code_info {
-    #[derive(PartialEq,Clone,Default)]
-    #[cfg_attr(serde, derive(Serialize, Deserialize))]
-    pub struct Location {
-        // message fields
-        pub path: ::std::vec::Vec<i32>,
-        ///  Always has exactly three or four elements: start line, start column,
-        ///  end line (optional, otherwise assumed same as start line), end column.
-        ///  These are packed into a single field for efficiency.  Note that line
-        ///  and column numbers are zero-based -- typically you will want to add
-        ///  1 to each before displaying to a user.
-        pub span: ::std::vec::Vec<i32>,
-        leading_comments: ::std::option::Option<::std::string::String>,
-        trailing_comments: ::std::option::Option<::std::string::String>,
-        pub leading_detached_comments: ::std::vec::Vec<::std::string::String>,
-        // special fields
-        #[cfg_attr(serde, serde(skip))]
-        pub unknown_fields: crate::UnknownFields,
-        #[cfg_attr(serde, serde(skip))]
-        pub cached_size: crate::rt::CachedSize,
+    pub fn get_utf8_validation(&self) -> feature_set::Utf8Validation {
+        match self.utf8_validation {
+            Some(e) => e.enum_value_or(feature_set::Utf8Validation::UTF8_VALIDATION_UNKNOWN),
+            None => feature_set::Utf8Validation::UTF8_VALIDATION_UNKNOWN,
+        }
     }
 
-    impl<'a> ::std::default::Default for &'a Location {
-        fn default() -> &'a Location {
-            <Location as crate::Message>::default_instance()
-        }
+    pub fn clear_utf8_validation(&mut self) {
+        self.utf8_validation = ::std::option::Option::None;
     }
 
-    impl Location {
-        pub fn new() -> Location {
-            ::std::default::Default::default()
-        }
+    pub fn has_utf8_validation(&self) -> bool {
+        self.utf8_validation.is_some()
+    }
 
-        // optional string leading_comments = 3;
+    // Param is passed by value, moved
+    pub fn set_utf8_validation(&mut self, v: feature_set::Utf8Validation) {
+        self.utf8_validation = ::std::option::Option::Some(crate::ProtobufEnumOrUnknown::new(v));
+    }
 
-        pub fn get_leading_comments(&self) -> &str {
-            match self.leading_comments.as_ref() {
-                Some(v) => v,
-                None => "",
-            }
-        }
+    // optional .google.protobuf.FeatureSet.MessageEncoding message_encoding = 5;
 
-        pub fn clear_leading_comments(&mut self) {
-            self.leading_comments = ::std::option::Option::None;
+    pub fn get_message_encoding(&self) -> feature_set::MessageEncoding {
+        match self.message_encoding {
+            Some(e) => e.enum_value_or(feature_set::MessageEncoding::MESSAGE_ENCODING_UNKNOWN),
+            None => feature_set::MessageEncoding::MESSAGE_ENCODING_UNKNOWN,
+        }
+    }
+
+    pub fn clear_message_encoding(&mut self) {
+        self.message_encoding = ::std::option::Option::None;
+    }
+
+    pub fn has_message_encoding(&self) -> bool {
+        self.message_encoding.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message_encoding(&mut self, v: feature_set::MessageEncoding) {
+        self.message_encoding = ::std::option::Option::Some(crate::ProtobufEnumOrUnknown::new(v));
+    }
+
+    // optional .google.protobuf.FeatureSet.JsonFormat json_format = 6;
+
+    pub fn get_json_format(&self) -> feature_set::JsonFormat {
+        match self.json_format {
+            Some(e) => e.enum_value_or(feature_set::JsonFormat::JSON_FORMAT_UNKNOWN),
+            None => feature_set::JsonFormat::JSON_FORMAT_UNKNOWN,
+        }
+    }
+
+    pub fn clear_json_format(&mut self) {
+        self.json_format = ::std::option::Option::None;
+    }
+
+    pub fn has_json_format(&self) -> bool {
+        self.json_format.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_json_format(&mut self, v: feature_set::JsonFormat) {
+        self.json_format = ::std::option::Option::Some(crate::ProtobufEnumOrUnknown::new(v));
+    }
+
+    fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(crate::reflect::rt::v2::make_option_enum_accessor::<_, feature_set::FieldPresence>(
+            "field_presence",
+            |m: &FeatureSet| { &m.field_presence },
+            |m: &mut FeatureSet| { &mut m.field_presence },
+            feature_set::FieldPresence::FIELD_PRESENCE_UNKNOWN,
+        ));
+        fields.push(crate::reflect::rt::v2::make_option_enum_accessor::<_, feature_set::EnumType>(
+            "enum_type",
+            |m: &FeatureSet| { &m.enum_type },
+            |m: &mut FeatureSet| { &mut m.enum_type },
+            feature_set::EnumType::ENUM_TYPE_UNKNOWN,
+        ));
+        fields.push(crate::reflect::rt::v2::make_option_enum_accessor::<_, feature_set::RepeatedFieldEncoding>(
+            "repeated_field_encoding",
+            |m: &FeatureSet| { &m.repeated_field_encoding },
+            |m: &mut FeatureSet| { &mut m.repeated_field_encoding },
+            feature_set::RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN,
+        ));
+        fields.push(crate::reflect::rt::v2::make_option_enum_accessor::<_, feature_set::Utf8Validation>(
+            "utf8_validation",
+            |m: &FeatureSet| { &m.utf8_validation },
+            |m: &mut FeatureSet| { &mut m.utf8_validation },
+            feature_set::Utf8Validation::UTF8_VALIDATION_UNKNOWN,
+        ));
+        fields.push(crate::reflect::rt::v2::make_option_enum_accessor::<_, feature_set::MessageEncoding>(
+            "message_encoding",
+            |m: &FeatureSet| { &m.message_encoding },
+            |m: &mut FeatureSet| { &mut m.message_encoding },
+            feature_set::MessageEncoding::MESSAGE_ENCODING_UNKNOWN,
+        ));
+        fields.push(crate::reflect::rt::v2::make_option_enum_accessor::<_, feature_set::JsonFormat>(
+            "json_format",
+            |m: &FeatureSet| { &m.json_format },
+            |m: &mut FeatureSet| { &mut m.json_format },
+            feature_set::JsonFormat::JSON_FORMAT_UNKNOWN,
+        ));
+        crate::reflect::GeneratedMessageDescriptorData::new_2::<FeatureSet>(
+            "FeatureSet",
+            19,
+            fields,
+        )
+    }
+}
+
+impl crate::Message for FeatureSet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != crate::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
+                    }
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 1, &mut self.unknown_fields)? {
+                        self.field_presence = ::std::option::Option::Some(v);
+                    }
+                },
+                2 => {
+                    if wire_type != crate::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
+                    }
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 2, &mut self.unknown_fields)? {
+                        self.enum_type = ::std::option::Option::Some(v);
+                    }
+                },
+                3 => {
+                    if wire_type != crate::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
+                    }
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 3, &mut self.unknown_fields)? {
+                        self.repeated_field_encoding = ::std::option::Option::Some(v);
+                    }
+                },
+                4 => {
+                    if wire_type != crate::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
+                    }
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 4, &mut self.unknown_fields)? {
+                        self.utf8_validation = ::std::option::Option::Some(v);
+                    }
+                },
+                5 => {
+                    if wire_type != crate::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
+                    }
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 5, &mut self.unknown_fields)? {
+                        self.message_encoding = ::std::option::Option::Some(v);
+                    }
+                },
+                6 => {
+                    if wire_type != crate::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(crate::rt::unexpected_wire_type(wire_type));
+                    }
+                    if let ::std::option::Option::Some(v) = crate::rt::read_closed_enum_or_unknown(is, 6, &mut self.unknown_fields)? {
+                        self.json_format = ::std::option::Option::Some(v);
+                    }
+                },
+                _ => {
+                    crate::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.field_presence.is_some() {
+            self.field_presence = other.field_presence.clone();
+        }
+        if other.enum_type.is_some() {
+            self.enum_type = other.enum_type.clone();
+        }
+        if other.repeated_field_encoding.is_some() {
+            self.repeated_field_encoding = other.repeated_field_encoding.clone();
+        }
+        if other.utf8_validation.is_some() {
+            self.utf8_validation = other.utf8_validation.clone();
+        }
+        if other.message_encoding.is_some() {
+            self.message_encoding = other.message_encoding.clone();
+        }
+        if other.json_format.is_some() {
+            self.json_format = other.json_format.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.field_presence {
+            my_size += crate::rt::enum_or_unknown_size(1, v);
+        }
+        if let Some(v) = self.enum_type {
+            my_size += crate::rt::enum_or_unknown_size(2, v);
+        }
+        if let Some(v) = self.repeated_field_encoding {
+            my_size += crate::rt::enum_or_unknown_size(3, v);
+        }
+        if let Some(v) = self.utf8_validation {
+            my_size += crate::rt::enum_or_unknown_size(4, v);
+        }
+        if let Some(v) = self.message_encoding {
+            my_size += crate::rt::enum_or_unknown_size(5, v);
+        }
+        if let Some(v) = self.json_format {
+            my_size += crate::rt::enum_or_unknown_size(6, v);
+        }
+        my_size += crate::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::ProtobufResult<()> {
+        if let Some(v) = self.field_presence {
+            os.write_enum(1, crate::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.enum_type {
+            os.write_enum(2, crate::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.repeated_field_encoding {
+            os.write_enum(3, crate::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.utf8_validation {
+            os.write_enum(4, crate::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.message_encoding {
+            os.write_enum(5, crate::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.json_format {
+            os.write_enum(6, crate::ProtobufEnumOrUnknown::value(&v))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &crate::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut crate::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FeatureSet {
+        FeatureSet::new()
+    }
+
+    fn descriptor_static() -> crate::reflect::MessageDescriptor {
+        crate::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 23)
+    }
+
+    fn default_instance() -> &'static FeatureSet {
+        static instance: FeatureSet = FeatureSet {
+            field_presence: ::std::option::Option::None,
+            enum_type: ::std::option::Option::None,
+            repeated_field_encoding: ::std::option::Option::None,
+            utf8_validation: ::std::option::Option::None,
+            message_encoding: ::std::option::Option::None,
+            json_format: ::std::option::Option::None,
+            unknown_fields: crate::UnknownFields::new(),
+            cached_size: crate::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl crate::Clear for FeatureSet {
+    fn clear(&mut self) {
+        self.field_presence = ::std::option::Option::None;
+        self.enum_type = ::std::option::Option::None;
+        self.repeated_field_encoding = ::std::option::Option::None;
+        self.utf8_validation = ::std::option::Option::None;
+        self.message_encoding = ::std::option::Option::None;
+        self.json_format = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FeatureSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        crate::text_format::fmt(self, f)
+    }
+}
+
+impl crate::reflect::ProtobufValue for FeatureSet {
+    type RuntimeType = crate::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `FeatureSet`
+pub mod feature_set {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum FieldPresence {
+        FIELD_PRESENCE_UNKNOWN = 0,
+        EXPLICIT = 1,
+        IMPLICIT = 2,
+        LEGACY_REQUIRED = 3,
+    }
+
+    impl crate::ProtobufEnum for FieldPresence {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<FieldPresence> {
+            match value {
+                0 => ::std::option::Option::Some(FieldPresence::FIELD_PRESENCE_UNKNOWN),
+                1 => ::std::option::Option::Some(FieldPresence::EXPLICIT),
+                2 => ::std::option::Option::Some(FieldPresence::IMPLICIT),
+                3 => ::std::option::Option::Some(FieldPresence::LEGACY_REQUIRED),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [FieldPresence] = &[
+                FieldPresence::FIELD_PRESENCE_UNKNOWN,
+                FieldPresence::EXPLICIT,
+                FieldPresence::IMPLICIT,
+                FieldPresence::LEGACY_REQUIRED,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 7)
+        }
+    }
+
+    impl ::std::default::Default for FieldPresence {
+        fn default() -> Self {
+            FieldPresence::FIELD_PRESENCE_UNKNOWN
+        }
+    }
+
+    impl crate::reflect::ProtobufValue for FieldPresence {
+        type RuntimeType = crate::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl FieldPresence {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [FieldPresence] = &[
+            FieldPresence::FIELD_PRESENCE_UNKNOWN,
+            FieldPresence::EXPLICIT,
+            FieldPresence::IMPLICIT,
+            FieldPresence::LEGACY_REQUIRED,
+        ];
+
+        pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<FieldPresence>("FeatureSet.FieldPresence", 7)
+        }
+    }
+
+    impl ::std::str::FromStr for FieldPresence {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "FIELD_PRESENCE_UNKNOWN" => ::std::result::Result::Ok(FieldPresence::FIELD_PRESENCE_UNKNOWN),
+                "EXPLICIT" => ::std::result::Result::Ok(FieldPresence::EXPLICIT),
+                "IMPLICIT" => ::std::result::Result::Ok(FieldPresence::IMPLICIT),
+                "LEGACY_REQUIRED" => ::std::result::Result::Ok(FieldPresence::LEGACY_REQUIRED),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FeatureSet.FieldPresence", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for FieldPresence {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                FieldPresence::FIELD_PRESENCE_UNKNOWN => "FIELD_PRESENCE_UNKNOWN",
+                FieldPresence::EXPLICIT => "EXPLICIT",
+                FieldPresence::IMPLICIT => "IMPLICIT",
+                FieldPresence::LEGACY_REQUIRED => "LEGACY_REQUIRED",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for FieldPresence {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FeatureSet.FieldPresence", value))
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum EnumType {
+        ENUM_TYPE_UNKNOWN = 0,
+        OPEN = 1,
+        CLOSED = 2,
+    }
+
+    impl crate::ProtobufEnum for EnumType {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<EnumType> {
+            match value {
+                0 => ::std::option::Option::Some(EnumType::ENUM_TYPE_UNKNOWN),
+                1 => ::std::option::Option::Some(EnumType::OPEN),
+                2 => ::std::option::Option::Some(EnumType::CLOSED),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [EnumType] = &[
+                EnumType::ENUM_TYPE_UNKNOWN,
+                EnumType::OPEN,
+                EnumType::CLOSED,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 8)
+        }
+    }
+
+    impl ::std::default::Default for EnumType {
+        fn default() -> Self {
+            EnumType::ENUM_TYPE_UNKNOWN
+        }
+    }
+
+    impl crate::reflect::ProtobufValue for EnumType {
+        type RuntimeType = crate::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl EnumType {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [EnumType] = &[
+            EnumType::ENUM_TYPE_UNKNOWN,
+            EnumType::OPEN,
+            EnumType::CLOSED,
+        ];
+
+        pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<EnumType>("FeatureSet.EnumType", 8)
+        }
+    }
+
+    impl ::std::str::FromStr for EnumType {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "ENUM_TYPE_UNKNOWN" => ::std::result::Result::Ok(EnumType::ENUM_TYPE_UNKNOWN),
+                "OPEN" => ::std::result::Result::Ok(EnumType::OPEN),
+                "CLOSED" => ::std::result::Result::Ok(EnumType::CLOSED),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FeatureSet.EnumType", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for EnumType {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                EnumType::ENUM_TYPE_UNKNOWN => "ENUM_TYPE_UNKNOWN",
+                EnumType::OPEN => "OPEN",
+                EnumType::CLOSED => "CLOSED",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for EnumType {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FeatureSet.EnumType", value))
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum RepeatedFieldEncoding {
+        REPEATED_FIELD_ENCODING_UNKNOWN = 0,
+        PACKED = 1,
+        EXPANDED = 2,
+    }
+
+    impl crate::ProtobufEnum for RepeatedFieldEncoding {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<RepeatedFieldEncoding> {
+            match value {
+                0 => ::std::option::Option::Some(RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN),
+                1 => ::std::option::Option::Some(RepeatedFieldEncoding::PACKED),
+                2 => ::std::option::Option::Some(RepeatedFieldEncoding::EXPANDED),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [RepeatedFieldEncoding] = &[
+                RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN,
+                RepeatedFieldEncoding::PACKED,
+                RepeatedFieldEncoding::EXPANDED,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 9)
+        }
+    }
+
+    impl ::std::default::Default for RepeatedFieldEncoding {
+        fn default() -> Self {
+            RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN
+        }
+    }
+
+    impl crate::reflect::ProtobufValue for RepeatedFieldEncoding {
+        type RuntimeType = crate::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl RepeatedFieldEncoding {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [RepeatedFieldEncoding] = &[
+            RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN,
+            RepeatedFieldEncoding::PACKED,
+            RepeatedFieldEncoding::EXPANDED,
+        ];
+
+        pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<RepeatedFieldEncoding>("FeatureSet.RepeatedFieldEncoding", 9)
+        }
+    }
+
+    impl ::std::str::FromStr for RepeatedFieldEncoding {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "REPEATED_FIELD_ENCODING_UNKNOWN" => ::std::result::Result::Ok(RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN),
+                "PACKED" => ::std::result::Result::Ok(RepeatedFieldEncoding::PACKED),
+                "EXPANDED" => ::std::result::Result::Ok(RepeatedFieldEncoding::EXPANDED),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FeatureSet.RepeatedFieldEncoding", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for RepeatedFieldEncoding {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                RepeatedFieldEncoding::REPEATED_FIELD_ENCODING_UNKNOWN => "REPEATED_FIELD_ENCODING_UNKNOWN",
+                RepeatedFieldEncoding::PACKED => "PACKED",
+                RepeatedFieldEncoding::EXPANDED => "EXPANDED",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for RepeatedFieldEncoding {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FeatureSet.RepeatedFieldEncoding", value))
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum Utf8Validation {
+        UTF8_VALIDATION_UNKNOWN = 0,
+        VERIFY = 2,
+        NONE = 3,
+    }
+
+    impl crate::ProtobufEnum for Utf8Validation {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Utf8Validation> {
+            match value {
+                0 => ::std::option::Option::Some(Utf8Validation::UTF8_VALIDATION_UNKNOWN),
+                2 => ::std::option::Option::Some(Utf8Validation::VERIFY),
+                3 => ::std::option::Option::Some(Utf8Validation::NONE),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [Utf8Validation] = &[
+                Utf8Validation::UTF8_VALIDATION_UNKNOWN,
+                Utf8Validation::VERIFY,
+                Utf8Validation::NONE,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 10)
+        }
+    }
+
+    impl ::std::default::Default for Utf8Validation {
+        fn default() -> Self {
+            Utf8Validation::UTF8_VALIDATION_UNKNOWN
+        }
+    }
+
+    impl crate::reflect::ProtobufValue for Utf8Validation {
+        type RuntimeType = crate::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl Utf8Validation {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [Utf8Validation] = &[
+            Utf8Validation::UTF8_VALIDATION_UNKNOWN,
+            Utf8Validation::VERIFY,
+            Utf8Validation::NONE,
+        ];
+
+        pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<Utf8Validation>("FeatureSet.Utf8Validation", 10)
+        }
+    }
+
+    impl ::std::str::FromStr for Utf8Validation {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "UTF8_VALIDATION_UNKNOWN" => ::std::result::Result::Ok(Utf8Validation::UTF8_VALIDATION_UNKNOWN),
+                "VERIFY" => ::std::result::Result::Ok(Utf8Validation::VERIFY),
+                "NONE" => ::std::result::Result::Ok(Utf8Validation::NONE),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FeatureSet.Utf8Validation", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for Utf8Validation {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                Utf8Validation::UTF8_VALIDATION_UNKNOWN => "UTF8_VALIDATION_UNKNOWN",
+                Utf8Validation::VERIFY => "VERIFY",
+                Utf8Validation::NONE => "NONE",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for Utf8Validation {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FeatureSet.Utf8Validation", value))
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum MessageEncoding {
+        MESSAGE_ENCODING_UNKNOWN = 0,
+        LENGTH_PREFIXED = 1,
+        DELIMITED = 2,
+    }
+
+    impl crate::ProtobufEnum for MessageEncoding {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<MessageEncoding> {
+            match value {
+                0 => ::std::option::Option::Some(MessageEncoding::MESSAGE_ENCODING_UNKNOWN),
+                1 => ::std::option::Option::Some(MessageEncoding::LENGTH_PREFIXED),
+                2 => ::std::option::Option::Some(MessageEncoding::DELIMITED),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [MessageEncoding] = &[
+                MessageEncoding::MESSAGE_ENCODING_UNKNOWN,
+                MessageEncoding::LENGTH_PREFIXED,
+                MessageEncoding::DELIMITED,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 11)
+        }
+    }
+
+    impl ::std::default::Default for MessageEncoding {
+        fn default() -> Self {
+            MessageEncoding::MESSAGE_ENCODING_UNKNOWN
+        }
+    }
+
+    impl crate::reflect::ProtobufValue for MessageEncoding {
+        type RuntimeType = crate::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl MessageEncoding {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [MessageEncoding] = &[
+            MessageEncoding::MESSAGE_ENCODING_UNKNOWN,
+            MessageEncoding::LENGTH_PREFIXED,
+            MessageEncoding::DELIMITED,
+        ];
+
+        pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<MessageEncoding>("FeatureSet.MessageEncoding", 11)
+        }
+    }
+
+    impl ::std::str::FromStr for MessageEncoding {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "MESSAGE_ENCODING_UNKNOWN" => ::std::result::Result::Ok(MessageEncoding::MESSAGE_ENCODING_UNKNOWN),
+                "LENGTH_PREFIXED" => ::std::result::Result::Ok(MessageEncoding::LENGTH_PREFIXED),
+                "DELIMITED" => ::std::result::Result::Ok(MessageEncoding::DELIMITED),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FeatureSet.MessageEncoding", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for MessageEncoding {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                MessageEncoding::MESSAGE_ENCODING_UNKNOWN => "MESSAGE_ENCODING_UNKNOWN",
+                MessageEncoding::LENGTH_PREFIXED => "LENGTH_PREFIXED",
+                MessageEncoding::DELIMITED => "DELIMITED",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for MessageEncoding {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FeatureSet.MessageEncoding", value))
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    pub enum JsonFormat {
+        JSON_FORMAT_UNKNOWN = 0,
+        ALLOW = 1,
+        LEGACY_BEST_EFFORT = 2,
+    }
+
+    impl crate::ProtobufEnum for JsonFormat {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<JsonFormat> {
+            match value {
+                0 => ::std::option::Option::Some(JsonFormat::JSON_FORMAT_UNKNOWN),
+                1 => ::std::option::Option::Some(JsonFormat::ALLOW),
+                2 => ::std::option::Option::Some(JsonFormat::LEGACY_BEST_EFFORT),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [JsonFormat] = &[
+                JsonFormat::JSON_FORMAT_UNKNOWN,
+                JsonFormat::ALLOW,
+                JsonFormat::LEGACY_BEST_EFFORT,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
+            crate::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 12)
+        }
+    }
+
+    impl ::std::default::Default for JsonFormat {
+        fn default() -> Self {
+            JsonFormat::JSON_FORMAT_UNKNOWN
+        }
+    }
+
+    impl crate::reflect::ProtobufValue for JsonFormat {
+        type RuntimeType = crate::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl JsonFormat {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [JsonFormat] = &[
+            JsonFormat::JSON_FORMAT_UNKNOWN,
+            JsonFormat::ALLOW,
+            JsonFormat::LEGACY_BEST_EFFORT,
+        ];
+
+        pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
+            crate::reflect::GeneratedEnumDescriptorData::new_2::<JsonFormat>("FeatureSet.JsonFormat", 12)
+        }
+    }
+
+    impl ::std::str::FromStr for JsonFormat {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "JSON_FORMAT_UNKNOWN" => ::std::result::Result::Ok(JsonFormat::JSON_FORMAT_UNKNOWN),
+                "ALLOW" => ::std::result::Result::Ok(JsonFormat::ALLOW),
+                "LEGACY_BEST_EFFORT" => ::std::result::Result::Ok(JsonFormat::LEGACY_BEST_EFFORT),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.FeatureSet.JsonFormat", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for JsonFormat {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                JsonFormat::JSON_FORMAT_UNKNOWN => "JSON_FORMAT_UNKNOWN",
+                JsonFormat::ALLOW => "ALLOW",
+                JsonFormat::LEGACY_BEST_EFFORT => "LEGACY_BEST_EFFORT",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for JsonFormat {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.FeatureSet.JsonFormat", value))
+        }
+    }
+}
+
+///  Encapsulates information about the original source file from which a
+///  FileDescriptorProto was generated.
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
+pub struct SourceCodeInfo {
+    // message fields
+    pub location: ::std::vec::Vec<source_code_info::Location>,
+    // special fields
+    #[cfg_attr(serde, serde(skip))]
+    pub unknown_fields: crate::UnknownFields,
+    #[cfg_attr(serde, serde(skip))]
+    pub cached_size: crate::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SourceCodeInfo {
+    fn default() -> &'a SourceCodeInfo {
+        <SourceCodeInfo as crate::Message>::default_instance()
+    }
+}
+
+impl SourceCodeInfo {
+    pub fn new() -> SourceCodeInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(crate::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "location",
+            |m: &SourceCodeInfo| { &m.location },
+            |m: &mut SourceCodeInfo| { &mut m.location },
+        ));
+        crate::reflect::GeneratedMessageDescriptorData::new_2::<SourceCodeInfo>(
+            "SourceCodeInfo",
+            20,
+            fields,
+        )
+    }
+}
+
+impl crate::Message for SourceCodeInfo {
+    fn is_initialized(&self) -> bool {
+        for v in &self.location {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut crate::CodedInputStream<'_>) -> crate::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    crate::rt::read_repeated_message_into_vec(wire_type, is, &mut self.location)?;
+                },
+                _ => {
+                    crate::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        self.location.extend(other.location.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.location {
+            let len = value.compute_size();
+            my_size += 1 + crate::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += crate::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut crate::CodedOutputStream<'_>) -> crate::ProtobufResult<()> {
+        for v in &self.location {
+            crate::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &crate::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut crate::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> SourceCodeInfo {
+        SourceCodeInfo::new()
+    }
+
+    fn descriptor_static() -> crate::reflect::MessageDescriptor {
+        crate::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 24)
+    }
+
+    fn default_instance() -> &'static SourceCodeInfo {
+        static instance: SourceCodeInfo = SourceCodeInfo {
+            location: ::std::vec::Vec::new(),
+            unknown_fields: crate::UnknownFields::new(),
+            cached_size: crate::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl crate::Clear for SourceCodeInfo {
+    fn clear(&mut self) {
+        self.location.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SourceCodeInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        crate::text_format::fmt(self, f)
+    }
+}
+
+impl crate::reflect::ProtobufValue for SourceCodeInfo {
+    type RuntimeType = crate::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `SourceCodeInfo`
+pub mod source_code_info {
+    #[derive(PartialEq,Clone,Default)]
+    #[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
+    pub struct Location {
+        // message fields
+        pub path: ::std::vec::Vec<i32>,
+        ///  Always has exactly three or four elements: start line, start column,
+        ///  end line (optional, otherwise assumed same as start line), end column.
+        ///  These are packed into a single field for efficiency.  Note that line
+        ///  and column numbers are zero-based -- typically you will want to add
+        ///  1 to each before displaying to a user.
+        pub span: ::std::vec::Vec<i32>,
+        leading_comments: ::std::option::Option<::std::string::String>,
+        trailing_comments: ::std::option::Option<::std::string::String>,
+        pub leading_detached_comments: ::std::vec::Vec<::std::string::String>,
+        // special fields
+        #[cfg_attr(serde, serde(skip))]
+        pub unknown_fields: crate::UnknownFields,
+        #[cfg_attr(serde, serde(skip))]
+        pub cached_size: crate::rt::CachedSize,
+    }
+
+    impl<'a> ::std::default::Default for &'a Location {
+        fn default() -> &'a Location {
+            <Location as crate::Message>::default_instance()
+        }
+    }
+
+    impl Location {
+        pub fn new() -> Location {
+            ::std::default::Default::default()
+        }
+
+        // optional string leading_comments = 3;
+
+        pub fn get_leading_comments(&self) -> &str {
+            match self.leading_comments.as_ref() {
+                Some(v) => v,
+                None => "",
+            }
+        }
+
+        pub fn clear_leading_comments(&mut self) {
+            self.leading_comments = ::std::option::Option::None;
         }
 
         pub fn has_leading_comments(&self) -> bool {
@@ -7789,7 +9684,7 @@ pub mod source_code_info {
             ));
             crate::reflect::GeneratedMessageDescriptorData::new_2::<Location>(
                 "SourceCodeInfo.Location",
-                25,
+                26,
                 fields,
             )
         }
@@ -7833,6 +9728,19 @@ pub mod source_code_info {
             ::std::result::Result::Ok(())
         }
 
+        fn merge_from_message(&mut self, other: &Self) {
+            self.path.extend(other.path.iter().cloned());
+            self.span.extend(other.span.iter().cloned());
+            if other.leading_comments.is_some() {
+                self.leading_comments = other.leading_comments.clone();
+            }
+            if other.trailing_comments.is_some() {
+                self.trailing_comments = other.trailing_comments.clone();
+            }
+            self.leading_detached_comments.extend(other.leading_detached_comments.iter().cloned());
+            self.unknown_fields.merge_from(&other.unknown_fields);
+        }
+
         // Compute sizes of nested messages
         #[allow(unused_variables)]
         fn compute_size(&self) -> u32 {
@@ -7904,7 +9812,7 @@ pub mod source_code_info {
         }
 
         fn descriptor_static() -> crate::reflect::MessageDescriptor {
-            crate::reflect::MessageDescriptor::new_generated_2(super::file_descriptor(), 24)
+            crate::reflect::MessageDescriptor::new_generated_2(super::file_descriptor(), 25)
         }
 
         fn default_instance() -> &'static Location {
@@ -7947,7 +9855,7 @@ pub mod source_code_info {
 ///  file. A GeneratedCodeInfo message is associated with only one generated
 ///  source file, but may contain references to different source .proto files.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct GeneratedCodeInfo {
     // message fields
     ///  An Annotation connects some span of text in generated code to an element
@@ -7980,7 +9888,7 @@ impl GeneratedCodeInfo {
         ));
         crate::reflect::GeneratedMessageDescriptorData::new_2::<GeneratedCodeInfo>(
             "GeneratedCodeInfo",
-            20,
+            21,
             fields,
         )
     }
@@ -8011,6 +9919,11 @@ impl crate::Message for GeneratedCodeInfo {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        self.annotation.extend(other.annotation.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -8049,7 +9962,7 @@ impl crate::Message for GeneratedCodeInfo {
     }
 
     fn descriptor_static() -> crate::reflect::MessageDescriptor {
-        crate::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 25)
+        crate::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 26)
     }
 
     fn default_instance() -> &'static GeneratedCodeInfo {
@@ -8082,7 +9995,7 @@ impl crate::reflect::ProtobufValue for GeneratedCodeInfo {
 /// Nested message and enums of message `GeneratedCodeInfo`
 pub mod generated_code_info {
     #[derive(PartialEq,Clone,Default)]
-    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    #[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
     pub struct Annotation {
         // message fields
         ///  Identifies the element in the original source .proto file. This field
@@ -8216,7 +10129,7 @@ pub mod generated_code_info {
             ));
             crate::reflect::GeneratedMessageDescriptorData::new_2::<Annotation>(
                 "GeneratedCodeInfo.Annotation",
-                26,
+                27,
                 fields,
             )
         }
@@ -8260,6 +10173,20 @@ pub mod generated_code_info {
             ::std::result::Result::Ok(())
         }
 
+        fn merge_from_message(&mut self, other: &Self) {
+            self.path.extend(other.path.iter().cloned());
+            if other.source_file.is_some() {
+                self.source_file = other.source_file.clone();
+            }
+            if other.begin.is_some() {
+                self.begin = other.begin.clone();
+            }
+            if other.end.is_some() {
+                self.end = other.end.clone();
+            }
+            self.unknown_fields.merge_from(&other.unknown_fields);
+        }
+
         // Compute sizes of nested messages
         #[allow(unused_variables)]
         fn compute_size(&self) -> u32 {
@@ -8320,7 +10247,7 @@ pub mod generated_code_info {
         }
 
         fn descriptor_static() -> crate::reflect::MessageDescriptor {
-            crate::reflect::MessageDescriptor::new_generated_2(super::file_descriptor(), 26)
+            crate::reflect::MessageDescriptor::new_generated_2(super::file_descriptor(), 27)
         }
 
         fn default_instance() -> &'static Annotation {
@@ -8357,10 +10284,126 @@ pub mod generated_code_info {
     }
 }
 
+///  The full set of known editions.
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+#[cfg_attr(serde, derive(Serialize, Deserialize))]
+pub enum Edition {
+    EDITION_UNKNOWN = 0,
+    EDITION_LEGACY = 900,
+    EDITION_PROTO2 = 998,
+    EDITION_PROTO3 = 999,
+    EDITION_2023 = 1000,
+    EDITION_2024 = 1001,
+    EDITION_MAX = 2147483647,
+}
+
+impl crate::ProtobufEnum for Edition {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Edition> {
+        match value {
+            0 => ::std::option::Option::Some(Edition::EDITION_UNKNOWN),
+            900 => ::std::option::Option::Some(Edition::EDITION_LEGACY),
+            998 => ::std::option::Option::Some(Edition::EDITION_PROTO2),
+            999 => ::std::option::Option::Some(Edition::EDITION_PROTO3),
+            1000 => ::std::option::Option::Some(Edition::EDITION_2023),
+            1001 => ::std::option::Option::Some(Edition::EDITION_2024),
+            2147483647 => ::std::option::Option::Some(Edition::EDITION_MAX),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Edition] = &[
+            Edition::EDITION_UNKNOWN,
+            Edition::EDITION_LEGACY,
+            Edition::EDITION_PROTO2,
+            Edition::EDITION_PROTO3,
+            Edition::EDITION_2023,
+            Edition::EDITION_2024,
+            Edition::EDITION_MAX,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> crate::reflect::EnumDescriptor {
+        crate::reflect::EnumDescriptor::new_generated_2(file_descriptor(), 0)
+    }
+}
+
+impl ::std::default::Default for Edition {
+    fn default() -> Self {
+        Edition::EDITION_UNKNOWN
+    }
+}
+
+impl crate::reflect::ProtobufValue for Edition {
+    type RuntimeType = crate::reflect::runtime_types::RuntimeTypeEnum<Self>;
+}
+
+impl Edition {
+    /// All enum values in the order of declaration.
+    pub const ALL: &'static [Edition] = &[
+        Edition::EDITION_UNKNOWN,
+        Edition::EDITION_LEGACY,
+        Edition::EDITION_PROTO2,
+        Edition::EDITION_PROTO3,
+        Edition::EDITION_2023,
+        Edition::EDITION_2024,
+        Edition::EDITION_MAX,
+    ];
+
+    fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
+        crate::reflect::GeneratedEnumDescriptorData::new_2::<Edition>("Edition", 0)
+    }
+}
+
+impl ::std::str::FromStr for Edition {
+    type Err = crate::ProtobufEnumParseError;
+
+    fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+        match s {
+            "EDITION_UNKNOWN" => ::std::result::Result::Ok(Edition::EDITION_UNKNOWN),
+            "EDITION_LEGACY" => ::std::result::Result::Ok(Edition::EDITION_LEGACY),
+            "EDITION_PROTO2" => ::std::result::Result::Ok(Edition::EDITION_PROTO2),
+            "EDITION_PROTO3" => ::std::result::Result::Ok(Edition::EDITION_PROTO3),
+            "EDITION_2023" => ::std::result::Result::Ok(Edition::EDITION_2023),
+            "EDITION_2024" => ::std::result::Result::Ok(Edition::EDITION_2024),
+            "EDITION_MAX" => ::std::result::Result::Ok(Edition::EDITION_MAX),
+            _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.Edition", s)),
+        }
+    }
+}
+
+impl ::std::fmt::Display for Edition {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(match *self {
+            Edition::EDITION_UNKNOWN => "EDITION_UNKNOWN",
+            Edition::EDITION_LEGACY => "EDITION_LEGACY",
+            Edition::EDITION_PROTO2 => "EDITION_PROTO2",
+            Edition::EDITION_PROTO3 => "EDITION_PROTO3",
+            Edition::EDITION_2023 => "EDITION_2023",
+            Edition::EDITION_2024 => "EDITION_2024",
+            Edition::EDITION_MAX => "EDITION_MAX",
+        })
+    }
+}
+
+impl ::std::convert::TryFrom<i32> for Edition {
+    type Error = crate::ProtobufEnumFromI32Error;
+
+    fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+        <Self as crate::ProtobufEnum>::from_i32(value)
+            .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.Edition", value))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x20google/protobuf/descriptor.proto\x12\x0fgoogle.protobuf\"M\n\x11Fi\
     leDescriptorSet\x128\n\x04file\x18\x01\x20\x03(\x0b2$.google.protobuf.Fi\
-    leDescriptorProtoR\x04file\"\xe4\x04\n\x13FileDescriptorProto\x12\x12\n\
+    leDescriptorProtoR\x04file\"\x98\x05\n\x13FileDescriptorProto\x12\x12\n\
     \x04name\x18\x01\x20\x01(\tR\x04name\x12\x18\n\x07package\x18\x02\x20\
     \x01(\tR\x07package\x12\x1e\n\ndependency\x18\x03\x20\x03(\tR\ndependenc\
     y\x12+\n\x11public_dependency\x18\n\x20\x03(\x05R\x10publicDependency\
@@ -8373,70 +10416,71 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     sion\x126\n\x07options\x18\x08\x20\x01(\x0b2\x1c.google.protobuf.FileOpt\
     ionsR\x07options\x12I\n\x10source_code_info\x18\t\x20\x01(\x0b2\x1f.goog\
     le.protobuf.SourceCodeInfoR\x0esourceCodeInfo\x12\x16\n\x06syntax\x18\
-    \x0c\x20\x01(\tR\x06syntax\"\xb9\x06\n\x0fDescriptorProto\x12\x12\n\x04n\
-    ame\x18\x01\x20\x01(\tR\x04name\x12;\n\x05field\x18\x02\x20\x03(\x0b2%.g\
-    oogle.protobuf.FieldDescriptorProtoR\x05field\x12C\n\textension\x18\x06\
-    \x20\x03(\x0b2%.google.protobuf.FieldDescriptorProtoR\textension\x12A\n\
-    \x0bnested_type\x18\x03\x20\x03(\x0b2\x20.google.protobuf.DescriptorProt\
-    oR\nnestedType\x12A\n\tenum_type\x18\x04\x20\x03(\x0b2$.google.protobuf.\
-    EnumDescriptorProtoR\x08enumType\x12X\n\x0fextension_range\x18\x05\x20\
-    \x03(\x0b2/.google.protobuf.DescriptorProto.ExtensionRangeR\x0eextension\
-    Range\x12D\n\noneof_decl\x18\x08\x20\x03(\x0b2%.google.protobuf.OneofDes\
-    criptorProtoR\toneofDecl\x129\n\x07options\x18\x07\x20\x01(\x0b2\x1f.goo\
-    gle.protobuf.MessageOptionsR\x07options\x12U\n\x0ereserved_range\x18\t\
-    \x20\x03(\x0b2..google.protobuf.DescriptorProto.ReservedRangeR\rreserved\
-    Range\x12#\n\rreserved_name\x18\n\x20\x03(\tR\x0creservedName\x1az\n\x0e\
-    ExtensionRange\x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05start\x12\x10\
-    \n\x03end\x18\x02\x20\x01(\x05R\x03end\x12@\n\x07options\x18\x03\x20\x01\
-    (\x0b2&.google.protobuf.ExtensionRangeOptionsR\x07options\x1a7\n\rReserv\
-    edRange\x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05start\x12\x10\n\x03e\
-    nd\x18\x02\x20\x01(\x05R\x03end\"|\n\x15ExtensionRangeOptions\x12X\n\x14\
-    uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Uninterp\
-    retedOptionR\x13uninterpretedOption*\t\x08\xe8\x07\x10\x80\x80\x80\x80\
-    \x02\"\xc1\x06\n\x14FieldDescriptorProto\x12\x12\n\x04name\x18\x01\x20\
-    \x01(\tR\x04name\x12\x16\n\x06number\x18\x03\x20\x01(\x05R\x06number\x12\
-    A\n\x05label\x18\x04\x20\x01(\x0e2+.google.protobuf.FieldDescriptorProto\
-    .LabelR\x05label\x12>\n\x04type\x18\x05\x20\x01(\x0e2*.google.protobuf.F\
-    ieldDescriptorProto.TypeR\x04type\x12\x1b\n\ttype_name\x18\x06\x20\x01(\
-    \tR\x08typeName\x12\x1a\n\x08extendee\x18\x02\x20\x01(\tR\x08extendee\
-    \x12#\n\rdefault_value\x18\x07\x20\x01(\tR\x0cdefaultValue\x12\x1f\n\x0b\
-    oneof_index\x18\t\x20\x01(\x05R\noneofIndex\x12\x1b\n\tjson_name\x18\n\
-    \x20\x01(\tR\x08jsonName\x127\n\x07options\x18\x08\x20\x01(\x0b2\x1d.goo\
-    gle.protobuf.FieldOptionsR\x07options\x12'\n\x0fproto3_optional\x18\x11\
-    \x20\x01(\x08R\x0eproto3Optional\"\xb6\x02\n\x04Type\x12\x0f\n\x0bTYPE_D\
-    OUBLE\x10\x01\x12\x0e\n\nTYPE_FLOAT\x10\x02\x12\x0e\n\nTYPE_INT64\x10\
-    \x03\x12\x0f\n\x0bTYPE_UINT64\x10\x04\x12\x0e\n\nTYPE_INT32\x10\x05\x12\
-    \x10\n\x0cTYPE_FIXED64\x10\x06\x12\x10\n\x0cTYPE_FIXED32\x10\x07\x12\r\n\
-    \tTYPE_BOOL\x10\x08\x12\x0f\n\x0bTYPE_STRING\x10\t\x12\x0e\n\nTYPE_GROUP\
-    \x10\n\x12\x10\n\x0cTYPE_MESSAGE\x10\x0b\x12\x0e\n\nTYPE_BYTES\x10\x0c\
-    \x12\x0f\n\x0bTYPE_UINT32\x10\r\x12\r\n\tTYPE_ENUM\x10\x0e\x12\x11\n\rTY\
-    PE_SFIXED32\x10\x0f\x12\x11\n\rTYPE_SFIXED64\x10\x10\x12\x0f\n\x0bTYPE_S\
-    INT32\x10\x11\x12\x0f\n\x0bTYPE_SINT64\x10\x12\"C\n\x05Label\x12\x12\n\
-    \x0eLABEL_OPTIONAL\x10\x01\x12\x12\n\x0eLABEL_REQUIRED\x10\x02\x12\x12\n\
-    \x0eLABEL_REPEATED\x10\x03\"c\n\x14OneofDescriptorProto\x12\x12\n\x04nam\
-    e\x18\x01\x20\x01(\tR\x04name\x127\n\x07options\x18\x02\x20\x01(\x0b2\
-    \x1d.google.protobuf.OneofOptionsR\x07options\"\xe3\x02\n\x13EnumDescrip\
-    torProto\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12?\n\x05value\
-    \x18\x02\x20\x03(\x0b2).google.protobuf.EnumValueDescriptorProtoR\x05val\
-    ue\x126\n\x07options\x18\x03\x20\x01(\x0b2\x1c.google.protobuf.EnumOptio\
-    nsR\x07options\x12]\n\x0ereserved_range\x18\x04\x20\x03(\x0b26.google.pr\
-    otobuf.EnumDescriptorProto.EnumReservedRangeR\rreservedRange\x12#\n\rres\
-    erved_name\x18\x05\x20\x03(\tR\x0creservedName\x1a;\n\x11EnumReservedRan\
-    ge\x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05start\x12\x10\n\x03end\
-    \x18\x02\x20\x01(\x05R\x03end\"\x83\x01\n\x18EnumValueDescriptorProto\
-    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06number\x18\
-    \x02\x20\x01(\x05R\x06number\x12;\n\x07options\x18\x03\x20\x01(\x0b2!.go\
-    ogle.protobuf.EnumValueOptionsR\x07options\"\xa7\x01\n\x16ServiceDescrip\
-    torProto\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12>\n\x06method\
-    \x18\x02\x20\x03(\x0b2&.google.protobuf.MethodDescriptorProtoR\x06method\
-    \x129\n\x07options\x18\x03\x20\x01(\x0b2\x1f.google.protobuf.ServiceOpti\
-    onsR\x07options\"\x89\x02\n\x15MethodDescriptorProto\x12\x12\n\x04name\
-    \x18\x01\x20\x01(\tR\x04name\x12\x1d\n\ninput_type\x18\x02\x20\x01(\tR\t\
-    inputType\x12\x1f\n\x0boutput_type\x18\x03\x20\x01(\tR\noutputType\x128\
-    \n\x07options\x18\x04\x20\x01(\x0b2\x1e.google.protobuf.MethodOptionsR\
+    \x0c\x20\x01(\tR\x06syntax\x122\n\x07edition\x18\x0e\x20\x01(\x0e2\x18.g\
+    oogle.protobuf.EditionR\x07edition\"\xb9\x06\n\x0fDescriptorProto\x12\
+    \x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12;\n\x05field\x18\x02\x20\
+    \x03(\x0b2%.google.protobuf.FieldDescriptorProtoR\x05field\x12C\n\texten\
+    sion\x18\x06\x20\x03(\x0b2%.google.protobuf.FieldDescriptorProtoR\texten\
+    sion\x12A\n\x0bnested_type\x18\x03\x20\x03(\x0b2\x20.google.protobuf.Des\
+    criptorProtoR\nnestedType\x12A\n\tenum_type\x18\x04\x20\x03(\x0b2$.googl\
+    e.protobuf.EnumDescriptorProtoR\x08enumType\x12X\n\x0fextension_range\
+    \x18\x05\x20\x03(\x0b2/.google.protobuf.DescriptorProto.ExtensionRangeR\
+    \x0eextensionRange\x12D\n\noneof_decl\x18\x08\x20\x03(\x0b2%.google.prot\
+    obuf.OneofDescriptorProtoR\toneofDecl\x129\n\x07options\x18\x07\x20\x01(\
+    \x0b2\x1f.google.protobuf.MessageOptionsR\x07options\x12U\n\x0ereserved_\
+    range\x18\t\x20\x03(\x0b2..google.protobuf.DescriptorProto.ReservedRange\
+    R\rreservedRange\x12#\n\rreserved_name\x18\n\x20\x03(\tR\x0creservedName\
+    \x1az\n\x0eExtensionRange\x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05st\
+    art\x12\x10\n\x03end\x18\x02\x20\x01(\x05R\x03end\x12@\n\x07options\x18\
+    \x03\x20\x01(\x0b2&.google.protobuf.ExtensionRangeOptionsR\x07options\
+    \x1a7\n\rReservedRange\x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05start\
+    \x12\x10\n\x03end\x18\x02\x20\x01(\x05R\x03end\"|\n\x15ExtensionRangeOpt\
+    ions\x12X\n\x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.pr\
+    otobuf.UninterpretedOptionR\x13uninterpretedOption*\t\x08\xe8\x07\x10\
+    \x80\x80\x80\x80\x02\"\xc1\x06\n\x14FieldDescriptorProto\x12\x12\n\x04na\
+    me\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06number\x18\x03\x20\x01(\x05R\
+    \x06number\x12A\n\x05label\x18\x04\x20\x01(\x0e2+.google.protobuf.FieldD\
+    escriptorProto.LabelR\x05label\x12>\n\x04type\x18\x05\x20\x01(\x0e2*.goo\
+    gle.protobuf.FieldDescriptorProto.TypeR\x04type\x12\x1b\n\ttype_name\x18\
+    \x06\x20\x01(\tR\x08typeName\x12\x1a\n\x08extendee\x18\x02\x20\x01(\tR\
+    \x08extendee\x12#\n\rdefault_value\x18\x07\x20\x01(\tR\x0cdefaultValue\
+    \x12\x1f\n\x0boneof_index\x18\t\x20\x01(\x05R\noneofIndex\x12\x1b\n\tjso\
+    n_name\x18\n\x20\x01(\tR\x08jsonName\x127\n\x07options\x18\x08\x20\x01(\
+    \x0b2\x1d.google.protobuf.FieldOptionsR\x07options\x12'\n\x0fproto3_opti\
+    onal\x18\x11\x20\x01(\x08R\x0eproto3Optional\"\xb6\x02\n\x04Type\x12\x0f\
+    \n\x0bTYPE_DOUBLE\x10\x01\x12\x0e\n\nTYPE_FLOAT\x10\x02\x12\x0e\n\nTYPE_\
+    INT64\x10\x03\x12\x0f\n\x0bTYPE_UINT64\x10\x04\x12\x0e\n\nTYPE_INT32\x10\
+    \x05\x12\x10\n\x0cTYPE_FIXED64\x10\x06\x12\x10\n\x0cTYPE_FIXED32\x10\x07\
+    \x12\r\n\tTYPE_BOOL\x10\x08\x12\x0f\n\x0bTYPE_STRING\x10\t\x12\x0e\n\nTY\
+    PE_GROUP\x10\n\x12\x10\n\x0cTYPE_MESSAGE\x10\x0b\x12\x0e\n\nTYPE_BYTES\
+    \x10\x0c\x12\x0f\n\x0bTYPE_UINT32\x10\r\x12\r\n\tTYPE_ENUM\x10\x0e\x12\
+    \x11\n\rTYPE_SFIXED32\x10\x0f\x12\x11\n\rTYPE_SFIXED64\x10\x10\x12\x0f\n\
+    \x0bTYPE_SINT32\x10\x11\x12\x0f\n\x0bTYPE_SINT64\x10\x12\"C\n\x05Label\
+    \x12\x12\n\x0eLABEL_OPTIONAL\x10\x01\x12\x12\n\x0eLABEL_REQUIRED\x10\x02\
+    \x12\x12\n\x0eLABEL_REPEATED\x10\x03\"c\n\x14OneofDescriptorProto\x12\
+    \x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x127\n\x07options\x18\x02\x20\
+    \x01(\x0b2\x1d.google.protobuf.OneofOptionsR\x07options\"\xe3\x02\n\x13E\
+    numDescriptorProto\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12?\n\
+    \x05value\x18\x02\x20\x03(\x0b2).google.protobuf.EnumValueDescriptorProt\
+    oR\x05value\x126\n\x07options\x18\x03\x20\x01(\x0b2\x1c.google.protobuf.\
+    EnumOptionsR\x07options\x12]\n\x0ereserved_range\x18\x04\x20\x03(\x0b26.\
+    google.protobuf.EnumDescriptorProto.EnumReservedRangeR\rreservedRange\
+    \x12#\n\rreserved_name\x18\x05\x20\x03(\tR\x0creservedName\x1a;\n\x11Enu\
+    mReservedRange\x12\x14\n\x05start\x18\x01\x20\x01(\x05R\x05start\x12\x10\
+    \n\x03end\x18\x02\x20\x01(\x05R\x03end\"\x83\x01\n\x18EnumValueDescripto\
+    rProto\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06number\
+    \x18\x02\x20\x01(\x05R\x06number\x12;\n\x07options\x18\x03\x20\x01(\x0b2\
+    !.google.protobuf.EnumValueOptionsR\x07options\"\xa7\x01\n\x16ServiceDes\
+    criptorProto\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12>\n\x06met\
+    hod\x18\x02\x20\x03(\x0b2&.google.protobuf.MethodDescriptorProtoR\x06met\
+    hod\x129\n\x07options\x18\x03\x20\x01(\x0b2\x1f.google.protobuf.ServiceO\
+    ptionsR\x07options\"\x89\x02\n\x15MethodDescriptorProto\x12\x12\n\x04nam\
+    e\x18\x01\x20\x01(\tR\x04name\x12\x1d\n\ninput_type\x18\x02\x20\x01(\tR\
+    \tinputType\x12\x1f\n\x0boutput_type\x18\x03\x20\x01(\tR\noutputType\x12\
+    8\n\x07options\x18\x04\x20\x01(\x0b2\x1e.google.protobuf.MethodOptionsR\
     \x07options\x120\n\x10client_streaming\x18\x05\x20\x01(\x08:\x05falseR\
     \x0fclientStreaming\x120\n\x10server_streaming\x18\x06\x20\x01(\x08:\x05\
-    falseR\x0fserverStreaming\"\x91\t\n\x0bFileOptions\x12!\n\x0cjava_packag\
+    falseR\x0fserverStreaming\"\xca\t\n\x0bFileOptions\x12!\n\x0cjava_packag\
     e\x18\x01\x20\x01(\tR\x0bjavaPackage\x120\n\x14java_outer_classname\x18\
     \x08\x20\x01(\tR\x12javaOuterClassname\x125\n\x13java_multiple_files\x18\
     \n\x20\x01(\x08:\x05falseR\x11javaMultipleFiles\x12D\n\x1djava_generate_\
@@ -8457,349 +10501,417 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     lass_prefix\x18(\x20\x01(\tR\x0ephpClassPrefix\x12#\n\rphp_namespace\x18\
     )\x20\x01(\tR\x0cphpNamespace\x124\n\x16php_metadata_namespace\x18,\x20\
     \x01(\tR\x14phpMetadataNamespace\x12!\n\x0cruby_package\x18-\x20\x01(\tR\
-    \x0brubyPackage\x12X\n\x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2\
-    $.google.protobuf.UninterpretedOptionR\x13uninterpretedOption\":\n\x0cOp\
-    timizeMode\x12\t\n\x05SPEED\x10\x01\x12\r\n\tCODE_SIZE\x10\x02\x12\x10\n\
-    \x0cLITE_RUNTIME\x10\x03*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02J\x04\x08\
-    &\x10'\"\xd1\x02\n\x0eMessageOptions\x12<\n\x17message_set_wire_format\
-    \x18\x01\x20\x01(\x08:\x05falseR\x14messageSetWireFormat\x12L\n\x1fno_st\
-    andard_descriptor_accessor\x18\x02\x20\x01(\x08:\x05falseR\x1cnoStandard\
-    DescriptorAccessor\x12%\n\ndeprecated\x18\x03\x20\x01(\x08:\x05falseR\nd\
-    eprecated\x12\x1b\n\tmap_entry\x18\x07\x20\x01(\x08R\x08mapEntry\x12X\n\
-    \x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Unin\
-    terpretedOptionR\x13uninterpretedOption*\t\x08\xe8\x07\x10\x80\x80\x80\
-    \x80\x02J\x04\x08\x08\x10\tJ\x04\x08\t\x10\n\"\xe2\x03\n\x0cFieldOptions\
-    \x12A\n\x05ctype\x18\x01\x20\x01(\x0e2#.google.protobuf.FieldOptions.CTy\
-    pe:\x06STRINGR\x05ctype\x12\x16\n\x06packed\x18\x02\x20\x01(\x08R\x06pac\
-    ked\x12G\n\x06jstype\x18\x06\x20\x01(\x0e2$.google.protobuf.FieldOptions\
-    .JSType:\tJS_NORMALR\x06jstype\x12\x19\n\x04lazy\x18\x05\x20\x01(\x08:\
-    \x05falseR\x04lazy\x12%\n\ndeprecated\x18\x03\x20\x01(\x08:\x05falseR\nd\
-    eprecated\x12\x19\n\x04weak\x18\n\x20\x01(\x08:\x05falseR\x04weak\x12X\n\
-    \x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Unin\
-    terpretedOptionR\x13uninterpretedOption\"/\n\x05CType\x12\n\n\x06STRING\
-    \x10\0\x12\x08\n\x04CORD\x10\x01\x12\x10\n\x0cSTRING_PIECE\x10\x02\"5\n\
-    \x06JSType\x12\r\n\tJS_NORMAL\x10\0\x12\r\n\tJS_STRING\x10\x01\x12\r\n\t\
-    JS_NUMBER\x10\x02*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02J\x04\x08\x04\
-    \x10\x05\"s\n\x0cOneofOptions\x12X\n\x14uninterpreted_option\x18\xe7\x07\
-    \x20\x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterpretedOpt\
-    ion*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\xc0\x01\n\x0bEnumOptions\
-    \x12\x1f\n\x0ballow_alias\x18\x02\x20\x01(\x08R\nallowAlias\x12%\n\ndepr\
-    ecated\x18\x03\x20\x01(\x08:\x05falseR\ndeprecated\x12X\n\x14uninterpret\
-    ed_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.UninterpretedOption\
-    R\x13uninterpretedOption*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02J\x04\x08\
-    \x05\x10\x06\"\x9e\x01\n\x10EnumValueOptions\x12%\n\ndeprecated\x18\x01\
-    \x20\x01(\x08:\x05falseR\ndeprecated\x12X\n\x14uninterpreted_option\x18\
+    \x0brubyPackage\x127\n\x08features\x182\x20\x01(\x0b2\x1b.google.protobu\
+    f.FeatureSetR\x08features\x12X\n\x14uninterpreted_option\x18\xe7\x07\x20\
+    \x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterpretedOption\
+    \":\n\x0cOptimizeMode\x12\t\n\x05SPEED\x10\x01\x12\r\n\tCODE_SIZE\x10\
+    \x02\x12\x10\n\x0cLITE_RUNTIME\x10\x03*\t\x08\xe8\x07\x10\x80\x80\x80\
+    \x80\x02J\x04\x08&\x10'\"\x8a\x03\n\x0eMessageOptions\x12<\n\x17message_\
+    set_wire_format\x18\x01\x20\x01(\x08:\x05falseR\x14messageSetWireFormat\
+    \x12L\n\x1fno_standard_descriptor_accessor\x18\x02\x20\x01(\x08:\x05fals\
+    eR\x1cnoStandardDescriptorAccessor\x12%\n\ndeprecated\x18\x03\x20\x01(\
+    \x08:\x05falseR\ndeprecated\x12\x1b\n\tmap_entry\x18\x07\x20\x01(\x08R\
+    \x08mapEntry\x127\n\x08features\x18\x0c\x20\x01(\x0b2\x1b.google.protobu\
+    f.FeatureSetR\x08features\x12X\n\x14uninterpreted_option\x18\xe7\x07\x20\
+    \x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterpretedOption*\
+    \t\x08\xe8\x07\x10\x80\x80\x80\x80\x02J\x04\x08\x08\x10\tJ\x04\x08\t\x10\
+    \n\"\x9b\x04\n\x0cFieldOptions\x12A\n\x05ctype\x18\x01\x20\x01(\x0e2#.go\
+    ogle.protobuf.FieldOptions.CType:\x06STRINGR\x05ctype\x12\x16\n\x06packe\
+    d\x18\x02\x20\x01(\x08R\x06packed\x12G\n\x06jstype\x18\x06\x20\x01(\x0e2\
+    $.google.protobuf.FieldOptions.JSType:\tJS_NORMALR\x06jstype\x12\x19\n\
+    \x04lazy\x18\x05\x20\x01(\x08:\x05falseR\x04lazy\x12%\n\ndeprecated\x18\
+    \x03\x20\x01(\x08:\x05falseR\ndeprecated\x12\x19\n\x04weak\x18\n\x20\x01\
+    (\x08:\x05falseR\x04weak\x127\n\x08features\x18\x15\x20\x01(\x0b2\x1b.go\
+    ogle.protobuf.FeatureSetR\x08features\x12X\n\x14uninterpreted_option\x18\
     \xe7\x07\x20\x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterp\
-    retedOption*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\x9c\x01\n\x0eServic\
-    eOptions\x12%\n\ndeprecated\x18!\x20\x01(\x08:\x05falseR\ndeprecated\x12\
-    X\n\x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.U\
-    ninterpretedOptionR\x13uninterpretedOption*\t\x08\xe8\x07\x10\x80\x80\
-    \x80\x80\x02\"\xe0\x02\n\rMethodOptions\x12%\n\ndeprecated\x18!\x20\x01(\
-    \x08:\x05falseR\ndeprecated\x12q\n\x11idempotency_level\x18\"\x20\x01(\
-    \x0e2/.google.protobuf.MethodOptions.IdempotencyLevel:\x13IDEMPOTENCY_UN\
-    KNOWNR\x10idempotencyLevel\x12X\n\x14uninterpreted_option\x18\xe7\x07\
+    retedOption\"/\n\x05CType\x12\n\n\x06STRING\x10\0\x12\x08\n\x04CORD\x10\
+    \x01\x12\x10\n\x0cSTRING_PIECE\x10\x02\"5\n\x06JSType\x12\r\n\tJS_NORMAL\
+    \x10\0\x12\r\n\tJS_STRING\x10\x01\x12\r\n\tJS_NUMBER\x10\x02*\t\x08\xe8\
+    \x07\x10\x80\x80\x80\x80\x02J\x04\x08\x04\x10\x05\"\xac\x01\n\x0cOneofOp\
+    tions\x127\n\x08features\x18\x01\x20\x01(\x0b2\x1b.google.protobuf.Featu\
+    reSetR\x08features\x12X\n\x14uninterpreted_option\x18\xe7\x07\x20\x03(\
+    \x0b2$.google.protobuf.UninterpretedOptionR\x13uninterpretedOption*\t\
+    \x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\xf9\x01\n\x0bEnumOptions\x12\x1f\
+    \n\x0ballow_alias\x18\x02\x20\x01(\x08R\nallowAlias\x12%\n\ndeprecated\
+    \x18\x03\x20\x01(\x08:\x05falseR\ndeprecated\x127\n\x08features\x18\x07\
+    \x20\x01(\x0b2\x1b.google.protobuf.FeatureSetR\x08features\x12X\n\x14uni\
+    nterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Uninterpret\
+    edOptionR\x13uninterpretedOption*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02J\
+    \x04\x08\x05\x10\x06\"\xd7\x01\n\x10EnumValueOptions\x12%\n\ndeprecated\
+    \x18\x01\x20\x01(\x08:\x05falseR\ndeprecated\x127\n\x08features\x18\x02\
+    \x20\x01(\x0b2\x1b.google.protobuf.FeatureSetR\x08features\x12X\n\x14uni\
+    nterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Uninterpret\
+    edOptionR\x13uninterpretedOption*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\
+    \"\xd5\x01\n\x0eServiceOptions\x12%\n\ndeprecated\x18!\x20\x01(\x08:\x05\
+    falseR\ndeprecated\x127\n\x08features\x18\"\x20\x01(\x0b2\x1b.google.pro\
+    tobuf.FeatureSetR\x08features\x12X\n\x14uninterpreted_option\x18\xe7\x07\
     \x20\x03(\x0b2$.google.protobuf.UninterpretedOptionR\x13uninterpretedOpt\
-    ion\"P\n\x10IdempotencyLevel\x12\x17\n\x13IDEMPOTENCY_UNKNOWN\x10\0\x12\
-    \x13\n\x0fNO_SIDE_EFFECTS\x10\x01\x12\x0e\n\nIDEMPOTENT\x10\x02*\t\x08\
-    \xe8\x07\x10\x80\x80\x80\x80\x02\"\x9a\x03\n\x13UninterpretedOption\x12A\
-    \n\x04name\x18\x02\x20\x03(\x0b2-.google.protobuf.UninterpretedOption.Na\
-    mePartR\x04name\x12)\n\x10identifier_value\x18\x03\x20\x01(\tR\x0fidenti\
-    fierValue\x12,\n\x12positive_int_value\x18\x04\x20\x01(\x04R\x10positive\
-    IntValue\x12,\n\x12negative_int_value\x18\x05\x20\x01(\x03R\x10negativeI\
-    ntValue\x12!\n\x0cdouble_value\x18\x06\x20\x01(\x01R\x0bdoubleValue\x12!\
-    \n\x0cstring_value\x18\x07\x20\x01(\x0cR\x0bstringValue\x12'\n\x0faggreg\
-    ate_value\x18\x08\x20\x01(\tR\x0eaggregateValue\x1aJ\n\x08NamePart\x12\
-    \x1b\n\tname_part\x18\x01\x20\x02(\tR\x08namePart\x12!\n\x0cis_extension\
-    \x18\x02\x20\x02(\x08R\x0bisExtension\"\xa7\x02\n\x0eSourceCodeInfo\x12D\
-    \n\x08location\x18\x01\x20\x03(\x0b2(.google.protobuf.SourceCodeInfo.Loc\
-    ationR\x08location\x1a\xce\x01\n\x08Location\x12\x16\n\x04path\x18\x01\
-    \x20\x03(\x05R\x04pathB\x02\x10\x01\x12\x16\n\x04span\x18\x02\x20\x03(\
-    \x05R\x04spanB\x02\x10\x01\x12)\n\x10leading_comments\x18\x03\x20\x01(\t\
-    R\x0fleadingComments\x12+\n\x11trailing_comments\x18\x04\x20\x01(\tR\x10\
-    trailingComments\x12:\n\x19leading_detached_comments\x18\x06\x20\x03(\tR\
-    \x17leadingDetachedComments\"\xd1\x01\n\x11GeneratedCodeInfo\x12M\n\nann\
-    otation\x18\x01\x20\x03(\x0b2-.google.protobuf.GeneratedCodeInfo.Annotat\
-    ionR\nannotation\x1am\n\nAnnotation\x12\x16\n\x04path\x18\x01\x20\x03(\
-    \x05R\x04pathB\x02\x10\x01\x12\x1f\n\x0bsource_file\x18\x02\x20\x01(\tR\
-    \nsourceFile\x12\x14\n\x05begin\x18\x03\x20\x01(\x05R\x05begin\x12\x10\n\
-    \x03end\x18\x04\x20\x01(\x05R\x03endB\x8f\x01\n\x13com.google.protobufB\
-    \x10DescriptorProtosH\x01Z>github.com/golang/protobuf/protoc-gen-go/desc\
-    riptor;descriptor\xf8\x01\x01\xa2\x02\x03GPB\xaa\x02\x1aGoogle.Protobuf.\
-    ReflectionJ\xbc\xc8\x02\n\x07\x12\x05'\0\x8c\x07\x01\n\xaa\x0f\n\x01\x0c\
-    \x12\x03'\0\x122\xc1\x0c\x20Protocol\x20Buffers\x20-\x20Google's\x20data\
-    \x20interchange\x20format\n\x20Copyright\x202008\x20Google\x20Inc.\x20\
-    \x20All\x20rights\x20reserved.\n\x20https://developers.google.com/protoc\
-    ol-buffers/\n\n\x20Redistribution\x20and\x20use\x20in\x20source\x20and\
-    \x20binary\x20forms,\x20with\x20or\x20without\n\x20modification,\x20are\
-    \x20permitted\x20provided\x20that\x20the\x20following\x20conditions\x20a\
-    re\n\x20met:\n\n\x20\x20\x20\x20\x20*\x20Redistributions\x20of\x20source\
-    \x20code\x20must\x20retain\x20the\x20above\x20copyright\n\x20notice,\x20\
-    this\x20list\x20of\x20conditions\x20and\x20the\x20following\x20disclaime\
-    r.\n\x20\x20\x20\x20\x20*\x20Redistributions\x20in\x20binary\x20form\x20\
-    must\x20reproduce\x20the\x20above\n\x20copyright\x20notice,\x20this\x20l\
-    ist\x20of\x20conditions\x20and\x20the\x20following\x20disclaimer\n\x20in\
-    \x20the\x20documentation\x20and/or\x20other\x20materials\x20provided\x20\
-    with\x20the\n\x20distribution.\n\x20\x20\x20\x20\x20*\x20Neither\x20the\
-    \x20name\x20of\x20Google\x20Inc.\x20nor\x20the\x20names\x20of\x20its\n\
-    \x20contributors\x20may\x20be\x20used\x20to\x20endorse\x20or\x20promote\
-    \x20products\x20derived\x20from\n\x20this\x20software\x20without\x20spec\
-    ific\x20prior\x20written\x20permission.\n\n\x20THIS\x20SOFTWARE\x20IS\
-    \x20PROVIDED\x20BY\x20THE\x20COPYRIGHT\x20HOLDERS\x20AND\x20CONTRIBUTORS\
-    \n\x20\"AS\x20IS\"\x20AND\x20ANY\x20EXPRESS\x20OR\x20IMPLIED\x20WARRANTI\
-    ES,\x20INCLUDING,\x20BUT\x20NOT\n\x20LIMITED\x20TO,\x20THE\x20IMPLIED\
-    \x20WARRANTIES\x20OF\x20MERCHANTABILITY\x20AND\x20FITNESS\x20FOR\n\x20A\
-    \x20PARTICULAR\x20PURPOSE\x20ARE\x20DISCLAIMED.\x20IN\x20NO\x20EVENT\x20\
-    SHALL\x20THE\x20COPYRIGHT\n\x20OWNER\x20OR\x20CONTRIBUTORS\x20BE\x20LIAB\
-    LE\x20FOR\x20ANY\x20DIRECT,\x20INDIRECT,\x20INCIDENTAL,\n\x20SPECIAL,\
-    \x20EXEMPLARY,\x20OR\x20CONSEQUENTIAL\x20DAMAGES\x20(INCLUDING,\x20BUT\
-    \x20NOT\n\x20LIMITED\x20TO,\x20PROCUREMENT\x20OF\x20SUBSTITUTE\x20GOODS\
-    \x20OR\x20SERVICES;\x20LOSS\x20OF\x20USE,\n\x20DATA,\x20OR\x20PROFITS;\
-    \x20OR\x20BUSINESS\x20INTERRUPTION)\x20HOWEVER\x20CAUSED\x20AND\x20ON\
-    \x20ANY\n\x20THEORY\x20OF\x20LIABILITY,\x20WHETHER\x20IN\x20CONTRACT,\
-    \x20STRICT\x20LIABILITY,\x20OR\x20TORT\n\x20(INCLUDING\x20NEGLIGENCE\x20\
-    OR\x20OTHERWISE)\x20ARISING\x20IN\x20ANY\x20WAY\x20OUT\x20OF\x20THE\x20U\
-    SE\n\x20OF\x20THIS\x20SOFTWARE,\x20EVEN\x20IF\x20ADVISED\x20OF\x20THE\
-    \x20POSSIBILITY\x20OF\x20SUCH\x20DAMAGE.\n2\xdb\x02\x20Author:\x20kenton\
-    @google.com\x20(Kenton\x20Varda)\n\x20\x20Based\x20on\x20original\x20Pro\
-    tocol\x20Buffers\x20design\x20by\n\x20\x20Sanjay\x20Ghemawat,\x20Jeff\
-    \x20Dean,\x20and\x20others.\n\n\x20The\x20messages\x20in\x20this\x20file\
-    \x20describe\x20the\x20definitions\x20found\x20in\x20.proto\x20files.\n\
-    \x20A\x20valid\x20.proto\x20file\x20can\x20be\x20translated\x20directly\
-    \x20to\x20a\x20FileDescriptorProto\n\x20without\x20any\x20other\x20infor\
-    mation\x20(e.g.\x20without\x20reading\x20its\x20imports).\n\n\x08\n\x01\
-    \x02\x12\x03)\0\x18\n\x08\n\x01\x08\x12\x03+\0U\n\t\n\x02\x08\x0b\x12\
-    \x03+\0U\n\x08\n\x01\x08\x12\x03,\0,\n\t\n\x02\x08\x01\x12\x03,\0,\n\x08\
-    \n\x01\x08\x12\x03-\01\n\t\n\x02\x08\x08\x12\x03-\01\n\x08\n\x01\x08\x12\
-    \x03.\07\n\t\n\x02\x08%\x12\x03.\07\n\x08\n\x01\x08\x12\x03/\0!\n\t\n\
-    \x02\x08$\x12\x03/\0!\n\x08\n\x01\x08\x12\x030\0\x1f\n\t\n\x02\x08\x1f\
-    \x12\x030\0\x1f\n\x08\n\x01\x08\x12\x034\0\x1c\n\x7f\n\x02\x08\t\x12\x03\
-    4\0\x1c\x1at\x20descriptor.proto\x20must\x20be\x20optimized\x20for\x20sp\
-    eed\x20because\x20reflection-based\n\x20algorithms\x20don't\x20work\x20d\
-    uring\x20bootstrapping.\n\nj\n\x02\x04\0\x12\x048\0:\x01\x1a^\x20The\x20\
-    protocol\x20compiler\x20can\x20output\x20a\x20FileDescriptorSet\x20conta\
-    ining\x20the\x20.proto\n\x20files\x20it\x20parses.\n\n\n\n\x03\x04\0\x01\
-    \x12\x038\x08\x19\n\x0b\n\x04\x04\0\x02\0\x12\x039\x02(\n\x0c\n\x05\x04\
-    \0\x02\0\x04\x12\x039\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x039\x0b\x1e\
-    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x039\x1f#\n\x0c\n\x05\x04\0\x02\0\x03\
-    \x12\x039&'\n/\n\x02\x04\x01\x12\x04=\0Z\x01\x1a#\x20Describes\x20a\x20c\
-    omplete\x20.proto\x20file.\n\n\n\n\x03\x04\x01\x01\x12\x03=\x08\x1b\n9\n\
-    \x04\x04\x01\x02\0\x12\x03>\x02\x1b\",\x20file\x20name,\x20relative\x20t\
-    o\x20root\x20of\x20source\x20tree\n\n\x0c\n\x05\x04\x01\x02\0\x04\x12\
-    \x03>\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03>\x0b\x11\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03>\x12\x16\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03>\x19\x1a\n*\n\x04\x04\x01\x02\x01\x12\x03?\x02\x1e\"\x1d\x20e.g.\
-    \x20\"foo\",\x20\"foo.bar\",\x20etc.\n\n\x0c\n\x05\x04\x01\x02\x01\x04\
-    \x12\x03?\x02\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03?\x0b\x11\n\x0c\n\
-    \x05\x04\x01\x02\x01\x01\x12\x03?\x12\x19\n\x0c\n\x05\x04\x01\x02\x01\
-    \x03\x12\x03?\x1c\x1d\n4\n\x04\x04\x01\x02\x02\x12\x03B\x02!\x1a'\x20Nam\
-    es\x20of\x20files\x20imported\x20by\x20this\x20file.\n\n\x0c\n\x05\x04\
-    \x01\x02\x02\x04\x12\x03B\x02\n\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03B\
-    \x0b\x11\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03B\x12\x1c\n\x0c\n\x05\
-    \x04\x01\x02\x02\x03\x12\x03B\x1f\x20\nQ\n\x04\x04\x01\x02\x03\x12\x03D\
-    \x02(\x1aD\x20Indexes\x20of\x20the\x20public\x20imported\x20files\x20in\
-    \x20the\x20dependency\x20list\x20above.\n\n\x0c\n\x05\x04\x01\x02\x03\
-    \x04\x12\x03D\x02\n\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03D\x0b\x10\n\
-    \x0c\n\x05\x04\x01\x02\x03\x01\x12\x03D\x11\"\n\x0c\n\x05\x04\x01\x02\
-    \x03\x03\x12\x03D%'\nz\n\x04\x04\x01\x02\x04\x12\x03G\x02&\x1am\x20Index\
-    es\x20of\x20the\x20weak\x20imported\x20files\x20in\x20the\x20dependency\
-    \x20list.\n\x20For\x20Google-internal\x20migration\x20only.\x20Do\x20not\
-    \x20use.\n\n\x0c\n\x05\x04\x01\x02\x04\x04\x12\x03G\x02\n\n\x0c\n\x05\
-    \x04\x01\x02\x04\x05\x12\x03G\x0b\x10\n\x0c\n\x05\x04\x01\x02\x04\x01\
-    \x12\x03G\x11\x20\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03G#%\n6\n\x04\
-    \x04\x01\x02\x05\x12\x03J\x02,\x1a)\x20All\x20top-level\x20definitions\
-    \x20in\x20this\x20file.\n\n\x0c\n\x05\x04\x01\x02\x05\x04\x12\x03J\x02\n\
-    \n\x0c\n\x05\x04\x01\x02\x05\x06\x12\x03J\x0b\x1a\n\x0c\n\x05\x04\x01\
-    \x02\x05\x01\x12\x03J\x1b'\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03J*+\n\
-    \x0b\n\x04\x04\x01\x02\x06\x12\x03K\x02-\n\x0c\n\x05\x04\x01\x02\x06\x04\
-    \x12\x03K\x02\n\n\x0c\n\x05\x04\x01\x02\x06\x06\x12\x03K\x0b\x1e\n\x0c\n\
-    \x05\x04\x01\x02\x06\x01\x12\x03K\x1f(\n\x0c\n\x05\x04\x01\x02\x06\x03\
-    \x12\x03K+,\n\x0b\n\x04\x04\x01\x02\x07\x12\x03L\x02.\n\x0c\n\x05\x04\
-    \x01\x02\x07\x04\x12\x03L\x02\n\n\x0c\n\x05\x04\x01\x02\x07\x06\x12\x03L\
-    \x0b!\n\x0c\n\x05\x04\x01\x02\x07\x01\x12\x03L\")\n\x0c\n\x05\x04\x01\
-    \x02\x07\x03\x12\x03L,-\n\x0b\n\x04\x04\x01\x02\x08\x12\x03M\x02.\n\x0c\
-    \n\x05\x04\x01\x02\x08\x04\x12\x03M\x02\n\n\x0c\n\x05\x04\x01\x02\x08\
-    \x06\x12\x03M\x0b\x1f\n\x0c\n\x05\x04\x01\x02\x08\x01\x12\x03M\x20)\n\
-    \x0c\n\x05\x04\x01\x02\x08\x03\x12\x03M,-\n\x0b\n\x04\x04\x01\x02\t\x12\
-    \x03O\x02#\n\x0c\n\x05\x04\x01\x02\t\x04\x12\x03O\x02\n\n\x0c\n\x05\x04\
-    \x01\x02\t\x06\x12\x03O\x0b\x16\n\x0c\n\x05\x04\x01\x02\t\x01\x12\x03O\
-    \x17\x1e\n\x0c\n\x05\x04\x01\x02\t\x03\x12\x03O!\"\n\xf4\x01\n\x04\x04\
-    \x01\x02\n\x12\x03U\x02/\x1a\xe6\x01\x20This\x20field\x20contains\x20opt\
-    ional\x20information\x20about\x20the\x20original\x20source\x20code.\n\
-    \x20You\x20may\x20safely\x20remove\x20this\x20entire\x20field\x20without\
-    \x20harming\x20runtime\n\x20functionality\x20of\x20the\x20descriptors\
-    \x20--\x20the\x20information\x20is\x20needed\x20only\x20by\n\x20developm\
-    ent\x20tools.\n\n\x0c\n\x05\x04\x01\x02\n\x04\x12\x03U\x02\n\n\x0c\n\x05\
-    \x04\x01\x02\n\x06\x12\x03U\x0b\x19\n\x0c\n\x05\x04\x01\x02\n\x01\x12\
-    \x03U\x1a*\n\x0c\n\x05\x04\x01\x02\n\x03\x12\x03U-.\n]\n\x04\x04\x01\x02\
-    \x0b\x12\x03Y\x02\x1e\x1aP\x20The\x20syntax\x20of\x20the\x20proto\x20fil\
-    e.\n\x20The\x20supported\x20values\x20are\x20\"proto2\"\x20and\x20\"prot\
-    o3\".\n\n\x0c\n\x05\x04\x01\x02\x0b\x04\x12\x03Y\x02\n\n\x0c\n\x05\x04\
-    \x01\x02\x0b\x05\x12\x03Y\x0b\x11\n\x0c\n\x05\x04\x01\x02\x0b\x01\x12\
-    \x03Y\x12\x18\n\x0c\n\x05\x04\x01\x02\x0b\x03\x12\x03Y\x1b\x1d\n'\n\x02\
-    \x04\x02\x12\x04]\0}\x01\x1a\x1b\x20Describes\x20a\x20message\x20type.\n\
-    \n\n\n\x03\x04\x02\x01\x12\x03]\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\
-    \x03^\x02\x1b\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03^\x02\n\n\x0c\n\x05\
-    \x04\x02\x02\0\x05\x12\x03^\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03^\x12\x16\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03^\x19\x1a\n\x0b\n\x04\
-    \x04\x02\x02\x01\x12\x03`\x02*\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03`\
-    \x02\n\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03`\x0b\x1f\n\x0c\n\x05\x04\
-    \x02\x02\x01\x01\x12\x03`\x20%\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03`(\
-    )\n\x0b\n\x04\x04\x02\x02\x02\x12\x03a\x02.\n\x0c\n\x05\x04\x02\x02\x02\
-    \x04\x12\x03a\x02\n\n\x0c\n\x05\x04\x02\x02\x02\x06\x12\x03a\x0b\x1f\n\
-    \x0c\n\x05\x04\x02\x02\x02\x01\x12\x03a\x20)\n\x0c\n\x05\x04\x02\x02\x02\
-    \x03\x12\x03a,-\n\x0b\n\x04\x04\x02\x02\x03\x12\x03c\x02+\n\x0c\n\x05\
-    \x04\x02\x02\x03\x04\x12\x03c\x02\n\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\
-    \x03c\x0b\x1a\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03c\x1b&\n\x0c\n\x05\
-    \x04\x02\x02\x03\x03\x12\x03c)*\n\x0b\n\x04\x04\x02\x02\x04\x12\x03d\x02\
-    -\n\x0c\n\x05\x04\x02\x02\x04\x04\x12\x03d\x02\n\n\x0c\n\x05\x04\x02\x02\
-    \x04\x06\x12\x03d\x0b\x1e\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03d\x1f(\
-    \n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03d+,\n\x0c\n\x04\x04\x02\x03\0\
-    \x12\x04f\x02k\x03\n\x0c\n\x05\x04\x02\x03\0\x01\x12\x03f\n\x18\n\x1b\n\
-    \x06\x04\x02\x03\0\x02\0\x12\x03g\x04\x1d\"\x0c\x20Inclusive.\n\n\x0e\n\
-    \x07\x04\x02\x03\0\x02\0\x04\x12\x03g\x04\x0c\n\x0e\n\x07\x04\x02\x03\0\
-    \x02\0\x05\x12\x03g\r\x12\n\x0e\n\x07\x04\x02\x03\0\x02\0\x01\x12\x03g\
-    \x13\x18\n\x0e\n\x07\x04\x02\x03\0\x02\0\x03\x12\x03g\x1b\x1c\n\x1b\n\
-    \x06\x04\x02\x03\0\x02\x01\x12\x03h\x04\x1b\"\x0c\x20Exclusive.\n\n\x0e\
-    \n\x07\x04\x02\x03\0\x02\x01\x04\x12\x03h\x04\x0c\n\x0e\n\x07\x04\x02\
-    \x03\0\x02\x01\x05\x12\x03h\r\x12\n\x0e\n\x07\x04\x02\x03\0\x02\x01\x01\
-    \x12\x03h\x13\x16\n\x0e\n\x07\x04\x02\x03\0\x02\x01\x03\x12\x03h\x19\x1a\
-    \n\r\n\x06\x04\x02\x03\0\x02\x02\x12\x03j\x04/\n\x0e\n\x07\x04\x02\x03\0\
-    \x02\x02\x04\x12\x03j\x04\x0c\n\x0e\n\x07\x04\x02\x03\0\x02\x02\x06\x12\
-    \x03j\r\"\n\x0e\n\x07\x04\x02\x03\0\x02\x02\x01\x12\x03j#*\n\x0e\n\x07\
-    \x04\x02\x03\0\x02\x02\x03\x12\x03j-.\n\x0b\n\x04\x04\x02\x02\x05\x12\
-    \x03l\x02.\n\x0c\n\x05\x04\x02\x02\x05\x04\x12\x03l\x02\n\n\x0c\n\x05\
-    \x04\x02\x02\x05\x06\x12\x03l\x0b\x19\n\x0c\n\x05\x04\x02\x02\x05\x01\
-    \x12\x03l\x1a)\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03l,-\n\x0b\n\x04\
-    \x04\x02\x02\x06\x12\x03n\x02/\n\x0c\n\x05\x04\x02\x02\x06\x04\x12\x03n\
-    \x02\n\n\x0c\n\x05\x04\x02\x02\x06\x06\x12\x03n\x0b\x1f\n\x0c\n\x05\x04\
-    \x02\x02\x06\x01\x12\x03n\x20*\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03n-\
-    .\n\x0b\n\x04\x04\x02\x02\x07\x12\x03p\x02&\n\x0c\n\x05\x04\x02\x02\x07\
-    \x04\x12\x03p\x02\n\n\x0c\n\x05\x04\x02\x02\x07\x06\x12\x03p\x0b\x19\n\
-    \x0c\n\x05\x04\x02\x02\x07\x01\x12\x03p\x1a!\n\x0c\n\x05\x04\x02\x02\x07\
-    \x03\x12\x03p$%\n\xaa\x01\n\x04\x04\x02\x03\x01\x12\x04u\x02x\x03\x1a\
-    \x9b\x01\x20Range\x20of\x20reserved\x20tag\x20numbers.\x20Reserved\x20ta\
-    g\x20numbers\x20may\x20not\x20be\x20used\x20by\n\x20fields\x20or\x20exte\
-    nsion\x20ranges\x20in\x20the\x20same\x20message.\x20Reserved\x20ranges\
-    \x20may\n\x20not\x20overlap.\n\n\x0c\n\x05\x04\x02\x03\x01\x01\x12\x03u\
-    \n\x17\n\x1b\n\x06\x04\x02\x03\x01\x02\0\x12\x03v\x04\x1d\"\x0c\x20Inclu\
-    sive.\n\n\x0e\n\x07\x04\x02\x03\x01\x02\0\x04\x12\x03v\x04\x0c\n\x0e\n\
-    \x07\x04\x02\x03\x01\x02\0\x05\x12\x03v\r\x12\n\x0e\n\x07\x04\x02\x03\
-    \x01\x02\0\x01\x12\x03v\x13\x18\n\x0e\n\x07\x04\x02\x03\x01\x02\0\x03\
-    \x12\x03v\x1b\x1c\n\x1b\n\x06\x04\x02\x03\x01\x02\x01\x12\x03w\x04\x1b\"\
-    \x0c\x20Exclusive.\n\n\x0e\n\x07\x04\x02\x03\x01\x02\x01\x04\x12\x03w\
-    \x04\x0c\n\x0e\n\x07\x04\x02\x03\x01\x02\x01\x05\x12\x03w\r\x12\n\x0e\n\
-    \x07\x04\x02\x03\x01\x02\x01\x01\x12\x03w\x13\x16\n\x0e\n\x07\x04\x02\
-    \x03\x01\x02\x01\x03\x12\x03w\x19\x1a\n\x0b\n\x04\x04\x02\x02\x08\x12\
-    \x03y\x02,\n\x0c\n\x05\x04\x02\x02\x08\x04\x12\x03y\x02\n\n\x0c\n\x05\
-    \x04\x02\x02\x08\x06\x12\x03y\x0b\x18\n\x0c\n\x05\x04\x02\x02\x08\x01\
-    \x12\x03y\x19'\n\x0c\n\x05\x04\x02\x02\x08\x03\x12\x03y*+\n\x82\x01\n\
-    \x04\x04\x02\x02\t\x12\x03|\x02%\x1au\x20Reserved\x20field\x20names,\x20\
-    which\x20may\x20not\x20be\x20used\x20by\x20fields\x20in\x20the\x20same\
-    \x20message.\n\x20A\x20given\x20name\x20may\x20only\x20be\x20reserved\
-    \x20once.\n\n\x0c\n\x05\x04\x02\x02\t\x04\x12\x03|\x02\n\n\x0c\n\x05\x04\
-    \x02\x02\t\x05\x12\x03|\x0b\x11\n\x0c\n\x05\x04\x02\x02\t\x01\x12\x03|\
-    \x12\x1f\n\x0c\n\x05\x04\x02\x02\t\x03\x12\x03|\"$\n\x0b\n\x02\x04\x03\
-    \x12\x05\x7f\0\x86\x01\x01\n\n\n\x03\x04\x03\x01\x12\x03\x7f\x08\x1d\nO\
-    \n\x04\x04\x03\x02\0\x12\x04\x81\x01\x02:\x1aA\x20The\x20parser\x20store\
-    s\x20options\x20it\x20doesn't\x20recognize\x20here.\x20See\x20above.\n\n\
-    \r\n\x05\x04\x03\x02\0\x04\x12\x04\x81\x01\x02\n\n\r\n\x05\x04\x03\x02\0\
-    \x06\x12\x04\x81\x01\x0b\x1e\n\r\n\x05\x04\x03\x02\0\x01\x12\x04\x81\x01\
-    \x1f3\n\r\n\x05\x04\x03\x02\0\x03\x12\x04\x81\x0169\nZ\n\x03\x04\x03\x05\
-    \x12\x04\x85\x01\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20o\
-    ptions\x20in\x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\
-    \n\x0c\n\x04\x04\x03\x05\0\x12\x04\x85\x01\r\x18\n\r\n\x05\x04\x03\x05\0\
-    \x01\x12\x04\x85\x01\r\x11\n\r\n\x05\x04\x03\x05\0\x02\x12\x04\x85\x01\
-    \x15\x18\n3\n\x02\x04\x04\x12\x06\x89\x01\0\xee\x01\x01\x1a%\x20Describe\
-    s\x20a\x20field\x20within\x20a\x20message.\n\n\x0b\n\x03\x04\x04\x01\x12\
-    \x04\x89\x01\x08\x1c\n\x0e\n\x04\x04\x04\x04\0\x12\x06\x8a\x01\x02\xa9\
-    \x01\x03\n\r\n\x05\x04\x04\x04\0\x01\x12\x04\x8a\x01\x07\x0b\nS\n\x06\
-    \x04\x04\x04\0\x02\0\x12\x04\x8d\x01\x04\x14\x1aC\x200\x20is\x20reserved\
-    \x20for\x20errors.\n\x20Order\x20is\x20weird\x20for\x20historical\x20rea\
-    sons.\n\n\x0f\n\x07\x04\x04\x04\0\x02\0\x01\x12\x04\x8d\x01\x04\x0f\n\
-    \x0f\n\x07\x04\x04\x04\0\x02\0\x02\x12\x04\x8d\x01\x12\x13\n\x0e\n\x06\
-    \x04\x04\x04\0\x02\x01\x12\x04\x8e\x01\x04\x13\n\x0f\n\x07\x04\x04\x04\0\
-    \x02\x01\x01\x12\x04\x8e\x01\x04\x0e\n\x0f\n\x07\x04\x04\x04\0\x02\x01\
-    \x02\x12\x04\x8e\x01\x11\x12\nw\n\x06\x04\x04\x04\0\x02\x02\x12\x04\x91\
-    \x01\x04\x13\x1ag\x20Not\x20ZigZag\x20encoded.\x20\x20Negative\x20number\
-    s\x20take\x2010\x20bytes.\x20\x20Use\x20TYPE_SINT64\x20if\n\x20negative\
-    \x20values\x20are\x20likely.\n\n\x0f\n\x07\x04\x04\x04\0\x02\x02\x01\x12\
-    \x04\x91\x01\x04\x0e\n\x0f\n\x07\x04\x04\x04\0\x02\x02\x02\x12\x04\x91\
-    \x01\x11\x12\n\x0e\n\x06\x04\x04\x04\0\x02\x03\x12\x04\x92\x01\x04\x14\n\
-    \x0f\n\x07\x04\x04\x04\0\x02\x03\x01\x12\x04\x92\x01\x04\x0f\n\x0f\n\x07\
-    \x04\x04\x04\0\x02\x03\x02\x12\x04\x92\x01\x12\x13\nw\n\x06\x04\x04\x04\
-    \0\x02\x04\x12\x04\x95\x01\x04\x13\x1ag\x20Not\x20ZigZag\x20encoded.\x20\
-    \x20Negative\x20numbers\x20take\x2010\x20bytes.\x20\x20Use\x20TYPE_SINT3\
-    2\x20if\n\x20negative\x20values\x20are\x20likely.\n\n\x0f\n\x07\x04\x04\
-    \x04\0\x02\x04\x01\x12\x04\x95\x01\x04\x0e\n\x0f\n\x07\x04\x04\x04\0\x02\
-    \x04\x02\x12\x04\x95\x01\x11\x12\n\x0e\n\x06\x04\x04\x04\0\x02\x05\x12\
-    \x04\x96\x01\x04\x15\n\x0f\n\x07\x04\x04\x04\0\x02\x05\x01\x12\x04\x96\
-    \x01\x04\x10\n\x0f\n\x07\x04\x04\x04\0\x02\x05\x02\x12\x04\x96\x01\x13\
-    \x14\n\x0e\n\x06\x04\x04\x04\0\x02\x06\x12\x04\x97\x01\x04\x15\n\x0f\n\
-    \x07\x04\x04\x04\0\x02\x06\x01\x12\x04\x97\x01\x04\x10\n\x0f\n\x07\x04\
-    \x04\x04\0\x02\x06\x02\x12\x04\x97\x01\x13\x14\n\x0e\n\x06\x04\x04\x04\0\
-    \x02\x07\x12\x04\x98\x01\x04\x12\n\x0f\n\x07\x04\x04\x04\0\x02\x07\x01\
-    \x12\x04\x98\x01\x04\r\n\x0f\n\x07\x04\x04\x04\0\x02\x07\x02\x12\x04\x98\
-    \x01\x10\x11\n\x0e\n\x06\x04\x04\x04\0\x02\x08\x12\x04\x99\x01\x04\x14\n\
-    \x0f\n\x07\x04\x04\x04\0\x02\x08\x01\x12\x04\x99\x01\x04\x0f\n\x0f\n\x07\
-    \x04\x04\x04\0\x02\x08\x02\x12\x04\x99\x01\x12\x13\n\xe2\x01\n\x06\x04\
-    \x04\x04\0\x02\t\x12\x04\x9e\x01\x04\x14\x1a\xd1\x01\x20Tag-delimited\
-    \x20aggregate.\n\x20Group\x20type\x20is\x20deprecated\x20and\x20not\x20s\
-    upported\x20in\x20proto3.\x20However,\x20Proto3\n\x20implementations\x20\
-    should\x20still\x20be\x20able\x20to\x20parse\x20the\x20group\x20wire\x20\
-    format\x20and\n\x20treat\x20group\x20fields\x20as\x20unknown\x20fields.\
-    \n\n\x0f\n\x07\x04\x04\x04\0\x02\t\x01\x12\x04\x9e\x01\x04\x0e\n\x0f\n\
-    \x07\x04\x04\x04\0\x02\t\x02\x12\x04\x9e\x01\x11\x13\n-\n\x06\x04\x04\
-    \x04\0\x02\n\x12\x04\x9f\x01\x04\x16\"\x1d\x20Length-delimited\x20aggreg\
-    ate.\n\n\x0f\n\x07\x04\x04\x04\0\x02\n\x01\x12\x04\x9f\x01\x04\x10\n\x0f\
-    \n\x07\x04\x04\x04\0\x02\n\x02\x12\x04\x9f\x01\x13\x15\n#\n\x06\x04\x04\
-    \x04\0\x02\x0b\x12\x04\xa2\x01\x04\x14\x1a\x13\x20New\x20in\x20version\
-    \x202.\n\n\x0f\n\x07\x04\x04\x04\0\x02\x0b\x01\x12\x04\xa2\x01\x04\x0e\n\
-    \x0f\n\x07\x04\x04\x04\0\x02\x0b\x02\x12\x04\xa2\x01\x11\x13\n\x0e\n\x06\
-    \x04\x04\x04\0\x02\x0c\x12\x04\xa3\x01\x04\x15\n\x0f\n\x07\x04\x04\x04\0\
-    \x02\x0c\x01\x12\x04\xa3\x01\x04\x0f\n\x0f\n\x07\x04\x04\x04\0\x02\x0c\
-    \x02\x12\x04\xa3\x01\x12\x14\n\x0e\n\x06\x04\x04\x04\0\x02\r\x12\x04\xa4\
-    \x01\x04\x13\n\x0f\n\x07\x04\x04\x04\0\x02\r\x01\x12\x04\xa4\x01\x04\r\n\
-    \x0f\n\x07\x04\x04\x04\0\x02\r\x02\x12\x04\xa4\x01\x10\x12\n\x0e\n\x06\
-    \x04\x04\x04\0\x02\x0e\x12\x04\xa5\x01\x04\x17\n\x0f\n\x07\x04\x04\x04\0\
-    \x02\x0e\x01\x12\x04\xa5\x01\x04\x11\n\x0f\n\x07\x04\x04\x04\0\x02\x0e\
-    \x02\x12\x04\xa5\x01\x14\x16\n\x0e\n\x06\x04\x04\x04\0\x02\x0f\x12\x04\
-    \xa6\x01\x04\x17\n\x0f\n\x07\x04\x04\x04\0\x02\x0f\x01\x12\x04\xa6\x01\
-    \x04\x11\n\x0f\n\x07\x04\x04\x04\0\x02\x0f\x02\x12\x04\xa6\x01\x14\x16\n\
-    '\n\x06\x04\x04\x04\0\x02\x10\x12\x04\xa7\x01\x04\x15\"\x17\x20Uses\x20Z\
-    igZag\x20encoding.\n\n\x0f\n\x07\x04\x04\x04\0\x02\x10\x01\x12\x04\xa7\
-    \x01\x04\x0f\n\x0f\n\x07\x04\x04\x04\0\x02\x10\x02\x12\x04\xa7\x01\x12\
-    \x14\n'\n\x06\x04\x04\x04\0\x02\x11\x12\x04\xa8\x01\x04\x15\"\x17\x20Use\
-    s\x20ZigZag\x20encoding.\n\n\x0f\n\x07\x04\x04\x04\0\x02\x11\x01\x12\x04\
-    \xa8\x01\x04\x0f\n\x0f\n\x07\x04\x04\x04\0\x02\x11\x02\x12\x04\xa8\x01\
-    \x12\x14\n\x0e\n\x04\x04\x04\x04\x01\x12\x06\xab\x01\x02\xb0\x01\x03\n\r\
-    \n\x05\x04\x04\x04\x01\x01\x12\x04\xab\x01\x07\x0c\n*\n\x06\x04\x04\x04\
-    \x01\x02\0\x12\x04\xad\x01\x04\x17\x1a\x1a\x200\x20is\x20reserved\x20for\
-    \x20errors\n\n\x0f\n\x07\x04\x04\x04\x01\x02\0\x01\x12\x04\xad\x01\x04\
-    \x12\n\x0f\n\x07\x04\x04\x04\x01\x02\0\x02\x12\x04\xad\x01\x15\x16\n\x0e\
-    \n\x06\x04\x04\x04\x01\x02\x01\x12\x04\xae\x01\x04\x17\n\x0f\n\x07\x04\
-    \x04\x04\x01\x02\x01\x01\x12\x04\xae\x01\x04\x12\n\x0f\n\x07\x04\x04\x04\
-    \x01\x02\x01\x02\x12\x04\xae\x01\x15\x16\n\x0e\n\x06\x04\x04\x04\x01\x02\
-    \x02\x12\x04\xaf\x01\x04\x17\n\x0f\n\x07\x04\x04\x04\x01\x02\x02\x01\x12\
-    \x04\xaf\x01\x04\x12\n\x0f\n\x07\x04\x04\x04\x01\x02\x02\x02\x12\x04\xaf\
-    \x01\x15\x16\n\x0c\n\x04\x04\x04\x02\0\x12\x04\xb2\x01\x02\x1b\n\r\n\x05\
-    \x04\x04\x02\0\x04\x12\x04\xb2\x01\x02\n\n\r\n\x05\x04\x04\x02\0\x05\x12\
-    \x04\xb2\x01\x0b\x11\n\r\n\x05\x04\x04\x02\0\x01\x12\x04\xb2\x01\x12\x16\
-    \n\r\n\x05\x04\x04\x02\0\x03\x12\x04\xb2\x01\x19\x1a\n\x0c\n\x04\x04\x04\
-    \x02\x01\x12\x04\xb3\x01\x02\x1c\n\r\n\x05\x04\x04\x02\x01\x04\x12\x04\
-    \xb3\x01\x02\n\n\r\n\x05\x04\x04\x02\x01\x05\x12\x04\xb3\x01\x0b\x10\n\r\
-    \n\x05\x04\x04\x02\x01\x01\x12\x04\xb3\x01\x11\x17\n\r\n\x05\x04\x04\x02\
-    \x01\x03\x12\x04\xb3\x01\x1a\x1b\n\x0c\n\x04\x04\x04\x02\x02\x12\x04\xb4\
-    \x01\x02\x1b\n\r\n\x05\x04\x04\x02\x02\x04\x12\x04\xb4\x01\x02\n\n\r\n\
-    \x05\x04\x04\x02\x02\x06\x12\x04\xb4\x01\x0b\x10\n\r\n\x05\x04\x04\x02\
-    \x02\x01\x12\x04\xb4\x01\x11\x16\n\r\n\x05\x04\x04\x02\x02\x03\x12\x04\
-    \xb4\x01\x19\x1a\n\x9c\x01\n\x04\x04\x04\x02\x03\x12\x04\xb8\x01\x02\x19\
-    \x1a\x8d\x01\x20If\x20type_name\x20is\x20set,\x20this\x20need\x20not\x20\
-    be\x20set.\x20\x20If\x20both\x20this\x20and\x20type_name\n\x20are\x20set\
-    ,\x20this\x20must\x20be\x20one\x20of\x20TYPE_ENUM,\x20TYPE_MESSAGE\x20or\
-    \x20TYPE_GROUP.\n\n\r\n\x05\x04\x04\x02\x03\x04\x12\x04\xb8\x01\x02\n\n\
-    \r\n\x05\x04\x04\x02\x03\x06\x12\x04\xb8\x01\x0b\x0f\n\r\n\x05\x04\x04\
-    \x02\x03\x01\x12\x04\xb8\x01\x10\x14\n\r\n\x05\x04\x04\x02\x03\x03\x12\
-    \x04\xb8\x01\x17\x18\n\xb7\x02\n\x04\x04\x04\x02\x04\x12\x04\xbf\x01\x02\
+    ion*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\x99\x03\n\rMethodOptions\
+    \x12%\n\ndeprecated\x18!\x20\x01(\x08:\x05falseR\ndeprecated\x12q\n\x11i\
+    dempotency_level\x18\"\x20\x01(\x0e2/.google.protobuf.MethodOptions.Idem\
+    potencyLevel:\x13IDEMPOTENCY_UNKNOWNR\x10idempotencyLevel\x127\n\x08feat\
+    ures\x18#\x20\x01(\x0b2\x1b.google.protobuf.FeatureSetR\x08features\x12X\
+    \n\x14uninterpreted_option\x18\xe7\x07\x20\x03(\x0b2$.google.protobuf.Un\
+    interpretedOptionR\x13uninterpretedOption\"P\n\x10IdempotencyLevel\x12\
+    \x17\n\x13IDEMPOTENCY_UNKNOWN\x10\0\x12\x13\n\x0fNO_SIDE_EFFECTS\x10\x01\
+    \x12\x0e\n\nIDEMPOTENT\x10\x02*\t\x08\xe8\x07\x10\x80\x80\x80\x80\x02\"\
+    \x9a\x03\n\x13UninterpretedOption\x12A\n\x04name\x18\x02\x20\x03(\x0b2-.\
+    google.protobuf.UninterpretedOption.NamePartR\x04name\x12)\n\x10identifi\
+    er_value\x18\x03\x20\x01(\tR\x0fidentifierValue\x12,\n\x12positive_int_v\
+    alue\x18\x04\x20\x01(\x04R\x10positiveIntValue\x12,\n\x12negative_int_va\
+    lue\x18\x05\x20\x01(\x03R\x10negativeIntValue\x12!\n\x0cdouble_value\x18\
+    \x06\x20\x01(\x01R\x0bdoubleValue\x12!\n\x0cstring_value\x18\x07\x20\x01\
+    (\x0cR\x0bstringValue\x12'\n\x0faggregate_value\x18\x08\x20\x01(\tR\x0ea\
+    ggregateValue\x1aJ\n\x08NamePart\x12\x1b\n\tname_part\x18\x01\x20\x02(\t\
+    R\x08namePart\x12!\n\x0cis_extension\x18\x02\x20\x02(\x08R\x0bisExtensio\
+    n\"\xfb\x07\n\nFeatureSet\x12P\n\x0efield_presence\x18\x01\x20\x01(\x0e2\
+    ).google.protobuf.FeatureSet.FieldPresenceR\rfieldPresence\x12A\n\tenum_\
+    type\x18\x02\x20\x01(\x0e2$.google.protobuf.FeatureSet.EnumTypeR\x08enum\
+    Type\x12i\n\x17repeated_field_encoding\x18\x03\x20\x01(\x0e21.google.pro\
+    tobuf.FeatureSet.RepeatedFieldEncodingR\x15repeatedFieldEncoding\x12S\n\
+    \x0futf8_validation\x18\x04\x20\x01(\x0e2*.google.protobuf.FeatureSet.Ut\
+    f8ValidationR\x0eutf8Validation\x12V\n\x10message_encoding\x18\x05\x20\
+    \x01(\x0e2+.google.protobuf.FeatureSet.MessageEncodingR\x0fmessageEncodi\
+    ng\x12G\n\x0bjson_format\x18\x06\x20\x01(\x0e2&.google.protobuf.FeatureS\
+    et.JsonFormatR\njsonFormat\"\\\n\rFieldPresence\x12\x1a\n\x16FIELD_PRESE\
+    NCE_UNKNOWN\x10\0\x12\x0c\n\x08EXPLICIT\x10\x01\x12\x0c\n\x08IMPLICIT\
+    \x10\x02\x12\x13\n\x0fLEGACY_REQUIRED\x10\x03\"7\n\x08EnumType\x12\x15\n\
+    \x11ENUM_TYPE_UNKNOWN\x10\0\x12\x08\n\x04OPEN\x10\x01\x12\n\n\x06CLOSED\
+    \x10\x02\"V\n\x15RepeatedFieldEncoding\x12#\n\x1fREPEATED_FIELD_ENCODING\
+    _UNKNOWN\x10\0\x12\n\n\x06PACKED\x10\x01\x12\x0c\n\x08EXPANDED\x10\x02\"\
+    I\n\x0eUtf8Validation\x12\x1b\n\x17UTF8_VALIDATION_UNKNOWN\x10\0\x12\n\n\
+    \x06VERIFY\x10\x02\x12\x08\n\x04NONE\x10\x03\"\x04\x08\x01\x10\x01\"S\n\
+    \x0fMessageEncoding\x12\x1c\n\x18MESSAGE_ENCODING_UNKNOWN\x10\0\x12\x13\
+    \n\x0fLENGTH_PREFIXED\x10\x01\x12\r\n\tDELIMITED\x10\x02\"H\n\nJsonForma\
+    t\x12\x17\n\x13JSON_FORMAT_UNKNOWN\x10\0\x12\t\n\x05ALLOW\x10\x01\x12\
+    \x16\n\x12LEGACY_BEST_EFFORT\x10\x02*\x06\x08\xe8\x07\x10\x8bN*\x06\x08\
+    \x8bN\x10\x90N*\x06\x08\x90N\x10\x91NJ\x06\x08\xe7\x07\x10\xe8\x07\"\xa7\
+    \x02\n\x0eSourceCodeInfo\x12D\n\x08location\x18\x01\x20\x03(\x0b2(.googl\
+    e.protobuf.SourceCodeInfo.LocationR\x08location\x1a\xce\x01\n\x08Locatio\
+    n\x12\x16\n\x04path\x18\x01\x20\x03(\x05R\x04pathB\x02\x10\x01\x12\x16\n\
+    \x04span\x18\x02\x20\x03(\x05R\x04spanB\x02\x10\x01\x12)\n\x10leading_co\
+    mments\x18\x03\x20\x01(\tR\x0fleadingComments\x12+\n\x11trailing_comment\
+    s\x18\x04\x20\x01(\tR\x10trailingComments\x12:\n\x19leading_detached_com\
+    ments\x18\x06\x20\x03(\tR\x17leadingDetachedComments\"\xd1\x01\n\x11Gene\
+    ratedCodeInfo\x12M\n\nannotation\x18\x01\x20\x03(\x0b2-.google.protobuf.\
+    GeneratedCodeInfo.AnnotationR\nannotation\x1am\n\nAnnotation\x12\x16\n\
+    \x04path\x18\x01\x20\x03(\x05R\x04pathB\x02\x10\x01\x12\x1f\n\x0bsource_\
+    file\x18\x02\x20\x01(\tR\nsourceFile\x12\x14\n\x05begin\x18\x03\x20\x01(\
+    \x05R\x05begin\x12\x10\n\x03end\x18\x04\x20\x01(\x05R\x03end*\x98\x01\n\
+    \x07Edition\x12\x13\n\x0fEDITION_UNKNOWN\x10\0\x12\x13\n\x0eEDITION_LEGA\
+    CY\x10\x84\x07\x12\x13\n\x0eEDITION_PROTO2\x10\xe6\x07\x12\x13\n\x0eEDIT\
+    ION_PROTO3\x10\xe7\x07\x12\x11\n\x0cEDITION_2023\x10\xe8\x07\x12\x11\n\
+    \x0cEDITION_2024\x10\xe9\x07\x12\x13\n\x0bEDITION_MAX\x10\xff\xff\xff\
+    \xff\x07B\x8f\x01\n\x13com.google.protobufB\x10DescriptorProtosH\x01Z>gi\
+    thub.com/golang/protobuf/protoc-gen-go/descriptor;descriptor\xf8\x01\x01\
+    \xa2\x02\x03GPB\xaa\x02\x1aGoogle.Protobuf.ReflectionJ\xc1\xda\x02\n\x07\
+    \x12\x05'\0\xff\x07\x01\n\xaa\x0f\n\x01\x0c\x12\x03'\0\x122\xc1\x0c\x20P\
+    rotocol\x20Buffers\x20-\x20Google's\x20data\x20interchange\x20format\n\
+    \x20Copyright\x202008\x20Google\x20Inc.\x20\x20All\x20rights\x20reserved\
+    .\n\x20https://developers.google.com/protocol-buffers/\n\n\x20Redistribu\
+    tion\x20and\x20use\x20in\x20source\x20and\x20binary\x20forms,\x20with\
+    \x20or\x20without\n\x20modification,\x20are\x20permitted\x20provided\x20\
+    that\x20the\x20following\x20conditions\x20are\n\x20met:\n\n\x20\x20\x20\
+    \x20\x20*\x20Redistributions\x20of\x20source\x20code\x20must\x20retain\
+    \x20the\x20above\x20copyright\n\x20notice,\x20this\x20list\x20of\x20cond\
+    itions\x20and\x20the\x20following\x20disclaimer.\n\x20\x20\x20\x20\x20*\
+    \x20Redistributions\x20in\x20binary\x20form\x20must\x20reproduce\x20the\
+    \x20above\n\x20copyright\x20notice,\x20this\x20list\x20of\x20conditions\
+    \x20and\x20the\x20following\x20disclaimer\n\x20in\x20the\x20documentatio\
+    n\x20and/or\x20other\x20materials\x20provided\x20with\x20the\n\x20distri\
+    bution.\n\x20\x20\x20\x20\x20*\x20Neither\x20the\x20name\x20of\x20Google\
+    \x20Inc.\x20nor\x20the\x20names\x20of\x20its\n\x20contributors\x20may\
+    \x20be\x20used\x20to\x20endorse\x20or\x20promote\x20products\x20derived\
+    \x20from\n\x20this\x20software\x20without\x20specific\x20prior\x20writte\
+    n\x20permission.\n\n\x20THIS\x20SOFTWARE\x20IS\x20PROVIDED\x20BY\x20THE\
+    \x20COPYRIGHT\x20HOLDERS\x20AND\x20CONTRIBUTORS\n\x20\"AS\x20IS\"\x20AND\
+    \x20ANY\x20EXPRESS\x20OR\x20IMPLIED\x20WARRANTIES,\x20INCLUDING,\x20BUT\
+    \x20NOT\n\x20LIMITED\x20TO,\x20THE\x20IMPLIED\x20WARRANTIES\x20OF\x20MER\
+    CHANTABILITY\x20AND\x20FITNESS\x20FOR\n\x20A\x20PARTICULAR\x20PURPOSE\
+    \x20ARE\x20DISCLAIMED.\x20IN\x20NO\x20EVENT\x20SHALL\x20THE\x20COPYRIGHT\
+    \n\x20OWNER\x20OR\x20CONTRIBUTORS\x20BE\x20LIABLE\x20FOR\x20ANY\x20DIREC\
+    T,\x20INDIRECT,\x20INCIDENTAL,\n\x20SPECIAL,\x20EXEMPLARY,\x20OR\x20CONS\
+    EQUENTIAL\x20DAMAGES\x20(INCLUDING,\x20BUT\x20NOT\n\x20LIMITED\x20TO,\
+    \x20PROCUREMENT\x20OF\x20SUBSTITUTE\x20GOODS\x20OR\x20SERVICES;\x20LOSS\
+    \x20OF\x20USE,\n\x20DATA,\x20OR\x20PROFITS;\x20OR\x20BUSINESS\x20INTERRU\
+    PTION)\x20HOWEVER\x20CAUSED\x20AND\x20ON\x20ANY\n\x20THEORY\x20OF\x20LIA\
+    BILITY,\x20WHETHER\x20IN\x20CONTRACT,\x20STRICT\x20LIABILITY,\x20OR\x20T\
+    ORT\n\x20(INCLUDING\x20NEGLIGENCE\x20OR\x20OTHERWISE)\x20ARISING\x20IN\
+    \x20ANY\x20WAY\x20OUT\x20OF\x20THE\x20USE\n\x20OF\x20THIS\x20SOFTWARE,\
+    \x20EVEN\x20IF\x20ADVISED\x20OF\x20THE\x20POSSIBILITY\x20OF\x20SUCH\x20D\
+    AMAGE.\n2\xdb\x02\x20Author:\x20kenton@google.com\x20(Kenton\x20Varda)\n\
+    \x20\x20Based\x20on\x20original\x20Protocol\x20Buffers\x20design\x20by\n\
+    \x20\x20Sanjay\x20Ghemawat,\x20Jeff\x20Dean,\x20and\x20others.\n\n\x20Th\
+    e\x20messages\x20in\x20this\x20file\x20describe\x20the\x20definitions\
+    \x20found\x20in\x20.proto\x20files.\n\x20A\x20valid\x20.proto\x20file\
+    \x20can\x20be\x20translated\x20directly\x20to\x20a\x20FileDescriptorProt\
+    o\n\x20without\x20any\x20other\x20information\x20(e.g.\x20without\x20rea\
+    ding\x20its\x20imports).\n\n\x08\n\x01\x02\x12\x03)\0\x18\n\x08\n\x01\
+    \x08\x12\x03+\0U\n\t\n\x02\x08\x0b\x12\x03+\0U\n\x08\n\x01\x08\x12\x03,\
+    \0,\n\t\n\x02\x08\x01\x12\x03,\0,\n\x08\n\x01\x08\x12\x03-\01\n\t\n\x02\
+    \x08\x08\x12\x03-\01\n\x08\n\x01\x08\x12\x03.\07\n\t\n\x02\x08%\x12\x03.\
+    \07\n\x08\n\x01\x08\x12\x03/\0!\n\t\n\x02\x08$\x12\x03/\0!\n\x08\n\x01\
+    \x08\x12\x030\0\x1f\n\t\n\x02\x08\x1f\x12\x030\0\x1f\n\x08\n\x01\x08\x12\
+    \x034\0\x1c\n\x7f\n\x02\x08\t\x12\x034\0\x1c\x1at\x20descriptor.proto\
+    \x20must\x20be\x20optimized\x20for\x20speed\x20because\x20reflection-bas\
+    ed\n\x20algorithms\x20don't\x20work\x20during\x20bootstrapping.\n\nj\n\
+    \x02\x04\0\x12\x048\0:\x01\x1a^\x20The\x20protocol\x20compiler\x20can\
+    \x20output\x20a\x20FileDescriptorSet\x20containing\x20the\x20.proto\n\
+    \x20files\x20it\x20parses.\n\n\n\n\x03\x04\0\x01\x12\x038\x08\x19\n\x0b\
+    \n\x04\x04\0\x02\0\x12\x039\x02(\n\x0c\n\x05\x04\0\x02\0\x04\x12\x039\
+    \x02\n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x039\x0b\x1e\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x039\x1f#\n\x0c\n\x05\x04\0\x02\0\x03\x12\x039&'\n-\n\x02\
+    \x05\0\x12\x04=\0V\x01\x1a!\x20The\x20full\x20set\x20of\x20known\x20edit\
+    ions.\n\n:\n\x04\x05\0\x02\0\x12\x03?\x02\x16\x1a-\x20A\x20placeholder\
+    \x20for\x20an\x20unknown\x20edition\x20value.\n\n\x99\x01\n\x04\x05\0\
+    \x02\x01\x12\x03C\x02\x17\x1a\x8b\x01\x20A\x20placeholder\x20edition\x20\
+    for\x20specifying\x20default\x20behaviors\x20*before*\x20a\x20feature\n\
+    \x20was\x20first\x20introduced.\x20\x20This\x20is\x20effectively\x20an\
+    \x20\"infinite\x20past\".\n\n\x82\x02\n\x04\x05\0\x02\x02\x12\x03I\x02\
+    \x17\x1a\xf4\x01\x20Legacy\x20syntax\x20\"editions\".\x20\x20These\x20pr\
+    e-date\x20editions,\x20but\x20behave\x20much\x20like\n\x20distinct\x20ed\
+    itions.\x20\x20These\x20can't\x20be\x20used\x20to\x20specify\x20the\x20e\
+    dition\x20of\x20proto\n\x20files,\x20but\x20feature\x20definitions\x20mu\
+    st\x20supply\x20proto2/proto3\x20defaults\x20for\n\x20backwards\x20compa\
+    tibility.\n\n\x0b\n\x04\x05\0\x02\x03\x12\x03J\x02\x17\n\xaf\x01\n\x04\
+    \x05\0\x02\x04\x12\x03O\x02\x16\x1a\xa1\x01\x20Editions\x20that\x20have\
+    \x20been\x20released.\x20\x20The\x20specific\x20values\x20are\x20arbitra\
+    ry\x20and\n\x20should\x20not\x20be\x20depended\x20on,\x20but\x20they\x20\
+    will\x20always\x20be\x20time-ordered\x20for\x20easy\n\x20comparison.\n\n\
+    \x0b\n\x04\x05\0\x02\x05\x12\x03P\x02\x16\n\xb8\x01\n\x04\x05\0\x02\x06\
+    \x12\x03U\x02\x1b\x1a\xaa\x01\x20Placeholder\x20for\x20specifying\x20unb\
+    ounded\x20edition\x20support.\x20\x20This\x20should\x20only\n\x20ever\
+    \x20be\x20used\x20by\x20plugins\x20that\x20can\x20expect\x20to\x20never\
+    \x20require\x20any\x20changes\x20to\n\x20support\x20a\x20new\x20edition.\
+    \n\n/\n\x02\x04\x01\x12\x04Y\0{\x01\x1a#\x20Describes\x20a\x20complete\
+    \x20.proto\x20file.\n\n\n\n\x03\x04\x01\x01\x12\x03Y\x08\x1b\n9\n\x04\
+    \x04\x01\x02\0\x12\x03Z\x02\x1b\",\x20file\x20name,\x20relative\x20to\
+    \x20root\x20of\x20source\x20tree\n\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\
+    Z\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03Z\x0b\x11\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03Z\x12\x16\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03Z\
+    \x19\x1a\n*\n\x04\x04\x01\x02\x01\x12\x03[\x02\x1e\"\x1d\x20e.g.\x20\"fo\
+    o\",\x20\"foo.bar\",\x20etc.\n\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03[\
+    \x02\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03[\x0b\x11\n\x0c\n\x05\x04\
+    \x01\x02\x01\x01\x12\x03[\x12\x19\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\
+    \x03[\x1c\x1d\n4\n\x04\x04\x01\x02\x02\x12\x03^\x02!\x1a'\x20Names\x20of\
+    \x20files\x20imported\x20by\x20this\x20file.\n\n\x0c\n\x05\x04\x01\x02\
+    \x02\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03^\x0b\x11\
+    \n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03^\x12\x1c\n\x0c\n\x05\x04\x01\
+    \x02\x02\x03\x12\x03^\x1f\x20\nQ\n\x04\x04\x01\x02\x03\x12\x03`\x02(\x1a\
+    D\x20Indexes\x20of\x20the\x20public\x20imported\x20files\x20in\x20the\
+    \x20dependency\x20list\x20above.\n\n\x0c\n\x05\x04\x01\x02\x03\x04\x12\
+    \x03`\x02\n\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03`\x0b\x10\n\x0c\n\x05\
+    \x04\x01\x02\x03\x01\x12\x03`\x11\"\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\
+    \x03`%'\nz\n\x04\x04\x01\x02\x04\x12\x03c\x02&\x1am\x20Indexes\x20of\x20\
+    the\x20weak\x20imported\x20files\x20in\x20the\x20dependency\x20list.\n\
+    \x20For\x20Google-internal\x20migration\x20only.\x20Do\x20not\x20use.\n\
+    \n\x0c\n\x05\x04\x01\x02\x04\x04\x12\x03c\x02\n\n\x0c\n\x05\x04\x01\x02\
+    \x04\x05\x12\x03c\x0b\x10\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03c\x11\
+    \x20\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03c#%\n6\n\x04\x04\x01\x02\x05\
+    \x12\x03f\x02,\x1a)\x20All\x20top-level\x20definitions\x20in\x20this\x20\
+    file.\n\n\x0c\n\x05\x04\x01\x02\x05\x04\x12\x03f\x02\n\n\x0c\n\x05\x04\
+    \x01\x02\x05\x06\x12\x03f\x0b\x1a\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\
+    \x03f\x1b'\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03f*+\n\x0b\n\x04\x04\
+    \x01\x02\x06\x12\x03g\x02-\n\x0c\n\x05\x04\x01\x02\x06\x04\x12\x03g\x02\
+    \n\n\x0c\n\x05\x04\x01\x02\x06\x06\x12\x03g\x0b\x1e\n\x0c\n\x05\x04\x01\
+    \x02\x06\x01\x12\x03g\x1f(\n\x0c\n\x05\x04\x01\x02\x06\x03\x12\x03g+,\n\
+    \x0b\n\x04\x04\x01\x02\x07\x12\x03h\x02.\n\x0c\n\x05\x04\x01\x02\x07\x04\
+    \x12\x03h\x02\n\n\x0c\n\x05\x04\x01\x02\x07\x06\x12\x03h\x0b!\n\x0c\n\
+    \x05\x04\x01\x02\x07\x01\x12\x03h\")\n\x0c\n\x05\x04\x01\x02\x07\x03\x12\
+    \x03h,-\n\x0b\n\x04\x04\x01\x02\x08\x12\x03i\x02.\n\x0c\n\x05\x04\x01\
+    \x02\x08\x04\x12\x03i\x02\n\n\x0c\n\x05\x04\x01\x02\x08\x06\x12\x03i\x0b\
+    \x1f\n\x0c\n\x05\x04\x01\x02\x08\x01\x12\x03i\x20)\n\x0c\n\x05\x04\x01\
+    \x02\x08\x03\x12\x03i,-\n\x0b\n\x04\x04\x01\x02\t\x12\x03k\x02#\n\x0c\n\
+    \x05\x04\x01\x02\t\x04\x12\x03k\x02\n\n\x0c\n\x05\x04\x01\x02\t\x06\x12\
+    \x03k\x0b\x16\n\x0c\n\x05\x04\x01\x02\t\x01\x12\x03k\x17\x1e\n\x0c\n\x05\
+    \x04\x01\x02\t\x03\x12\x03k!\"\n\xf4\x01\n\x04\x04\x01\x02\n\x12\x03q\
+    \x02/\x1a\xe6\x01\x20This\x20field\x20contains\x20optional\x20informatio\
+    n\x20about\x20the\x20original\x20source\x20code.\n\x20You\x20may\x20safe\
+    ly\x20remove\x20this\x20entire\x20field\x20without\x20harming\x20runtime\
+    \n\x20functionality\x20of\x20the\x20descriptors\x20--\x20the\x20informat\
+    ion\x20is\x20needed\x20only\x20by\n\x20development\x20tools.\n\n\x0c\n\
+    \x05\x04\x01\x02\n\x04\x12\x03q\x02\n\n\x0c\n\x05\x04\x01\x02\n\x06\x12\
+    \x03q\x0b\x19\n\x0c\n\x05\x04\x01\x02\n\x01\x12\x03q\x1a*\n\x0c\n\x05\
+    \x04\x01\x02\n\x03\x12\x03q-.\n\xa5\x01\n\x04\x04\x01\x02\x0b\x12\x03w\
+    \x02\x1e\x1a\x97\x01\x20The\x20syntax\x20of\x20the\x20proto\x20file.\n\
+    \x20The\x20supported\x20values\x20are\x20\"proto2\",\x20\"proto3\",\x20a\
+    nd\x20\"editions\".\n\n\x20If\x20`edition`\x20is\x20present,\x20this\x20\
+    value\x20must\x20be\x20\"editions\".\n\n\x0c\n\x05\x04\x01\x02\x0b\x04\
+    \x12\x03w\x02\n\n\x0c\n\x05\x04\x01\x02\x0b\x05\x12\x03w\x0b\x11\n\x0c\n\
+    \x05\x04\x01\x02\x0b\x01\x12\x03w\x12\x18\n\x0c\n\x05\x04\x01\x02\x0b\
+    \x03\x12\x03w\x1b\x1d\n-\n\x04\x04\x01\x02\x0c\x12\x03z\x02\x20\x1a\x20\
+    \x20The\x20edition\x20of\x20the\x20proto\x20file.\n\n(\n\x02\x04\x02\x12\
+    \x05~\0\x9e\x01\x01\x1a\x1b\x20Describes\x20a\x20message\x20type.\n\n\n\
+    \n\x03\x04\x02\x01\x12\x03~\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\x03\
+    \x7f\x02\x1b\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\x7f\x02\n\n\x0c\n\x05\
+    \x04\x02\x02\0\x05\x12\x03\x7f\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
+    \x03\x7f\x12\x16\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x7f\x19\x1a\n\x0c\
+    \n\x04\x04\x02\x02\x01\x12\x04\x81\x01\x02*\n\r\n\x05\x04\x02\x02\x01\
+    \x04\x12\x04\x81\x01\x02\n\n\r\n\x05\x04\x02\x02\x01\x06\x12\x04\x81\x01\
+    \x0b\x1f\n\r\n\x05\x04\x02\x02\x01\x01\x12\x04\x81\x01\x20%\n\r\n\x05\
+    \x04\x02\x02\x01\x03\x12\x04\x81\x01()\n\x0c\n\x04\x04\x02\x02\x02\x12\
+    \x04\x82\x01\x02.\n\r\n\x05\x04\x02\x02\x02\x04\x12\x04\x82\x01\x02\n\n\
+    \r\n\x05\x04\x02\x02\x02\x06\x12\x04\x82\x01\x0b\x1f\n\r\n\x05\x04\x02\
+    \x02\x02\x01\x12\x04\x82\x01\x20)\n\r\n\x05\x04\x02\x02\x02\x03\x12\x04\
+    \x82\x01,-\n\x0c\n\x04\x04\x02\x02\x03\x12\x04\x84\x01\x02+\n\r\n\x05\
+    \x04\x02\x02\x03\x04\x12\x04\x84\x01\x02\n\n\r\n\x05\x04\x02\x02\x03\x06\
+    \x12\x04\x84\x01\x0b\x1a\n\r\n\x05\x04\x02\x02\x03\x01\x12\x04\x84\x01\
+    \x1b&\n\r\n\x05\x04\x02\x02\x03\x03\x12\x04\x84\x01)*\n\x0c\n\x04\x04\
+    \x02\x02\x04\x12\x04\x85\x01\x02-\n\r\n\x05\x04\x02\x02\x04\x04\x12\x04\
+    \x85\x01\x02\n\n\r\n\x05\x04\x02\x02\x04\x06\x12\x04\x85\x01\x0b\x1e\n\r\
+    \n\x05\x04\x02\x02\x04\x01\x12\x04\x85\x01\x1f(\n\r\n\x05\x04\x02\x02\
+    \x04\x03\x12\x04\x85\x01+,\n\x0e\n\x04\x04\x02\x03\0\x12\x06\x87\x01\x02\
+    \x8c\x01\x03\n\r\n\x05\x04\x02\x03\0\x01\x12\x04\x87\x01\n\x18\n\x1c\n\
+    \x06\x04\x02\x03\0\x02\0\x12\x04\x88\x01\x04\x1d\"\x0c\x20Inclusive.\n\n\
+    \x0f\n\x07\x04\x02\x03\0\x02\0\x04\x12\x04\x88\x01\x04\x0c\n\x0f\n\x07\
+    \x04\x02\x03\0\x02\0\x05\x12\x04\x88\x01\r\x12\n\x0f\n\x07\x04\x02\x03\0\
+    \x02\0\x01\x12\x04\x88\x01\x13\x18\n\x0f\n\x07\x04\x02\x03\0\x02\0\x03\
+    \x12\x04\x88\x01\x1b\x1c\n\x1c\n\x06\x04\x02\x03\0\x02\x01\x12\x04\x89\
+    \x01\x04\x1b\"\x0c\x20Exclusive.\n\n\x0f\n\x07\x04\x02\x03\0\x02\x01\x04\
+    \x12\x04\x89\x01\x04\x0c\n\x0f\n\x07\x04\x02\x03\0\x02\x01\x05\x12\x04\
+    \x89\x01\r\x12\n\x0f\n\x07\x04\x02\x03\0\x02\x01\x01\x12\x04\x89\x01\x13\
+    \x16\n\x0f\n\x07\x04\x02\x03\0\x02\x01\x03\x12\x04\x89\x01\x19\x1a\n\x0e\
+    \n\x06\x04\x02\x03\0\x02\x02\x12\x04\x8b\x01\x04/\n\x0f\n\x07\x04\x02\
+    \x03\0\x02\x02\x04\x12\x04\x8b\x01\x04\x0c\n\x0f\n\x07\x04\x02\x03\0\x02\
+    \x02\x06\x12\x04\x8b\x01\r\"\n\x0f\n\x07\x04\x02\x03\0\x02\x02\x01\x12\
+    \x04\x8b\x01#*\n\x0f\n\x07\x04\x02\x03\0\x02\x02\x03\x12\x04\x8b\x01-.\n\
+    \x0c\n\x04\x04\x02\x02\x05\x12\x04\x8d\x01\x02.\n\r\n\x05\x04\x02\x02\
+    \x05\x04\x12\x04\x8d\x01\x02\n\n\r\n\x05\x04\x02\x02\x05\x06\x12\x04\x8d\
+    \x01\x0b\x19\n\r\n\x05\x04\x02\x02\x05\x01\x12\x04\x8d\x01\x1a)\n\r\n\
+    \x05\x04\x02\x02\x05\x03\x12\x04\x8d\x01,-\n\x0c\n\x04\x04\x02\x02\x06\
+    \x12\x04\x8f\x01\x02/\n\r\n\x05\x04\x02\x02\x06\x04\x12\x04\x8f\x01\x02\
+    \n\n\r\n\x05\x04\x02\x02\x06\x06\x12\x04\x8f\x01\x0b\x1f\n\r\n\x05\x04\
+    \x02\x02\x06\x01\x12\x04\x8f\x01\x20*\n\r\n\x05\x04\x02\x02\x06\x03\x12\
+    \x04\x8f\x01-.\n\x0c\n\x04\x04\x02\x02\x07\x12\x04\x91\x01\x02&\n\r\n\
+    \x05\x04\x02\x02\x07\x04\x12\x04\x91\x01\x02\n\n\r\n\x05\x04\x02\x02\x07\
+    \x06\x12\x04\x91\x01\x0b\x19\n\r\n\x05\x04\x02\x02\x07\x01\x12\x04\x91\
+    \x01\x1a!\n\r\n\x05\x04\x02\x02\x07\x03\x12\x04\x91\x01$%\n\xac\x01\n\
+    \x04\x04\x02\x03\x01\x12\x06\x96\x01\x02\x99\x01\x03\x1a\x9b\x01\x20Rang\
+    e\x20of\x20reserved\x20tag\x20numbers.\x20Reserved\x20tag\x20numbers\x20\
+    may\x20not\x20be\x20used\x20by\n\x20fields\x20or\x20extension\x20ranges\
+    \x20in\x20the\x20same\x20message.\x20Reserved\x20ranges\x20may\n\x20not\
+    \x20overlap.\n\n\r\n\x05\x04\x02\x03\x01\x01\x12\x04\x96\x01\n\x17\n\x1c\
+    \n\x06\x04\x02\x03\x01\x02\0\x12\x04\x97\x01\x04\x1d\"\x0c\x20Inclusive.\
+    \n\n\x0f\n\x07\x04\x02\x03\x01\x02\0\x04\x12\x04\x97\x01\x04\x0c\n\x0f\n\
+    \x07\x04\x02\x03\x01\x02\0\x05\x12\x04\x97\x01\r\x12\n\x0f\n\x07\x04\x02\
+    \x03\x01\x02\0\x01\x12\x04\x97\x01\x13\x18\n\x0f\n\x07\x04\x02\x03\x01\
+    \x02\0\x03\x12\x04\x97\x01\x1b\x1c\n\x1c\n\x06\x04\x02\x03\x01\x02\x01\
+    \x12\x04\x98\x01\x04\x1b\"\x0c\x20Exclusive.\n\n\x0f\n\x07\x04\x02\x03\
+    \x01\x02\x01\x04\x12\x04\x98\x01\x04\x0c\n\x0f\n\x07\x04\x02\x03\x01\x02\
+    \x01\x05\x12\x04\x98\x01\r\x12\n\x0f\n\x07\x04\x02\x03\x01\x02\x01\x01\
+    \x12\x04\x98\x01\x13\x16\n\x0f\n\x07\x04\x02\x03\x01\x02\x01\x03\x12\x04\
+    \x98\x01\x19\x1a\n\x0c\n\x04\x04\x02\x02\x08\x12\x04\x9a\x01\x02,\n\r\n\
+    \x05\x04\x02\x02\x08\x04\x12\x04\x9a\x01\x02\n\n\r\n\x05\x04\x02\x02\x08\
+    \x06\x12\x04\x9a\x01\x0b\x18\n\r\n\x05\x04\x02\x02\x08\x01\x12\x04\x9a\
+    \x01\x19'\n\r\n\x05\x04\x02\x02\x08\x03\x12\x04\x9a\x01*+\n\x83\x01\n\
+    \x04\x04\x02\x02\t\x12\x04\x9d\x01\x02%\x1au\x20Reserved\x20field\x20nam\
+    es,\x20which\x20may\x20not\x20be\x20used\x20by\x20fields\x20in\x20the\
+    \x20same\x20message.\n\x20A\x20given\x20name\x20may\x20only\x20be\x20res\
+    erved\x20once.\n\n\r\n\x05\x04\x02\x02\t\x04\x12\x04\x9d\x01\x02\n\n\r\n\
+    \x05\x04\x02\x02\t\x05\x12\x04\x9d\x01\x0b\x11\n\r\n\x05\x04\x02\x02\t\
+    \x01\x12\x04\x9d\x01\x12\x1f\n\r\n\x05\x04\x02\x02\t\x03\x12\x04\x9d\x01\
+    \"$\n\x0c\n\x02\x04\x03\x12\x06\xa0\x01\0\xa7\x01\x01\n\x0b\n\x03\x04\
+    \x03\x01\x12\x04\xa0\x01\x08\x1d\nO\n\x04\x04\x03\x02\0\x12\x04\xa2\x01\
+    \x02:\x1aA\x20The\x20parser\x20stores\x20options\x20it\x20doesn't\x20rec\
+    ognize\x20here.\x20See\x20above.\n\n\r\n\x05\x04\x03\x02\0\x04\x12\x04\
+    \xa2\x01\x02\n\n\r\n\x05\x04\x03\x02\0\x06\x12\x04\xa2\x01\x0b\x1e\n\r\n\
+    \x05\x04\x03\x02\0\x01\x12\x04\xa2\x01\x1f3\n\r\n\x05\x04\x03\x02\0\x03\
+    \x12\x04\xa2\x0169\nZ\n\x03\x04\x03\x05\x12\x04\xa6\x01\x02\x19\x1aM\x20\
+    Clients\x20can\x20define\x20custom\x20options\x20in\x20extensions\x20of\
+    \x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\x03\x05\0\x12\
+    \x04\xa6\x01\r\x18\n\r\n\x05\x04\x03\x05\0\x01\x12\x04\xa6\x01\r\x11\n\r\
+    \n\x05\x04\x03\x05\0\x02\x12\x04\xa6\x01\x15\x18\n3\n\x02\x04\x04\x12\
+    \x06\xaa\x01\0\x8f\x02\x01\x1a%\x20Describes\x20a\x20field\x20within\x20\
+    a\x20message.\n\n\x0b\n\x03\x04\x04\x01\x12\x04\xaa\x01\x08\x1c\n\x0e\n\
+    \x04\x04\x04\x04\0\x12\x06\xab\x01\x02\xca\x01\x03\n\r\n\x05\x04\x04\x04\
+    \0\x01\x12\x04\xab\x01\x07\x0b\nS\n\x06\x04\x04\x04\0\x02\0\x12\x04\xae\
+    \x01\x04\x14\x1aC\x200\x20is\x20reserved\x20for\x20errors.\n\x20Order\
+    \x20is\x20weird\x20for\x20historical\x20reasons.\n\n\x0f\n\x07\x04\x04\
+    \x04\0\x02\0\x01\x12\x04\xae\x01\x04\x0f\n\x0f\n\x07\x04\x04\x04\0\x02\0\
+    \x02\x12\x04\xae\x01\x12\x13\n\x0e\n\x06\x04\x04\x04\0\x02\x01\x12\x04\
+    \xaf\x01\x04\x13\n\x0f\n\x07\x04\x04\x04\0\x02\x01\x01\x12\x04\xaf\x01\
+    \x04\x0e\n\x0f\n\x07\x04\x04\x04\0\x02\x01\x02\x12\x04\xaf\x01\x11\x12\n\
+    w\n\x06\x04\x04\x04\0\x02\x02\x12\x04\xb2\x01\x04\x13\x1ag\x20Not\x20Zig\
+    Zag\x20encoded.\x20\x20Negative\x20numbers\x20take\x2010\x20bytes.\x20\
+    \x20Use\x20TYPE_SINT64\x20if\n\x20negative\x20values\x20are\x20likely.\n\
+    \n\x0f\n\x07\x04\x04\x04\0\x02\x02\x01\x12\x04\xb2\x01\x04\x0e\n\x0f\n\
+    \x07\x04\x04\x04\0\x02\x02\x02\x12\x04\xb2\x01\x11\x12\n\x0e\n\x06\x04\
+    \x04\x04\0\x02\x03\x12\x04\xb3\x01\x04\x14\n\x0f\n\x07\x04\x04\x04\0\x02\
+    \x03\x01\x12\x04\xb3\x01\x04\x0f\n\x0f\n\x07\x04\x04\x04\0\x02\x03\x02\
+    \x12\x04\xb3\x01\x12\x13\nw\n\x06\x04\x04\x04\0\x02\x04\x12\x04\xb6\x01\
+    \x04\x13\x1ag\x20Not\x20ZigZag\x20encoded.\x20\x20Negative\x20numbers\
+    \x20take\x2010\x20bytes.\x20\x20Use\x20TYPE_SINT32\x20if\n\x20negative\
+    \x20values\x20are\x20likely.\n\n\x0f\n\x07\x04\x04\x04\0\x02\x04\x01\x12\
+    \x04\xb6\x01\x04\x0e\n\x0f\n\x07\x04\x04\x04\0\x02\x04\x02\x12\x04\xb6\
+    \x01\x11\x12\n\x0e\n\x06\x04\x04\x04\0\x02\x05\x12\x04\xb7\x01\x04\x15\n\
+    \x0f\n\x07\x04\x04\x04\0\x02\x05\x01\x12\x04\xb7\x01\x04\x10\n\x0f\n\x07\
+    \x04\x04\x04\0\x02\x05\x02\x12\x04\xb7\x01\x13\x14\n\x0e\n\x06\x04\x04\
+    \x04\0\x02\x06\x12\x04\xb8\x01\x04\x15\n\x0f\n\x07\x04\x04\x04\0\x02\x06\
+    \x01\x12\x04\xb8\x01\x04\x10\n\x0f\n\x07\x04\x04\x04\0\x02\x06\x02\x12\
+    \x04\xb8\x01\x13\x14\n\x0e\n\x06\x04\x04\x04\0\x02\x07\x12\x04\xb9\x01\
+    \x04\x12\n\x0f\n\x07\x04\x04\x04\0\x02\x07\x01\x12\x04\xb9\x01\x04\r\n\
+    \x0f\n\x07\x04\x04\x04\0\x02\x07\x02\x12\x04\xb9\x01\x10\x11\n\x0e\n\x06\
+    \x04\x04\x04\0\x02\x08\x12\x04\xba\x01\x04\x14\n\x0f\n\x07\x04\x04\x04\0\
+    \x02\x08\x01\x12\x04\xba\x01\x04\x0f\n\x0f\n\x07\x04\x04\x04\0\x02\x08\
+    \x02\x12\x04\xba\x01\x12\x13\n\xe2\x01\n\x06\x04\x04\x04\0\x02\t\x12\x04\
+    \xbf\x01\x04\x14\x1a\xd1\x01\x20Tag-delimited\x20aggregate.\n\x20Group\
+    \x20type\x20is\x20deprecated\x20and\x20not\x20supported\x20in\x20proto3.\
+    \x20However,\x20Proto3\n\x20implementations\x20should\x20still\x20be\x20\
+    able\x20to\x20parse\x20the\x20group\x20wire\x20format\x20and\n\x20treat\
+    \x20group\x20fields\x20as\x20unknown\x20fields.\n\n\x0f\n\x07\x04\x04\
+    \x04\0\x02\t\x01\x12\x04\xbf\x01\x04\x0e\n\x0f\n\x07\x04\x04\x04\0\x02\t\
+    \x02\x12\x04\xbf\x01\x11\x13\n-\n\x06\x04\x04\x04\0\x02\n\x12\x04\xc0\
+    \x01\x04\x16\"\x1d\x20Length-delimited\x20aggregate.\n\n\x0f\n\x07\x04\
+    \x04\x04\0\x02\n\x01\x12\x04\xc0\x01\x04\x10\n\x0f\n\x07\x04\x04\x04\0\
+    \x02\n\x02\x12\x04\xc0\x01\x13\x15\n#\n\x06\x04\x04\x04\0\x02\x0b\x12\
+    \x04\xc3\x01\x04\x14\x1a\x13\x20New\x20in\x20version\x202.\n\n\x0f\n\x07\
+    \x04\x04\x04\0\x02\x0b\x01\x12\x04\xc3\x01\x04\x0e\n\x0f\n\x07\x04\x04\
+    \x04\0\x02\x0b\x02\x12\x04\xc3\x01\x11\x13\n\x0e\n\x06\x04\x04\x04\0\x02\
+    \x0c\x12\x04\xc4\x01\x04\x15\n\x0f\n\x07\x04\x04\x04\0\x02\x0c\x01\x12\
+    \x04\xc4\x01\x04\x0f\n\x0f\n\x07\x04\x04\x04\0\x02\x0c\x02\x12\x04\xc4\
+    \x01\x12\x14\n\x0e\n\x06\x04\x04\x04\0\x02\r\x12\x04\xc5\x01\x04\x13\n\
+    \x0f\n\x07\x04\x04\x04\0\x02\r\x01\x12\x04\xc5\x01\x04\r\n\x0f\n\x07\x04\
+    \x04\x04\0\x02\r\x02\x12\x04\xc5\x01\x10\x12\n\x0e\n\x06\x04\x04\x04\0\
+    \x02\x0e\x12\x04\xc6\x01\x04\x17\n\x0f\n\x07\x04\x04\x04\0\x02\x0e\x01\
+    \x12\x04\xc6\x01\x04\x11\n\x0f\n\x07\x04\x04\x04\0\x02\x0e\x02\x12\x04\
+    \xc6\x01\x14\x16\n\x0e\n\x06\x04\x04\x04\0\x02\x0f\x12\x04\xc7\x01\x04\
+    \x17\n\x0f\n\x07\x04\x04\x04\0\x02\x0f\x01\x12\x04\xc7\x01\x04\x11\n\x0f\
+    \n\x07\x04\x04\x04\0\x02\x0f\x02\x12\x04\xc7\x01\x14\x16\n'\n\x06\x04\
+    \x04\x04\0\x02\x10\x12\x04\xc8\x01\x04\x15\"\x17\x20Uses\x20ZigZag\x20en\
+    coding.\n\n\x0f\n\x07\x04\x04\x04\0\x02\x10\x01\x12\x04\xc8\x01\x04\x0f\
+    \n\x0f\n\x07\x04\x04\x04\0\x02\x10\x02\x12\x04\xc8\x01\x12\x14\n'\n\x06\
+    \x04\x04\x04\0\x02\x11\x12\x04\xc9\x01\x04\x15\"\x17\x20Uses\x20ZigZag\
+    \x20encoding.\n\n\x0f\n\x07\x04\x04\x04\0\x02\x11\x01\x12\x04\xc9\x01\
+    \x04\x0f\n\x0f\n\x07\x04\x04\x04\0\x02\x11\x02\x12\x04\xc9\x01\x12\x14\n\
+    \x0e\n\x04\x04\x04\x04\x01\x12\x06\xcc\x01\x02\xd1\x01\x03\n\r\n\x05\x04\
+    \x04\x04\x01\x01\x12\x04\xcc\x01\x07\x0c\n*\n\x06\x04\x04\x04\x01\x02\0\
+    \x12\x04\xce\x01\x04\x17\x1a\x1a\x200\x20is\x20reserved\x20for\x20errors\
+    \n\n\x0f\n\x07\x04\x04\x04\x01\x02\0\x01\x12\x04\xce\x01\x04\x12\n\x0f\n\
+    \x07\x04\x04\x04\x01\x02\0\x02\x12\x04\xce\x01\x15\x16\n\x0e\n\x06\x04\
+    \x04\x04\x01\x02\x01\x12\x04\xcf\x01\x04\x17\n\x0f\n\x07\x04\x04\x04\x01\
+    \x02\x01\x01\x12\x04\xcf\x01\x04\x12\n\x0f\n\x07\x04\x04\x04\x01\x02\x01\
+    \x02\x12\x04\xcf\x01\x15\x16\n\x0e\n\x06\x04\x04\x04\x01\x02\x02\x12\x04\
+    \xd0\x01\x04\x17\n\x0f\n\x07\x04\x04\x04\x01\x02\x02\x01\x12\x04\xd0\x01\
+    \x04\x12\n\x0f\n\x07\x04\x04\x04\x01\x02\x02\x02\x12\x04\xd0\x01\x15\x16\
+    \n\x0c\n\x04\x04\x04\x02\0\x12\x04\xd3\x01\x02\x1b\n\r\n\x05\x04\x04\x02\
+    \0\x04\x12\x04\xd3\x01\x02\n\n\r\n\x05\x04\x04\x02\0\x05\x12\x04\xd3\x01\
+    \x0b\x11\n\r\n\x05\x04\x04\x02\0\x01\x12\x04\xd3\x01\x12\x16\n\r\n\x05\
+    \x04\x04\x02\0\x03\x12\x04\xd3\x01\x19\x1a\n\x0c\n\x04\x04\x04\x02\x01\
+    \x12\x04\xd4\x01\x02\x1c\n\r\n\x05\x04\x04\x02\x01\x04\x12\x04\xd4\x01\
+    \x02\n\n\r\n\x05\x04\x04\x02\x01\x05\x12\x04\xd4\x01\x0b\x10\n\r\n\x05\
+    \x04\x04\x02\x01\x01\x12\x04\xd4\x01\x11\x17\n\r\n\x05\x04\x04\x02\x01\
+    \x03\x12\x04\xd4\x01\x1a\x1b\n\x0c\n\x04\x04\x04\x02\x02\x12\x04\xd5\x01\
+    \x02\x1b\n\r\n\x05\x04\x04\x02\x02\x04\x12\x04\xd5\x01\x02\n\n\r\n\x05\
+    \x04\x04\x02\x02\x06\x12\x04\xd5\x01\x0b\x10\n\r\n\x05\x04\x04\x02\x02\
+    \x01\x12\x04\xd5\x01\x11\x16\n\r\n\x05\x04\x04\x02\x02\x03\x12\x04\xd5\
+    \x01\x19\x1a\n\x9c\x01\n\x04\x04\x04\x02\x03\x12\x04\xd9\x01\x02\x19\x1a\
+    \x8d\x01\x20If\x20type_name\x20is\x20set,\x20this\x20need\x20not\x20be\
+    \x20set.\x20\x20If\x20both\x20this\x20and\x20type_name\n\x20are\x20set,\
+    \x20this\x20must\x20be\x20one\x20of\x20TYPE_ENUM,\x20TYPE_MESSAGE\x20or\
+    \x20TYPE_GROUP.\n\n\r\n\x05\x04\x04\x02\x03\x04\x12\x04\xd9\x01\x02\n\n\
+    \r\n\x05\x04\x04\x02\x03\x06\x12\x04\xd9\x01\x0b\x0f\n\r\n\x05\x04\x04\
+    \x02\x03\x01\x12\x04\xd9\x01\x10\x14\n\r\n\x05\x04\x04\x02\x03\x03\x12\
+    \x04\xd9\x01\x17\x18\n\xb7\x02\n\x04\x04\x04\x02\x04\x12\x04\xe0\x01\x02\
     \x20\x1a\xa8\x02\x20For\x20message\x20and\x20enum\x20types,\x20this\x20i\
     s\x20the\x20name\x20of\x20the\x20type.\x20\x20If\x20the\x20name\n\x20sta\
     rts\x20with\x20a\x20'.',\x20it\x20is\x20fully-qualified.\x20\x20Otherwis\
@@ -8807,45 +10919,45 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20type\x20(i.e.\x20first\x20the\x20nested\x20types\x20within\x20this\n\
     \x20message\x20are\x20searched,\x20then\x20within\x20the\x20parent,\x20o\
     n\x20up\x20to\x20the\x20root\n\x20namespace).\n\n\r\n\x05\x04\x04\x02\
-    \x04\x04\x12\x04\xbf\x01\x02\n\n\r\n\x05\x04\x04\x02\x04\x05\x12\x04\xbf\
-    \x01\x0b\x11\n\r\n\x05\x04\x04\x02\x04\x01\x12\x04\xbf\x01\x12\x1b\n\r\n\
-    \x05\x04\x04\x02\x04\x03\x12\x04\xbf\x01\x1e\x1f\n~\n\x04\x04\x04\x02\
-    \x05\x12\x04\xc3\x01\x02\x1f\x1ap\x20For\x20extensions,\x20this\x20is\
+    \x04\x04\x12\x04\xe0\x01\x02\n\n\r\n\x05\x04\x04\x02\x04\x05\x12\x04\xe0\
+    \x01\x0b\x11\n\r\n\x05\x04\x04\x02\x04\x01\x12\x04\xe0\x01\x12\x1b\n\r\n\
+    \x05\x04\x04\x02\x04\x03\x12\x04\xe0\x01\x1e\x1f\n~\n\x04\x04\x04\x02\
+    \x05\x12\x04\xe4\x01\x02\x1f\x1ap\x20For\x20extensions,\x20this\x20is\
     \x20the\x20name\x20of\x20the\x20type\x20being\x20extended.\x20\x20It\x20\
     is\n\x20resolved\x20in\x20the\x20same\x20manner\x20as\x20type_name.\n\n\
-    \r\n\x05\x04\x04\x02\x05\x04\x12\x04\xc3\x01\x02\n\n\r\n\x05\x04\x04\x02\
-    \x05\x05\x12\x04\xc3\x01\x0b\x11\n\r\n\x05\x04\x04\x02\x05\x01\x12\x04\
-    \xc3\x01\x12\x1a\n\r\n\x05\x04\x04\x02\x05\x03\x12\x04\xc3\x01\x1d\x1e\n\
-    \xb1\x02\n\x04\x04\x04\x02\x06\x12\x04\xca\x01\x02$\x1a\xa2\x02\x20For\
+    \r\n\x05\x04\x04\x02\x05\x04\x12\x04\xe4\x01\x02\n\n\r\n\x05\x04\x04\x02\
+    \x05\x05\x12\x04\xe4\x01\x0b\x11\n\r\n\x05\x04\x04\x02\x05\x01\x12\x04\
+    \xe4\x01\x12\x1a\n\r\n\x05\x04\x04\x02\x05\x03\x12\x04\xe4\x01\x1d\x1e\n\
+    \xb1\x02\n\x04\x04\x04\x02\x06\x12\x04\xeb\x01\x02$\x1a\xa2\x02\x20For\
     \x20numeric\x20types,\x20contains\x20the\x20original\x20text\x20represen\
     tation\x20of\x20the\x20value.\n\x20For\x20booleans,\x20\"true\"\x20or\
     \x20\"false\".\n\x20For\x20strings,\x20contains\x20the\x20default\x20tex\
     t\x20contents\x20(not\x20escaped\x20in\x20any\x20way).\n\x20For\x20bytes\
     ,\x20contains\x20the\x20C\x20escaped\x20value.\x20\x20All\x20bytes\x20>=\
     \x20128\x20are\x20escaped.\n\x20TODO(kenton):\x20\x20Base-64\x20encode?\
-    \n\n\r\n\x05\x04\x04\x02\x06\x04\x12\x04\xca\x01\x02\n\n\r\n\x05\x04\x04\
-    \x02\x06\x05\x12\x04\xca\x01\x0b\x11\n\r\n\x05\x04\x04\x02\x06\x01\x12\
-    \x04\xca\x01\x12\x1f\n\r\n\x05\x04\x04\x02\x06\x03\x12\x04\xca\x01\"#\n\
-    \x84\x01\n\x04\x04\x04\x02\x07\x12\x04\xce\x01\x02!\x1av\x20If\x20set,\
+    \n\n\r\n\x05\x04\x04\x02\x06\x04\x12\x04\xeb\x01\x02\n\n\r\n\x05\x04\x04\
+    \x02\x06\x05\x12\x04\xeb\x01\x0b\x11\n\r\n\x05\x04\x04\x02\x06\x01\x12\
+    \x04\xeb\x01\x12\x1f\n\r\n\x05\x04\x04\x02\x06\x03\x12\x04\xeb\x01\"#\n\
+    \x84\x01\n\x04\x04\x04\x02\x07\x12\x04\xef\x01\x02!\x1av\x20If\x20set,\
     \x20gives\x20the\x20index\x20of\x20a\x20oneof\x20in\x20the\x20containing\
     \x20type's\x20oneof_decl\n\x20list.\x20\x20This\x20field\x20is\x20a\x20m\
     ember\x20of\x20that\x20oneof.\n\n\r\n\x05\x04\x04\x02\x07\x04\x12\x04\
-    \xce\x01\x02\n\n\r\n\x05\x04\x04\x02\x07\x05\x12\x04\xce\x01\x0b\x10\n\r\
-    \n\x05\x04\x04\x02\x07\x01\x12\x04\xce\x01\x11\x1c\n\r\n\x05\x04\x04\x02\
-    \x07\x03\x12\x04\xce\x01\x1f\x20\n\xfa\x01\n\x04\x04\x04\x02\x08\x12\x04\
-    \xd4\x01\x02!\x1a\xeb\x01\x20JSON\x20name\x20of\x20this\x20field.\x20The\
+    \xef\x01\x02\n\n\r\n\x05\x04\x04\x02\x07\x05\x12\x04\xef\x01\x0b\x10\n\r\
+    \n\x05\x04\x04\x02\x07\x01\x12\x04\xef\x01\x11\x1c\n\r\n\x05\x04\x04\x02\
+    \x07\x03\x12\x04\xef\x01\x1f\x20\n\xfa\x01\n\x04\x04\x04\x02\x08\x12\x04\
+    \xf5\x01\x02!\x1a\xeb\x01\x20JSON\x20name\x20of\x20this\x20field.\x20The\
     \x20value\x20is\x20set\x20by\x20protocol\x20compiler.\x20If\x20the\n\x20\
     user\x20has\x20set\x20a\x20\"json_name\"\x20option\x20on\x20this\x20fiel\
     d,\x20that\x20option's\x20value\n\x20will\x20be\x20used.\x20Otherwise,\
     \x20it's\x20deduced\x20from\x20the\x20field's\x20name\x20by\x20convertin\
     g\n\x20it\x20to\x20camelCase.\n\n\r\n\x05\x04\x04\x02\x08\x04\x12\x04\
-    \xd4\x01\x02\n\n\r\n\x05\x04\x04\x02\x08\x05\x12\x04\xd4\x01\x0b\x11\n\r\
-    \n\x05\x04\x04\x02\x08\x01\x12\x04\xd4\x01\x12\x1b\n\r\n\x05\x04\x04\x02\
-    \x08\x03\x12\x04\xd4\x01\x1e\x20\n\x0c\n\x04\x04\x04\x02\t\x12\x04\xd6\
-    \x01\x02$\n\r\n\x05\x04\x04\x02\t\x04\x12\x04\xd6\x01\x02\n\n\r\n\x05\
-    \x04\x04\x02\t\x06\x12\x04\xd6\x01\x0b\x17\n\r\n\x05\x04\x04\x02\t\x01\
-    \x12\x04\xd6\x01\x18\x1f\n\r\n\x05\x04\x04\x02\t\x03\x12\x04\xd6\x01\"#\
-    \n\xb3\t\n\x04\x04\x04\x02\n\x12\x04\xed\x01\x02%\x1a\xa4\t\x20If\x20tru\
+    \xf5\x01\x02\n\n\r\n\x05\x04\x04\x02\x08\x05\x12\x04\xf5\x01\x0b\x11\n\r\
+    \n\x05\x04\x04\x02\x08\x01\x12\x04\xf5\x01\x12\x1b\n\r\n\x05\x04\x04\x02\
+    \x08\x03\x12\x04\xf5\x01\x1e\x20\n\x0c\n\x04\x04\x04\x02\t\x12\x04\xf7\
+    \x01\x02$\n\r\n\x05\x04\x04\x02\t\x04\x12\x04\xf7\x01\x02\n\n\r\n\x05\
+    \x04\x04\x02\t\x06\x12\x04\xf7\x01\x0b\x17\n\r\n\x05\x04\x04\x02\t\x01\
+    \x12\x04\xf7\x01\x18\x1f\n\r\n\x05\x04\x04\x02\t\x03\x12\x04\xf7\x01\"#\
+    \n\xb3\t\n\x04\x04\x04\x02\n\x12\x04\x8e\x02\x02%\x1a\xa4\t\x20If\x20tru\
     e,\x20this\x20is\x20a\x20proto3\x20\"optional\".\x20When\x20a\x20proto3\
     \x20field\x20is\x20optional,\x20it\n\x20tracks\x20presence\x20regardless\
     \x20of\x20field\x20type.\n\n\x20When\x20proto3_optional\x20is\x20true,\
@@ -8871,118 +10983,118 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20always\x20create\x20a\n\x20synthetic\x20oneof.\n\n\x20Proto2\x20opti\
     onal\x20fields\x20do\x20not\x20set\x20this\x20flag,\x20because\x20they\
     \x20already\x20indicate\n\x20optional\x20with\x20`LABEL_OPTIONAL`.\n\n\r\
-    \n\x05\x04\x04\x02\n\x04\x12\x04\xed\x01\x02\n\n\r\n\x05\x04\x04\x02\n\
-    \x05\x12\x04\xed\x01\x0b\x0f\n\r\n\x05\x04\x04\x02\n\x01\x12\x04\xed\x01\
-    \x10\x1f\n\r\n\x05\x04\x04\x02\n\x03\x12\x04\xed\x01\"$\n\"\n\x02\x04\
-    \x05\x12\x06\xf1\x01\0\xf4\x01\x01\x1a\x14\x20Describes\x20a\x20oneof.\n\
-    \n\x0b\n\x03\x04\x05\x01\x12\x04\xf1\x01\x08\x1c\n\x0c\n\x04\x04\x05\x02\
-    \0\x12\x04\xf2\x01\x02\x1b\n\r\n\x05\x04\x05\x02\0\x04\x12\x04\xf2\x01\
-    \x02\n\n\r\n\x05\x04\x05\x02\0\x05\x12\x04\xf2\x01\x0b\x11\n\r\n\x05\x04\
-    \x05\x02\0\x01\x12\x04\xf2\x01\x12\x16\n\r\n\x05\x04\x05\x02\0\x03\x12\
-    \x04\xf2\x01\x19\x1a\n\x0c\n\x04\x04\x05\x02\x01\x12\x04\xf3\x01\x02$\n\
-    \r\n\x05\x04\x05\x02\x01\x04\x12\x04\xf3\x01\x02\n\n\r\n\x05\x04\x05\x02\
-    \x01\x06\x12\x04\xf3\x01\x0b\x17\n\r\n\x05\x04\x05\x02\x01\x01\x12\x04\
-    \xf3\x01\x18\x1f\n\r\n\x05\x04\x05\x02\x01\x03\x12\x04\xf3\x01\"#\n'\n\
-    \x02\x04\x06\x12\x06\xf7\x01\0\x91\x02\x01\x1a\x19\x20Describes\x20an\
-    \x20enum\x20type.\n\n\x0b\n\x03\x04\x06\x01\x12\x04\xf7\x01\x08\x1b\n\
-    \x0c\n\x04\x04\x06\x02\0\x12\x04\xf8\x01\x02\x1b\n\r\n\x05\x04\x06\x02\0\
-    \x04\x12\x04\xf8\x01\x02\n\n\r\n\x05\x04\x06\x02\0\x05\x12\x04\xf8\x01\
-    \x0b\x11\n\r\n\x05\x04\x06\x02\0\x01\x12\x04\xf8\x01\x12\x16\n\r\n\x05\
-    \x04\x06\x02\0\x03\x12\x04\xf8\x01\x19\x1a\n\x0c\n\x04\x04\x06\x02\x01\
-    \x12\x04\xfa\x01\x02.\n\r\n\x05\x04\x06\x02\x01\x04\x12\x04\xfa\x01\x02\
-    \n\n\r\n\x05\x04\x06\x02\x01\x06\x12\x04\xfa\x01\x0b#\n\r\n\x05\x04\x06\
-    \x02\x01\x01\x12\x04\xfa\x01$)\n\r\n\x05\x04\x06\x02\x01\x03\x12\x04\xfa\
-    \x01,-\n\x0c\n\x04\x04\x06\x02\x02\x12\x04\xfc\x01\x02#\n\r\n\x05\x04\
-    \x06\x02\x02\x04\x12\x04\xfc\x01\x02\n\n\r\n\x05\x04\x06\x02\x02\x06\x12\
-    \x04\xfc\x01\x0b\x16\n\r\n\x05\x04\x06\x02\x02\x01\x12\x04\xfc\x01\x17\
-    \x1e\n\r\n\x05\x04\x06\x02\x02\x03\x12\x04\xfc\x01!\"\n\xaf\x02\n\x04\
-    \x04\x06\x03\0\x12\x06\x84\x02\x02\x87\x02\x03\x1a\x9e\x02\x20Range\x20o\
+    \n\x05\x04\x04\x02\n\x04\x12\x04\x8e\x02\x02\n\n\r\n\x05\x04\x04\x02\n\
+    \x05\x12\x04\x8e\x02\x0b\x0f\n\r\n\x05\x04\x04\x02\n\x01\x12\x04\x8e\x02\
+    \x10\x1f\n\r\n\x05\x04\x04\x02\n\x03\x12\x04\x8e\x02\"$\n\"\n\x02\x04\
+    \x05\x12\x06\x92\x02\0\x95\x02\x01\x1a\x14\x20Describes\x20a\x20oneof.\n\
+    \n\x0b\n\x03\x04\x05\x01\x12\x04\x92\x02\x08\x1c\n\x0c\n\x04\x04\x05\x02\
+    \0\x12\x04\x93\x02\x02\x1b\n\r\n\x05\x04\x05\x02\0\x04\x12\x04\x93\x02\
+    \x02\n\n\r\n\x05\x04\x05\x02\0\x05\x12\x04\x93\x02\x0b\x11\n\r\n\x05\x04\
+    \x05\x02\0\x01\x12\x04\x93\x02\x12\x16\n\r\n\x05\x04\x05\x02\0\x03\x12\
+    \x04\x93\x02\x19\x1a\n\x0c\n\x04\x04\x05\x02\x01\x12\x04\x94\x02\x02$\n\
+    \r\n\x05\x04\x05\x02\x01\x04\x12\x04\x94\x02\x02\n\n\r\n\x05\x04\x05\x02\
+    \x01\x06\x12\x04\x94\x02\x0b\x17\n\r\n\x05\x04\x05\x02\x01\x01\x12\x04\
+    \x94\x02\x18\x1f\n\r\n\x05\x04\x05\x02\x01\x03\x12\x04\x94\x02\"#\n'\n\
+    \x02\x04\x06\x12\x06\x98\x02\0\xb2\x02\x01\x1a\x19\x20Describes\x20an\
+    \x20enum\x20type.\n\n\x0b\n\x03\x04\x06\x01\x12\x04\x98\x02\x08\x1b\n\
+    \x0c\n\x04\x04\x06\x02\0\x12\x04\x99\x02\x02\x1b\n\r\n\x05\x04\x06\x02\0\
+    \x04\x12\x04\x99\x02\x02\n\n\r\n\x05\x04\x06\x02\0\x05\x12\x04\x99\x02\
+    \x0b\x11\n\r\n\x05\x04\x06\x02\0\x01\x12\x04\x99\x02\x12\x16\n\r\n\x05\
+    \x04\x06\x02\0\x03\x12\x04\x99\x02\x19\x1a\n\x0c\n\x04\x04\x06\x02\x01\
+    \x12\x04\x9b\x02\x02.\n\r\n\x05\x04\x06\x02\x01\x04\x12\x04\x9b\x02\x02\
+    \n\n\r\n\x05\x04\x06\x02\x01\x06\x12\x04\x9b\x02\x0b#\n\r\n\x05\x04\x06\
+    \x02\x01\x01\x12\x04\x9b\x02$)\n\r\n\x05\x04\x06\x02\x01\x03\x12\x04\x9b\
+    \x02,-\n\x0c\n\x04\x04\x06\x02\x02\x12\x04\x9d\x02\x02#\n\r\n\x05\x04\
+    \x06\x02\x02\x04\x12\x04\x9d\x02\x02\n\n\r\n\x05\x04\x06\x02\x02\x06\x12\
+    \x04\x9d\x02\x0b\x16\n\r\n\x05\x04\x06\x02\x02\x01\x12\x04\x9d\x02\x17\
+    \x1e\n\r\n\x05\x04\x06\x02\x02\x03\x12\x04\x9d\x02!\"\n\xaf\x02\n\x04\
+    \x04\x06\x03\0\x12\x06\xa5\x02\x02\xa8\x02\x03\x1a\x9e\x02\x20Range\x20o\
     f\x20reserved\x20numeric\x20values.\x20Reserved\x20values\x20may\x20not\
     \x20be\x20used\x20by\n\x20entries\x20in\x20the\x20same\x20enum.\x20Reser\
     ved\x20ranges\x20may\x20not\x20overlap.\n\n\x20Note\x20that\x20this\x20i\
     s\x20distinct\x20from\x20DescriptorProto.ReservedRange\x20in\x20that\x20\
     it\n\x20is\x20inclusive\x20such\x20that\x20it\x20can\x20appropriately\
     \x20represent\x20the\x20entire\x20int32\n\x20domain.\n\n\r\n\x05\x04\x06\
-    \x03\0\x01\x12\x04\x84\x02\n\x1b\n\x1c\n\x06\x04\x06\x03\0\x02\0\x12\x04\
-    \x85\x02\x04\x1d\"\x0c\x20Inclusive.\n\n\x0f\n\x07\x04\x06\x03\0\x02\0\
-    \x04\x12\x04\x85\x02\x04\x0c\n\x0f\n\x07\x04\x06\x03\0\x02\0\x05\x12\x04\
-    \x85\x02\r\x12\n\x0f\n\x07\x04\x06\x03\0\x02\0\x01\x12\x04\x85\x02\x13\
-    \x18\n\x0f\n\x07\x04\x06\x03\0\x02\0\x03\x12\x04\x85\x02\x1b\x1c\n\x1c\n\
-    \x06\x04\x06\x03\0\x02\x01\x12\x04\x86\x02\x04\x1b\"\x0c\x20Inclusive.\n\
-    \n\x0f\n\x07\x04\x06\x03\0\x02\x01\x04\x12\x04\x86\x02\x04\x0c\n\x0f\n\
-    \x07\x04\x06\x03\0\x02\x01\x05\x12\x04\x86\x02\r\x12\n\x0f\n\x07\x04\x06\
-    \x03\0\x02\x01\x01\x12\x04\x86\x02\x13\x16\n\x0f\n\x07\x04\x06\x03\0\x02\
-    \x01\x03\x12\x04\x86\x02\x19\x1a\n\xaa\x01\n\x04\x04\x06\x02\x03\x12\x04\
-    \x8c\x02\x020\x1a\x9b\x01\x20Range\x20of\x20reserved\x20numeric\x20value\
+    \x03\0\x01\x12\x04\xa5\x02\n\x1b\n\x1c\n\x06\x04\x06\x03\0\x02\0\x12\x04\
+    \xa6\x02\x04\x1d\"\x0c\x20Inclusive.\n\n\x0f\n\x07\x04\x06\x03\0\x02\0\
+    \x04\x12\x04\xa6\x02\x04\x0c\n\x0f\n\x07\x04\x06\x03\0\x02\0\x05\x12\x04\
+    \xa6\x02\r\x12\n\x0f\n\x07\x04\x06\x03\0\x02\0\x01\x12\x04\xa6\x02\x13\
+    \x18\n\x0f\n\x07\x04\x06\x03\0\x02\0\x03\x12\x04\xa6\x02\x1b\x1c\n\x1c\n\
+    \x06\x04\x06\x03\0\x02\x01\x12\x04\xa7\x02\x04\x1b\"\x0c\x20Inclusive.\n\
+    \n\x0f\n\x07\x04\x06\x03\0\x02\x01\x04\x12\x04\xa7\x02\x04\x0c\n\x0f\n\
+    \x07\x04\x06\x03\0\x02\x01\x05\x12\x04\xa7\x02\r\x12\n\x0f\n\x07\x04\x06\
+    \x03\0\x02\x01\x01\x12\x04\xa7\x02\x13\x16\n\x0f\n\x07\x04\x06\x03\0\x02\
+    \x01\x03\x12\x04\xa7\x02\x19\x1a\n\xaa\x01\n\x04\x04\x06\x02\x03\x12\x04\
+    \xad\x02\x020\x1a\x9b\x01\x20Range\x20of\x20reserved\x20numeric\x20value\
     s.\x20Reserved\x20numeric\x20values\x20may\x20not\x20be\x20used\n\x20by\
     \x20enum\x20values\x20in\x20the\x20same\x20enum\x20declaration.\x20Reser\
     ved\x20ranges\x20may\x20not\n\x20overlap.\n\n\r\n\x05\x04\x06\x02\x03\
-    \x04\x12\x04\x8c\x02\x02\n\n\r\n\x05\x04\x06\x02\x03\x06\x12\x04\x8c\x02\
-    \x0b\x1c\n\r\n\x05\x04\x06\x02\x03\x01\x12\x04\x8c\x02\x1d+\n\r\n\x05\
-    \x04\x06\x02\x03\x03\x12\x04\x8c\x02./\nl\n\x04\x04\x06\x02\x04\x12\x04\
-    \x90\x02\x02$\x1a^\x20Reserved\x20enum\x20value\x20names,\x20which\x20ma\
+    \x04\x12\x04\xad\x02\x02\n\n\r\n\x05\x04\x06\x02\x03\x06\x12\x04\xad\x02\
+    \x0b\x1c\n\r\n\x05\x04\x06\x02\x03\x01\x12\x04\xad\x02\x1d+\n\r\n\x05\
+    \x04\x06\x02\x03\x03\x12\x04\xad\x02./\nl\n\x04\x04\x06\x02\x04\x12\x04\
+    \xb1\x02\x02$\x1a^\x20Reserved\x20enum\x20value\x20names,\x20which\x20ma\
     y\x20not\x20be\x20reused.\x20A\x20given\x20name\x20may\x20only\n\x20be\
-    \x20reserved\x20once.\n\n\r\n\x05\x04\x06\x02\x04\x04\x12\x04\x90\x02\
-    \x02\n\n\r\n\x05\x04\x06\x02\x04\x05\x12\x04\x90\x02\x0b\x11\n\r\n\x05\
-    \x04\x06\x02\x04\x01\x12\x04\x90\x02\x12\x1f\n\r\n\x05\x04\x06\x02\x04\
-    \x03\x12\x04\x90\x02\"#\n1\n\x02\x04\x07\x12\x06\x94\x02\0\x99\x02\x01\
+    \x20reserved\x20once.\n\n\r\n\x05\x04\x06\x02\x04\x04\x12\x04\xb1\x02\
+    \x02\n\n\r\n\x05\x04\x06\x02\x04\x05\x12\x04\xb1\x02\x0b\x11\n\r\n\x05\
+    \x04\x06\x02\x04\x01\x12\x04\xb1\x02\x12\x1f\n\r\n\x05\x04\x06\x02\x04\
+    \x03\x12\x04\xb1\x02\"#\n1\n\x02\x04\x07\x12\x06\xb5\x02\0\xba\x02\x01\
     \x1a#\x20Describes\x20a\x20value\x20within\x20an\x20enum.\n\n\x0b\n\x03\
-    \x04\x07\x01\x12\x04\x94\x02\x08\x20\n\x0c\n\x04\x04\x07\x02\0\x12\x04\
-    \x95\x02\x02\x1b\n\r\n\x05\x04\x07\x02\0\x04\x12\x04\x95\x02\x02\n\n\r\n\
-    \x05\x04\x07\x02\0\x05\x12\x04\x95\x02\x0b\x11\n\r\n\x05\x04\x07\x02\0\
-    \x01\x12\x04\x95\x02\x12\x16\n\r\n\x05\x04\x07\x02\0\x03\x12\x04\x95\x02\
-    \x19\x1a\n\x0c\n\x04\x04\x07\x02\x01\x12\x04\x96\x02\x02\x1c\n\r\n\x05\
-    \x04\x07\x02\x01\x04\x12\x04\x96\x02\x02\n\n\r\n\x05\x04\x07\x02\x01\x05\
-    \x12\x04\x96\x02\x0b\x10\n\r\n\x05\x04\x07\x02\x01\x01\x12\x04\x96\x02\
-    \x11\x17\n\r\n\x05\x04\x07\x02\x01\x03\x12\x04\x96\x02\x1a\x1b\n\x0c\n\
-    \x04\x04\x07\x02\x02\x12\x04\x98\x02\x02(\n\r\n\x05\x04\x07\x02\x02\x04\
-    \x12\x04\x98\x02\x02\n\n\r\n\x05\x04\x07\x02\x02\x06\x12\x04\x98\x02\x0b\
-    \x1b\n\r\n\x05\x04\x07\x02\x02\x01\x12\x04\x98\x02\x1c#\n\r\n\x05\x04\
-    \x07\x02\x02\x03\x12\x04\x98\x02&'\n$\n\x02\x04\x08\x12\x06\x9c\x02\0\
-    \xa1\x02\x01\x1a\x16\x20Describes\x20a\x20service.\n\n\x0b\n\x03\x04\x08\
-    \x01\x12\x04\x9c\x02\x08\x1e\n\x0c\n\x04\x04\x08\x02\0\x12\x04\x9d\x02\
-    \x02\x1b\n\r\n\x05\x04\x08\x02\0\x04\x12\x04\x9d\x02\x02\n\n\r\n\x05\x04\
-    \x08\x02\0\x05\x12\x04\x9d\x02\x0b\x11\n\r\n\x05\x04\x08\x02\0\x01\x12\
-    \x04\x9d\x02\x12\x16\n\r\n\x05\x04\x08\x02\0\x03\x12\x04\x9d\x02\x19\x1a\
-    \n\x0c\n\x04\x04\x08\x02\x01\x12\x04\x9e\x02\x02,\n\r\n\x05\x04\x08\x02\
-    \x01\x04\x12\x04\x9e\x02\x02\n\n\r\n\x05\x04\x08\x02\x01\x06\x12\x04\x9e\
-    \x02\x0b\x20\n\r\n\x05\x04\x08\x02\x01\x01\x12\x04\x9e\x02!'\n\r\n\x05\
-    \x04\x08\x02\x01\x03\x12\x04\x9e\x02*+\n\x0c\n\x04\x04\x08\x02\x02\x12\
-    \x04\xa0\x02\x02&\n\r\n\x05\x04\x08\x02\x02\x04\x12\x04\xa0\x02\x02\n\n\
-    \r\n\x05\x04\x08\x02\x02\x06\x12\x04\xa0\x02\x0b\x19\n\r\n\x05\x04\x08\
-    \x02\x02\x01\x12\x04\xa0\x02\x1a!\n\r\n\x05\x04\x08\x02\x02\x03\x12\x04\
-    \xa0\x02$%\n0\n\x02\x04\t\x12\x06\xa4\x02\0\xb2\x02\x01\x1a\"\x20Describ\
+    \x04\x07\x01\x12\x04\xb5\x02\x08\x20\n\x0c\n\x04\x04\x07\x02\0\x12\x04\
+    \xb6\x02\x02\x1b\n\r\n\x05\x04\x07\x02\0\x04\x12\x04\xb6\x02\x02\n\n\r\n\
+    \x05\x04\x07\x02\0\x05\x12\x04\xb6\x02\x0b\x11\n\r\n\x05\x04\x07\x02\0\
+    \x01\x12\x04\xb6\x02\x12\x16\n\r\n\x05\x04\x07\x02\0\x03\x12\x04\xb6\x02\
+    \x19\x1a\n\x0c\n\x04\x04\x07\x02\x01\x12\x04\xb7\x02\x02\x1c\n\r\n\x05\
+    \x04\x07\x02\x01\x04\x12\x04\xb7\x02\x02\n\n\r\n\x05\x04\x07\x02\x01\x05\
+    \x12\x04\xb7\x02\x0b\x10\n\r\n\x05\x04\x07\x02\x01\x01\x12\x04\xb7\x02\
+    \x11\x17\n\r\n\x05\x04\x07\x02\x01\x03\x12\x04\xb7\x02\x1a\x1b\n\x0c\n\
+    \x04\x04\x07\x02\x02\x12\x04\xb9\x02\x02(\n\r\n\x05\x04\x07\x02\x02\x04\
+    \x12\x04\xb9\x02\x02\n\n\r\n\x05\x04\x07\x02\x02\x06\x12\x04\xb9\x02\x0b\
+    \x1b\n\r\n\x05\x04\x07\x02\x02\x01\x12\x04\xb9\x02\x1c#\n\r\n\x05\x04\
+    \x07\x02\x02\x03\x12\x04\xb9\x02&'\n$\n\x02\x04\x08\x12\x06\xbd\x02\0\
+    \xc2\x02\x01\x1a\x16\x20Describes\x20a\x20service.\n\n\x0b\n\x03\x04\x08\
+    \x01\x12\x04\xbd\x02\x08\x1e\n\x0c\n\x04\x04\x08\x02\0\x12\x04\xbe\x02\
+    \x02\x1b\n\r\n\x05\x04\x08\x02\0\x04\x12\x04\xbe\x02\x02\n\n\r\n\x05\x04\
+    \x08\x02\0\x05\x12\x04\xbe\x02\x0b\x11\n\r\n\x05\x04\x08\x02\0\x01\x12\
+    \x04\xbe\x02\x12\x16\n\r\n\x05\x04\x08\x02\0\x03\x12\x04\xbe\x02\x19\x1a\
+    \n\x0c\n\x04\x04\x08\x02\x01\x12\x04\xbf\x02\x02,\n\r\n\x05\x04\x08\x02\
+    \x01\x04\x12\x04\xbf\x02\x02\n\n\r\n\x05\x04\x08\x02\x01\x06\x12\x04\xbf\
+    \x02\x0b\x20\n\r\n\x05\x04\x08\x02\x01\x01\x12\x04\xbf\x02!'\n\r\n\x05\
+    \x04\x08\x02\x01\x03\x12\x04\xbf\x02*+\n\x0c\n\x04\x04\x08\x02\x02\x12\
+    \x04\xc1\x02\x02&\n\r\n\x05\x04\x08\x02\x02\x04\x12\x04\xc1\x02\x02\n\n\
+    \r\n\x05\x04\x08\x02\x02\x06\x12\x04\xc1\x02\x0b\x19\n\r\n\x05\x04\x08\
+    \x02\x02\x01\x12\x04\xc1\x02\x1a!\n\r\n\x05\x04\x08\x02\x02\x03\x12\x04\
+    \xc1\x02$%\n0\n\x02\x04\t\x12\x06\xc5\x02\0\xd3\x02\x01\x1a\"\x20Describ\
     es\x20a\x20method\x20of\x20a\x20service.\n\n\x0b\n\x03\x04\t\x01\x12\x04\
-    \xa4\x02\x08\x1d\n\x0c\n\x04\x04\t\x02\0\x12\x04\xa5\x02\x02\x1b\n\r\n\
-    \x05\x04\t\x02\0\x04\x12\x04\xa5\x02\x02\n\n\r\n\x05\x04\t\x02\0\x05\x12\
-    \x04\xa5\x02\x0b\x11\n\r\n\x05\x04\t\x02\0\x01\x12\x04\xa5\x02\x12\x16\n\
-    \r\n\x05\x04\t\x02\0\x03\x12\x04\xa5\x02\x19\x1a\n\x97\x01\n\x04\x04\t\
-    \x02\x01\x12\x04\xa9\x02\x02!\x1a\x88\x01\x20Input\x20and\x20output\x20t\
+    \xc5\x02\x08\x1d\n\x0c\n\x04\x04\t\x02\0\x12\x04\xc6\x02\x02\x1b\n\r\n\
+    \x05\x04\t\x02\0\x04\x12\x04\xc6\x02\x02\n\n\r\n\x05\x04\t\x02\0\x05\x12\
+    \x04\xc6\x02\x0b\x11\n\r\n\x05\x04\t\x02\0\x01\x12\x04\xc6\x02\x12\x16\n\
+    \r\n\x05\x04\t\x02\0\x03\x12\x04\xc6\x02\x19\x1a\n\x97\x01\n\x04\x04\t\
+    \x02\x01\x12\x04\xca\x02\x02!\x1a\x88\x01\x20Input\x20and\x20output\x20t\
     ype\x20names.\x20\x20These\x20are\x20resolved\x20in\x20the\x20same\x20wa\
     y\x20as\n\x20FieldDescriptorProto.type_name,\x20but\x20must\x20refer\x20\
-    to\x20a\x20message\x20type.\n\n\r\n\x05\x04\t\x02\x01\x04\x12\x04\xa9\
-    \x02\x02\n\n\r\n\x05\x04\t\x02\x01\x05\x12\x04\xa9\x02\x0b\x11\n\r\n\x05\
-    \x04\t\x02\x01\x01\x12\x04\xa9\x02\x12\x1c\n\r\n\x05\x04\t\x02\x01\x03\
-    \x12\x04\xa9\x02\x1f\x20\n\x0c\n\x04\x04\t\x02\x02\x12\x04\xaa\x02\x02\"\
-    \n\r\n\x05\x04\t\x02\x02\x04\x12\x04\xaa\x02\x02\n\n\r\n\x05\x04\t\x02\
-    \x02\x05\x12\x04\xaa\x02\x0b\x11\n\r\n\x05\x04\t\x02\x02\x01\x12\x04\xaa\
-    \x02\x12\x1d\n\r\n\x05\x04\t\x02\x02\x03\x12\x04\xaa\x02\x20!\n\x0c\n\
-    \x04\x04\t\x02\x03\x12\x04\xac\x02\x02%\n\r\n\x05\x04\t\x02\x03\x04\x12\
-    \x04\xac\x02\x02\n\n\r\n\x05\x04\t\x02\x03\x06\x12\x04\xac\x02\x0b\x18\n\
-    \r\n\x05\x04\t\x02\x03\x01\x12\x04\xac\x02\x19\x20\n\r\n\x05\x04\t\x02\
-    \x03\x03\x12\x04\xac\x02#$\nE\n\x04\x04\t\x02\x04\x12\x04\xaf\x02\x027\
+    to\x20a\x20message\x20type.\n\n\r\n\x05\x04\t\x02\x01\x04\x12\x04\xca\
+    \x02\x02\n\n\r\n\x05\x04\t\x02\x01\x05\x12\x04\xca\x02\x0b\x11\n\r\n\x05\
+    \x04\t\x02\x01\x01\x12\x04\xca\x02\x12\x1c\n\r\n\x05\x04\t\x02\x01\x03\
+    \x12\x04\xca\x02\x1f\x20\n\x0c\n\x04\x04\t\x02\x02\x12\x04\xcb\x02\x02\"\
+    \n\r\n\x05\x04\t\x02\x02\x04\x12\x04\xcb\x02\x02\n\n\r\n\x05\x04\t\x02\
+    \x02\x05\x12\x04\xcb\x02\x0b\x11\n\r\n\x05\x04\t\x02\x02\x01\x12\x04\xcb\
+    \x02\x12\x1d\n\r\n\x05\x04\t\x02\x02\x03\x12\x04\xcb\x02\x20!\n\x0c\n\
+    \x04\x04\t\x02\x03\x12\x04\xcd\x02\x02%\n\r\n\x05\x04\t\x02\x03\x04\x12\
+    \x04\xcd\x02\x02\n\n\r\n\x05\x04\t\x02\x03\x06\x12\x04\xcd\x02\x0b\x18\n\
+    \r\n\x05\x04\t\x02\x03\x01\x12\x04\xcd\x02\x19\x20\n\r\n\x05\x04\t\x02\
+    \x03\x03\x12\x04\xcd\x02#$\nE\n\x04\x04\t\x02\x04\x12\x04\xd0\x02\x027\
     \x1a7\x20Identifies\x20if\x20client\x20streams\x20multiple\x20client\x20\
-    messages\n\n\r\n\x05\x04\t\x02\x04\x04\x12\x04\xaf\x02\x02\n\n\r\n\x05\
-    \x04\t\x02\x04\x05\x12\x04\xaf\x02\x0b\x0f\n\r\n\x05\x04\t\x02\x04\x01\
-    \x12\x04\xaf\x02\x10\x20\n\r\n\x05\x04\t\x02\x04\x03\x12\x04\xaf\x02#$\n\
-    \r\n\x05\x04\t\x02\x04\x08\x12\x04\xaf\x02%6\n\r\n\x05\x04\t\x02\x04\x07\
-    \x12\x04\xaf\x0205\nE\n\x04\x04\t\x02\x05\x12\x04\xb1\x02\x027\x1a7\x20I\
+    messages\n\n\r\n\x05\x04\t\x02\x04\x04\x12\x04\xd0\x02\x02\n\n\r\n\x05\
+    \x04\t\x02\x04\x05\x12\x04\xd0\x02\x0b\x0f\n\r\n\x05\x04\t\x02\x04\x01\
+    \x12\x04\xd0\x02\x10\x20\n\r\n\x05\x04\t\x02\x04\x03\x12\x04\xd0\x02#$\n\
+    \r\n\x05\x04\t\x02\x04\x08\x12\x04\xd0\x02%6\n\r\n\x05\x04\t\x02\x04\x07\
+    \x12\x04\xd0\x0205\nE\n\x04\x04\t\x02\x05\x12\x04\xd2\x02\x027\x1a7\x20I\
     dentifies\x20if\x20server\x20streams\x20multiple\x20server\x20messages\n\
-    \n\r\n\x05\x04\t\x02\x05\x04\x12\x04\xb1\x02\x02\n\n\r\n\x05\x04\t\x02\
-    \x05\x05\x12\x04\xb1\x02\x0b\x0f\n\r\n\x05\x04\t\x02\x05\x01\x12\x04\xb1\
-    \x02\x10\x20\n\r\n\x05\x04\t\x02\x05\x03\x12\x04\xb1\x02#$\n\r\n\x05\x04\
-    \t\x02\x05\x08\x12\x04\xb1\x02%6\n\r\n\x05\x04\t\x02\x05\x07\x12\x04\xb1\
-    \x0205\n\xaf\x0e\n\x02\x04\n\x12\x06\xd5\x02\0\xd0\x03\x012N\x20========\
+    \n\r\n\x05\x04\t\x02\x05\x04\x12\x04\xd2\x02\x02\n\n\r\n\x05\x04\t\x02\
+    \x05\x05\x12\x04\xd2\x02\x0b\x0f\n\r\n\x05\x04\t\x02\x05\x01\x12\x04\xd2\
+    \x02\x10\x20\n\r\n\x05\x04\t\x02\x05\x03\x12\x04\xd2\x02#$\n\r\n\x05\x04\
+    \t\x02\x05\x08\x12\x04\xd2\x02%6\n\r\n\x05\x04\t\x02\x05\x07\x12\x04\xd2\
+    \x0205\n\xaf\x0e\n\x02\x04\n\x12\x06\xf6\x02\0\xf4\x03\x012N\x20========\
     ===========================================================\n\x20Options\
     \n2\xd0\r\x20Each\x20of\x20the\x20definitions\x20above\x20may\x20have\
     \x20\"options\"\x20attached.\x20\x20These\x20are\n\x20just\x20annotation\
@@ -9022,26 +11134,26 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     s\n\x20\x20\x20If\x20this\x20turns\x20out\x20to\x20be\x20popular,\x20a\
     \x20web\x20service\x20will\x20be\x20set\x20up\n\x20\x20\x20to\x20automat\
     ically\x20assign\x20option\x20numbers.\n\n\x0b\n\x03\x04\n\x01\x12\x04\
-    \xd5\x02\x08\x13\n\xf4\x01\n\x04\x04\n\x02\0\x12\x04\xdb\x02\x02#\x1a\
+    \xf6\x02\x08\x13\n\xf4\x01\n\x04\x04\n\x02\0\x12\x04\xfc\x02\x02#\x1a\
     \xe5\x01\x20Sets\x20the\x20Java\x20package\x20where\x20classes\x20genera\
     ted\x20from\x20this\x20.proto\x20will\x20be\n\x20placed.\x20\x20By\x20de\
     fault,\x20the\x20proto\x20package\x20is\x20used,\x20but\x20this\x20is\
     \x20often\n\x20inappropriate\x20because\x20proto\x20packages\x20do\x20no\
     t\x20normally\x20start\x20with\x20backwards\n\x20domain\x20names.\n\n\r\
-    \n\x05\x04\n\x02\0\x04\x12\x04\xdb\x02\x02\n\n\r\n\x05\x04\n\x02\0\x05\
-    \x12\x04\xdb\x02\x0b\x11\n\r\n\x05\x04\n\x02\0\x01\x12\x04\xdb\x02\x12\
-    \x1e\n\r\n\x05\x04\n\x02\0\x03\x12\x04\xdb\x02!\"\n\xbf\x02\n\x04\x04\n\
-    \x02\x01\x12\x04\xe3\x02\x02+\x1a\xb0\x02\x20If\x20set,\x20all\x20the\
+    \n\x05\x04\n\x02\0\x04\x12\x04\xfc\x02\x02\n\n\r\n\x05\x04\n\x02\0\x05\
+    \x12\x04\xfc\x02\x0b\x11\n\r\n\x05\x04\n\x02\0\x01\x12\x04\xfc\x02\x12\
+    \x1e\n\r\n\x05\x04\n\x02\0\x03\x12\x04\xfc\x02!\"\n\xbf\x02\n\x04\x04\n\
+    \x02\x01\x12\x04\x84\x03\x02+\x1a\xb0\x02\x20If\x20set,\x20all\x20the\
     \x20classes\x20from\x20the\x20.proto\x20file\x20are\x20wrapped\x20in\x20\
     a\x20single\n\x20outer\x20class\x20with\x20the\x20given\x20name.\x20\x20\
     This\x20applies\x20to\x20both\x20Proto1\n\x20(equivalent\x20to\x20the\
     \x20old\x20\"--one_java_file\"\x20option)\x20and\x20Proto2\x20(where\n\
     \x20a\x20.proto\x20always\x20translates\x20to\x20a\x20single\x20class,\
     \x20but\x20you\x20may\x20want\x20to\n\x20explicitly\x20choose\x20the\x20\
-    class\x20name).\n\n\r\n\x05\x04\n\x02\x01\x04\x12\x04\xe3\x02\x02\n\n\r\
-    \n\x05\x04\n\x02\x01\x05\x12\x04\xe3\x02\x0b\x11\n\r\n\x05\x04\n\x02\x01\
-    \x01\x12\x04\xe3\x02\x12&\n\r\n\x05\x04\n\x02\x01\x03\x12\x04\xe3\x02)*\
-    \n\xa3\x03\n\x04\x04\n\x02\x02\x12\x04\xeb\x02\x02;\x1a\x94\x03\x20If\
+    class\x20name).\n\n\r\n\x05\x04\n\x02\x01\x04\x12\x04\x84\x03\x02\n\n\r\
+    \n\x05\x04\n\x02\x01\x05\x12\x04\x84\x03\x0b\x11\n\r\n\x05\x04\n\x02\x01\
+    \x01\x12\x04\x84\x03\x12&\n\r\n\x05\x04\n\x02\x01\x03\x12\x04\x84\x03)*\
+    \n\xa3\x03\n\x04\x04\n\x02\x02\x12\x04\x8c\x03\x02;\x1a\x94\x03\x20If\
     \x20set\x20true,\x20then\x20the\x20Java\x20code\x20generator\x20will\x20\
     generate\x20a\x20separate\x20.java\n\x20file\x20for\x20each\x20top-level\
     \x20message,\x20enum,\x20and\x20service\x20defined\x20in\x20the\x20.prot\
@@ -9050,17 +11162,17 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     lassname.\x20\x20However,\x20the\x20outer\x20class\x20will\x20still\x20b\
     e\n\x20generated\x20to\x20contain\x20the\x20file's\x20getDescriptor()\
     \x20method\x20as\x20well\x20as\x20any\n\x20top-level\x20extensions\x20de\
-    fined\x20in\x20the\x20file.\n\n\r\n\x05\x04\n\x02\x02\x04\x12\x04\xeb\
-    \x02\x02\n\n\r\n\x05\x04\n\x02\x02\x05\x12\x04\xeb\x02\x0b\x0f\n\r\n\x05\
-    \x04\n\x02\x02\x01\x12\x04\xeb\x02\x10#\n\r\n\x05\x04\n\x02\x02\x03\x12\
-    \x04\xeb\x02&(\n\r\n\x05\x04\n\x02\x02\x08\x12\x04\xeb\x02):\n\r\n\x05\
-    \x04\n\x02\x02\x07\x12\x04\xeb\x0249\n)\n\x04\x04\n\x02\x03\x12\x04\xee\
-    \x02\x02E\x1a\x1b\x20This\x20option\x20does\x20nothing.\n\n\r\n\x05\x04\
-    \n\x02\x03\x04\x12\x04\xee\x02\x02\n\n\r\n\x05\x04\n\x02\x03\x05\x12\x04\
-    \xee\x02\x0b\x0f\n\r\n\x05\x04\n\x02\x03\x01\x12\x04\xee\x02\x10-\n\r\n\
-    \x05\x04\n\x02\x03\x03\x12\x04\xee\x0202\n\r\n\x05\x04\n\x02\x03\x08\x12\
-    \x04\xee\x023D\n\x0e\n\x06\x04\n\x02\x03\x08\x03\x12\x04\xee\x024C\n\xe6\
-    \x02\n\x04\x04\n\x02\x04\x12\x04\xf6\x02\x02>\x1a\xd7\x02\x20If\x20set\
+    fined\x20in\x20the\x20file.\n\n\r\n\x05\x04\n\x02\x02\x04\x12\x04\x8c\
+    \x03\x02\n\n\r\n\x05\x04\n\x02\x02\x05\x12\x04\x8c\x03\x0b\x0f\n\r\n\x05\
+    \x04\n\x02\x02\x01\x12\x04\x8c\x03\x10#\n\r\n\x05\x04\n\x02\x02\x03\x12\
+    \x04\x8c\x03&(\n\r\n\x05\x04\n\x02\x02\x08\x12\x04\x8c\x03):\n\r\n\x05\
+    \x04\n\x02\x02\x07\x12\x04\x8c\x0349\n)\n\x04\x04\n\x02\x03\x12\x04\x8f\
+    \x03\x02E\x1a\x1b\x20This\x20option\x20does\x20nothing.\n\n\r\n\x05\x04\
+    \n\x02\x03\x04\x12\x04\x8f\x03\x02\n\n\r\n\x05\x04\n\x02\x03\x05\x12\x04\
+    \x8f\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x03\x01\x12\x04\x8f\x03\x10-\n\r\n\
+    \x05\x04\n\x02\x03\x03\x12\x04\x8f\x0302\n\r\n\x05\x04\n\x02\x03\x08\x12\
+    \x04\x8f\x033D\n\x0e\n\x06\x04\n\x02\x03\x08\x03\x12\x04\x8f\x034C\n\xe6\
+    \x02\n\x04\x04\n\x02\x04\x12\x04\x97\x03\x02>\x1a\xd7\x02\x20If\x20set\
     \x20true,\x20then\x20the\x20Java2\x20code\x20generator\x20will\x20genera\
     te\x20code\x20that\n\x20throws\x20an\x20exception\x20whenever\x20an\x20a\
     ttempt\x20is\x20made\x20to\x20assign\x20a\x20non-UTF-8\n\x20byte\x20sequ\
@@ -9068,29 +11180,29 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20do\x20the\x20same.\n\x20However,\x20an\x20extension\x20field\x20stil\
     l\x20accepts\x20non-UTF-8\x20byte\x20sequences.\n\x20This\x20option\x20h\
     as\x20no\x20effect\x20on\x20when\x20used\x20with\x20the\x20lite\x20runti\
-    me.\n\n\r\n\x05\x04\n\x02\x04\x04\x12\x04\xf6\x02\x02\n\n\r\n\x05\x04\n\
-    \x02\x04\x05\x12\x04\xf6\x02\x0b\x0f\n\r\n\x05\x04\n\x02\x04\x01\x12\x04\
-    \xf6\x02\x10&\n\r\n\x05\x04\n\x02\x04\x03\x12\x04\xf6\x02)+\n\r\n\x05\
-    \x04\n\x02\x04\x08\x12\x04\xf6\x02,=\n\r\n\x05\x04\n\x02\x04\x07\x12\x04\
-    \xf6\x027<\nL\n\x04\x04\n\x04\0\x12\x06\xfa\x02\x02\xff\x02\x03\x1a<\x20\
+    me.\n\n\r\n\x05\x04\n\x02\x04\x04\x12\x04\x97\x03\x02\n\n\r\n\x05\x04\n\
+    \x02\x04\x05\x12\x04\x97\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x04\x01\x12\x04\
+    \x97\x03\x10&\n\r\n\x05\x04\n\x02\x04\x03\x12\x04\x97\x03)+\n\r\n\x05\
+    \x04\n\x02\x04\x08\x12\x04\x97\x03,=\n\r\n\x05\x04\n\x02\x04\x07\x12\x04\
+    \x97\x037<\nL\n\x04\x04\n\x04\0\x12\x06\x9b\x03\x02\xa0\x03\x03\x1a<\x20\
     Generated\x20classes\x20can\x20be\x20optimized\x20for\x20speed\x20or\x20\
-    code\x20size.\n\n\r\n\x05\x04\n\x04\0\x01\x12\x04\xfa\x02\x07\x13\nD\n\
-    \x06\x04\n\x04\0\x02\0\x12\x04\xfb\x02\x04\x0e\"4\x20Generate\x20complet\
+    code\x20size.\n\n\r\n\x05\x04\n\x04\0\x01\x12\x04\x9b\x03\x07\x13\nD\n\
+    \x06\x04\n\x04\0\x02\0\x12\x04\x9c\x03\x04\x0e\"4\x20Generate\x20complet\
     e\x20code\x20for\x20parsing,\x20serialization,\n\n\x0f\n\x07\x04\n\x04\0\
-    \x02\0\x01\x12\x04\xfb\x02\x04\t\n\x0f\n\x07\x04\n\x04\0\x02\0\x02\x12\
-    \x04\xfb\x02\x0c\r\nG\n\x06\x04\n\x04\0\x02\x01\x12\x04\xfd\x02\x04\x12\
+    \x02\0\x01\x12\x04\x9c\x03\x04\t\n\x0f\n\x07\x04\n\x04\0\x02\0\x02\x12\
+    \x04\x9c\x03\x0c\r\nG\n\x06\x04\n\x04\0\x02\x01\x12\x04\x9e\x03\x04\x12\
     \x1a\x06\x20etc.\n\"/\x20Use\x20ReflectionOps\x20to\x20implement\x20thes\
-    e\x20methods.\n\n\x0f\n\x07\x04\n\x04\0\x02\x01\x01\x12\x04\xfd\x02\x04\
-    \r\n\x0f\n\x07\x04\n\x04\0\x02\x01\x02\x12\x04\xfd\x02\x10\x11\nG\n\x06\
-    \x04\n\x04\0\x02\x02\x12\x04\xfe\x02\x04\x15\"7\x20Generate\x20code\x20u\
+    e\x20methods.\n\n\x0f\n\x07\x04\n\x04\0\x02\x01\x01\x12\x04\x9e\x03\x04\
+    \r\n\x0f\n\x07\x04\n\x04\0\x02\x01\x02\x12\x04\x9e\x03\x10\x11\nG\n\x06\
+    \x04\n\x04\0\x02\x02\x12\x04\x9f\x03\x04\x15\"7\x20Generate\x20code\x20u\
     sing\x20MessageLite\x20and\x20the\x20lite\x20runtime.\n\n\x0f\n\x07\x04\
-    \n\x04\0\x02\x02\x01\x12\x04\xfe\x02\x04\x10\n\x0f\n\x07\x04\n\x04\0\x02\
-    \x02\x02\x12\x04\xfe\x02\x13\x14\n\x0c\n\x04\x04\n\x02\x05\x12\x04\x80\
-    \x03\x02;\n\r\n\x05\x04\n\x02\x05\x04\x12\x04\x80\x03\x02\n\n\r\n\x05\
-    \x04\n\x02\x05\x06\x12\x04\x80\x03\x0b\x17\n\r\n\x05\x04\n\x02\x05\x01\
-    \x12\x04\x80\x03\x18$\n\r\n\x05\x04\n\x02\x05\x03\x12\x04\x80\x03'(\n\r\
-    \n\x05\x04\n\x02\x05\x08\x12\x04\x80\x03):\n\r\n\x05\x04\n\x02\x05\x07\
-    \x12\x04\x80\x0349\n\xe2\x02\n\x04\x04\n\x02\x06\x12\x04\x87\x03\x02\"\
+    \n\x04\0\x02\x02\x01\x12\x04\x9f\x03\x04\x10\n\x0f\n\x07\x04\n\x04\0\x02\
+    \x02\x02\x12\x04\x9f\x03\x13\x14\n\x0c\n\x04\x04\n\x02\x05\x12\x04\xa1\
+    \x03\x02;\n\r\n\x05\x04\n\x02\x05\x04\x12\x04\xa1\x03\x02\n\n\r\n\x05\
+    \x04\n\x02\x05\x06\x12\x04\xa1\x03\x0b\x17\n\r\n\x05\x04\n\x02\x05\x01\
+    \x12\x04\xa1\x03\x18$\n\r\n\x05\x04\n\x02\x05\x03\x12\x04\xa1\x03'(\n\r\
+    \n\x05\x04\n\x02\x05\x08\x12\x04\xa1\x03):\n\r\n\x05\x04\n\x02\x05\x07\
+    \x12\x04\xa1\x0349\n\xe2\x02\n\x04\x04\n\x02\x06\x12\x04\xa8\x03\x02\"\
     \x1a\xd3\x02\x20Sets\x20the\x20Go\x20package\x20where\x20structs\x20gene\
     rated\x20from\x20this\x20.proto\x20will\x20be\n\x20placed.\x20If\x20omit\
     ted,\x20the\x20Go\x20package\x20will\x20be\x20derived\x20from\x20the\x20\
@@ -9099,9 +11211,9 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20package\x20statement\x20in\x20the\x20.proto\x20file,\x20if\x20presen\
     t.\n\x20\x20\x20-\x20Otherwise,\x20the\x20basename\x20of\x20the\x20.prot\
     o\x20file,\x20without\x20extension.\n\n\r\n\x05\x04\n\x02\x06\x04\x12\
-    \x04\x87\x03\x02\n\n\r\n\x05\x04\n\x02\x06\x05\x12\x04\x87\x03\x0b\x11\n\
-    \r\n\x05\x04\n\x02\x06\x01\x12\x04\x87\x03\x12\x1c\n\r\n\x05\x04\n\x02\
-    \x06\x03\x12\x04\x87\x03\x1f!\n\xd4\x04\n\x04\x04\n\x02\x07\x12\x04\x96\
+    \x04\xa8\x03\x02\n\n\r\n\x05\x04\n\x02\x06\x05\x12\x04\xa8\x03\x0b\x11\n\
+    \r\n\x05\x04\n\x02\x06\x01\x12\x04\xa8\x03\x12\x1c\n\r\n\x05\x04\n\x02\
+    \x06\x03\x12\x04\xa8\x03\x1f!\n\xd4\x04\n\x04\x04\n\x02\x07\x12\x04\xb7\
     \x03\x02;\x1a\xc5\x04\x20Should\x20generic\x20services\x20be\x20generate\
     d\x20in\x20each\x20language?\x20\x20\"Generic\"\x20services\n\x20are\x20\
     not\x20specific\x20to\x20any\x20particular\x20RPC\x20system.\x20\x20They\
@@ -9114,402 +11226,414 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     ecific\x20to\x20your\x20particular\x20RPC\x20system.\x20\x20Therefore,\n\
     \x20these\x20default\x20to\x20false.\x20\x20Old\x20code\x20which\x20depe\
     nds\x20on\x20generic\x20services\x20should\n\x20explicitly\x20set\x20the\
-    m\x20to\x20true.\n\n\r\n\x05\x04\n\x02\x07\x04\x12\x04\x96\x03\x02\n\n\r\
-    \n\x05\x04\n\x02\x07\x05\x12\x04\x96\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x07\
-    \x01\x12\x04\x96\x03\x10#\n\r\n\x05\x04\n\x02\x07\x03\x12\x04\x96\x03&(\
-    \n\r\n\x05\x04\n\x02\x07\x08\x12\x04\x96\x03):\n\r\n\x05\x04\n\x02\x07\
-    \x07\x12\x04\x96\x0349\n\x0c\n\x04\x04\n\x02\x08\x12\x04\x97\x03\x02=\n\
-    \r\n\x05\x04\n\x02\x08\x04\x12\x04\x97\x03\x02\n\n\r\n\x05\x04\n\x02\x08\
-    \x05\x12\x04\x97\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x08\x01\x12\x04\x97\x03\
-    \x10%\n\r\n\x05\x04\n\x02\x08\x03\x12\x04\x97\x03(*\n\r\n\x05\x04\n\x02\
-    \x08\x08\x12\x04\x97\x03+<\n\r\n\x05\x04\n\x02\x08\x07\x12\x04\x97\x036;\
-    \n\x0c\n\x04\x04\n\x02\t\x12\x04\x98\x03\x02;\n\r\n\x05\x04\n\x02\t\x04\
-    \x12\x04\x98\x03\x02\n\n\r\n\x05\x04\n\x02\t\x05\x12\x04\x98\x03\x0b\x0f\
-    \n\r\n\x05\x04\n\x02\t\x01\x12\x04\x98\x03\x10#\n\r\n\x05\x04\n\x02\t\
-    \x03\x12\x04\x98\x03&(\n\r\n\x05\x04\n\x02\t\x08\x12\x04\x98\x03):\n\r\n\
-    \x05\x04\n\x02\t\x07\x12\x04\x98\x0349\n\x0c\n\x04\x04\n\x02\n\x12\x04\
-    \x99\x03\x02<\n\r\n\x05\x04\n\x02\n\x04\x12\x04\x99\x03\x02\n\n\r\n\x05\
-    \x04\n\x02\n\x05\x12\x04\x99\x03\x0b\x0f\n\r\n\x05\x04\n\x02\n\x01\x12\
-    \x04\x99\x03\x10$\n\r\n\x05\x04\n\x02\n\x03\x12\x04\x99\x03')\n\r\n\x05\
-    \x04\n\x02\n\x08\x12\x04\x99\x03*;\n\r\n\x05\x04\n\x02\n\x07\x12\x04\x99\
-    \x035:\n\xf3\x01\n\x04\x04\n\x02\x0b\x12\x04\x9f\x03\x022\x1a\xe4\x01\
+    m\x20to\x20true.\n\n\r\n\x05\x04\n\x02\x07\x04\x12\x04\xb7\x03\x02\n\n\r\
+    \n\x05\x04\n\x02\x07\x05\x12\x04\xb7\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x07\
+    \x01\x12\x04\xb7\x03\x10#\n\r\n\x05\x04\n\x02\x07\x03\x12\x04\xb7\x03&(\
+    \n\r\n\x05\x04\n\x02\x07\x08\x12\x04\xb7\x03):\n\r\n\x05\x04\n\x02\x07\
+    \x07\x12\x04\xb7\x0349\n\x0c\n\x04\x04\n\x02\x08\x12\x04\xb8\x03\x02=\n\
+    \r\n\x05\x04\n\x02\x08\x04\x12\x04\xb8\x03\x02\n\n\r\n\x05\x04\n\x02\x08\
+    \x05\x12\x04\xb8\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x08\x01\x12\x04\xb8\x03\
+    \x10%\n\r\n\x05\x04\n\x02\x08\x03\x12\x04\xb8\x03(*\n\r\n\x05\x04\n\x02\
+    \x08\x08\x12\x04\xb8\x03+<\n\r\n\x05\x04\n\x02\x08\x07\x12\x04\xb8\x036;\
+    \n\x0c\n\x04\x04\n\x02\t\x12\x04\xb9\x03\x02;\n\r\n\x05\x04\n\x02\t\x04\
+    \x12\x04\xb9\x03\x02\n\n\r\n\x05\x04\n\x02\t\x05\x12\x04\xb9\x03\x0b\x0f\
+    \n\r\n\x05\x04\n\x02\t\x01\x12\x04\xb9\x03\x10#\n\r\n\x05\x04\n\x02\t\
+    \x03\x12\x04\xb9\x03&(\n\r\n\x05\x04\n\x02\t\x08\x12\x04\xb9\x03):\n\r\n\
+    \x05\x04\n\x02\t\x07\x12\x04\xb9\x0349\n\x0c\n\x04\x04\n\x02\n\x12\x04\
+    \xba\x03\x02<\n\r\n\x05\x04\n\x02\n\x04\x12\x04\xba\x03\x02\n\n\r\n\x05\
+    \x04\n\x02\n\x05\x12\x04\xba\x03\x0b\x0f\n\r\n\x05\x04\n\x02\n\x01\x12\
+    \x04\xba\x03\x10$\n\r\n\x05\x04\n\x02\n\x03\x12\x04\xba\x03')\n\r\n\x05\
+    \x04\n\x02\n\x08\x12\x04\xba\x03*;\n\r\n\x05\x04\n\x02\n\x07\x12\x04\xba\
+    \x035:\n\xf3\x01\n\x04\x04\n\x02\x0b\x12\x04\xc0\x03\x022\x1a\xe4\x01\
     \x20Is\x20this\x20file\x20deprecated?\n\x20Depending\x20on\x20the\x20tar\
     get\x20platform,\x20this\x20can\x20emit\x20Deprecated\x20annotations\n\
     \x20for\x20everything\x20in\x20the\x20file,\x20or\x20it\x20will\x20be\
     \x20completely\x20ignored;\x20in\x20the\x20very\n\x20least,\x20this\x20i\
     s\x20a\x20formalization\x20for\x20deprecating\x20files.\n\n\r\n\x05\x04\
-    \n\x02\x0b\x04\x12\x04\x9f\x03\x02\n\n\r\n\x05\x04\n\x02\x0b\x05\x12\x04\
-    \x9f\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x0b\x01\x12\x04\x9f\x03\x10\x1a\n\r\
-    \n\x05\x04\n\x02\x0b\x03\x12\x04\x9f\x03\x1d\x1f\n\r\n\x05\x04\n\x02\x0b\
-    \x08\x12\x04\x9f\x03\x201\n\r\n\x05\x04\n\x02\x0b\x07\x12\x04\x9f\x03+0\
-    \n\x7f\n\x04\x04\n\x02\x0c\x12\x04\xa3\x03\x027\x1aq\x20Enables\x20the\
+    \n\x02\x0b\x04\x12\x04\xc0\x03\x02\n\n\r\n\x05\x04\n\x02\x0b\x05\x12\x04\
+    \xc0\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x0b\x01\x12\x04\xc0\x03\x10\x1a\n\r\
+    \n\x05\x04\n\x02\x0b\x03\x12\x04\xc0\x03\x1d\x1f\n\r\n\x05\x04\n\x02\x0b\
+    \x08\x12\x04\xc0\x03\x201\n\r\n\x05\x04\n\x02\x0b\x07\x12\x04\xc0\x03+0\
+    \n\x7f\n\x04\x04\n\x02\x0c\x12\x04\xc4\x03\x027\x1aq\x20Enables\x20the\
     \x20use\x20of\x20arenas\x20for\x20the\x20proto\x20messages\x20in\x20this\
     \x20file.\x20This\x20applies\n\x20only\x20to\x20generated\x20classes\x20\
-    for\x20C++.\n\n\r\n\x05\x04\n\x02\x0c\x04\x12\x04\xa3\x03\x02\n\n\r\n\
-    \x05\x04\n\x02\x0c\x05\x12\x04\xa3\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x0c\
-    \x01\x12\x04\xa3\x03\x10\x20\n\r\n\x05\x04\n\x02\x0c\x03\x12\x04\xa3\x03\
-    #%\n\r\n\x05\x04\n\x02\x0c\x08\x12\x04\xa3\x03&6\n\r\n\x05\x04\n\x02\x0c\
-    \x07\x12\x04\xa3\x0315\n\x92\x01\n\x04\x04\n\x02\r\x12\x04\xa8\x03\x02)\
+    for\x20C++.\n\n\r\n\x05\x04\n\x02\x0c\x04\x12\x04\xc4\x03\x02\n\n\r\n\
+    \x05\x04\n\x02\x0c\x05\x12\x04\xc4\x03\x0b\x0f\n\r\n\x05\x04\n\x02\x0c\
+    \x01\x12\x04\xc4\x03\x10\x20\n\r\n\x05\x04\n\x02\x0c\x03\x12\x04\xc4\x03\
+    #%\n\r\n\x05\x04\n\x02\x0c\x08\x12\x04\xc4\x03&6\n\r\n\x05\x04\n\x02\x0c\
+    \x07\x12\x04\xc4\x0315\n\x92\x01\n\x04\x04\n\x02\r\x12\x04\xc9\x03\x02)\
     \x1a\x83\x01\x20Sets\x20the\x20objective\x20c\x20class\x20prefix\x20whic\
     h\x20is\x20prepended\x20to\x20all\x20objective\x20c\n\x20generated\x20cl\
     asses\x20from\x20this\x20.proto.\x20There\x20is\x20no\x20default.\n\n\r\
-    \n\x05\x04\n\x02\r\x04\x12\x04\xa8\x03\x02\n\n\r\n\x05\x04\n\x02\r\x05\
-    \x12\x04\xa8\x03\x0b\x11\n\r\n\x05\x04\n\x02\r\x01\x12\x04\xa8\x03\x12#\
-    \n\r\n\x05\x04\n\x02\r\x03\x12\x04\xa8\x03&(\nI\n\x04\x04\n\x02\x0e\x12\
-    \x04\xab\x03\x02(\x1a;\x20Namespace\x20for\x20generated\x20classes;\x20d\
+    \n\x05\x04\n\x02\r\x04\x12\x04\xc9\x03\x02\n\n\r\n\x05\x04\n\x02\r\x05\
+    \x12\x04\xc9\x03\x0b\x11\n\r\n\x05\x04\n\x02\r\x01\x12\x04\xc9\x03\x12#\
+    \n\r\n\x05\x04\n\x02\r\x03\x12\x04\xc9\x03&(\nI\n\x04\x04\n\x02\x0e\x12\
+    \x04\xcc\x03\x02(\x1a;\x20Namespace\x20for\x20generated\x20classes;\x20d\
     efaults\x20to\x20the\x20package.\n\n\r\n\x05\x04\n\x02\x0e\x04\x12\x04\
-    \xab\x03\x02\n\n\r\n\x05\x04\n\x02\x0e\x05\x12\x04\xab\x03\x0b\x11\n\r\n\
-    \x05\x04\n\x02\x0e\x01\x12\x04\xab\x03\x12\"\n\r\n\x05\x04\n\x02\x0e\x03\
-    \x12\x04\xab\x03%'\n\x91\x02\n\x04\x04\n\x02\x0f\x12\x04\xb1\x03\x02$\
+    \xcc\x03\x02\n\n\r\n\x05\x04\n\x02\x0e\x05\x12\x04\xcc\x03\x0b\x11\n\r\n\
+    \x05\x04\n\x02\x0e\x01\x12\x04\xcc\x03\x12\"\n\r\n\x05\x04\n\x02\x0e\x03\
+    \x12\x04\xcc\x03%'\n\x91\x02\n\x04\x04\n\x02\x0f\x12\x04\xd2\x03\x02$\
     \x1a\x82\x02\x20By\x20default\x20Swift\x20generators\x20will\x20take\x20\
     the\x20proto\x20package\x20and\x20CamelCase\x20it\n\x20replacing\x20'.'\
     \x20with\x20underscore\x20and\x20use\x20that\x20to\x20prefix\x20the\x20t\
     ypes/symbols\n\x20defined.\x20When\x20this\x20options\x20is\x20provided,\
     \x20they\x20will\x20use\x20this\x20value\x20instead\n\x20to\x20prefix\
     \x20the\x20types/symbols\x20defined.\n\n\r\n\x05\x04\n\x02\x0f\x04\x12\
-    \x04\xb1\x03\x02\n\n\r\n\x05\x04\n\x02\x0f\x05\x12\x04\xb1\x03\x0b\x11\n\
-    \r\n\x05\x04\n\x02\x0f\x01\x12\x04\xb1\x03\x12\x1e\n\r\n\x05\x04\n\x02\
-    \x0f\x03\x12\x04\xb1\x03!#\n~\n\x04\x04\n\x02\x10\x12\x04\xb5\x03\x02(\
+    \x04\xd2\x03\x02\n\n\r\n\x05\x04\n\x02\x0f\x05\x12\x04\xd2\x03\x0b\x11\n\
+    \r\n\x05\x04\n\x02\x0f\x01\x12\x04\xd2\x03\x12\x1e\n\r\n\x05\x04\n\x02\
+    \x0f\x03\x12\x04\xd2\x03!#\n~\n\x04\x04\n\x02\x10\x12\x04\xd6\x03\x02(\
     \x1ap\x20Sets\x20the\x20php\x20class\x20prefix\x20which\x20is\x20prepend\
     ed\x20to\x20all\x20php\x20generated\x20classes\n\x20from\x20this\x20.pro\
-    to.\x20Default\x20is\x20empty.\n\n\r\n\x05\x04\n\x02\x10\x04\x12\x04\xb5\
-    \x03\x02\n\n\r\n\x05\x04\n\x02\x10\x05\x12\x04\xb5\x03\x0b\x11\n\r\n\x05\
-    \x04\n\x02\x10\x01\x12\x04\xb5\x03\x12\"\n\r\n\x05\x04\n\x02\x10\x03\x12\
-    \x04\xb5\x03%'\n\xbe\x01\n\x04\x04\n\x02\x11\x12\x04\xba\x03\x02%\x1a\
+    to.\x20Default\x20is\x20empty.\n\n\r\n\x05\x04\n\x02\x10\x04\x12\x04\xd6\
+    \x03\x02\n\n\r\n\x05\x04\n\x02\x10\x05\x12\x04\xd6\x03\x0b\x11\n\r\n\x05\
+    \x04\n\x02\x10\x01\x12\x04\xd6\x03\x12\"\n\r\n\x05\x04\n\x02\x10\x03\x12\
+    \x04\xd6\x03%'\n\xbe\x01\n\x04\x04\n\x02\x11\x12\x04\xdb\x03\x02%\x1a\
     \xaf\x01\x20Use\x20this\x20option\x20to\x20change\x20the\x20namespace\
     \x20of\x20php\x20generated\x20classes.\x20Default\n\x20is\x20empty.\x20W\
     hen\x20this\x20option\x20is\x20empty,\x20the\x20package\x20name\x20will\
     \x20be\x20used\x20for\n\x20determining\x20the\x20namespace.\n\n\r\n\x05\
-    \x04\n\x02\x11\x04\x12\x04\xba\x03\x02\n\n\r\n\x05\x04\n\x02\x11\x05\x12\
-    \x04\xba\x03\x0b\x11\n\r\n\x05\x04\n\x02\x11\x01\x12\x04\xba\x03\x12\x1f\
-    \n\r\n\x05\x04\n\x02\x11\x03\x12\x04\xba\x03\"$\n\xca\x01\n\x04\x04\n\
-    \x02\x12\x12\x04\xbf\x03\x02.\x1a\xbb\x01\x20Use\x20this\x20option\x20to\
+    \x04\n\x02\x11\x04\x12\x04\xdb\x03\x02\n\n\r\n\x05\x04\n\x02\x11\x05\x12\
+    \x04\xdb\x03\x0b\x11\n\r\n\x05\x04\n\x02\x11\x01\x12\x04\xdb\x03\x12\x1f\
+    \n\r\n\x05\x04\n\x02\x11\x03\x12\x04\xdb\x03\"$\n\xca\x01\n\x04\x04\n\
+    \x02\x12\x12\x04\xe0\x03\x02.\x1a\xbb\x01\x20Use\x20this\x20option\x20to\
     \x20change\x20the\x20namespace\x20of\x20php\x20generated\x20metadata\x20\
     classes.\n\x20Default\x20is\x20empty.\x20When\x20this\x20option\x20is\
     \x20empty,\x20the\x20proto\x20file\x20name\x20will\x20be\n\x20used\x20fo\
     r\x20determining\x20the\x20namespace.\n\n\r\n\x05\x04\n\x02\x12\x04\x12\
-    \x04\xbf\x03\x02\n\n\r\n\x05\x04\n\x02\x12\x05\x12\x04\xbf\x03\x0b\x11\n\
-    \r\n\x05\x04\n\x02\x12\x01\x12\x04\xbf\x03\x12(\n\r\n\x05\x04\n\x02\x12\
-    \x03\x12\x04\xbf\x03+-\n\xc2\x01\n\x04\x04\n\x02\x13\x12\x04\xc4\x03\x02\
+    \x04\xe0\x03\x02\n\n\r\n\x05\x04\n\x02\x12\x05\x12\x04\xe0\x03\x0b\x11\n\
+    \r\n\x05\x04\n\x02\x12\x01\x12\x04\xe0\x03\x12(\n\r\n\x05\x04\n\x02\x12\
+    \x03\x12\x04\xe0\x03+-\n\xc2\x01\n\x04\x04\n\x02\x13\x12\x04\xe5\x03\x02\
     $\x1a\xb3\x01\x20Use\x20this\x20option\x20to\x20change\x20the\x20package\
     \x20of\x20ruby\x20generated\x20classes.\x20Default\n\x20is\x20empty.\x20\
     When\x20this\x20option\x20is\x20not\x20set,\x20the\x20package\x20name\
     \x20will\x20be\x20used\x20for\n\x20determining\x20the\x20ruby\x20package\
-    .\n\n\r\n\x05\x04\n\x02\x13\x04\x12\x04\xc4\x03\x02\n\n\r\n\x05\x04\n\
-    \x02\x13\x05\x12\x04\xc4\x03\x0b\x11\n\r\n\x05\x04\n\x02\x13\x01\x12\x04\
-    \xc4\x03\x12\x1e\n\r\n\x05\x04\n\x02\x13\x03\x12\x04\xc4\x03!#\n|\n\x04\
-    \x04\n\x02\x14\x12\x04\xc9\x03\x02:\x1an\x20The\x20parser\x20stores\x20o\
-    ptions\x20it\x20doesn't\x20recognize\x20here.\n\x20See\x20the\x20documen\
-    tation\x20for\x20the\x20\"Options\"\x20section\x20above.\n\n\r\n\x05\x04\
-    \n\x02\x14\x04\x12\x04\xc9\x03\x02\n\n\r\n\x05\x04\n\x02\x14\x06\x12\x04\
-    \xc9\x03\x0b\x1e\n\r\n\x05\x04\n\x02\x14\x01\x12\x04\xc9\x03\x1f3\n\r\n\
-    \x05\x04\n\x02\x14\x03\x12\x04\xc9\x0369\n\x87\x01\n\x03\x04\n\x05\x12\
-    \x04\xcd\x03\x02\x19\x1az\x20Clients\x20can\x20define\x20custom\x20optio\
-    ns\x20in\x20extensions\x20of\x20this\x20message.\n\x20See\x20the\x20docu\
-    mentation\x20for\x20the\x20\"Options\"\x20section\x20above.\n\n\x0c\n\
-    \x04\x04\n\x05\0\x12\x04\xcd\x03\r\x18\n\r\n\x05\x04\n\x05\0\x01\x12\x04\
-    \xcd\x03\r\x11\n\r\n\x05\x04\n\x05\0\x02\x12\x04\xcd\x03\x15\x18\n\x0b\n\
-    \x03\x04\n\t\x12\x04\xcf\x03\x02\x0e\n\x0c\n\x04\x04\n\t\0\x12\x04\xcf\
-    \x03\x0b\r\n\r\n\x05\x04\n\t\0\x01\x12\x04\xcf\x03\x0b\r\n\r\n\x05\x04\n\
-    \t\0\x02\x12\x04\xcf\x03\x0b\r\n\x0c\n\x02\x04\x0b\x12\x06\xd2\x03\0\x92\
-    \x04\x01\n\x0b\n\x03\x04\x0b\x01\x12\x04\xd2\x03\x08\x16\n\xd8\x05\n\x04\
-    \x04\x0b\x02\0\x12\x04\xe5\x03\x02>\x1a\xc9\x05\x20Set\x20true\x20to\x20\
-    use\x20the\x20old\x20proto1\x20MessageSet\x20wire\x20format\x20for\x20ex\
-    tensions.\n\x20This\x20is\x20provided\x20for\x20backwards-compatibility\
-    \x20with\x20the\x20MessageSet\x20wire\n\x20format.\x20\x20You\x20should\
-    \x20not\x20use\x20this\x20for\x20any\x20other\x20reason:\x20\x20It's\x20\
-    less\n\x20efficient,\x20has\x20fewer\x20features,\x20and\x20is\x20more\
-    \x20complicated.\n\n\x20The\x20message\x20must\x20be\x20defined\x20exact\
-    ly\x20as\x20follows:\n\x20\x20\x20message\x20Foo\x20{\n\x20\x20\x20\x20\
-    \x20option\x20message_set_wire_format\x20=\x20true;\n\x20\x20\x20\x20\
-    \x20extensions\x204\x20to\x20max;\n\x20\x20\x20}\n\x20Note\x20that\x20th\
-    e\x20message\x20cannot\x20have\x20any\x20defined\x20fields;\x20MessageSe\
-    ts\x20only\n\x20have\x20extensions.\n\n\x20All\x20extensions\x20of\x20yo\
-    ur\x20type\x20must\x20be\x20singular\x20messages;\x20e.g.\x20they\x20can\
-    not\n\x20be\x20int32s,\x20enums,\x20or\x20repeated\x20messages.\n\n\x20B\
-    ecause\x20this\x20is\x20an\x20option,\x20the\x20above\x20two\x20restrict\
-    ions\x20are\x20not\x20enforced\x20by\n\x20the\x20protocol\x20compiler.\n\
-    \n\r\n\x05\x04\x0b\x02\0\x04\x12\x04\xe5\x03\x02\n\n\r\n\x05\x04\x0b\x02\
-    \0\x05\x12\x04\xe5\x03\x0b\x0f\n\r\n\x05\x04\x0b\x02\0\x01\x12\x04\xe5\
-    \x03\x10'\n\r\n\x05\x04\x0b\x02\0\x03\x12\x04\xe5\x03*+\n\r\n\x05\x04\
-    \x0b\x02\0\x08\x12\x04\xe5\x03,=\n\r\n\x05\x04\x0b\x02\0\x07\x12\x04\xe5\
-    \x037<\n\xeb\x01\n\x04\x04\x0b\x02\x01\x12\x04\xea\x03\x02F\x1a\xdc\x01\
-    \x20Disables\x20the\x20generation\x20of\x20the\x20standard\x20\"descript\
-    or()\"\x20accessor,\x20which\x20can\n\x20conflict\x20with\x20a\x20field\
-    \x20of\x20the\x20same\x20name.\x20\x20This\x20is\x20meant\x20to\x20make\
-    \x20migration\n\x20from\x20proto1\x20easier;\x20new\x20code\x20should\
-    \x20avoid\x20fields\x20named\x20\"descriptor\".\n\n\r\n\x05\x04\x0b\x02\
-    \x01\x04\x12\x04\xea\x03\x02\n\n\r\n\x05\x04\x0b\x02\x01\x05\x12\x04\xea\
-    \x03\x0b\x0f\n\r\n\x05\x04\x0b\x02\x01\x01\x12\x04\xea\x03\x10/\n\r\n\
-    \x05\x04\x0b\x02\x01\x03\x12\x04\xea\x0323\n\r\n\x05\x04\x0b\x02\x01\x08\
-    \x12\x04\xea\x034E\n\r\n\x05\x04\x0b\x02\x01\x07\x12\x04\xea\x03?D\n\xee\
-    \x01\n\x04\x04\x0b\x02\x02\x12\x04\xf0\x03\x021\x1a\xdf\x01\x20Is\x20thi\
-    s\x20message\x20deprecated?\n\x20Depending\x20on\x20the\x20target\x20pla\
-    tform,\x20this\x20can\x20emit\x20Deprecated\x20annotations\n\x20for\x20t\
-    he\x20message,\x20or\x20it\x20will\x20be\x20completely\x20ignored;\x20in\
-    \x20the\x20very\x20least,\n\x20this\x20is\x20a\x20formalization\x20for\
-    \x20deprecating\x20messages.\n\n\r\n\x05\x04\x0b\x02\x02\x04\x12\x04\xf0\
-    \x03\x02\n\n\r\n\x05\x04\x0b\x02\x02\x05\x12\x04\xf0\x03\x0b\x0f\n\r\n\
-    \x05\x04\x0b\x02\x02\x01\x12\x04\xf0\x03\x10\x1a\n\r\n\x05\x04\x0b\x02\
-    \x02\x03\x12\x04\xf0\x03\x1d\x1e\n\r\n\x05\x04\x0b\x02\x02\x08\x12\x04\
-    \xf0\x03\x1f0\n\r\n\x05\x04\x0b\x02\x02\x07\x12\x04\xf0\x03*/\n\xa0\x06\
-    \n\x04\x04\x0b\x02\x03\x12\x04\x87\x04\x02\x1e\x1a\x91\x06\x20Whether\
-    \x20the\x20message\x20is\x20an\x20automatically\x20generated\x20map\x20e\
-    ntry\x20type\x20for\x20the\n\x20maps\x20field.\n\n\x20For\x20maps\x20fie\
-    lds:\n\x20\x20\x20\x20\x20map<KeyType,\x20ValueType>\x20map_field\x20=\
-    \x201;\n\x20The\x20parsed\x20descriptor\x20looks\x20like:\n\x20\x20\x20\
-    \x20\x20message\x20MapFieldEntry\x20{\n\x20\x20\x20\x20\x20\x20\x20\x20\
-    \x20option\x20map_entry\x20=\x20true;\n\x20\x20\x20\x20\x20\x20\x20\x20\
-    \x20optional\x20KeyType\x20key\x20=\x201;\n\x20\x20\x20\x20\x20\x20\x20\
-    \x20\x20optional\x20ValueType\x20value\x20=\x202;\n\x20\x20\x20\x20\x20}\
-    \n\x20\x20\x20\x20\x20repeated\x20MapFieldEntry\x20map_field\x20=\x201;\
-    \n\n\x20Implementations\x20may\x20choose\x20not\x20to\x20generate\x20the\
-    \x20map_entry=true\x20message,\x20but\n\x20use\x20a\x20native\x20map\x20\
-    in\x20the\x20target\x20language\x20to\x20hold\x20the\x20keys\x20and\x20v\
-    alues.\n\x20The\x20reflection\x20APIs\x20in\x20such\x20implementations\
-    \x20still\x20need\x20to\x20work\x20as\n\x20if\x20the\x20field\x20is\x20a\
-    \x20repeated\x20message\x20field.\n\n\x20NOTE:\x20Do\x20not\x20set\x20th\
-    e\x20option\x20in\x20.proto\x20files.\x20Always\x20use\x20the\x20maps\
-    \x20syntax\n\x20instead.\x20The\x20option\x20should\x20only\x20be\x20imp\
-    licitly\x20set\x20by\x20the\x20proto\x20compiler\n\x20parser.\n\n\r\n\
-    \x05\x04\x0b\x02\x03\x04\x12\x04\x87\x04\x02\n\n\r\n\x05\x04\x0b\x02\x03\
-    \x05\x12\x04\x87\x04\x0b\x0f\n\r\n\x05\x04\x0b\x02\x03\x01\x12\x04\x87\
-    \x04\x10\x19\n\r\n\x05\x04\x0b\x02\x03\x03\x12\x04\x87\x04\x1c\x1d\n$\n\
-    \x03\x04\x0b\t\x12\x04\x89\x04\x02\r\"\x17\x20javalite_serializable\n\n\
-    \x0c\n\x04\x04\x0b\t\0\x12\x04\x89\x04\x0b\x0c\n\r\n\x05\x04\x0b\t\0\x01\
-    \x12\x04\x89\x04\x0b\x0c\n\r\n\x05\x04\x0b\t\0\x02\x12\x04\x89\x04\x0b\
-    \x0c\n\x1f\n\x03\x04\x0b\t\x12\x04\x8a\x04\x02\r\"\x12\x20javanano_as_li\
-    te\n\n\x0c\n\x04\x04\x0b\t\x01\x12\x04\x8a\x04\x0b\x0c\n\r\n\x05\x04\x0b\
-    \t\x01\x01\x12\x04\x8a\x04\x0b\x0c\n\r\n\x05\x04\x0b\t\x01\x02\x12\x04\
-    \x8a\x04\x0b\x0c\nO\n\x04\x04\x0b\x02\x04\x12\x04\x8e\x04\x02:\x1aA\x20T\
-    he\x20parser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20here.\
-    \x20See\x20above.\n\n\r\n\x05\x04\x0b\x02\x04\x04\x12\x04\x8e\x04\x02\n\
-    \n\r\n\x05\x04\x0b\x02\x04\x06\x12\x04\x8e\x04\x0b\x1e\n\r\n\x05\x04\x0b\
-    \x02\x04\x01\x12\x04\x8e\x04\x1f3\n\r\n\x05\x04\x0b\x02\x04\x03\x12\x04\
-    \x8e\x0469\nZ\n\x03\x04\x0b\x05\x12\x04\x91\x04\x02\x19\x1aM\x20Clients\
+    .\n\n\r\n\x05\x04\n\x02\x13\x04\x12\x04\xe5\x03\x02\n\n\r\n\x05\x04\n\
+    \x02\x13\x05\x12\x04\xe5\x03\x0b\x11\n\r\n\x05\x04\n\x02\x13\x01\x12\x04\
+    \xe5\x03\x12\x1e\n\r\n\x05\x04\n\x02\x13\x03\x12\x04\xe5\x03!#\n=\n\x04\
+    \x04\n\x02\x14\x12\x04\xe8\x03\x02$\x1a/\x20Any\x20features\x20defined\
+    \x20in\x20the\x20specific\x20edition.\n\n|\n\x04\x04\n\x02\x15\x12\x04\
+    \xed\x03\x02:\x1an\x20The\x20parser\x20stores\x20options\x20it\x20doesn'\
+    t\x20recognize\x20here.\n\x20See\x20the\x20documentation\x20for\x20the\
+    \x20\"Options\"\x20section\x20above.\n\n\r\n\x05\x04\n\x02\x15\x04\x12\
+    \x04\xed\x03\x02\n\n\r\n\x05\x04\n\x02\x15\x06\x12\x04\xed\x03\x0b\x1e\n\
+    \r\n\x05\x04\n\x02\x15\x01\x12\x04\xed\x03\x1f3\n\r\n\x05\x04\n\x02\x15\
+    \x03\x12\x04\xed\x0369\n\x87\x01\n\x03\x04\n\x05\x12\x04\xf1\x03\x02\x19\
+    \x1az\x20Clients\x20can\x20define\x20custom\x20options\x20in\x20extensio\
+    ns\x20of\x20this\x20message.\n\x20See\x20the\x20documentation\x20for\x20\
+    the\x20\"Options\"\x20section\x20above.\n\n\x0c\n\x04\x04\n\x05\0\x12\
+    \x04\xf1\x03\r\x18\n\r\n\x05\x04\n\x05\0\x01\x12\x04\xf1\x03\r\x11\n\r\n\
+    \x05\x04\n\x05\0\x02\x12\x04\xf1\x03\x15\x18\n\x0b\n\x03\x04\n\t\x12\x04\
+    \xf3\x03\x02\x0e\n\x0c\n\x04\x04\n\t\0\x12\x04\xf3\x03\x0b\r\n\r\n\x05\
+    \x04\n\t\0\x01\x12\x04\xf3\x03\x0b\r\n\r\n\x05\x04\n\t\0\x02\x12\x04\xf3\
+    \x03\x0b\r\n\x0c\n\x02\x04\x0b\x12\x06\xf6\x03\0\xb9\x04\x01\n\x0b\n\x03\
+    \x04\x0b\x01\x12\x04\xf6\x03\x08\x16\n\xd8\x05\n\x04\x04\x0b\x02\0\x12\
+    \x04\x89\x04\x02>\x1a\xc9\x05\x20Set\x20true\x20to\x20use\x20the\x20old\
+    \x20proto1\x20MessageSet\x20wire\x20format\x20for\x20extensions.\n\x20Th\
+    is\x20is\x20provided\x20for\x20backwards-compatibility\x20with\x20the\
+    \x20MessageSet\x20wire\n\x20format.\x20\x20You\x20should\x20not\x20use\
+    \x20this\x20for\x20any\x20other\x20reason:\x20\x20It's\x20less\n\x20effi\
+    cient,\x20has\x20fewer\x20features,\x20and\x20is\x20more\x20complicated.\
+    \n\n\x20The\x20message\x20must\x20be\x20defined\x20exactly\x20as\x20foll\
+    ows:\n\x20\x20\x20message\x20Foo\x20{\n\x20\x20\x20\x20\x20option\x20mes\
+    sage_set_wire_format\x20=\x20true;\n\x20\x20\x20\x20\x20extensions\x204\
+    \x20to\x20max;\n\x20\x20\x20}\n\x20Note\x20that\x20the\x20message\x20can\
+    not\x20have\x20any\x20defined\x20fields;\x20MessageSets\x20only\n\x20hav\
+    e\x20extensions.\n\n\x20All\x20extensions\x20of\x20your\x20type\x20must\
+    \x20be\x20singular\x20messages;\x20e.g.\x20they\x20cannot\n\x20be\x20int\
+    32s,\x20enums,\x20or\x20repeated\x20messages.\n\n\x20Because\x20this\x20\
+    is\x20an\x20option,\x20the\x20above\x20two\x20restrictions\x20are\x20not\
+    \x20enforced\x20by\n\x20the\x20protocol\x20compiler.\n\n\r\n\x05\x04\x0b\
+    \x02\0\x04\x12\x04\x89\x04\x02\n\n\r\n\x05\x04\x0b\x02\0\x05\x12\x04\x89\
+    \x04\x0b\x0f\n\r\n\x05\x04\x0b\x02\0\x01\x12\x04\x89\x04\x10'\n\r\n\x05\
+    \x04\x0b\x02\0\x03\x12\x04\x89\x04*+\n\r\n\x05\x04\x0b\x02\0\x08\x12\x04\
+    \x89\x04,=\n\r\n\x05\x04\x0b\x02\0\x07\x12\x04\x89\x047<\n\xeb\x01\n\x04\
+    \x04\x0b\x02\x01\x12\x04\x8e\x04\x02F\x1a\xdc\x01\x20Disables\x20the\x20\
+    generation\x20of\x20the\x20standard\x20\"descriptor()\"\x20accessor,\x20\
+    which\x20can\n\x20conflict\x20with\x20a\x20field\x20of\x20the\x20same\
+    \x20name.\x20\x20This\x20is\x20meant\x20to\x20make\x20migration\n\x20fro\
+    m\x20proto1\x20easier;\x20new\x20code\x20should\x20avoid\x20fields\x20na\
+    med\x20\"descriptor\".\n\n\r\n\x05\x04\x0b\x02\x01\x04\x12\x04\x8e\x04\
+    \x02\n\n\r\n\x05\x04\x0b\x02\x01\x05\x12\x04\x8e\x04\x0b\x0f\n\r\n\x05\
+    \x04\x0b\x02\x01\x01\x12\x04\x8e\x04\x10/\n\r\n\x05\x04\x0b\x02\x01\x03\
+    \x12\x04\x8e\x0423\n\r\n\x05\x04\x0b\x02\x01\x08\x12\x04\x8e\x044E\n\r\n\
+    \x05\x04\x0b\x02\x01\x07\x12\x04\x8e\x04?D\n\xee\x01\n\x04\x04\x0b\x02\
+    \x02\x12\x04\x94\x04\x021\x1a\xdf\x01\x20Is\x20this\x20message\x20deprec\
+    ated?\n\x20Depending\x20on\x20the\x20target\x20platform,\x20this\x20can\
+    \x20emit\x20Deprecated\x20annotations\n\x20for\x20the\x20message,\x20or\
+    \x20it\x20will\x20be\x20completely\x20ignored;\x20in\x20the\x20very\x20l\
+    east,\n\x20this\x20is\x20a\x20formalization\x20for\x20deprecating\x20mes\
+    sages.\n\n\r\n\x05\x04\x0b\x02\x02\x04\x12\x04\x94\x04\x02\n\n\r\n\x05\
+    \x04\x0b\x02\x02\x05\x12\x04\x94\x04\x0b\x0f\n\r\n\x05\x04\x0b\x02\x02\
+    \x01\x12\x04\x94\x04\x10\x1a\n\r\n\x05\x04\x0b\x02\x02\x03\x12\x04\x94\
+    \x04\x1d\x1e\n\r\n\x05\x04\x0b\x02\x02\x08\x12\x04\x94\x04\x1f0\n\r\n\
+    \x05\x04\x0b\x02\x02\x07\x12\x04\x94\x04*/\n\xa0\x06\n\x04\x04\x0b\x02\
+    \x03\x12\x04\xab\x04\x02\x1e\x1a\x91\x06\x20Whether\x20the\x20message\
+    \x20is\x20an\x20automatically\x20generated\x20map\x20entry\x20type\x20fo\
+    r\x20the\n\x20maps\x20field.\n\n\x20For\x20maps\x20fields:\n\x20\x20\x20\
+    \x20\x20map<KeyType,\x20ValueType>\x20map_field\x20=\x201;\n\x20The\x20p\
+    arsed\x20descriptor\x20looks\x20like:\n\x20\x20\x20\x20\x20message\x20Ma\
+    pFieldEntry\x20{\n\x20\x20\x20\x20\x20\x20\x20\x20\x20option\x20map_entr\
+    y\x20=\x20true;\n\x20\x20\x20\x20\x20\x20\x20\x20\x20optional\x20KeyType\
+    \x20key\x20=\x201;\n\x20\x20\x20\x20\x20\x20\x20\x20\x20optional\x20Valu\
+    eType\x20value\x20=\x202;\n\x20\x20\x20\x20\x20}\n\x20\x20\x20\x20\x20re\
+    peated\x20MapFieldEntry\x20map_field\x20=\x201;\n\n\x20Implementations\
+    \x20may\x20choose\x20not\x20to\x20generate\x20the\x20map_entry=true\x20m\
+    essage,\x20but\n\x20use\x20a\x20native\x20map\x20in\x20the\x20target\x20\
+    language\x20to\x20hold\x20the\x20keys\x20and\x20values.\n\x20The\x20refl\
+    ection\x20APIs\x20in\x20such\x20implementations\x20still\x20need\x20to\
+    \x20work\x20as\n\x20if\x20the\x20field\x20is\x20a\x20repeated\x20message\
+    \x20field.\n\n\x20NOTE:\x20Do\x20not\x20set\x20the\x20option\x20in\x20.p\
+    roto\x20files.\x20Always\x20use\x20the\x20maps\x20syntax\n\x20instead.\
+    \x20The\x20option\x20should\x20only\x20be\x20implicitly\x20set\x20by\x20\
+    the\x20proto\x20compiler\n\x20parser.\n\n\r\n\x05\x04\x0b\x02\x03\x04\
+    \x12\x04\xab\x04\x02\n\n\r\n\x05\x04\x0b\x02\x03\x05\x12\x04\xab\x04\x0b\
+    \x0f\n\r\n\x05\x04\x0b\x02\x03\x01\x12\x04\xab\x04\x10\x19\n\r\n\x05\x04\
+    \x0b\x02\x03\x03\x12\x04\xab\x04\x1c\x1d\n$\n\x03\x04\x0b\t\x12\x04\xad\
+    \x04\x02\r\"\x17\x20javalite_serializable\n\n\x0c\n\x04\x04\x0b\t\0\x12\
+    \x04\xad\x04\x0b\x0c\n\r\n\x05\x04\x0b\t\0\x01\x12\x04\xad\x04\x0b\x0c\n\
+    \r\n\x05\x04\x0b\t\0\x02\x12\x04\xad\x04\x0b\x0c\n\x1f\n\x03\x04\x0b\t\
+    \x12\x04\xae\x04\x02\r\"\x12\x20javanano_as_lite\n\n\x0c\n\x04\x04\x0b\t\
+    \x01\x12\x04\xae\x04\x0b\x0c\n\r\n\x05\x04\x0b\t\x01\x01\x12\x04\xae\x04\
+    \x0b\x0c\n\r\n\x05\x04\x0b\t\x01\x02\x12\x04\xae\x04\x0b\x0c\n=\n\x04\
+    \x04\x0b\x02\x04\x12\x04\xb1\x04\x02$\x1a/\x20Any\x20features\x20defined\
+    \x20in\x20the\x20specific\x20edition.\n\nO\n\x04\x04\x0b\x02\x05\x12\x04\
+    \xb5\x04\x02:\x1aA\x20The\x20parser\x20stores\x20options\x20it\x20doesn'\
+    t\x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x04\x0b\x02\x05\x04\
+    \x12\x04\xb5\x04\x02\n\n\r\n\x05\x04\x0b\x02\x05\x06\x12\x04\xb5\x04\x0b\
+    \x1e\n\r\n\x05\x04\x0b\x02\x05\x01\x12\x04\xb5\x04\x1f3\n\r\n\x05\x04\
+    \x0b\x02\x05\x03\x12\x04\xb5\x0469\nZ\n\x03\x04\x0b\x05\x12\x04\xb8\x04\
+    \x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20options\x20in\x20\
+    extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\
+    \x0b\x05\0\x12\x04\xb8\x04\r\x18\n\r\n\x05\x04\x0b\x05\0\x01\x12\x04\xb8\
+    \x04\r\x11\n\r\n\x05\x04\x0b\x05\0\x02\x12\x04\xb8\x04\x15\x18\n\x0c\n\
+    \x02\x04\x0c\x12\x06\xbb\x04\0\x99\x05\x01\n\x0b\n\x03\x04\x0c\x01\x12\
+    \x04\xbb\x04\x08\x14\n\xa3\x02\n\x04\x04\x0c\x02\0\x12\x04\xc0\x04\x02.\
+    \x1a\x94\x02\x20The\x20ctype\x20option\x20instructs\x20the\x20C++\x20cod\
+    e\x20generator\x20to\x20use\x20a\x20different\n\x20representation\x20of\
+    \x20the\x20field\x20than\x20it\x20normally\x20would.\x20\x20See\x20the\
+    \x20specific\n\x20options\x20below.\x20\x20This\x20option\x20is\x20not\
+    \x20yet\x20implemented\x20in\x20the\x20open\x20source\n\x20release\x20--\
+    \x20sorry,\x20we'll\x20try\x20to\x20include\x20it\x20in\x20a\x20future\
+    \x20version!\n\n\r\n\x05\x04\x0c\x02\0\x04\x12\x04\xc0\x04\x02\n\n\r\n\
+    \x05\x04\x0c\x02\0\x06\x12\x04\xc0\x04\x0b\x10\n\r\n\x05\x04\x0c\x02\0\
+    \x01\x12\x04\xc0\x04\x11\x16\n\r\n\x05\x04\x0c\x02\0\x03\x12\x04\xc0\x04\
+    \x19\x1a\n\r\n\x05\x04\x0c\x02\0\x08\x12\x04\xc0\x04\x1b-\n\r\n\x05\x04\
+    \x0c\x02\0\x07\x12\x04\xc0\x04&,\n\x0e\n\x04\x04\x0c\x04\0\x12\x06\xc1\
+    \x04\x02\xc8\x04\x03\n\r\n\x05\x04\x0c\x04\0\x01\x12\x04\xc1\x04\x07\x0c\
+    \n\x1f\n\x06\x04\x0c\x04\0\x02\0\x12\x04\xc3\x04\x04\x0f\x1a\x0f\x20Defa\
+    ult\x20mode.\n\n\x0f\n\x07\x04\x0c\x04\0\x02\0\x01\x12\x04\xc3\x04\x04\n\
+    \n\x0f\n\x07\x04\x0c\x04\0\x02\0\x02\x12\x04\xc3\x04\r\x0e\n\x0e\n\x06\
+    \x04\x0c\x04\0\x02\x01\x12\x04\xc5\x04\x04\r\n\x0f\n\x07\x04\x0c\x04\0\
+    \x02\x01\x01\x12\x04\xc5\x04\x04\x08\n\x0f\n\x07\x04\x0c\x04\0\x02\x01\
+    \x02\x12\x04\xc5\x04\x0b\x0c\n\x0e\n\x06\x04\x0c\x04\0\x02\x02\x12\x04\
+    \xc7\x04\x04\x15\n\x0f\n\x07\x04\x0c\x04\0\x02\x02\x01\x12\x04\xc7\x04\
+    \x04\x10\n\x0f\n\x07\x04\x0c\x04\0\x02\x02\x02\x12\x04\xc7\x04\x13\x14\n\
+    \xda\x02\n\x04\x04\x0c\x02\x01\x12\x04\xce\x04\x02\x1b\x1a\xcb\x02\x20Th\
+    e\x20packed\x20option\x20can\x20be\x20enabled\x20for\x20repeated\x20prim\
+    itive\x20fields\x20to\x20enable\n\x20a\x20more\x20efficient\x20represent\
+    ation\x20on\x20the\x20wire.\x20Rather\x20than\x20repeatedly\n\x20writing\
+    \x20the\x20tag\x20and\x20type\x20for\x20each\x20element,\x20the\x20entir\
+    e\x20array\x20is\x20encoded\x20as\n\x20a\x20single\x20length-delimited\
+    \x20blob.\x20In\x20proto3,\x20only\x20explicit\x20setting\x20it\x20to\n\
+    \x20false\x20will\x20avoid\x20using\x20packed\x20encoding.\n\n\r\n\x05\
+    \x04\x0c\x02\x01\x04\x12\x04\xce\x04\x02\n\n\r\n\x05\x04\x0c\x02\x01\x05\
+    \x12\x04\xce\x04\x0b\x0f\n\r\n\x05\x04\x0c\x02\x01\x01\x12\x04\xce\x04\
+    \x10\x16\n\r\n\x05\x04\x0c\x02\x01\x03\x12\x04\xce\x04\x19\x1a\n\x9a\x05\
+    \n\x04\x04\x0c\x02\x02\x12\x04\xdb\x04\x023\x1a\x8b\x05\x20The\x20jstype\
+    \x20option\x20determines\x20the\x20JavaScript\x20type\x20used\x20for\x20\
+    values\x20of\x20the\n\x20field.\x20\x20The\x20option\x20is\x20permitted\
+    \x20only\x20for\x2064\x20bit\x20integral\x20and\x20fixed\x20types\n\x20(\
+    int64,\x20uint64,\x20sint64,\x20fixed64,\x20sfixed64).\x20\x20A\x20field\
+    \x20with\x20jstype\x20JS_STRING\n\x20is\x20represented\x20as\x20JavaScri\
+    pt\x20string,\x20which\x20avoids\x20loss\x20of\x20precision\x20that\n\
+    \x20can\x20happen\x20when\x20a\x20large\x20value\x20is\x20converted\x20t\
+    o\x20a\x20floating\x20point\x20JavaScript.\n\x20Specifying\x20JS_NUMBER\
+    \x20for\x20the\x20jstype\x20causes\x20the\x20generated\x20JavaScript\x20\
+    code\x20to\n\x20use\x20the\x20JavaScript\x20\"number\"\x20type.\x20\x20T\
+    he\x20behavior\x20of\x20the\x20default\x20option\n\x20JS_NORMAL\x20is\
+    \x20implementation\x20dependent.\n\n\x20This\x20option\x20is\x20an\x20en\
+    um\x20to\x20permit\x20additional\x20types\x20to\x20be\x20added,\x20e.g.\
+    \n\x20goog.math.Integer.\n\n\r\n\x05\x04\x0c\x02\x02\x04\x12\x04\xdb\x04\
+    \x02\n\n\r\n\x05\x04\x0c\x02\x02\x06\x12\x04\xdb\x04\x0b\x11\n\r\n\x05\
+    \x04\x0c\x02\x02\x01\x12\x04\xdb\x04\x12\x18\n\r\n\x05\x04\x0c\x02\x02\
+    \x03\x12\x04\xdb\x04\x1b\x1c\n\r\n\x05\x04\x0c\x02\x02\x08\x12\x04\xdb\
+    \x04\x1d2\n\r\n\x05\x04\x0c\x02\x02\x07\x12\x04\xdb\x04(1\n\x0e\n\x04\
+    \x04\x0c\x04\x01\x12\x06\xdc\x04\x02\xe5\x04\x03\n\r\n\x05\x04\x0c\x04\
+    \x01\x01\x12\x04\xdc\x04\x07\r\n'\n\x06\x04\x0c\x04\x01\x02\0\x12\x04\
+    \xde\x04\x04\x12\x1a\x17\x20Use\x20the\x20default\x20type.\n\n\x0f\n\x07\
+    \x04\x0c\x04\x01\x02\0\x01\x12\x04\xde\x04\x04\r\n\x0f\n\x07\x04\x0c\x04\
+    \x01\x02\0\x02\x12\x04\xde\x04\x10\x11\n)\n\x06\x04\x0c\x04\x01\x02\x01\
+    \x12\x04\xe1\x04\x04\x12\x1a\x19\x20Use\x20JavaScript\x20strings.\n\n\
+    \x0f\n\x07\x04\x0c\x04\x01\x02\x01\x01\x12\x04\xe1\x04\x04\r\n\x0f\n\x07\
+    \x04\x0c\x04\x01\x02\x01\x02\x12\x04\xe1\x04\x10\x11\n)\n\x06\x04\x0c\
+    \x04\x01\x02\x02\x12\x04\xe4\x04\x04\x12\x1a\x19\x20Use\x20JavaScript\
+    \x20numbers.\n\n\x0f\n\x07\x04\x0c\x04\x01\x02\x02\x01\x12\x04\xe4\x04\
+    \x04\r\n\x0f\n\x07\x04\x0c\x04\x01\x02\x02\x02\x12\x04\xe4\x04\x10\x11\n\
+    \xef\x0c\n\x04\x04\x0c\x02\x03\x12\x04\x83\x05\x02+\x1a\xe0\x0c\x20Shoul\
+    d\x20this\x20field\x20be\x20parsed\x20lazily?\x20\x20Lazy\x20applies\x20\
+    only\x20to\x20message-type\n\x20fields.\x20\x20It\x20means\x20that\x20wh\
+    en\x20the\x20outer\x20message\x20is\x20initially\x20parsed,\x20the\n\x20\
+    inner\x20message's\x20contents\x20will\x20not\x20be\x20parsed\x20but\x20\
+    instead\x20stored\x20in\x20encoded\n\x20form.\x20\x20The\x20inner\x20mes\
+    sage\x20will\x20actually\x20be\x20parsed\x20when\x20it\x20is\x20first\
+    \x20accessed.\n\n\x20This\x20is\x20only\x20a\x20hint.\x20\x20Implementat\
+    ions\x20are\x20free\x20to\x20choose\x20whether\x20to\x20use\n\x20eager\
+    \x20or\x20lazy\x20parsing\x20regardless\x20of\x20the\x20value\x20of\x20t\
+    his\x20option.\x20\x20However,\n\x20setting\x20this\x20option\x20true\
+    \x20suggests\x20that\x20the\x20protocol\x20author\x20believes\x20that\n\
+    \x20using\x20lazy\x20parsing\x20on\x20this\x20field\x20is\x20worth\x20th\
+    e\x20additional\x20bookkeeping\n\x20overhead\x20typically\x20needed\x20t\
+    o\x20implement\x20it.\n\n\x20This\x20option\x20does\x20not\x20affect\x20\
+    the\x20public\x20interface\x20of\x20any\x20generated\x20code;\n\x20all\
+    \x20method\x20signatures\x20remain\x20the\x20same.\x20\x20Furthermore,\
+    \x20thread-safety\x20of\x20the\n\x20interface\x20is\x20not\x20affected\
+    \x20by\x20this\x20option;\x20const\x20methods\x20remain\x20safe\x20to\n\
+    \x20call\x20from\x20multiple\x20threads\x20concurrently,\x20while\x20non\
+    -const\x20methods\x20continue\n\x20to\x20require\x20exclusive\x20access.\
+    \n\n\n\x20Note\x20that\x20implementations\x20may\x20choose\x20not\x20to\
+    \x20check\x20required\x20fields\x20within\n\x20a\x20lazy\x20sub-message.\
+    \x20\x20That\x20is,\x20calling\x20IsInitialized()\x20on\x20the\x20outer\
+    \x20message\n\x20may\x20return\x20true\x20even\x20if\x20the\x20inner\x20\
+    message\x20has\x20missing\x20required\x20fields.\n\x20This\x20is\x20nece\
+    ssary\x20because\x20otherwise\x20the\x20inner\x20message\x20would\x20hav\
+    e\x20to\x20be\n\x20parsed\x20in\x20order\x20to\x20perform\x20the\x20chec\
+    k,\x20defeating\x20the\x20purpose\x20of\x20lazy\n\x20parsing.\x20\x20An\
+    \x20implementation\x20which\x20chooses\x20not\x20to\x20check\x20required\
+    \x20fields\n\x20must\x20be\x20consistent\x20about\x20it.\x20\x20That\x20\
+    is,\x20for\x20any\x20particular\x20sub-message,\x20the\n\x20implementati\
+    on\x20must\x20either\x20*always*\x20check\x20its\x20required\x20fields,\
+    \x20or\x20*never*\n\x20check\x20its\x20required\x20fields,\x20regardless\
+    \x20of\x20whether\x20or\x20not\x20the\x20message\x20has\n\x20been\x20par\
+    sed.\n\n\r\n\x05\x04\x0c\x02\x03\x04\x12\x04\x83\x05\x02\n\n\r\n\x05\x04\
+    \x0c\x02\x03\x05\x12\x04\x83\x05\x0b\x0f\n\r\n\x05\x04\x0c\x02\x03\x01\
+    \x12\x04\x83\x05\x10\x14\n\r\n\x05\x04\x0c\x02\x03\x03\x12\x04\x83\x05\
+    \x17\x18\n\r\n\x05\x04\x0c\x02\x03\x08\x12\x04\x83\x05\x19*\n\r\n\x05\
+    \x04\x0c\x02\x03\x07\x12\x04\x83\x05$)\n\xe8\x01\n\x04\x04\x0c\x02\x04\
+    \x12\x04\x89\x05\x021\x1a\xd9\x01\x20Is\x20this\x20field\x20deprecated?\
+    \n\x20Depending\x20on\x20the\x20target\x20platform,\x20this\x20can\x20em\
+    it\x20Deprecated\x20annotations\n\x20for\x20accessors,\x20or\x20it\x20wi\
+    ll\x20be\x20completely\x20ignored;\x20in\x20the\x20very\x20least,\x20thi\
+    s\n\x20is\x20a\x20formalization\x20for\x20deprecating\x20fields.\n\n\r\n\
+    \x05\x04\x0c\x02\x04\x04\x12\x04\x89\x05\x02\n\n\r\n\x05\x04\x0c\x02\x04\
+    \x05\x12\x04\x89\x05\x0b\x0f\n\r\n\x05\x04\x0c\x02\x04\x01\x12\x04\x89\
+    \x05\x10\x1a\n\r\n\x05\x04\x0c\x02\x04\x03\x12\x04\x89\x05\x1d\x1e\n\r\n\
+    \x05\x04\x0c\x02\x04\x08\x12\x04\x89\x05\x1f0\n\r\n\x05\x04\x0c\x02\x04\
+    \x07\x12\x04\x89\x05*/\n?\n\x04\x04\x0c\x02\x05\x12\x04\x8c\x05\x02,\x1a\
+    1\x20For\x20Google-internal\x20migration\x20only.\x20Do\x20not\x20use.\n\
+    \n\r\n\x05\x04\x0c\x02\x05\x04\x12\x04\x8c\x05\x02\n\n\r\n\x05\x04\x0c\
+    \x02\x05\x05\x12\x04\x8c\x05\x0b\x0f\n\r\n\x05\x04\x0c\x02\x05\x01\x12\
+    \x04\x8c\x05\x10\x14\n\r\n\x05\x04\x0c\x02\x05\x03\x12\x04\x8c\x05\x17\
+    \x19\n\r\n\x05\x04\x0c\x02\x05\x08\x12\x04\x8c\x05\x1a+\n\r\n\x05\x04\
+    \x0c\x02\x05\x07\x12\x04\x8c\x05%*\n=\n\x04\x04\x0c\x02\x06\x12\x04\x8f\
+    \x05\x02$\x1a/\x20Any\x20features\x20defined\x20in\x20the\x20specific\
+    \x20edition.\n\nO\n\x04\x04\x0c\x02\x07\x12\x04\x93\x05\x02:\x1aA\x20The\
+    \x20parser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20here.\
+    \x20See\x20above.\n\n\r\n\x05\x04\x0c\x02\x07\x04\x12\x04\x93\x05\x02\n\
+    \n\r\n\x05\x04\x0c\x02\x07\x06\x12\x04\x93\x05\x0b\x1e\n\r\n\x05\x04\x0c\
+    \x02\x07\x01\x12\x04\x93\x05\x1f3\n\r\n\x05\x04\x0c\x02\x07\x03\x12\x04\
+    \x93\x0569\nZ\n\x03\x04\x0c\x05\x12\x04\x96\x05\x02\x19\x1aM\x20Clients\
     \x20can\x20define\x20custom\x20options\x20in\x20extensions\x20of\x20this\
-    \x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\x0b\x05\0\x12\x04\x91\
-    \x04\r\x18\n\r\n\x05\x04\x0b\x05\0\x01\x12\x04\x91\x04\r\x11\n\r\n\x05\
-    \x04\x0b\x05\0\x02\x12\x04\x91\x04\x15\x18\n\x0c\n\x02\x04\x0c\x12\x06\
-    \x94\x04\0\xef\x04\x01\n\x0b\n\x03\x04\x0c\x01\x12\x04\x94\x04\x08\x14\n\
-    \xa3\x02\n\x04\x04\x0c\x02\0\x12\x04\x99\x04\x02.\x1a\x94\x02\x20The\x20\
-    ctype\x20option\x20instructs\x20the\x20C++\x20code\x20generator\x20to\
-    \x20use\x20a\x20different\n\x20representation\x20of\x20the\x20field\x20t\
-    han\x20it\x20normally\x20would.\x20\x20See\x20the\x20specific\n\x20optio\
-    ns\x20below.\x20\x20This\x20option\x20is\x20not\x20yet\x20implemented\
-    \x20in\x20the\x20open\x20source\n\x20release\x20--\x20sorry,\x20we'll\
-    \x20try\x20to\x20include\x20it\x20in\x20a\x20future\x20version!\n\n\r\n\
-    \x05\x04\x0c\x02\0\x04\x12\x04\x99\x04\x02\n\n\r\n\x05\x04\x0c\x02\0\x06\
-    \x12\x04\x99\x04\x0b\x10\n\r\n\x05\x04\x0c\x02\0\x01\x12\x04\x99\x04\x11\
-    \x16\n\r\n\x05\x04\x0c\x02\0\x03\x12\x04\x99\x04\x19\x1a\n\r\n\x05\x04\
-    \x0c\x02\0\x08\x12\x04\x99\x04\x1b-\n\r\n\x05\x04\x0c\x02\0\x07\x12\x04\
-    \x99\x04&,\n\x0e\n\x04\x04\x0c\x04\0\x12\x06\x9a\x04\x02\xa1\x04\x03\n\r\
-    \n\x05\x04\x0c\x04\0\x01\x12\x04\x9a\x04\x07\x0c\n\x1f\n\x06\x04\x0c\x04\
-    \0\x02\0\x12\x04\x9c\x04\x04\x0f\x1a\x0f\x20Default\x20mode.\n\n\x0f\n\
-    \x07\x04\x0c\x04\0\x02\0\x01\x12\x04\x9c\x04\x04\n\n\x0f\n\x07\x04\x0c\
-    \x04\0\x02\0\x02\x12\x04\x9c\x04\r\x0e\n\x0e\n\x06\x04\x0c\x04\0\x02\x01\
-    \x12\x04\x9e\x04\x04\r\n\x0f\n\x07\x04\x0c\x04\0\x02\x01\x01\x12\x04\x9e\
-    \x04\x04\x08\n\x0f\n\x07\x04\x0c\x04\0\x02\x01\x02\x12\x04\x9e\x04\x0b\
-    \x0c\n\x0e\n\x06\x04\x0c\x04\0\x02\x02\x12\x04\xa0\x04\x04\x15\n\x0f\n\
-    \x07\x04\x0c\x04\0\x02\x02\x01\x12\x04\xa0\x04\x04\x10\n\x0f\n\x07\x04\
-    \x0c\x04\0\x02\x02\x02\x12\x04\xa0\x04\x13\x14\n\xda\x02\n\x04\x04\x0c\
-    \x02\x01\x12\x04\xa7\x04\x02\x1b\x1a\xcb\x02\x20The\x20packed\x20option\
-    \x20can\x20be\x20enabled\x20for\x20repeated\x20primitive\x20fields\x20to\
-    \x20enable\n\x20a\x20more\x20efficient\x20representation\x20on\x20the\
-    \x20wire.\x20Rather\x20than\x20repeatedly\n\x20writing\x20the\x20tag\x20\
-    and\x20type\x20for\x20each\x20element,\x20the\x20entire\x20array\x20is\
-    \x20encoded\x20as\n\x20a\x20single\x20length-delimited\x20blob.\x20In\
-    \x20proto3,\x20only\x20explicit\x20setting\x20it\x20to\n\x20false\x20wil\
-    l\x20avoid\x20using\x20packed\x20encoding.\n\n\r\n\x05\x04\x0c\x02\x01\
-    \x04\x12\x04\xa7\x04\x02\n\n\r\n\x05\x04\x0c\x02\x01\x05\x12\x04\xa7\x04\
-    \x0b\x0f\n\r\n\x05\x04\x0c\x02\x01\x01\x12\x04\xa7\x04\x10\x16\n\r\n\x05\
-    \x04\x0c\x02\x01\x03\x12\x04\xa7\x04\x19\x1a\n\x9a\x05\n\x04\x04\x0c\x02\
-    \x02\x12\x04\xb4\x04\x023\x1a\x8b\x05\x20The\x20jstype\x20option\x20dete\
-    rmines\x20the\x20JavaScript\x20type\x20used\x20for\x20values\x20of\x20th\
-    e\n\x20field.\x20\x20The\x20option\x20is\x20permitted\x20only\x20for\x20\
-    64\x20bit\x20integral\x20and\x20fixed\x20types\n\x20(int64,\x20uint64,\
-    \x20sint64,\x20fixed64,\x20sfixed64).\x20\x20A\x20field\x20with\x20jstyp\
-    e\x20JS_STRING\n\x20is\x20represented\x20as\x20JavaScript\x20string,\x20\
-    which\x20avoids\x20loss\x20of\x20precision\x20that\n\x20can\x20happen\
-    \x20when\x20a\x20large\x20value\x20is\x20converted\x20to\x20a\x20floatin\
-    g\x20point\x20JavaScript.\n\x20Specifying\x20JS_NUMBER\x20for\x20the\x20\
-    jstype\x20causes\x20the\x20generated\x20JavaScript\x20code\x20to\n\x20us\
-    e\x20the\x20JavaScript\x20\"number\"\x20type.\x20\x20The\x20behavior\x20\
-    of\x20the\x20default\x20option\n\x20JS_NORMAL\x20is\x20implementation\
-    \x20dependent.\n\n\x20This\x20option\x20is\x20an\x20enum\x20to\x20permit\
-    \x20additional\x20types\x20to\x20be\x20added,\x20e.g.\n\x20goog.math.Int\
-    eger.\n\n\r\n\x05\x04\x0c\x02\x02\x04\x12\x04\xb4\x04\x02\n\n\r\n\x05\
-    \x04\x0c\x02\x02\x06\x12\x04\xb4\x04\x0b\x11\n\r\n\x05\x04\x0c\x02\x02\
-    \x01\x12\x04\xb4\x04\x12\x18\n\r\n\x05\x04\x0c\x02\x02\x03\x12\x04\xb4\
-    \x04\x1b\x1c\n\r\n\x05\x04\x0c\x02\x02\x08\x12\x04\xb4\x04\x1d2\n\r\n\
-    \x05\x04\x0c\x02\x02\x07\x12\x04\xb4\x04(1\n\x0e\n\x04\x04\x0c\x04\x01\
-    \x12\x06\xb5\x04\x02\xbe\x04\x03\n\r\n\x05\x04\x0c\x04\x01\x01\x12\x04\
-    \xb5\x04\x07\r\n'\n\x06\x04\x0c\x04\x01\x02\0\x12\x04\xb7\x04\x04\x12\
-    \x1a\x17\x20Use\x20the\x20default\x20type.\n\n\x0f\n\x07\x04\x0c\x04\x01\
-    \x02\0\x01\x12\x04\xb7\x04\x04\r\n\x0f\n\x07\x04\x0c\x04\x01\x02\0\x02\
-    \x12\x04\xb7\x04\x10\x11\n)\n\x06\x04\x0c\x04\x01\x02\x01\x12\x04\xba\
-    \x04\x04\x12\x1a\x19\x20Use\x20JavaScript\x20strings.\n\n\x0f\n\x07\x04\
-    \x0c\x04\x01\x02\x01\x01\x12\x04\xba\x04\x04\r\n\x0f\n\x07\x04\x0c\x04\
-    \x01\x02\x01\x02\x12\x04\xba\x04\x10\x11\n)\n\x06\x04\x0c\x04\x01\x02\
-    \x02\x12\x04\xbd\x04\x04\x12\x1a\x19\x20Use\x20JavaScript\x20numbers.\n\
-    \n\x0f\n\x07\x04\x0c\x04\x01\x02\x02\x01\x12\x04\xbd\x04\x04\r\n\x0f\n\
-    \x07\x04\x0c\x04\x01\x02\x02\x02\x12\x04\xbd\x04\x10\x11\n\xef\x0c\n\x04\
-    \x04\x0c\x02\x03\x12\x04\xdc\x04\x02+\x1a\xe0\x0c\x20Should\x20this\x20f\
-    ield\x20be\x20parsed\x20lazily?\x20\x20Lazy\x20applies\x20only\x20to\x20\
-    message-type\n\x20fields.\x20\x20It\x20means\x20that\x20when\x20the\x20o\
-    uter\x20message\x20is\x20initially\x20parsed,\x20the\n\x20inner\x20messa\
-    ge's\x20contents\x20will\x20not\x20be\x20parsed\x20but\x20instead\x20sto\
-    red\x20in\x20encoded\n\x20form.\x20\x20The\x20inner\x20message\x20will\
-    \x20actually\x20be\x20parsed\x20when\x20it\x20is\x20first\x20accessed.\n\
-    \n\x20This\x20is\x20only\x20a\x20hint.\x20\x20Implementations\x20are\x20\
-    free\x20to\x20choose\x20whether\x20to\x20use\n\x20eager\x20or\x20lazy\
-    \x20parsing\x20regardless\x20of\x20the\x20value\x20of\x20this\x20option.\
-    \x20\x20However,\n\x20setting\x20this\x20option\x20true\x20suggests\x20t\
-    hat\x20the\x20protocol\x20author\x20believes\x20that\n\x20using\x20lazy\
-    \x20parsing\x20on\x20this\x20field\x20is\x20worth\x20the\x20additional\
-    \x20bookkeeping\n\x20overhead\x20typically\x20needed\x20to\x20implement\
-    \x20it.\n\n\x20This\x20option\x20does\x20not\x20affect\x20the\x20public\
-    \x20interface\x20of\x20any\x20generated\x20code;\n\x20all\x20method\x20s\
-    ignatures\x20remain\x20the\x20same.\x20\x20Furthermore,\x20thread-safety\
-    \x20of\x20the\n\x20interface\x20is\x20not\x20affected\x20by\x20this\x20o\
-    ption;\x20const\x20methods\x20remain\x20safe\x20to\n\x20call\x20from\x20\
-    multiple\x20threads\x20concurrently,\x20while\x20non-const\x20methods\
-    \x20continue\n\x20to\x20require\x20exclusive\x20access.\n\n\n\x20Note\
-    \x20that\x20implementations\x20may\x20choose\x20not\x20to\x20check\x20re\
-    quired\x20fields\x20within\n\x20a\x20lazy\x20sub-message.\x20\x20That\
-    \x20is,\x20calling\x20IsInitialized()\x20on\x20the\x20outer\x20message\n\
-    \x20may\x20return\x20true\x20even\x20if\x20the\x20inner\x20message\x20ha\
-    s\x20missing\x20required\x20fields.\n\x20This\x20is\x20necessary\x20beca\
-    use\x20otherwise\x20the\x20inner\x20message\x20would\x20have\x20to\x20be\
-    \n\x20parsed\x20in\x20order\x20to\x20perform\x20the\x20check,\x20defeati\
-    ng\x20the\x20purpose\x20of\x20lazy\n\x20parsing.\x20\x20An\x20implementa\
-    tion\x20which\x20chooses\x20not\x20to\x20check\x20required\x20fields\n\
-    \x20must\x20be\x20consistent\x20about\x20it.\x20\x20That\x20is,\x20for\
-    \x20any\x20particular\x20sub-message,\x20the\n\x20implementation\x20must\
-    \x20either\x20*always*\x20check\x20its\x20required\x20fields,\x20or\x20*\
-    never*\n\x20check\x20its\x20required\x20fields,\x20regardless\x20of\x20w\
-    hether\x20or\x20not\x20the\x20message\x20has\n\x20been\x20parsed.\n\n\r\
-    \n\x05\x04\x0c\x02\x03\x04\x12\x04\xdc\x04\x02\n\n\r\n\x05\x04\x0c\x02\
-    \x03\x05\x12\x04\xdc\x04\x0b\x0f\n\r\n\x05\x04\x0c\x02\x03\x01\x12\x04\
-    \xdc\x04\x10\x14\n\r\n\x05\x04\x0c\x02\x03\x03\x12\x04\xdc\x04\x17\x18\n\
-    \r\n\x05\x04\x0c\x02\x03\x08\x12\x04\xdc\x04\x19*\n\r\n\x05\x04\x0c\x02\
-    \x03\x07\x12\x04\xdc\x04$)\n\xe8\x01\n\x04\x04\x0c\x02\x04\x12\x04\xe2\
-    \x04\x021\x1a\xd9\x01\x20Is\x20this\x20field\x20deprecated?\n\x20Dependi\
-    ng\x20on\x20the\x20target\x20platform,\x20this\x20can\x20emit\x20Depreca\
-    ted\x20annotations\n\x20for\x20accessors,\x20or\x20it\x20will\x20be\x20c\
-    ompletely\x20ignored;\x20in\x20the\x20very\x20least,\x20this\n\x20is\x20\
-    a\x20formalization\x20for\x20deprecating\x20fields.\n\n\r\n\x05\x04\x0c\
-    \x02\x04\x04\x12\x04\xe2\x04\x02\n\n\r\n\x05\x04\x0c\x02\x04\x05\x12\x04\
-    \xe2\x04\x0b\x0f\n\r\n\x05\x04\x0c\x02\x04\x01\x12\x04\xe2\x04\x10\x1a\n\
-    \r\n\x05\x04\x0c\x02\x04\x03\x12\x04\xe2\x04\x1d\x1e\n\r\n\x05\x04\x0c\
-    \x02\x04\x08\x12\x04\xe2\x04\x1f0\n\r\n\x05\x04\x0c\x02\x04\x07\x12\x04\
-    \xe2\x04*/\n?\n\x04\x04\x0c\x02\x05\x12\x04\xe5\x04\x02,\x1a1\x20For\x20\
-    Google-internal\x20migration\x20only.\x20Do\x20not\x20use.\n\n\r\n\x05\
-    \x04\x0c\x02\x05\x04\x12\x04\xe5\x04\x02\n\n\r\n\x05\x04\x0c\x02\x05\x05\
-    \x12\x04\xe5\x04\x0b\x0f\n\r\n\x05\x04\x0c\x02\x05\x01\x12\x04\xe5\x04\
-    \x10\x14\n\r\n\x05\x04\x0c\x02\x05\x03\x12\x04\xe5\x04\x17\x19\n\r\n\x05\
-    \x04\x0c\x02\x05\x08\x12\x04\xe5\x04\x1a+\n\r\n\x05\x04\x0c\x02\x05\x07\
-    \x12\x04\xe5\x04%*\nO\n\x04\x04\x0c\x02\x06\x12\x04\xe9\x04\x02:\x1aA\
+    \x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\x0c\x05\0\x12\x04\x96\
+    \x05\r\x18\n\r\n\x05\x04\x0c\x05\0\x01\x12\x04\x96\x05\r\x11\n\r\n\x05\
+    \x04\x0c\x05\0\x02\x12\x04\x96\x05\x15\x18\n\x1c\n\x03\x04\x0c\t\x12\x04\
+    \x98\x05\x02\r\"\x0f\x20removed\x20jtype\n\n\x0c\n\x04\x04\x0c\t\0\x12\
+    \x04\x98\x05\x0b\x0c\n\r\n\x05\x04\x0c\t\0\x01\x12\x04\x98\x05\x0b\x0c\n\
+    \r\n\x05\x04\x0c\t\0\x02\x12\x04\x98\x05\x0b\x0c\n\x0c\n\x02\x04\r\x12\
+    \x06\x9b\x05\0\xa4\x05\x01\n\x0b\n\x03\x04\r\x01\x12\x04\x9b\x05\x08\x14\
+    \n=\n\x04\x04\r\x02\0\x12\x04\x9d\x05\x02#\x1a/\x20Any\x20features\x20de\
+    fined\x20in\x20the\x20specific\x20edition.\n\nO\n\x04\x04\r\x02\x01\x12\
+    \x04\xa0\x05\x02:\x1aA\x20The\x20parser\x20stores\x20options\x20it\x20do\
+    esn't\x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x04\r\x02\x01\
+    \x04\x12\x04\xa0\x05\x02\n\n\r\n\x05\x04\r\x02\x01\x06\x12\x04\xa0\x05\
+    \x0b\x1e\n\r\n\x05\x04\r\x02\x01\x01\x12\x04\xa0\x05\x1f3\n\r\n\x05\x04\
+    \r\x02\x01\x03\x12\x04\xa0\x0569\nZ\n\x03\x04\r\x05\x12\x04\xa3\x05\x02\
+    \x19\x1aM\x20Clients\x20can\x20define\x20custom\x20options\x20in\x20exte\
+    nsions\x20of\x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\r\
+    \x05\0\x12\x04\xa3\x05\r\x18\n\r\n\x05\x04\r\x05\0\x01\x12\x04\xa3\x05\r\
+    \x11\n\r\n\x05\x04\r\x05\0\x02\x12\x04\xa3\x05\x15\x18\n\x0c\n\x02\x04\
+    \x0e\x12\x06\xa6\x05\0\xbc\x05\x01\n\x0b\n\x03\x04\x0e\x01\x12\x04\xa6\
+    \x05\x08\x13\n`\n\x04\x04\x0e\x02\0\x12\x04\xaa\x05\x02\x20\x1aR\x20Set\
+    \x20this\x20option\x20to\x20true\x20to\x20allow\x20mapping\x20different\
+    \x20tag\x20names\x20to\x20the\x20same\n\x20value.\n\n\r\n\x05\x04\x0e\
+    \x02\0\x04\x12\x04\xaa\x05\x02\n\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\xaa\
+    \x05\x0b\x0f\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\xaa\x05\x10\x1b\n\r\n\
+    \x05\x04\x0e\x02\0\x03\x12\x04\xaa\x05\x1e\x1f\n\xe5\x01\n\x04\x04\x0e\
+    \x02\x01\x12\x04\xb0\x05\x021\x1a\xd6\x01\x20Is\x20this\x20enum\x20depre\
+    cated?\n\x20Depending\x20on\x20the\x20target\x20platform,\x20this\x20can\
+    \x20emit\x20Deprecated\x20annotations\n\x20for\x20the\x20enum,\x20or\x20\
+    it\x20will\x20be\x20completely\x20ignored;\x20in\x20the\x20very\x20least\
+    ,\x20this\n\x20is\x20a\x20formalization\x20for\x20deprecating\x20enums.\
+    \n\n\r\n\x05\x04\x0e\x02\x01\x04\x12\x04\xb0\x05\x02\n\n\r\n\x05\x04\x0e\
+    \x02\x01\x05\x12\x04\xb0\x05\x0b\x0f\n\r\n\x05\x04\x0e\x02\x01\x01\x12\
+    \x04\xb0\x05\x10\x1a\n\r\n\x05\x04\x0e\x02\x01\x03\x12\x04\xb0\x05\x1d\
+    \x1e\n\r\n\x05\x04\x0e\x02\x01\x08\x12\x04\xb0\x05\x1f0\n\r\n\x05\x04\
+    \x0e\x02\x01\x07\x12\x04\xb0\x05*/\n\x1f\n\x03\x04\x0e\t\x12\x04\xb2\x05\
+    \x02\r\"\x12\x20javanano_as_lite\n\n\x0c\n\x04\x04\x0e\t\0\x12\x04\xb2\
+    \x05\x0b\x0c\n\r\n\x05\x04\x0e\t\0\x01\x12\x04\xb2\x05\x0b\x0c\n\r\n\x05\
+    \x04\x0e\t\0\x02\x12\x04\xb2\x05\x0b\x0c\n=\n\x04\x04\x0e\x02\x02\x12\
+    \x04\xb5\x05\x02#\x1a/\x20Any\x20features\x20defined\x20in\x20the\x20spe\
+    cific\x20edition.\n\nO\n\x04\x04\x0e\x02\x03\x12\x04\xb8\x05\x02:\x1aA\
     \x20The\x20parser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20\
-    here.\x20See\x20above.\n\n\r\n\x05\x04\x0c\x02\x06\x04\x12\x04\xe9\x04\
-    \x02\n\n\r\n\x05\x04\x0c\x02\x06\x06\x12\x04\xe9\x04\x0b\x1e\n\r\n\x05\
-    \x04\x0c\x02\x06\x01\x12\x04\xe9\x04\x1f3\n\r\n\x05\x04\x0c\x02\x06\x03\
-    \x12\x04\xe9\x0469\nZ\n\x03\x04\x0c\x05\x12\x04\xec\x04\x02\x19\x1aM\x20\
+    here.\x20See\x20above.\n\n\r\n\x05\x04\x0e\x02\x03\x04\x12\x04\xb8\x05\
+    \x02\n\n\r\n\x05\x04\x0e\x02\x03\x06\x12\x04\xb8\x05\x0b\x1e\n\r\n\x05\
+    \x04\x0e\x02\x03\x01\x12\x04\xb8\x05\x1f3\n\r\n\x05\x04\x0e\x02\x03\x03\
+    \x12\x04\xb8\x0569\nZ\n\x03\x04\x0e\x05\x12\x04\xbb\x05\x02\x19\x1aM\x20\
     Clients\x20can\x20define\x20custom\x20options\x20in\x20extensions\x20of\
-    \x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\x0c\x05\0\x12\
-    \x04\xec\x04\r\x18\n\r\n\x05\x04\x0c\x05\0\x01\x12\x04\xec\x04\r\x11\n\r\
-    \n\x05\x04\x0c\x05\0\x02\x12\x04\xec\x04\x15\x18\n\x1c\n\x03\x04\x0c\t\
-    \x12\x04\xee\x04\x02\r\"\x0f\x20removed\x20jtype\n\n\x0c\n\x04\x04\x0c\t\
-    \0\x12\x04\xee\x04\x0b\x0c\n\r\n\x05\x04\x0c\t\0\x01\x12\x04\xee\x04\x0b\
-    \x0c\n\r\n\x05\x04\x0c\t\0\x02\x12\x04\xee\x04\x0b\x0c\n\x0c\n\x02\x04\r\
-    \x12\x06\xf1\x04\0\xf7\x04\x01\n\x0b\n\x03\x04\r\x01\x12\x04\xf1\x04\x08\
-    \x14\nO\n\x04\x04\r\x02\0\x12\x04\xf3\x04\x02:\x1aA\x20The\x20parser\x20\
-    stores\x20options\x20it\x20doesn't\x20recognize\x20here.\x20See\x20above\
-    .\n\n\r\n\x05\x04\r\x02\0\x04\x12\x04\xf3\x04\x02\n\n\r\n\x05\x04\r\x02\
-    \0\x06\x12\x04\xf3\x04\x0b\x1e\n\r\n\x05\x04\r\x02\0\x01\x12\x04\xf3\x04\
-    \x1f3\n\r\n\x05\x04\r\x02\0\x03\x12\x04\xf3\x0469\nZ\n\x03\x04\r\x05\x12\
-    \x04\xf6\x04\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20optio\
-    ns\x20in\x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\
-    \x0c\n\x04\x04\r\x05\0\x12\x04\xf6\x04\r\x18\n\r\n\x05\x04\r\x05\0\x01\
-    \x12\x04\xf6\x04\r\x11\n\r\n\x05\x04\r\x05\0\x02\x12\x04\xf6\x04\x15\x18\
-    \n\x0c\n\x02\x04\x0e\x12\x06\xf9\x04\0\x8c\x05\x01\n\x0b\n\x03\x04\x0e\
-    \x01\x12\x04\xf9\x04\x08\x13\n`\n\x04\x04\x0e\x02\0\x12\x04\xfd\x04\x02\
-    \x20\x1aR\x20Set\x20this\x20option\x20to\x20true\x20to\x20allow\x20mappi\
-    ng\x20different\x20tag\x20names\x20to\x20the\x20same\n\x20value.\n\n\r\n\
-    \x05\x04\x0e\x02\0\x04\x12\x04\xfd\x04\x02\n\n\r\n\x05\x04\x0e\x02\0\x05\
-    \x12\x04\xfd\x04\x0b\x0f\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\xfd\x04\x10\
-    \x1b\n\r\n\x05\x04\x0e\x02\0\x03\x12\x04\xfd\x04\x1e\x1f\n\xe5\x01\n\x04\
-    \x04\x0e\x02\x01\x12\x04\x83\x05\x021\x1a\xd6\x01\x20Is\x20this\x20enum\
-    \x20deprecated?\n\x20Depending\x20on\x20the\x20target\x20platform,\x20th\
-    is\x20can\x20emit\x20Deprecated\x20annotations\n\x20for\x20the\x20enum,\
-    \x20or\x20it\x20will\x20be\x20completely\x20ignored;\x20in\x20the\x20ver\
-    y\x20least,\x20this\n\x20is\x20a\x20formalization\x20for\x20deprecating\
-    \x20enums.\n\n\r\n\x05\x04\x0e\x02\x01\x04\x12\x04\x83\x05\x02\n\n\r\n\
-    \x05\x04\x0e\x02\x01\x05\x12\x04\x83\x05\x0b\x0f\n\r\n\x05\x04\x0e\x02\
-    \x01\x01\x12\x04\x83\x05\x10\x1a\n\r\n\x05\x04\x0e\x02\x01\x03\x12\x04\
-    \x83\x05\x1d\x1e\n\r\n\x05\x04\x0e\x02\x01\x08\x12\x04\x83\x05\x1f0\n\r\
-    \n\x05\x04\x0e\x02\x01\x07\x12\x04\x83\x05*/\n\x1f\n\x03\x04\x0e\t\x12\
-    \x04\x85\x05\x02\r\"\x12\x20javanano_as_lite\n\n\x0c\n\x04\x04\x0e\t\0\
-    \x12\x04\x85\x05\x0b\x0c\n\r\n\x05\x04\x0e\t\0\x01\x12\x04\x85\x05\x0b\
-    \x0c\n\r\n\x05\x04\x0e\t\0\x02\x12\x04\x85\x05\x0b\x0c\nO\n\x04\x04\x0e\
-    \x02\x02\x12\x04\x88\x05\x02:\x1aA\x20The\x20parser\x20stores\x20options\
+    \x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\x0e\x05\0\x12\
+    \x04\xbb\x05\r\x18\n\r\n\x05\x04\x0e\x05\0\x01\x12\x04\xbb\x05\r\x11\n\r\
+    \n\x05\x04\x0e\x05\0\x02\x12\x04\xbb\x05\x15\x18\n\x0c\n\x02\x04\x0f\x12\
+    \x06\xbe\x05\0\xcd\x05\x01\n\x0b\n\x03\x04\x0f\x01\x12\x04\xbe\x05\x08\
+    \x18\n\xf7\x01\n\x04\x04\x0f\x02\0\x12\x04\xc3\x05\x021\x1a\xe8\x01\x20I\
+    s\x20this\x20enum\x20value\x20deprecated?\n\x20Depending\x20on\x20the\
+    \x20target\x20platform,\x20this\x20can\x20emit\x20Deprecated\x20annotati\
+    ons\n\x20for\x20the\x20enum\x20value,\x20or\x20it\x20will\x20be\x20compl\
+    etely\x20ignored;\x20in\x20the\x20very\x20least,\n\x20this\x20is\x20a\
+    \x20formalization\x20for\x20deprecating\x20enum\x20values.\n\n\r\n\x05\
+    \x04\x0f\x02\0\x04\x12\x04\xc3\x05\x02\n\n\r\n\x05\x04\x0f\x02\0\x05\x12\
+    \x04\xc3\x05\x0b\x0f\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\xc3\x05\x10\x1a\
+    \n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\xc3\x05\x1d\x1e\n\r\n\x05\x04\x0f\
+    \x02\0\x08\x12\x04\xc3\x05\x1f0\n\r\n\x05\x04\x0f\x02\0\x07\x12\x04\xc3\
+    \x05*/\n=\n\x04\x04\x0f\x02\x01\x12\x04\xc6\x05\x02#\x1a/\x20Any\x20feat\
+    ures\x20defined\x20in\x20the\x20specific\x20edition.\n\nO\n\x04\x04\x0f\
+    \x02\x02\x12\x04\xc9\x05\x02:\x1aA\x20The\x20parser\x20stores\x20options\
     \x20it\x20doesn't\x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x04\
-    \x0e\x02\x02\x04\x12\x04\x88\x05\x02\n\n\r\n\x05\x04\x0e\x02\x02\x06\x12\
-    \x04\x88\x05\x0b\x1e\n\r\n\x05\x04\x0e\x02\x02\x01\x12\x04\x88\x05\x1f3\
-    \n\r\n\x05\x04\x0e\x02\x02\x03\x12\x04\x88\x0569\nZ\n\x03\x04\x0e\x05\
-    \x12\x04\x8b\x05\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20o\
+    \x0f\x02\x02\x04\x12\x04\xc9\x05\x02\n\n\r\n\x05\x04\x0f\x02\x02\x06\x12\
+    \x04\xc9\x05\x0b\x1e\n\r\n\x05\x04\x0f\x02\x02\x01\x12\x04\xc9\x05\x1f3\
+    \n\r\n\x05\x04\x0f\x02\x02\x03\x12\x04\xc9\x0569\nZ\n\x03\x04\x0f\x05\
+    \x12\x04\xcc\x05\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20o\
     ptions\x20in\x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\
-    \n\x0c\n\x04\x04\x0e\x05\0\x12\x04\x8b\x05\r\x18\n\r\n\x05\x04\x0e\x05\0\
-    \x01\x12\x04\x8b\x05\r\x11\n\r\n\x05\x04\x0e\x05\0\x02\x12\x04\x8b\x05\
-    \x15\x18\n\x0c\n\x02\x04\x0f\x12\x06\x8e\x05\0\x9a\x05\x01\n\x0b\n\x03\
-    \x04\x0f\x01\x12\x04\x8e\x05\x08\x18\n\xf7\x01\n\x04\x04\x0f\x02\0\x12\
-    \x04\x93\x05\x021\x1a\xe8\x01\x20Is\x20this\x20enum\x20value\x20deprecat\
-    ed?\n\x20Depending\x20on\x20the\x20target\x20platform,\x20this\x20can\
-    \x20emit\x20Deprecated\x20annotations\n\x20for\x20the\x20enum\x20value,\
-    \x20or\x20it\x20will\x20be\x20completely\x20ignored;\x20in\x20the\x20ver\
-    y\x20least,\n\x20this\x20is\x20a\x20formalization\x20for\x20deprecating\
-    \x20enum\x20values.\n\n\r\n\x05\x04\x0f\x02\0\x04\x12\x04\x93\x05\x02\n\
-    \n\r\n\x05\x04\x0f\x02\0\x05\x12\x04\x93\x05\x0b\x0f\n\r\n\x05\x04\x0f\
-    \x02\0\x01\x12\x04\x93\x05\x10\x1a\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\
-    \x93\x05\x1d\x1e\n\r\n\x05\x04\x0f\x02\0\x08\x12\x04\x93\x05\x1f0\n\r\n\
-    \x05\x04\x0f\x02\0\x07\x12\x04\x93\x05*/\nO\n\x04\x04\x0f\x02\x01\x12\
-    \x04\x96\x05\x02:\x1aA\x20The\x20parser\x20stores\x20options\x20it\x20do\
-    esn't\x20recognize\x20here.\x20See\x20above.\n\n\r\n\x05\x04\x0f\x02\x01\
-    \x04\x12\x04\x96\x05\x02\n\n\r\n\x05\x04\x0f\x02\x01\x06\x12\x04\x96\x05\
-    \x0b\x1e\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\x96\x05\x1f3\n\r\n\x05\
-    \x04\x0f\x02\x01\x03\x12\x04\x96\x0569\nZ\n\x03\x04\x0f\x05\x12\x04\x99\
-    \x05\x02\x19\x1aM\x20Clients\x20can\x20define\x20custom\x20options\x20in\
-    \x20extensions\x20of\x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\
-    \x04\x0f\x05\0\x12\x04\x99\x05\r\x18\n\r\n\x05\x04\x0f\x05\0\x01\x12\x04\
-    \x99\x05\r\x11\n\r\n\x05\x04\x0f\x05\0\x02\x12\x04\x99\x05\x15\x18\n\x0c\
-    \n\x02\x04\x10\x12\x06\x9c\x05\0\xae\x05\x01\n\x0b\n\x03\x04\x10\x01\x12\
-    \x04\x9c\x05\x08\x16\n\xd9\x03\n\x04\x04\x10\x02\0\x12\x04\xa7\x05\x022\
-    \x1a\xdf\x01\x20Is\x20this\x20service\x20deprecated?\n\x20Depending\x20o\
-    n\x20the\x20target\x20platform,\x20this\x20can\x20emit\x20Deprecated\x20\
-    annotations\n\x20for\x20the\x20service,\x20or\x20it\x20will\x20be\x20com\
-    pletely\x20ignored;\x20in\x20the\x20very\x20least,\n\x20this\x20is\x20a\
-    \x20formalization\x20for\x20deprecating\x20services.\n2\xe8\x01\x20Note:\
-    \x20\x20Field\x20numbers\x201\x20through\x2032\x20are\x20reserved\x20for\
-    \x20Google's\x20internal\x20RPC\n\x20\x20\x20framework.\x20\x20We\x20apo\
-    logize\x20for\x20hoarding\x20these\x20numbers\x20to\x20ourselves,\x20but\
-    \n\x20\x20\x20we\x20were\x20already\x20using\x20them\x20long\x20before\
-    \x20we\x20decided\x20to\x20release\x20Protocol\n\x20\x20\x20Buffers.\n\n\
-    \r\n\x05\x04\x10\x02\0\x04\x12\x04\xa7\x05\x02\n\n\r\n\x05\x04\x10\x02\0\
-    \x05\x12\x04\xa7\x05\x0b\x0f\n\r\n\x05\x04\x10\x02\0\x01\x12\x04\xa7\x05\
-    \x10\x1a\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\xa7\x05\x1d\x1f\n\r\n\x05\
-    \x04\x10\x02\0\x08\x12\x04\xa7\x05\x201\n\r\n\x05\x04\x10\x02\0\x07\x12\
-    \x04\xa7\x05+0\nO\n\x04\x04\x10\x02\x01\x12\x04\xaa\x05\x02:\x1aA\x20The\
+    \n\x0c\n\x04\x04\x0f\x05\0\x12\x04\xcc\x05\r\x18\n\r\n\x05\x04\x0f\x05\0\
+    \x01\x12\x04\xcc\x05\r\x11\n\r\n\x05\x04\x0f\x05\0\x02\x12\x04\xcc\x05\
+    \x15\x18\n\x0c\n\x02\x04\x10\x12\x06\xcf\x05\0\xe4\x05\x01\n\x0b\n\x03\
+    \x04\x10\x01\x12\x04\xcf\x05\x08\x16\n\xd9\x03\n\x04\x04\x10\x02\0\x12\
+    \x04\xda\x05\x022\x1a\xdf\x01\x20Is\x20this\x20service\x20deprecated?\n\
+    \x20Depending\x20on\x20the\x20target\x20platform,\x20this\x20can\x20emit\
+    \x20Deprecated\x20annotations\n\x20for\x20the\x20service,\x20or\x20it\
+    \x20will\x20be\x20completely\x20ignored;\x20in\x20the\x20very\x20least,\
+    \n\x20this\x20is\x20a\x20formalization\x20for\x20deprecating\x20services\
+    .\n2\xe8\x01\x20Note:\x20\x20Field\x20numbers\x201\x20through\x2032\x20a\
+    re\x20reserved\x20for\x20Google's\x20internal\x20RPC\n\x20\x20\x20framew\
+    ork.\x20\x20We\x20apologize\x20for\x20hoarding\x20these\x20numbers\x20to\
+    \x20ourselves,\x20but\n\x20\x20\x20we\x20were\x20already\x20using\x20the\
+    m\x20long\x20before\x20we\x20decided\x20to\x20release\x20Protocol\n\x20\
+    \x20\x20Buffers.\n\n\r\n\x05\x04\x10\x02\0\x04\x12\x04\xda\x05\x02\n\n\r\
+    \n\x05\x04\x10\x02\0\x05\x12\x04\xda\x05\x0b\x0f\n\r\n\x05\x04\x10\x02\0\
+    \x01\x12\x04\xda\x05\x10\x1a\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\xda\x05\
+    \x1d\x1f\n\r\n\x05\x04\x10\x02\0\x08\x12\x04\xda\x05\x201\n\r\n\x05\x04\
+    \x10\x02\0\x07\x12\x04\xda\x05+0\n=\n\x04\x04\x10\x02\x01\x12\x04\xdd\
+    \x05\x02$\x1a/\x20Any\x20features\x20defined\x20in\x20the\x20specific\
+    \x20edition.\n\nO\n\x04\x04\x10\x02\x02\x12\x04\xe0\x05\x02:\x1aA\x20The\
     \x20parser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20here.\
-    \x20See\x20above.\n\n\r\n\x05\x04\x10\x02\x01\x04\x12\x04\xaa\x05\x02\n\
-    \n\r\n\x05\x04\x10\x02\x01\x06\x12\x04\xaa\x05\x0b\x1e\n\r\n\x05\x04\x10\
-    \x02\x01\x01\x12\x04\xaa\x05\x1f3\n\r\n\x05\x04\x10\x02\x01\x03\x12\x04\
-    \xaa\x0569\nZ\n\x03\x04\x10\x05\x12\x04\xad\x05\x02\x19\x1aM\x20Clients\
+    \x20See\x20above.\n\n\r\n\x05\x04\x10\x02\x02\x04\x12\x04\xe0\x05\x02\n\
+    \n\r\n\x05\x04\x10\x02\x02\x06\x12\x04\xe0\x05\x0b\x1e\n\r\n\x05\x04\x10\
+    \x02\x02\x01\x12\x04\xe0\x05\x1f3\n\r\n\x05\x04\x10\x02\x02\x03\x12\x04\
+    \xe0\x0569\nZ\n\x03\x04\x10\x05\x12\x04\xe3\x05\x02\x19\x1aM\x20Clients\
     \x20can\x20define\x20custom\x20options\x20in\x20extensions\x20of\x20this\
-    \x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\x10\x05\0\x12\x04\xad\
-    \x05\r\x18\n\r\n\x05\x04\x10\x05\0\x01\x12\x04\xad\x05\r\x11\n\r\n\x05\
-    \x04\x10\x05\0\x02\x12\x04\xad\x05\x15\x18\n\x0c\n\x02\x04\x11\x12\x06\
-    \xb0\x05\0\xcd\x05\x01\n\x0b\n\x03\x04\x11\x01\x12\x04\xb0\x05\x08\x15\n\
-    \xd6\x03\n\x04\x04\x11\x02\0\x12\x04\xbb\x05\x022\x1a\xdc\x01\x20Is\x20t\
+    \x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\x10\x05\0\x12\x04\xe3\
+    \x05\r\x18\n\r\n\x05\x04\x10\x05\0\x01\x12\x04\xe3\x05\r\x11\n\r\n\x05\
+    \x04\x10\x05\0\x02\x12\x04\xe3\x05\x15\x18\n\x0c\n\x02\x04\x11\x12\x06\
+    \xe6\x05\0\x86\x06\x01\n\x0b\n\x03\x04\x11\x01\x12\x04\xe6\x05\x08\x15\n\
+    \xd6\x03\n\x04\x04\x11\x02\0\x12\x04\xf1\x05\x022\x1a\xdc\x01\x20Is\x20t\
     his\x20method\x20deprecated?\n\x20Depending\x20on\x20the\x20target\x20pl\
     atform,\x20this\x20can\x20emit\x20Deprecated\x20annotations\n\x20for\x20\
     the\x20method,\x20or\x20it\x20will\x20be\x20completely\x20ignored;\x20in\
@@ -9520,286 +11644,318 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     g\x20these\x20numbers\x20to\x20ourselves,\x20but\n\x20\x20\x20we\x20were\
     \x20already\x20using\x20them\x20long\x20before\x20we\x20decided\x20to\
     \x20release\x20Protocol\n\x20\x20\x20Buffers.\n\n\r\n\x05\x04\x11\x02\0\
-    \x04\x12\x04\xbb\x05\x02\n\n\r\n\x05\x04\x11\x02\0\x05\x12\x04\xbb\x05\
-    \x0b\x0f\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xbb\x05\x10\x1a\n\r\n\x05\
-    \x04\x11\x02\0\x03\x12\x04\xbb\x05\x1d\x1f\n\r\n\x05\x04\x11\x02\0\x08\
-    \x12\x04\xbb\x05\x201\n\r\n\x05\x04\x11\x02\0\x07\x12\x04\xbb\x05+0\n\
-    \xf0\x01\n\x04\x04\x11\x04\0\x12\x06\xc0\x05\x02\xc4\x05\x03\x1a\xdf\x01\
+    \x04\x12\x04\xf1\x05\x02\n\n\r\n\x05\x04\x11\x02\0\x05\x12\x04\xf1\x05\
+    \x0b\x0f\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xf1\x05\x10\x1a\n\r\n\x05\
+    \x04\x11\x02\0\x03\x12\x04\xf1\x05\x1d\x1f\n\r\n\x05\x04\x11\x02\0\x08\
+    \x12\x04\xf1\x05\x201\n\r\n\x05\x04\x11\x02\0\x07\x12\x04\xf1\x05+0\n\
+    \xf0\x01\n\x04\x04\x11\x04\0\x12\x06\xf6\x05\x02\xfa\x05\x03\x1a\xdf\x01\
     \x20Is\x20this\x20method\x20side-effect-free\x20(or\x20safe\x20in\x20HTT\
     P\x20parlance),\x20or\x20idempotent,\n\x20or\x20neither?\x20HTTP\x20base\
     d\x20RPC\x20implementation\x20may\x20choose\x20GET\x20verb\x20for\x20saf\
     e\n\x20methods,\x20and\x20PUT\x20verb\x20for\x20idempotent\x20methods\
     \x20instead\x20of\x20the\x20default\x20POST.\n\n\r\n\x05\x04\x11\x04\0\
-    \x01\x12\x04\xc0\x05\x07\x17\n\x0e\n\x06\x04\x11\x04\0\x02\0\x12\x04\xc1\
-    \x05\x04\x1c\n\x0f\n\x07\x04\x11\x04\0\x02\0\x01\x12\x04\xc1\x05\x04\x17\
-    \n\x0f\n\x07\x04\x11\x04\0\x02\0\x02\x12\x04\xc1\x05\x1a\x1b\n$\n\x06\
-    \x04\x11\x04\0\x02\x01\x12\x04\xc2\x05\x04\x18\"\x14\x20implies\x20idemp\
-    otent\n\n\x0f\n\x07\x04\x11\x04\0\x02\x01\x01\x12\x04\xc2\x05\x04\x13\n\
-    \x0f\n\x07\x04\x11\x04\0\x02\x01\x02\x12\x04\xc2\x05\x16\x17\n7\n\x06\
-    \x04\x11\x04\0\x02\x02\x12\x04\xc3\x05\x04\x13\"'\x20idempotent,\x20but\
+    \x01\x12\x04\xf6\x05\x07\x17\n\x0e\n\x06\x04\x11\x04\0\x02\0\x12\x04\xf7\
+    \x05\x04\x1c\n\x0f\n\x07\x04\x11\x04\0\x02\0\x01\x12\x04\xf7\x05\x04\x17\
+    \n\x0f\n\x07\x04\x11\x04\0\x02\0\x02\x12\x04\xf7\x05\x1a\x1b\n$\n\x06\
+    \x04\x11\x04\0\x02\x01\x12\x04\xf8\x05\x04\x18\"\x14\x20implies\x20idemp\
+    otent\n\n\x0f\n\x07\x04\x11\x04\0\x02\x01\x01\x12\x04\xf8\x05\x04\x13\n\
+    \x0f\n\x07\x04\x11\x04\0\x02\x01\x02\x12\x04\xf8\x05\x16\x17\n7\n\x06\
+    \x04\x11\x04\0\x02\x02\x12\x04\xf9\x05\x04\x13\"'\x20idempotent,\x20but\
     \x20may\x20have\x20side\x20effects\n\n\x0f\n\x07\x04\x11\x04\0\x02\x02\
-    \x01\x12\x04\xc3\x05\x04\x0e\n\x0f\n\x07\x04\x11\x04\0\x02\x02\x02\x12\
-    \x04\xc3\x05\x11\x12\n\x0e\n\x04\x04\x11\x02\x01\x12\x06\xc5\x05\x02\xc6\
-    \x05&\n\r\n\x05\x04\x11\x02\x01\x04\x12\x04\xc5\x05\x02\n\n\r\n\x05\x04\
-    \x11\x02\x01\x06\x12\x04\xc5\x05\x0b\x1b\n\r\n\x05\x04\x11\x02\x01\x01\
-    \x12\x04\xc5\x05\x1c-\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\xc5\x0502\n\
-    \r\n\x05\x04\x11\x02\x01\x08\x12\x04\xc6\x05\x06%\n\r\n\x05\x04\x11\x02\
-    \x01\x07\x12\x04\xc6\x05\x11$\nO\n\x04\x04\x11\x02\x02\x12\x04\xc9\x05\
-    \x02:\x1aA\x20The\x20parser\x20stores\x20options\x20it\x20doesn't\x20rec\
-    ognize\x20here.\x20See\x20above.\n\n\r\n\x05\x04\x11\x02\x02\x04\x12\x04\
-    \xc9\x05\x02\n\n\r\n\x05\x04\x11\x02\x02\x06\x12\x04\xc9\x05\x0b\x1e\n\r\
-    \n\x05\x04\x11\x02\x02\x01\x12\x04\xc9\x05\x1f3\n\r\n\x05\x04\x11\x02\
-    \x02\x03\x12\x04\xc9\x0569\nZ\n\x03\x04\x11\x05\x12\x04\xcc\x05\x02\x19\
-    \x1aM\x20Clients\x20can\x20define\x20custom\x20options\x20in\x20extensio\
-    ns\x20of\x20this\x20message.\x20See\x20above.\n\n\x0c\n\x04\x04\x11\x05\
-    \0\x12\x04\xcc\x05\r\x18\n\r\n\x05\x04\x11\x05\0\x01\x12\x04\xcc\x05\r\
-    \x11\n\r\n\x05\x04\x11\x05\0\x02\x12\x04\xcc\x05\x15\x18\n\x8b\x03\n\x02\
-    \x04\x12\x12\x06\xd6\x05\0\xea\x05\x01\x1a\xfc\x02\x20A\x20message\x20re\
-    presenting\x20a\x20option\x20the\x20parser\x20does\x20not\x20recognize.\
-    \x20This\x20only\n\x20appears\x20in\x20options\x20protos\x20created\x20b\
-    y\x20the\x20compiler::Parser\x20class.\n\x20DescriptorPool\x20resolves\
-    \x20these\x20when\x20building\x20Descriptor\x20objects.\x20Therefore,\n\
-    \x20options\x20protos\x20in\x20descriptor\x20objects\x20(e.g.\x20returne\
-    d\x20by\x20Descriptor::options(),\n\x20or\x20produced\x20by\x20Descripto\
-    r::CopyTo())\x20will\x20never\x20have\x20UninterpretedOptions\n\x20in\
-    \x20them.\n\n\x0b\n\x03\x04\x12\x01\x12\x04\xd6\x05\x08\x1b\n\xcb\x02\n\
-    \x04\x04\x12\x03\0\x12\x06\xdc\x05\x02\xdf\x05\x03\x1a\xba\x02\x20The\
-    \x20name\x20of\x20the\x20uninterpreted\x20option.\x20\x20Each\x20string\
-    \x20represents\x20a\x20segment\x20in\n\x20a\x20dot-separated\x20name.\
-    \x20\x20is_extension\x20is\x20true\x20iff\x20a\x20segment\x20represents\
-    \x20an\n\x20extension\x20(denoted\x20with\x20parentheses\x20in\x20option\
-    s\x20specs\x20in\x20.proto\x20files).\n\x20E.g.,{\x20[\"foo\",\x20false]\
-    ,\x20[\"bar.baz\",\x20true],\x20[\"qux\",\x20false]\x20}\x20represents\n\
-    \x20\"foo.(bar.baz).qux\".\n\n\r\n\x05\x04\x12\x03\0\x01\x12\x04\xdc\x05\
-    \n\x12\n\x0e\n\x06\x04\x12\x03\0\x02\0\x12\x04\xdd\x05\x04\"\n\x0f\n\x07\
-    \x04\x12\x03\0\x02\0\x04\x12\x04\xdd\x05\x04\x0c\n\x0f\n\x07\x04\x12\x03\
-    \0\x02\0\x05\x12\x04\xdd\x05\r\x13\n\x0f\n\x07\x04\x12\x03\0\x02\0\x01\
-    \x12\x04\xdd\x05\x14\x1d\n\x0f\n\x07\x04\x12\x03\0\x02\0\x03\x12\x04\xdd\
-    \x05\x20!\n\x0e\n\x06\x04\x12\x03\0\x02\x01\x12\x04\xde\x05\x04#\n\x0f\n\
-    \x07\x04\x12\x03\0\x02\x01\x04\x12\x04\xde\x05\x04\x0c\n\x0f\n\x07\x04\
-    \x12\x03\0\x02\x01\x05\x12\x04\xde\x05\r\x11\n\x0f\n\x07\x04\x12\x03\0\
-    \x02\x01\x01\x12\x04\xde\x05\x12\x1e\n\x0f\n\x07\x04\x12\x03\0\x02\x01\
-    \x03\x12\x04\xde\x05!\"\n\x0c\n\x04\x04\x12\x02\0\x12\x04\xe0\x05\x02\
-    \x1d\n\r\n\x05\x04\x12\x02\0\x04\x12\x04\xe0\x05\x02\n\n\r\n\x05\x04\x12\
-    \x02\0\x06\x12\x04\xe0\x05\x0b\x13\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\
-    \xe0\x05\x14\x18\n\r\n\x05\x04\x12\x02\0\x03\x12\x04\xe0\x05\x1b\x1c\n\
-    \x9c\x01\n\x04\x04\x12\x02\x01\x12\x04\xe4\x05\x02'\x1a\x8d\x01\x20The\
-    \x20value\x20of\x20the\x20uninterpreted\x20option,\x20in\x20whatever\x20\
-    type\x20the\x20tokenizer\n\x20identified\x20it\x20as\x20during\x20parsin\
-    g.\x20Exactly\x20one\x20of\x20these\x20should\x20be\x20set.\n\n\r\n\x05\
-    \x04\x12\x02\x01\x04\x12\x04\xe4\x05\x02\n\n\r\n\x05\x04\x12\x02\x01\x05\
-    \x12\x04\xe4\x05\x0b\x11\n\r\n\x05\x04\x12\x02\x01\x01\x12\x04\xe4\x05\
-    \x12\"\n\r\n\x05\x04\x12\x02\x01\x03\x12\x04\xe4\x05%&\n\x0c\n\x04\x04\
-    \x12\x02\x02\x12\x04\xe5\x05\x02)\n\r\n\x05\x04\x12\x02\x02\x04\x12\x04\
-    \xe5\x05\x02\n\n\r\n\x05\x04\x12\x02\x02\x05\x12\x04\xe5\x05\x0b\x11\n\r\
-    \n\x05\x04\x12\x02\x02\x01\x12\x04\xe5\x05\x12$\n\r\n\x05\x04\x12\x02\
-    \x02\x03\x12\x04\xe5\x05'(\n\x0c\n\x04\x04\x12\x02\x03\x12\x04\xe6\x05\
-    \x02(\n\r\n\x05\x04\x12\x02\x03\x04\x12\x04\xe6\x05\x02\n\n\r\n\x05\x04\
-    \x12\x02\x03\x05\x12\x04\xe6\x05\x0b\x10\n\r\n\x05\x04\x12\x02\x03\x01\
-    \x12\x04\xe6\x05\x11#\n\r\n\x05\x04\x12\x02\x03\x03\x12\x04\xe6\x05&'\n\
-    \x0c\n\x04\x04\x12\x02\x04\x12\x04\xe7\x05\x02#\n\r\n\x05\x04\x12\x02\
-    \x04\x04\x12\x04\xe7\x05\x02\n\n\r\n\x05\x04\x12\x02\x04\x05\x12\x04\xe7\
-    \x05\x0b\x11\n\r\n\x05\x04\x12\x02\x04\x01\x12\x04\xe7\x05\x12\x1e\n\r\n\
-    \x05\x04\x12\x02\x04\x03\x12\x04\xe7\x05!\"\n\x0c\n\x04\x04\x12\x02\x05\
-    \x12\x04\xe8\x05\x02\"\n\r\n\x05\x04\x12\x02\x05\x04\x12\x04\xe8\x05\x02\
-    \n\n\r\n\x05\x04\x12\x02\x05\x05\x12\x04\xe8\x05\x0b\x10\n\r\n\x05\x04\
-    \x12\x02\x05\x01\x12\x04\xe8\x05\x11\x1d\n\r\n\x05\x04\x12\x02\x05\x03\
-    \x12\x04\xe8\x05\x20!\n\x0c\n\x04\x04\x12\x02\x06\x12\x04\xe9\x05\x02&\n\
-    \r\n\x05\x04\x12\x02\x06\x04\x12\x04\xe9\x05\x02\n\n\r\n\x05\x04\x12\x02\
-    \x06\x05\x12\x04\xe9\x05\x0b\x11\n\r\n\x05\x04\x12\x02\x06\x01\x12\x04\
-    \xe9\x05\x12!\n\r\n\x05\x04\x12\x02\x06\x03\x12\x04\xe9\x05$%\n\xda\x01\
-    \n\x02\x04\x13\x12\x06\xf1\x05\0\xf2\x06\x01\x1aj\x20Encapsulates\x20inf\
-    ormation\x20about\x20the\x20original\x20source\x20file\x20from\x20which\
-    \x20a\n\x20FileDescriptorProto\x20was\x20generated.\n2`\x20=============\
-    ======================================================\n\x20Optional\x20\
-    source\x20code\x20info\n\n\x0b\n\x03\x04\x13\x01\x12\x04\xf1\x05\x08\x16\
-    \n\x82\x11\n\x04\x04\x13\x02\0\x12\x04\x9d\x06\x02!\x1a\xf3\x10\x20A\x20\
-    Location\x20identifies\x20a\x20piece\x20of\x20source\x20code\x20in\x20a\
-    \x20.proto\x20file\x20which\n\x20corresponds\x20to\x20a\x20particular\
-    \x20definition.\x20\x20This\x20information\x20is\x20intended\n\x20to\x20\
-    be\x20useful\x20to\x20IDEs,\x20code\x20indexers,\x20documentation\x20gen\
-    erators,\x20and\x20similar\n\x20tools.\n\n\x20For\x20example,\x20say\x20\
-    we\x20have\x20a\x20file\x20like:\n\x20\x20\x20message\x20Foo\x20{\n\x20\
-    \x20\x20\x20\x20optional\x20string\x20foo\x20=\x201;\n\x20\x20\x20}\n\
-    \x20Let's\x20look\x20at\x20just\x20the\x20field\x20definition:\n\x20\x20\
-    \x20optional\x20string\x20foo\x20=\x201;\n\x20\x20\x20^\x20\x20\x20\x20\
-    \x20\x20\x20^^\x20\x20\x20\x20\x20^^\x20\x20^\x20\x20^^^\n\x20\x20\x20a\
-    \x20\x20\x20\x20\x20\x20\x20bc\x20\x20\x20\x20\x20de\x20\x20f\x20\x20ghi\
-    \n\x20We\x20have\x20the\x20following\x20locations:\n\x20\x20\x20span\x20\
-    \x20\x20path\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\
-    represents\n\x20\x20\x20[a,i)\x20\x20[\x204,\x200,\x202,\x200\x20]\x20\
-    \x20\x20\x20\x20The\x20whole\x20field\x20definition.\n\x20\x20\x20[a,b)\
-    \x20\x20[\x204,\x200,\x202,\x200,\x204\x20]\x20\x20The\x20label\x20(opti\
-    onal).\n\x20\x20\x20[c,d)\x20\x20[\x204,\x200,\x202,\x200,\x205\x20]\x20\
-    \x20The\x20type\x20(string).\n\x20\x20\x20[e,f)\x20\x20[\x204,\x200,\x20\
-    2,\x200,\x201\x20]\x20\x20The\x20name\x20(foo).\n\x20\x20\x20[g,h)\x20\
-    \x20[\x204,\x200,\x202,\x200,\x203\x20]\x20\x20The\x20number\x20(1).\n\n\
-    \x20Notes:\n\x20-\x20A\x20location\x20may\x20refer\x20to\x20a\x20repeate\
-    d\x20field\x20itself\x20(i.e.\x20not\x20to\x20any\n\x20\x20\x20particula\
-    r\x20index\x20within\x20it).\x20\x20This\x20is\x20used\x20whenever\x20a\
-    \x20set\x20of\x20elements\x20are\n\x20\x20\x20logically\x20enclosed\x20i\
-    n\x20a\x20single\x20code\x20segment.\x20\x20For\x20example,\x20an\x20ent\
-    ire\n\x20\x20\x20extend\x20block\x20(possibly\x20containing\x20multiple\
-    \x20extension\x20definitions)\x20will\n\x20\x20\x20have\x20an\x20outer\
-    \x20location\x20whose\x20path\x20refers\x20to\x20the\x20\"extensions\"\
-    \x20repeated\n\x20\x20\x20field\x20without\x20an\x20index.\n\x20-\x20Mul\
-    tiple\x20locations\x20may\x20have\x20the\x20same\x20path.\x20\x20This\
-    \x20happens\x20when\x20a\x20single\n\x20\x20\x20logical\x20declaration\
-    \x20is\x20spread\x20out\x20across\x20multiple\x20places.\x20\x20The\x20m\
-    ost\n\x20\x20\x20obvious\x20example\x20is\x20the\x20\"extend\"\x20block\
-    \x20again\x20--\x20there\x20may\x20be\x20multiple\n\x20\x20\x20extend\
-    \x20blocks\x20in\x20the\x20same\x20scope,\x20each\x20of\x20which\x20will\
-    \x20have\x20the\x20same\x20path.\n\x20-\x20A\x20location's\x20span\x20is\
-    \x20not\x20always\x20a\x20subset\x20of\x20its\x20parent's\x20span.\x20\
-    \x20For\n\x20\x20\x20example,\x20the\x20\"extendee\"\x20of\x20an\x20exte\
-    nsion\x20declaration\x20appears\x20at\x20the\n\x20\x20\x20beginning\x20o\
-    f\x20the\x20\"extend\"\x20block\x20and\x20is\x20shared\x20by\x20all\x20e\
-    xtensions\x20within\n\x20\x20\x20the\x20block.\n\x20-\x20Just\x20because\
-    \x20a\x20location's\x20span\x20is\x20a\x20subset\x20of\x20some\x20other\
-    \x20location's\x20span\n\x20\x20\x20does\x20not\x20mean\x20that\x20it\
-    \x20is\x20a\x20descendant.\x20\x20For\x20example,\x20a\x20\"group\"\x20d\
-    efines\n\x20\x20\x20both\x20a\x20type\x20and\x20a\x20field\x20in\x20a\
-    \x20single\x20declaration.\x20\x20Thus,\x20the\x20locations\n\x20\x20\
-    \x20corresponding\x20to\x20the\x20type\x20and\x20field\x20and\x20their\
-    \x20components\x20will\x20overlap.\n\x20-\x20Code\x20which\x20tries\x20t\
-    o\x20interpret\x20locations\x20should\x20probably\x20be\x20designed\x20t\
-    o\n\x20\x20\x20ignore\x20those\x20that\x20it\x20doesn't\x20understand,\
-    \x20as\x20more\x20types\x20of\x20locations\x20could\n\x20\x20\x20be\x20r\
-    ecorded\x20in\x20the\x20future.\n\n\r\n\x05\x04\x13\x02\0\x04\x12\x04\
-    \x9d\x06\x02\n\n\r\n\x05\x04\x13\x02\0\x06\x12\x04\x9d\x06\x0b\x13\n\r\n\
-    \x05\x04\x13\x02\0\x01\x12\x04\x9d\x06\x14\x1c\n\r\n\x05\x04\x13\x02\0\
-    \x03\x12\x04\x9d\x06\x1f\x20\n\x0e\n\x04\x04\x13\x03\0\x12\x06\x9e\x06\
-    \x02\xf1\x06\x03\n\r\n\x05\x04\x13\x03\0\x01\x12\x04\x9e\x06\n\x12\n\x83\
-    \x07\n\x06\x04\x13\x03\0\x02\0\x12\x04\xb6\x06\x04,\x1a\xf2\x06\x20Ident\
-    ifies\x20which\x20part\x20of\x20the\x20FileDescriptorProto\x20was\x20def\
-    ined\x20at\x20this\n\x20location.\n\n\x20Each\x20element\x20is\x20a\x20f\
-    ield\x20number\x20or\x20an\x20index.\x20\x20They\x20form\x20a\x20path\
-    \x20from\n\x20the\x20root\x20FileDescriptorProto\x20to\x20the\x20place\
-    \x20where\x20the\x20definition.\x20\x20For\n\x20example,\x20this\x20path\
-    :\n\x20\x20\x20[\x204,\x203,\x202,\x207,\x201\x20]\n\x20refers\x20to:\n\
-    \x20\x20\x20file.message_type(3)\x20\x20//\x204,\x203\n\x20\x20\x20\x20\
-    \x20\x20\x20.field(7)\x20\x20\x20\x20\x20\x20\x20\x20\x20//\x202,\x207\n\
-    \x20\x20\x20\x20\x20\x20\x20.name()\x20\x20\x20\x20\x20\x20\x20\x20\x20\
-    \x20\x20//\x201\n\x20This\x20is\x20because\x20FileDescriptorProto.messag\
-    e_type\x20has\x20field\x20number\x204:\n\x20\x20\x20repeated\x20Descript\
-    orProto\x20message_type\x20=\x204;\n\x20and\x20DescriptorProto.field\x20\
-    has\x20field\x20number\x202:\n\x20\x20\x20repeated\x20FieldDescriptorPro\
-    to\x20field\x20=\x202;\n\x20and\x20FieldDescriptorProto.name\x20has\x20f\
-    ield\x20number\x201:\n\x20\x20\x20optional\x20string\x20name\x20=\x201;\
-    \n\n\x20Thus,\x20the\x20above\x20path\x20gives\x20the\x20location\x20of\
-    \x20a\x20field\x20name.\x20\x20If\x20we\x20removed\n\x20the\x20last\x20e\
-    lement:\n\x20\x20\x20[\x204,\x203,\x202,\x207\x20]\n\x20this\x20path\x20\
-    refers\x20to\x20the\x20whole\x20field\x20declaration\x20(from\x20the\x20\
-    beginning\n\x20of\x20the\x20label\x20to\x20the\x20terminating\x20semicol\
-    on).\n\n\x0f\n\x07\x04\x13\x03\0\x02\0\x04\x12\x04\xb6\x06\x04\x0c\n\x0f\
-    \n\x07\x04\x13\x03\0\x02\0\x05\x12\x04\xb6\x06\r\x12\n\x0f\n\x07\x04\x13\
-    \x03\0\x02\0\x01\x12\x04\xb6\x06\x13\x17\n\x0f\n\x07\x04\x13\x03\0\x02\0\
-    \x03\x12\x04\xb6\x06\x1a\x1b\n\x0f\n\x07\x04\x13\x03\0\x02\0\x08\x12\x04\
-    \xb6\x06\x1c+\n\x10\n\x08\x04\x13\x03\0\x02\0\x08\x02\x12\x04\xb6\x06\
-    \x1d*\n\xd2\x02\n\x06\x04\x13\x03\0\x02\x01\x12\x04\xbd\x06\x04,\x1a\xc1\
-    \x02\x20Always\x20has\x20exactly\x20three\x20or\x20four\x20elements:\x20\
-    start\x20line,\x20start\x20column,\n\x20end\x20line\x20(optional,\x20oth\
-    erwise\x20assumed\x20same\x20as\x20start\x20line),\x20end\x20column.\n\
-    \x20These\x20are\x20packed\x20into\x20a\x20single\x20field\x20for\x20eff\
-    iciency.\x20\x20Note\x20that\x20line\n\x20and\x20column\x20numbers\x20ar\
-    e\x20zero-based\x20--\x20typically\x20you\x20will\x20want\x20to\x20add\n\
-    \x201\x20to\x20each\x20before\x20displaying\x20to\x20a\x20user.\n\n\x0f\
-    \n\x07\x04\x13\x03\0\x02\x01\x04\x12\x04\xbd\x06\x04\x0c\n\x0f\n\x07\x04\
-    \x13\x03\0\x02\x01\x05\x12\x04\xbd\x06\r\x12\n\x0f\n\x07\x04\x13\x03\0\
-    \x02\x01\x01\x12\x04\xbd\x06\x13\x17\n\x0f\n\x07\x04\x13\x03\0\x02\x01\
-    \x03\x12\x04\xbd\x06\x1a\x1b\n\x0f\n\x07\x04\x13\x03\0\x02\x01\x08\x12\
-    \x04\xbd\x06\x1c+\n\x10\n\x08\x04\x13\x03\0\x02\x01\x08\x02\x12\x04\xbd\
-    \x06\x1d*\n\xa5\x0c\n\x06\x04\x13\x03\0\x02\x02\x12\x04\xee\x06\x04)\x1a\
-    \x94\x0c\x20If\x20this\x20SourceCodeInfo\x20represents\x20a\x20complete\
-    \x20declaration,\x20these\x20are\x20any\n\x20comments\x20appearing\x20be\
-    fore\x20and\x20after\x20the\x20declaration\x20which\x20appear\x20to\x20b\
-    e\n\x20attached\x20to\x20the\x20declaration.\n\n\x20A\x20series\x20of\
-    \x20line\x20comments\x20appearing\x20on\x20consecutive\x20lines,\x20with\
-    \x20no\x20other\n\x20tokens\x20appearing\x20on\x20those\x20lines,\x20wil\
-    l\x20be\x20treated\x20as\x20a\x20single\x20comment.\n\n\x20leading_detac\
-    hed_comments\x20will\x20keep\x20paragraphs\x20of\x20comments\x20that\x20\
-    appear\n\x20before\x20(but\x20not\x20connected\x20to)\x20the\x20current\
-    \x20element.\x20Each\x20paragraph,\n\x20separated\x20by\x20empty\x20line\
-    s,\x20will\x20be\x20one\x20comment\x20element\x20in\x20the\x20repeated\n\
-    \x20field.\n\n\x20Only\x20the\x20comment\x20content\x20is\x20provided;\
-    \x20comment\x20markers\x20(e.g.\x20//)\x20are\n\x20stripped\x20out.\x20\
-    \x20For\x20block\x20comments,\x20leading\x20whitespace\x20and\x20an\x20a\
-    sterisk\n\x20will\x20be\x20stripped\x20from\x20the\x20beginning\x20of\
-    \x20each\x20line\x20other\x20than\x20the\x20first.\n\x20Newlines\x20are\
-    \x20included\x20in\x20the\x20output.\n\n\x20Examples:\n\n\x20\x20\x20opt\
-    ional\x20int32\x20foo\x20=\x201;\x20\x20//\x20Comment\x20attached\x20to\
-    \x20foo.\n\x20\x20\x20//\x20Comment\x20attached\x20to\x20bar.\n\x20\x20\
-    \x20optional\x20int32\x20bar\x20=\x202;\n\n\x20\x20\x20optional\x20strin\
-    g\x20baz\x20=\x203;\n\x20\x20\x20//\x20Comment\x20attached\x20to\x20baz.\
-    \n\x20\x20\x20//\x20Another\x20line\x20attached\x20to\x20baz.\n\n\x20\
-    \x20\x20//\x20Comment\x20attached\x20to\x20qux.\n\x20\x20\x20//\n\x20\
-    \x20\x20//\x20Another\x20line\x20attached\x20to\x20qux.\n\x20\x20\x20opt\
-    ional\x20double\x20qux\x20=\x204;\n\n\x20\x20\x20//\x20Detached\x20comme\
-    nt\x20for\x20corge.\x20This\x20is\x20not\x20leading\x20or\x20trailing\
-    \x20comments\n\x20\x20\x20//\x20to\x20qux\x20or\x20corge\x20because\x20t\
-    here\x20are\x20blank\x20lines\x20separating\x20it\x20from\n\x20\x20\x20/\
-    /\x20both.\n\n\x20\x20\x20//\x20Detached\x20comment\x20for\x20corge\x20p\
-    aragraph\x202.\n\n\x20\x20\x20optional\x20string\x20corge\x20=\x205;\n\
-    \x20\x20\x20/*\x20Block\x20comment\x20attached\n\x20\x20\x20\x20*\x20to\
-    \x20corge.\x20\x20Leading\x20asterisks\n\x20\x20\x20\x20*\x20will\x20be\
-    \x20removed.\x20*/\n\x20\x20\x20/*\x20Block\x20comment\x20attached\x20to\
-    \n\x20\x20\x20\x20*\x20grault.\x20*/\n\x20\x20\x20optional\x20int32\x20g\
-    rault\x20=\x206;\n\n\x20\x20\x20//\x20ignored\x20detached\x20comments.\n\
-    \n\x0f\n\x07\x04\x13\x03\0\x02\x02\x04\x12\x04\xee\x06\x04\x0c\n\x0f\n\
-    \x07\x04\x13\x03\0\x02\x02\x05\x12\x04\xee\x06\r\x13\n\x0f\n\x07\x04\x13\
-    \x03\0\x02\x02\x01\x12\x04\xee\x06\x14$\n\x0f\n\x07\x04\x13\x03\0\x02\
-    \x02\x03\x12\x04\xee\x06'(\n\x0e\n\x06\x04\x13\x03\0\x02\x03\x12\x04\xef\
-    \x06\x04*\n\x0f\n\x07\x04\x13\x03\0\x02\x03\x04\x12\x04\xef\x06\x04\x0c\
-    \n\x0f\n\x07\x04\x13\x03\0\x02\x03\x05\x12\x04\xef\x06\r\x13\n\x0f\n\x07\
-    \x04\x13\x03\0\x02\x03\x01\x12\x04\xef\x06\x14%\n\x0f\n\x07\x04\x13\x03\
-    \0\x02\x03\x03\x12\x04\xef\x06()\n\x0e\n\x06\x04\x13\x03\0\x02\x04\x12\
-    \x04\xf0\x06\x042\n\x0f\n\x07\x04\x13\x03\0\x02\x04\x04\x12\x04\xf0\x06\
-    \x04\x0c\n\x0f\n\x07\x04\x13\x03\0\x02\x04\x05\x12\x04\xf0\x06\r\x13\n\
-    \x0f\n\x07\x04\x13\x03\0\x02\x04\x01\x12\x04\xf0\x06\x14-\n\x0f\n\x07\
-    \x04\x13\x03\0\x02\x04\x03\x12\x04\xf0\x0601\n\xee\x01\n\x02\x04\x14\x12\
-    \x06\xf7\x06\0\x8c\x07\x01\x1a\xdf\x01\x20Describes\x20the\x20relationsh\
-    ip\x20between\x20generated\x20code\x20and\x20its\x20original\x20source\n\
-    \x20file.\x20A\x20GeneratedCodeInfo\x20message\x20is\x20associated\x20wi\
-    th\x20only\x20one\x20generated\n\x20source\x20file,\x20but\x20may\x20con\
-    tain\x20references\x20to\x20different\x20source\x20.proto\x20files.\n\n\
-    \x0b\n\x03\x04\x14\x01\x12\x04\xf7\x06\x08\x19\nx\n\x04\x04\x14\x02\0\
-    \x12\x04\xfa\x06\x02%\x1aj\x20An\x20Annotation\x20connects\x20some\x20sp\
-    an\x20of\x20text\x20in\x20generated\x20code\x20to\x20an\x20element\n\x20\
-    of\x20its\x20generating\x20.proto\x20file.\n\n\r\n\x05\x04\x14\x02\0\x04\
-    \x12\x04\xfa\x06\x02\n\n\r\n\x05\x04\x14\x02\0\x06\x12\x04\xfa\x06\x0b\
-    \x15\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\xfa\x06\x16\x20\n\r\n\x05\x04\
-    \x14\x02\0\x03\x12\x04\xfa\x06#$\n\x0e\n\x04\x04\x14\x03\0\x12\x06\xfb\
-    \x06\x02\x8b\x07\x03\n\r\n\x05\x04\x14\x03\0\x01\x12\x04\xfb\x06\n\x14\n\
-    \x8f\x01\n\x06\x04\x14\x03\0\x02\0\x12\x04\xfe\x06\x04,\x1a\x7f\x20Ident\
-    ifies\x20the\x20element\x20in\x20the\x20original\x20source\x20.proto\x20\
-    file.\x20This\x20field\n\x20is\x20formatted\x20the\x20same\x20as\x20Sour\
-    ceCodeInfo.Location.path.\n\n\x0f\n\x07\x04\x14\x03\0\x02\0\x04\x12\x04\
-    \xfe\x06\x04\x0c\n\x0f\n\x07\x04\x14\x03\0\x02\0\x05\x12\x04\xfe\x06\r\
-    \x12\n\x0f\n\x07\x04\x14\x03\0\x02\0\x01\x12\x04\xfe\x06\x13\x17\n\x0f\n\
-    \x07\x04\x14\x03\0\x02\0\x03\x12\x04\xfe\x06\x1a\x1b\n\x0f\n\x07\x04\x14\
-    \x03\0\x02\0\x08\x12\x04\xfe\x06\x1c+\n\x10\n\x08\x04\x14\x03\0\x02\0\
-    \x08\x02\x12\x04\xfe\x06\x1d*\nO\n\x06\x04\x14\x03\0\x02\x01\x12\x04\x81\
-    \x07\x04$\x1a?\x20Identifies\x20the\x20filesystem\x20path\x20to\x20the\
-    \x20original\x20source\x20.proto.\n\n\x0f\n\x07\x04\x14\x03\0\x02\x01\
-    \x04\x12\x04\x81\x07\x04\x0c\n\x0f\n\x07\x04\x14\x03\0\x02\x01\x05\x12\
-    \x04\x81\x07\r\x13\n\x0f\n\x07\x04\x14\x03\0\x02\x01\x01\x12\x04\x81\x07\
-    \x14\x1f\n\x0f\n\x07\x04\x14\x03\0\x02\x01\x03\x12\x04\x81\x07\"#\nw\n\
-    \x06\x04\x14\x03\0\x02\x02\x12\x04\x85\x07\x04\x1d\x1ag\x20Identifies\
-    \x20the\x20starting\x20offset\x20in\x20bytes\x20in\x20the\x20generated\
-    \x20code\n\x20that\x20relates\x20to\x20the\x20identified\x20object.\n\n\
-    \x0f\n\x07\x04\x14\x03\0\x02\x02\x04\x12\x04\x85\x07\x04\x0c\n\x0f\n\x07\
-    \x04\x14\x03\0\x02\x02\x05\x12\x04\x85\x07\r\x12\n\x0f\n\x07\x04\x14\x03\
-    \0\x02\x02\x01\x12\x04\x85\x07\x13\x18\n\x0f\n\x07\x04\x14\x03\0\x02\x02\
-    \x03\x12\x04\x85\x07\x1b\x1c\n\xdb\x01\n\x06\x04\x14\x03\0\x02\x03\x12\
-    \x04\x8a\x07\x04\x1b\x1a\xca\x01\x20Identifies\x20the\x20ending\x20offse\
-    t\x20in\x20bytes\x20in\x20the\x20generated\x20code\x20that\n\x20relates\
-    \x20to\x20the\x20identified\x20offset.\x20The\x20end\x20offset\x20should\
-    \x20be\x20one\x20past\n\x20the\x20last\x20relevant\x20byte\x20(so\x20the\
-    \x20length\x20of\x20the\x20text\x20=\x20end\x20-\x20begin).\n\n\x0f\n\
-    \x07\x04\x14\x03\0\x02\x03\x04\x12\x04\x8a\x07\x04\x0c\n\x0f\n\x07\x04\
-    \x14\x03\0\x02\x03\x05\x12\x04\x8a\x07\r\x12\n\x0f\n\x07\x04\x14\x03\0\
-    \x02\x03\x01\x12\x04\x8a\x07\x13\x16\n\x0f\n\x07\x04\x14\x03\0\x02\x03\
-    \x03\x12\x04\x8a\x07\x19\x1a\
+    \x01\x12\x04\xf9\x05\x04\x0e\n\x0f\n\x07\x04\x11\x04\0\x02\x02\x02\x12\
+    \x04\xf9\x05\x11\x12\n\x0e\n\x04\x04\x11\x02\x01\x12\x06\xfb\x05\x02\xfc\
+    \x05&\n\r\n\x05\x04\x11\x02\x01\x04\x12\x04\xfb\x05\x02\n\n\r\n\x05\x04\
+    \x11\x02\x01\x06\x12\x04\xfb\x05\x0b\x1b\n\r\n\x05\x04\x11\x02\x01\x01\
+    \x12\x04\xfb\x05\x1c-\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\xfb\x0502\n\
+    \r\n\x05\x04\x11\x02\x01\x08\x12\x04\xfc\x05\x06%\n\r\n\x05\x04\x11\x02\
+    \x01\x07\x12\x04\xfc\x05\x11$\n=\n\x04\x04\x11\x02\x02\x12\x04\xff\x05\
+    \x02$\x1a/\x20Any\x20features\x20defined\x20in\x20the\x20specific\x20edi\
+    tion.\n\nO\n\x04\x04\x11\x02\x03\x12\x04\x82\x06\x02:\x1aA\x20The\x20par\
+    ser\x20stores\x20options\x20it\x20doesn't\x20recognize\x20here.\x20See\
+    \x20above.\n\n\r\n\x05\x04\x11\x02\x03\x04\x12\x04\x82\x06\x02\n\n\r\n\
+    \x05\x04\x11\x02\x03\x06\x12\x04\x82\x06\x0b\x1e\n\r\n\x05\x04\x11\x02\
+    \x03\x01\x12\x04\x82\x06\x1f3\n\r\n\x05\x04\x11\x02\x03\x03\x12\x04\x82\
+    \x0669\nZ\n\x03\x04\x11\x05\x12\x04\x85\x06\x02\x19\x1aM\x20Clients\x20c\
+    an\x20define\x20custom\x20options\x20in\x20extensions\x20of\x20this\x20m\
+    essage.\x20See\x20above.\n\n\x0c\n\x04\x04\x11\x05\0\x12\x04\x85\x06\r\
+    \x18\n\r\n\x05\x04\x11\x05\0\x01\x12\x04\x85\x06\r\x11\n\r\n\x05\x04\x11\
+    \x05\0\x02\x12\x04\x85\x06\x15\x18\n\x8b\x03\n\x02\x04\x12\x12\x06\x8f\
+    \x06\0\xdd\x06\x01\x1a\xfc\x02\x20A\x20message\x20representing\x20a\x20o\
+    ption\x20the\x20parser\x20does\x20not\x20recognize.\x20This\x20only\n\
+    \x20appears\x20in\x20options\x20protos\x20created\x20by\x20the\x20compil\
+    er::Parser\x20class.\n\x20DescriptorPool\x20resolves\x20these\x20when\
+    \x20building\x20Descriptor\x20objects.\x20Therefore,\n\x20options\x20pro\
+    tos\x20in\x20descriptor\x20objects\x20(e.g.\x20returned\x20by\x20Descrip\
+    tor::options(),\n\x20or\x20produced\x20by\x20Descriptor::CopyTo())\x20wi\
+    ll\x20never\x20have\x20UninterpretedOptions\n\x20in\x20them.\n\n\x0b\n\
+    \x03\x04\x12\x01\x12\x04\x8f\x06\x08\x1b\n\xcb\x02\n\x04\x04\x12\x03\0\
+    \x12\x06\x95\x06\x02\x98\x06\x03\x1a\xba\x02\x20The\x20name\x20of\x20the\
+    \x20uninterpreted\x20option.\x20\x20Each\x20string\x20represents\x20a\
+    \x20segment\x20in\n\x20a\x20dot-separated\x20name.\x20\x20is_extension\
+    \x20is\x20true\x20iff\x20a\x20segment\x20represents\x20an\n\x20extension\
+    \x20(denoted\x20with\x20parentheses\x20in\x20options\x20specs\x20in\x20.\
+    proto\x20files).\n\x20E.g.,{\x20[\"foo\",\x20false],\x20[\"bar.baz\",\
+    \x20true],\x20[\"qux\",\x20false]\x20}\x20represents\n\x20\"foo.(bar.baz\
+    ).qux\".\n\n\r\n\x05\x04\x12\x03\0\x01\x12\x04\x95\x06\n\x12\n\x0e\n\x06\
+    \x04\x12\x03\0\x02\0\x12\x04\x96\x06\x04\"\n\x0f\n\x07\x04\x12\x03\0\x02\
+    \0\x04\x12\x04\x96\x06\x04\x0c\n\x0f\n\x07\x04\x12\x03\0\x02\0\x05\x12\
+    \x04\x96\x06\r\x13\n\x0f\n\x07\x04\x12\x03\0\x02\0\x01\x12\x04\x96\x06\
+    \x14\x1d\n\x0f\n\x07\x04\x12\x03\0\x02\0\x03\x12\x04\x96\x06\x20!\n\x0e\
+    \n\x06\x04\x12\x03\0\x02\x01\x12\x04\x97\x06\x04#\n\x0f\n\x07\x04\x12\
+    \x03\0\x02\x01\x04\x12\x04\x97\x06\x04\x0c\n\x0f\n\x07\x04\x12\x03\0\x02\
+    \x01\x05\x12\x04\x97\x06\r\x11\n\x0f\n\x07\x04\x12\x03\0\x02\x01\x01\x12\
+    \x04\x97\x06\x12\x1e\n\x0f\n\x07\x04\x12\x03\0\x02\x01\x03\x12\x04\x97\
+    \x06!\"\n\x0c\n\x04\x04\x12\x02\0\x12\x04\x99\x06\x02\x1d\n\r\n\x05\x04\
+    \x12\x02\0\x04\x12\x04\x99\x06\x02\n\n\r\n\x05\x04\x12\x02\0\x06\x12\x04\
+    \x99\x06\x0b\x13\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\x99\x06\x14\x18\n\r\
+    \n\x05\x04\x12\x02\0\x03\x12\x04\x99\x06\x1b\x1c\n\x9c\x01\n\x04\x04\x12\
+    \x02\x01\x12\x04\x9d\x06\x02'\x1a\x8d\x01\x20The\x20value\x20of\x20the\
+    \x20uninterpreted\x20option,\x20in\x20whatever\x20type\x20the\x20tokeniz\
+    er\n\x20identified\x20it\x20as\x20during\x20parsing.\x20Exactly\x20one\
+    \x20of\x20these\x20should\x20be\x20set.\n\n\r\n\x05\x04\x12\x02\x01\x04\
+    \x12\x04\x9d\x06\x02\n\n\r\n\x05\x04\x12\x02\x01\x05\x12\x04\x9d\x06\x0b\
+    \x11\n\r\n\x05\x04\x12\x02\x01\x01\x12\x04\x9d\x06\x12\"\n\r\n\x05\x04\
+    \x12\x02\x01\x03\x12\x04\x9d\x06%&\n\x0c\n\x04\x04\x12\x02\x02\x12\x04\
+    \x9e\x06\x02)\n\r\n\x05\x04\x12\x02\x02\x04\x12\x04\x9e\x06\x02\n\n\r\n\
+    \x05\x04\x12\x02\x02\x05\x12\x04\x9e\x06\x0b\x11\n\r\n\x05\x04\x12\x02\
+    \x02\x01\x12\x04\x9e\x06\x12$\n\r\n\x05\x04\x12\x02\x02\x03\x12\x04\x9e\
+    \x06'(\n\x0c\n\x04\x04\x12\x02\x03\x12\x04\x9f\x06\x02(\n\r\n\x05\x04\
+    \x12\x02\x03\x04\x12\x04\x9f\x06\x02\n\n\r\n\x05\x04\x12\x02\x03\x05\x12\
+    \x04\x9f\x06\x0b\x10\n\r\n\x05\x04\x12\x02\x03\x01\x12\x04\x9f\x06\x11#\
+    \n\r\n\x05\x04\x12\x02\x03\x03\x12\x04\x9f\x06&'\n\x0c\n\x04\x04\x12\x02\
+    \x04\x12\x04\xa0\x06\x02#\n\r\n\x05\x04\x12\x02\x04\x04\x12\x04\xa0\x06\
+    \x02\n\n\r\n\x05\x04\x12\x02\x04\x05\x12\x04\xa0\x06\x0b\x11\n\r\n\x05\
+    \x04\x12\x02\x04\x01\x12\x04\xa0\x06\x12\x1e\n\r\n\x05\x04\x12\x02\x04\
+    \x03\x12\x04\xa0\x06!\"\n\x0c\n\x04\x04\x12\x02\x05\x12\x04\xa1\x06\x02\
+    \"\n\r\n\x05\x04\x12\x02\x05\x04\x12\x04\xa1\x06\x02\n\n\r\n\x05\x04\x12\
+    \x02\x05\x05\x12\x04\xa1\x06\x0b\x10\n\r\n\x05\x04\x12\x02\x05\x01\x12\
+    \x04\xa1\x06\x11\x1d\n\r\n\x05\x04\x12\x02\x05\x03\x12\x04\xa1\x06\x20!\
+    \n\x0c\n\x04\x04\x12\x02\x06\x12\x04\xa2\x06\x02&\n\r\n\x05\x04\x12\x02\
+    \x06\x04\x12\x04\xa2\x06\x02\n\n\r\n\x05\x04\x12\x02\x06\x05\x12\x04\xa2\
+    \x06\x0b\x11\n\r\n\x05\x04\x12\x02\x06\x01\x12\x04\xa2\x06\x12!\n\r\n\
+    \x05\x04\x12\x02\x06\x03\x12\x04\xa2\x06$%\n\xcc\x01\n\x02\x04\x13\x12\
+    \x06\xab\x06\0\xdd\x06\x01\x1a\xbd\x01\x20Features\x20are\x20settings\
+    \x20of\x20elements\x20of\x20`.proto`\x20files\x20which\x20are\x20inherit\
+    ed\n\x20by\x20nested\x20elements:\x20file\x20features\x20are\x20defaults\
+    \x20for\x20messages,\x20message\n\x20features\x20are\x20defaults\x20for\
+    \x20fields,\x20and\x20so\x20on.\n\n\x0e\n\x04\x04\x13\x04\0\x12\x06\xac\
+    \x06\x02\xb1\x06\x03\n\x0e\n\x06\x04\x13\x04\0\x02\0\x12\x04\xad\x06\x04\
+    \x1f\n\x0e\n\x06\x04\x13\x04\0\x02\x01\x12\x04\xae\x06\x04\x11\n\x0e\n\
+    \x06\x04\x13\x04\0\x02\x02\x12\x04\xaf\x06\x04\x11\n\x0e\n\x06\x04\x13\
+    \x04\0\x02\x03\x12\x04\xb0\x06\x04\x18\n\x0c\n\x04\x04\x13\x02\0\x12\x04\
+    \xb2\x06\x02,\n\x0e\n\x04\x04\x13\x04\x01\x12\x06\xb4\x06\x02\xb8\x06\
+    \x03\n\x0e\n\x06\x04\x13\x04\x01\x02\0\x12\x04\xb5\x06\x04\x1a\n\x0e\n\
+    \x06\x04\x13\x04\x01\x02\x01\x12\x04\xb6\x06\x04\r\n\x0e\n\x06\x04\x13\
+    \x04\x01\x02\x02\x12\x04\xb7\x06\x04\x0f\n\x0c\n\x04\x04\x13\x02\x01\x12\
+    \x04\xb9\x06\x02\"\n\x0e\n\x04\x04\x13\x04\x02\x12\x06\xbb\x06\x02\xbf\
+    \x06\x03\n\x0e\n\x06\x04\x13\x04\x02\x02\0\x12\x04\xbc\x06\x04(\n\x0e\n\
+    \x06\x04\x13\x04\x02\x02\x01\x12\x04\xbd\x06\x04\x0f\n\x0e\n\x06\x04\x13\
+    \x04\x02\x02\x02\x12\x04\xbe\x06\x04\x11\n\x0c\n\x04\x04\x13\x02\x02\x12\
+    \x04\xc0\x06\x02=\n\x0e\n\x04\x04\x13\x04\x03\x12\x06\xc2\x06\x02\xc7\
+    \x06\x03\n\x0e\n\x06\x04\x13\x04\x03\x02\0\x12\x04\xc3\x06\x04\x20\n\x0e\
+    \n\x06\x04\x13\x04\x03\x02\x01\x12\x04\xc4\x06\x04\x0f\n\x0e\n\x06\x04\
+    \x13\x04\x03\x02\x02\x12\x04\xc5\x06\x04\r\n\x0c\n\x04\x04\x13\x02\x03\
+    \x12\x04\xc8\x06\x02.\n\x0e\n\x04\x04\x13\x04\x04\x12\x06\xca\x06\x02\
+    \xce\x06\x03\n\x0e\n\x06\x04\x13\x04\x04\x02\0\x12\x04\xcb\x06\x04!\n\
+    \x0e\n\x06\x04\x13\x04\x04\x02\x01\x12\x04\xcc\x06\x04\x18\n\x0e\n\x06\
+    \x04\x13\x04\x04\x02\x02\x12\x04\xcd\x06\x04\x12\n\x0c\n\x04\x04\x13\x02\
+    \x04\x12\x04\xcf\x06\x020\n\x0e\n\x04\x04\x13\x04\x05\x12\x06\xd1\x06\
+    \x02\xd5\x06\x03\n\x0e\n\x06\x04\x13\x04\x05\x02\0\x12\x04\xd2\x06\x04\
+    \x1c\n\x0e\n\x06\x04\x13\x04\x05\x02\x01\x12\x04\xd3\x06\x04\x0e\n\x0e\n\
+    \x06\x04\x13\x04\x05\x02\x02\x12\x04\xd4\x06\x04\x1b\n\x0c\n\x04\x04\x13\
+    \x02\x05\x12\x04\xd6\x06\x02&\n\xda\x01\n\x02\x04\x14\x12\x06\xe4\x06\0\
+    \xe5\x07\x01\x1aj\x20Encapsulates\x20information\x20about\x20the\x20orig\
+    inal\x20source\x20file\x20from\x20which\x20a\n\x20FileDescriptorProto\
+    \x20was\x20generated.\n2`\x20===========================================\
+    ========================\n\x20Optional\x20source\x20code\x20info\n\n\x0b\
+    \n\x03\x04\x14\x01\x12\x04\xe4\x06\x08\x16\n\x82\x11\n\x04\x04\x14\x02\0\
+    \x12\x04\x90\x07\x02!\x1a\xf3\x10\x20A\x20Location\x20identifies\x20a\
+    \x20piece\x20of\x20source\x20code\x20in\x20a\x20.proto\x20file\x20which\
+    \n\x20corresponds\x20to\x20a\x20particular\x20definition.\x20\x20This\
+    \x20information\x20is\x20intended\n\x20to\x20be\x20useful\x20to\x20IDEs,\
+    \x20code\x20indexers,\x20documentation\x20generators,\x20and\x20similar\
+    \n\x20tools.\n\n\x20For\x20example,\x20say\x20we\x20have\x20a\x20file\
+    \x20like:\n\x20\x20\x20message\x20Foo\x20{\n\x20\x20\x20\x20\x20optional\
+    \x20string\x20foo\x20=\x201;\n\x20\x20\x20}\n\x20Let's\x20look\x20at\x20\
+    just\x20the\x20field\x20definition:\n\x20\x20\x20optional\x20string\x20f\
+    oo\x20=\x201;\n\x20\x20\x20^\x20\x20\x20\x20\x20\x20\x20^^\x20\x20\x20\
+    \x20\x20^^\x20\x20^\x20\x20^^^\n\x20\x20\x20a\x20\x20\x20\x20\x20\x20\
+    \x20bc\x20\x20\x20\x20\x20de\x20\x20f\x20\x20ghi\n\x20We\x20have\x20the\
+    \x20following\x20locations:\n\x20\x20\x20span\x20\x20\x20path\x20\x20\
+    \x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20represents\n\x20\x20\
+    \x20[a,i)\x20\x20[\x204,\x200,\x202,\x200\x20]\x20\x20\x20\x20\x20The\
+    \x20whole\x20field\x20definition.\n\x20\x20\x20[a,b)\x20\x20[\x204,\x200\
+    ,\x202,\x200,\x204\x20]\x20\x20The\x20label\x20(optional).\n\x20\x20\x20\
+    [c,d)\x20\x20[\x204,\x200,\x202,\x200,\x205\x20]\x20\x20The\x20type\x20(\
+    string).\n\x20\x20\x20[e,f)\x20\x20[\x204,\x200,\x202,\x200,\x201\x20]\
+    \x20\x20The\x20name\x20(foo).\n\x20\x20\x20[g,h)\x20\x20[\x204,\x200,\
+    \x202,\x200,\x203\x20]\x20\x20The\x20number\x20(1).\n\n\x20Notes:\n\x20-\
+    \x20A\x20location\x20may\x20refer\x20to\x20a\x20repeated\x20field\x20its\
+    elf\x20(i.e.\x20not\x20to\x20any\n\x20\x20\x20particular\x20index\x20wit\
+    hin\x20it).\x20\x20This\x20is\x20used\x20whenever\x20a\x20set\x20of\x20e\
+    lements\x20are\n\x20\x20\x20logically\x20enclosed\x20in\x20a\x20single\
+    \x20code\x20segment.\x20\x20For\x20example,\x20an\x20entire\n\x20\x20\
+    \x20extend\x20block\x20(possibly\x20containing\x20multiple\x20extension\
+    \x20definitions)\x20will\n\x20\x20\x20have\x20an\x20outer\x20location\
+    \x20whose\x20path\x20refers\x20to\x20the\x20\"extensions\"\x20repeated\n\
+    \x20\x20\x20field\x20without\x20an\x20index.\n\x20-\x20Multiple\x20locat\
+    ions\x20may\x20have\x20the\x20same\x20path.\x20\x20This\x20happens\x20wh\
+    en\x20a\x20single\n\x20\x20\x20logical\x20declaration\x20is\x20spread\
+    \x20out\x20across\x20multiple\x20places.\x20\x20The\x20most\n\x20\x20\
+    \x20obvious\x20example\x20is\x20the\x20\"extend\"\x20block\x20again\x20-\
+    -\x20there\x20may\x20be\x20multiple\n\x20\x20\x20extend\x20blocks\x20in\
+    \x20the\x20same\x20scope,\x20each\x20of\x20which\x20will\x20have\x20the\
+    \x20same\x20path.\n\x20-\x20A\x20location's\x20span\x20is\x20not\x20alwa\
+    ys\x20a\x20subset\x20of\x20its\x20parent's\x20span.\x20\x20For\n\x20\x20\
+    \x20example,\x20the\x20\"extendee\"\x20of\x20an\x20extension\x20declarat\
+    ion\x20appears\x20at\x20the\n\x20\x20\x20beginning\x20of\x20the\x20\"ext\
+    end\"\x20block\x20and\x20is\x20shared\x20by\x20all\x20extensions\x20with\
+    in\n\x20\x20\x20the\x20block.\n\x20-\x20Just\x20because\x20a\x20location\
+    's\x20span\x20is\x20a\x20subset\x20of\x20some\x20other\x20location's\x20\
+    span\n\x20\x20\x20does\x20not\x20mean\x20that\x20it\x20is\x20a\x20descen\
+    dant.\x20\x20For\x20example,\x20a\x20\"group\"\x20defines\n\x20\x20\x20b\
+    oth\x20a\x20type\x20and\x20a\x20field\x20in\x20a\x20single\x20declaratio\
+    n.\x20\x20Thus,\x20the\x20locations\n\x20\x20\x20corresponding\x20to\x20\
+    the\x20type\x20and\x20field\x20and\x20their\x20components\x20will\x20ove\
+    rlap.\n\x20-\x20Code\x20which\x20tries\x20to\x20interpret\x20locations\
+    \x20should\x20probably\x20be\x20designed\x20to\n\x20\x20\x20ignore\x20th\
+    ose\x20that\x20it\x20doesn't\x20understand,\x20as\x20more\x20types\x20of\
+    \x20locations\x20could\n\x20\x20\x20be\x20recorded\x20in\x20the\x20futur\
+    e.\n\n\r\n\x05\x04\x14\x02\0\x04\x12\x04\x90\x07\x02\n\n\r\n\x05\x04\x14\
+    \x02\0\x06\x12\x04\x90\x07\x0b\x13\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\
+    \x90\x07\x14\x1c\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\x90\x07\x1f\x20\n\
+    \x0e\n\x04\x04\x14\x03\0\x12\x06\x91\x07\x02\xe4\x07\x03\n\r\n\x05\x04\
+    \x14\x03\0\x01\x12\x04\x91\x07\n\x12\n\x83\x07\n\x06\x04\x14\x03\0\x02\0\
+    \x12\x04\xa9\x07\x04,\x1a\xf2\x06\x20Identifies\x20which\x20part\x20of\
+    \x20the\x20FileDescriptorProto\x20was\x20defined\x20at\x20this\n\x20loca\
+    tion.\n\n\x20Each\x20element\x20is\x20a\x20field\x20number\x20or\x20an\
+    \x20index.\x20\x20They\x20form\x20a\x20path\x20from\n\x20the\x20root\x20\
+    FileDescriptorProto\x20to\x20the\x20place\x20where\x20the\x20definition.\
+    \x20\x20For\n\x20example,\x20this\x20path:\n\x20\x20\x20[\x204,\x203,\
+    \x202,\x207,\x201\x20]\n\x20refers\x20to:\n\x20\x20\x20file.message_type\
+    (3)\x20\x20//\x204,\x203\n\x20\x20\x20\x20\x20\x20\x20.field(7)\x20\x20\
+    \x20\x20\x20\x20\x20\x20\x20//\x202,\x207\n\x20\x20\x20\x20\x20\x20\x20.\
+    name()\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20//\x201\n\x20This\x20i\
+    s\x20because\x20FileDescriptorProto.message_type\x20has\x20field\x20numb\
+    er\x204:\n\x20\x20\x20repeated\x20DescriptorProto\x20message_type\x20=\
+    \x204;\n\x20and\x20DescriptorProto.field\x20has\x20field\x20number\x202:\
+    \n\x20\x20\x20repeated\x20FieldDescriptorProto\x20field\x20=\x202;\n\x20\
+    and\x20FieldDescriptorProto.name\x20has\x20field\x20number\x201:\n\x20\
+    \x20\x20optional\x20string\x20name\x20=\x201;\n\n\x20Thus,\x20the\x20abo\
+    ve\x20path\x20gives\x20the\x20location\x20of\x20a\x20field\x20name.\x20\
+    \x20If\x20we\x20removed\n\x20the\x20last\x20element:\n\x20\x20\x20[\x204\
+    ,\x203,\x202,\x207\x20]\n\x20this\x20path\x20refers\x20to\x20the\x20whol\
+    e\x20field\x20declaration\x20(from\x20the\x20beginning\n\x20of\x20the\
+    \x20label\x20to\x20the\x20terminating\x20semicolon).\n\n\x0f\n\x07\x04\
+    \x14\x03\0\x02\0\x04\x12\x04\xa9\x07\x04\x0c\n\x0f\n\x07\x04\x14\x03\0\
+    \x02\0\x05\x12\x04\xa9\x07\r\x12\n\x0f\n\x07\x04\x14\x03\0\x02\0\x01\x12\
+    \x04\xa9\x07\x13\x17\n\x0f\n\x07\x04\x14\x03\0\x02\0\x03\x12\x04\xa9\x07\
+    \x1a\x1b\n\x0f\n\x07\x04\x14\x03\0\x02\0\x08\x12\x04\xa9\x07\x1c+\n\x10\
+    \n\x08\x04\x14\x03\0\x02\0\x08\x02\x12\x04\xa9\x07\x1d*\n\xd2\x02\n\x06\
+    \x04\x14\x03\0\x02\x01\x12\x04\xb0\x07\x04,\x1a\xc1\x02\x20Always\x20has\
+    \x20exactly\x20three\x20or\x20four\x20elements:\x20start\x20line,\x20sta\
+    rt\x20column,\n\x20end\x20line\x20(optional,\x20otherwise\x20assumed\x20\
+    same\x20as\x20start\x20line),\x20end\x20column.\n\x20These\x20are\x20pac\
+    ked\x20into\x20a\x20single\x20field\x20for\x20efficiency.\x20\x20Note\
+    \x20that\x20line\n\x20and\x20column\x20numbers\x20are\x20zero-based\x20-\
+    -\x20typically\x20you\x20will\x20want\x20to\x20add\n\x201\x20to\x20each\
+    \x20before\x20displaying\x20to\x20a\x20user.\n\n\x0f\n\x07\x04\x14\x03\0\
+    \x02\x01\x04\x12\x04\xb0\x07\x04\x0c\n\x0f\n\x07\x04\x14\x03\0\x02\x01\
+    \x05\x12\x04\xb0\x07\r\x12\n\x0f\n\x07\x04\x14\x03\0\x02\x01\x01\x12\x04\
+    \xb0\x07\x13\x17\n\x0f\n\x07\x04\x14\x03\0\x02\x01\x03\x12\x04\xb0\x07\
+    \x1a\x1b\n\x0f\n\x07\x04\x14\x03\0\x02\x01\x08\x12\x04\xb0\x07\x1c+\n\
+    \x10\n\x08\x04\x14\x03\0\x02\x01\x08\x02\x12\x04\xb0\x07\x1d*\n\xa5\x0c\
+    \n\x06\x04\x14\x03\0\x02\x02\x12\x04\xe1\x07\x04)\x1a\x94\x0c\x20If\x20t\
+    his\x20SourceCodeInfo\x20represents\x20a\x20complete\x20declaration,\x20\
+    these\x20are\x20any\n\x20comments\x20appearing\x20before\x20and\x20after\
+    \x20the\x20declaration\x20which\x20appear\x20to\x20be\n\x20attached\x20t\
+    o\x20the\x20declaration.\n\n\x20A\x20series\x20of\x20line\x20comments\
+    \x20appearing\x20on\x20consecutive\x20lines,\x20with\x20no\x20other\n\
+    \x20tokens\x20appearing\x20on\x20those\x20lines,\x20will\x20be\x20treate\
+    d\x20as\x20a\x20single\x20comment.\n\n\x20leading_detached_comments\x20w\
+    ill\x20keep\x20paragraphs\x20of\x20comments\x20that\x20appear\n\x20befor\
+    e\x20(but\x20not\x20connected\x20to)\x20the\x20current\x20element.\x20Ea\
+    ch\x20paragraph,\n\x20separated\x20by\x20empty\x20lines,\x20will\x20be\
+    \x20one\x20comment\x20element\x20in\x20the\x20repeated\n\x20field.\n\n\
+    \x20Only\x20the\x20comment\x20content\x20is\x20provided;\x20comment\x20m\
+    arkers\x20(e.g.\x20//)\x20are\n\x20stripped\x20out.\x20\x20For\x20block\
+    \x20comments,\x20leading\x20whitespace\x20and\x20an\x20asterisk\n\x20wil\
+    l\x20be\x20stripped\x20from\x20the\x20beginning\x20of\x20each\x20line\
+    \x20other\x20than\x20the\x20first.\n\x20Newlines\x20are\x20included\x20i\
+    n\x20the\x20output.\n\n\x20Examples:\n\n\x20\x20\x20optional\x20int32\
+    \x20foo\x20=\x201;\x20\x20//\x20Comment\x20attached\x20to\x20foo.\n\x20\
+    \x20\x20//\x20Comment\x20attached\x20to\x20bar.\n\x20\x20\x20optional\
+    \x20int32\x20bar\x20=\x202;\n\n\x20\x20\x20optional\x20string\x20baz\x20\
+    =\x203;\n\x20\x20\x20//\x20Comment\x20attached\x20to\x20baz.\n\x20\x20\
+    \x20//\x20Another\x20line\x20attached\x20to\x20baz.\n\n\x20\x20\x20//\
+    \x20Comment\x20attached\x20to\x20qux.\n\x20\x20\x20//\n\x20\x20\x20//\
+    \x20Another\x20line\x20attached\x20to\x20qux.\n\x20\x20\x20optional\x20d\
+    ouble\x20qux\x20=\x204;\n\n\x20\x20\x20//\x20Detached\x20comment\x20for\
+    \x20corge.\x20This\x20is\x20not\x20leading\x20or\x20trailing\x20comments\
+    \n\x20\x20\x20//\x20to\x20qux\x20or\x20corge\x20because\x20there\x20are\
+    \x20blank\x20lines\x20separating\x20it\x20from\n\x20\x20\x20//\x20both.\
+    \n\n\x20\x20\x20//\x20Detached\x20comment\x20for\x20corge\x20paragraph\
+    \x202.\n\n\x20\x20\x20optional\x20string\x20corge\x20=\x205;\n\x20\x20\
+    \x20/*\x20Block\x20comment\x20attached\n\x20\x20\x20\x20*\x20to\x20corge\
+    .\x20\x20Leading\x20asterisks\n\x20\x20\x20\x20*\x20will\x20be\x20remove\
+    d.\x20*/\n\x20\x20\x20/*\x20Block\x20comment\x20attached\x20to\n\x20\x20\
+    \x20\x20*\x20grault.\x20*/\n\x20\x20\x20optional\x20int32\x20grault\x20=\
+    \x206;\n\n\x20\x20\x20//\x20ignored\x20detached\x20comments.\n\n\x0f\n\
+    \x07\x04\x14\x03\0\x02\x02\x04\x12\x04\xe1\x07\x04\x0c\n\x0f\n\x07\x04\
+    \x14\x03\0\x02\x02\x05\x12\x04\xe1\x07\r\x13\n\x0f\n\x07\x04\x14\x03\0\
+    \x02\x02\x01\x12\x04\xe1\x07\x14$\n\x0f\n\x07\x04\x14\x03\0\x02\x02\x03\
+    \x12\x04\xe1\x07'(\n\x0e\n\x06\x04\x14\x03\0\x02\x03\x12\x04\xe2\x07\x04\
+    *\n\x0f\n\x07\x04\x14\x03\0\x02\x03\x04\x12\x04\xe2\x07\x04\x0c\n\x0f\n\
+    \x07\x04\x14\x03\0\x02\x03\x05\x12\x04\xe2\x07\r\x13\n\x0f\n\x07\x04\x14\
+    \x03\0\x02\x03\x01\x12\x04\xe2\x07\x14%\n\x0f\n\x07\x04\x14\x03\0\x02\
+    \x03\x03\x12\x04\xe2\x07()\n\x0e\n\x06\x04\x14\x03\0\x02\x04\x12\x04\xe3\
+    \x07\x042\n\x0f\n\x07\x04\x14\x03\0\x02\x04\x04\x12\x04\xe3\x07\x04\x0c\
+    \n\x0f\n\x07\x04\x14\x03\0\x02\x04\x05\x12\x04\xe3\x07\r\x13\n\x0f\n\x07\
+    \x04\x14\x03\0\x02\x04\x01\x12\x04\xe3\x07\x14-\n\x0f\n\x07\x04\x14\x03\
+    \0\x02\x04\x03\x12\x04\xe3\x0701\n\xee\x01\n\x02\x04\x15\x12\x06\xea\x07\
+    \0\xff\x07\x01\x1a\xdf\x01\x20Describes\x20the\x20relationship\x20betwee\
+    n\x20generated\x20code\x20and\x20its\x20original\x20source\n\x20file.\
+    \x20A\x20GeneratedCodeInfo\x20message\x20is\x20associated\x20with\x20onl\
+    y\x20one\x20generated\n\x20source\x20file,\x20but\x20may\x20contain\x20r\
+    eferences\x20to\x20different\x20source\x20.proto\x20files.\n\n\x0b\n\x03\
+    \x04\x15\x01\x12\x04\xea\x07\x08\x19\nx\n\x04\x04\x15\x02\0\x12\x04\xed\
+    \x07\x02%\x1aj\x20An\x20Annotation\x20connects\x20some\x20span\x20of\x20\
+    text\x20in\x20generated\x20code\x20to\x20an\x20element\n\x20of\x20its\
+    \x20generating\x20.proto\x20file.\n\n\r\n\x05\x04\x15\x02\0\x04\x12\x04\
+    \xed\x07\x02\n\n\r\n\x05\x04\x15\x02\0\x06\x12\x04\xed\x07\x0b\x15\n\r\n\
+    \x05\x04\x15\x02\0\x01\x12\x04\xed\x07\x16\x20\n\r\n\x05\x04\x15\x02\0\
+    \x03\x12\x04\xed\x07#$\n\x0e\n\x04\x04\x15\x03\0\x12\x06\xee\x07\x02\xfe\
+    \x07\x03\n\r\n\x05\x04\x15\x03\0\x01\x12\x04\xee\x07\n\x14\n\x8f\x01\n\
+    \x06\x04\x15\x03\0\x02\0\x12\x04\xf1\x07\x04,\x1a\x7f\x20Identifies\x20t\
+    he\x20element\x20in\x20the\x20original\x20source\x20.proto\x20file.\x20T\
+    his\x20field\n\x20is\x20formatted\x20the\x20same\x20as\x20SourceCodeInfo\
+    .Location.path.\n\n\x0f\n\x07\x04\x15\x03\0\x02\0\x04\x12\x04\xf1\x07\
+    \x04\x0c\n\x0f\n\x07\x04\x15\x03\0\x02\0\x05\x12\x04\xf1\x07\r\x12\n\x0f\
+    \n\x07\x04\x15\x03\0\x02\0\x01\x12\x04\xf1\x07\x13\x17\n\x0f\n\x07\x04\
+    \x15\x03\0\x02\0\x03\x12\x04\xf1\x07\x1a\x1b\n\x0f\n\x07\x04\x15\x03\0\
+    \x02\0\x08\x12\x04\xf1\x07\x1c+\n\x10\n\x08\x04\x15\x03\0\x02\0\x08\x02\
+    \x12\x04\xf1\x07\x1d*\nO\n\x06\x04\x15\x03\0\x02\x01\x12\x04\xf4\x07\x04\
+    $\x1a?\x20Identifies\x20the\x20filesystem\x20path\x20to\x20the\x20origin\
+    al\x20source\x20.proto.\n\n\x0f\n\x07\x04\x15\x03\0\x02\x01\x04\x12\x04\
+    \xf4\x07\x04\x0c\n\x0f\n\x07\x04\x15\x03\0\x02\x01\x05\x12\x04\xf4\x07\r\
+    \x13\n\x0f\n\x07\x04\x15\x03\0\x02\x01\x01\x12\x04\xf4\x07\x14\x1f\n\x0f\
+    \n\x07\x04\x15\x03\0\x02\x01\x03\x12\x04\xf4\x07\"#\nw\n\x06\x04\x15\x03\
+    \0\x02\x02\x12\x04\xf8\x07\x04\x1d\x1ag\x20Identifies\x20the\x20starting\
+    \x20offset\x20in\x20bytes\x20in\x20the\x20generated\x20code\n\x20that\
+    \x20relates\x20to\x20the\x20identified\x20object.\n\n\x0f\n\x07\x04\x15\
+    \x03\0\x02\x02\x04\x12\x04\xf8\x07\x04\x0c\n\x0f\n\x07\x04\x15\x03\0\x02\
+    \x02\x05\x12\x04\xf8\x07\r\x12\n\x0f\n\x07\x04\x15\x03\0\x02\x02\x01\x12\
+    \x04\xf8\x07\x13\x18\n\x0f\n\x07\x04\x15\x03\0\x02\x02\x03\x12\x04\xf8\
+    \x07\x1b\x1c\n\xdb\x01\n\x06\x04\x15\x03\0\x02\x03\x12\x04\xfd\x07\x04\
+    \x1b\x1a\xca\x01\x20Identifies\x20the\x20ending\x20offset\x20in\x20bytes\
+    \x20in\x20the\x20generated\x20code\x20that\n\x20relates\x20to\x20the\x20\
+    identified\x20offset.\x20The\x20end\x20offset\x20should\x20be\x20one\x20\
+    past\n\x20the\x20last\x20relevant\x20byte\x20(so\x20the\x20length\x20of\
+    \x20the\x20text\x20=\x20end\x20-\x20begin).\n\n\x0f\n\x07\x04\x15\x03\0\
+    \x02\x03\x04\x12\x04\xfd\x07\x04\x0c\n\x0f\n\x07\x04\x15\x03\0\x02\x03\
+    \x05\x12\x04\xfd\x07\r\x12\n\x0f\n\x07\x04\x15\x03\0\x02\x03\x01\x12\x04\
+    \xfd\x07\x13\x16\n\x0f\n\x07\x04\x15\x03\0\x02\x03\x03\x12\x04\xfd\x07\
+    \x19\x1a\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -9835,6 +11991,7 @@ pub fn file_descriptor() -> crate::reflect::FileDescriptor {
         messages.push(ServiceOptions::generated_message_descriptor_data());
         messages.push(MethodOptions::generated_message_descriptor_data());
         messages.push(UninterpretedOption::generated_message_descriptor_data());
+        messages.push(FeatureSet::generated_message_descriptor_data());
         messages.push(SourceCodeInfo::generated_message_descriptor_data());
         messages.push(GeneratedCodeInfo::generated_message_descriptor_data());
         messages.push(descriptor_proto::ExtensionRange::generated_message_descriptor_data());
@@ -9844,12 +12001,19 @@ pub fn file_descriptor() -> crate::reflect::FileDescriptor {
         messages.push(source_code_info::Location::generated_message_descriptor_data());
         messages.push(generated_code_info::Annotation::generated_message_descriptor_data());
         let mut enums = ::std::vec::Vec::new();
+        enums.push(Edition::generated_enum_descriptor_data());
         enums.push(field_descriptor_proto::Type::generated_enum_descriptor_data());
         enums.push(field_descriptor_proto::Label::generated_enum_descriptor_data());
         enums.push(file_options::OptimizeMode::generated_enum_descriptor_data());
         enums.push(field_options::CType::generated_enum_descriptor_data());
         enums.push(field_options::JSType::generated_enum_descriptor_data());
         enums.push(method_options::IdempotencyLevel::generated_enum_descriptor_data());
+        enums.push(feature_set::FieldPresence::generated_enum_descriptor_data());
+        enums.push(feature_set::EnumType::generated_enum_descriptor_data());
+        enums.push(feature_set::RepeatedFieldEncoding::generated_enum_descriptor_data());
+        enums.push(feature_set::Utf8Validation::generated_enum_descriptor_data());
+        enums.push(feature_set::MessageEncoding::generated_enum_descriptor_data());
+        enums.push(feature_set::JsonFormat::generated_enum_descriptor_data());
         crate::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
@@ -36,6 +36,75 @@ pub trait ProtobufEnum: Eq + Sized + Copy + 'static + ProtobufValue + fmt::Debug
     }
 }
 
+/// Error returned by generated `FromStr` implementation of enums
+/// when a string is not a name of any enum value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtobufEnumParseError {
+    enum_name: &'static str,
+    name: String,
+}
+
+impl ProtobufEnumParseError {
+    /// This function is called from generated code, it is not stable, and should not be called.
+    #[doc(hidden)]
+    pub fn new(enum_name: &'static str, name: &str) -> ProtobufEnumParseError {
+        ProtobufEnumParseError {
+            enum_name,
+            name: name.to_owned(),
+        }
+    }
+
+    /// String which failed to parse.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for ProtobufEnumParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "unknown value name `{}` of enum `{}`",
+            self.name, self.enum_name
+        )
+    }
+}
+
+impl std::error::Error for ProtobufEnumParseError {}
+
+/// Error returned by generated `TryFrom<i32>` implementation of enums
+/// when a number is not a number of any enum value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtobufEnumFromI32Error {
+    enum_name: &'static str,
+    value: i32,
+}
+
+impl ProtobufEnumFromI32Error {
+    /// This function is called from generated code, it is not stable, and should not be called.
+    #[doc(hidden)]
+    pub fn new(enum_name: &'static str, value: i32) -> ProtobufEnumFromI32Error {
+        ProtobufEnumFromI32Error { enum_name, value }
+    }
+
+    /// Number which failed to convert.
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+}
+
+impl fmt::Display for ProtobufEnumFromI32Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "unknown value {} of enum `{}`",
+            self.value, self.enum_name
+        )
+    }
+}
+
+impl std::error::Error for ProtobufEnumFromI32Error {}
+
 /// Protobuf enums with possibly unknown values are preserved in this struct.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
 #[repr(transparent)]
@@ -70,6 +139,10 @@ impl<E: ProtobufEnum> ProtobufEnumOrUnknown<E> {
         self.value
     }
 
+    pub(crate) fn value_mut(&mut self) -> &mut i32 {
+        &mut self.value
+    }
+
     /// Get `i32` value as typed enum. Return `None` is value is unknown.
     pub fn enum_value(&self) -> Result<E, i32> {
         E::from_i32(self.value).ok_or(self.value)
@@ -53,6 +53,8 @@ pub enum ProtobufError {
     Utf8(str::Utf8Error),
     /// Not all required fields of message set.
     MessageNotInitialized(String),
+    /// Message cannot be converted to a custom Rust type.
+    MessageConvert(String),
 }
 
 impl fmt::Display for ProtobufError {
@@ -63,6 +65,7 @@ impl fmt::Display for ProtobufError {
             &ProtobufError::WireError(ref e) => fmt::Display::fmt(e, f),
             &ProtobufError::Utf8(ref e) => write!(f, "{}", e),
             &ProtobufError::MessageNotInitialized { .. } => write!(f, "not all message fields set"),
+            ProtobufError::MessageConvert(e) => write!(f, "message conversion failed: {}", e),
         }
     }
 }
@@ -74,6 +77,7 @@ impl Error for ProtobufError {
             &ProtobufError::Utf8(ref e) => Some(e),
             &ProtobufError::WireError(..) => None,
             &ProtobufError::MessageNotInitialized { .. } => None,
+            &ProtobufError::MessageConvert(..) => None,
         }
     }
 }
@@ -8,6 +8,11 @@ use std::marker::PhantomData;
 
 use crate::message::Message;
 use crate::reflect::types::ProtobufType;
+use crate::wire_format::WireType;
+use crate::CodedInputStream;
+use crate::CodedOutputStream;
+use crate::ProtobufResult;
+use crate::UnknownValues;
 
 /// Optional ext field
 ///
@@ -45,9 +50,51 @@ impl<M: Message, T: ProtobufType> ExtFieldOptional<M, T> {
 }
 
 impl<M: Message, T: ProtobufType> ExtFieldRepeated<M, T> {
-    /// Get a copy of value from a message (**not implemented**).
-    pub fn get(&self, _m: &M) -> Vec<T::ProtobufValue> {
-        // TODO
-        unimplemented!()
+    /// Get a copy of values from a message.
+    ///
+    /// Extension data is stored in [`UnknownFields`](crate::UnknownFields),
+    /// both packed and non-packed values are returned.
+    /// Values are returned up to the first value which cannot be decoded.
+    pub fn get(&self, m: &M) -> Vec<T::ProtobufValue> {
+        let mut r = Vec::new();
+        if let Some(values) = m.get_unknown_fields().get(self.field_number) {
+            // Values decoded before an error are kept
+            let _ = read_repeated::<T>(self.field_number, values, &mut r);
+        }
+        r
+    }
+}
+
+fn read_repeated<T: ProtobufType>(
+    field_number: u32,
+    values: &UnknownValues,
+    target: &mut Vec<T::ProtobufValue>,
+) -> ProtobufResult<()> {
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        for value in values {
+            os.write_unknown(field_number, value)?;
+        }
+        os.flush()?;
+    }
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    while !is.eof()? {
+        let (_, wire_type) = is.read_tag_unpack()?;
+        if wire_type == T::WIRE_TYPE {
+            target.push(T::read(&mut is)?);
+        } else if wire_type == WireType::WireTypeLengthDelimited {
+            // Packed scalars
+            let len = is.read_raw_varint64()?;
+            let old_limit = is.push_limit(len)?;
+            while !is.eof()? {
+                target.push(T::read(&mut is)?);
+            }
+            is.pop_limit(old_limit);
+        } else {
+            is.skip_field(wire_type)?;
+        }
     }
+    Ok(())
 }
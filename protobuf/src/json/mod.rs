@@ -7,6 +7,9 @@ mod json_name;
 mod parse;
 mod print;
 mod rfc_3339;
+#[cfg(feature = "with-serde")]
+mod serde;
+mod well_known_dynamic;
 mod well_known_wrapper;
 
 #[doc(hidden)]
@@ -23,3 +26,13 @@ pub use self::print::print_to_string;
 pub use self::print::print_to_string_with_options;
 pub use self::print::PrintError;
 pub use self::print::PrintOptions;
+#[cfg(feature = "with-serde")]
+pub use self::serde::deserialize_message;
+#[cfg(feature = "with-serde")]
+pub use self::serde::serialize_message;
+#[cfg(feature = "with-serde")]
+pub use self::serde::MessageMergeSeed;
+#[cfg(feature = "with-serde")]
+pub use self::serde::MessageSeed;
+#[cfg(feature = "with-serde")]
+pub use self::serde::MessageSerialize;
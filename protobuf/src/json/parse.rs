@@ -8,6 +8,7 @@ use std::fmt;
 use super::base64;
 
 use crate::enums::ProtobufEnum;
+use crate::error::ProtobufError;
 use crate::json::base64::FromBase64Error;
 use crate::message::Message;
 use crate::reflect::EnumDescriptor;
@@ -27,6 +28,7 @@ use crate::text_format::lexer::TokenizerError;
 
 use super::float;
 use super::rfc_3339;
+use super::well_known_dynamic;
 use crate::text_format::lexer::JsonNumberLit;
 
 use crate::json::well_known_wrapper::WellKnownWrapper;
@@ -51,7 +53,7 @@ use crate::well_known_types::UInt64Value;
 use crate::well_known_types::Value;
 
 #[derive(Debug)]
-enum ParseErrorWithoutLocInner {
+pub(crate) enum ParseErrorWithoutLocInner {
     TokenizerError(TokenizerError),
     UnknownFieldName(String),
     UnknownEnumVariantName(String),
@@ -68,11 +70,12 @@ enum ParseErrorWithoutLocInner {
     UnexpectedToken,
     AnyParsingIsNotImplemented,
     MessageNotInitialized,
+    Protobuf(ProtobufError),
 }
 
 /// JSON parse error.
 #[derive(Debug)]
-struct ParseErrorWithoutLoc(ParseErrorWithoutLocInner);
+pub(crate) struct ParseErrorWithoutLoc(pub(crate) ParseErrorWithoutLocInner);
 
 impl fmt::Display for ParseErrorWithoutLoc {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -105,6 +108,7 @@ impl fmt::Display for ParseErrorWithoutLoc {
             ParseErrorWithoutLocInner::MessageNotInitialized => {
                 write!(f, "Message not initialized")
             }
+            ParseErrorWithoutLocInner::Protobuf(e) => write!(f, "{}", e),
         }
     }
 }
@@ -117,6 +121,12 @@ impl From<TokenizerError> for ParseErrorWithoutLoc {
     }
 }
 
+impl From<ProtobufError> for ParseErrorWithoutLoc {
+    fn from(e: ProtobufError) -> Self {
+        ParseErrorWithoutLoc(ParseErrorWithoutLocInner::Protobuf(e))
+    }
+}
+
 impl From<FromBase64Error> for ParseErrorWithoutLoc {
     fn from(e: FromBase64Error) -> Self {
         ParseErrorWithoutLoc(ParseErrorWithoutLocInner::FromBase64Error(e))
@@ -156,7 +166,7 @@ impl fmt::Display for ParseError {
 
 impl std::error::Error for ParseError {}
 
-type ParseResultWithoutLoc<A> = Result<A, ParseErrorWithoutLoc>;
+pub(crate) type ParseResultWithoutLoc<A> = Result<A, ParseErrorWithoutLoc>;
 type ParseResult<A> = Result<A, ParseError>;
 
 #[derive(Clone)]
@@ -165,7 +175,7 @@ struct Parser<'a> {
     parse_options: ParseOptions,
 }
 
-trait FromJsonNumber: PartialEq + Sized {
+pub(crate) trait FromJsonNumber: PartialEq + Sized {
     fn from_f64(v: f64) -> Self;
     fn to_f64(&self) -> f64;
     fn from_string(v: &str) -> ParseResultWithoutLoc<Self>;
@@ -271,23 +281,160 @@ impl FromJsonNumber for f64 {
     }
 }
 
-impl<'a> Parser<'a> {
-    fn read_bool(&mut self) -> ParseResultWithoutLoc<bool> {
-        if self.tokenizer.next_ident_if_eq("true")? {
-            Ok(true)
-        } else if self.tokenizer.next_ident_if_eq("false")? {
-            Ok(false)
+pub(crate) fn parse_bool(s: &str) -> ParseResultWithoutLoc<bool> {
+    if s == "true" {
+        Ok(true)
+    } else if s == "false" {
+        Ok(false)
+    } else {
+        Err(ParseErrorWithoutLoc(
+            ParseErrorWithoutLocInner::ExpectingBool,
+        ))
+    }
+}
+
+pub(crate) fn enum_value_by_name(
+    name: String,
+    descriptor: &EnumDescriptor,
+) -> ParseResultWithoutLoc<EnumValueDescriptor> {
+    match descriptor.get_value_by_name(&name) {
+        Some(v) => Ok(v),
+        None => Err(ParseErrorWithoutLoc(
+            ParseErrorWithoutLocInner::UnknownEnumVariantName(name),
+        )),
+    }
+}
+
+pub(crate) fn enum_value_by_number(
+    descriptor: &EnumDescriptor,
+    number: i32,
+) -> ParseResultWithoutLoc<EnumValueDescriptor> {
+    match descriptor.get_value_by_number(number) {
+        Some(v) => Ok(v),
+        // TODO: EnumValueOrUnknown
+        None => Err(ParseErrorWithoutLoc(
+            ParseErrorWithoutLocInner::UnknownEnumVariantNumber(number),
+        )),
+    }
+}
+
+/// Parse map key, which is always a string in JSON.
+pub(crate) fn parse_map_key(
+    key: String,
+    t: &RuntimeTypeBox,
+) -> ParseResultWithoutLoc<ReflectValueBox> {
+    match t {
+        RuntimeTypeBox::I32 => i32::from_string(&key).map(ReflectValueBox::I32),
+        RuntimeTypeBox::I64 => i64::from_string(&key).map(ReflectValueBox::I64),
+        RuntimeTypeBox::U32 => u32::from_string(&key).map(ReflectValueBox::U32),
+        RuntimeTypeBox::U64 => u64::from_string(&key).map(ReflectValueBox::U64),
+        RuntimeTypeBox::Bool => parse_bool(&key).map(ReflectValueBox::Bool),
+        RuntimeTypeBox::String => Ok(ReflectValueBox::String(key)),
+        t @ RuntimeTypeBox::F32
+        | t @ RuntimeTypeBox::F64
+        | t @ RuntimeTypeBox::VecU8
+        | t @ RuntimeTypeBox::Enum(..) => panic!("{} cannot be a map key", t),
+        RuntimeTypeBox::Message(_) => panic!("message cannot be a map key"),
+    }
+}
+
+pub(crate) fn merge_duration_from_str(
+    duration: &mut Duration,
+    s: &str,
+) -> ParseResultWithoutLoc<()> {
+    let mut lexer = Lexer::new(s, ParserLanguage::Json);
+
+    fn next_dec(lexer: &mut Lexer) -> ParseResultWithoutLoc<(u64, u32)> {
+        let s = lexer.take_while(|c| c >= '0' && c <= '9');
+
+        if s.len() == 0 {
+            Ok((0, 0))
         } else {
-            Err(ParseErrorWithoutLoc(
-                ParseErrorWithoutLocInner::ExpectingBool,
+            match s.parse() {
+                Ok(n) => Ok((n, s.len() as u32)),
+                Err(_) => Err(ParseErrorWithoutLoc(
+                    ParseErrorWithoutLocInner::IncorrectDuration,
+                )),
+            }
+        }
+    }
+
+    let minus = lexer.next_char_if_eq('-');
+    let seconds = match next_dec(&mut lexer)? {
+        (_, 0) => {
+            return Err(ParseErrorWithoutLoc(
+                ParseErrorWithoutLocInner::IncorrectDuration,
             ))
         }
+        (s, _) => s,
+    };
+    let nanos = if lexer.next_char_if_eq('.') {
+        let (mut a, mut b) = next_dec(&mut lexer)?;
+        if b > 9 {
+            return Err(ParseErrorWithoutLoc(
+                ParseErrorWithoutLocInner::IncorrectDuration,
+            ));
+        }
+        while b != 9 {
+            b += 1;
+            a *= 10;
+        }
+
+        if a > 999_999_999 {
+            return Err(ParseErrorWithoutLoc(
+                ParseErrorWithoutLocInner::IncorrectDuration,
+            ));
+        }
+
+        a
+    } else {
+        0
+    };
+
+    // The suffix "s" is required
+    if !lexer.next_char_if_eq('s') {
+        return Err(ParseErrorWithoutLoc(
+            ParseErrorWithoutLocInner::IncorrectDuration,
+        ));
+    }
+
+    if !lexer.eof() {
+        return Err(ParseErrorWithoutLoc(
+            ParseErrorWithoutLocInner::IncorrectDuration,
+        ));
     }
 
-    fn parse_bool(&self, s: &str) -> ParseResultWithoutLoc<bool> {
-        if s == "true" {
+    if minus {
+        duration.seconds = -(seconds as i64);
+        duration.nanos = -(nanos as i32);
+    } else {
+        duration.seconds = seconds as i64;
+        duration.nanos = nanos as i32;
+    }
+    Ok(())
+}
+
+pub(crate) fn merge_timestamp_from_str(
+    timestamp: &mut Timestamp,
+    s: &str,
+) -> ParseResultWithoutLoc<()> {
+    let (seconds, nanos) = rfc_3339::TmUtc::parse_rfc_3339(s)?;
+    timestamp.seconds = seconds;
+    timestamp.nanos = nanos as i32;
+    Ok(())
+}
+
+pub(crate) fn merge_field_mask_from_str(field_mask: &mut FieldMask, s: &str) {
+    if !s.is_empty() {
+        field_mask.paths = s.split(',').map(|s| s.to_owned()).collect();
+    }
+}
+
+impl<'a> Parser<'a> {
+    fn read_bool(&mut self) -> ParseResultWithoutLoc<bool> {
+        if self.tokenizer.next_ident_if_eq("true")? {
             Ok(true)
-        } else if s == "false" {
+        } else if self.tokenizer.next_ident_if_eq("false")? {
             Ok(false)
         } else {
             Err(ParseErrorWithoutLoc(
@@ -403,16 +550,10 @@ impl<'a> Parser<'a> {
 
         if self.tokenizer.lookahead_is_str_lit()? {
             let name = self.read_string()?;
-            self.parse_enum(name, descriptor)
+            enum_value_by_name(name, descriptor)
         } else if self.tokenizer.lookahead_is_json_number()? {
             let number = self.read_i32()?;
-            match descriptor.get_value_by_number(number) {
-                Some(v) => Ok(v),
-                // TODO: EnumValueOrUnknown
-                None => Err(ParseErrorWithoutLoc(
-                    ParseErrorWithoutLocInner::UnknownEnumVariantNumber(number),
-                )),
-            }
+            enum_value_by_number(descriptor, number)
         } else {
             Err(ParseErrorWithoutLoc(
                 ParseErrorWithoutLocInner::ExpectingStrOrInt,
@@ -420,20 +561,6 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_enum(
-        &self,
-        name: String,
-        descriptor: &EnumDescriptor,
-    ) -> ParseResultWithoutLoc<EnumValueDescriptor> {
-        // TODO: can map key be int
-        match descriptor.get_value_by_name(&name) {
-            Some(v) => Ok(v),
-            None => Err(ParseErrorWithoutLoc(
-                ParseErrorWithoutLocInner::UnknownEnumVariantName(name),
-            )),
-        }
-    }
-
     fn read_wk_null_value(&mut self) -> ParseResultWithoutLoc<NullValue> {
         self.tokenizer.next_ident_expect_eq("null")?;
         Ok(NullValue::NULL_VALUE)
@@ -552,22 +679,6 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
-    fn parse_key(&self, key: String, t: &RuntimeTypeBox) -> ParseResultWithoutLoc<ReflectValueBox> {
-        match t {
-            RuntimeTypeBox::I32 => self.parse_number::<i32>(&key).map(ReflectValueBox::I32),
-            RuntimeTypeBox::I64 => self.parse_number::<i64>(&key).map(ReflectValueBox::I64),
-            RuntimeTypeBox::U32 => self.parse_number::<u32>(&key).map(ReflectValueBox::U32),
-            RuntimeTypeBox::U64 => self.parse_number::<u64>(&key).map(ReflectValueBox::U64),
-            RuntimeTypeBox::Bool => self.parse_bool(&key).map(ReflectValueBox::Bool),
-            RuntimeTypeBox::String => Ok(ReflectValueBox::String(key)),
-            t @ RuntimeTypeBox::F32
-            | t @ RuntimeTypeBox::F64
-            | t @ RuntimeTypeBox::VecU8
-            | t @ RuntimeTypeBox::Enum(..) => panic!("{} cannot be a map key", t),
-            RuntimeTypeBox::Message(_) => panic!("message cannot be a map key"),
-        }
-    }
-
     fn merge_map_field(
         &mut self,
         message: &mut dyn MessageDyn,
@@ -579,7 +690,7 @@ impl<'a> Parser<'a> {
         map.clear();
 
         self.read_map(
-            |ss, s| ss.parse_key(s, kt),
+            |_, s| parse_map_key(s, kt),
             |s, k| {
                 let v = s.read_value(vt)?;
                 map.insert(k, v);
@@ -636,6 +747,12 @@ impl<'a> Parser<'a> {
     }
 
     fn merge_inner(&mut self, message: &mut dyn MessageDyn) -> ParseResultWithoutLoc<()> {
+        if let Some(mut generated) = well_known_dynamic::to_generated(message)? {
+            self.merge_inner(&mut *generated)?;
+            well_known_dynamic::from_generated(message, &*generated)?;
+            return Ok(());
+        }
+
         if let Some(duration) = message.downcast_mut() {
             return self.merge_wk_duration(duration);
         }
@@ -734,91 +851,17 @@ impl<'a> Parser<'a> {
 
     fn merge_wk_duration(&mut self, duration: &mut Duration) -> ParseResultWithoutLoc<()> {
         let s = self.read_string()?;
-        let mut lexer = Lexer::new(&s, ParserLanguage::Json);
-
-        fn next_dec(lexer: &mut Lexer) -> ParseResultWithoutLoc<(u64, u32)> {
-            let s = lexer.take_while(|c| c >= '0' && c <= '9');
-
-            if s.len() == 0 {
-                Ok((0, 0))
-            } else {
-                match s.parse() {
-                    Ok(n) => Ok((n, s.len() as u32)),
-                    Err(_) => Err(ParseErrorWithoutLoc(
-                        ParseErrorWithoutLocInner::IncorrectDuration,
-                    )),
-                }
-            }
-        }
-
-        let minus = lexer.next_char_if_eq('-');
-        let seconds = match next_dec(&mut lexer)? {
-            (_, 0) => {
-                return Err(ParseErrorWithoutLoc(
-                    ParseErrorWithoutLocInner::IncorrectDuration,
-                ))
-            }
-            (s, _) => s,
-        };
-        let nanos = if lexer.next_char_if_eq('.') {
-            let (mut a, mut b) = next_dec(&mut lexer)?;
-            if b > 9 {
-                return Err(ParseErrorWithoutLoc(
-                    ParseErrorWithoutLocInner::IncorrectDuration,
-                ));
-            }
-            while b != 9 {
-                b += 1;
-                a *= 10;
-            }
-
-            if a > 999_999_999 {
-                return Err(ParseErrorWithoutLoc(
-                    ParseErrorWithoutLocInner::IncorrectDuration,
-                ));
-            }
-
-            a
-        } else {
-            0
-        };
-
-        // The suffix "s" is required
-        if !lexer.next_char_if_eq('s') {
-            return Err(ParseErrorWithoutLoc(
-                ParseErrorWithoutLocInner::IncorrectDuration,
-            ));
-        }
-
-        if !lexer.eof() {
-            return Err(ParseErrorWithoutLoc(
-                ParseErrorWithoutLocInner::IncorrectDuration,
-            ));
-        }
-
-        if minus {
-            duration.seconds = -(seconds as i64);
-            duration.nanos = -(nanos as i32);
-        } else {
-            duration.seconds = seconds as i64;
-            duration.nanos = nanos as i32;
-        }
-        Ok(())
+        merge_duration_from_str(duration, &s)
     }
 
     fn merge_wk_timestamp(&mut self, timestamp: &mut Timestamp) -> ParseResultWithoutLoc<()> {
         let s = self.read_string()?;
-        let (seconds, nanos) = rfc_3339::TmUtc::parse_rfc_3339(&s)?;
-        timestamp.seconds = seconds;
-        timestamp.nanos = nanos as i32;
-        Ok(())
+        merge_timestamp_from_str(timestamp, &s)
     }
 
     fn merge_wk_field_mask(&mut self, field_mask: &mut FieldMask) -> ParseResultWithoutLoc<()> {
         let s = self.read_string()?;
-        if !s.is_empty() {
-            field_mask.paths = s.split(',').map(|s| s.to_owned()).collect();
-        }
+        merge_field_mask_from_str(field_mask, &s);
         Ok(())
     }
 
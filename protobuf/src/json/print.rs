@@ -1,5 +1,7 @@
+use crate::error::ProtobufError;
 use crate::json::base64;
 use crate::json::float;
+use crate::json::well_known_dynamic;
 use crate::reflect::EnumDescriptor;
 use crate::reflect::MessageRef;
 use crate::reflect::ReflectFieldRef;
@@ -39,15 +41,16 @@ use crate::reflect::RuntimeFieldType;
 use crate::reflect::RuntimeTypeBox;
 
 #[derive(Debug)]
-enum PrintErrorInner {
+pub(crate) enum PrintErrorInner {
     Fmt(fmt::Error),
     AnyPrintingIsNotImplemented,
     TimestampNegativeNanos,
+    Protobuf(ProtobufError),
 }
 
 /// Print to JSON error.
 #[derive(Debug)]
-pub struct PrintError(PrintErrorInner);
+pub struct PrintError(pub(crate) PrintErrorInner);
 
 impl From<fmt::Error> for PrintError {
     fn from(e: fmt::Error) -> Self {
@@ -55,6 +58,27 @@ impl From<fmt::Error> for PrintError {
     }
 }
 
+impl From<ProtobufError> for PrintError {
+    fn from(e: ProtobufError) -> Self {
+        PrintError(PrintErrorInner::Protobuf(e))
+    }
+}
+
+impl fmt::Display for PrintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.0 {
+            PrintErrorInner::Fmt(e) => write!(f, "{}", e),
+            PrintErrorInner::AnyPrintingIsNotImplemented => {
+                write!(f, "JSON printing of Any is not implemented")
+            }
+            PrintErrorInner::TimestampNegativeNanos => write!(f, "Timestamp has negative nanos"),
+            PrintErrorInner::Protobuf(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for PrintError {}
+
 pub type PrintResult<T> = Result<T, PrintError>;
 
 struct Printer {
@@ -212,26 +236,35 @@ impl<'a> PrintableToJson for ReflectValueRef<'a> {
     }
 }
 
+/// JSON string representation of `Duration`, e. g. `1.500000000s`.
+pub(crate) fn duration_to_string(duration: &Duration) -> String {
+    let sign = if duration.seconds >= 0 { "" } else { "-" };
+    format!(
+        "{}{}.{:09}s",
+        sign,
+        duration.seconds.abs(),
+        duration.nanos.abs()
+    )
+}
+
+/// JSON string representation of `Timestamp` in RFC 3339 format.
+pub(crate) fn timestamp_to_string(timestamp: &Timestamp) -> PrintResult<String> {
+    if timestamp.nanos < 0 {
+        return Err(PrintError(PrintErrorInner::TimestampNegativeNanos));
+    }
+    let tm_utc = TmUtc::from_protobuf_timestamp(timestamp.seconds, timestamp.nanos as u32);
+    Ok(tm_utc.to_string())
+}
+
 impl PrintableToJson for Duration {
     fn print_to_json(&self, w: &mut Printer) -> PrintResult<()> {
-        let sign = if self.seconds >= 0 { "" } else { "-" };
-        Ok(write!(
-            w.buf,
-            "\"{}{}.{:09}s\"",
-            sign,
-            self.seconds.abs(),
-            self.nanos.abs()
-        )?)
+        w.print_printable(&duration_to_string(self))
     }
 }
 
 impl PrintableToJson for Timestamp {
     fn print_to_json(&self, w: &mut Printer) -> PrintResult<()> {
-        if self.nanos < 0 {
-            return Err(PrintError(PrintErrorInner::TimestampNegativeNanos));
-        }
-        let tm_utc = TmUtc::from_protobuf_timestamp(self.seconds, self.nanos as u32);
-        w.print_printable(&tm_utc.to_string())
+        w.print_printable(&timestamp_to_string(self)?)
     }
 }
 
@@ -421,7 +454,9 @@ impl Printer {
     }
 
     fn print_message(&mut self, message: &MessageRef) -> PrintResult<()> {
-        if let Some(duration) = message.downcast_ref::<Duration>() {
+        if let Some(generated) = well_known_dynamic::to_generated(&**message)? {
+            self.print_message(&MessageRef::from(&*generated))
+        } else if let Some(duration) = message.downcast_ref::<Duration>() {
             self.print_printable(duration)
         } else if let Some(timestamp) = message.downcast_ref::<Timestamp>() {
             self.print_printable(timestamp)
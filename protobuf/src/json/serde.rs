@@ -0,0 +1,962 @@
+//! `serde` integration which follows
+//! [proto3 JSON mapping](https://developers.google.com/protocol-buffers/docs/proto3#json).
+//!
+//! Output is the same as output of [`print_to_string`](crate::json::print_to_string),
+//! and input is interpreted the same way as by [`merge_from_str`](crate::json::merge_from_str),
+//! but any `serde` data format can be used, so messages can be embedded
+//! in larger `serde` documents.
+//!
+//! Messages are walked with reflection and passed to and from `serde`
+//! field by field, without intermediate JSON text.
+
+use std::fmt;
+
+use serde::de;
+use serde::de::DeserializeSeed;
+use serde::de::Error as _;
+use serde::de::Unexpected;
+use serde::ser::Error as _;
+use serde::ser::SerializeMap;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::enums::ProtobufEnum;
+use crate::json::base64;
+use crate::json::float;
+use crate::json::parse::enum_value_by_name;
+use crate::json::parse::enum_value_by_number;
+use crate::json::parse::merge_duration_from_str;
+use crate::json::parse::merge_field_mask_from_str;
+use crate::json::parse::merge_timestamp_from_str;
+use crate::json::parse::parse_map_key;
+use crate::json::parse::FromJsonNumber;
+use crate::json::parse::ParseErrorWithoutLoc;
+use crate::json::parse::ParseErrorWithoutLocInner;
+use crate::json::print::duration_to_string;
+use crate::json::print::timestamp_to_string;
+use crate::json::print::PrintError;
+use crate::json::print::PrintErrorInner;
+use crate::json::well_known_dynamic;
+use crate::json::ParseOptions;
+use crate::json::PrintOptions;
+use crate::message::Message;
+use crate::message_dyn::MessageDyn;
+use crate::reflect::EnumDescriptor;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::reflect::MessageRef;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectMapRef;
+use crate::reflect::ReflectRepeatedRef;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::well_known_types::value;
+use crate::well_known_types::Any;
+use crate::well_known_types::BoolValue;
+use crate::well_known_types::BytesValue;
+use crate::well_known_types::DoubleValue;
+use crate::well_known_types::Duration;
+use crate::well_known_types::FieldMask;
+use crate::well_known_types::FloatValue;
+use crate::well_known_types::Int32Value;
+use crate::well_known_types::Int64Value;
+use crate::well_known_types::ListValue;
+use crate::well_known_types::NullValue;
+use crate::well_known_types::StringValue;
+use crate::well_known_types::Struct;
+use crate::well_known_types::Timestamp;
+use crate::well_known_types::UInt32Value;
+use crate::well_known_types::UInt64Value;
+use crate::well_known_types::Value;
+
+/// `serde::Serialize` adapter for `&dyn MessageDyn`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let json = serde_json::to_string(&protobuf::json::MessageSerialize::new(&message))?;
+/// ```
+pub struct MessageSerialize<'a> {
+    message: &'a dyn MessageDyn,
+    print_options: PrintOptions,
+}
+
+impl<'a> MessageSerialize<'a> {
+    /// Serialize message with default print options.
+    pub fn new(message: &'a dyn MessageDyn) -> MessageSerialize<'a> {
+        MessageSerialize::with_options(message, &PrintOptions::default())
+    }
+
+    /// Serialize message with specified print options.
+    pub fn with_options(
+        message: &'a dyn MessageDyn,
+        print_options: &PrintOptions,
+    ) -> MessageSerialize<'a> {
+        MessageSerialize {
+            message,
+            print_options: print_options.clone(),
+        }
+    }
+}
+
+impl<'a> Serialize for MessageSerialize<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializeMessage {
+            message: self.message,
+            options: &self.print_options,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// `serde::de::DeserializeSeed` which merges input into provided message.
+pub struct MessageMergeSeed<'a> {
+    message: &'a mut dyn MessageDyn,
+    parse_options: ParseOptions,
+}
+
+impl<'a> MessageMergeSeed<'a> {
+    /// Merge into message with default parse options.
+    pub fn new(message: &'a mut dyn MessageDyn) -> MessageMergeSeed<'a> {
+        MessageMergeSeed::with_options(message, &ParseOptions::default())
+    }
+
+    /// Merge into message with specified parse options.
+    pub fn with_options(
+        message: &'a mut dyn MessageDyn,
+        parse_options: &ParseOptions,
+    ) -> MessageMergeSeed<'a> {
+        MessageMergeSeed {
+            message,
+            parse_options: parse_options.clone(),
+        }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MessageMergeSeed<'a> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        merge_message(self.message, &self.parse_options, deserializer)
+    }
+}
+
+/// `serde::de::DeserializeSeed` which creates a message of type specified
+/// by a descriptor.
+///
+/// Message type does not need to be known at compile time:
+/// messages of dynamic descriptors are built through reflection.
+///
+/// # Examples
+///
+/// ```ignore
+/// let message: Box<dyn MessageDyn> = MessageSeed::new(&descriptor)
+///     .deserialize(&mut serde_json::Deserializer::from_str(json))?;
+/// ```
+pub struct MessageSeed {
+    descriptor: MessageDescriptor,
+    parse_options: ParseOptions,
+}
+
+impl MessageSeed {
+    /// Create message of given type with default parse options.
+    pub fn new(descriptor: &MessageDescriptor) -> MessageSeed {
+        MessageSeed::with_options(descriptor, &ParseOptions::default())
+    }
+
+    /// Create message of given type with specified parse options.
+    pub fn with_options(
+        descriptor: &MessageDescriptor,
+        parse_options: &ParseOptions,
+    ) -> MessageSeed {
+        MessageSeed {
+            descriptor: descriptor.clone(),
+            parse_options: parse_options.clone(),
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for MessageSeed {
+    type Value = Box<dyn MessageDyn>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Box<dyn MessageDyn>, D::Error> {
+        let mut message = self.descriptor.new_instance();
+        merge_message(&mut *message, &self.parse_options, deserializer)?;
+        message.check_initialized_dyn().map_err(D::Error::custom)?;
+        Ok(message)
+    }
+}
+
+/// Serialize with default print options.
+impl<'a> Serialize for MessageRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MessageSerialize::new(&**self).serialize(serializer)
+    }
+}
+
+/// Implementation of `serde::Serialize::serialize` for generated messages.
+pub fn serialize_message<S: Serializer>(
+    message: &dyn MessageDyn,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    MessageSerialize::new(message).serialize(serializer)
+}
+
+/// Implementation of `serde::Deserialize::deserialize` for generated messages.
+pub fn deserialize_message<'de, M: Message, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<M, D::Error> {
+    let mut message = M::new();
+    merge_message(&mut message, &ParseOptions::default(), deserializer)?;
+    message.check_initialized().map_err(D::Error::custom)?;
+    Ok(message)
+}
+
+fn is_wrapper(message: &dyn MessageDyn) -> bool {
+    message.downcast_ref::<DoubleValue>().is_some()
+        || message.downcast_ref::<FloatValue>().is_some()
+        || message.downcast_ref::<Int64Value>().is_some()
+        || message.downcast_ref::<UInt64Value>().is_some()
+        || message.downcast_ref::<Int32Value>().is_some()
+        || message.downcast_ref::<UInt32Value>().is_some()
+        || message.downcast_ref::<BoolValue>().is_some()
+        || message.downcast_ref::<StringValue>().is_some()
+        || message.downcast_ref::<BytesValue>().is_some()
+}
+
+/// Field holding the value of a well-known wrapper type.
+fn wrapper_value_field(message: &dyn MessageDyn) -> FieldDescriptor {
+    message
+        .descriptor_dyn()
+        .get_field_by_name("value")
+        .expect("wrapper type must have `value` field")
+}
+
+/// Protobuf JSON string for NaN and infinities, which are not JSON numbers.
+fn non_finite_float_str(v: f64) -> Option<&'static str> {
+    if v.is_nan() {
+        Some(float::PROTOBUF_JSON_NAN)
+    } else if v.is_infinite() && v > 0.0 {
+        Some(float::PROTOBUF_JSON_INF)
+    } else if v.is_infinite() {
+        Some(float::PROTOBUF_JSON_MINUS_INF)
+    } else {
+        None
+    }
+}
+
+fn map_key_to_string(key: &ReflectValueRef) -> String {
+    match key {
+        ReflectValueRef::U32(v) => v.to_string(),
+        ReflectValueRef::U64(v) => v.to_string(),
+        ReflectValueRef::I32(v) => v.to_string(),
+        ReflectValueRef::I64(v) => v.to_string(),
+        ReflectValueRef::Bool(v) => v.to_string(),
+        ReflectValueRef::String(v) => (*v).to_owned(),
+        ReflectValueRef::F32(..)
+        | ReflectValueRef::F64(..)
+        | ReflectValueRef::Bytes(..)
+        | ReflectValueRef::Enum(..)
+        | ReflectValueRef::Message(..) => panic!("{:?} cannot be a map key", key),
+    }
+}
+
+struct SerializeMessage<'a> {
+    message: &'a dyn MessageDyn,
+    options: &'a PrintOptions,
+}
+
+impl<'a> SerializeMessage<'a> {
+    fn nested<'b>(&self, message: &'b dyn MessageDyn) -> SerializeMessage<'b>
+    where
+        'a: 'b,
+    {
+        SerializeMessage {
+            message,
+            options: self.options,
+        }
+    }
+
+    fn value<'b>(&self, value: ReflectValueRef<'b>) -> SerializeValue<'b>
+    where
+        'a: 'b,
+    {
+        SerializeValue {
+            value,
+            options: self.options,
+        }
+    }
+
+    fn serialize_wk_value<S: Serializer>(
+        &self,
+        value: &Value,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value.kind {
+            // None should not be possible here, but it's better to print null than crash
+            None | Some(value::Kind::null_value(..)) => serializer.serialize_unit(),
+            Some(value::Kind::bool_value(b)) => serializer.serialize_bool(b),
+            Some(value::Kind::number_value(n)) => {
+                self.value(ReflectValueRef::F64(n)).serialize(serializer)
+            }
+            Some(value::Kind::string_value(ref s)) => serializer.serialize_str(s),
+            Some(value::Kind::struct_value(ref s)) => self.nested(s).serialize(serializer),
+            Some(value::Kind::list_value(ref l)) => self.nested(l).serialize(serializer),
+        }
+    }
+
+    fn serialize_regular_message<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let message = self.message;
+        let descriptor = message.descriptor_dyn();
+
+        let mut map = serializer.serialize_map(None)?;
+        for field in descriptor.fields() {
+            let json_field_name = if self.options.proto_field_name {
+                field.get_name()
+            } else {
+                field.json_name()
+            };
+
+            match field.get_reflect(message) {
+                ReflectFieldRef::Optional(None) => {
+                    if self.options.always_output_default_values {
+                        let is_message = matches!(
+                            field.runtime_field_type(),
+                            RuntimeFieldType::Singular(RuntimeTypeBox::Message(_))
+                        );
+
+                        let is_oneof = field.get_proto().has_oneof_index();
+
+                        if !is_message && !is_oneof {
+                            let v = field.get_singular_field_or_default(message);
+                            map.serialize_entry(json_field_name, &self.value(v))?;
+                        }
+                    }
+                }
+                ReflectFieldRef::Optional(Some(v)) => {
+                    map.serialize_entry(json_field_name, &self.value(v))?;
+                }
+                ReflectFieldRef::Repeated(v) => {
+                    if !v.is_empty() {
+                        let v = SerializeRepeated {
+                            repeated: &v,
+                            options: self.options,
+                        };
+                        map.serialize_entry(json_field_name, &v)?;
+                    }
+                }
+                ReflectFieldRef::Map(v) => {
+                    if !v.is_empty() {
+                        let v = SerializeReflectMap {
+                            map: &v,
+                            options: self.options,
+                        };
+                        map.serialize_entry(json_field_name, &v)?;
+                    }
+                }
+            }
+        }
+        map.end()
+    }
+}
+
+impl<'a> Serialize for SerializeMessage<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let message = self.message;
+        if let Some(generated) =
+            well_known_dynamic::to_generated(message).map_err(S::Error::custom)?
+        {
+            self.nested(&*generated).serialize(serializer)
+        } else if let Some(duration) = message.downcast_ref::<Duration>() {
+            serializer.serialize_str(&duration_to_string(duration))
+        } else if let Some(timestamp) = message.downcast_ref::<Timestamp>() {
+            serializer.serialize_str(&timestamp_to_string(timestamp).map_err(S::Error::custom)?)
+        } else if let Some(field_mask) = message.downcast_ref::<FieldMask>() {
+            serializer.serialize_str(&field_mask.paths.join(","))
+        } else if message.downcast_ref::<Any>().is_some() {
+            Err(S::Error::custom(PrintError(
+                PrintErrorInner::AnyPrintingIsNotImplemented,
+            )))
+        } else if let Some(value) = message.downcast_ref::<Value>() {
+            self.serialize_wk_value(value, serializer)
+        } else if is_wrapper(message) {
+            let v = wrapper_value_field(message).get_singular_field_or_default(message);
+            self.value(v).serialize(serializer)
+        } else if let Some(list) = message.downcast_ref::<ListValue>() {
+            serializer.collect_seq(list.values.iter().map(|v| self.nested(v)))
+        } else if let Some(value) = message.downcast_ref::<Struct>() {
+            serializer.collect_map(value.fields.iter().map(|(k, v)| (k, self.nested(v))))
+        } else {
+            self.serialize_regular_message(serializer)
+        }
+    }
+}
+
+struct SerializeValue<'a> {
+    value: ReflectValueRef<'a>,
+    options: &'a PrintOptions,
+}
+
+impl<'a> SerializeValue<'a> {
+    fn serialize_enum<S: Serializer>(
+        &self,
+        descriptor: &EnumDescriptor,
+        v: i32,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if self.options.enum_values_int {
+            return serializer.serialize_i32(v);
+        }
+        match descriptor.get_value_by_number(v) {
+            Some(value) if value.cast::<NullValue>().is_some() => serializer.serialize_unit(),
+            Some(value) => serializer.serialize_str(value.get_name()),
+            None => serializer.serialize_i32(v),
+        }
+    }
+}
+
+impl<'a> Serialize for SerializeValue<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.value {
+            ReflectValueRef::U32(v) => serializer.serialize_u32(v),
+            // 64-bit integers are strings in JSON
+            ReflectValueRef::U64(v) => serializer.serialize_str(&v.to_string()),
+            ReflectValueRef::I32(v) => serializer.serialize_i32(v),
+            ReflectValueRef::I64(v) => serializer.serialize_str(&v.to_string()),
+            ReflectValueRef::F32(v) => match non_finite_float_str(v as f64) {
+                Some(s) => serializer.serialize_str(s),
+                None => serializer.serialize_f32(v),
+            },
+            ReflectValueRef::F64(v) => match non_finite_float_str(v) {
+                Some(s) => serializer.serialize_str(s),
+                None => serializer.serialize_f64(v),
+            },
+            ReflectValueRef::Bool(v) => serializer.serialize_bool(v),
+            ReflectValueRef::String(v) => serializer.serialize_str(v),
+            ReflectValueRef::Bytes(v) => serializer.serialize_str(&base64::encode(v)),
+            ReflectValueRef::Enum(ref d, v) => self.serialize_enum(d, v, serializer),
+            ReflectValueRef::Message(ref m) => SerializeMessage {
+                message: &**m,
+                options: self.options,
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+struct SerializeRepeated<'a> {
+    repeated: &'a ReflectRepeatedRef<'a>,
+    options: &'a PrintOptions,
+}
+
+impl<'a> Serialize for SerializeRepeated<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.repeated.into_iter().map(|value| SerializeValue {
+            value,
+            options: self.options,
+        }))
+    }
+}
+
+struct SerializeReflectMap<'a> {
+    map: &'a ReflectMapRef<'a>,
+    options: &'a PrintOptions,
+}
+
+impl<'a> Serialize for SerializeReflectMap<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.map.into_iter().map(|(k, value)| {
+            let v = SerializeValue {
+                value,
+                options: self.options,
+            };
+            (map_key_to_string(&k), v)
+        }))
+    }
+}
+
+fn merge_message<'de, D: Deserializer<'de>>(
+    message: &mut dyn MessageDyn,
+    options: &ParseOptions,
+    deserializer: D,
+) -> Result<(), D::Error> {
+    if let Some(mut generated) =
+        well_known_dynamic::to_generated(message).map_err(D::Error::custom)?
+    {
+        merge_message(&mut *generated, options, deserializer)?;
+        return well_known_dynamic::from_generated(message, &*generated).map_err(D::Error::custom);
+    }
+
+    if let Some(duration) = message.downcast_mut::<Duration>() {
+        let s = String::deserialize(deserializer)?;
+        return merge_duration_from_str(duration, &s).map_err(D::Error::custom);
+    }
+
+    if let Some(timestamp) = message.downcast_mut::<Timestamp>() {
+        let s = String::deserialize(deserializer)?;
+        return merge_timestamp_from_str(timestamp, &s).map_err(D::Error::custom);
+    }
+
+    if let Some(field_mask) = message.downcast_mut::<FieldMask>() {
+        let s = String::deserialize(deserializer)?;
+        merge_field_mask_from_str(field_mask, &s);
+        return Ok(());
+    }
+
+    if let Some(value) = message.downcast_mut::<Value>() {
+        value.kind = Some(deserializer.deserialize_any(WkValueVisitor { options })?);
+        return Ok(());
+    }
+
+    if message.downcast_mut::<Any>().is_some() {
+        return Err(D::Error::custom(ParseErrorWithoutLoc(
+            ParseErrorWithoutLocInner::AnyParsingIsNotImplemented,
+        )));
+    }
+
+    if is_wrapper(message) {
+        let field = wrapper_value_field(message);
+        return FieldSeed {
+            message,
+            field: &field,
+            options,
+        }
+        .deserialize(deserializer);
+    }
+
+    if let Some(list) = message.downcast_mut::<ListValue>() {
+        list.values.clear();
+        return deserializer.deserialize_option(WkListValueVisitor { list, options });
+    }
+
+    if let Some(value) = message.downcast_mut::<Struct>() {
+        value.fields.clear();
+        return deserializer.deserialize_option(WkStructVisitor { value, options });
+    }
+
+    deserializer.deserialize_map(MessageVisitor { message, options })
+}
+
+struct MessageVisitor<'a> {
+    message: &'a mut dyn MessageDyn,
+    options: &'a ParseOptions,
+}
+
+impl<'a, 'de> de::Visitor<'de> for MessageVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "message {}", self.message.descriptor_dyn().full_name())
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let descriptor = self.message.descriptor_dyn();
+        while let Some(field_name) = map.next_key::<String>()? {
+            // Proto3 JSON parsers are required to accept both
+            // the converted `lowerCamelCase` name and the proto field name.
+            match descriptor.get_field_by_name_or_json_name(&field_name) {
+                Some(field) => map.next_value_seed(FieldSeed {
+                    message: &mut *self.message,
+                    field: &field,
+                    options: self.options,
+                })?,
+                None if self.options.ignore_unknown_fields => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+                None => {
+                    return Err(A::Error::custom(ParseErrorWithoutLoc(
+                        ParseErrorWithoutLocInner::UnknownFieldName(field_name),
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct FieldSeed<'a> {
+    message: &'a mut dyn MessageDyn,
+    field: &'a FieldDescriptor,
+    options: &'a ParseOptions,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for FieldSeed<'a> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let FieldSeed {
+            message,
+            field,
+            options,
+        } = self;
+        match field.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => {
+                let v = ValueSeed { t: &t, options }.deserialize(deserializer)?;
+                field.set_singular_field(message, v);
+                Ok(())
+            }
+            RuntimeFieldType::Repeated(t) => {
+                field.mut_repeated(message).clear();
+                deserializer.deserialize_option(RepeatedVisitor {
+                    message,
+                    field,
+                    t: &t,
+                    options,
+                })
+            }
+            RuntimeFieldType::Map(kt, vt) => {
+                field.mut_map(message).clear();
+                deserializer.deserialize_option(MapVisitor {
+                    message,
+                    field,
+                    kt: &kt,
+                    vt: &vt,
+                    options,
+                })
+            }
+        }
+    }
+}
+
+/// Repeated field, `null` is accepted as empty list.
+struct RepeatedVisitor<'a> {
+    message: &'a mut dyn MessageDyn,
+    field: &'a FieldDescriptor,
+    t: &'a RuntimeTypeBox,
+    options: &'a ParseOptions,
+}
+
+impl<'a, 'de> de::Visitor<'de> for RepeatedVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "list of {}", self.t)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let RepeatedVisitor {
+            message,
+            field,
+            t,
+            options,
+        } = self;
+        let mut repeated = field.mut_repeated(message);
+        while let Some(v) = seq.next_element_seed(ValueSeed { t, options })? {
+            repeated.push(v);
+        }
+        Ok(())
+    }
+}
+
+/// Map field, `null` is accepted as empty map.
+struct MapVisitor<'a> {
+    message: &'a mut dyn MessageDyn,
+    field: &'a FieldDescriptor,
+    kt: &'a RuntimeTypeBox,
+    vt: &'a RuntimeTypeBox,
+    options: &'a ParseOptions,
+}
+
+impl<'a, 'de> de::Visitor<'de> for MapVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "map of {} to {}", self.kt, self.vt)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut access: A) -> Result<(), A::Error> {
+        let mut map = self.field.mut_map(self.message);
+        while let Some(k) = access.next_key::<String>()? {
+            let k = parse_map_key(k, self.kt).map_err(A::Error::custom)?;
+            let v = access.next_value_seed(ValueSeed {
+                t: self.vt,
+                options: self.options,
+            })?;
+            map.insert(k, v);
+        }
+        Ok(())
+    }
+}
+
+struct ValueSeed<'a> {
+    t: &'a RuntimeTypeBox,
+    options: &'a ParseOptions,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ValueSeed<'a> {
+    type Value = ReflectValueBox;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<ReflectValueBox, D::Error> {
+        match self.t {
+            RuntimeTypeBox::Message(d) => {
+                let mut message = d.new_instance();
+                merge_message(&mut *message, self.options, deserializer)?;
+                Ok(ReflectValueBox::Message(message))
+            }
+            RuntimeTypeBox::Enum(e) if e.is::<NullValue>() => {
+                <()>::deserialize(deserializer)?;
+                Ok(ReflectValueBox::from(NullValue::NULL_VALUE.descriptor()))
+            }
+            t => deserializer.deserialize_any(ScalarVisitor { t }),
+        }
+    }
+}
+
+/// Value of a field of any type except message.
+struct ScalarVisitor<'a> {
+    t: &'a RuntimeTypeBox,
+}
+
+impl<'a> ScalarVisitor<'a> {
+    fn parse_number<E: de::Error>(&self, s: &str) -> Result<ReflectValueBox, E> {
+        let r = match self.t {
+            RuntimeTypeBox::I32 => i32::from_string(s).map(ReflectValueBox::I32),
+            RuntimeTypeBox::I64 => i64::from_string(s).map(ReflectValueBox::I64),
+            RuntimeTypeBox::U32 => u32::from_string(s).map(ReflectValueBox::U32),
+            RuntimeTypeBox::U64 => u64::from_string(s).map(ReflectValueBox::U64),
+            RuntimeTypeBox::F32 => f32::from_string(s).map(ReflectValueBox::F32),
+            RuntimeTypeBox::F64 => f64::from_string(s).map(ReflectValueBox::F64),
+            RuntimeTypeBox::Enum(e) => i32::from_string(s)
+                .and_then(|n| enum_value_by_number(e, n).map(ReflectValueBox::from)),
+            _ => return Err(E::invalid_type(Unexpected::Other("number"), self)),
+        };
+        r.map_err(E::custom)
+    }
+}
+
+impl<'a, 'de> de::Visitor<'de> for ScalarVisitor<'a> {
+    type Value = ReflectValueBox;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JSON value of type {}", self.t)
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<ReflectValueBox, E> {
+        match self.t {
+            RuntimeTypeBox::Bool => Ok(ReflectValueBox::Bool(v)),
+            _ => Err(E::invalid_type(Unexpected::Bool(v), &self)),
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<ReflectValueBox, E> {
+        self.parse_number(&v.to_string())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<ReflectValueBox, E> {
+        self.parse_number(&v.to_string())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<ReflectValueBox, E> {
+        match self.t {
+            RuntimeTypeBox::F32 => Ok(ReflectValueBox::F32(v as f32)),
+            RuntimeTypeBox::F64 => Ok(ReflectValueBox::F64(v)),
+            _ => Err(E::invalid_type(Unexpected::Float(v), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<ReflectValueBox, E> {
+        match self.t {
+            RuntimeTypeBox::String => Ok(ReflectValueBox::String(v.to_owned())),
+            RuntimeTypeBox::VecU8 => base64::decode(v)
+                .map(ReflectValueBox::Bytes)
+                .map_err(E::custom),
+            RuntimeTypeBox::Enum(e) => enum_value_by_name(v.to_owned(), e)
+                .map(ReflectValueBox::from)
+                .map_err(E::custom),
+            RuntimeTypeBox::Bool | RuntimeTypeBox::Message(..) => {
+                Err(E::invalid_type(Unexpected::Str(v), &self))
+            }
+            // Numbers can be quoted
+            _ => self.parse_number(v),
+        }
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<ReflectValueBox, E> {
+        match self.t {
+            RuntimeTypeBox::VecU8 => Ok(ReflectValueBox::Bytes(v.to_vec())),
+            _ => Err(E::invalid_type(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<ReflectValueBox, E> {
+        match self.t {
+            RuntimeTypeBox::VecU8 => Ok(ReflectValueBox::Bytes(v)),
+            _ => Err(E::invalid_type(Unexpected::Bytes(&v), &self)),
+        }
+    }
+}
+
+/// `google.protobuf.Value` is any JSON value.
+struct WkValueVisitor<'a> {
+    options: &'a ParseOptions,
+}
+
+impl<'a, 'de> de::Visitor<'de> for WkValueVisitor<'a> {
+    type Value = value::Kind;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JSON value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<value::Kind, E> {
+        Ok(value::Kind::null_value(NullValue::NULL_VALUE.into()))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<value::Kind, E> {
+        self.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<value::Kind, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<value::Kind, E> {
+        Ok(value::Kind::bool_value(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<value::Kind, E> {
+        Ok(value::Kind::number_value(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<value::Kind, E> {
+        Ok(value::Kind::number_value(v as f64))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<value::Kind, E> {
+        Ok(value::Kind::number_value(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<value::Kind, E> {
+        Ok(value::Kind::string_value(v.to_owned()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<value::Kind, E> {
+        Ok(value::Kind::string_value(v))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<value::Kind, A::Error> {
+        let mut list = ListValue::new();
+        WkListValueVisitor {
+            list: &mut list,
+            options: self.options,
+        }
+        .visit_seq(seq)?;
+        Ok(value::Kind::list_value(list))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<value::Kind, A::Error> {
+        let mut value = Struct::new();
+        WkStructVisitor {
+            value: &mut value,
+            options: self.options,
+        }
+        .visit_map(map)?;
+        Ok(value::Kind::struct_value(value))
+    }
+}
+
+/// `google.protobuf.ListValue`, `null` is accepted as empty list.
+struct WkListValueVisitor<'a> {
+    list: &'a mut ListValue,
+    options: &'a ParseOptions,
+}
+
+impl<'a, 'de> de::Visitor<'de> for WkListValueVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JSON array")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        loop {
+            let mut value = Value::new();
+            let seed = MessageMergeSeed::with_options(&mut value, self.options);
+            if seq.next_element_seed(seed)?.is_none() {
+                return Ok(());
+            }
+            self.list.values.push(value);
+        }
+    }
+}
+
+/// `google.protobuf.Struct`, `null` is accepted as empty object.
+struct WkStructVisitor<'a> {
+    value: &'a mut Struct,
+    options: &'a ParseOptions,
+}
+
+impl<'a, 'de> de::Visitor<'de> for WkStructVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JSON object")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(k) = map.next_key::<String>()? {
+            let mut value = Value::new();
+            map.next_value_seed(MessageMergeSeed::with_options(&mut value, self.options))?;
+            self.value.fields.insert(k, value);
+        }
+        Ok(())
+    }
+}
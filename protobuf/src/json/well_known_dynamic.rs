@@ -0,0 +1,81 @@
+//! Support of well-known types created from dynamic descriptors,
+//! e. g. from `google/protobuf/duration.proto` loaded at runtime.
+//!
+//! Such messages are converted to generated well-known types through
+//! wire format, so they get the same special JSON representation.
+
+use crate::error::ProtobufResult;
+use crate::message::Message;
+use crate::message_dyn::MessageDyn;
+use crate::reflect::MessageDescriptor;
+use crate::well_known_types::Any;
+use crate::well_known_types::BoolValue;
+use crate::well_known_types::BytesValue;
+use crate::well_known_types::DoubleValue;
+use crate::well_known_types::Duration;
+use crate::well_known_types::FieldMask;
+use crate::well_known_types::FloatValue;
+use crate::well_known_types::Int32Value;
+use crate::well_known_types::Int64Value;
+use crate::well_known_types::ListValue;
+use crate::well_known_types::StringValue;
+use crate::well_known_types::Struct;
+use crate::well_known_types::Timestamp;
+use crate::well_known_types::UInt32Value;
+use crate::well_known_types::UInt64Value;
+use crate::well_known_types::Value;
+
+/// Descriptor of generated well-known type if `descriptor` is a dynamic
+/// descriptor of a well-known type.
+fn generated_descriptor(descriptor: &MessageDescriptor) -> Option<MessageDescriptor> {
+    let generated = match descriptor.full_name() {
+        "google.protobuf.Any" => Any::descriptor_static(),
+        "google.protobuf.Duration" => Duration::descriptor_static(),
+        "google.protobuf.Timestamp" => Timestamp::descriptor_static(),
+        "google.protobuf.FieldMask" => FieldMask::descriptor_static(),
+        "google.protobuf.Value" => Value::descriptor_static(),
+        "google.protobuf.ListValue" => ListValue::descriptor_static(),
+        "google.protobuf.Struct" => Struct::descriptor_static(),
+        "google.protobuf.DoubleValue" => DoubleValue::descriptor_static(),
+        "google.protobuf.FloatValue" => FloatValue::descriptor_static(),
+        "google.protobuf.Int64Value" => Int64Value::descriptor_static(),
+        "google.protobuf.UInt64Value" => UInt64Value::descriptor_static(),
+        "google.protobuf.Int32Value" => Int32Value::descriptor_static(),
+        "google.protobuf.UInt32Value" => UInt32Value::descriptor_static(),
+        "google.protobuf.BoolValue" => BoolValue::descriptor_static(),
+        "google.protobuf.StringValue" => StringValue::descriptor_static(),
+        "google.protobuf.BytesValue" => BytesValue::descriptor_static(),
+        _ => return None,
+    };
+    if generated == *descriptor {
+        None
+    } else {
+        Some(generated)
+    }
+}
+
+/// Copy of a dynamic well-known type message as generated message.
+///
+/// Return `None` if message is not a dynamic well-known type.
+pub(crate) fn to_generated(
+    message: &dyn MessageDyn,
+) -> ProtobufResult<Option<Box<dyn MessageDyn>>> {
+    match generated_descriptor(&message.descriptor_dyn()) {
+        Some(descriptor) => {
+            let mut generated = descriptor.new_instance();
+            generated.merge_from_bytes_dyn(&message.write_to_bytes_dyn()?)?;
+            Ok(Some(generated))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Replace the content of dynamic message with the generated message.
+pub(crate) fn from_generated(
+    message: &mut dyn MessageDyn,
+    generated: &dyn MessageDyn,
+) -> ProtobufResult<()> {
+    let bytes = generated.write_to_bytes_dyn()?;
+    message.clear_dyn();
+    message.merge_from_bytes_dyn(&bytes)
+}
@@ -8,8 +8,12 @@
 #![deny(intra_doc_link_resolution_failure)]
 #![cfg_attr(rustc_nightly, feature(specialization))]
 
+#[cfg(feature = "with-arbitrary")]
+extern crate arbitrary;
 #[cfg(feature = "bytes")]
 extern crate bytes;
+#[cfg(feature = "with-chrono")]
+extern crate chrono;
 #[cfg(feature = "with-serde")]
 extern crate serde;
 #[macro_use]
@@ -17,8 +21,11 @@ extern crate serde;
 extern crate serde_derive;
 pub use crate::clear::Clear;
 pub use crate::enums::ProtobufEnum;
+pub use crate::enums::ProtobufEnumFromI32Error;
 pub use crate::enums::ProtobufEnumOrUnknown;
+pub use crate::enums::ProtobufEnumParseError;
 pub use crate::message::Message;
+pub use crate::message_convert::MessageConvert;
 pub use crate::message_dyn::MessageDyn;
 pub use crate::message_field::MessageField;
 pub use crate::oneof::Oneof;
@@ -30,6 +37,8 @@ pub use crate::unknown::UnknownValue;
 pub use crate::unknown::UnknownValueRef;
 pub use crate::unknown::UnknownValues;
 pub use crate::unknown::UnknownValuesIter;
+pub use crate::well_known_types_util::FieldMaskError;
+pub use crate::well_known_types_util::FieldMaskMergeOptions;
 pub mod wire_format;
 #[cfg(feature = "bytes")]
 pub use crate::chars::Chars;
@@ -49,6 +58,7 @@ pub mod ext;
 pub mod json;
 mod lazy_v2;
 mod message;
+mod message_convert;
 mod message_dyn;
 mod message_field;
 mod oneof;
@@ -229,11 +229,23 @@ pub trait Message: fmt::Debug + Clear + Send + Sync + Sized + 'static {
     /// ```
     fn default_instance() -> &'static Self;
 
+    /// Merge fields of `other` message into this message.
+    ///
+    /// Set singular fields overwrite, repeated fields are appended,
+    /// map entries are overwritten by key, message fields are merged recursively.
+    ///
+    /// # See also
+    ///
+    /// [`MessageDyn::merge_dyn`](crate::MessageDyn::merge_dyn), `dyn` version of this function.
+    fn merge_from_message(&mut self, other: &Self) {
+        <dyn MessageDyn>::merge_dyn(self, other)
+    }
+
     /// Reflective equality.
     ///
     /// # See also
     ///
-    /// [`dyn Message::reflect_eq_dyn()`], `dyn` version of this function.
+    /// [`MessageDyn::reflect_eq_dyn`](crate::MessageDyn::reflect_eq_dyn), `dyn` version of this function.
     fn reflect_eq(&self, other: &Self, mode: &ReflectEqMode) -> bool {
         MessageDyn::reflect_eq_dyn(self, other, mode)
     }
@@ -0,0 +1,202 @@
+//! Conversion between protobuf messages and custom Rust types.
+
+use std::fmt;
+use std::time::SystemTime;
+
+use crate::error::ProtobufError;
+use crate::error::ProtobufResult;
+use crate::message::Message;
+use crate::reflect::MessageRef;
+use crate::reflect::ProtobufValue;
+use crate::reflect::ReflectTypeError;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::well_known_types::Duration;
+use crate::well_known_types::Timestamp;
+
+/// Rust type which is stored in generated structs instead of protobuf message `M`.
+///
+/// Code generator can be configured to map certain message types to custom
+/// Rust types (see `Customize::type_mapping` in `protobuf-codegen`).
+/// Generated `merge_from`, `compute_size`, `write_to_with_cached_sizes`
+/// and reflection accessors convert values through this trait.
+///
+/// The message is a type parameter, so the trait can be implemented
+/// for a foreign type next to the generated message:
+///
+/// ```ignore
+/// impl protobuf::MessageConvert<crate::ids::Uuid> for uuid::Uuid {
+///     // ...
+/// }
+/// ```
+pub trait MessageConvert<M: Message + ProtobufValue>:
+    Clone + PartialEq + fmt::Debug + Send + Sync + Sized + 'static
+{
+    /// Convert a message into this type.
+    ///
+    /// Called when the field is parsed or set through reflection.
+    fn from_message(message: M) -> ProtobufResult<Self>;
+
+    /// Convert this value into a message.
+    ///
+    /// Called when the field is serialized or read through reflection.
+    fn to_message(&self) -> M;
+}
+
+/// Reflective message value of a custom type value.
+pub(crate) fn to_value_ref<M, T>(value: &T) -> ReflectValueRef<'static>
+where
+    M: Message + ProtobufValue,
+    T: MessageConvert<M>,
+{
+    let message: M = value.to_message();
+    ReflectValueRef::Message(MessageRef::new_owned(Box::new(message)))
+}
+
+/// Convert reflective message value to a custom type.
+pub(crate) fn from_value_box<M, T>(value: ReflectValueBox) -> Result<T, ReflectTypeError>
+where
+    M: Message + ProtobufValue,
+    T: MessageConvert<M>,
+{
+    let message: M = match value.downcast() {
+        Ok(message) => message,
+        Err(value) => {
+            return Err(ReflectTypeError::Mismatch {
+                expected: M::runtime_type_box(),
+                actual: value.get_type(),
+            })
+        }
+    };
+    T::from_message(message).map_err(|e| match e {
+        ProtobufError::MessageConvert(e) => ReflectTypeError::Convert(e),
+        e => ReflectTypeError::Convert(e.to_string()),
+    })
+}
+
+const NANOS_PER_SECOND: i32 = 1_000_000_000;
+
+/// `google.protobuf.Timestamp` as [`SystemTime`].
+impl MessageConvert<Timestamp> for SystemTime {
+    fn from_message(message: Timestamp) -> ProtobufResult<SystemTime> {
+        if message.nanos < 0 || message.nanos >= NANOS_PER_SECOND {
+            return Err(ProtobufError::MessageConvert(format!(
+                "Timestamp nanos out of range: {}",
+                message.nanos
+            )));
+        }
+        let since_epoch = std::time::Duration::new(message.seconds.unsigned_abs(), 0);
+        let nanos = std::time::Duration::from_nanos(message.nanos as u64);
+        let time = if message.seconds >= 0 {
+            SystemTime::UNIX_EPOCH
+                .checked_add(since_epoch)
+                .and_then(|t| t.checked_add(nanos))
+        } else {
+            SystemTime::UNIX_EPOCH
+                .checked_sub(since_epoch)
+                .and_then(|t| t.checked_add(nanos))
+        };
+        time.ok_or_else(|| {
+            ProtobufError::MessageConvert(format!(
+                "Timestamp is outside of SystemTime range: {}s",
+                message.seconds
+            ))
+        })
+    }
+
+    fn to_message(&self) -> Timestamp {
+        Timestamp::from(*self)
+    }
+}
+
+/// `google.protobuf.Duration` as [`std::time::Duration`].
+///
+/// Negative durations cannot be represented and fail to convert.
+impl MessageConvert<Duration> for std::time::Duration {
+    fn from_message(message: Duration) -> ProtobufResult<std::time::Duration> {
+        if message.seconds < 0 || message.nanos < 0 || message.nanos >= NANOS_PER_SECOND {
+            return Err(ProtobufError::MessageConvert(format!(
+                "Duration cannot be represented as std::time::Duration: {}s {}ns",
+                message.seconds, message.nanos
+            )));
+        }
+        Ok(std::time::Duration::new(
+            message.seconds as u64,
+            message.nanos as u32,
+        ))
+    }
+
+    fn to_message(&self) -> Duration {
+        Duration::from(*self)
+    }
+}
+
+/// `google.protobuf.Timestamp` as `chrono::DateTime<Utc>`.
+#[cfg(feature = "with-chrono")]
+impl MessageConvert<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from_message(message: Timestamp) -> ProtobufResult<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+
+        if message.nanos < 0 || message.nanos >= NANOS_PER_SECOND {
+            return Err(ProtobufError::MessageConvert(format!(
+                "Timestamp nanos out of range: {}",
+                message.nanos
+            )));
+        }
+        match chrono::Utc.timestamp_opt(message.seconds, message.nanos as u32) {
+            chrono::LocalResult::Single(t) => Ok(t),
+            _ => Err(ProtobufError::MessageConvert(format!(
+                "Timestamp is outside of DateTime range: {}s",
+                message.seconds
+            ))),
+        }
+    }
+
+    fn to_message(&self) -> Timestamp {
+        Timestamp {
+            seconds: self.timestamp(),
+            nanos: self.timestamp_subsec_nanos() as i32,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MessageConvert;
+    use crate::well_known_types::Duration;
+    use crate::well_known_types::Timestamp;
+    use std::time::SystemTime;
+
+    #[test]
+    fn system_time() {
+        let t = SystemTime::UNIX_EPOCH - std::time::Duration::from_millis(3_200);
+        let m: Timestamp = t.to_message();
+        assert_eq!(-4, m.seconds);
+        assert_eq!(800_000_000, m.nanos);
+        assert_eq!(t, SystemTime::from_message(m).unwrap());
+
+        let t = SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(3_200);
+        let m: Timestamp = t.to_message();
+        assert_eq!(t, SystemTime::from_message(m).unwrap());
+
+        let malformed = Timestamp {
+            nanos: -1,
+            ..Default::default()
+        };
+        assert!(SystemTime::from_message(malformed).is_err());
+    }
+
+    #[test]
+    fn std_duration() {
+        let d = std::time::Duration::from_millis(4_123);
+        let m: Duration = d.to_message();
+        assert_eq!(d, std::time::Duration::from_message(m).unwrap());
+
+        let negative = Duration {
+            seconds: -1,
+            ..Default::default()
+        };
+        assert!(std::time::Duration::from_message(negative).is_err());
+    }
+}
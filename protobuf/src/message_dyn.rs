@@ -26,6 +26,9 @@ pub trait MessageDyn: Any + fmt::Debug + Send + Sync + 'static {
     /// Compute (and cache) the message size.
     fn compute_size_dyn(&self) -> u32;
 
+    /// Get size previously computed by `compute_size_dyn`.
+    fn get_cached_size_dyn(&self) -> u32;
+
     /// True iff all required fields are initialized.
     /// Always returns `true` for protobuf 3.
     fn is_initialized_dyn(&self) -> bool;
@@ -34,6 +37,9 @@ pub trait MessageDyn: Any + fmt::Debug + Send + Sync + 'static {
     fn get_unknown_fields_dyn(&self) -> &UnknownFields;
     /// Get a mutable reference to unknown fields.
     fn mut_unknown_fields_dyn(&mut self) -> &mut UnknownFields;
+
+    /// Clear all fields including unknown fields.
+    fn clear_dyn(&mut self);
 }
 
 impl<M: Message> MessageDyn for M {
@@ -53,6 +59,10 @@ impl<M: Message> MessageDyn for M {
         self.compute_size()
     }
 
+    fn get_cached_size_dyn(&self) -> u32 {
+        self.get_cached_size()
+    }
+
     fn is_initialized_dyn(&self) -> bool {
         self.is_initialized()
     }
@@ -64,6 +74,10 @@ impl<M: Message> MessageDyn for M {
     fn mut_unknown_fields_dyn(&mut self) -> &mut UnknownFields {
         self.mut_unknown_fields()
     }
+
+    fn clear_dyn(&mut self) {
+        self.clear()
+    }
 }
 
 impl dyn MessageDyn {
@@ -223,6 +237,18 @@ impl dyn MessageDyn {
         self.descriptor_dyn().clone_message(self)
     }
 
+    /// Merge fields of `other` message into this message.
+    ///
+    /// Result is the same as merging serialized `other` with
+    /// [`merge_from_bytes_dyn`](Self::merge_from_bytes_dyn), but without serialization.
+    ///
+    /// # Panics
+    ///
+    /// If messages have different types.
+    pub fn merge_dyn(&mut self, other: &dyn MessageDyn) {
+        self.descriptor_dyn().merge(self, other)
+    }
+
     /// Reflectively compare the messages.
     pub fn reflect_eq_dyn(&self, other: &dyn MessageDyn, mode: &ReflectEqMode) -> bool {
         MessageDescriptor::reflect_eq_maybe_unrelated(self, other, mode)
@@ -245,6 +271,9 @@ impl PartialEq for Box<dyn MessageDyn> {
 mod test {
     use crate::descriptor::FileDescriptorProto;
     use crate::message::Message;
+    use crate::well_known_types::Duration;
+    use crate::well_known_types::Struct;
+    use crate::well_known_types::Value;
     use crate::MessageDyn;
 
     #[test]
@@ -275,4 +304,85 @@ mod test {
         let mut d: Box<dyn MessageDyn> = Box::new(m);
         let mut _c: Box<FileDescriptorProto> = d.downcast_box().unwrap();
     }
+
+    #[test]
+    fn merge_from_message() {
+        let mut a = FileDescriptorProto::new();
+        a.set_name("a.proto".to_owned());
+        a.set_package("a".to_owned());
+        a.dependency.push("x.proto".to_owned());
+        a.options
+            .set_default()
+            .set_java_package("a.java".to_owned());
+        a.mut_unknown_fields().add_varint(1000, 1);
+
+        let mut b = FileDescriptorProto::new();
+        b.set_name("b.proto".to_owned());
+        b.dependency.push("y.proto".to_owned());
+        b.options.set_default().set_go_package("b.go".to_owned());
+        b.mut_unknown_fields().add_varint(1000, 2);
+
+        let mut expected = a.clone();
+        expected
+            .merge_from_bytes(&b.write_to_bytes().unwrap())
+            .unwrap();
+
+        a.merge_from_message(&b);
+        assert_eq!(expected, a);
+        assert_eq!("b.proto", a.get_name());
+        assert_eq!("a", a.get_package());
+        assert_eq!(vec!["x.proto", "y.proto"], a.dependency);
+        assert_eq!("a.java", a.options.get_or_default().get_java_package());
+        assert_eq!("b.go", a.options.get_or_default().get_go_package());
+    }
+
+    #[test]
+    fn merge_dyn_proto3() {
+        let value = |s: &str| {
+            let mut v = Value::new();
+            v.set_string_value(s.to_owned());
+            v
+        };
+
+        let mut a = Struct::new();
+        a.fields.insert("x".to_owned(), value("1"));
+        a.fields.insert("y".to_owned(), value("2"));
+        let mut b = Struct::new();
+        b.fields.insert("y".to_owned(), value("3"));
+        b.fields.insert("z".to_owned(), value("4"));
+
+        (&mut a as &mut dyn MessageDyn).merge_dyn(&b);
+        assert_eq!(value("1"), a.fields["x"]);
+        assert_eq!(value("3"), a.fields["y"]);
+        assert_eq!(value("4"), a.fields["z"]);
+
+        // oneof switches
+        let mut v = value("s");
+        let mut n = Value::new();
+        n.set_number_value(1.0);
+        v.merge_from_message(&n);
+        assert!(!v.has_string_value());
+        assert_eq!(1.0, v.get_number_value());
+
+        // oneof message merges
+        let mut sa = Value::new();
+        sa.mut_struct_value()
+            .fields
+            .insert("a".to_owned(), value("a"));
+        let mut sb = Value::new();
+        sb.mut_struct_value()
+            .fields
+            .insert("b".to_owned(), value("b"));
+        sa.merge_from_message(&sb);
+        assert_eq!(2, sa.get_struct_value().fields.len());
+
+        // default proto3 values do not overwrite
+        let mut da = Duration::new();
+        da.seconds = 5;
+        let mut db = Duration::new();
+        db.nanos = 3;
+        da.merge_from_message(&db);
+        assert_eq!(5, da.seconds);
+        assert_eq!(3, da.nanos);
+    }
 }
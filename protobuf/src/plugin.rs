@@ -23,7 +23,7 @@
 
 ///  The version number of protocol compiler.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Version {
     // message fields
     major: ::std::option::Option<i32>,
@@ -218,6 +218,22 @@ impl crate::Message for Version {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.major.is_some() {
+            self.major = other.major.clone();
+        }
+        if other.minor.is_some() {
+            self.minor = other.minor.clone();
+        }
+        if other.patch.is_some() {
+            self.patch = other.patch.clone();
+        }
+        if other.suffix.is_some() {
+            self.suffix = other.suffix.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -311,7 +327,7 @@ impl crate::reflect::ProtobufValue for Version {
 
 ///  An encoded CodeGeneratorRequest is written to the plugin's stdin.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct CodeGeneratorRequest {
     // message fields
     ///  The .proto files that were explicitly listed on the command-line.  The
@@ -464,6 +480,18 @@ impl crate::Message for CodeGeneratorRequest {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        self.file_to_generate.extend(other.file_to_generate.iter().cloned());
+        if other.parameter.is_some() {
+            self.parameter = other.parameter.clone();
+        }
+        self.proto_file.extend(other.proto_file.iter().cloned());
+        if let ::std::option::Option::Some(v) = other.compiler_version.as_ref() {
+            self.compiler_version.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -559,7 +587,7 @@ impl crate::reflect::ProtobufValue for CodeGeneratorRequest {
 
 ///  The plugin writes an encoded CodeGeneratorResponse to stdout.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct CodeGeneratorResponse {
     // message fields
     ///  Error message.  If non-empty, code generation failed.  The plugin process
@@ -712,6 +740,17 @@ impl crate::Message for CodeGeneratorResponse {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.error.is_some() {
+            self.error = other.error.clone();
+        }
+        if other.supported_features.is_some() {
+            self.supported_features = other.supported_features.clone();
+        }
+        self.file.extend(other.file.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -800,7 +839,7 @@ impl crate::reflect::ProtobufValue for CodeGeneratorResponse {
 pub mod code_generator_response {
     ///  Represents a single generated file.
     #[derive(PartialEq,Clone,Default)]
-    #[cfg_attr(serde, derive(Serialize, Deserialize))]
+    #[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
     pub struct File {
         // message fields
         ///  The file name, relative to the output directory.  The name must not
@@ -1044,6 +1083,19 @@ pub mod code_generator_response {
             ::std::result::Result::Ok(())
         }
 
+        fn merge_from_message(&mut self, other: &Self) {
+            if other.name.is_some() {
+                self.name = other.name.clone();
+            }
+            if other.insertion_point.is_some() {
+                self.insertion_point = other.insertion_point.clone();
+            }
+            if other.content.is_some() {
+                self.content = other.content.clone();
+            }
+            self.unknown_fields.merge_from(&other.unknown_fields);
+        }
+
         // Compute sizes of nested messages
         #[allow(unused_variables)]
         fn compute_size(&self) -> u32 {
@@ -1172,10 +1224,46 @@ pub mod code_generator_response {
     }
 
     impl Feature {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [Feature] = &[
+            Feature::FEATURE_NONE,
+            Feature::FEATURE_PROTO3_OPTIONAL,
+        ];
+
         pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
             crate::reflect::GeneratedEnumDescriptorData::new_2::<Feature>("CodeGeneratorResponse.Feature", 0)
         }
     }
+
+    impl ::std::str::FromStr for Feature {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "FEATURE_NONE" => ::std::result::Result::Ok(Feature::FEATURE_NONE),
+                "FEATURE_PROTO3_OPTIONAL" => ::std::result::Result::Ok(Feature::FEATURE_PROTO3_OPTIONAL),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.compiler.CodeGeneratorResponse.Feature", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for Feature {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                Feature::FEATURE_NONE => "FEATURE_NONE",
+                Feature::FEATURE_PROTO3_OPTIONAL => "FEATURE_PROTO3_OPTIONAL",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for Feature {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.compiler.CodeGeneratorResponse.Feature", value))
+        }
+    }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
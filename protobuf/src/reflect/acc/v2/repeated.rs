@@ -1,6 +1,7 @@
 use std::{fmt, marker};
 
 use crate::message::Message;
+use crate::message_convert::MessageConvert;
 
 use crate::message_dyn::MessageDyn;
 use crate::reflect::acc::v2::AccessorV2;
@@ -8,6 +9,7 @@ use crate::reflect::acc::FieldAccessor;
 use crate::reflect::repeated::ReflectRepeated;
 use crate::reflect::repeated::ReflectRepeatedMut;
 use crate::reflect::repeated::ReflectRepeatedRef;
+use crate::reflect::repeated::VecOfConverted;
 use crate::reflect::ProtobufValue;
 use crate::reflect::RuntimeTypeBox;
 
@@ -57,6 +59,33 @@ where
     }
 }
 
+struct RepeatedFieldGetMutConvertedImpl<M, V, T>
+where
+    M: Message + 'static,
+    V: Message + ProtobufValue,
+    T: MessageConvert<V>,
+{
+    get_field: for<'a> fn(&'a M) -> &'a Vec<T>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut Vec<T>,
+    _marker: marker::PhantomData<V>,
+}
+
+impl<M, V, T> RepeatedFieldGetMut<M, dyn ReflectRepeated>
+    for RepeatedFieldGetMutConvertedImpl<M, V, T>
+where
+    M: Message + 'static,
+    V: Message + ProtobufValue,
+    T: MessageConvert<V>,
+{
+    fn get_field<'a>(&self, m: &'a M) -> &'a dyn ReflectRepeated {
+        VecOfConverted::<V, T>::from_vec((self.get_field)(m)) as &dyn ReflectRepeated
+    }
+
+    fn mut_field<'a>(&self, m: &'a mut M) -> &'a mut dyn ReflectRepeated {
+        VecOfConverted::<V, T>::from_vec_mut((self.mut_field)(m)) as &mut dyn ReflectRepeated
+    }
+}
+
 struct RepeatedFieldAccessorImpl<M, V>
 where
     M: Message,
@@ -111,3 +140,29 @@ where
         }),
     )
 }
+
+/// Make accessor for `Vec` field of custom type values stored instead of messages
+pub fn make_vec_converted_message_accessor<M, V, T>(
+    name: &'static str,
+    get_vec: for<'a> fn(&'a M) -> &'a Vec<T>,
+    mut_vec: for<'a> fn(&'a mut M) -> &'a mut Vec<T>,
+) -> FieldAccessor
+where
+    M: Message + 'static,
+    V: Message + ProtobufValue,
+    T: MessageConvert<V>,
+{
+    FieldAccessor::new_v2(
+        name,
+        AccessorV2::Repeated(RepeatedFieldAccessorHolder {
+            accessor: Box::new(RepeatedFieldAccessorImpl::<M, V> {
+                fns: Box::new(RepeatedFieldGetMutConvertedImpl::<M, V, T> {
+                    get_field: get_vec,
+                    mut_field: mut_vec,
+                    _marker: marker::PhantomData,
+                }),
+                _marker: marker::PhantomData,
+            }),
+        }),
+    )
+}
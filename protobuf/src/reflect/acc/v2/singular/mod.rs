@@ -4,13 +4,18 @@ use std::marker;
 use crate::enums::ProtobufEnum;
 use crate::enums::ProtobufEnumOrUnknown;
 use crate::message::Message;
+use crate::message_convert;
+use crate::message_convert::MessageConvert;
 use crate::message_dyn::MessageDyn;
 use crate::reflect::acc::v2::AccessorV2;
 use crate::reflect::acc::FieldAccessor;
+use crate::reflect::message::message_mut::MessageMut;
 use crate::reflect::runtime_types::RuntimeTypeWithDeref;
+use crate::reflect::value::value_ref::ReflectValueGuard;
 use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::MessageRef;
 use crate::reflect::ProtobufValue;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::MessageField;
@@ -23,6 +28,7 @@ trait OptionLike<T> {
     fn as_option_ref(&self) -> Option<&T>;
     fn as_option_mut(&mut self) -> Option<&mut T>;
     fn set_value(&mut self, value: T);
+    fn clear_value(&mut self);
 }
 
 impl<T> OptionLike<T> for Option<T> {
@@ -37,6 +43,10 @@ impl<T> OptionLike<T> for Option<T> {
     fn set_value(&mut self, value: T) {
         *self = Some(value);
     }
+
+    fn clear_value(&mut self) {
+        *self = None;
+    }
 }
 
 impl<T> OptionLike<T> for MessageField<T> {
@@ -51,6 +61,10 @@ impl<T> OptionLike<T> for MessageField<T> {
     fn set_value(&mut self, value: T) {
         *self = MessageField::some(value);
     }
+
+    fn clear_value(&mut self) {
+        *self = MessageField::none();
+    }
 }
 
 /// This trait should not be used directly, use `FieldDescriptor` instead
@@ -58,7 +72,17 @@ pub(crate) trait SingularFieldAccessor: Send + Sync + 'static {
     fn get_field<'a>(&self, m: &'a dyn MessageDyn) -> Option<ReflectValueRef<'a>>;
     fn get_field_or_default<'a>(&self, m: &'a dyn MessageDyn) -> ReflectValueRef<'a>;
     fn mut_field_or_default<'a>(&self, m: &'a mut dyn MessageDyn) -> ReflectValueMut<'a>;
-    fn set_field(&self, m: &mut dyn MessageDyn, value: ReflectValueBox);
+    fn mut_converted_message<'a>(&self, m: &'a mut dyn MessageDyn) -> MessageMut<'a> {
+        match self.mut_field_or_default(m) {
+            ReflectValueMut::Message(m) => MessageMut::new(m),
+        }
+    }
+    fn set_field(
+        &self,
+        m: &mut dyn MessageDyn,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError>;
+    fn clear_field(&self, m: &mut dyn MessageDyn);
 }
 
 pub(crate) struct SingularFieldAccessorHolder {
@@ -84,7 +108,9 @@ trait MutOrDefaultImpl<M>: Send + Sync + 'static {
 }
 
 trait SetImpl<M>: Send + Sync + 'static {
-    fn set_singular_field(&self, m: &mut M, value: ReflectValueBox);
+    fn set_singular_field(&self, m: &mut M, value: ReflectValueBox)
+        -> Result<(), ReflectTypeError>;
+    fn clear_singular_field(&self, m: &mut M);
 }
 
 struct MutOrDefaultUnmplemented<M>
@@ -156,10 +182,19 @@ where
             .mut_singular_field_or_default_impl(m)
     }
 
-    fn set_field(&self, m: &mut dyn MessageDyn, value: ReflectValueBox) {
+    fn set_field(
+        &self,
+        m: &mut dyn MessageDyn,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
         let m = m.downcast_mut().unwrap();
         self.set_impl.set_singular_field(m, value)
     }
+
+    fn clear_field(&self, m: &mut dyn MessageDyn) {
+        let m = m.downcast_mut().unwrap();
+        self.set_impl.clear_singular_field(m)
+    }
 }
 
 struct GetOptionImplFieldPointer<M, V>
@@ -431,7 +466,10 @@ where
     M: Message,
     V: ProtobufValue,
 {
+    has_field: fn(&M) -> bool,
     set_field: for<'a> fn(&'a mut M, V),
+    // Clears the whole `oneof`
+    clear_field: fn(&mut M),
 }
 
 impl<M, V> SetImpl<M> for SetImplFieldPointer<M, V>
@@ -439,8 +477,17 @@ where
     M: Message,
     V: ProtobufValue,
 {
-    fn set_singular_field(&self, m: &mut M, value: ReflectValueBox) {
-        V::set_from_value_box((self.mut_field)(m), value);
+    fn set_singular_field(
+        &self,
+        m: &mut M,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
+        *(self.mut_field)(m) = V::try_from_value_box(value)?;
+        Ok(())
+    }
+
+    fn clear_singular_field(&self, m: &mut M) {
+        *(self.mut_field)(m) = V::default();
     }
 }
 
@@ -450,8 +497,17 @@ where
     V: ProtobufValue,
     O: OptionLike<V> + Sync + Send + 'static,
 {
-    fn set_singular_field(&self, m: &mut M, value: ReflectValueBox) {
-        (self.mut_field)(m).set_value(V::from_value_box(value).expect("wrong type"));
+    fn set_singular_field(
+        &self,
+        m: &mut M,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
+        (self.mut_field)(m).set_value(V::try_from_value_box(value)?);
+        Ok(())
+    }
+
+    fn clear_singular_field(&self, m: &mut M) {
+        (self.mut_field)(m).clear_value();
     }
 }
 
@@ -460,9 +516,19 @@ where
     M: Message,
     V: ProtobufValue,
 {
-    fn set_singular_field(&self, m: &mut M, value: ReflectValueBox) {
-        let value = value.downcast::<V>().expect("message");
-        (self.set_field)(m, value)
+    fn set_singular_field(
+        &self,
+        m: &mut M,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
+        (self.set_field)(m, V::try_from_value_box(value)?);
+        Ok(())
+    }
+
+    fn clear_singular_field(&self, m: &mut M) {
+        if (self.has_field)(m) {
+            (self.clear_field)(m);
+        }
     }
 }
 
@@ -658,3 +724,92 @@ where
         }),
     )
 }
+
+struct ConvertedMessageFieldAccessor<M, V, T>
+where
+    M: Message,
+    V: Message + ProtobufValue,
+    T: MessageConvert<V>,
+{
+    get_field: for<'a> fn(&'a M) -> &'a Option<T>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut Option<T>,
+    _marker: marker::PhantomData<V>,
+}
+
+impl<M, V, T> SingularFieldAccessor for ConvertedMessageFieldAccessor<M, V, T>
+where
+    M: Message,
+    V: Message + ProtobufValue,
+    T: MessageConvert<V>,
+{
+    fn get_field<'a>(&self, m: &'a dyn MessageDyn) -> Option<ReflectValueRef<'a>> {
+        let m = m.downcast_ref().unwrap();
+        (self.get_field)(m)
+            .as_ref()
+            .map(message_convert::to_value_ref::<V, T>)
+    }
+
+    fn get_field_or_default<'a>(&self, m: &'a dyn MessageDyn) -> ReflectValueRef<'a> {
+        match self.get_field(m) {
+            Some(v) => v,
+            None => ReflectValueRef::Message(MessageRef::from(V::default_instance())),
+        }
+    }
+
+    fn mut_field_or_default<'a>(&self, _m: &'a mut dyn MessageDyn) -> ReflectValueMut<'a> {
+        panic!("field is stored as a custom type, use `FieldDescriptor::mut_converted_message`")
+    }
+
+    fn mut_converted_message<'a>(&self, m: &'a mut dyn MessageDyn) -> MessageMut<'a> {
+        let m = m.downcast_mut().unwrap();
+        let field = (self.mut_field)(m);
+        let message: V = match field {
+            Some(v) => v.to_message(),
+            None => V::new(),
+        };
+        let guard =
+            ReflectValueGuard::copy(ReflectValueBox::Message(Box::new(message)), move |v| {
+                *field = Some(message_convert::from_value_box::<V, T>(v)?);
+                Ok(())
+            });
+        guard.into_message().unwrap()
+    }
+
+    fn set_field(
+        &self,
+        m: &mut dyn MessageDyn,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
+        let m = m.downcast_mut().unwrap();
+        *(self.mut_field)(m) = Some(message_convert::from_value_box::<V, T>(value)?);
+        Ok(())
+    }
+
+    fn clear_field(&self, m: &mut dyn MessageDyn) {
+        let m = m.downcast_mut().unwrap();
+        *(self.mut_field)(m) = None;
+    }
+}
+
+/// Make accessor for message field stored as `Option<T>` of custom type
+pub fn make_option_converted_message_accessor<M, V, T>(
+    name: &'static str,
+    get_field: for<'a> fn(&'a M) -> &'a Option<T>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut Option<T>,
+) -> FieldAccessor
+where
+    M: Message + 'static,
+    V: Message + ProtobufValue,
+    T: MessageConvert<V>,
+{
+    FieldAccessor::new_v2(
+        name,
+        AccessorV2::Singular(SingularFieldAccessorHolder {
+            accessor: Box::new(ConvertedMessageFieldAccessor::<M, V, T> {
+                get_field,
+                mut_field,
+                _marker: marker::PhantomData,
+            }),
+        }),
+    )
+}
@@ -17,12 +17,13 @@ use crate::Message;
 use std::marker;
 
 /// Make accessor for `oneof` `message` field
-pub fn make_oneof_message_has_get_mut_set_accessor<M, F>(
+pub fn make_oneof_message_has_get_mut_set_clear_accessor<M, F>(
     name: &'static str,
     has_field: fn(&M) -> bool,
     get_field: for<'a> fn(&'a M) -> &'a F,
     mut_field: for<'a> fn(&'a mut M) -> &'a mut F,
     set_field: fn(&mut M, F),
+    clear_field: fn(&mut M),
 ) -> FieldAccessor
 where
     M: Message + 'static,
@@ -38,7 +39,11 @@ where
                 },
                 get_or_default_impl: GetOrDefaultGetRef::<M, F> { get_field },
                 mut_or_default_impl: MutOrDefaultGetMut::<M, F> { mut_field },
-                set_impl: SetImplSetField::<M, F> { set_field },
+                set_impl: SetImplSetField::<M, F> {
+                    has_field,
+                    set_field,
+                    clear_field,
+                },
                 _marker: marker::PhantomData,
             }),
         }),
@@ -46,11 +51,12 @@ where
 }
 
 /// Make accessor for `Copy` field
-pub fn make_oneof_copy_has_get_set_simpler_accessors<M, V>(
+pub fn make_oneof_copy_has_get_set_clear_simpler_accessors<M, V>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: fn(&M) -> V,
     set: fn(&mut M, V),
+    clear: fn(&mut M),
 ) -> FieldAccessor
 where
     M: Message + 'static,
@@ -63,7 +69,11 @@ where
                 get_option_impl: GetOptionImplHasGetCopy::<M, V> { has, get },
                 get_or_default_impl: GetOrDefaultGetCopy::<M, V> { get_field: get },
                 mut_or_default_impl: MutOrDefaultUnmplemented::new(),
-                set_impl: SetImplSetField::<M, V> { set_field: set },
+                set_impl: SetImplSetField::<M, V> {
+                    has_field: has,
+                    set_field: set,
+                    clear_field: clear,
+                },
                 _marker: marker::PhantomData,
             }),
         }),
@@ -71,11 +81,12 @@ where
 }
 
 /// Make accessor for `oneof` field
-pub fn make_oneof_deref_has_get_set_simpler_accessor<M, F>(
+pub fn make_oneof_deref_has_get_set_clear_simpler_accessor<M, F>(
     name: &'static str,
     has: fn(&M) -> bool,
     get: for<'a> fn(&'a M) -> &'a <F::RuntimeType as RuntimeTypeWithDeref>::DerefTarget,
     set: fn(&mut M, F),
+    clear: fn(&mut M),
 ) -> FieldAccessor
 where
     M: Message + 'static,
@@ -89,7 +100,11 @@ where
                 get_option_impl: GetOptionImplHasGetRefDeref::<M, F> { has, get },
                 get_or_default_impl: GetOrDefaultGetRefDeref::<M, F> { get_field: get },
                 mut_or_default_impl: MutOrDefaultUnmplemented::new(),
-                set_impl: SetImplSetField::<M, F> { set_field: set },
+                set_impl: SetImplSetField::<M, F> {
+                    has_field: has,
+                    set_field: set,
+                    clear_field: clear,
+                },
                 _marker: marker::PhantomData,
             }),
         }),
@@ -0,0 +1,734 @@
+//! Structural comparison of messages.
+//!
+//! [`MessageDifferencer`] walks two messages of the same type with reflection
+//! and reports which values were added, removed or modified, rather than
+//! a single `bool` like [`MessageDescriptor::eq`](crate::reflect::MessageDescriptor::eq).
+//!
+//! ```
+//! # use protobuf::descriptor::FileDescriptorProto;
+//! # use protobuf::reflect::diff::MessageDifferencer;
+//! let mut a = FileDescriptorProto::new();
+//! a.set_name("a.proto".to_owned());
+//! let mut b = a.clone();
+//! b.set_package("p".to_owned());
+//!
+//! let diff = MessageDifferencer::new().compare(&a, &b);
+//! assert_eq!("added: package: \"p\"\n", diff.to_string());
+//! ```
+
+use std::fmt;
+
+use crate::message_dyn::MessageDyn;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectMapRef;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::text_format::print_value_to;
+use crate::UnknownFields;
+use crate::UnknownValueRef;
+
+/// How elements of a repeated field are matched between two messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepeatedFieldComparison {
+    /// Elements are matched by index (default).
+    List,
+    /// Order is ignored, each element is matched to an equal element
+    /// of the other list.
+    Set,
+    /// Elements are messages matched by value of the named field,
+    /// e. g. `name` for `DescriptorProto.field`.
+    Keyed(String),
+}
+
+/// How unknown fields are compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownFieldsComparison {
+    /// Values of each unknown field number are compared as a list (default).
+    Compare,
+    /// Unknown fields are not compared.
+    Ignore,
+}
+
+/// What happened to the value at [`Difference::path`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DifferenceKind {
+    /// Value is present only in the second message.
+    Added(ReflectValueBox),
+    /// Value is present only in the first message.
+    Removed(ReflectValueBox),
+    /// Value is present in both messages, but differs.
+    Modified(ReflectValueBox, ReflectValueBox),
+}
+
+/// Single difference found by [`MessageDifferencer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// Path to the value from the root message, e. g.
+    /// `message_type[0].field["id"].number`.
+    ///
+    /// Repeated elements are addressed by index, or by key for keyed
+    /// comparison, map values by key, unknown fields by field number.
+    pub path: String,
+    /// The difference.
+    pub kind: DifferenceKind,
+}
+
+/// Groups are compared as their serialized content.
+fn unknown_value_to_box(value: &UnknownValueRef) -> ReflectValueBox {
+    match value {
+        UnknownValueRef::Group(group) => ReflectValueBox::Bytes(group.write_to_bytes()),
+        value => value.to_reflect_value_ref().unwrap().to_box(),
+    }
+}
+
+fn value_to_string(value: &ReflectValueRef) -> String {
+    let mut r = String::new();
+    print_value_to(value, &mut r);
+    r
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            DifferenceKind::Added(v) => write!(
+                f,
+                "added: {}: {}",
+                self.path,
+                value_to_string(&v.as_value_ref())
+            ),
+            DifferenceKind::Removed(v) => write!(
+                f,
+                "removed: {}: {}",
+                self.path,
+                value_to_string(&v.as_value_ref())
+            ),
+            DifferenceKind::Modified(a, b) => write!(
+                f,
+                "modified: {}: {} -> {}",
+                self.path,
+                value_to_string(&a.as_value_ref()),
+                value_to_string(&b.as_value_ref())
+            ),
+        }
+    }
+}
+
+/// Result of [`MessageDifferencer::compare`].
+///
+/// `Display` renders a report with one difference per line.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MessageDiff {
+    differences: Vec<Difference>,
+}
+
+impl MessageDiff {
+    /// Messages are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Differences in field order.
+    pub fn differences(&self) -> &[Difference] {
+        &self.differences
+    }
+
+    /// Take the differences.
+    pub fn into_differences(self) -> Vec<Difference> {
+        self.differences
+    }
+}
+
+impl fmt::Display for MessageDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for d in &self.differences {
+            writeln!(f, "{}", d)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare two messages of the same type and report what differs.
+///
+/// By default behaves like `PartialEq`: repeated fields are compared
+/// by index, floats exactly and unknown fields are compared too.
+#[derive(Clone)]
+pub struct MessageDifferencer {
+    ignored_fields: Vec<FieldDescriptor>,
+    repeated_fields: Vec<(FieldDescriptor, RepeatedFieldComparison)>,
+    float_fraction: f64,
+    float_margin: f64,
+    nan_equal: bool,
+    unknown_fields: UnknownFieldsComparison,
+}
+
+impl Default for MessageDifferencer {
+    fn default() -> MessageDifferencer {
+        MessageDifferencer {
+            ignored_fields: Vec::new(),
+            repeated_fields: Vec::new(),
+            float_fraction: 0.0,
+            float_margin: 0.0,
+            nan_equal: false,
+            unknown_fields: UnknownFieldsComparison::Compare,
+        }
+    }
+}
+
+impl MessageDifferencer {
+    /// Differencer with default options.
+    pub fn new() -> MessageDifferencer {
+        Default::default()
+    }
+
+    /// Do not compare given field in any message of its type.
+    pub fn ignore_field(&mut self, field: FieldDescriptor) -> &mut Self {
+        self.ignored_fields.push(field);
+        self
+    }
+
+    /// Set how elements of given repeated field are matched.
+    ///
+    /// # Panics
+    ///
+    /// If field is not repeated, or if comparison is
+    /// [`Keyed`](RepeatedFieldComparison::Keyed) and the element type
+    /// is not a message with the key field.
+    pub fn set_repeated_field_comparison(
+        &mut self,
+        field: FieldDescriptor,
+        comparison: RepeatedFieldComparison,
+    ) -> &mut Self {
+        assert!(field.is_repeated(), "not a repeated field: {}", field);
+        if let RepeatedFieldComparison::Keyed(key) = &comparison {
+            key_field(&field, key);
+        }
+        self.repeated_fields.retain(|(f, _)| f != &field);
+        self.repeated_fields.push((field, comparison));
+        self
+    }
+
+    /// Consider `float` and `double` values equal if they differ
+    /// by no more than `margin`, or by no more than `fraction`
+    /// of the larger absolute value.
+    pub fn set_float_tolerance(&mut self, fraction: f64, margin: f64) -> &mut Self {
+        self.float_fraction = fraction;
+        self.float_margin = margin;
+        self
+    }
+
+    /// Consider `NaN` values equal to each other.
+    pub fn set_nan_equal(&mut self, nan_equal: bool) -> &mut Self {
+        self.nan_equal = nan_equal;
+        self
+    }
+
+    /// Set how unknown fields are compared.
+    pub fn set_unknown_fields_comparison(
+        &mut self,
+        comparison: UnknownFieldsComparison,
+    ) -> &mut Self {
+        self.unknown_fields = comparison;
+        self
+    }
+
+    /// Compare two messages.
+    ///
+    /// # Panics
+    ///
+    /// If messages have different types.
+    pub fn compare(&self, a: &dyn MessageDyn, b: &dyn MessageDyn) -> MessageDiff {
+        let mut differences = Vec::new();
+        self.compare_messages(a, b, "", &mut differences);
+        MessageDiff { differences }
+    }
+
+    /// Messages have no differences.
+    ///
+    /// # Panics
+    ///
+    /// If messages have different types.
+    pub fn equivalent(&self, a: &dyn MessageDyn, b: &dyn MessageDyn) -> bool {
+        self.compare(a, b).is_empty()
+    }
+
+    fn compare_messages(
+        &self,
+        a: &dyn MessageDyn,
+        b: &dyn MessageDyn,
+        path: &str,
+        out: &mut Vec<Difference>,
+    ) {
+        let d = a.descriptor_dyn();
+        let bd = b.descriptor_dyn();
+        assert!(d == bd, "messages of different types: {} and {}", d, bd);
+
+        for field in d.fields() {
+            if self.ignored_fields.contains(&field) {
+                continue;
+            }
+            let field_path = join_path(path, field.get_name());
+            match (field.get_reflect(a), field.get_reflect(b)) {
+                (ReflectFieldRef::Optional(av), ReflectFieldRef::Optional(bv)) => {
+                    self.compare_optional(av, bv, field_path, out)
+                }
+                (ReflectFieldRef::Repeated(ar), ReflectFieldRef::Repeated(br)) => {
+                    let av: Vec<_> = ar.into_iter().collect();
+                    let bv: Vec<_> = br.into_iter().collect();
+                    match self.repeated_comparison(&field) {
+                        RepeatedFieldComparison::List => {
+                            self.compare_lists(&av, &bv, &field_path, out)
+                        }
+                        RepeatedFieldComparison::Set => {
+                            self.compare_sets(&av, &bv, &field_path, out)
+                        }
+                        RepeatedFieldComparison::Keyed(key) => {
+                            let key = key_field(&field, key);
+                            self.compare_keyed(&key, &av, &bv, &field_path, out)
+                        }
+                    }
+                }
+                (ReflectFieldRef::Map(am), ReflectFieldRef::Map(bm)) => {
+                    self.compare_maps(&am, &bm, &field_path, out)
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        if self.unknown_fields == UnknownFieldsComparison::Compare {
+            self.compare_unknown_fields(
+                a.get_unknown_fields_dyn(),
+                b.get_unknown_fields_dyn(),
+                path,
+                out,
+            );
+        }
+    }
+
+    fn repeated_comparison(&self, field: &FieldDescriptor) -> &RepeatedFieldComparison {
+        self.repeated_fields
+            .iter()
+            .find(|(f, _)| f == field)
+            .map(|(_, c)| c)
+            .unwrap_or(&RepeatedFieldComparison::List)
+    }
+
+    fn compare_optional(
+        &self,
+        a: Option<ReflectValueRef>,
+        b: Option<ReflectValueRef>,
+        path: String,
+        out: &mut Vec<Difference>,
+    ) {
+        let kind = match (a, b) {
+            (None, None) => return,
+            (Some(a), Some(b)) => return self.compare_values(&a, &b, path, out),
+            (Some(a), None) => DifferenceKind::Removed(a.to_box()),
+            (None, Some(b)) => DifferenceKind::Added(b.to_box()),
+        };
+        out.push(Difference { path, kind });
+    }
+
+    fn compare_values(
+        &self,
+        a: &ReflectValueRef,
+        b: &ReflectValueRef,
+        path: String,
+        out: &mut Vec<Difference>,
+    ) {
+        match (a, b) {
+            (ReflectValueRef::Message(am), ReflectValueRef::Message(bm)) => {
+                self.compare_messages(&**am, &**bm, &path, out)
+            }
+            _ => {
+                if !self.values_equal(a, b) {
+                    out.push(Difference {
+                        path,
+                        kind: DifferenceKind::Modified(a.to_box(), b.to_box()),
+                    });
+                }
+            }
+        }
+    }
+
+    fn values_equal(&self, a: &ReflectValueRef, b: &ReflectValueRef) -> bool {
+        match (a, b) {
+            (ReflectValueRef::F32(a), ReflectValueRef::F32(b)) => {
+                self.floats_equal(*a as f64, *b as f64)
+            }
+            (ReflectValueRef::F64(a), ReflectValueRef::F64(b)) => self.floats_equal(*a, *b),
+            (ReflectValueRef::Message(a), ReflectValueRef::Message(b)) => {
+                let mut differences = Vec::new();
+                self.compare_messages(&**a, &**b, "", &mut differences);
+                differences.is_empty()
+            }
+            _ => a == b,
+        }
+    }
+
+    fn floats_equal(&self, a: f64, b: f64) -> bool {
+        if a == b {
+            return true;
+        }
+        if a.is_nan() || b.is_nan() {
+            return self.nan_equal && a.is_nan() && b.is_nan();
+        }
+        let diff = (a - b).abs();
+        diff <= self.float_margin || diff <= self.float_fraction * a.abs().max(b.abs())
+    }
+
+    fn compare_lists(
+        &self,
+        a: &[ReflectValueRef],
+        b: &[ReflectValueRef],
+        path: &str,
+        out: &mut Vec<Difference>,
+    ) {
+        for i in 0..a.len().max(b.len()) {
+            let element_path = format!("{}[{}]", path, i);
+            self.compare_optional(a.get(i).cloned(), b.get(i).cloned(), element_path, out);
+        }
+    }
+
+    fn compare_sets(
+        &self,
+        a: &[ReflectValueRef],
+        b: &[ReflectValueRef],
+        path: &str,
+        out: &mut Vec<Difference>,
+    ) {
+        let mut matched = vec![false; b.len()];
+        for (i, av) in a.iter().enumerate() {
+            let found = (0..b.len()).find(|&j| !matched[j] && self.values_equal(av, &b[j]));
+            match found {
+                Some(j) => matched[j] = true,
+                None => out.push(Difference {
+                    path: format!("{}[{}]", path, i),
+                    kind: DifferenceKind::Removed(av.to_box()),
+                }),
+            }
+        }
+        for (j, bv) in b.iter().enumerate() {
+            if !matched[j] {
+                out.push(Difference {
+                    path: format!("{}[{}]", path, j),
+                    kind: DifferenceKind::Added(bv.to_box()),
+                });
+            }
+        }
+    }
+
+    fn compare_keyed(
+        &self,
+        key: &FieldDescriptor,
+        a: &[ReflectValueRef],
+        b: &[ReflectValueRef],
+        path: &str,
+        out: &mut Vec<Difference>,
+    ) {
+        let key_of = |v: &ReflectValueRef| {
+            let m = v.to_message().expect("message");
+            value_to_string(&key.get_singular_field_or_default(&*m))
+        };
+        let b_keys: Vec<String> = b.iter().map(key_of).collect();
+        let mut matched = vec![false; b.len()];
+        for av in a {
+            let k = key_of(av);
+            let element_path = format!("{}[{}]", path, k);
+            match (0..b.len()).find(|&j| !matched[j] && b_keys[j] == k) {
+                Some(j) => {
+                    matched[j] = true;
+                    self.compare_values(av, &b[j], element_path, out);
+                }
+                None => out.push(Difference {
+                    path: element_path,
+                    kind: DifferenceKind::Removed(av.to_box()),
+                }),
+            }
+        }
+        for (j, bv) in b.iter().enumerate() {
+            if !matched[j] {
+                out.push(Difference {
+                    path: format!("{}[{}]", path, b_keys[j]),
+                    kind: DifferenceKind::Added(bv.to_box()),
+                });
+            }
+        }
+    }
+
+    fn compare_maps(
+        &self,
+        a: &ReflectMapRef,
+        b: &ReflectMapRef,
+        path: &str,
+        out: &mut Vec<Difference>,
+    ) {
+        let mut entries = Vec::new();
+        for (k, av) in a {
+            entries.push((value_to_string(&k), Some(av), b.get(k)));
+        }
+        for (k, bv) in b {
+            if a.get(k.clone()).is_none() {
+                entries.push((value_to_string(&k), None, Some(bv)));
+            }
+        }
+        // Map iteration order is unspecified, sort for stable output
+        entries.sort_by(|x, y| x.0.cmp(&y.0));
+        for (k, av, bv) in entries {
+            self.compare_optional(av, bv, format!("{}[{}]", path, k), out);
+        }
+    }
+
+    fn compare_unknown_fields(
+        &self,
+        a: &UnknownFields,
+        b: &UnknownFields,
+        path: &str,
+        out: &mut Vec<Difference>,
+    ) {
+        let mut numbers: Vec<u32> = a.iter().chain(b.iter()).map(|(n, _)| n).collect();
+        numbers.sort();
+        numbers.dedup();
+        for n in numbers {
+            let av: Vec<_> = a.get(n).into_iter().flat_map(|v| v.iter()).collect();
+            let bv: Vec<_> = b.get(n).into_iter().flat_map(|v| v.iter()).collect();
+            let field_path = join_path(path, &n.to_string());
+            for i in 0..av.len().max(bv.len()) {
+                let element_path = format!("{}[{}]", field_path, i);
+                match (av.get(i), bv.get(i)) {
+                    (Some(UnknownValueRef::Group(ag)), Some(UnknownValueRef::Group(bg))) => {
+                        self.compare_unknown_fields(ag, bg, &element_path, out)
+                    }
+                    (av, bv) => {
+                        let av = av.map(unknown_value_to_box);
+                        let bv = bv.map(unknown_value_to_box);
+                        self.compare_optional(
+                            av.as_ref().map(ReflectValueBox::as_value_ref),
+                            bv.as_ref().map(ReflectValueBox::as_value_ref),
+                            element_path,
+                            out,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn join_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+fn key_field(field: &FieldDescriptor, key: &str) -> FieldDescriptor {
+    let element = match field.runtime_field_type() {
+        RuntimeFieldType::Repeated(RuntimeTypeBox::Message(m)) => m,
+        _ => panic!("not a repeated message field: {}", field),
+    };
+    match element.get_field_by_name(key) {
+        Some(f) if f.is_singular() => f,
+        _ => panic!("no singular field {} in {}", key, element),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::descriptor::UninterpretedOption;
+    use crate::well_known_types::Struct;
+    use crate::well_known_types::Value;
+    use crate::Message;
+
+    fn field(name: &str, number: i32) -> FieldDescriptorProto {
+        let mut f = FieldDescriptorProto::new();
+        f.set_name(name.to_owned());
+        f.set_number(number);
+        f
+    }
+
+    fn message(fields: Vec<FieldDescriptorProto>) -> FileDescriptorProto {
+        let mut m = DescriptorProto::new();
+        m.set_name("M".to_owned());
+        m.field = fields;
+        let mut file = FileDescriptorProto::new();
+        file.message_type.push(m);
+        file
+    }
+
+    fn field_of<M: Message>(name: &str) -> FieldDescriptor {
+        M::descriptor_static().get_field_by_name(name).unwrap()
+    }
+
+    #[test]
+    fn scalars() {
+        let mut a = FileDescriptorProto::new();
+        a.set_name("a.proto".to_owned());
+        a.set_syntax("proto2".to_owned());
+        let mut b = FileDescriptorProto::new();
+        b.set_name("b.proto".to_owned());
+        b.set_package("p".to_owned());
+
+        let diff = MessageDifferencer::new().compare(&a, &b);
+        assert_eq!(
+            "\
+modified: name: \"a.proto\" -> \"b.proto\"
+added: package: \"p\"
+removed: syntax: \"proto2\"
+",
+            diff.to_string()
+        );
+        assert!(MessageDifferencer::new().equivalent(&a, &a.clone()));
+    }
+
+    #[test]
+    fn nested_list() {
+        let a = message(vec![field("a", 1), field("b", 2)]);
+        let b = message(vec![field("a", 1), field("b", 3), field("c", 4)]);
+        let diff = MessageDifferencer::new().compare(&a, &b);
+        assert_eq!(
+            "\
+modified: message_type[0].field[1].number: 2 -> 3
+added: message_type[0].field[2]: { name: \"c\" number: 4 }
+",
+            diff.to_string()
+        );
+    }
+
+    #[test]
+    fn keyed() {
+        let a = message(vec![field("a", 1), field("b", 2), field("c", 3)]);
+        let b = message(vec![field("d", 4), field("b", 5), field("a", 1)]);
+        let mut differencer = MessageDifferencer::new();
+        differencer.set_repeated_field_comparison(
+            field_of::<DescriptorProto>("field"),
+            RepeatedFieldComparison::Keyed("name".to_owned()),
+        );
+        let diff = differencer.compare(&a, &b);
+        assert_eq!(
+            "\
+modified: message_type[0].field[\"b\"].number: 2 -> 5
+removed: message_type[0].field[\"c\"]: { name: \"c\" number: 3 }
+added: message_type[0].field[\"d\"]: { name: \"d\" number: 4 }
+",
+            diff.to_string()
+        );
+    }
+
+    #[test]
+    fn set() {
+        let mut a = FileDescriptorProto::new();
+        a.dependency = vec!["x".to_owned(), "y".to_owned(), "z".to_owned()];
+        let mut b = FileDescriptorProto::new();
+        b.dependency = vec!["w".to_owned(), "z".to_owned(), "x".to_owned()];
+
+        let mut differencer = MessageDifferencer::new();
+        assert_eq!(3, differencer.compare(&a, &b).differences().len());
+
+        differencer.set_repeated_field_comparison(
+            field_of::<FileDescriptorProto>("dependency"),
+            RepeatedFieldComparison::Set,
+        );
+        assert_eq!(
+            vec![
+                Difference {
+                    path: "dependency[1]".to_owned(),
+                    kind: DifferenceKind::Removed("y".to_owned().into()),
+                },
+                Difference {
+                    path: "dependency[0]".to_owned(),
+                    kind: DifferenceKind::Added("w".to_owned().into()),
+                },
+            ],
+            differencer.compare(&a, &b).into_differences()
+        );
+    }
+
+    #[test]
+    fn ignore_field() {
+        let a = message(vec![field("a", 1)]);
+        let b = message(vec![field("a", 2)]);
+        let mut differencer = MessageDifferencer::new();
+        assert!(!differencer.equivalent(&a, &b));
+        differencer.ignore_field(field_of::<FieldDescriptorProto>("number"));
+        assert!(differencer.equivalent(&a, &b));
+    }
+
+    #[test]
+    fn float_tolerance() {
+        let option = |v: f64| {
+            let mut o = UninterpretedOption::new();
+            o.set_double_value(v);
+            o
+        };
+        let mut differencer = MessageDifferencer::new();
+        assert!(!differencer.equivalent(&option(1.0), &option(1.001)));
+        assert!(!differencer.equivalent(&option(f64::NAN), &option(f64::NAN)));
+
+        differencer.set_float_tolerance(0.0, 0.01);
+        assert!(differencer.equivalent(&option(1.0), &option(1.001)));
+        assert!(!differencer.equivalent(&option(1000.0), &option(1000.1)));
+
+        differencer.set_float_tolerance(0.001, 0.0);
+        assert!(!differencer.equivalent(&option(1.0), &option(1.01)));
+        assert!(differencer.equivalent(&option(1000.0), &option(1000.1)));
+
+        differencer.set_nan_equal(true);
+        assert!(differencer.equivalent(&option(f64::NAN), &option(f64::NAN)));
+    }
+
+    #[test]
+    fn map() {
+        let value = |s: &str| {
+            let mut v = Value::new();
+            v.set_string_value(s.to_owned());
+            v
+        };
+        let mut a = Struct::new();
+        a.fields.insert("x".to_owned(), value("1"));
+        a.fields.insert("y".to_owned(), value("2"));
+        let mut b = Struct::new();
+        b.fields.insert("y".to_owned(), value("3"));
+        b.fields.insert("z".to_owned(), value("4"));
+
+        let diff = MessageDifferencer::new().compare(&a, &b);
+        assert_eq!(
+            "\
+removed: fields[\"x\"]: { string_value: \"1\" }
+modified: fields[\"y\"].string_value: \"2\" -> \"3\"
+added: fields[\"z\"]: { string_value: \"4\" }
+",
+            diff.to_string()
+        );
+    }
+
+    #[test]
+    fn unknown_fields() {
+        let a = message(vec![field("a", 1)]);
+        let mut b = a.clone();
+        b.message_type[0].field[0]
+            .mut_unknown_fields()
+            .add_varint(1000, 7);
+        b.mut_unknown_fields()
+            .add_length_delimited(1001, b"x".to_vec());
+
+        let mut differencer = MessageDifferencer::new();
+        assert_eq!(
+            "\
+added: message_type[0].field[0].1000[0]: 7
+added: 1001[0]: \"x\"
+",
+            differencer.compare(&a, &b).to_string()
+        );
+
+        differencer.set_unknown_fields_comparison(UnknownFieldsComparison::Ignore);
+        assert!(differencer.equivalent(&a, &b));
+    }
+}
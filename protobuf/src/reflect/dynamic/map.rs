@@ -1,8 +1,12 @@
 use crate::reflect::map::ReflectMap;
 use crate::reflect::map::ReflectMapIter;
+use crate::reflect::map::ReflectMapIterMut;
+use crate::reflect::map::ReflectMapIterMutTrait;
 use crate::reflect::map::ReflectMapIterTrait;
 use crate::reflect::runtime_types::RuntimeType;
+use crate::reflect::value::value_ref::ReflectValueGuard;
 use crate::reflect::ProtobufValue;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeTypeBox;
@@ -112,6 +116,20 @@ impl<'a, K: ProtobufValue + Eq + Hash + 'static> ReflectMapIterTrait<'a>
     }
 }
 
+struct DynamicMapIterMutImpl<'a, K: ProtobufValue + Eq + Hash + 'static> {
+    iter: hash_map::IterMut<'a, K, ReflectValueBox>,
+}
+
+impl<'a, K: ProtobufValue + Eq + Hash + 'static> ReflectMapIterMutTrait<'a>
+    for DynamicMapIterMutImpl<'a, K>
+{
+    fn next(&mut self) -> Option<(ReflectValueRef<'a>, ReflectValueGuard<'a>)> {
+        self.iter
+            .next()
+            .map(|(k, v)| (K::as_ref(k), v.as_value_guard()))
+    }
+}
+
 impl ReflectMap for DynamicMap {
     fn reflect_iter(&self) -> ReflectMapIter {
         match &self.maps {
@@ -142,6 +160,17 @@ impl ReflectMap for DynamicMap {
         }
     }
 
+    fn reflect_iter_mut(&mut self) -> ReflectMapIterMut<'_> {
+        match &mut self.maps {
+            Maps::U32(m) => ReflectMapIterMut::new(DynamicMapIterMutImpl { iter: m.iter_mut() }),
+            Maps::I32(m) => ReflectMapIterMut::new(DynamicMapIterMutImpl { iter: m.iter_mut() }),
+            Maps::U64(m) => ReflectMapIterMut::new(DynamicMapIterMutImpl { iter: m.iter_mut() }),
+            Maps::I64(m) => ReflectMapIterMut::new(DynamicMapIterMutImpl { iter: m.iter_mut() }),
+            Maps::Bool(m) => ReflectMapIterMut::new(DynamicMapIterMutImpl { iter: m.iter_mut() }),
+            Maps::String(m) => ReflectMapIterMut::new(DynamicMapIterMutImpl { iter: m.iter_mut() }),
+        }
+    }
+
     fn len(&self) -> usize {
         self.maps.len()
     }
@@ -163,20 +192,47 @@ impl ReflectMap for DynamicMap {
         .map(ReflectValueBox::as_value_ref)
     }
 
-    fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox) {
-        assert!(value.get_type() == self.value);
-        match (&mut self.maps, &key) {
-            (Maps::U32(m), ReflectValueBox::U32(k)) => m.insert(*k, value),
-            (Maps::U64(m), ReflectValueBox::U64(k)) => m.insert(*k, value),
-            (Maps::I32(m), ReflectValueBox::I32(k)) => m.insert(*k, value),
-            (Maps::I64(m), ReflectValueBox::I64(k)) => m.insert(*k, value),
-            (Maps::Bool(m), ReflectValueBox::Bool(k)) => m.insert(*k, value),
-            (Maps::String(m), _) => match key {
-                ReflectValueBox::String(k) => m.insert(k, value),
-                _ => panic!("wrong key type"),
-            },
-            _ => panic!("wrong key type"),
-        };
+    fn get_mut<'a>(&'a mut self, key: ReflectValueRef) -> Option<ReflectValueGuard<'a>> {
+        match (&mut self.maps, key) {
+            (Maps::U32(m), ReflectValueRef::U32(v)) => m.get_mut(&v),
+            (Maps::U64(m), ReflectValueRef::U64(v)) => m.get_mut(&v),
+            (Maps::I32(m), ReflectValueRef::I32(v)) => m.get_mut(&v),
+            (Maps::I64(m), ReflectValueRef::I64(v)) => m.get_mut(&v),
+            (Maps::Bool(m), ReflectValueRef::Bool(v)) => m.get_mut(&v),
+            (Maps::String(m), ReflectValueRef::String(v)) => m.get_mut(v),
+            _ => None,
+        }
+        .map(ReflectValueBox::as_value_guard)
+    }
+
+    fn try_insert(
+        &mut self,
+        key: ReflectValueBox,
+        value: ReflectValueBox,
+    ) -> Result<Option<ReflectValueBox>, ReflectTypeError> {
+        let key = ReflectTypeError::check(self.maps.key_type(), key)?;
+        let value = ReflectTypeError::check(self.value.clone(), value)?;
+        Ok(match (&mut self.maps, key) {
+            (Maps::U32(m), ReflectValueBox::U32(k)) => m.insert(k, value),
+            (Maps::U64(m), ReflectValueBox::U64(k)) => m.insert(k, value),
+            (Maps::I32(m), ReflectValueBox::I32(k)) => m.insert(k, value),
+            (Maps::I64(m), ReflectValueBox::I64(k)) => m.insert(k, value),
+            (Maps::Bool(m), ReflectValueBox::Bool(k)) => m.insert(k, value),
+            (Maps::String(m), ReflectValueBox::String(k)) => m.insert(k, value),
+            _ => unreachable!("key type is checked"),
+        })
+    }
+
+    fn remove(&mut self, key: ReflectValueRef) -> Option<ReflectValueBox> {
+        match (&mut self.maps, key) {
+            (Maps::U32(m), ReflectValueRef::U32(v)) => m.remove(&v),
+            (Maps::U64(m), ReflectValueRef::U64(v)) => m.remove(&v),
+            (Maps::I32(m), ReflectValueRef::I32(v)) => m.remove(&v),
+            (Maps::I64(m), ReflectValueRef::I64(v)) => m.remove(&v),
+            (Maps::Bool(m), ReflectValueRef::Bool(v)) => m.remove(&v),
+            (Maps::String(m), ReflectValueRef::String(v)) => m.remove(v),
+            _ => None,
+        }
     }
 
     fn clear(&mut self) {
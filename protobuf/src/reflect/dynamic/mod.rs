@@ -1,20 +1,33 @@
+use std::error::Error;
+use std::fmt;
+
 use crate::cached_size::CachedSize;
+use crate::descriptor::field_descriptor_proto::Type;
 use crate::message_dyn::MessageDyn;
 use crate::reflect::dynamic::map::DynamicMap;
 use crate::reflect::dynamic::optional::DynamicOptional;
 use crate::reflect::dynamic::repeated::DynamicRepeated;
 use crate::reflect::map::ReflectMap;
+use crate::reflect::message::MessageDescriptorImplRef;
 use crate::reflect::repeated::ReflectRepeated;
 use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::FieldDescriptor;
 use crate::reflect::MessageDescriptor;
+use crate::reflect::ReflectEqMode;
 use crate::reflect::ReflectFieldRef;
 use crate::reflect::ReflectMapMut;
 use crate::reflect::ReflectMapRef;
 use crate::reflect::ReflectRepeatedMut;
 use crate::reflect::ReflectRepeatedRef;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::rt;
+use crate::stream;
+use crate::wire_format;
+use crate::wire_format::WireType;
 use crate::Clear;
 use crate::CodedInputStream;
 use crate::CodedOutputStream;
@@ -25,6 +38,7 @@ use crate::UnknownFields;
 pub(crate) mod map;
 pub(crate) mod optional;
 pub(crate) mod repeated;
+pub(crate) mod wire;
 
 #[derive(Debug, Clone)]
 enum DynamicFieldValue {
@@ -61,8 +75,42 @@ impl DynamicFieldValue {
     }
 }
 
+/// Error of [`DynamicMessage`] field accessors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynamicFieldError {
+    /// Message has no field with given name or number.
+    UnknownField(String),
+    /// Field is repeated or map.
+    NotSingular(String),
+    /// Value type does not match field type.
+    Type(ReflectTypeError),
+}
+
+impl fmt::Display for DynamicFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicFieldError::UnknownField(field) => write!(f, "unknown field: {}", field),
+            DynamicFieldError::NotSingular(field) => write!(f, "not a singular field: {}", field),
+            DynamicFieldError::Type(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl Error for DynamicFieldError {}
+
+impl From<ReflectTypeError> for DynamicFieldError {
+    fn from(e: ReflectTypeError) -> Self {
+        DynamicFieldError::Type(e)
+    }
+}
+
+/// Message of a type known only at runtime, e. g. loaded from
+/// [`FileDescriptor::new_dynamic`](crate::reflect::FileDescriptor::new_dynamic).
+///
+/// Messages are equal if they have the same descriptor, equal fields
+/// and equal unknown fields.
 #[derive(Debug, Clone)]
-pub(crate) struct DynamicMessage {
+pub struct DynamicMessage {
     pub(crate) descriptor: MessageDescriptor,
     fields: Box<[DynamicFieldValue]>,
     unknown_fields: UnknownFields,
@@ -70,7 +118,25 @@ pub(crate) struct DynamicMessage {
 }
 
 impl DynamicMessage {
-    pub(crate) fn new(descriptor: MessageDescriptor) -> DynamicMessage {
+    /// New empty message of given type.
+    ///
+    /// Use [`MessageDescriptor::new_instance`] to create an empty message
+    /// of either generated or dynamic type.
+    ///
+    /// # Panics
+    ///
+    /// If the descriptor is of a generated message type or of a map entry.
+    pub fn new(descriptor: MessageDescriptor) -> DynamicMessage {
+        let dynamic = match descriptor.get_impl() {
+            MessageDescriptorImplRef::Dynamic(..) => true,
+            MessageDescriptorImplRef::Generated(..) => false,
+        };
+        assert!(
+            dynamic,
+            "message type is generated: {}",
+            descriptor.full_name()
+        );
+        descriptor.assert_not_map_entry();
         DynamicMessage {
             descriptor,
             fields: Vec::new().into_boxed_slice(),
@@ -79,6 +145,137 @@ impl DynamicMessage {
         }
     }
 
+    /// Parse a message of given type from bytes.
+    pub fn parse_from_bytes(
+        descriptor: &MessageDescriptor,
+        bytes: &[u8],
+    ) -> ProtobufResult<DynamicMessage> {
+        let mut message = DynamicMessage::new(descriptor.clone());
+        message.merge_from_bytes(bytes)?;
+        message.check_initialized()?;
+        Ok(message)
+    }
+
+    /// Copy a message of any representation, e. g. a generated message,
+    /// into a dynamic message of the same type.
+    ///
+    /// Fields are copied by number, fields missing in `descriptor`
+    /// or of different types are stored in unknown fields.
+    ///
+    /// # Panics
+    ///
+    /// If message full type name is different from `descriptor`
+    /// or `descriptor` is a generated message type.
+    pub fn from_message(
+        message: &dyn MessageDyn,
+        descriptor: &MessageDescriptor,
+    ) -> DynamicMessage {
+        let mut dynamic = DynamicMessage::new(descriptor.clone());
+        transcode(message, &mut dynamic);
+        dynamic
+    }
+
+    /// Copy this message into a generated message of the same type.
+    ///
+    /// Fields are copied by number, fields missing in `M`
+    /// or of different types are stored in unknown fields.
+    ///
+    /// # Panics
+    ///
+    /// If message full type name is different from `M`.
+    pub fn to_message<M: Message>(&self) -> M {
+        let mut message = M::new();
+        transcode(self, &mut message);
+        message
+    }
+
+    /// Message type.
+    pub fn descriptor(&self) -> &MessageDescriptor {
+        &self.descriptor
+    }
+
+    fn field_by_name(&self, name: &str) -> Result<FieldDescriptor, DynamicFieldError> {
+        self.descriptor
+            .get_field_by_name(name)
+            .ok_or_else(|| DynamicFieldError::UnknownField(name.to_owned()))
+    }
+
+    fn field_by_number(&self, number: u32) -> Result<FieldDescriptor, DynamicFieldError> {
+        self.descriptor
+            .get_field_by_number(number)
+            .ok_or_else(|| DynamicFieldError::UnknownField(number.to_string()))
+    }
+
+    fn singular_or_default(
+        &self,
+        field: FieldDescriptor,
+    ) -> Result<ReflectValueRef<'_>, DynamicFieldError> {
+        if !field.is_singular() {
+            return Err(DynamicFieldError::NotSingular(field.to_string()));
+        }
+        Ok(field.get_singular_field_or_default(self))
+    }
+
+    fn set_checked(
+        &mut self,
+        field: FieldDescriptor,
+        value: ReflectValueBox,
+    ) -> Result<(), DynamicFieldError> {
+        let value = match field.runtime_field_type() {
+            RuntimeFieldType::Singular(t) => ReflectTypeError::check(t, value)?,
+            _ => return Err(DynamicFieldError::NotSingular(field.to_string())),
+        };
+        self.set_field(&field, value);
+        Ok(())
+    }
+
+    /// Get any field by name.
+    pub fn get_field(&self, name: &str) -> Result<ReflectFieldRef<'_>, DynamicFieldError> {
+        let field = self.field_by_name(name)?;
+        Ok(self.get_reflect(&field))
+    }
+
+    /// Get any field by number.
+    pub fn get_field_by_number(
+        &self,
+        number: u32,
+    ) -> Result<ReflectFieldRef<'_>, DynamicFieldError> {
+        let field = self.field_by_number(number)?;
+        Ok(self.get_reflect(&field))
+    }
+
+    /// Get singular field value by name, or field default value if the field is unset.
+    pub fn get(&self, name: &str) -> Result<ReflectValueRef<'_>, DynamicFieldError> {
+        let field = self.field_by_name(name)?;
+        self.singular_or_default(field)
+    }
+
+    /// Get singular field value by number, or field default value if the field is unset.
+    pub fn get_by_number(&self, number: u32) -> Result<ReflectValueRef<'_>, DynamicFieldError> {
+        let field = self.field_by_number(number)?;
+        self.singular_or_default(field)
+    }
+
+    /// Set singular field by name.
+    pub fn set<V: Into<ReflectValueBox>>(
+        &mut self,
+        name: &str,
+        value: V,
+    ) -> Result<(), DynamicFieldError> {
+        let field = self.field_by_name(name)?;
+        self.set_checked(field, value.into())
+    }
+
+    /// Set singular field by number.
+    pub fn set_by_number<V: Into<ReflectValueBox>>(
+        &mut self,
+        number: u32,
+        value: V,
+    ) -> Result<(), DynamicFieldError> {
+        let field = self.field_by_number(number)?;
+        self.set_checked(field, value.into())
+    }
+
     fn init_fields(&mut self) {
         if self.fields.is_empty() {
             self.fields = self
@@ -98,6 +295,11 @@ impl DynamicMessage {
         }
     }
 
+    /// Clear the field.
+    ///
+    /// # Panics
+    ///
+    /// If the field belongs to a different message type.
     pub fn clear_field(&mut self, field: &FieldDescriptor) {
         assert_eq!(field.message_descriptor, self.descriptor);
         if self.fields.is_empty() {
@@ -125,7 +327,6 @@ impl DynamicMessage {
         assert_eq!(field.message_descriptor, self.descriptor);
         self.init_fields();
         self.clear_oneof_group_fields_except(field);
-        // TODO: reset oneof group fields
         match &mut self.fields[field.index] {
             DynamicFieldValue::Singular(f) => f.mut_or_default(),
             _ => panic!("Not a singular field"),
@@ -138,7 +339,6 @@ impl DynamicMessage {
     ) -> ReflectRepeatedMut<'a> {
         assert_eq!(self.descriptor, field.message_descriptor);
         self.init_fields();
-        // TODO: reset oneof group fields
         match &mut self.fields[field.index] {
             DynamicFieldValue::Repeated(r) => ReflectRepeatedMut::new(r),
             _ => panic!("Not a repeated field: {}", field),
@@ -148,7 +348,6 @@ impl DynamicMessage {
     pub(crate) fn mut_map<'a>(&'a mut self, field: &FieldDescriptor) -> ReflectMapMut<'a> {
         assert_eq!(field.message_descriptor, self.descriptor);
         self.init_fields();
-        // TODO: reset oneof group fields
         match &mut self.fields[field.index] {
             DynamicFieldValue::Map(m) => ReflectMapMut::new(m),
             _ => panic!("Not a map field: {}", field),
@@ -158,25 +357,290 @@ impl DynamicMessage {
     pub(crate) fn set_field(&mut self, field: &FieldDescriptor, value: ReflectValueBox) {
         assert_eq!(field.message_descriptor, self.descriptor);
         self.init_fields();
-        // TODO: reset oneof group fields
+        self.clear_oneof_group_fields_except(field);
         match &mut self.fields[field.index] {
             DynamicFieldValue::Singular(s) => s.set(value),
             _ => panic!("Not a singular field: {}", field),
         }
     }
 
-    pub fn downcast_ref(message: &dyn MessageDyn) -> &DynamicMessage {
+    pub(crate) fn downcast_ref(message: &dyn MessageDyn) -> &DynamicMessage {
         MessageDyn::downcast_ref(message).unwrap()
     }
 
-    pub fn downcast_mut(message: &mut dyn MessageDyn) -> &mut DynamicMessage {
+    pub(crate) fn downcast_mut(message: &mut dyn MessageDyn) -> &mut DynamicMessage {
         MessageDyn::downcast_mut(message).unwrap()
     }
 }
 
+impl DynamicMessage {
+    fn merge_field(
+        &mut self,
+        field: &FieldDescriptor,
+        wire_type: WireType,
+        is: &mut CodedInputStream,
+    ) -> ProtobufResult<()> {
+        let t = wire::field_type(field);
+        if t == Type::TYPE_GROUP {
+            return self.merge_group_field(field, wire_type, is);
+        }
+        let number = field.get_proto().get_number() as u32;
+
+        match field.runtime_field_type() {
+            RuntimeFieldType::Singular(runtime_type) => {
+                if wire_type != wire::wire_type(t) {
+                    return Err(rt::unexpected_wire_type(wire_type));
+                }
+                match runtime_type {
+                    // Merge into existing message like generated code does
+                    RuntimeTypeBox::Message(_) => match self.mut_singular_field_or_default(field) {
+                        ReflectValueMut::Message(m) => wire::merge_message(is, m),
+                    },
+                    runtime_type => {
+                        let value = wire::read_value(is, t, &runtime_type)?;
+                        match unknown_closed_enum_value(&value) {
+                            Some(v) => self.unknown_fields.add_varint(number, v as i64 as u64),
+                            None => self.set_field(field, value),
+                        }
+                        Ok(())
+                    }
+                }
+            }
+            RuntimeFieldType::Repeated(runtime_type) => {
+                // Packed and non-packed encodings are both accepted
+                if wire_type == WireType::WireTypeLengthDelimited && wire::is_packable(t) {
+                    let len = is.read_raw_varint64()?;
+                    let old_limit = is.push_limit(len)?;
+                    while !is.eof()? {
+                        let value = wire::read_value(is, t, &runtime_type)?;
+                        self.push_repeated(field, number, value);
+                    }
+                    is.pop_limit(old_limit);
+                } else {
+                    if wire_type != wire::wire_type(t) {
+                        return Err(rt::unexpected_wire_type(wire_type));
+                    }
+                    let value = wire::read_value(is, t, &runtime_type)?;
+                    self.push_repeated(field, number, value);
+                }
+                Ok(())
+            }
+            RuntimeFieldType::Map(key_type, value_type) => {
+                if wire_type != WireType::WireTypeLengthDelimited {
+                    return Err(rt::unexpected_wire_type(wire_type));
+                }
+                let types = wire::map_entry_types(field);
+
+                let mut key = key_type.default_value_ref().to_box();
+                let mut value = value_type.default_value_ref().to_box();
+
+                let len = is.read_raw_varint64()?;
+                let old_limit = is.push_limit(len)?;
+                while !is.eof()? {
+                    let (number, wire_type) = is.read_tag_unpack()?;
+                    match number {
+                        1 if wire_type == wire::wire_type(types.0) => {
+                            key = wire::read_value(is, types.0, &key_type)?;
+                        }
+                        2 if wire_type == wire::wire_type(types.1) => {
+                            value = wire::read_value(is, types.1, &value_type)?;
+                        }
+                        1 | 2 => return Err(rt::unexpected_wire_type(wire_type)),
+                        _ => is.skip_field(wire_type)?,
+                    }
+                }
+                is.pop_limit(old_limit);
+
+                if unknown_closed_enum_value(&value).is_some() {
+                    wire::add_unknown_map_entry(
+                        &mut self.unknown_fields,
+                        number,
+                        types,
+                        &key.as_value_ref(),
+                        &value.as_value_ref(),
+                    );
+                } else {
+                    self.mut_map(field).insert(key, value);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn push_repeated(&mut self, field: &FieldDescriptor, number: u32, value: ReflectValueBox) {
+        match unknown_closed_enum_value(&value) {
+            Some(v) => self.unknown_fields.add_varint(number, v as i64 as u64),
+            None => self.mut_repeated(field).push(value),
+        }
+    }
+
+    fn merge_group_field(
+        &mut self,
+        field: &FieldDescriptor,
+        wire_type: WireType,
+        is: &mut CodedInputStream,
+    ) -> ProtobufResult<()> {
+        if wire_type != WireType::WireTypeStartGroup {
+            return Err(rt::unexpected_wire_type(wire_type));
+        }
+        let number = field.get_proto().get_number() as u32;
+        match field.runtime_field_type() {
+            RuntimeFieldType::Singular(..) => match self.mut_singular_field_or_default(field) {
+                ReflectValueMut::Message(m) => wire::merge_group(is, number, m),
+            },
+            RuntimeFieldType::Repeated(RuntimeTypeBox::Message(m)) => {
+                let mut message = m.new_instance();
+                wire::merge_group(is, number, &mut *message)?;
+                self.mut_repeated(field)
+                    .push(ReflectValueBox::Message(message));
+                Ok(())
+            }
+            _ => panic!("group field must be singular or repeated message"),
+        }
+    }
+
+    /// Merge fields of the group until the end group tag.
+    pub(crate) fn merge_group_fields(
+        &mut self,
+        is: &mut CodedInputStream,
+        field_number: u32,
+    ) -> ProtobufResult<()> {
+        loop {
+            let (number, wire_type) = is.read_tag_unpack()?;
+            if wire_type == WireType::WireTypeEndGroup {
+                return stream::check_end_group(field_number, number);
+            }
+            self.merge_tagged(number, wire_type, is)?;
+        }
+    }
+
+    fn merge_tagged(
+        &mut self,
+        number: u32,
+        wire_type: WireType,
+        is: &mut CodedInputStream,
+    ) -> ProtobufResult<()> {
+        match self.descriptor.get_field_by_number(number) {
+            Some(field) => self.merge_field(&field, wire_type, is),
+            None => rt::read_unknown_or_skip_group(number, wire_type, is, &mut self.unknown_fields),
+        }
+    }
+
+    /// Singular field value which is written.
+    ///
+    /// Fields without presence (e. g. proto3 singular fields) are not written when default.
+    fn singular_to_write<'a>(
+        &'a self,
+        field: &FieldDescriptor,
+        value: Option<ReflectValueRef<'a>>,
+    ) -> Option<ReflectValueRef<'a>> {
+        let value = value?;
+        if field.has_presence() || value.is_non_zero() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Convert a value to the representation of `target` type.
+///
+/// Values of different types are returned as is, so setting them fails.
+fn transcode_value(value: &ReflectValueRef, target: &RuntimeTypeBox) -> ReflectValueBox {
+    match (value, target) {
+        (ReflectValueRef::Enum(e, v), RuntimeTypeBox::Enum(d))
+            if e.full_name() == d.full_name() =>
+        {
+            ReflectValueBox::Enum(d.clone(), *v)
+        }
+        (ReflectValueRef::Message(m), RuntimeTypeBox::Message(d))
+            if m.descriptor_dyn().full_name() == d.full_name() =>
+        {
+            let mut message = d.new_instance();
+            transcode(&**m, &mut *message);
+            ReflectValueBox::Message(message)
+        }
+        (value, _) => value.to_box(),
+    }
+}
+
+/// Copy fields of `source` into empty `target` of the same protobuf type
+/// but possibly different representation (generated or dynamic).
+///
+/// Fields missing in `target` and values of types different from
+/// `target` field types are stored in `target` unknown fields.
+fn transcode(source: &dyn MessageDyn, target: &mut dyn MessageDyn) {
+    let source_descriptor = source.descriptor_dyn();
+    let target_descriptor = target.descriptor_dyn();
+    assert_eq!(
+        source_descriptor.full_name(),
+        target_descriptor.full_name(),
+        "different message types"
+    );
+
+    let mut unknown_fields = source.get_unknown_fields_dyn().clone();
+    for field in source_descriptor.fields() {
+        let number = field.get_proto().get_number() as u32;
+        let t = wire::field_type(&field);
+        let target_field = match target_descriptor.get_field_by_number(number) {
+            Some(f) => f,
+            None => {
+                wire::add_unknown_field(&mut unknown_fields, &field, field.get_reflect(source));
+                continue;
+            }
+        };
+        match (field.get_reflect(source), target_field.runtime_field_type()) {
+            (ReflectFieldRef::Optional(None), _) => {}
+            (ReflectFieldRef::Optional(Some(v)), RuntimeFieldType::Singular(target_type)) => {
+                let value = transcode_value(&v, &target_type);
+                if target_field.try_set_singular_field(target, value).is_err() {
+                    let value = ReflectFieldRef::Optional(Some(v));
+                    wire::add_unknown_field(&mut unknown_fields, &field, value);
+                }
+            }
+            (ReflectFieldRef::Repeated(r), RuntimeFieldType::Repeated(target_type)) => {
+                let mut target_repeated = target_field.mut_repeated(target);
+                for v in r {
+                    let value = transcode_value(&v, &target_type);
+                    if target_repeated.try_push(value).is_err() {
+                        wire::add_unknown(&mut unknown_fields, number, t, &v);
+                    }
+                }
+            }
+            (ReflectFieldRef::Map(m), RuntimeFieldType::Map(key_type, value_type)) => {
+                let types = wire::map_entry_types(&field);
+                let mut target_map = target_field.mut_map(target);
+                for (k, v) in &m {
+                    let key = transcode_value(&k, &key_type);
+                    let value = transcode_value(&v, &value_type);
+                    if target_map.try_insert(key, value).is_err() {
+                        wire::add_unknown_map_entry(&mut unknown_fields, number, types, &k, &v);
+                    }
+                }
+            }
+            (value, _) => wire::add_unknown_field(&mut unknown_fields, &field, value),
+        }
+    }
+
+    *target.mut_unknown_fields_dyn() = unknown_fields;
+}
+
+impl PartialEq for DynamicMessage {
+    fn eq(&self, other: &DynamicMessage) -> bool {
+        self.descriptor == other.descriptor
+            && self
+                .descriptor
+                .reflect_eq(self, other, &ReflectEqMode::default())
+            && self.unknown_fields == other.unknown_fields
+    }
+}
+
 impl Clear for DynamicMessage {
     fn clear(&mut self) {
-        unimplemented!()
+        for field in self.fields.iter_mut() {
+            field.clear();
+        }
+        self.unknown_fields.clear();
     }
 }
 
@@ -186,19 +650,117 @@ impl Message for DynamicMessage {
     }
 
     fn is_initialized(&self) -> bool {
-        unimplemented!()
+        fn is_message_initialized(value: ReflectValueRef) -> bool {
+            match value {
+                ReflectValueRef::Message(m) => m.is_initialized_dyn(),
+                _ => true,
+            }
+        }
+
+        for field in self.descriptor.fields() {
+            let initialized = match self.get_reflect(&field) {
+                ReflectFieldRef::Optional(Some(v)) => is_message_initialized(v),
+                ReflectFieldRef::Optional(None) => !field.is_required(),
+                ReflectFieldRef::Repeated(r) => r.into_iter().all(is_message_initialized),
+                ReflectFieldRef::Map(m) => (&m).into_iter().all(|(_, v)| is_message_initialized(v)),
+            };
+            if !initialized {
+                return false;
+            }
+        }
+        true
     }
 
-    fn merge_from(&mut self, _is: &mut CodedInputStream) -> ProtobufResult<()> {
-        unimplemented!()
+    fn merge_from(&mut self, is: &mut CodedInputStream) -> ProtobufResult<()> {
+        while !is.eof()? {
+            let (number, wire_type) = is.read_tag_unpack()?;
+            self.merge_tagged(number, wire_type, is)?;
+        }
+        Ok(())
     }
 
-    fn write_to_with_cached_sizes(&self, _os: &mut CodedOutputStream) -> ProtobufResult<()> {
-        unimplemented!()
+    fn write_to_with_cached_sizes(&self, os: &mut CodedOutputStream) -> ProtobufResult<()> {
+        for field in self.descriptor.fields() {
+            let t = wire::field_type(&field);
+            let number = field.get_proto().get_number() as u32;
+            match self.get_reflect(&field) {
+                ReflectFieldRef::Optional(value) => {
+                    if let Some(value) = self.singular_to_write(&field, value) {
+                        wire::write_field(os, number, t, &value)?;
+                    }
+                }
+                ReflectFieldRef::Repeated(repeated) => {
+                    if field.is_packed() {
+                        if repeated.is_empty() {
+                            continue;
+                        }
+                        let data_size: u32 = (0..repeated.len())
+                            .map(|i| repeated.get(i))
+                            .map(|v| wire::compute_value_size_no_tag(t, &v))
+                            .sum();
+                        os.write_tag(number, wire_format::WireTypeLengthDelimited)?;
+                        os.write_raw_varint32(data_size)?;
+                        for value in repeated {
+                            wire::write_value_no_tag(os, t, &value)?;
+                        }
+                    } else {
+                        for value in repeated {
+                            wire::write_field(os, number, t, &value)?;
+                        }
+                    }
+                }
+                ReflectFieldRef::Map(map) => {
+                    let types = wire::map_entry_types(&field);
+                    for (key, value) in &map {
+                        wire::write_map_entry(os, number, types, &key, &value)?;
+                    }
+                }
+            }
+        }
+        os.write_unknown_fields(&self.unknown_fields)?;
+        Ok(())
     }
 
     fn compute_size(&self) -> u32 {
-        unimplemented!()
+        let mut size = 0;
+        for field in self.descriptor.fields() {
+            let t = wire::field_type(&field);
+            let number = field.get_proto().get_number() as u32;
+            let tag_size = rt::tag_size(number);
+            match self.get_reflect(&field) {
+                ReflectFieldRef::Optional(value) => {
+                    if let Some(value) = self.singular_to_write(&field, value) {
+                        size += wire::compute_field_size(number, t, &value);
+                    }
+                }
+                ReflectFieldRef::Repeated(repeated) => {
+                    if field.is_packed() {
+                        if !repeated.is_empty() {
+                            let data_size: u32 = (0..repeated.len())
+                                .map(|i| repeated.get(i))
+                                .map(|v| wire::compute_value_size_no_tag(t, &v))
+                                .sum();
+                            size += tag_size + rt::compute_raw_varint32_size(data_size) + data_size;
+                        }
+                    } else {
+                        size += (0..repeated.len())
+                            .map(|i| repeated.get(i))
+                            .map(|v| wire::compute_field_size(number, t, &v))
+                            .sum::<u32>();
+                    }
+                }
+                ReflectFieldRef::Map(map) => {
+                    let types = wire::map_entry_types(&field);
+                    for (key, value) in &map {
+                        let entry_size = wire::compute_map_entry_size(types, &key, &value);
+                        size += tag_size + rt::compute_raw_varint32_size(entry_size) + entry_size;
+                    }
+                }
+            }
+        }
+        size += rt::unknown_fields_size(&self.unknown_fields);
+        self.cached_size.set(size);
+        size
     }
 
     fn get_cached_size(&self) -> u32 {
@@ -227,3 +789,527 @@ impl Message for DynamicMessage {
         panic!("There's no default instance for dynamic message")
     }
 }
+
+/// Value of closed enum not declared in the enum,
+/// such values are stored in unknown fields.
+fn unknown_closed_enum_value(value: &ReflectValueBox) -> Option<i32> {
+    match value {
+        ReflectValueBox::Enum(e, v) if !e.is_open() && e.get_value_by_number(*v).is_none() => {
+            Some(*v)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::field_descriptor_proto::Type;
+    use crate::descriptor::source_code_info::Location;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::reflect::DynamicFieldError;
+    use crate::reflect::DynamicMessage;
+    use crate::reflect::FileDescriptor;
+    use crate::reflect::MessageDescriptor;
+    use crate::reflect::ReflectFieldRef;
+    use crate::reflect::ReflectTypeError;
+    use crate::reflect::ReflectValueBox;
+    use crate::reflect::ReflectValueRef;
+    use crate::reflect::RuntimeTypeBox;
+    use crate::well_known_types::Struct;
+    use crate::well_known_types::Timestamp;
+    use crate::well_known_types::Value;
+    use crate::Message;
+
+    fn dynamic_descriptor(descriptor: &MessageDescriptor) -> MessageDescriptor {
+        let file =
+            FileDescriptor::new_dynamic(descriptor.file_descriptor_proto().clone(), Vec::new());
+        file.message_by_package_relative_name(descriptor.get_name())
+            .unwrap()
+    }
+
+    fn dynamic_copy(message: &dyn crate::MessageDyn) -> Box<dyn crate::MessageDyn> {
+        let dynamic = dynamic_descriptor(&message.descriptor_dyn());
+        let mut copy = dynamic.new_instance();
+        copy.merge_from_bytes_dyn(&message.write_to_bytes_dyn().unwrap())
+            .unwrap();
+        copy
+    }
+
+    #[test]
+    fn binary_roundtrip() {
+        let mut file = FileDescriptorProto::descriptor_static()
+            .file_descriptor_proto()
+            .clone();
+        // Packed field
+        let mut location = Location::new();
+        location.path = vec![4, 0, 2, 1];
+        location.span = vec![10, 20, 30];
+        file.source_code_info
+            .mut_or_default()
+            .location
+            .push(location);
+
+        let bytes = file.write_to_bytes().unwrap();
+        let dynamic = dynamic_copy(&file);
+        assert_eq!(bytes, dynamic.write_to_bytes_dyn().unwrap());
+    }
+
+    #[test]
+    fn binary_roundtrip_map() {
+        let mut s = Struct::new();
+        let mut value = Value::new();
+        value.set_number_value(10.0);
+        s.fields.insert("a".to_owned(), value);
+        let mut value = Value::new();
+        value.set_string_value("x".to_owned());
+        s.fields.insert("b".to_owned(), value);
+
+        let dynamic = dynamic_copy(&s);
+        let bytes = dynamic.write_to_bytes_dyn().unwrap();
+        assert_eq!(s, Struct::parse_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn empty_map() {
+        let d = dynamic_descriptor(&Struct::descriptor_static());
+        let m = d.new_instance();
+        let fields = d.get_field_by_name("fields").unwrap();
+        let map = fields.get_map(&*m);
+        assert!(map.is_empty());
+        assert_eq!(RuntimeTypeBox::String, map.key_type());
+        assert_eq!(0, (&map).into_iter().count());
+        assert!(m.write_to_bytes_dyn().unwrap().is_empty());
+    }
+
+    #[test]
+    fn repeated_mut() {
+        let mut file = FileDescriptorProto::new();
+        file.dependency = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+        for mut m in vec![
+            Box::new(file.clone()) as Box<dyn crate::MessageDyn>,
+            dynamic_copy(&file),
+        ] {
+            let field = m.descriptor_dyn().get_field_by_name("dependency").unwrap();
+            let s = |s: &str| ReflectValueBox::String(s.to_owned());
+            let mut repeated = field.mut_repeated(&mut *m);
+            repeated.insert(1, s("x"));
+            assert_eq!(s("c"), repeated.remove(3));
+            repeated.swap(0, 2);
+            repeated.retain(|v| v != ReflectValueRef::String("x"));
+            assert_eq!(vec![s("b"), s("a")], repeated);
+            repeated.truncate(1);
+            assert_eq!(
+                Err(ReflectTypeError::Mismatch {
+                    expected: RuntimeTypeBox::String,
+                    actual: RuntimeTypeBox::I32,
+                }),
+                repeated.try_push(ReflectValueBox::I32(1))
+            );
+            assert!(repeated.try_set(0, s("y")).is_ok());
+            assert_eq!(vec![s("y")], repeated);
+        }
+    }
+
+    #[test]
+    fn map_mut() {
+        let mut value = Value::new();
+        value.set_number_value(1.0);
+        let mut s = Struct::new();
+        s.fields.insert("a".to_owned(), value.clone());
+        for mut m in vec![
+            Box::new(s.clone()) as Box<dyn crate::MessageDyn>,
+            dynamic_copy(&s),
+        ] {
+            let field = m.descriptor_dyn().get_field_by_name("fields").unwrap();
+            let mut map = field.mut_map(&mut *m);
+            let number_value = match map.value_type() {
+                RuntimeTypeBox::Message(d) => d.get_field_by_name("number_value").unwrap(),
+                t => panic!("{}", t),
+            };
+
+            number_value.set_singular_field(
+                map.get_mut(ReflectValueRef::String("a"))
+                    .unwrap()
+                    .as_message_mut()
+                    .unwrap(),
+                ReflectValueBox::F64(2.0),
+            );
+            number_value.set_singular_field(
+                map.entry(ReflectValueBox::String("b".to_owned()))
+                    .or_insert_default()
+                    .as_message_mut()
+                    .unwrap(),
+                ReflectValueBox::F64(3.0),
+            );
+            let mut sum = 0.0;
+            for (_, mut v) in map.iter_mut() {
+                sum += number_value
+                    .get_singular_field_or_default(v.as_message_mut().unwrap())
+                    .to_f64()
+                    .unwrap();
+            }
+            assert_eq!(5.0, sum);
+
+            assert!(map
+                .try_insert(
+                    ReflectValueBox::I32(1),
+                    ReflectValueBox::Message(Box::new(Value::new()))
+                )
+                .is_err());
+            assert!(map.remove(ReflectValueRef::String("a")).is_some());
+            assert!(map.remove(ReflectValueRef::String("a")).is_none());
+            assert_eq!(1, map.len());
+        }
+
+        let number_value = Value::descriptor_static()
+            .get_field_by_name("number_value")
+            .unwrap();
+        assert!(number_value
+            .try_set_singular_field(&mut value, ReflectValueBox::F32(1.0))
+            .is_err());
+        assert_eq!(1.0, value.get_number_value());
+    }
+
+    #[test]
+    fn scalar_map_mut() {
+        use crate::descriptor::field_descriptor_proto::Label;
+
+        let mut key = FieldDescriptorProto::new();
+        key.set_name("key".to_owned());
+        key.set_number(1);
+        key.set_field_type(Type::TYPE_STRING);
+        let mut value = FieldDescriptorProto::new();
+        value.set_name("value".to_owned());
+        value.set_number(2);
+        value.set_field_type(Type::TYPE_INT32);
+        let mut entry = DescriptorProto::new();
+        entry.set_name("MEntry".to_owned());
+        entry.options.mut_or_default().set_map_entry(true);
+        entry.field.push(key);
+        entry.field.push(value);
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("m".to_owned());
+        field.set_number(1);
+        field.set_label(Label::LABEL_REPEATED);
+        field.set_field_type(Type::TYPE_MESSAGE);
+        field.set_type_name(".M.MEntry".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        message.nested_type.push(entry);
+        message.field.push(field);
+        let mut file = FileDescriptorProto::new();
+        file.set_name("map.proto".to_owned());
+        file.message_type.push(message);
+        let file = FileDescriptor::new_dynamic(file, Vec::new());
+        let d = file.message_by_package_relative_name("M").unwrap();
+        let field = d.get_field_by_name("m").unwrap();
+
+        let mut m = d.new_instance();
+        let mut map = field.mut_map(&mut *m);
+        let key = |k: &str| ReflectValueBox::String(k.to_owned());
+        map.entry(key("a"))
+            .or_insert_default()
+            .set(ReflectValueBox::I32(1));
+        map.insert(key("b"), ReflectValueBox::I32(2));
+        map.get_mut(ReflectValueRef::String("b"))
+            .unwrap()
+            .set(ReflectValueBox::I32(3));
+        for (_, mut v) in map.iter_mut() {
+            let n = v.get().to_i32().unwrap();
+            v.set(ReflectValueBox::I32(n * 10));
+        }
+        assert_eq!(
+            Some(ReflectValueRef::I32(10)),
+            map.get(ReflectValueRef::String("a"))
+        );
+        assert_eq!(
+            Some(ReflectValueRef::I32(30)),
+            map.get(ReflectValueRef::String("b"))
+        );
+    }
+
+    #[test]
+    fn groups() {
+        use crate::descriptor::field_descriptor_proto::Label;
+
+        fn field(name: &str, number: i32, label: Label, t: Type) -> FieldDescriptorProto {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name(name.to_owned());
+            field.set_number(number);
+            field.set_label(label);
+            field.set_field_type(t);
+            field
+        }
+
+        let mut g = DescriptorProto::new();
+        g.set_name("G".to_owned());
+        g.field
+            .push(field("a", 2, Label::LABEL_OPTIONAL, Type::TYPE_INT32));
+        let mut nested = field("g", 1, Label::LABEL_OPTIONAL, Type::TYPE_GROUP);
+        nested.set_type_name(".M.G".to_owned());
+        g.field.push(nested);
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        message.nested_type.push(g);
+        let mut g = field("g", 1, Label::LABEL_OPTIONAL, Type::TYPE_GROUP);
+        g.set_type_name(".M.G".to_owned());
+        message.field.push(g);
+        let mut r = field("r", 3, Label::LABEL_REPEATED, Type::TYPE_GROUP);
+        r.set_type_name(".M.G".to_owned());
+        message.field.push(r);
+        let mut file = FileDescriptorProto::new();
+        file.set_name("groups.proto".to_owned());
+        file.message_type.push(message);
+        let file = FileDescriptor::new_dynamic(file, Vec::new());
+        let d = file.message_by_package_relative_name("M").unwrap();
+        let a = file
+            .message_by_package_relative_name("M.G")
+            .unwrap()
+            .get_field_by_name("a")
+            .unwrap();
+
+        let bytes = [0x0b, 0x10, 5, 0x0c, 0x1b, 0x1c, 0x1b, 0x10, 7, 0x1c];
+        let m = DynamicMessage::parse_from_bytes(&d, &bytes).unwrap();
+        let g = d.get_field_by_name("g").unwrap();
+        assert_eq!(
+            ReflectValueRef::I32(5),
+            a.get_singular_field_or_default(&*g.get_message(&m))
+        );
+        let r = d.get_field_by_name("r").unwrap();
+        let r = r.get_repeated(&m);
+        assert_eq!(2, r.len());
+        match r.get(1) {
+            ReflectValueRef::Message(g) => {
+                assert_eq!(
+                    ReflectValueRef::I32(7),
+                    a.get_singular_field_or_default(&*g)
+                )
+            }
+            v => panic!("{:?}", v),
+        }
+        assert_eq!(0, m.unknown_fields.iter().count());
+        assert_eq!(&bytes[..], &m.write_to_bytes().unwrap()[..]);
+
+        // End group tag with wrong field number
+        assert!(DynamicMessage::parse_from_bytes(&d, &[0x0b, 0x10, 5, 0x14]).is_err());
+        // Missing end group tag
+        assert!(DynamicMessage::parse_from_bytes(&d, &[0x0b, 0x10, 5]).is_err());
+        // Group nesting is limited like message nesting
+        let mut deep = vec![0x0b; 200];
+        deep.extend(vec![0x0c; 200]);
+        assert!(DynamicMessage::parse_from_bytes(&d, &deep).is_err());
+    }
+
+    #[test]
+    fn delimited() {
+        use crate::descriptor::field_descriptor_proto::Label;
+        use crate::reflect::features::Edition;
+        use crate::reflect::features::FeatureSet;
+        use crate::reflect::features::MessageEncoding;
+        use crate::reflect::features::OptionsWithFeatures;
+
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        let mut child = FieldDescriptorProto::new();
+        child.set_name("child".to_owned());
+        child.set_number(1);
+        child.set_label(Label::LABEL_OPTIONAL);
+        child.set_field_type(Type::TYPE_MESSAGE);
+        child.set_type_name(".M".to_owned());
+        message.field.push(child);
+        let mut a = FieldDescriptorProto::new();
+        a.set_name("a".to_owned());
+        a.set_number(2);
+        a.set_label(Label::LABEL_OPTIONAL);
+        a.set_field_type(Type::TYPE_INT32);
+        message.field.push(a);
+        let mut file = FileDescriptorProto::new();
+        file.set_name("delimited.proto".to_owned());
+        Edition::Edition2023.set_on_file(&mut file);
+        file.options.mut_or_default().set_features(&FeatureSet {
+            message_encoding: Some(MessageEncoding::Delimited),
+            ..Default::default()
+        });
+        file.message_type.push(message);
+        let file = FileDescriptor::new_dynamic(file, Vec::new());
+        let d = file.message_by_package_relative_name("M").unwrap();
+        let child = d.get_field_by_name("child").unwrap();
+        assert!(child.is_delimited());
+
+        let bytes = [0x0b, 0x10, 5, 0x0c, 0x10, 3];
+        let m = DynamicMessage::parse_from_bytes(&d, &bytes).unwrap();
+        assert_eq!(ReflectValueRef::I32(3), m.get("a").unwrap());
+        let a = d.get_field_by_name("a").unwrap();
+        assert_eq!(
+            ReflectValueRef::I32(5),
+            a.get_singular_field_or_default(&*child.get_message(&m))
+        );
+        assert_eq!(&bytes[..], &m.write_to_bytes().unwrap()[..]);
+        // Length-delimited encoding is not accepted
+        assert!(DynamicMessage::parse_from_bytes(&d, &[0x0a, 2, 0x10, 5]).is_err());
+    }
+
+    #[test]
+    fn accessors() {
+        let d = dynamic_descriptor(&FieldDescriptorProto::descriptor_static());
+        let mut m = DynamicMessage::new(d.clone());
+        assert_eq!(&d, m.descriptor());
+
+        m.set("name", "f").unwrap();
+        m.set_by_number(3, 7).unwrap();
+        assert_eq!(ReflectValueRef::String("f"), m.get("name").unwrap());
+        assert_eq!(ReflectValueRef::I32(7), m.get_by_number(3).unwrap());
+        assert_eq!(ReflectValueRef::String(""), m.get("json_name").unwrap());
+        match m.get_field("name").unwrap() {
+            ReflectFieldRef::Optional(Some(ReflectValueRef::String("f"))) => {}
+            _ => panic!("wrong field value"),
+        }
+
+        assert_eq!(
+            Err(DynamicFieldError::UnknownField("nam".to_owned())),
+            m.set("nam", "f")
+        );
+        assert_eq!(
+            Err(DynamicFieldError::UnknownField("1000".to_owned())),
+            m.get_by_number(1000).map(|_| ())
+        );
+        assert_eq!(
+            Err(DynamicFieldError::Type(ReflectTypeError::Mismatch {
+                expected: RuntimeTypeBox::I32,
+                actual: RuntimeTypeBox::String,
+            })),
+            m.set("number", "7")
+        );
+        let d = dynamic_descriptor(&DescriptorProto::descriptor_static());
+        assert!(DynamicMessage::new(d).set("field", 1).is_err());
+    }
+
+    #[test]
+    fn transcode() {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("f".to_owned());
+        field.set_field_type(Type::TYPE_BYTES);
+        field.options.mut_or_default().set_deprecated(true);
+        let mut message = DescriptorProto::new();
+        message.field.push(field);
+        message.reserved_name.push("r".to_owned());
+        let mut file = FileDescriptorProto::new();
+        file.message_type.push(message);
+        file.mut_unknown_fields().add_varint(1000, 5);
+
+        let d = dynamic_descriptor(&FileDescriptorProto::descriptor_static());
+        let dynamic = DynamicMessage::from_message(&file, &d);
+        assert_eq!(
+            dynamic,
+            DynamicMessage::parse_from_bytes(&d, &file.write_to_bytes().unwrap()).unwrap()
+        );
+        assert_eq!(dynamic, dynamic.clone());
+        assert_ne!(dynamic, DynamicMessage::new(d.clone()));
+        assert_eq!(file, dynamic.to_message::<FileDescriptorProto>());
+
+        let mut value = Value::new();
+        value.set_string_value("x".to_owned());
+        let mut s = Struct::new();
+        s.fields.insert("a".to_owned(), value);
+        let d = dynamic_descriptor(&Struct::descriptor_static());
+        let dynamic = DynamicMessage::from_message(&s, &d);
+        assert_eq!(s, dynamic.to_message::<Struct>());
+
+        let mut other = dynamic.clone();
+        let a = other.descriptor().get_field_by_name("fields").unwrap();
+        other.mut_map(&a).clear();
+        assert_ne!(dynamic, other);
+    }
+
+    #[test]
+    fn transcode_mismatch() {
+        let mut file = Timestamp::descriptor_static()
+            .file_descriptor_proto()
+            .clone();
+        let message = &mut file.message_type[0];
+        message.field[1].set_field_type(Type::TYPE_STRING);
+        let mut extra = FieldDescriptorProto::new();
+        extra.set_name("extra".to_owned());
+        extra.set_number(3);
+        extra.set_field_type(Type::TYPE_SINT32);
+        message.field.push(extra);
+        let file = FileDescriptor::new_dynamic(file, Vec::new());
+        let d = file.message_by_package_relative_name("Timestamp").unwrap();
+
+        let mut dynamic = DynamicMessage::new(d.clone());
+        dynamic.set("seconds", 5i64).unwrap();
+        dynamic.set("nanos", "x").unwrap();
+        dynamic.set("extra", -1).unwrap();
+        let timestamp = dynamic.to_message::<Timestamp>();
+        assert_eq!(5, timestamp.seconds);
+        assert_eq!(0, timestamp.nanos);
+        let unknown = timestamp.get_unknown_fields();
+        assert_eq!(
+            vec![b"x".to_vec()],
+            unknown.get(2).unwrap().length_delimited
+        );
+        assert_eq!(vec![1], unknown.get(3).unwrap().varint);
+        let bytes = timestamp.write_to_bytes().unwrap();
+        assert_eq!(
+            dynamic,
+            DynamicMessage::parse_from_bytes(&d, &bytes).unwrap()
+        );
+
+        let mut timestamp = Timestamp::new();
+        timestamp.nanos = 7;
+        let dynamic = DynamicMessage::from_message(&timestamp, &d);
+        assert_eq!(Ok(ReflectValueRef::String("")), dynamic.get("nanos"));
+        assert_eq!(vec![7], dynamic.unknown_fields.get(2).unwrap().varint);
+    }
+
+    #[test]
+    fn closed_enum_unknown_values() {
+        use crate::descriptor::field_descriptor_proto::Label;
+        use crate::descriptor::EnumDescriptorProto;
+        use crate::descriptor::EnumValueDescriptorProto;
+
+        let mut e = EnumDescriptorProto::new();
+        e.set_name("E".to_owned());
+        for (name, number) in &[("A", 0), ("B", 1)] {
+            let mut value = EnumValueDescriptorProto::new();
+            value.set_name((*name).to_owned());
+            value.set_number(*number);
+            e.value.push(value);
+        }
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        for (name, number, label) in &[
+            ("e", 1, Label::LABEL_OPTIONAL),
+            ("r", 2, Label::LABEL_REPEATED),
+        ] {
+            let mut field = FieldDescriptorProto::new();
+            field.set_name((*name).to_owned());
+            field.set_number(*number);
+            field.set_label(*label);
+            field.set_field_type(Type::TYPE_ENUM);
+            field.set_type_name(".E".to_owned());
+            message.field.push(field);
+        }
+        let mut file = FileDescriptorProto::new();
+        file.set_name("closed.proto".to_owned());
+        file.enum_type.push(e);
+        file.message_type.push(message);
+        let file = FileDescriptor::new_dynamic(file, Vec::new());
+        let d = file.message_by_package_relative_name("M").unwrap();
+        assert!(!file.enums()[0].is_open());
+
+        let mut m = d.new_instance();
+        m.merge_from_bytes_dyn(&[0x08, 0x05, 0x10, 0x01, 0x10, 0x07])
+            .unwrap();
+
+        let e = d.get_field_by_name("e").unwrap();
+        let r = d.get_field_by_name("r").unwrap();
+        assert!(!e.has_field(&*m));
+        assert_eq!(1, r.get_repeated(&*m).len());
+        let unknown_fields = m.get_unknown_fields_dyn();
+        assert_eq!(&[5], &unknown_fields.get(1).unwrap().varint[..]);
+        assert_eq!(&[7], &unknown_fields.get(2).unwrap().varint[..]);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::reflect::repeated::ReflectRepeated;
 use crate::reflect::repeated::ReflectRepeatedIter;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueGuard;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeTypeBox;
 
@@ -23,14 +25,42 @@ impl ReflectRepeated for DynamicRepeated {
         self.vec[index].as_value_ref()
     }
 
-    fn set(&mut self, index: usize, value: ReflectValueBox) {
-        assert_eq!(self.elem, value.get_type());
+    fn get_mut(&mut self, index: usize) -> ReflectValueGuard<'_> {
+        self.vec[index].as_value_guard()
+    }
+
+    fn set(&mut self, index: usize, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        let value = ReflectTypeError::check(self.elem.clone(), value)?;
         self.vec[index] = value;
+        Ok(())
     }
 
-    fn push(&mut self, value: ReflectValueBox) {
-        assert_eq!(self.elem, value.get_type());
+    fn push(&mut self, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        let value = ReflectTypeError::check(self.elem.clone(), value)?;
         self.vec.push(value);
+        Ok(())
+    }
+
+    fn insert(&mut self, index: usize, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        let value = ReflectTypeError::check(self.elem.clone(), value)?;
+        self.vec.insert(index, value);
+        Ok(())
+    }
+
+    fn remove(&mut self, index: usize) -> ReflectValueBox {
+        self.vec.remove(index)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len);
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.vec.swap(a, b);
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(ReflectValueRef) -> bool) {
+        self.vec.retain(|v| f(v.as_value_ref()));
     }
 
     fn clear(&mut self) {
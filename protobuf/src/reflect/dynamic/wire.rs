@@ -0,0 +1,372 @@
+//! Binary encoding of values of dynamic messages.
+
+use crate::descriptor::field_descriptor_proto::Type;
+use crate::message_dyn::MessageDyn;
+use crate::reflect::dynamic::DynamicMessage;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeTypeBox;
+use crate::rt;
+use crate::wire_format;
+use crate::wire_format::WireType;
+use crate::zigzag::encode_zig_zag_32;
+use crate::zigzag::encode_zig_zag_64;
+use crate::CodedInputStream;
+use crate::CodedOutputStream;
+use crate::ProtobufResult;
+use crate::UnknownFields;
+use crate::UnknownValue;
+
+/// Wire type of non-packed value of given type.
+pub(crate) fn wire_type(t: Type) -> WireType {
+    match t {
+        Type::TYPE_INT32
+        | Type::TYPE_INT64
+        | Type::TYPE_UINT32
+        | Type::TYPE_UINT64
+        | Type::TYPE_SINT32
+        | Type::TYPE_SINT64
+        | Type::TYPE_BOOL
+        | Type::TYPE_ENUM => WireType::WireTypeVarint,
+        Type::TYPE_FIXED32 | Type::TYPE_SFIXED32 | Type::TYPE_FLOAT => WireType::WireTypeFixed32,
+        Type::TYPE_FIXED64 | Type::TYPE_SFIXED64 | Type::TYPE_DOUBLE => WireType::WireTypeFixed64,
+        Type::TYPE_STRING | Type::TYPE_BYTES | Type::TYPE_MESSAGE => {
+            WireType::WireTypeLengthDelimited
+        }
+        Type::TYPE_GROUP => WireType::WireTypeStartGroup,
+    }
+}
+
+/// Type of the field on the wire: message fields with delimited encoding
+/// are written like groups.
+pub(crate) fn field_type(field: &FieldDescriptor) -> Type {
+    if field.is_delimited() {
+        Type::TYPE_GROUP
+    } else {
+        field.get_proto().get_field_type()
+    }
+}
+
+/// Values of this type can be stored in packed repeated field.
+pub(crate) fn is_packable(t: Type) -> bool {
+    matches!(
+        wire_type(t),
+        WireType::WireTypeVarint | WireType::WireTypeFixed32 | WireType::WireTypeFixed64
+    )
+}
+
+/// Types of key and value fields of the map entry.
+pub(crate) fn map_entry_types(field: &FieldDescriptor) -> (Type, Type) {
+    let type_name = field.get_proto().get_type_name();
+    let type_name = type_name.strip_prefix('.').unwrap_or(type_name);
+    let entry: MessageDescriptor = field
+        .message_descriptor
+        .get_nested_messages()
+        .into_iter()
+        .find(|m| m.full_name() == type_name)
+        .expect("map entry");
+    let entry = entry.get_proto();
+    (
+        entry.field[0].get_field_type(),
+        entry.field[1].get_field_type(),
+    )
+}
+
+/// Read length-delimited message into given message.
+pub(crate) fn merge_message(
+    is: &mut CodedInputStream,
+    message: &mut dyn MessageDyn,
+) -> ProtobufResult<()> {
+    is.incr_recursion()?;
+    let res = (|| {
+        let len = is.read_raw_varint64()?;
+        let old_limit = is.push_limit(len)?;
+        message.merge_from_dyn(is)?;
+        is.pop_limit(old_limit);
+        Ok(())
+    })();
+    is.decr_recursion();
+    res
+}
+
+/// Read group content into given message, up to and including the end group tag.
+pub(crate) fn merge_group(
+    is: &mut CodedInputStream,
+    field_number: u32,
+    message: &mut dyn MessageDyn,
+) -> ProtobufResult<()> {
+    is.incr_recursion()?;
+    let res = match message.downcast_mut::<DynamicMessage>() {
+        Some(message) => message.merge_group_fields(is, field_number),
+        // Generated messages read fields until the end of input
+        None => is.read_group_bytes(field_number).and_then(|bytes| {
+            let mut is = is.nested(&bytes);
+            message.merge_from_dyn(&mut is)
+        }),
+    };
+    is.decr_recursion();
+    res
+}
+
+/// Read a value of given type, without tag.
+pub(crate) fn read_value(
+    is: &mut CodedInputStream,
+    t: Type,
+    runtime_type: &RuntimeTypeBox,
+) -> ProtobufResult<ReflectValueBox> {
+    Ok(match t {
+        Type::TYPE_INT32 => ReflectValueBox::I32(is.read_int32()?),
+        Type::TYPE_INT64 => ReflectValueBox::I64(is.read_int64()?),
+        Type::TYPE_UINT32 => ReflectValueBox::U32(is.read_uint32()?),
+        Type::TYPE_UINT64 => ReflectValueBox::U64(is.read_uint64()?),
+        Type::TYPE_SINT32 => ReflectValueBox::I32(is.read_sint32()?),
+        Type::TYPE_SINT64 => ReflectValueBox::I64(is.read_sint64()?),
+        Type::TYPE_FIXED32 => ReflectValueBox::U32(is.read_fixed32()?),
+        Type::TYPE_FIXED64 => ReflectValueBox::U64(is.read_fixed64()?),
+        Type::TYPE_SFIXED32 => ReflectValueBox::I32(is.read_sfixed32()?),
+        Type::TYPE_SFIXED64 => ReflectValueBox::I64(is.read_sfixed64()?),
+        Type::TYPE_FLOAT => ReflectValueBox::F32(is.read_float()?),
+        Type::TYPE_DOUBLE => ReflectValueBox::F64(is.read_double()?),
+        Type::TYPE_BOOL => ReflectValueBox::Bool(is.read_bool()?),
+        Type::TYPE_STRING => ReflectValueBox::String(is.read_string()?),
+        Type::TYPE_BYTES => ReflectValueBox::Bytes(is.read_bytes()?),
+        Type::TYPE_ENUM => match runtime_type {
+            RuntimeTypeBox::Enum(e) => ReflectValueBox::Enum(e.clone(), is.read_int32()?),
+            t => panic!("expecting enum type, got {}", t),
+        },
+        Type::TYPE_MESSAGE => match runtime_type {
+            RuntimeTypeBox::Message(m) => {
+                let mut message = m.new_instance();
+                merge_message(is, &mut *message)?;
+                ReflectValueBox::Message(message)
+            }
+            t => panic!("expecting message type, got {}", t),
+        },
+        Type::TYPE_GROUP => {
+            return Err(rt::unexpected_wire_type(WireType::WireTypeStartGroup));
+        }
+    })
+}
+
+/// Compute size of value with tag, caching sizes of nested messages.
+pub(crate) fn compute_field_size(field_number: u32, t: Type, value: &ReflectValueRef) -> u32 {
+    let tag_size = rt::tag_size(field_number);
+    let end_tag_size = if t == Type::TYPE_GROUP { tag_size } else { 0 };
+    tag_size + compute_value_size_no_tag(t, value) + end_tag_size
+}
+
+/// Write value with tag, sizes of nested messages must be cached.
+pub(crate) fn write_field(
+    os: &mut CodedOutputStream,
+    field_number: u32,
+    t: Type,
+    value: &ReflectValueRef,
+) -> ProtobufResult<()> {
+    os.write_tag(field_number, wire_type(t))?;
+    write_value_no_tag(os, t, value)?;
+    if t == Type::TYPE_GROUP {
+        os.write_tag(field_number, WireType::WireTypeEndGroup)?;
+    }
+    Ok(())
+}
+
+/// Compute size of value without tag, caching sizes of nested messages.
+///
+/// Size of group does not include the end group tag.
+pub(crate) fn compute_value_size_no_tag(t: Type, value: &ReflectValueRef) -> u32 {
+    match (t, value) {
+        (Type::TYPE_INT32, ReflectValueRef::I32(v)) => rt::compute_raw_varint64_size(*v as u64),
+        (Type::TYPE_INT64, ReflectValueRef::I64(v)) => rt::compute_raw_varint64_size(*v as u64),
+        (Type::TYPE_UINT32, ReflectValueRef::U32(v)) => rt::compute_raw_varint32_size(*v),
+        (Type::TYPE_UINT64, ReflectValueRef::U64(v)) => rt::compute_raw_varint64_size(*v),
+        (Type::TYPE_SINT32, ReflectValueRef::I32(v)) => {
+            rt::compute_raw_varint32_size(encode_zig_zag_32(*v))
+        }
+        (Type::TYPE_SINT64, ReflectValueRef::I64(v)) => {
+            rt::compute_raw_varint64_size(encode_zig_zag_64(*v))
+        }
+        (Type::TYPE_ENUM, ReflectValueRef::Enum(_, v)) => rt::compute_raw_varint64_size(*v as u64),
+        (Type::TYPE_BOOL, _) => 1,
+        (Type::TYPE_FIXED32, _) | (Type::TYPE_SFIXED32, _) | (Type::TYPE_FLOAT, _) => 4,
+        (Type::TYPE_FIXED64, _) | (Type::TYPE_SFIXED64, _) | (Type::TYPE_DOUBLE, _) => 8,
+        (Type::TYPE_STRING, ReflectValueRef::String(s)) => {
+            rt::compute_raw_varint64_size(s.len() as u64) + s.len() as u32
+        }
+        (Type::TYPE_BYTES, ReflectValueRef::Bytes(b)) => {
+            rt::compute_raw_varint64_size(b.len() as u64) + b.len() as u32
+        }
+        (Type::TYPE_MESSAGE, ReflectValueRef::Message(m)) => {
+            let len = m.compute_size_dyn();
+            rt::compute_raw_varint32_size(len) + len
+        }
+        (Type::TYPE_GROUP, ReflectValueRef::Message(m)) => m.compute_size_dyn(),
+        (t, v) => panic!("value {:?} does not match type {:?}", v, t),
+    }
+}
+
+/// Write value without tag, sizes of nested messages must be cached.
+///
+/// The end group tag is not written.
+pub(crate) fn write_value_no_tag(
+    os: &mut CodedOutputStream,
+    t: Type,
+    value: &ReflectValueRef,
+) -> ProtobufResult<()> {
+    match (t, value) {
+        (Type::TYPE_INT32, ReflectValueRef::I32(v)) => os.write_int32_no_tag(*v),
+        (Type::TYPE_INT64, ReflectValueRef::I64(v)) => os.write_int64_no_tag(*v),
+        (Type::TYPE_UINT32, ReflectValueRef::U32(v)) => os.write_uint32_no_tag(*v),
+        (Type::TYPE_UINT64, ReflectValueRef::U64(v)) => os.write_uint64_no_tag(*v),
+        (Type::TYPE_SINT32, ReflectValueRef::I32(v)) => os.write_sint32_no_tag(*v),
+        (Type::TYPE_SINT64, ReflectValueRef::I64(v)) => os.write_sint64_no_tag(*v),
+        (Type::TYPE_FIXED32, ReflectValueRef::U32(v)) => os.write_fixed32_no_tag(*v),
+        (Type::TYPE_FIXED64, ReflectValueRef::U64(v)) => os.write_fixed64_no_tag(*v),
+        (Type::TYPE_SFIXED32, ReflectValueRef::I32(v)) => os.write_sfixed32_no_tag(*v),
+        (Type::TYPE_SFIXED64, ReflectValueRef::I64(v)) => os.write_sfixed64_no_tag(*v),
+        (Type::TYPE_FLOAT, ReflectValueRef::F32(v)) => os.write_float_no_tag(*v),
+        (Type::TYPE_DOUBLE, ReflectValueRef::F64(v)) => os.write_double_no_tag(*v),
+        (Type::TYPE_BOOL, ReflectValueRef::Bool(v)) => os.write_bool_no_tag(*v),
+        (Type::TYPE_STRING, ReflectValueRef::String(v)) => os.write_string_no_tag(v),
+        (Type::TYPE_BYTES, ReflectValueRef::Bytes(v)) => os.write_bytes_no_tag(v),
+        (Type::TYPE_ENUM, ReflectValueRef::Enum(_, v)) => os.write_enum_no_tag(*v),
+        (Type::TYPE_MESSAGE, ReflectValueRef::Message(m)) => {
+            os.write_raw_varint32(m.get_cached_size_dyn())?;
+            m.write_to_with_cached_sizes_dyn(os)
+        }
+        (Type::TYPE_GROUP, ReflectValueRef::Message(m)) => m.write_to_with_cached_sizes_dyn(os),
+        (t, v) => panic!("value {:?} does not match type {:?}", v, t),
+    }
+}
+
+/// Size of a map entry without tag and length.
+pub(crate) fn compute_map_entry_size(
+    types: (Type, Type),
+    key: &ReflectValueRef,
+    value: &ReflectValueRef,
+) -> u32 {
+    // Tags of fields 1 and 2 occupy one byte
+    1 + compute_value_size_no_tag(types.0, key) + 1 + compute_value_size_no_tag(types.1, value)
+}
+
+/// Write map entry, nested sizes must be cached.
+pub(crate) fn write_map_entry(
+    os: &mut CodedOutputStream,
+    field_number: u32,
+    types: (Type, Type),
+    key: &ReflectValueRef,
+    value: &ReflectValueRef,
+) -> ProtobufResult<()> {
+    // Sizes of nested messages are cached, so recomputing entry size is cheap
+    let entry_size = 1
+        + compute_value_size_no_tag_cached(types.0, key)
+        + 1
+        + compute_value_size_no_tag_cached(types.1, value);
+    os.write_tag(field_number, wire_format::WireTypeLengthDelimited)?;
+    os.write_raw_varint32(entry_size)?;
+    os.write_tag(1, wire_type(types.0))?;
+    write_value_no_tag(os, types.0, key)?;
+    os.write_tag(2, wire_type(types.1))?;
+    write_value_no_tag(os, types.1, value)?;
+    Ok(())
+}
+
+/// Like `compute_value_size_no_tag`, but use cached size for messages.
+pub(crate) fn compute_value_size_no_tag_cached(t: Type, value: &ReflectValueRef) -> u32 {
+    match value {
+        ReflectValueRef::Message(m) => {
+            let len = m.get_cached_size_dyn();
+            rt::compute_raw_varint32_size(len) + len
+        }
+        value => compute_value_size_no_tag(t, value),
+    }
+}
+
+/// Store value with given field number and type as unknown field.
+pub(crate) fn add_unknown(
+    unknown_fields: &mut UnknownFields,
+    field_number: u32,
+    t: Type,
+    value: &ReflectValueRef,
+) {
+    let value = match (t, value) {
+        (Type::TYPE_GROUP, ReflectValueRef::Message(m)) => {
+            unknown_fields.add_group(field_number, message_to_unknown_fields(&**m));
+            return;
+        }
+        (Type::TYPE_SINT32, ReflectValueRef::I32(v)) => UnknownValue::sint32(*v),
+        (Type::TYPE_SINT64, ReflectValueRef::I64(v)) => UnknownValue::sint64(*v),
+        (Type::TYPE_SFIXED32, ReflectValueRef::I32(v)) => UnknownValue::sfixed32(*v),
+        (Type::TYPE_SFIXED64, ReflectValueRef::I64(v)) => UnknownValue::sfixed64(*v),
+        (Type::TYPE_FIXED32, ReflectValueRef::U32(v)) => UnknownValue::Fixed32(*v),
+        (Type::TYPE_FIXED64, ReflectValueRef::U64(v)) => UnknownValue::Fixed64(*v),
+        (_, ReflectValueRef::I32(v)) | (_, ReflectValueRef::Enum(_, v)) => UnknownValue::int32(*v),
+        (_, ReflectValueRef::I64(v)) => UnknownValue::int64(*v),
+        (_, ReflectValueRef::U32(v)) => UnknownValue::Varint(*v as u64),
+        (_, ReflectValueRef::U64(v)) => UnknownValue::Varint(*v),
+        (_, ReflectValueRef::Bool(v)) => UnknownValue::Varint(*v as u64),
+        (_, ReflectValueRef::F32(v)) => UnknownValue::float(*v),
+        (_, ReflectValueRef::F64(v)) => UnknownValue::double(*v),
+        (_, ReflectValueRef::String(v)) => UnknownValue::LengthDelimited(v.as_bytes().to_vec()),
+        (_, ReflectValueRef::Bytes(v)) => UnknownValue::LengthDelimited(v.to_vec()),
+        (_, ReflectValueRef::Message(m)) => {
+            UnknownValue::LengthDelimited(message_to_unknown_fields(&**m).write_to_bytes())
+        }
+    };
+    unknown_fields.add_value(field_number, value);
+}
+
+/// Store map entry as unknown field.
+pub(crate) fn add_unknown_map_entry(
+    unknown_fields: &mut UnknownFields,
+    field_number: u32,
+    types: (Type, Type),
+    key: &ReflectValueRef,
+    value: &ReflectValueRef,
+) {
+    let mut entry = UnknownFields::new();
+    add_unknown(&mut entry, 1, types.0, key);
+    add_unknown(&mut entry, 2, types.1, value);
+    unknown_fields.add_length_delimited(field_number, entry.write_to_bytes());
+}
+
+/// Store all values of the field as unknown fields.
+pub(crate) fn add_unknown_field(
+    unknown_fields: &mut UnknownFields,
+    field: &FieldDescriptor,
+    value: ReflectFieldRef,
+) {
+    let t = field_type(field);
+    let number = field.get_proto().get_number() as u32;
+    match value {
+        ReflectFieldRef::Optional(Some(v)) => {
+            if field.has_presence() || v.is_non_zero() {
+                add_unknown(unknown_fields, number, t, &v);
+            }
+        }
+        ReflectFieldRef::Optional(None) => {}
+        ReflectFieldRef::Repeated(repeated) => {
+            for v in repeated {
+                add_unknown(unknown_fields, number, t, &v);
+            }
+        }
+        ReflectFieldRef::Map(map) => {
+            let types = map_entry_types(field);
+            for (k, v) in &map {
+                add_unknown_map_entry(unknown_fields, number, types, &k, &v);
+            }
+        }
+    }
+}
+
+/// Encode all fields of the message as unknown fields.
+fn message_to_unknown_fields(message: &dyn MessageDyn) -> UnknownFields {
+    let mut unknown_fields = UnknownFields::new();
+    for field in message.descriptor_dyn().fields() {
+        add_unknown_field(&mut unknown_fields, &field, field.get_reflect(message));
+    }
+    unknown_fields.merge_from(message.get_unknown_fields_dyn());
+    unknown_fields
+}
@@ -4,14 +4,25 @@ use std::hash::Hash;
 use std::hash::Hasher;
 
 use crate::descriptor::EnumDescriptorProto;
+use crate::descriptor::EnumOptions;
 use crate::descriptor::EnumValueDescriptorProto;
+use crate::descriptor::EnumValueOptions;
 use crate::enums::ProtobufEnum;
+use crate::ext::ExtFieldOptional;
+use crate::ext::ExtFieldRepeated;
+use crate::message::Message;
 use crate::reflect::enums::dynamic::DynamicEnumDescriptor;
 use crate::reflect::enums::generated::GeneratedEnumDescriptor;
 #[cfg(not(rustc_nightly))]
 use crate::reflect::enums::generated::GetEnumDescriptor;
+use crate::reflect::features::EnumType;
+use crate::reflect::features::OptionsWithFeatures;
+use crate::reflect::features::ResolvedFeatures;
 use crate::reflect::file::FileDescriptorImpl;
+use crate::reflect::options;
+use crate::reflect::types::ProtobufType;
 use crate::reflect::FileDescriptor;
+use crate::reflect::ReflectValueBox;
 
 pub(crate) mod dynamic;
 pub(crate) mod generated;
@@ -74,6 +85,55 @@ impl EnumValueDescriptor {
         &self.enum_descriptor
     }
 
+    /// Options of this enum value, default instance if not specified in `.proto` file.
+    pub fn options(&self) -> &EnumValueOptions {
+        self.get_proto().options.get_or_default()
+    }
+
+    /// Get custom option of this enum value by extension generated by `protoc`.
+    pub fn get_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldOptional<EnumValueOptions, T>,
+    ) -> Option<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get custom option of this enum value by extension fully-qualified name,
+    /// e. g. `acme.sensitive`.
+    ///
+    /// Extension is looked up in the file of this enum value and its dependencies.
+    /// Returns `None` if option is not set, extension is not found
+    /// or extension is repeated.
+    pub fn get_option_by_name(&self, name: &str) -> Option<ReflectValueBox> {
+        options::custom_option(
+            &self.enum_descriptor.file_descriptor,
+            ".google.protobuf.EnumValueOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    /// Get repeated custom option of this enum value by extension generated by `protoc`.
+    pub fn get_repeated_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldRepeated<EnumValueOptions, T>,
+    ) -> Vec<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get repeated custom option of this enum value by extension fully-qualified name.
+    ///
+    /// Returns empty vec if option is not set, extension is not found
+    /// or extension is not repeated.
+    pub fn get_repeated_option_by_name(&self, name: &str) -> Vec<ReflectValueBox> {
+        options::custom_repeated_option(
+            &self.enum_descriptor.file_descriptor,
+            ".google.protobuf.EnumValueOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
     /// Convert this value descriptor into proper enum object.
     ///
     /// ```
@@ -151,6 +211,68 @@ impl EnumDescriptor {
         }
     }
 
+    /// Features of this enum, inherited from file and containing messages.
+    pub fn features(&self) -> ResolvedFeatures {
+        let file = self.file_descriptor.proto();
+        let entry = self.file_descriptor.enum_index_entry(self.index);
+        let features = ResolvedFeatures::for_messages(file, &entry.message_path.eval_path(file));
+        features.merge(&self.get_proto().options.get_or_default().features())
+    }
+
+    /// Options of this enum, default instance if not specified in `.proto` file.
+    pub fn options(&self) -> &EnumOptions {
+        self.get_proto().options.get_or_default()
+    }
+
+    /// Get custom option of this enum by extension generated by `protoc`.
+    pub fn get_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldOptional<EnumOptions, T>,
+    ) -> Option<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get custom option of this enum by extension fully-qualified name,
+    /// e. g. `acme.sensitive`.
+    ///
+    /// Extension is looked up in the file of this enum and its dependencies.
+    /// Returns `None` if option is not set, extension is not found
+    /// or extension is repeated.
+    pub fn get_option_by_name(&self, name: &str) -> Option<ReflectValueBox> {
+        options::custom_option(
+            &self.file_descriptor,
+            ".google.protobuf.EnumOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    /// Get repeated custom option of this enum by extension generated by `protoc`.
+    pub fn get_repeated_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldRepeated<EnumOptions, T>,
+    ) -> Vec<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get repeated custom option of this enum by extension fully-qualified name.
+    ///
+    /// Returns empty vec if option is not set, extension is not found
+    /// or extension is not repeated.
+    pub fn get_repeated_option_by_name(&self, name: &str) -> Vec<ReflectValueBox> {
+        options::custom_repeated_option(
+            &self.file_descriptor,
+            ".google.protobuf.EnumOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    /// This enum is open: unknown values are preserved in fields.
+    pub fn is_open(&self) -> bool {
+        self.features().enum_type == EnumType::Open
+    }
+
     /// Get `EnumDescriptor` object for given enum type
     pub fn for_type<E: ProtobufEnum>() -> EnumDescriptor {
         E::enum_descriptor_static()
@@ -0,0 +1,700 @@
+//! Protobuf [editions](https://protobuf.dev/editions/overview/) and features.
+//!
+//! In editions files behavior of fields and enums is configured by features
+//! (e. g. `option features.field_presence = IMPLICIT;`) rather than by syntax.
+//! Features are inherited: file features are defaults for messages,
+//! message features are defaults for fields and so on.
+//! Proto2 and proto3 files are treated as editions with
+//! fixed defaults, so the same resolution applies to all files.
+
+use crate::descriptor;
+use crate::descriptor::feature_set;
+use crate::descriptor::field_descriptor_proto;
+use crate::descriptor::DescriptorProto;
+use crate::descriptor::EnumOptions;
+use crate::descriptor::EnumValueOptions;
+use crate::descriptor::FieldDescriptorProto;
+use crate::descriptor::FieldOptions;
+use crate::descriptor::FileDescriptorProto;
+use crate::descriptor::FileOptions;
+use crate::descriptor::MessageOptions;
+use crate::descriptor::MethodOptions;
+use crate::descriptor::OneofOptions;
+use crate::descriptor::ServiceOptions;
+use crate::Message;
+use crate::MessageField;
+use crate::ProtobufEnum;
+
+/// Edition of `.proto` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Edition {
+    /// `syntax = "proto2";` or no syntax statement.
+    Proto2,
+    /// `syntax = "proto3";`
+    Proto3,
+    /// `edition = "2023";`
+    Edition2023,
+}
+
+impl Edition {
+    /// Value of `google.protobuf.Edition` enum.
+    pub fn value(&self) -> i32 {
+        match self {
+            Edition::Proto2 => 998,
+            Edition::Proto3 => 999,
+            Edition::Edition2023 => 1000,
+        }
+    }
+
+    /// Edition by value of `google.protobuf.Edition` enum.
+    pub fn from_i32(value: i32) -> Option<Edition> {
+        match value {
+            998 => Some(Edition::Proto2),
+            999 => Some(Edition::Proto3),
+            1000 => Some(Edition::Edition2023),
+            _ => None,
+        }
+    }
+
+    /// Edition by name used in `edition` statement, e. g. `2023`.
+    pub fn from_name(name: &str) -> Option<Edition> {
+        match name {
+            "2023" => Some(Edition::Edition2023),
+            _ => None,
+        }
+    }
+
+    /// Name used in `syntax` or `edition` statement.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Edition::Proto2 => "proto2",
+            Edition::Proto3 => "proto3",
+            Edition::Edition2023 => "2023",
+        }
+    }
+
+    /// Edition of file.
+    ///
+    /// Files with unknown edition are treated as the latest supported edition.
+    pub fn of_file(file: &FileDescriptorProto) -> Edition {
+        match file.get_syntax() {
+            "" | "proto2" => Edition::Proto2,
+            "proto3" => Edition::Proto3,
+            _ => Edition::from_i32(file.get_edition().value()).unwrap_or(Edition::Edition2023),
+        }
+    }
+
+    /// Set `syntax` and `edition` fields of file.
+    pub fn set_on_file(&self, file: &mut FileDescriptorProto) {
+        file.clear_edition();
+        match self {
+            Edition::Proto2 | Edition::Proto3 => file.set_syntax(self.name().to_owned()),
+            _ => {
+                file.set_syntax("editions".to_owned());
+                file.set_edition(descriptor::Edition::from_i32(self.value()).unwrap());
+            }
+        }
+    }
+}
+
+/// `features.field_presence`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldPresence {
+    /// Field tracks whether it is set.
+    Explicit,
+    /// Field is unset when it has default value, like proto3 singular fields.
+    Implicit,
+    /// Proto2 `required` field.
+    LegacyRequired,
+}
+
+/// `features.enum_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnumType {
+    /// Unknown values are stored in fields, like proto3 enums.
+    Open,
+    /// Unknown values are treated as unknown fields, like proto2 enums.
+    Closed,
+}
+
+/// `features.repeated_field_encoding`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RepeatedFieldEncoding {
+    /// Repeated scalar fields are packed.
+    Packed,
+    /// Each element is written with own tag.
+    Expanded,
+}
+
+/// `features.utf8_validation`
+///
+/// Rust strings are always valid UTF-8, so generated code validates
+/// `string` fields regardless of this feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Utf8Validation {
+    /// Strings are validated when parsed.
+    Verify,
+    /// Strings are not validated.
+    None,
+}
+
+/// `features.message_encoding`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageEncoding {
+    /// Message fields are written as length-delimited.
+    LengthPrefixed,
+    /// Message fields are written like groups.
+    Delimited,
+}
+
+/// `features.json_format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonFormat {
+    /// JSON mapping is well-defined.
+    Allow,
+    /// Legacy JSON mapping, conflicts are allowed.
+    LegacyBestEffort,
+}
+
+impl FieldPresence {
+    fn value(&self) -> i32 {
+        match self {
+            FieldPresence::Explicit => 1,
+            FieldPresence::Implicit => 2,
+            FieldPresence::LegacyRequired => 3,
+        }
+    }
+
+    fn from_i32(value: i32) -> Option<FieldPresence> {
+        match value {
+            1 => Some(FieldPresence::Explicit),
+            2 => Some(FieldPresence::Implicit),
+            3 => Some(FieldPresence::LegacyRequired),
+            _ => None,
+        }
+    }
+}
+
+impl EnumType {
+    fn value(&self) -> i32 {
+        match self {
+            EnumType::Open => 1,
+            EnumType::Closed => 2,
+        }
+    }
+
+    fn from_i32(value: i32) -> Option<EnumType> {
+        match value {
+            1 => Some(EnumType::Open),
+            2 => Some(EnumType::Closed),
+            _ => None,
+        }
+    }
+}
+
+impl RepeatedFieldEncoding {
+    fn value(&self) -> i32 {
+        match self {
+            RepeatedFieldEncoding::Packed => 1,
+            RepeatedFieldEncoding::Expanded => 2,
+        }
+    }
+
+    fn from_i32(value: i32) -> Option<RepeatedFieldEncoding> {
+        match value {
+            1 => Some(RepeatedFieldEncoding::Packed),
+            2 => Some(RepeatedFieldEncoding::Expanded),
+            _ => None,
+        }
+    }
+}
+
+impl Utf8Validation {
+    fn value(&self) -> i32 {
+        match self {
+            Utf8Validation::Verify => 2,
+            Utf8Validation::None => 3,
+        }
+    }
+
+    fn from_i32(value: i32) -> Option<Utf8Validation> {
+        match value {
+            2 => Some(Utf8Validation::Verify),
+            3 => Some(Utf8Validation::None),
+            _ => None,
+        }
+    }
+}
+
+impl MessageEncoding {
+    fn value(&self) -> i32 {
+        match self {
+            MessageEncoding::LengthPrefixed => 1,
+            MessageEncoding::Delimited => 2,
+        }
+    }
+
+    fn from_i32(value: i32) -> Option<MessageEncoding> {
+        match value {
+            1 => Some(MessageEncoding::LengthPrefixed),
+            2 => Some(MessageEncoding::Delimited),
+            _ => None,
+        }
+    }
+}
+
+impl JsonFormat {
+    fn value(&self) -> i32 {
+        match self {
+            JsonFormat::Allow => 1,
+            JsonFormat::LegacyBestEffort => 2,
+        }
+    }
+
+    fn from_i32(value: i32) -> Option<JsonFormat> {
+        match value {
+            1 => Some(JsonFormat::Allow),
+            2 => Some(JsonFormat::LegacyBestEffort),
+            _ => None,
+        }
+    }
+}
+
+/// Features explicitly set on an element (`google.protobuf.FeatureSet`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureSet {
+    /// `features.field_presence`
+    pub field_presence: Option<FieldPresence>,
+    /// `features.enum_type`
+    pub enum_type: Option<EnumType>,
+    /// `features.repeated_field_encoding`
+    pub repeated_field_encoding: Option<RepeatedFieldEncoding>,
+    /// `features.utf8_validation`
+    pub utf8_validation: Option<Utf8Validation>,
+    /// `features.message_encoding`
+    pub message_encoding: Option<MessageEncoding>,
+    /// `features.json_format`
+    pub json_format: Option<JsonFormat>,
+}
+
+impl FeatureSet {
+    /// Features set in `google.protobuf.FeatureSet` message.
+    ///
+    /// Unknown feature values are ignored.
+    pub fn from_proto(proto: &descriptor::FeatureSet) -> FeatureSet {
+        FeatureSet {
+            field_presence: FieldPresence::from_i32(proto.get_field_presence().value()),
+            enum_type: EnumType::from_i32(proto.get_enum_type().value()),
+            repeated_field_encoding: RepeatedFieldEncoding::from_i32(
+                proto.get_repeated_field_encoding().value(),
+            ),
+            utf8_validation: Utf8Validation::from_i32(proto.get_utf8_validation().value()),
+            message_encoding: MessageEncoding::from_i32(proto.get_message_encoding().value()),
+            json_format: JsonFormat::from_i32(proto.get_json_format().value()),
+        }
+    }
+
+    /// Convert to `google.protobuf.FeatureSet` message.
+    pub fn to_proto(&self) -> descriptor::FeatureSet {
+        let mut r = descriptor::FeatureSet::new();
+        if let Some(v) = self.field_presence {
+            r.set_field_presence(feature_set::FieldPresence::from_i32(v.value()).unwrap());
+        }
+        if let Some(v) = self.enum_type {
+            r.set_enum_type(feature_set::EnumType::from_i32(v.value()).unwrap());
+        }
+        if let Some(v) = self.repeated_field_encoding {
+            r.set_repeated_field_encoding(
+                feature_set::RepeatedFieldEncoding::from_i32(v.value()).unwrap(),
+            );
+        }
+        if let Some(v) = self.utf8_validation {
+            r.set_utf8_validation(feature_set::Utf8Validation::from_i32(v.value()).unwrap());
+        }
+        if let Some(v) = self.message_encoding {
+            r.set_message_encoding(feature_set::MessageEncoding::from_i32(v.value()).unwrap());
+        }
+        if let Some(v) = self.json_format {
+            r.set_json_format(feature_set::JsonFormat::from_i32(v.value()).unwrap());
+        }
+        r
+    }
+
+    /// Override features with features set in `other`.
+    pub fn merge_from(&mut self, other: &FeatureSet) {
+        self.field_presence = other.field_presence.or(self.field_presence);
+        self.enum_type = other.enum_type.or(self.enum_type);
+        self.repeated_field_encoding = other
+            .repeated_field_encoding
+            .or(self.repeated_field_encoding);
+        self.utf8_validation = other.utf8_validation.or(self.utf8_validation);
+        self.message_encoding = other.message_encoding.or(self.message_encoding);
+        self.json_format = other.json_format.or(self.json_format);
+    }
+
+    /// No features are set.
+    pub fn is_empty(&self) -> bool {
+        *self == FeatureSet::default()
+    }
+
+    /// Features as names and values used in `.proto` files,
+    /// e. g. `("field_presence", "IMPLICIT")`.
+    pub(crate) fn option_values(&self) -> Vec<(&'static str, &'static str)> {
+        let mut r = Vec::new();
+        if let Some(v) = self.field_presence {
+            r.push((
+                "field_presence",
+                match v {
+                    FieldPresence::Explicit => "EXPLICIT",
+                    FieldPresence::Implicit => "IMPLICIT",
+                    FieldPresence::LegacyRequired => "LEGACY_REQUIRED",
+                },
+            ));
+        }
+        if let Some(v) = self.enum_type {
+            r.push((
+                "enum_type",
+                match v {
+                    EnumType::Open => "OPEN",
+                    EnumType::Closed => "CLOSED",
+                },
+            ));
+        }
+        if let Some(v) = self.repeated_field_encoding {
+            r.push((
+                "repeated_field_encoding",
+                match v {
+                    RepeatedFieldEncoding::Packed => "PACKED",
+                    RepeatedFieldEncoding::Expanded => "EXPANDED",
+                },
+            ));
+        }
+        if let Some(v) = self.utf8_validation {
+            r.push((
+                "utf8_validation",
+                match v {
+                    Utf8Validation::Verify => "VERIFY",
+                    Utf8Validation::None => "NONE",
+                },
+            ));
+        }
+        if let Some(v) = self.message_encoding {
+            r.push((
+                "message_encoding",
+                match v {
+                    MessageEncoding::LengthPrefixed => "LENGTH_PREFIXED",
+                    MessageEncoding::Delimited => "DELIMITED",
+                },
+            ));
+        }
+        if let Some(v) = self.json_format {
+            r.push((
+                "json_format",
+                match v {
+                    JsonFormat::Allow => "ALLOW",
+                    JsonFormat::LegacyBestEffort => "LEGACY_BEST_EFFORT",
+                },
+            ));
+        }
+        r
+    }
+}
+
+/// Options message which may contain `features` field.
+pub trait OptionsWithFeatures: Message {
+    /// `features` field of these options.
+    fn features_field(&self) -> &MessageField<descriptor::FeatureSet>;
+
+    /// Mutable `features` field of these options.
+    fn mut_features_field(&mut self) -> &mut MessageField<descriptor::FeatureSet>;
+
+    /// Features set in these options.
+    fn features(&self) -> FeatureSet {
+        match self.features_field().as_ref() {
+            Some(features) => FeatureSet::from_proto(features),
+            None => FeatureSet::default(),
+        }
+    }
+
+    /// Replace features in these options.
+    fn set_features(&mut self, features: &FeatureSet) {
+        *self.mut_features_field() = if features.is_empty() {
+            MessageField::none()
+        } else {
+            MessageField::some(features.to_proto())
+        };
+    }
+}
+
+impl OptionsWithFeatures for FileOptions {
+    fn features_field(&self) -> &MessageField<descriptor::FeatureSet> {
+        &self.features
+    }
+
+    fn mut_features_field(&mut self) -> &mut MessageField<descriptor::FeatureSet> {
+        &mut self.features
+    }
+}
+
+impl OptionsWithFeatures for MessageOptions {
+    fn features_field(&self) -> &MessageField<descriptor::FeatureSet> {
+        &self.features
+    }
+
+    fn mut_features_field(&mut self) -> &mut MessageField<descriptor::FeatureSet> {
+        &mut self.features
+    }
+}
+
+impl OptionsWithFeatures for FieldOptions {
+    fn features_field(&self) -> &MessageField<descriptor::FeatureSet> {
+        &self.features
+    }
+
+    fn mut_features_field(&mut self) -> &mut MessageField<descriptor::FeatureSet> {
+        &mut self.features
+    }
+}
+
+impl OptionsWithFeatures for OneofOptions {
+    fn features_field(&self) -> &MessageField<descriptor::FeatureSet> {
+        &self.features
+    }
+
+    fn mut_features_field(&mut self) -> &mut MessageField<descriptor::FeatureSet> {
+        &mut self.features
+    }
+}
+
+impl OptionsWithFeatures for EnumOptions {
+    fn features_field(&self) -> &MessageField<descriptor::FeatureSet> {
+        &self.features
+    }
+
+    fn mut_features_field(&mut self) -> &mut MessageField<descriptor::FeatureSet> {
+        &mut self.features
+    }
+}
+
+impl OptionsWithFeatures for EnumValueOptions {
+    fn features_field(&self) -> &MessageField<descriptor::FeatureSet> {
+        &self.features
+    }
+
+    fn mut_features_field(&mut self) -> &mut MessageField<descriptor::FeatureSet> {
+        &mut self.features
+    }
+}
+
+impl OptionsWithFeatures for ServiceOptions {
+    fn features_field(&self) -> &MessageField<descriptor::FeatureSet> {
+        &self.features
+    }
+
+    fn mut_features_field(&mut self) -> &mut MessageField<descriptor::FeatureSet> {
+        &mut self.features
+    }
+}
+
+impl OptionsWithFeatures for MethodOptions {
+    fn features_field(&self) -> &MessageField<descriptor::FeatureSet> {
+        &self.features
+    }
+
+    fn mut_features_field(&mut self) -> &mut MessageField<descriptor::FeatureSet> {
+        &mut self.features
+    }
+}
+
+pub(crate) fn options_features<O: OptionsWithFeatures>(options: &MessageField<O>) -> FeatureSet {
+    match options.as_ref() {
+        Some(options) => options.features(),
+        None => FeatureSet::default(),
+    }
+}
+
+/// Features of an element after inheritance is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResolvedFeatures {
+    /// `features.field_presence`
+    pub field_presence: FieldPresence,
+    /// `features.enum_type`
+    pub enum_type: EnumType,
+    /// `features.repeated_field_encoding`
+    pub repeated_field_encoding: RepeatedFieldEncoding,
+    /// `features.utf8_validation`
+    pub utf8_validation: Utf8Validation,
+    /// `features.message_encoding`
+    pub message_encoding: MessageEncoding,
+    /// `features.json_format`
+    pub json_format: JsonFormat,
+}
+
+impl ResolvedFeatures {
+    /// Features of an edition when nothing is overridden.
+    pub fn defaults(edition: Edition) -> ResolvedFeatures {
+        match edition {
+            Edition::Proto2 => ResolvedFeatures {
+                field_presence: FieldPresence::Explicit,
+                enum_type: EnumType::Closed,
+                repeated_field_encoding: RepeatedFieldEncoding::Expanded,
+                utf8_validation: Utf8Validation::None,
+                message_encoding: MessageEncoding::LengthPrefixed,
+                json_format: JsonFormat::LegacyBestEffort,
+            },
+            Edition::Proto3 => ResolvedFeatures {
+                field_presence: FieldPresence::Implicit,
+                enum_type: EnumType::Open,
+                repeated_field_encoding: RepeatedFieldEncoding::Packed,
+                utf8_validation: Utf8Validation::Verify,
+                message_encoding: MessageEncoding::LengthPrefixed,
+                json_format: JsonFormat::Allow,
+            },
+            Edition::Edition2023 => ResolvedFeatures {
+                field_presence: FieldPresence::Explicit,
+                enum_type: EnumType::Open,
+                repeated_field_encoding: RepeatedFieldEncoding::Packed,
+                utf8_validation: Utf8Validation::Verify,
+                message_encoding: MessageEncoding::LengthPrefixed,
+                json_format: JsonFormat::Allow,
+            },
+        }
+    }
+
+    /// Features of a child element which overrides given features.
+    pub fn merge(&self, features: &FeatureSet) -> ResolvedFeatures {
+        ResolvedFeatures {
+            field_presence: features.field_presence.unwrap_or(self.field_presence),
+            enum_type: features.enum_type.unwrap_or(self.enum_type),
+            repeated_field_encoding: features
+                .repeated_field_encoding
+                .unwrap_or(self.repeated_field_encoding),
+            utf8_validation: features.utf8_validation.unwrap_or(self.utf8_validation),
+            message_encoding: features.message_encoding.unwrap_or(self.message_encoding),
+            json_format: features.json_format.unwrap_or(self.json_format),
+        }
+    }
+
+    /// Features of a file.
+    pub(crate) fn for_file(file: &FileDescriptorProto) -> ResolvedFeatures {
+        ResolvedFeatures::defaults(Edition::of_file(file)).merge(&options_features(&file.options))
+    }
+
+    /// Features of a message or enum nested in given messages.
+    pub(crate) fn for_messages(
+        file: &FileDescriptorProto,
+        messages: &[&DescriptorProto],
+    ) -> ResolvedFeatures {
+        let mut r = ResolvedFeatures::for_file(file);
+        for m in messages {
+            r = r.merge(&options_features(&m.options));
+        }
+        r
+    }
+
+    /// Features of a field given features of its message or oneof.
+    ///
+    /// Proto2 and proto3 have no features syntax, but labels and
+    /// `packed` option are mapped to features like `protoc` does.
+    pub(crate) fn for_field(
+        &self,
+        edition: Edition,
+        field: &FieldDescriptorProto,
+    ) -> ResolvedFeatures {
+        let mut r = self.merge(&options_features(&field.options));
+        if edition == Edition::Proto2 || edition == Edition::Proto3 {
+            if field.get_label() == field_descriptor_proto::Label::LABEL_REQUIRED {
+                r.field_presence = FieldPresence::LegacyRequired;
+            }
+            if field.get_proto3_optional() {
+                r.field_presence = FieldPresence::Explicit;
+            }
+            if field.get_field_type() == field_descriptor_proto::Type::TYPE_GROUP {
+                r.message_encoding = MessageEncoding::Delimited;
+            }
+            if let Some(options) = field.options.as_ref() {
+                if options.has_packed() {
+                    r.repeated_field_encoding = if options.get_packed() {
+                        RepeatedFieldEncoding::Packed
+                    } else {
+                        RepeatedFieldEncoding::Expanded
+                    };
+                }
+            }
+        }
+        r
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn feature_set_proto() {
+        let features = FeatureSet {
+            field_presence: Some(FieldPresence::Implicit),
+            repeated_field_encoding: Some(RepeatedFieldEncoding::Expanded),
+            ..Default::default()
+        };
+        let bytes = features.to_proto().write_to_bytes().unwrap();
+        assert_eq!(vec![0x08, 2, 0x18, 2], bytes);
+        let proto = descriptor::FeatureSet::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(features, FeatureSet::from_proto(&proto));
+    }
+
+    #[test]
+    fn options_features() {
+        let mut options = FieldOptions::new();
+        assert!(options.features().is_empty());
+        let features = FeatureSet {
+            enum_type: Some(EnumType::Closed),
+            ..Default::default()
+        };
+        options.set_features(&features);
+        assert_eq!(features, options.features());
+        let options = FieldOptions::parse_from_bytes(&options.write_to_bytes().unwrap()).unwrap();
+        assert_eq!(features, options.features());
+    }
+
+    #[test]
+    fn file_edition() {
+        let mut file = FileDescriptorProto::new();
+        assert_eq!(Edition::Proto2, Edition::of_file(&file));
+        Edition::Edition2023.set_on_file(&mut file);
+        assert_eq!("editions", file.get_syntax());
+        assert_eq!(descriptor::Edition::EDITION_2023, file.get_edition());
+        assert_eq!(Edition::Edition2023, Edition::of_file(&file));
+        Edition::Proto3.set_on_file(&mut file);
+        assert_eq!(Edition::Proto3, Edition::of_file(&file));
+    }
+
+    #[test]
+    fn legacy_field_features() {
+        let mut field = FieldDescriptorProto::new();
+        field.set_label(field_descriptor_proto::Label::LABEL_REPEATED);
+        field.set_field_type(field_descriptor_proto::Type::TYPE_INT32);
+        let proto3 = ResolvedFeatures::defaults(Edition::Proto3);
+        assert_eq!(
+            RepeatedFieldEncoding::Packed,
+            proto3
+                .for_field(Edition::Proto3, &field)
+                .repeated_field_encoding
+        );
+        field.options.mut_or_default().set_packed(false);
+        assert_eq!(
+            RepeatedFieldEncoding::Expanded,
+            proto3
+                .for_field(Edition::Proto3, &field)
+                .repeated_field_encoding
+        );
+
+        let mut field = FieldDescriptorProto::new();
+        field.set_label(field_descriptor_proto::Label::LABEL_REQUIRED);
+        let proto2 = ResolvedFeatures::defaults(Edition::Proto2);
+        assert_eq!(
+            FieldPresence::LegacyRequired,
+            proto2.for_field(Edition::Proto2, &field).field_presence
+        );
+    }
+}
@@ -1,17 +1,22 @@
 use crate::descriptor::field_descriptor_proto;
+use crate::descriptor::DescriptorProto;
 use crate::descriptor::FieldDescriptorProto;
 use crate::json::json_name;
+use crate::reflect::features::options_features;
+use crate::reflect::features::Edition;
+use crate::reflect::features::ResolvedFeatures;
 use crate::reflect::file::building::FileDescriptorBuilding;
 use crate::reflect::EnumDescriptor;
 use crate::reflect::EnumValueDescriptor;
 use crate::reflect::FieldDescriptor;
+use crate::reflect::FileDescriptor;
 use crate::reflect::MessageDescriptor;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeFieldType;
 use crate::reflect::RuntimeTypeBox;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum ForwardRuntimeTypeBox {
     RuntimeTypeBox(RuntimeTypeBox),
     CurrentFileEnum(usize),
@@ -19,21 +24,20 @@ pub(crate) enum ForwardRuntimeTypeBox {
 }
 
 impl ForwardRuntimeTypeBox {
-    fn resolve(&self, field: &FieldDescriptor) -> RuntimeTypeBox {
+    fn resolve(&self, file: &FileDescriptor) -> RuntimeTypeBox {
         match self {
             ForwardRuntimeTypeBox::RuntimeTypeBox(t) => t.clone(),
-            ForwardRuntimeTypeBox::CurrentFileMessage(m) => RuntimeTypeBox::Message(
-                MessageDescriptor::new(field.message_descriptor.file_descriptor().clone(), *m),
-            ),
-            ForwardRuntimeTypeBox::CurrentFileEnum(m) => RuntimeTypeBox::Enum(EnumDescriptor::new(
-                field.message_descriptor.file_descriptor().clone(),
-                *m,
-            )),
+            ForwardRuntimeTypeBox::CurrentFileMessage(m) => {
+                RuntimeTypeBox::Message(MessageDescriptor::new(file.clone(), *m))
+            }
+            ForwardRuntimeTypeBox::CurrentFileEnum(m) => {
+                RuntimeTypeBox::Enum(EnumDescriptor::new(file.clone(), *m))
+            }
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum ForwardRuntimeFieldType {
     Singular(ForwardRuntimeTypeBox),
     Repeated(ForwardRuntimeTypeBox),
@@ -42,11 +46,16 @@ pub(crate) enum ForwardRuntimeFieldType {
 
 impl ForwardRuntimeFieldType {
     pub fn resolve(&self, field: &FieldDescriptor) -> RuntimeFieldType {
+        self.resolve_in_file(field.message_descriptor.file_descriptor())
+    }
+
+    /// Resolve type defined in given file, e. g. type of an extension.
+    pub fn resolve_in_file(&self, file: &FileDescriptor) -> RuntimeFieldType {
         match self {
-            ForwardRuntimeFieldType::Singular(t) => RuntimeFieldType::Singular(t.resolve(field)),
-            ForwardRuntimeFieldType::Repeated(t) => RuntimeFieldType::Repeated(t.resolve(field)),
+            ForwardRuntimeFieldType::Singular(t) => RuntimeFieldType::Singular(t.resolve(file)),
+            ForwardRuntimeFieldType::Repeated(t) => RuntimeFieldType::Repeated(t.resolve(file)),
             ForwardRuntimeFieldType::Map(k, v) => {
-                RuntimeFieldType::Map(k.resolve(field), v.resolve(field))
+                RuntimeFieldType::Map(k.resolve(file), v.resolve(file))
             }
         }
     }
@@ -63,6 +72,7 @@ pub(crate) struct FieldIndex {
     pub(crate) json_name: String,
     pub(crate) field_type: ForwardRuntimeFieldType,
     pub(crate) default_value: Option<FieldDefaultValue>,
+    pub(crate) features: ResolvedFeatures,
 }
 
 impl FieldIndex {
@@ -103,7 +113,12 @@ impl FieldIndex {
         })
     }
 
-    pub fn index(field: &FieldDescriptorProto, building: &FileDescriptorBuilding) -> FieldIndex {
+    pub fn index(
+        field: &FieldDescriptorProto,
+        message: &DescriptorProto,
+        message_features: &ResolvedFeatures,
+        building: &FileDescriptorBuilding,
+    ) -> FieldIndex {
         let default_value = if field.has_default_value() {
             Some(Self::parse_default_value(field, building))
         } else {
@@ -116,10 +131,20 @@ impl FieldIndex {
             json_name(field.get_name())
         };
 
+        let parent_features = if field.has_oneof_index() {
+            let oneof = &message.oneof_decl[field.get_oneof_index() as usize];
+            message_features.merge(&options_features(&oneof.options))
+        } else {
+            *message_features
+        };
+        let features =
+            parent_features.for_field(Edition::of_file(building.current_file_descriptor), field);
+
         FieldIndex {
             default_value,
             json_name,
             field_type: building.resolve_field_type(field),
+            features,
         }
     }
 
@@ -1,25 +1,38 @@
 use crate::descriptor::field_descriptor_proto;
 use crate::descriptor::FieldDescriptorProto;
+use crate::descriptor::FieldOptions;
+use crate::ext::ExtFieldOptional;
+use crate::ext::ExtFieldRepeated;
+use crate::message::Message;
 use crate::message_dyn::MessageDyn;
 use crate::reflect::acc::v2::map::MapFieldAccessorHolder;
 use crate::reflect::acc::v2::repeated::RepeatedFieldAccessorHolder;
 use crate::reflect::acc::v2::singular::SingularFieldAccessorHolder;
 use crate::reflect::acc::v2::AccessorV2;
 use crate::reflect::acc::GeneratedFieldAccessor;
+use crate::reflect::dynamic::wire::is_packable;
 use crate::reflect::dynamic::DynamicMessage;
+use crate::reflect::features::FieldPresence;
+use crate::reflect::features::MessageEncoding;
+use crate::reflect::features::RepeatedFieldEncoding;
+use crate::reflect::features::ResolvedFeatures;
 use crate::reflect::field::dynamic::DynamicFieldDescriptorRef;
 use crate::reflect::field::index::FieldIndex;
 use crate::reflect::map::ReflectMapMut;
 use crate::reflect::map::ReflectMapRef;
+use crate::reflect::message::message_mut::MessageMut;
 use crate::reflect::message::message_ref::MessageRef;
 use crate::reflect::message::MessageDescriptorImplRef;
 use crate::reflect::oneof::OneofDescriptor;
+use crate::reflect::options;
 use crate::reflect::reflect_eq::ReflectEq;
 use crate::reflect::reflect_eq::ReflectEqMode;
 use crate::reflect::repeated::ReflectRepeatedMut;
 use crate::reflect::repeated::ReflectRepeatedRef;
+use crate::reflect::types::ProtobufType;
 use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::MessageDescriptor;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeTypeBox;
@@ -46,7 +59,7 @@ impl<'a> ReflectFieldRef<'a> {
             RuntimeFieldType::Repeated(elem) => {
                 ReflectFieldRef::Repeated(ReflectRepeatedRef::new_empty(elem))
             }
-            RuntimeFieldType::Map(..) => unimplemented!(),
+            RuntimeFieldType::Map(k, v) => ReflectFieldRef::Map(ReflectMapRef::new_empty(k, v)),
         }
     }
 }
@@ -121,6 +134,13 @@ impl FieldDescriptor {
         }
     }
 
+    /// Oneof declared in `.proto` file containing this field,
+    /// i. e. [`containing_oneof`](Self::containing_oneof) unless it is
+    /// [synthetic](OneofDescriptor::is_synthetic).
+    pub fn containing_real_oneof(&self) -> Option<OneofDescriptor> {
+        self.containing_oneof().filter(|o| !o.is_synthetic())
+    }
+
     fn get_index(&self) -> &FieldIndex {
         &self.message_descriptor.get_index().fields[self.index]
     }
@@ -158,6 +178,102 @@ impl FieldDescriptor {
         }
     }
 
+    /// Features of this field, inherited from file, messages and oneof.
+    ///
+    /// For proto2 and proto3 files labels and `packed` option are
+    /// reflected in features, e. g. `required` field has
+    /// [`FieldPresence::LegacyRequired`].
+    pub fn features(&self) -> ResolvedFeatures {
+        self.get_index().features
+    }
+
+    /// Options of this field, default instance if not specified in `.proto` file.
+    pub fn options(&self) -> &FieldOptions {
+        self.get_proto().options.get_or_default()
+    }
+
+    /// Get custom option of this field by extension generated by `protoc`.
+    pub fn get_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldOptional<FieldOptions, T>,
+    ) -> Option<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get custom option of this field by extension fully-qualified name,
+    /// e. g. `acme.sensitive`.
+    ///
+    /// Extension is looked up in the file of this field and its dependencies.
+    /// Returns `None` if option is not set, extension is not found
+    /// or extension is repeated.
+    pub fn get_option_by_name(&self, name: &str) -> Option<ReflectValueBox> {
+        options::custom_option(
+            self.message_descriptor.file_descriptor(),
+            ".google.protobuf.FieldOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    /// Get repeated custom option of this field by extension generated by `protoc`.
+    pub fn get_repeated_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldRepeated<FieldOptions, T>,
+    ) -> Vec<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get repeated custom option of this field by extension fully-qualified name.
+    ///
+    /// Returns empty vec if option is not set, extension is not found
+    /// or extension is not repeated.
+    pub fn get_repeated_option_by_name(&self, name: &str) -> Vec<ReflectValueBox> {
+        options::custom_repeated_option(
+            self.message_descriptor.file_descriptor(),
+            ".google.protobuf.FieldOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    /// Singular field tracks whether it is set.
+    ///
+    /// Message fields and oneof members always have presence.
+    pub fn has_presence(&self) -> bool {
+        if self.is_repeated_or_map() {
+            return false;
+        }
+        self.get_proto().get_field_type() == field_descriptor_proto::Type::TYPE_MESSAGE
+            || self.get_proto().get_field_type() == field_descriptor_proto::Type::TYPE_GROUP
+            || self.containing_oneof().is_some()
+            || self.features().field_presence != FieldPresence::Implicit
+    }
+
+    /// Field is required: proto2 `required` or `LEGACY_REQUIRED` presence.
+    pub fn is_required(&self) -> bool {
+        self.features().field_presence == FieldPresence::LegacyRequired
+    }
+
+    /// Repeated field is written packed.
+    pub fn is_packed(&self) -> bool {
+        self.is_repeated_or_map()
+            && is_packable(self.get_proto().get_field_type())
+            && self.features().repeated_field_encoding == RepeatedFieldEncoding::Packed
+    }
+
+    /// Message field is written like a group.
+    ///
+    /// Map fields are always length-delimited.
+    pub fn is_delimited(&self) -> bool {
+        match self.get_proto().get_field_type() {
+            field_descriptor_proto::Type::TYPE_GROUP => true,
+            field_descriptor_proto::Type::TYPE_MESSAGE => {
+                self.features().message_encoding == MessageEncoding::Delimited && !self.is_map()
+            }
+            _ => false,
+        }
+    }
+
     fn get_impl(&self) -> FieldDescriptorImplRef {
         match self.message_descriptor.get_impl() {
             MessageDescriptorImplRef::Generated(g) => {
@@ -249,14 +365,34 @@ impl FieldDescriptor {
     ///
     /// # Panics
     ///
-    /// If this field belongs to a different message type or
-    /// field type is not singular message.
+    /// If this field belongs to a different message type,
+    /// field type is not singular message, or the field is stored
+    /// as a custom type (use [`mut_converted_message`](Self::mut_converted_message)).
     pub fn mut_message<'a>(&self, m: &'a mut dyn MessageDyn) -> &'a mut dyn MessageDyn {
         match self.mut_singular_field_or_default(m) {
             ReflectValueMut::Message(m) => m,
         }
     }
 
+    /// Get a mutable reference to a message field stored as a custom type
+    /// (see [`MessageConvert`](crate::MessageConvert)).
+    /// Initialize field with default message if unset.
+    ///
+    /// The reference points to a converted message, which is converted back
+    /// when the reference is dropped or [`finish`](MessageMut::finish)ed.
+    /// For other message fields the reference points to the field message.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type or
+    /// field type is not singular message.
+    pub fn mut_converted_message<'a>(&self, m: &'a mut dyn MessageDyn) -> MessageMut<'a> {
+        match self.singular() {
+            SingularFieldAccessorRef::Generated(g) => g.accessor.mut_converted_message(m),
+            SingularFieldAccessorRef::Dynamic(..) => MessageMut::new(self.mut_message(m)),
+        }
+    }
+
     /// Default value.
     ///
     /// # Panics
@@ -317,14 +453,44 @@ impl FieldDescriptor {
     /// # Panics
     ///
     /// If this field belongs to a different message type or
-    /// field is not singular or value is of different type.
+    /// field is not singular or value is of different type,
+    /// or value cannot be converted to the custom type the field is stored as.
     pub fn set_singular_field(&self, m: &mut dyn MessageDyn, value: ReflectValueBox) {
+        if let Err(e) = self.set_singular_field_impl(m, value) {
+            panic!("{}: {}", self, e);
+        }
+    }
+
+    fn set_singular_field_impl(
+        &self,
+        m: &mut dyn MessageDyn,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
         match self.singular() {
             SingularFieldAccessorRef::Generated(g) => g.accessor.set_field(m, value),
-            SingularFieldAccessorRef::Dynamic(d) => d.set_field(m, value),
+            SingularFieldAccessorRef::Dynamic(d) => {
+                d.set_field(m, value);
+                Ok(())
+            }
         }
     }
 
+    /// Set singular field, or return an error if value is of different type
+    /// or cannot be converted to the custom type the field is stored as.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type or
+    /// field is not singular.
+    pub fn try_set_singular_field(
+        &self,
+        m: &mut dyn MessageDyn,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
+        let value = ReflectTypeError::check(self.singular_runtime_type(), value)?;
+        self.set_singular_field_impl(m, value)
+    }
+
     /// Dynamic representation of field type.
     pub fn runtime_field_type(&self) -> RuntimeFieldType {
         self.get_index().field_type.resolve(self)
@@ -356,6 +522,55 @@ impl FieldDescriptor {
         }
     }
 
+    /// Clear the field: unset singular field, or remove all elements
+    /// of repeated or map field.
+    ///
+    /// # Panics
+    ///
+    /// If this field belongs to a different message type.
+    pub fn clear_field(&self, m: &mut dyn MessageDyn) {
+        if self.is_repeated_or_map() {
+            if self.is_map() {
+                self.mut_map(m).clear();
+            } else {
+                self.mut_repeated(m).clear();
+            }
+            return;
+        }
+        match self.singular() {
+            SingularFieldAccessorRef::Generated(g) => g.accessor.clear_field(m),
+            SingularFieldAccessorRef::Dynamic(d) => d.clear_field(m),
+        }
+    }
+
+    /// Merge this field of `source` into `target`, see [`MessageDyn::merge_dyn`].
+    pub(crate) fn merge_field(&self, target: &mut dyn MessageDyn, source: &dyn MessageDyn) {
+        match self.get_reflect(source) {
+            ReflectFieldRef::Optional(None) => {}
+            ReflectFieldRef::Optional(Some(ReflectValueRef::Message(m))) => {
+                if self.has_field(target) {
+                    m.descriptor_dyn()
+                        .merge(&mut *self.mut_converted_message(target), &*m);
+                } else {
+                    self.set_singular_field(target, ReflectValueBox::Message(m.clone_box()));
+                }
+            }
+            ReflectFieldRef::Optional(Some(v)) => self.set_singular_field(target, v.to_box()),
+            ReflectFieldRef::Repeated(r) => {
+                let mut target_repeated = self.mut_repeated(target);
+                for v in r {
+                    target_repeated.push(v.to_box());
+                }
+            }
+            ReflectFieldRef::Map(m) => {
+                let mut target_map = self.mut_map(target);
+                for (k, v) in &m {
+                    target_map.insert(k.to_box(), v.to_box());
+                }
+            }
+        }
+    }
+
     // repeated
 
     /// Get repeated field.
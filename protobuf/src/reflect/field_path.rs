@@ -0,0 +1,805 @@
+//! Access message fields by path expressions.
+//!
+//! A path is a sequence of field names separated by dots, where a repeated
+//! field name is followed by an element index, and a map field name
+//! by a key: `order.items[2].sku`, `labels["env"]`, `counts[-1]`.
+//!
+//! ```
+//! # use protobuf::descriptor::FileDescriptorProto;
+//! # use protobuf::reflect::field_path::FieldPath;
+//! # use protobuf::reflect::ReflectValueBox;
+//! # use protobuf::reflect::ReflectValueRef;
+//! let mut file = FileDescriptorProto::new();
+//! let path: FieldPath = "options.java_package".parse().unwrap();
+//! path.set(&mut file, ReflectValueBox::String("com.example".to_owned()))
+//!     .unwrap();
+//! assert_eq!(
+//!     Some(ReflectValueRef::String("com.example")),
+//!     path.get(&file).unwrap()
+//! );
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::message_dyn::MessageDyn;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::reflect::MessageRef;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectTypeError;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::text_format::quote_escape_bytes;
+
+/// Error of parsing or evaluating a [`FieldPath`].
+///
+/// `path` in variants is the path prefix up to the offending component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldPathError {
+    /// Path expression is malformed.
+    Syntax(String),
+    /// Message has no field with such name.
+    UnknownField {
+        /// Path prefix.
+        path: String,
+        /// Full name of the message type.
+        message: String,
+    },
+    /// Path continues after a field which is not a message.
+    NotMessage(String),
+    /// Index or key is given for a singular field.
+    NotRepeatedOrMap(String),
+    /// Repeated or map field is given without index or key.
+    NotSingular(String),
+    /// Value is appended to a field which is not repeated.
+    NotRepeated(String),
+    /// Repeated field element selector is not an index.
+    InvalidIndex(String),
+    /// Repeated field element index is out of range.
+    IndexOutOfRange {
+        /// Path prefix.
+        path: String,
+        /// Number of elements in the field.
+        len: usize,
+    },
+    /// Map key cannot be converted to the map key type.
+    InvalidKey {
+        /// Path prefix.
+        path: String,
+        /// Map key type.
+        key_type: RuntimeTypeBox,
+    },
+    /// Value type does not match the field type.
+    TypeMismatch {
+        /// Path prefix.
+        path: String,
+        /// Field type.
+        expected: RuntimeTypeBox,
+        /// Value type.
+        actual: RuntimeTypeBox,
+    },
+    /// Value cannot be converted to the Rust type the field is stored as.
+    Convert {
+        /// Path prefix.
+        path: String,
+        /// Conversion error.
+        error: String,
+    },
+    /// Path continues inside a message field stored as a custom Rust type,
+    /// which cannot be borrowed.
+    ConvertedMessage(String),
+    /// Path is empty, but the operation needs a field.
+    EmptyPath,
+}
+
+impl fmt::Display for FieldPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldPathError::Syntax(e) => write!(f, "invalid field path: {}", e),
+            FieldPathError::UnknownField { path, message } => {
+                write!(f, "{}: no such field in {}", path, message)
+            }
+            FieldPathError::NotMessage(path) => write!(f, "{}: not a message field", path),
+            FieldPathError::NotRepeatedOrMap(path) => {
+                write!(f, "{}: index or key given for singular field", path)
+            }
+            FieldPathError::NotSingular(path) => {
+                write!(
+                    f,
+                    "{}: index or key required for repeated or map field",
+                    path
+                )
+            }
+            FieldPathError::NotRepeated(path) => write!(f, "{}: not a repeated field", path),
+            FieldPathError::InvalidIndex(path) => write!(f, "{}: invalid index", path),
+            FieldPathError::IndexOutOfRange { path, len } => {
+                write!(f, "{}: index out of range, length is {}", path, len)
+            }
+            FieldPathError::InvalidKey { path, key_type } => {
+                write!(f, "{}: key is not a valid {}", path, key_type)
+            }
+            FieldPathError::TypeMismatch {
+                path,
+                expected,
+                actual,
+            } => write!(f, "{}: expecting {}, got {}", path, expected, actual),
+            FieldPathError::Convert { path, error } => write!(f, "{}: {}", path, error),
+            FieldPathError::ConvertedMessage(path) => {
+                write!(f, "{}: message stored as custom type", path)
+            }
+            FieldPathError::EmptyPath => write!(f, "empty path"),
+        }
+    }
+}
+
+impl Error for FieldPathError {}
+
+/// Element selector as written in the path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector {
+    /// Index or non-string key, e. g. `2`, `-1` or `true`.
+    Token(String),
+    /// String key.
+    Str(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    field: String,
+    selector: Option<Selector>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.field)?;
+        match &self.selector {
+            None => Ok(()),
+            Some(Selector::Token(t)) => write!(f, "[{}]", t),
+            Some(Selector::Str(s)) => write!(f, "[{}]", quote_escape_bytes(s.as_bytes())),
+        }
+    }
+}
+
+/// Parsed path to a field, a repeated field element or a map value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldPath {
+    steps: Vec<Step>,
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, step) in self.steps.iter().enumerate() {
+            if i != 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", step)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for FieldPath {
+    type Err = FieldPathError;
+
+    fn from_str(path: &str) -> Result<FieldPath, FieldPathError> {
+        FieldPath::parse(path)
+    }
+}
+
+fn is_ident_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+fn message_mut(value: &mut ReflectValueBox) -> &mut dyn MessageDyn {
+    match value {
+        ReflectValueBox::Message(m) => &mut **m,
+        _ => unreachable!("element type is checked to be message"),
+    }
+}
+
+impl FieldPath {
+    /// Parse a path expression.
+    pub fn parse(path: &str) -> Result<FieldPath, FieldPathError> {
+        let syntax = |pos: usize, what: &str| {
+            FieldPathError::Syntax(format!("{} at {} in `{}`", what, pos, path))
+        };
+
+        let bytes = path.as_bytes();
+        let mut pos = 0;
+        let mut steps = Vec::new();
+        loop {
+            let start = pos;
+            while pos < bytes.len() && is_ident_char(bytes[pos]) {
+                pos += 1;
+            }
+            if start == pos || bytes[start].is_ascii_digit() {
+                return Err(syntax(start, "expecting field name"));
+            }
+            let field = path[start..pos].to_owned();
+
+            let mut selector = None;
+            if pos < bytes.len() && bytes[pos] == b'[' {
+                pos += 1;
+                if pos < bytes.len() && bytes[pos] == b'"' {
+                    pos += 1;
+                    let mut s = Vec::new();
+                    loop {
+                        match bytes.get(pos) {
+                            None => return Err(syntax(pos, "unterminated string")),
+                            Some(b'"') => break,
+                            Some(b'\\') => {
+                                pos += 1;
+                                match bytes.get(pos) {
+                                    Some(b'n') => s.push(b'\n'),
+                                    Some(b't') => s.push(b'\t'),
+                                    Some(b'r') => s.push(b'\r'),
+                                    Some(&c) => s.push(c),
+                                    None => return Err(syntax(pos, "unterminated string")),
+                                }
+                            }
+                            Some(&c) => s.push(c),
+                        }
+                        pos += 1;
+                    }
+                    pos += 1;
+                    // Escapes only replace ASCII bytes, so the result is valid UTF-8
+                    selector = Some(Selector::Str(String::from_utf8(s).unwrap()));
+                } else {
+                    let start = pos;
+                    while pos < bytes.len() && (is_ident_char(bytes[pos]) || bytes[pos] == b'-') {
+                        pos += 1;
+                    }
+                    if start == pos {
+                        return Err(syntax(start, "expecting index or key"));
+                    }
+                    selector = Some(Selector::Token(path[start..pos].to_owned()));
+                }
+                if pos >= bytes.len() || bytes[pos] != b']' {
+                    return Err(syntax(pos, "expecting `]`"));
+                }
+                pos += 1;
+            }
+
+            steps.push(Step { field, selector });
+
+            if pos == bytes.len() {
+                return Ok(FieldPath { steps });
+            }
+            if bytes[pos] != b'.' {
+                return Err(syntax(pos, "expecting `.`"));
+            }
+            pos += 1;
+        }
+    }
+
+    /// Empty path of the root message.
+    pub(crate) fn root() -> FieldPath {
+        FieldPath { steps: Vec::new() }
+    }
+
+    /// Number of fields in this path.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Path is empty, i. e. it points to the root message.
+    ///
+    /// Parsed paths are never empty, but paths passed to
+    /// [`MessageVisitor`](crate::reflect::visit::MessageVisitor) hooks
+    /// for the root message are.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub(crate) fn push_field(&mut self, name: &str) {
+        self.steps.push(Step {
+            field: name.to_owned(),
+            selector: None,
+        });
+    }
+
+    pub(crate) fn pop(&mut self) {
+        self.steps.pop();
+    }
+
+    fn last_selector(&mut self) -> &mut Option<Selector> {
+        &mut self.steps.last_mut().expect("path is empty").selector
+    }
+
+    /// Select repeated field element in the last step.
+    pub(crate) fn set_index(&mut self, index: usize) {
+        *self.last_selector() = Some(Selector::Token(index.to_string()));
+    }
+
+    /// Select map value in the last step.
+    pub(crate) fn set_key(&mut self, key: &ReflectValueRef) {
+        *self.last_selector() = Some(match key {
+            ReflectValueRef::String(s) => Selector::Str((*s).to_owned()),
+            ReflectValueRef::I32(v) => Selector::Token(v.to_string()),
+            ReflectValueRef::I64(v) => Selector::Token(v.to_string()),
+            ReflectValueRef::U32(v) => Selector::Token(v.to_string()),
+            ReflectValueRef::U64(v) => Selector::Token(v.to_string()),
+            ReflectValueRef::Bool(v) => Selector::Token(v.to_string()),
+            k => panic!("not a map key: {:?}", k),
+        });
+    }
+
+    pub(crate) fn clear_selector(&mut self) {
+        *self.last_selector() = None;
+    }
+
+    /// Path up to and including step `i`.
+    fn prefix(&self, i: usize) -> String {
+        FieldPath {
+            steps: self.steps[..=i].to_vec(),
+        }
+        .to_string()
+    }
+
+    fn field(
+        &self,
+        message: &MessageDescriptor,
+        i: usize,
+    ) -> Result<FieldDescriptor, FieldPathError> {
+        message
+            .get_field_by_name(&self.steps[i].field)
+            .ok_or_else(|| FieldPathError::UnknownField {
+                path: self.prefix(i),
+                message: message.full_name().to_owned(),
+            })
+    }
+
+    fn index(&self, i: usize, len: usize) -> Result<usize, FieldPathError> {
+        let index = match &self.steps[i].selector {
+            Some(Selector::Token(t)) => t.parse::<usize>().ok(),
+            _ => None,
+        };
+        match index {
+            None => Err(FieldPathError::InvalidIndex(self.prefix(i))),
+            Some(index) if index >= len => Err(FieldPathError::IndexOutOfRange {
+                path: self.prefix(i),
+                len,
+            }),
+            Some(index) => Ok(index),
+        }
+    }
+
+    fn key(&self, i: usize, key_type: &RuntimeTypeBox) -> Result<ReflectValueBox, FieldPathError> {
+        let key = match (&self.steps[i].selector, key_type) {
+            (Some(Selector::Str(s)), RuntimeTypeBox::String) => {
+                Some(ReflectValueBox::String(s.clone()))
+            }
+            (Some(Selector::Token(t)), RuntimeTypeBox::I32) => {
+                t.parse().ok().map(ReflectValueBox::I32)
+            }
+            (Some(Selector::Token(t)), RuntimeTypeBox::I64) => {
+                t.parse().ok().map(ReflectValueBox::I64)
+            }
+            (Some(Selector::Token(t)), RuntimeTypeBox::U32) => {
+                t.parse().ok().map(ReflectValueBox::U32)
+            }
+            (Some(Selector::Token(t)), RuntimeTypeBox::U64) => {
+                t.parse().ok().map(ReflectValueBox::U64)
+            }
+            (Some(Selector::Token(t)), RuntimeTypeBox::Bool) => {
+                t.parse().ok().map(ReflectValueBox::Bool)
+            }
+            _ => None,
+        };
+        key.ok_or_else(|| FieldPathError::InvalidKey {
+            path: self.prefix(i),
+            key_type: key_type.clone(),
+        })
+    }
+
+    fn type_error(&self, i: usize, e: ReflectTypeError) -> FieldPathError {
+        let path = self.prefix(i);
+        match e {
+            ReflectTypeError::Mismatch { expected, actual } => FieldPathError::TypeMismatch {
+                path,
+                expected,
+                actual,
+            },
+            ReflectTypeError::Convert(error) => FieldPathError::Convert { path, error },
+        }
+    }
+
+    /// Index of the last step, or error if the path is empty.
+    fn last(&self) -> Result<usize, FieldPathError> {
+        match self.steps.len() {
+            0 => Err(FieldPathError::EmptyPath),
+            len => Ok(len - 1),
+        }
+    }
+
+    /// Check the field at non-last step `i` contains messages.
+    fn check_message(&self, field: &FieldDescriptor, i: usize) -> Result<(), FieldPathError> {
+        match field.runtime_field_type() {
+            RuntimeFieldType::Singular(RuntimeTypeBox::Message(..))
+            | RuntimeFieldType::Repeated(RuntimeTypeBox::Message(..))
+            | RuntimeFieldType::Map(_, RuntimeTypeBox::Message(..)) => Ok(()),
+            _ => Err(FieldPathError::NotMessage(self.prefix(i))),
+        }
+    }
+
+    /// Get the value at this path.
+    ///
+    /// Return `None` if a field on the path is unset or a map key is absent.
+    /// Empty path refers to the message itself.
+    pub fn get<'a>(
+        &self,
+        message: &'a dyn MessageDyn,
+    ) -> Result<Option<ReflectValueRef<'a>>, FieldPathError> {
+        let mut message = message;
+        let last = match self.last() {
+            Ok(last) => last,
+            Err(_) => return Ok(Some(ReflectValueRef::Message(MessageRef::new(message)))),
+        };
+        for i in 0..=last {
+            let field = self.field(&message.descriptor_dyn(), i)?;
+            if i != last {
+                self.check_message(&field, i)?;
+            }
+            let value = match (&self.steps[i].selector, field.get_reflect(message)) {
+                (None, ReflectFieldRef::Optional(value)) => value,
+                (None, _) => return Err(FieldPathError::NotSingular(self.prefix(i))),
+                (Some(_), ReflectFieldRef::Optional(..)) => {
+                    return Err(FieldPathError::NotRepeatedOrMap(self.prefix(i)))
+                }
+                (Some(_), ReflectFieldRef::Repeated(repeated)) => {
+                    Some(repeated.get(self.index(i, repeated.len())?))
+                }
+                (Some(_), ReflectFieldRef::Map(map)) => {
+                    map.get(self.key(i, &map.key_type())?.as_value_ref())
+                }
+            };
+            if i == last {
+                return Ok(value);
+            }
+            message = match value {
+                Some(ReflectValueRef::Message(m)) => match m.as_borrowed() {
+                    Some(m) => m,
+                    None => return Err(FieldPathError::ConvertedMessage(self.prefix(i))),
+                },
+                _ => return Ok(None),
+            };
+        }
+        unreachable!("path is not empty")
+    }
+
+    /// Find the message containing the last field of the path,
+    /// creating intermediate messages, and call `f` with it.
+    fn with_last(
+        &self,
+        message: &mut dyn MessageDyn,
+        i: usize,
+        f: &mut dyn FnMut(&mut dyn MessageDyn, FieldDescriptor) -> Result<(), FieldPathError>,
+    ) -> Result<(), FieldPathError> {
+        let field = self.field(&message.descriptor_dyn(), i)?;
+        if i == self.steps.len() - 1 {
+            return f(message, field);
+        }
+        self.check_message(&field, i)?;
+        match (&self.steps[i].selector, field.runtime_field_type()) {
+            (None, RuntimeFieldType::Singular(..)) => {
+                let mut m = field.mut_converted_message(message);
+                self.with_last(&mut *m, i + 1, f)?;
+                m.finish().map_err(|e| self.type_error(i, e))
+            }
+            (None, _) => Err(FieldPathError::NotSingular(self.prefix(i))),
+            (Some(_), RuntimeFieldType::Singular(..)) => {
+                Err(FieldPathError::NotRepeatedOrMap(self.prefix(i)))
+            }
+            (Some(_), RuntimeFieldType::Repeated(..)) => {
+                let mut repeated = field.mut_repeated(message);
+                let index = self.index(i, repeated.len())?;
+                let mut element = repeated.get_mut(index);
+                let m = element
+                    .as_message_mut()
+                    .expect("element type is checked to be message");
+                self.with_last(m, i + 1, f)?;
+                element.finish().map_err(|e| self.type_error(i, e))
+            }
+            (Some(_), RuntimeFieldType::Map(key_type, value_type)) => {
+                let key = self.key(i, &key_type)?;
+                let mut value = match field.get_map(message).get(key.as_value_ref()) {
+                    Some(value) => value.to_box(),
+                    None => match value_type {
+                        RuntimeTypeBox::Message(m) => ReflectValueBox::Message(m.new_instance()),
+                        _ => unreachable!(),
+                    },
+                };
+                self.with_last(message_mut(&mut value), i + 1, f)?;
+                field
+                    .mut_map(message)
+                    .try_insert(key, value)
+                    .map_err(|e| self.type_error(i, e))
+            }
+        }
+    }
+
+    /// Set the value at this path, creating intermediate messages
+    /// and map entries.
+    pub fn set(
+        &self,
+        message: &mut dyn MessageDyn,
+        value: ReflectValueBox,
+    ) -> Result<(), FieldPathError> {
+        let i = self.last()?;
+        let mut value = Some(value);
+        self.with_last(message, 0, &mut |m, field| {
+            let value = value.take().unwrap();
+            let r = match (&self.steps[i].selector, field.runtime_field_type()) {
+                (None, RuntimeFieldType::Singular(..)) => field.try_set_singular_field(m, value),
+                (None, _) => return Err(FieldPathError::NotSingular(self.prefix(i))),
+                (Some(_), RuntimeFieldType::Singular(..)) => {
+                    return Err(FieldPathError::NotRepeatedOrMap(self.prefix(i)))
+                }
+                (Some(_), RuntimeFieldType::Repeated(..)) => {
+                    let index = self.index(i, field.get_repeated(m).len())?;
+                    field.mut_repeated(m).try_set(index, value)
+                }
+                (Some(_), RuntimeFieldType::Map(key_type, _)) => {
+                    let key = self.key(i, &key_type)?;
+                    field.mut_map(m).try_insert(key, value)
+                }
+            };
+            r.map_err(|e| self.type_error(i, e))
+        })
+    }
+
+    /// Clear the field, remove the repeated field element or the map entry
+    /// at this path.
+    pub fn clear(&self, message: &mut dyn MessageDyn) -> Result<(), FieldPathError> {
+        let i = self.last()?;
+        self.with_last(message, 0, &mut |m, field| {
+            match (&self.steps[i].selector, field.runtime_field_type()) {
+                (None, _) => field.clear_field(m),
+                (Some(_), RuntimeFieldType::Singular(..)) => {
+                    return Err(FieldPathError::NotRepeatedOrMap(self.prefix(i)))
+                }
+                (Some(_), RuntimeFieldType::Repeated(..)) => {
+                    let mut repeated = field.mut_repeated(m);
+                    let index = self.index(i, repeated.len())?;
+                    repeated.remove(index);
+                }
+                (Some(_), RuntimeFieldType::Map(key_type, _)) => {
+                    let key = self.key(i, &key_type)?;
+                    field.mut_map(m).remove(key.as_value_ref());
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Append a value to the repeated field at this path.
+    pub fn append(
+        &self,
+        message: &mut dyn MessageDyn,
+        value: ReflectValueBox,
+    ) -> Result<(), FieldPathError> {
+        let i = self.last()?;
+        let mut value = Some(value);
+        self.with_last(message, 0, &mut |m, field| {
+            let value = value.take().unwrap();
+            match (&self.steps[i].selector, field.runtime_field_type()) {
+                (None, RuntimeFieldType::Repeated(..)) => field
+                    .mut_repeated(m)
+                    .try_push(value)
+                    .map_err(|e| self.type_error(i, e)),
+                _ => Err(FieldPathError::NotRepeated(self.prefix(i))),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::well_known_types::Struct;
+    use crate::well_known_types::Value;
+
+    fn path(p: &str) -> FieldPath {
+        FieldPath::parse(p).unwrap()
+    }
+
+    fn file() -> FileDescriptorProto {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("f".to_owned());
+        field.set_number(1);
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        message.field.push(field);
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.message_type.push(message);
+        file
+    }
+
+    #[test]
+    fn parse() {
+        for p in &[
+            "a",
+            "a.b_c.d1",
+            "a[0].b",
+            "a[-1]",
+            "a[true]",
+            "a[\"x.y[]\\\"\"].b",
+        ] {
+            assert_eq!(*p, path(p).to_string());
+        }
+        assert_eq!("a[\"\\n\"]", path("a[\"\\n\"]").to_string());
+
+        for p in &[
+            "", "a.", ".a", "1a", "a[", "a[]", "a[1", "a[\"x]", "a b", "a[1]b",
+        ] {
+            match FieldPath::parse(p) {
+                Err(FieldPathError::Syntax(..)) => {}
+                r => panic!("{}: {:?}", p, r),
+            }
+        }
+    }
+
+    #[test]
+    fn get() {
+        let file = file();
+        assert_eq!(
+            Some(ReflectValueRef::String("f")),
+            path("message_type[0].field[0].name").get(&file).unwrap()
+        );
+        assert_eq!(
+            Some(ReflectValueRef::I32(1)),
+            path("message_type[0].field[0].number").get(&file).unwrap()
+        );
+        assert_eq!(None, path("options.java_package").get(&file).unwrap());
+        assert_eq!(None, path("package").get(&file).unwrap());
+
+        match FieldPath::root().get(&file) {
+            Ok(Some(ReflectValueRef::Message(m))) => {
+                assert_eq!(Some(&file), m.downcast_ref::<FileDescriptorProto>())
+            }
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn get_errors() {
+        let file = file();
+        assert_eq!(
+            Err(FieldPathError::UnknownField {
+                path: "message_type[0].fieldz".to_owned(),
+                message: "google.protobuf.DescriptorProto".to_owned(),
+            }),
+            path("message_type[0].fieldz").get(&file)
+        );
+        assert_eq!(
+            Err(FieldPathError::IndexOutOfRange {
+                path: "message_type[1]".to_owned(),
+                len: 1,
+            }),
+            path("message_type[1].name").get(&file)
+        );
+        assert_eq!(
+            Err(FieldPathError::NotMessage("name".to_owned())),
+            path("name.x").get(&file)
+        );
+        assert_eq!(
+            Err(FieldPathError::NotSingular("message_type".to_owned())),
+            path("message_type").get(&file)
+        );
+        assert_eq!(
+            Err(FieldPathError::NotRepeatedOrMap("name[0]".to_owned())),
+            path("name[0]").get(&file)
+        );
+        assert_eq!(
+            Err(FieldPathError::InvalidIndex("message_type[x]".to_owned())),
+            path("message_type[x]").get(&file)
+        );
+    }
+
+    #[test]
+    fn set() {
+        let mut file = file();
+        path("options.java_package")
+            .set(&mut file, ReflectValueBox::String("j".to_owned()))
+            .unwrap();
+        assert_eq!("j", file.options.get_ref().get_java_package());
+
+        path("message_type[0].field[0].number")
+            .set(&mut file, ReflectValueBox::I32(5))
+            .unwrap();
+        assert_eq!(5, file.message_type[0].field[0].get_number());
+
+        assert_eq!(
+            Err(FieldPathError::TypeMismatch {
+                path: "message_type[0].name".to_owned(),
+                expected: RuntimeTypeBox::String,
+                actual: RuntimeTypeBox::I32,
+            }),
+            path("message_type[0].name").set(&mut file, ReflectValueBox::I32(1))
+        );
+
+        path("dependency")
+            .append(&mut file, ReflectValueBox::String("x".to_owned()))
+            .unwrap();
+        path("dependency")
+            .append(&mut file, ReflectValueBox::String("y".to_owned()))
+            .unwrap();
+        path("dependency[0]")
+            .set(&mut file, ReflectValueBox::String("z".to_owned()))
+            .unwrap();
+        assert_eq!(vec!["z", "y"], file.dependency);
+        assert_eq!(
+            Err(FieldPathError::NotRepeated("name".to_owned())),
+            path("name").append(&mut file, ReflectValueBox::String("x".to_owned()))
+        );
+    }
+
+    #[test]
+    fn empty_path() {
+        let mut file = file();
+        let root = FieldPath::root();
+        assert_eq!(
+            Err(FieldPathError::EmptyPath),
+            root.set(&mut file, ReflectValueBox::I32(1))
+        );
+        assert_eq!(
+            Err(FieldPathError::EmptyPath),
+            root.append(&mut file, ReflectValueBox::I32(1))
+        );
+        assert_eq!(Err(FieldPathError::EmptyPath), root.clear(&mut file));
+    }
+
+    #[test]
+    fn clear() {
+        let mut file = file();
+        file.dependency = vec!["x".to_owned(), "y".to_owned(), "z".to_owned()];
+        path("dependency[1]").clear(&mut file).unwrap();
+        assert_eq!(vec!["x", "z"], file.dependency);
+        path("message_type[0].field[0].name")
+            .clear(&mut file)
+            .unwrap();
+        assert!(!file.message_type[0].field[0].has_name());
+        path("message_type").clear(&mut file).unwrap();
+        assert!(file.message_type.is_empty());
+    }
+
+    #[test]
+    fn map() {
+        let mut s = Struct::new();
+        path("fields[\"a\"].string_value")
+            .set(&mut s, ReflectValueBox::String("x".to_owned()))
+            .unwrap();
+        assert_eq!("x", s.fields["a"].get_string_value());
+        assert_eq!(
+            Some(ReflectValueRef::String("x")),
+            path("fields[\"a\"].string_value").get(&s).unwrap()
+        );
+        assert_eq!(None, path("fields[\"b\"].string_value").get(&s).unwrap());
+
+        let mut v = Value::new();
+        v.set_bool_value(true);
+        path("fields[\"b\"]")
+            .set(&mut s, ReflectValueBox::Message(Box::new(v)))
+            .unwrap();
+        assert_eq!(2, s.fields.len());
+
+        assert_eq!(
+            Err(FieldPathError::InvalidKey {
+                path: "fields[1]".to_owned(),
+                key_type: RuntimeTypeBox::String,
+            }),
+            path("fields[1]").get(&s)
+        );
+
+        path("fields[\"a\"]").clear(&mut s).unwrap();
+        assert_eq!(vec!["b"], s.fields.keys().collect::<Vec<_>>());
+    }
+}
@@ -6,6 +6,7 @@ use crate::reflect::file::fds::fds_extend_with_public;
 use crate::reflect::file::index::FileIndex;
 use crate::reflect::message::dynamic::DynamicMessageDescriptor;
 use crate::reflect::message::path::MessagePath;
+use crate::reflect::options::ExtensionIndex;
 use crate::reflect::FileDescriptor;
 use std::sync::Arc;
 
@@ -16,6 +17,7 @@ pub(crate) struct DynamicFileDescriptor {
     pub messages: Vec<DynamicMessageDescriptor>,
     pub enums: Vec<DynamicEnumDescriptor>,
     pub index: FileIndex,
+    pub extensions: ExtensionIndex,
 }
 
 impl DynamicFileDescriptor {
@@ -43,12 +45,22 @@ impl DynamicFileDescriptor {
             })
             .collect();
 
+        let extensions = ExtensionIndex::index(
+            &FileDescriptorBuilding {
+                current_file_index: &index,
+                current_file_descriptor: &proto,
+                deps_with_public: &fds_extend_with_public(dependencies.clone()),
+            },
+            &dependencies,
+        );
+
         DynamicFileDescriptor {
             messages,
             enums: Self::enums(&proto),
             proto,
             dependencies,
             index,
+            extensions,
         }
     }
 
@@ -4,6 +4,7 @@ use crate::reflect::file::building::FileDescriptorBuilding;
 use crate::reflect::file::fds::fds_extend_with_public;
 use crate::reflect::file::index::FileIndex;
 use crate::reflect::message::generated::GeneratedMessageDescriptor;
+use crate::reflect::options::ExtensionIndex;
 use crate::reflect::FileDescriptor;
 use crate::reflect::GeneratedEnumDescriptorData;
 use crate::reflect::GeneratedMessageDescriptorData;
@@ -18,6 +19,7 @@ pub struct GeneratedFileDescriptor {
     pub(crate) messages: Vec<GeneratedMessageDescriptor>,
     pub(crate) enums: Vec<GeneratedEnumDescriptor>,
     pub(crate) index: FileIndex,
+    pub(crate) extensions: ExtensionIndex,
 }
 
 impl GeneratedFileDescriptor {
@@ -65,12 +67,22 @@ impl GeneratedFileDescriptor {
             .map(|(i, e)| GeneratedEnumDescriptor::new(e, i, file_descriptor_proto))
             .collect();
 
+        let extensions = ExtensionIndex::index(
+            &FileDescriptorBuilding {
+                current_file_index: &index,
+                current_file_descriptor: file_descriptor_proto,
+                deps_with_public: &fds_extend_with_public(dependencies.clone()),
+            },
+            &dependencies,
+        );
+
         GeneratedFileDescriptor {
             proto: file_descriptor_proto,
             dependencies,
             messages,
             enums,
             index,
+            extensions,
         }
     }
 }
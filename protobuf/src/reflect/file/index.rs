@@ -19,7 +19,6 @@ pub(crate) struct FileIndexMessageEntry {
 #[derive(Debug)]
 pub(crate) struct FileIndexEnumEntry {
     pub message_path: MessagePath,
-    pub enum_index: usize,
     pub name_to_package: String,
 }
 
@@ -46,7 +45,6 @@ impl FileIndex {
         for (_, e) in file.enum_type.iter().enumerate() {
             index.enums.push(FileIndexEnumEntry {
                 message_path: MessagePath(Vec::new()),
-                enum_index: index.enums.len(),
                 name_to_package: e.get_name().to_owned(),
             });
         }
@@ -88,7 +86,6 @@ impl FileIndex {
         for (_, e) in message.enum_type.iter().enumerate() {
             self.enums.push(FileIndexEnumEntry {
                 message_path: path.clone(),
-                enum_index: self.enums.len(),
                 name_to_package: concat_paths(&name_to_package, e.get_name()),
             });
         }
@@ -1,11 +1,22 @@
 use crate::descriptor::DescriptorProto;
 use crate::descriptor::FileDescriptorProto;
+use crate::descriptor::FileOptions;
+use crate::ext::ExtFieldOptional;
+use crate::ext::ExtFieldRepeated;
+use crate::message::Message;
+use crate::reflect::features::Edition;
+use crate::reflect::features::ResolvedFeatures;
 use crate::reflect::file::dynamic::DynamicFileDescriptor;
 use crate::reflect::file::fds::FdsBuilder;
 use crate::reflect::file::index::FileIndex;
+use crate::reflect::file::index::FileIndexEnumEntry;
 use crate::reflect::file::index::FileIndexMessageEntry;
 use crate::reflect::name::protobuf_name_starts_with_package;
+use crate::reflect::options;
+use crate::reflect::options::ExtensionIndex;
+use crate::reflect::types::ProtobufType;
 use crate::reflect::MessageDescriptor;
+use crate::reflect::ReflectValueBox;
 use crate::reflect::{EnumDescriptor, GeneratedFileDescriptor};
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
@@ -17,6 +28,7 @@ pub(crate) mod dynamic;
 pub(crate) mod fds;
 pub(crate) mod generated;
 pub(crate) mod index;
+pub(crate) mod proto_source;
 
 #[derive(Clone, Debug)]
 pub(crate) enum FileDescriptorImpl {
@@ -71,10 +83,21 @@ impl FileDescriptor {
         }
     }
 
+    pub(crate) fn extension_index(&self) -> &ExtensionIndex {
+        match &self.imp {
+            FileDescriptorImpl::Generated(g) => &g.extensions,
+            FileDescriptorImpl::Dynamic(d) => &d.extensions,
+        }
+    }
+
     pub(crate) fn message_index_entry(&self, index: usize) -> &FileIndexMessageEntry {
         &self.index().messages[index]
     }
 
+    pub(crate) fn enum_index_entry(&self, index: usize) -> &FileIndexEnumEntry {
+        &self.index().enums[index]
+    }
+
     pub(crate) fn message_proto(&self, index: usize) -> &DescriptorProto {
         self.message_index_entry(index)
             .path
@@ -193,6 +216,75 @@ impl FileDescriptor {
         }
     }
 
+    /// Edition of this file, `proto2` and `proto3` are editions too.
+    pub fn edition(&self) -> Edition {
+        Edition::of_file(self.proto())
+    }
+
+    /// Features of this file.
+    pub fn features(&self) -> ResolvedFeatures {
+        ResolvedFeatures::for_file(self.proto())
+    }
+
+    /// Options of this file, default instance if not specified in `.proto` file.
+    pub fn options(&self) -> &FileOptions {
+        self.proto().options.get_or_default()
+    }
+
+    /// Get custom option of this file by extension generated by `protoc`.
+    pub fn get_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldOptional<FileOptions, T>,
+    ) -> Option<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get custom option of this file by extension fully-qualified name,
+    /// e. g. `acme.sensitive`.
+    ///
+    /// Extension is looked up in this file and its dependencies.
+    /// Returns `None` if option is not set, extension is not found
+    /// or extension is repeated.
+    pub fn get_option_by_name(&self, name: &str) -> Option<ReflectValueBox> {
+        options::custom_option(
+            self,
+            ".google.protobuf.FileOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    /// Get repeated custom option of this file by extension generated by `protoc`.
+    pub fn get_repeated_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldRepeated<FileOptions, T>,
+    ) -> Vec<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get repeated custom option of this file by extension fully-qualified name.
+    ///
+    /// Returns empty vec if option is not set, extension is not found
+    /// or extension is not repeated.
+    pub fn get_repeated_option_by_name(&self, name: &str) -> Vec<ReflectValueBox> {
+        options::custom_repeated_option(
+            self,
+            ".google.protobuf.FileOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    /// Render this file as `.proto` source.
+    ///
+    /// Parsing the output produces a descriptor equivalent to this one.
+    /// Comments are printed from `source_code_info` if it is present.
+    /// Custom options are printed only if their extensions are declared
+    /// in this file or its dependencies.
+    pub fn to_proto_source(&self) -> String {
+        proto_source::print_file(self)
+    }
+
     fn deps(&self) -> &[FileDescriptor] {
         match &self.imp {
             FileDescriptorImpl::Generated(g) => &g.dependencies,
@@ -209,7 +301,7 @@ impl FileDescriptor {
             .collect()
     }
 
-    fn _all_files(&self) -> Vec<&FileDescriptor> {
+    pub(crate) fn _all_files(&self) -> Vec<&FileDescriptor> {
         let mut r = Vec::new();
         let mut visited = HashSet::new();
 
@@ -0,0 +1,872 @@
+//! Render file descriptor as `.proto` source.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::descriptor::field_descriptor_proto::Label;
+use crate::descriptor::field_descriptor_proto::Type;
+use crate::descriptor::source_code_info::Location;
+use crate::descriptor::DescriptorProto;
+use crate::descriptor::EnumDescriptorProto;
+use crate::descriptor::FieldDescriptorProto;
+use crate::descriptor::ServiceDescriptorProto;
+use crate::json;
+use crate::reflect::features::Edition;
+use crate::reflect::features::OptionsWithFeatures;
+use crate::reflect::FileDescriptor;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueRef;
+use crate::text_format;
+use crate::text_format::lexer::float::format_protobuf_float;
+use crate::wire_format;
+use crate::zigzag::decode_zig_zag_32;
+use crate::zigzag::decode_zig_zag_64;
+use crate::CodedInputStream;
+use crate::MessageDyn;
+use crate::MessageField;
+use crate::UnknownValueRef;
+use crate::UnknownValues;
+
+/// Max field number, printed as `max` in ranges.
+const FIELD_NUMBER_MAX: i32 = 0x20000000 - 1;
+
+// Field numbers used in `SourceCodeInfo` paths.
+const FILE_PACKAGE: i32 = 2;
+const FILE_DEPENDENCY: i32 = 3;
+const FILE_MESSAGE_TYPE: i32 = 4;
+const FILE_ENUM_TYPE: i32 = 5;
+const FILE_SERVICE: i32 = 6;
+const FILE_EXTENSION: i32 = 7;
+const FILE_SYNTAX: i32 = 12;
+const MESSAGE_FIELD: i32 = 2;
+const MESSAGE_NESTED_TYPE: i32 = 3;
+const MESSAGE_ENUM_TYPE: i32 = 4;
+const MESSAGE_EXTENSION: i32 = 6;
+const MESSAGE_ONEOF_DECL: i32 = 8;
+const ENUM_VALUE: i32 = 2;
+const SERVICE_METHOD: i32 = 2;
+
+fn path_of(parent: &[i32], field: i32, index: usize) -> Vec<i32> {
+    let mut r = parent.to_vec();
+    r.push(field);
+    r.push(index as i32);
+    r
+}
+
+fn quote(s: &str) -> String {
+    text_format::quote_escape_bytes(s.as_bytes())
+}
+
+fn scalar_type_name(t: Type) -> &'static str {
+    match t {
+        Type::TYPE_DOUBLE => "double",
+        Type::TYPE_FLOAT => "float",
+        Type::TYPE_INT64 => "int64",
+        Type::TYPE_UINT64 => "uint64",
+        Type::TYPE_INT32 => "int32",
+        Type::TYPE_FIXED64 => "fixed64",
+        Type::TYPE_FIXED32 => "fixed32",
+        Type::TYPE_BOOL => "bool",
+        Type::TYPE_STRING => "string",
+        Type::TYPE_BYTES => "bytes",
+        Type::TYPE_UINT32 => "uint32",
+        Type::TYPE_SFIXED32 => "sfixed32",
+        Type::TYPE_SFIXED64 => "sfixed64",
+        Type::TYPE_SINT32 => "sint32",
+        Type::TYPE_SINT64 => "sint64",
+        Type::TYPE_GROUP | Type::TYPE_MESSAGE | Type::TYPE_ENUM => unreachable!(),
+    }
+}
+
+fn format_value(value: ReflectValueRef) -> String {
+    match value {
+        ReflectValueRef::U32(v) => v.to_string(),
+        ReflectValueRef::U64(v) => v.to_string(),
+        ReflectValueRef::I32(v) => v.to_string(),
+        ReflectValueRef::I64(v) => v.to_string(),
+        ReflectValueRef::F32(v) => format_protobuf_float(v as f64),
+        ReflectValueRef::F64(v) => format_protobuf_float(v),
+        ReflectValueRef::Bool(v) => v.to_string(),
+        ReflectValueRef::String(v) => quote(v),
+        ReflectValueRef::Bytes(v) => text_format::quote_escape_bytes(v),
+        ReflectValueRef::Enum(d, v) => match d.get_value_by_number(v) {
+            Some(v) => v.get_name().to_owned(),
+            None => v.to_string(),
+        },
+        ReflectValueRef::Message(m) => format_message(&*m),
+    }
+}
+
+fn format_message(m: &dyn MessageDyn) -> String {
+    let text = text_format::print_to_string(m);
+    if text.is_empty() {
+        "{ }".to_owned()
+    } else {
+        format!("{{ {} }}", text)
+    }
+}
+
+/// Messages, enums and extensions declared in a file or in a message.
+struct Scope<'a> {
+    /// Fully qualified name with leading dot, empty for file without package.
+    full_name: String,
+    path: &'a [i32],
+    messages: &'a [DescriptorProto],
+    messages_field: i32,
+    enums: &'a [EnumDescriptorProto],
+    enums_field: i32,
+    extensions: &'a [FieldDescriptorProto],
+    extensions_field: i32,
+}
+
+impl<'a> Scope<'a> {
+    /// Index of nested message which is declared by field:
+    /// map entry or group.
+    fn owned_message(&self, field: &FieldDescriptorProto) -> Option<usize> {
+        let is_group = field.get_field_type() == Type::TYPE_GROUP;
+        let is_map = field.get_field_type() == Type::TYPE_MESSAGE
+            && field.get_label() == Label::LABEL_REPEATED;
+        if !is_group && !is_map {
+            return None;
+        }
+        self.messages.iter().position(|m| {
+            (is_group || m.options.get_or_default().get_map_entry())
+                && field.get_type_name() == format!("{}.{}", self.full_name, m.get_name())
+        })
+    }
+}
+
+/// Fields or extensions printed together.
+enum Block<'a> {
+    Field(usize),
+    Oneof(usize, Vec<usize>),
+    Extend(&'a str, Vec<usize>),
+}
+
+struct Printer<'a> {
+    file: &'a FileDescriptor,
+    files: Vec<&'a FileDescriptor>,
+    locations: HashMap<&'a [i32], &'a Location>,
+    edition: Edition,
+    out: String,
+    indent: usize,
+}
+
+impl<'a> Printer<'a> {
+    fn line(&mut self, line: &str) {
+        if !line.is_empty() {
+            for _ in 0..self.indent {
+                self.out.push_str("  ");
+            }
+            self.out.push_str(line);
+        }
+        self.out.push('\n');
+    }
+
+    fn comment(&mut self, comment: &str) {
+        let comment = comment.strip_suffix('\n').unwrap_or(comment);
+        for line in comment.split('\n') {
+            self.line(&format!("//{}", line));
+        }
+    }
+
+    fn leading_comments(&mut self, path: &[i32]) {
+        if let Some(location) = self.locations.get(path).cloned() {
+            for detached in &location.leading_detached_comments {
+                self.comment(detached);
+                self.line("");
+            }
+            if location.has_leading_comments() {
+                self.comment(location.get_leading_comments());
+            }
+        }
+    }
+
+    fn trailing_comments(&mut self, path: &[i32]) {
+        if let Some(location) = self.locations.get(path).cloned() {
+            if location.has_trailing_comments() {
+                self.comment(location.get_trailing_comments());
+            }
+        }
+    }
+
+    /// Find extension in this file and dependencies.
+    ///
+    /// Returns fully qualified name and extension.
+    fn find_extension(
+        &self,
+        extendee: &str,
+        number: u32,
+    ) -> Option<(String, &'a FieldDescriptorProto)> {
+        fn find<'a>(
+            scope: &str,
+            extensions: &'a [FieldDescriptorProto],
+            messages: &'a [DescriptorProto],
+            extendee: &str,
+            number: u32,
+        ) -> Option<(String, &'a FieldDescriptorProto)> {
+            for e in extensions {
+                if e.get_extendee() == extendee && e.get_number() as u32 == number {
+                    return Some((format!("{}.{}", scope, e.get_name()), e));
+                }
+            }
+            for m in messages {
+                let scope = format!("{}.{}", scope, m.get_name());
+                if let Some(r) = find(&scope, &m.extension, &m.nested_type, extendee, number) {
+                    return Some(r);
+                }
+            }
+            None
+        }
+
+        for file in &self.files {
+            let proto = file.proto();
+            let scope = match proto.get_package() {
+                "" => String::new(),
+                package => format!(".{}", package),
+            };
+            let found = find(
+                &scope,
+                &proto.extension,
+                &proto.message_type,
+                extendee,
+                number,
+            );
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+
+    fn extension_value(&self, ext: &FieldDescriptorProto, value: UnknownValueRef) -> Vec<String> {
+        match value {
+            UnknownValueRef::Varint(v) => vec![self.varint_value(ext, v)],
+            UnknownValueRef::Fixed32(v) => vec![match ext.get_field_type() {
+                Type::TYPE_FLOAT => format_protobuf_float(f32::from_bits(v) as f64),
+                Type::TYPE_SFIXED32 => (v as i32).to_string(),
+                _ => v.to_string(),
+            }],
+            UnknownValueRef::Fixed64(v) => vec![match ext.get_field_type() {
+                Type::TYPE_DOUBLE => format_protobuf_float(f64::from_bits(v)),
+                Type::TYPE_SFIXED64 => (v as i64).to_string(),
+                _ => v.to_string(),
+            }],
+            UnknownValueRef::LengthDelimited(bytes) => match ext.get_field_type() {
+                Type::TYPE_STRING | Type::TYPE_BYTES => {
+                    vec![text_format::quote_escape_bytes(bytes)]
+                }
+                Type::TYPE_MESSAGE | Type::TYPE_GROUP => self.message_value(ext, bytes),
+                // packed repeated
+                t => {
+                    let mut r = Vec::new();
+                    let mut is = CodedInputStream::from_bytes(bytes);
+                    let wire_type = match t {
+                        Type::TYPE_FLOAT | Type::TYPE_FIXED32 | Type::TYPE_SFIXED32 => {
+                            wire_format::WireTypeFixed32
+                        }
+                        Type::TYPE_DOUBLE | Type::TYPE_FIXED64 | Type::TYPE_SFIXED64 => {
+                            wire_format::WireTypeFixed64
+                        }
+                        _ => wire_format::WireTypeVarint,
+                    };
+                    while let Ok(false) = is.eof() {
+                        let value = match wire_type {
+                            wire_format::WireTypeFixed32 => is
+                                .read_fixed32()
+                                .map(|v| self.extension_value(ext, UnknownValueRef::Fixed32(v))),
+                            wire_format::WireTypeFixed64 => is
+                                .read_fixed64()
+                                .map(|v| self.extension_value(ext, UnknownValueRef::Fixed64(v))),
+                            _ => is
+                                .read_raw_varint64()
+                                .map(|v| self.extension_value(ext, UnknownValueRef::Varint(v))),
+                        };
+                        match value {
+                            Ok(value) => r.extend(value),
+                            Err(_) => break,
+                        }
+                    }
+                    r
+                }
+            },
+            UnknownValueRef::Group(group) => self.message_value(ext, &group.write_to_bytes()),
+        }
+    }
+
+    fn message_value(&self, ext: &FieldDescriptorProto, bytes: &[u8]) -> Vec<String> {
+        let type_name = ext.get_type_name();
+        let message = self
+            .files
+            .iter()
+            .filter_map(|f| f.message_by_full_name(type_name))
+            .next();
+        match message {
+            Some(message) => {
+                let mut m = message.new_instance();
+                match m.merge_from_bytes_dyn(bytes) {
+                    Ok(()) => vec![format_message(&*m)],
+                    Err(_) => Vec::new(),
+                }
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn varint_value(&self, ext: &FieldDescriptorProto, v: u64) -> String {
+        match ext.get_field_type() {
+            Type::TYPE_INT32 => (v as i32).to_string(),
+            Type::TYPE_INT64 => (v as i64).to_string(),
+            Type::TYPE_UINT32 => (v as u32).to_string(),
+            Type::TYPE_SINT32 => decode_zig_zag_32(v as u32).to_string(),
+            Type::TYPE_SINT64 => decode_zig_zag_64(v).to_string(),
+            Type::TYPE_BOOL => (v != 0).to_string(),
+            Type::TYPE_ENUM => {
+                let type_name = ext.get_type_name();
+                let value = self
+                    .files
+                    .iter()
+                    .filter_map(|f| f.enum_by_full_name(type_name))
+                    .next()
+                    .and_then(|e| e.get_value_by_number(v as i32));
+                match value {
+                    Some(value) => value.get_name().to_owned(),
+                    None => (v as i32).to_string(),
+                }
+            }
+            _ => v.to_string(),
+        }
+    }
+
+    /// Options as `name = value` pairs.
+    ///
+    /// Custom options which cannot be resolved are skipped.
+    fn options<M: OptionsWithFeatures>(&self, options: &MessageField<M>) -> Vec<String> {
+        let options = match options.as_ref() {
+            Some(options) => options,
+            None => return Vec::new(),
+        };
+        let mut r = Vec::new();
+        let descriptor = M::descriptor_static();
+        for field in descriptor.fields() {
+            // Printed below as `features.xxx` options
+            if field.get_name() == "features" {
+                continue;
+            }
+            if let ReflectFieldRef::Optional(Some(value)) = field.get_reflect(options) {
+                r.push(format!("{} = {}", field.get_name(), format_value(value)));
+            }
+        }
+        for (name, value) in options.features().option_values() {
+            r.push(format!("features.{} = {}", name, value));
+        }
+        let mut unknown_fields: Vec<(u32, &UnknownValues)> =
+            options.get_unknown_fields().iter().collect();
+        unknown_fields.sort_by_key(|&(number, _)| number);
+        let extendee = format!(".{}", descriptor.full_name());
+        for (number, values) in unknown_fields {
+            if let Some((name, ext)) = self.find_extension(&extendee, number) {
+                for value in values.iter() {
+                    for value in self.extension_value(ext, value) {
+                        r.push(format!("({}) = {}", name, value));
+                    }
+                }
+            }
+        }
+        r
+    }
+
+    fn option_statements<M: OptionsWithFeatures>(&mut self, options: &MessageField<M>) {
+        for option in self.options(options) {
+            self.line(&format!("option {};", option));
+        }
+    }
+
+    fn bracket_options(options: Vec<String>) -> String {
+        if options.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", options.join(", "))
+        }
+    }
+
+    fn field_type(field: &FieldDescriptorProto) -> String {
+        match field.get_field_type() {
+            Type::TYPE_GROUP | Type::TYPE_MESSAGE | Type::TYPE_ENUM => {
+                field.get_type_name().to_owned()
+            }
+            t => scalar_type_name(t).to_owned(),
+        }
+    }
+
+    fn label(&self, field: &FieldDescriptorProto, in_oneof: bool) -> &'static str {
+        if field.get_label() == Label::LABEL_REPEATED {
+            return "repeated ";
+        }
+        if in_oneof {
+            return "";
+        }
+        match self.edition {
+            Edition::Proto2 if field.get_label() == Label::LABEL_REQUIRED => "required ",
+            Edition::Proto2 => "optional ",
+            Edition::Proto3 if field.get_proto3_optional() => "optional ",
+            Edition::Proto3 | Edition::Edition2023 => "",
+        }
+    }
+
+    fn field_options(&self, field: &FieldDescriptorProto) -> String {
+        let mut options = Vec::new();
+        if field.has_default_value() {
+            let value = match field.get_field_type() {
+                Type::TYPE_STRING => quote(field.get_default_value()),
+                // already escaped
+                Type::TYPE_BYTES => format!("\"{}\"", field.get_default_value()),
+                _ => field.get_default_value().to_owned(),
+            };
+            options.push(format!("default = {}", value));
+        }
+        if field.has_json_name() && field.get_json_name() != json::json_name(field.get_name()) {
+            options.push(format!("json_name = {}", quote(field.get_json_name())));
+        }
+        options.extend(self.options(&field.options));
+        Printer::bracket_options(options)
+    }
+
+    fn field(&mut self, scope: &Scope, field: &FieldDescriptorProto, path: &[i32], in_oneof: bool) {
+        self.leading_comments(path);
+        let owned = scope.owned_message(field);
+        let name = field.get_name();
+        let number = field.get_number();
+        let options = self.field_options(field);
+        match owned {
+            Some(index) if field.get_field_type() == Type::TYPE_GROUP => {
+                let group = &scope.messages[index];
+                let label = self.label(field, in_oneof);
+                self.line(&format!(
+                    "{}group {} = {}{} {{",
+                    label,
+                    group.get_name(),
+                    number,
+                    options
+                ));
+                self.indent += 1;
+                self.trailing_comments(path);
+                let group_path = path_of(scope.path, scope.messages_field, index);
+                let full_name = format!("{}.{}", scope.full_name, group.get_name());
+                self.message_body(group, &group_path, &full_name);
+                self.indent -= 1;
+                self.line("}");
+            }
+            Some(index) => {
+                let entry = &scope.messages[index];
+                let entry_type = |number| {
+                    entry
+                        .field
+                        .iter()
+                        .find(|f| f.get_number() == number)
+                        .map(Printer::field_type)
+                        .unwrap_or_default()
+                };
+                self.line(&format!(
+                    "map<{}, {}> {} = {}{};",
+                    entry_type(1),
+                    entry_type(2),
+                    name,
+                    number,
+                    options
+                ));
+                self.trailing_comments(path);
+            }
+            None => {
+                let label = self.label(field, in_oneof);
+                self.line(&format!(
+                    "{}{} {} = {}{};",
+                    label,
+                    Printer::field_type(field),
+                    name,
+                    number,
+                    options
+                ));
+                self.trailing_comments(path);
+            }
+        }
+    }
+
+    fn enumeration(&mut self, e: &EnumDescriptorProto, path: &[i32]) {
+        self.leading_comments(path);
+        self.line(&format!("enum {} {{", e.get_name()));
+        self.indent += 1;
+        self.trailing_comments(path);
+        self.option_statements(&e.options);
+        for (i, value) in e.value.iter().enumerate() {
+            let value_path = path_of(path, ENUM_VALUE, i);
+            self.leading_comments(&value_path);
+            let options = Printer::bracket_options(self.options(&value.options));
+            self.line(&format!(
+                "{} = {}{};",
+                value.get_name(),
+                value.get_number(),
+                options
+            ));
+            self.trailing_comments(&value_path);
+        }
+        let ranges: Vec<String> = e
+            .reserved_range
+            .iter()
+            .map(|r| match (r.get_start(), r.get_end()) {
+                (start, end) if start == end => start.to_string(),
+                (start, i32::MAX) => format!("{} to max", start),
+                (start, end) => format!("{} to {}", start, end),
+            })
+            .collect();
+        self.reserved(ranges, &e.reserved_name);
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn reserved(&mut self, ranges: Vec<String>, names: &[String]) {
+        if !ranges.is_empty() {
+            self.line(&format!("reserved {};", ranges.join(", ")));
+        }
+        if !names.is_empty() {
+            let names: Vec<String> = match self.edition {
+                Edition::Proto2 | Edition::Proto3 => names.iter().map(|n| quote(n)).collect(),
+                Edition::Edition2023 => names.to_vec(),
+            };
+            self.line(&format!("reserved {};", names.join(", ")));
+        }
+    }
+
+    fn message(&mut self, m: &DescriptorProto, path: &[i32], full_name: &str) {
+        self.leading_comments(path);
+        self.line(&format!("message {} {{", m.get_name()));
+        self.indent += 1;
+        self.trailing_comments(path);
+        self.message_body(m, path, full_name);
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn message_body(&mut self, m: &DescriptorProto, path: &[i32], full_name: &str) {
+        self.option_statements(&m.options);
+        let scope = Scope {
+            full_name: full_name.to_owned(),
+            path,
+            messages: &m.nested_type,
+            messages_field: MESSAGE_NESTED_TYPE,
+            enums: &m.enum_type,
+            enums_field: MESSAGE_ENUM_TYPE,
+            extensions: &m.extension,
+            extensions_field: MESSAGE_EXTENSION,
+        };
+
+        let mut fields = Vec::new();
+        let mut oneofs_printed = HashSet::new();
+        for (i, field) in m.field.iter().enumerate() {
+            if field.has_oneof_index() && !field.get_proto3_optional() {
+                let oneof = field.get_oneof_index() as usize;
+                if oneofs_printed.insert(oneof) {
+                    let members = m
+                        .field
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, f)| {
+                            f.has_oneof_index()
+                                && !f.get_proto3_optional()
+                                && f.get_oneof_index() as usize == oneof
+                        })
+                        .map(|(i, _)| i)
+                        .collect();
+                    fields.push(Block::Oneof(oneof, members));
+                }
+            } else {
+                fields.push(Block::Field(i));
+            }
+        }
+
+        self.scope_body(&scope, Some(m), fields);
+
+        for range in &m.extension_range {
+            self.line(&format!(
+                "extensions {};",
+                Printer::field_range(range.get_start(), range.get_end())
+            ));
+        }
+        let ranges = m
+            .reserved_range
+            .iter()
+            .map(|r| Printer::field_range(r.get_start(), r.get_end()))
+            .collect();
+        self.reserved(ranges, &m.reserved_name);
+    }
+
+    /// Range with exclusive end.
+    fn field_range(start: i32, end: i32) -> String {
+        match end - 1 {
+            end if end == start => start.to_string(),
+            FIELD_NUMBER_MAX => format!("{} to max", start),
+            end => format!("{} to {}", start, end),
+        }
+    }
+
+    /// Enums, messages, fields and extensions of a file or a message.
+    ///
+    /// Map entries and groups are declared by fields, and the order
+    /// of nested messages is preserved when the output is parsed again.
+    fn scope_body(&mut self, scope: &Scope, message: Option<&DescriptorProto>, fields: Vec<Block>) {
+        for (i, e) in scope.enums.iter().enumerate() {
+            self.enumeration(e, &path_of(scope.path, scope.enums_field, i));
+        }
+
+        let mut extends: Vec<Block> = Vec::new();
+        for (i, ext) in scope.extensions.iter().enumerate() {
+            match extends.last_mut() {
+                Some(Block::Extend(extendee, exts)) if *extendee == ext.get_extendee() => {
+                    exts.push(i)
+                }
+                _ => extends.push(Block::Extend(ext.get_extendee(), vec![i])),
+            }
+        }
+
+        let owned = |block: &Block| -> Option<usize> {
+            let members: Vec<&FieldDescriptorProto> = match block {
+                Block::Field(i) => vec![&message.unwrap().field[*i]],
+                Block::Oneof(_, members) => members
+                    .iter()
+                    .map(|&i| &message.unwrap().field[i])
+                    .collect(),
+                Block::Extend(_, members) => {
+                    members.iter().map(|&i| &scope.extensions[i]).collect()
+                }
+            };
+            members
+                .into_iter()
+                .filter_map(|f| scope.owned_message(f))
+                .min()
+        };
+        let first_owned = |blocks: &[Block]| blocks.iter().filter_map(&owned).next();
+        let owned_messages: HashSet<usize> = fields
+            .iter()
+            .chain(extends.iter())
+            .filter_map(&owned)
+            .collect();
+
+        // Merge fields and extensions so messages owned by them keep their order.
+        let mut next_field = 0;
+        let mut next_extend = 0;
+        let mut next_message = 0;
+        while next_field < fields.len() || next_extend < extends.len() {
+            let take_extend = if next_field == fields.len() {
+                true
+            } else if next_extend == extends.len() {
+                false
+            } else {
+                match (
+                    first_owned(&fields[next_field..]),
+                    first_owned(&extends[next_extend..]),
+                ) {
+                    (Some(f), Some(e)) => e < f,
+                    _ => false,
+                }
+            };
+            let block = if take_extend {
+                next_extend += 1;
+                &extends[next_extend - 1]
+            } else {
+                next_field += 1;
+                &fields[next_field - 1]
+            };
+            if let Some(owned) = owned(block) {
+                self.messages_before(scope, &owned_messages, &mut next_message, owned);
+            }
+            self.block(scope, message, block);
+        }
+        self.messages_before(
+            scope,
+            &owned_messages,
+            &mut next_message,
+            scope.messages.len(),
+        );
+    }
+
+    fn messages_before(
+        &mut self,
+        scope: &Scope,
+        owned_messages: &HashSet<usize>,
+        next_message: &mut usize,
+        end: usize,
+    ) {
+        while *next_message < end {
+            let i = *next_message;
+            *next_message += 1;
+            if owned_messages.contains(&i) {
+                continue;
+            }
+            let m = &scope.messages[i];
+            let full_name = format!("{}.{}", scope.full_name, m.get_name());
+            self.message(m, &path_of(scope.path, scope.messages_field, i), &full_name);
+        }
+    }
+
+    fn block(&mut self, scope: &Scope, message: Option<&DescriptorProto>, block: &Block) {
+        match block {
+            Block::Field(i) => {
+                let field_path = path_of(scope.path, MESSAGE_FIELD, *i);
+                self.field(scope, &message.unwrap().field[*i], &field_path, false);
+            }
+            Block::Oneof(oneof, members) => {
+                let message = message.unwrap();
+                let oneof_path = path_of(scope.path, MESSAGE_ONEOF_DECL, *oneof);
+                let decl = &message.oneof_decl[*oneof];
+                self.leading_comments(&oneof_path);
+                self.line(&format!("oneof {} {{", decl.get_name()));
+                self.indent += 1;
+                self.trailing_comments(&oneof_path);
+                self.option_statements(&decl.options);
+                for &i in members {
+                    let field_path = path_of(scope.path, MESSAGE_FIELD, i);
+                    self.field(scope, &message.field[i], &field_path, true);
+                }
+                self.indent -= 1;
+                self.line("}");
+            }
+            Block::Extend(extendee, members) => {
+                self.line(&format!("extend {} {{", extendee));
+                self.indent += 1;
+                for &i in members {
+                    let ext_path = path_of(scope.path, scope.extensions_field, i);
+                    self.field(scope, &scope.extensions[i], &ext_path, false);
+                }
+                self.indent -= 1;
+                self.line("}");
+            }
+        }
+    }
+
+    fn service(&mut self, service: &ServiceDescriptorProto, path: &[i32]) {
+        self.leading_comments(path);
+        self.line(&format!("service {} {{", service.get_name()));
+        self.indent += 1;
+        self.trailing_comments(path);
+        self.option_statements(&service.options);
+        for (i, method) in service.method.iter().enumerate() {
+            let method_path = path_of(path, SERVICE_METHOD, i);
+            self.leading_comments(&method_path);
+            let stream = |streaming| if streaming { "stream " } else { "" };
+            let mut line = String::new();
+            write!(
+                line,
+                "rpc {}({}{}) returns ({}{})",
+                method.get_name(),
+                stream(method.get_client_streaming()),
+                method.get_input_type(),
+                stream(method.get_server_streaming()),
+                method.get_output_type()
+            )
+            .unwrap();
+            let options = self.options(&method.options);
+            if options.is_empty() {
+                line.push(';');
+                self.line(&line);
+                self.trailing_comments(&method_path);
+            } else {
+                line.push_str(" {");
+                self.line(&line);
+                self.indent += 1;
+                self.trailing_comments(&method_path);
+                for option in options {
+                    self.line(&format!("option {};", option));
+                }
+                self.indent -= 1;
+                self.line("}");
+            }
+        }
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    fn file(&mut self) {
+        let file = self.file.proto();
+
+        self.leading_comments(&[FILE_SYNTAX]);
+        match self.edition {
+            Edition::Proto2 | Edition::Proto3 => {
+                self.line(&format!("syntax = \"{}\";", self.edition.name()))
+            }
+            Edition::Edition2023 => self.line(&format!("edition = \"{}\";", self.edition.name())),
+        }
+
+        if file.has_package() {
+            self.line("");
+            self.leading_comments(&[FILE_PACKAGE]);
+            self.line(&format!("package {};", file.get_package()));
+        }
+
+        if !file.dependency.is_empty() {
+            self.line("");
+        }
+        for (i, dep) in file.dependency.iter().enumerate() {
+            self.leading_comments(&[FILE_DEPENDENCY, i as i32]);
+            let vis = if file.public_dependency.contains(&(i as i32)) {
+                "public "
+            } else if file.weak_dependency.contains(&(i as i32)) {
+                "weak "
+            } else {
+                ""
+            };
+            self.line(&format!("import {}{};", vis, quote(dep)));
+        }
+
+        if !self.options(&file.options).is_empty() {
+            self.line("");
+            self.option_statements(&file.options);
+        }
+
+        let full_name = match file.get_package() {
+            "" => String::new(),
+            package => format!(".{}", package),
+        };
+        let scope = Scope {
+            full_name,
+            path: &[],
+            messages: &file.message_type,
+            messages_field: FILE_MESSAGE_TYPE,
+            enums: &file.enum_type,
+            enums_field: FILE_ENUM_TYPE,
+            extensions: &file.extension,
+            extensions_field: FILE_EXTENSION,
+        };
+        self.line("");
+        self.scope_body(&scope, None, Vec::new());
+
+        for (i, service) in file.service.iter().enumerate() {
+            self.service(service, &path_of(&[], FILE_SERVICE, i));
+        }
+    }
+}
+
+/// Render file as `.proto` source.
+pub(crate) fn print_file(file: &FileDescriptor) -> String {
+    let proto = file.proto();
+    let locations = proto
+        .source_code_info
+        .as_ref()
+        .map(|s| {
+            s.location
+                .iter()
+                .map(|l| (&l.path[..], l))
+                .collect::<HashMap<_, _>>()
+        })
+        .unwrap_or_default();
+    let mut printer = Printer {
+        file,
+        files: file._all_files(),
+        locations,
+        edition: file.edition(),
+        out: String::new(),
+        indent: 0,
+    };
+    printer.file();
+    printer.out
+}
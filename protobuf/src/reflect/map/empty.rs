@@ -0,0 +1,47 @@
+use crate::reflect::map::ReflectMapIter;
+use crate::reflect::map::ReflectMapIterTrait;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeTypeBox;
+
+/// Empty map of given key and value types, returned for map fields
+/// of dynamic messages which were never modified.
+#[derive(Clone)]
+pub(crate) struct DynamicEmptyMap {
+    key_type: RuntimeTypeBox,
+    value_type: RuntimeTypeBox,
+}
+
+impl DynamicEmptyMap {
+    pub(crate) fn new(key_type: RuntimeTypeBox, value_type: RuntimeTypeBox) -> DynamicEmptyMap {
+        DynamicEmptyMap {
+            key_type,
+            value_type,
+        }
+    }
+
+    pub(crate) fn key_type(&self) -> RuntimeTypeBox {
+        self.key_type.clone()
+    }
+
+    pub(crate) fn value_type(&self) -> RuntimeTypeBox {
+        self.value_type.clone()
+    }
+
+    pub(crate) fn reflect_iter<'a>(&self) -> ReflectMapIter<'a> {
+        ReflectMapIter::new(self.clone())
+    }
+}
+
+impl<'a> ReflectMapIterTrait<'a> for DynamicEmptyMap {
+    fn next(&mut self) -> Option<(ReflectValueRef<'a>, ReflectValueRef<'a>)> {
+        None
+    }
+
+    fn key_type(&self) -> RuntimeTypeBox {
+        DynamicEmptyMap::key_type(self)
+    }
+
+    fn value_type(&self) -> RuntimeTypeBox {
+        DynamicEmptyMap::value_type(self)
+    }
+}
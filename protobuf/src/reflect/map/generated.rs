@@ -1,7 +1,12 @@
 use crate::reflect::map::ReflectMap;
+use crate::reflect::map::ReflectMapIterMut;
+use crate::reflect::map::ReflectMapIterMutTrait;
 use crate::reflect::map::{ReflectMapIter, ReflectMapIterTrait};
+use crate::reflect::runtime_types::RuntimeType;
 use crate::reflect::runtime_types::RuntimeTypeHashable;
+use crate::reflect::value::value_ref::ReflectValueGuard;
 use crate::reflect::ProtobufValue;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeTypeBox;
@@ -19,6 +24,12 @@ where
         ReflectMapIter::new(GeneratedMapIterImpl::<'a, K, V> { iter: self.iter() })
     }
 
+    fn reflect_iter_mut<'a>(&'a mut self) -> ReflectMapIterMut<'a> {
+        ReflectMapIterMut::new(GeneratedMapIterMutImpl::<'a, K, V> {
+            iter: self.iter_mut(),
+        })
+    }
+
     fn len(&self) -> usize {
         HashMap::len(self)
     }
@@ -31,10 +42,24 @@ where
         <K::RuntimeType as RuntimeTypeHashable>::hash_map_get(self, key).map(V::as_ref)
     }
 
-    fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox) {
-        let key: K = key.downcast().expect("wrong key type");
-        let value: V = value.downcast().expect("wrong value type");
-        self.insert(key, value);
+    fn get_mut<'a>(&'a mut self, key: ReflectValueRef) -> Option<ReflectValueGuard<'a>> {
+        let key: K = key.to_box().downcast().ok()?;
+        self.get_mut(&key).map(V::RuntimeType::as_guard)
+    }
+
+    fn try_insert(
+        &mut self,
+        key: ReflectValueBox,
+        value: ReflectValueBox,
+    ) -> Result<Option<ReflectValueBox>, ReflectTypeError> {
+        let key = K::try_from_value_box(key)?;
+        let value = V::try_from_value_box(value)?;
+        Ok(self.insert(key, value).map(V::RuntimeType::into_value_box))
+    }
+
+    fn remove(&mut self, key: ReflectValueRef) -> Option<ReflectValueBox> {
+        let key: K = key.to_box().downcast().ok()?;
+        self.remove(&key).map(V::RuntimeType::into_value_box)
     }
 
     fn clear(&mut self) {
@@ -72,3 +97,17 @@ impl<'a, K: ProtobufValue + Eq + Hash, V: ProtobufValue> ReflectMapIterTrait<'a>
         V::runtime_type_box()
     }
 }
+
+struct GeneratedMapIterMutImpl<'a, K: Eq + Hash + 'static, V: 'static> {
+    iter: hash_map::IterMut<'a, K, V>,
+}
+
+impl<'a, K: ProtobufValue + Eq + Hash, V: ProtobufValue> ReflectMapIterMutTrait<'a>
+    for GeneratedMapIterMutImpl<'a, K, V>
+{
+    fn next(&mut self) -> Option<(ReflectValueRef<'a>, ReflectValueGuard<'a>)> {
+        self.iter
+            .next()
+            .map(|(k, v)| (K::as_ref(k), V::RuntimeType::as_guard(v)))
+    }
+}
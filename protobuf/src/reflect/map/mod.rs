@@ -1,22 +1,43 @@
+use crate::reflect::map::empty::DynamicEmptyMap;
 use crate::reflect::reflect_eq::ReflectEq;
 use crate::reflect::reflect_eq::ReflectEqMode;
+use crate::reflect::value::value_ref::ReflectValueGuard;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 use crate::reflect::RuntimeTypeBox;
 
+mod empty;
 mod generated;
 
 /// Implemented for `HashMap` with appropriate keys and values
 pub(crate) trait ReflectMap: Send + Sync + 'static {
     fn reflect_iter(&self) -> ReflectMapIter;
 
+    fn reflect_iter_mut(&mut self) -> ReflectMapIterMut<'_>;
+
     fn len(&self) -> usize;
 
     fn is_empty(&self) -> bool;
 
     fn get<'a>(&'a self, key: ReflectValueRef) -> Option<ReflectValueRef<'a>>;
 
-    fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox);
+    fn get_mut<'a>(&'a mut self, key: ReflectValueRef) -> Option<ReflectValueGuard<'a>>;
+
+    fn try_insert(
+        &mut self,
+        key: ReflectValueBox,
+        value: ReflectValueBox,
+    ) -> Result<Option<ReflectValueBox>, ReflectTypeError>;
+
+    fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox) -> Option<ReflectValueBox> {
+        match self.try_insert(key, value) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn remove(&mut self, key: ReflectValueRef) -> Option<ReflectValueBox>;
 
     fn clear(&mut self);
 
@@ -49,6 +70,20 @@ impl<'a> Iterator for ReflectMapIter<'a> {
     }
 }
 
+pub(crate) trait ReflectMapIterMutTrait<'a> {
+    fn next(&mut self) -> Option<(ReflectValueRef<'a>, ReflectValueGuard<'a>)>;
+}
+
+pub(crate) struct ReflectMapIterMut<'a> {
+    imp: Box<dyn ReflectMapIterMutTrait<'a> + 'a>,
+}
+
+impl<'a> ReflectMapIterMut<'a> {
+    pub(crate) fn new<I: ReflectMapIterMutTrait<'a> + 'a>(imp: I) -> ReflectMapIterMut<'a> {
+        ReflectMapIterMut { imp: Box::new(imp) }
+    }
+}
+
 impl<'a> IntoIterator for &'a dyn ReflectMap {
     type Item = (ReflectValueRef<'a>, ReflectValueRef<'a>);
     type IntoIter = ReflectMapIter<'a>;
@@ -58,10 +93,16 @@ impl<'a> IntoIterator for &'a dyn ReflectMap {
     }
 }
 
+#[derive(Clone)]
+enum ReflectMapRefImpl<'a> {
+    Generic(&'a dyn ReflectMap),
+    DynamicEmpty(DynamicEmptyMap),
+}
+
 /// Dynamic reference to `map` field
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct ReflectMapRef<'a> {
-    map: &'a dyn ReflectMap,
+    imp: ReflectMapRefImpl<'a>,
 }
 
 /// Dynamic mutable reference to `map` field
@@ -71,32 +112,55 @@ pub struct ReflectMapMut<'a> {
 
 impl<'a> ReflectMapRef<'a> {
     pub(crate) fn new(map: &'a dyn ReflectMap) -> ReflectMapRef<'a> {
-        ReflectMapRef { map }
+        ReflectMapRef {
+            imp: ReflectMapRefImpl::Generic(map),
+        }
+    }
+
+    pub(crate) fn new_empty(key: RuntimeTypeBox, value: RuntimeTypeBox) -> ReflectMapRef<'a> {
+        ReflectMapRef {
+            imp: ReflectMapRefImpl::DynamicEmpty(DynamicEmptyMap::new(key, value)),
+        }
     }
 
     /// Size of the map
     pub fn len(&self) -> usize {
-        self.map.len()
+        match &self.imp {
+            ReflectMapRefImpl::Generic(map) => map.len(),
+            ReflectMapRefImpl::DynamicEmpty(_) => 0,
+        }
     }
 
     /// Is map empty?
     pub fn is_empty(&self) -> bool {
-        self.map.is_empty()
+        match &self.imp {
+            ReflectMapRefImpl::Generic(map) => map.is_empty(),
+            ReflectMapRefImpl::DynamicEmpty(_) => true,
+        }
     }
 
     /// Find a value by given key.
-    pub fn get(&self, key: ReflectValueRef) -> Option<ReflectValueRef> {
-        self.map.get(key)
+    pub fn get(&self, key: ReflectValueRef) -> Option<ReflectValueRef<'a>> {
+        match &self.imp {
+            ReflectMapRefImpl::Generic(map) => map.get(key),
+            ReflectMapRefImpl::DynamicEmpty(_) => None,
+        }
     }
 
     /// Map key type
     pub fn key_type(&self) -> RuntimeTypeBox {
-        self.map.key_type()
+        match &self.imp {
+            ReflectMapRefImpl::Generic(map) => map.key_type(),
+            ReflectMapRefImpl::DynamicEmpty(map) => map.key_type(),
+        }
     }
 
     /// Map value type
     pub fn value_type(&self) -> RuntimeTypeBox {
-        self.map.value_type()
+        match &self.imp {
+            ReflectMapRefImpl::Generic(map) => map.value_type(),
+            ReflectMapRefImpl::DynamicEmpty(map) => map.value_type(),
+        }
     }
 }
 
@@ -129,7 +193,7 @@ impl<'a> ReflectMapMut<'a> {
     }
 
     fn as_ref(&'a self) -> ReflectMapRef<'a> {
-        ReflectMapRef { map: self.map }
+        ReflectMapRef::new(self.map)
     }
 
     /// Map key type
@@ -157,13 +221,55 @@ impl<'a> ReflectMapMut<'a> {
         self.map.get(key)
     }
 
+    /// Get a mutable reference to a value for given key.
+    pub fn get_mut(&mut self, key: ReflectValueRef) -> Option<ReflectValueGuard<'_>> {
+        self.map.get_mut(key)
+    }
+
     /// Insert a value into the map.
     ///
     /// # Panics
     ///
-    /// If given key has an incompatible key type.
+    /// If key or value type does not match map key or value type,
+    /// or value cannot be converted to the type map values are stored as.
     pub fn insert(&mut self, key: ReflectValueBox, value: ReflectValueBox) {
-        self.map.insert(key, value)
+        self.map.insert(key, value);
+    }
+
+    /// Insert a value into the map, or return an error if key or value type
+    /// does not match map key or value type, or value cannot be converted
+    /// to the type map values are stored as.
+    pub fn try_insert(
+        &mut self,
+        key: ReflectValueBox,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
+        self.map.try_insert(key, value).map(|_| ())
+    }
+
+    /// Remove an entry with given key and return its value.
+    pub fn remove(&mut self, key: ReflectValueRef) -> Option<ReflectValueBox> {
+        self.map.remove(key)
+    }
+
+    /// Get the entry for given key for in-place access.
+    ///
+    /// # Panics
+    ///
+    /// If given key has an incompatible key type.
+    pub fn entry(&mut self, key: ReflectValueBox) -> ReflectMapEntry<'_> {
+        assert_eq!(self.key_type(), key.get_type(), "wrong key type");
+        ReflectMapEntry {
+            map: &mut *self.map,
+            key,
+        }
+    }
+
+    /// Iterate over map entries with mutable references to values.
+    pub fn iter_mut(&mut self) -> ReflectMapMutIter<'_> {
+        ReflectMapMutIter {
+            iter: self.map.reflect_iter_mut(),
+        }
     }
 
     /// Clear
@@ -172,6 +278,77 @@ impl<'a> ReflectMapMut<'a> {
     }
 }
 
+/// Entry of a map field, see [`ReflectMapMut::entry`].
+pub struct ReflectMapEntry<'a> {
+    map: &'a mut dyn ReflectMap,
+    key: ReflectValueBox,
+}
+
+impl<'a> ReflectMapEntry<'a> {
+    /// Entry key
+    pub fn key(&self) -> ReflectValueRef<'_> {
+        self.key.as_value_ref()
+    }
+
+    /// Value in the map for this key
+    pub fn get(&self) -> Option<ReflectValueRef<'_>> {
+        self.map.get(self.key.as_value_ref())
+    }
+
+    /// Insert a value for this key and return the previous value.
+    ///
+    /// # Panics
+    ///
+    /// If value type does not match map value type.
+    pub fn insert(self, value: ReflectValueBox) -> Option<ReflectValueBox> {
+        self.map.insert(self.key, value)
+    }
+
+    /// Remove this key from the map and return the value.
+    pub fn remove(self) -> Option<ReflectValueBox> {
+        self.map.remove(self.key.as_value_ref())
+    }
+
+    /// Insert a value if the key is absent, and return the value in the map.
+    ///
+    /// # Panics
+    ///
+    /// If value type does not match map value type.
+    pub fn or_insert(self, value: ReflectValueBox) -> ReflectValueRef<'a> {
+        let map = self.map;
+        if map.get(self.key.as_value_ref()).is_none() {
+            map.insert(self.key.clone(), value);
+        }
+        let map: &'a dyn ReflectMap = map;
+        map.get(self.key.as_value_ref()).unwrap()
+    }
+
+    /// Insert the default value if the key is absent, and return
+    /// a mutable reference to the value in the map.
+    pub fn or_insert_default(self) -> ReflectValueGuard<'a> {
+        let map = self.map;
+        if map.get(self.key.as_value_ref()).is_none() {
+            let value = map.value_type().default_value_ref().to_box();
+            map.insert(self.key.clone(), value);
+        }
+        map.get_mut(self.key.as_value_ref()).unwrap()
+    }
+}
+
+/// Iterator over map entries with mutable values,
+/// see [`ReflectMapMut::iter_mut`].
+pub struct ReflectMapMutIter<'a> {
+    iter: ReflectMapIterMut<'a>,
+}
+
+impl<'a> Iterator for ReflectMapMutIter<'a> {
+    type Item = (ReflectValueRef<'a>, ReflectValueGuard<'a>);
+
+    fn next(&mut self) -> Option<(ReflectValueRef<'a>, ReflectValueGuard<'a>)> {
+        self.iter.imp.next()
+    }
+}
+
 /// Iterator over map
 pub struct ReflectMapRefIter<'a> {
     iter: ReflectMapIter<'a>,
@@ -201,7 +378,10 @@ impl<'a, 'b> IntoIterator for &'b ReflectMapRef<'a> {
 
     fn into_iter(self) -> ReflectMapRefIter<'a> {
         ReflectMapRefIter {
-            iter: self.map.reflect_iter(),
+            iter: match &self.imp {
+                ReflectMapRefImpl::Generic(map) => map.reflect_iter(),
+                ReflectMapRefImpl::DynamicEmpty(map) => map.reflect_iter(),
+            },
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::descriptor::FileDescriptorProto;
+use crate::reflect::features::ResolvedFeatures;
 use crate::reflect::file::building::FileDescriptorBuilding;
 use crate::reflect::message::index::MessageIndex;
 use crate::reflect::message::path::MessagePath;
@@ -15,7 +16,8 @@ impl DynamicMessageDescriptor {
         building: &FileDescriptorBuilding,
     ) -> DynamicMessageDescriptor {
         let m = path.eval(proto).unwrap();
-        let indices = MessageIndex::index(m, building);
+        let features = ResolvedFeatures::for_messages(proto, &path.eval_path(proto));
+        let indices = MessageIndex::index(m, &features, building);
 
         DynamicMessageDescriptor { indices }
     }
@@ -4,6 +4,7 @@ use crate::descriptor::FileDescriptorProto;
 use crate::message::Message;
 use crate::message_dyn::MessageDyn;
 use crate::reflect::acc::FieldAccessor;
+use crate::reflect::features::ResolvedFeatures;
 use crate::reflect::file::building::FileDescriptorBuilding;
 use crate::reflect::file::index::FileIndex;
 use crate::reflect::find_message_or_enum::find_message_or_enum;
@@ -106,7 +107,7 @@ impl GeneratedMessageDescriptor {
     pub(crate) fn new(
         data: GeneratedMessageDescriptorData,
         file_descriptor_proto: &'static FileDescriptorProto,
-        _file_index: &FileIndex,
+        file_index: &FileIndex,
         building: &FileDescriptorBuilding,
     ) -> GeneratedMessageDescriptor {
         let GeneratedMessageDescriptorData {
@@ -127,7 +128,14 @@ impl GeneratedMessageDescriptor {
             field_proto_by_name.insert(field_proto.get_name(), field_proto);
         }
 
-        let index = MessageIndex::index(proto, building);
+        let path = &file_index.messages
+            [file_index.message_by_name_to_package[protobuf_name_to_package]]
+            .path;
+        let features = ResolvedFeatures::for_messages(
+            file_descriptor_proto,
+            &path.eval_path(file_descriptor_proto),
+        );
+        let index = MessageIndex::index(proto, &features, building);
 
         GeneratedMessageDescriptor {
             non_map: Some(NonMapMessageDescriptor {
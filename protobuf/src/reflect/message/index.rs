@@ -1,4 +1,5 @@
 use crate::descriptor::DescriptorProto;
+use crate::reflect::features::ResolvedFeatures;
 use crate::reflect::field::index::FieldIndex;
 use crate::reflect::file::building::FileDescriptorBuilding;
 use std::collections::HashMap;
@@ -12,7 +13,11 @@ pub(crate) struct MessageIndex {
 }
 
 impl MessageIndex {
-    pub fn index(proto: &DescriptorProto, building: &FileDescriptorBuilding) -> MessageIndex {
+    pub fn index(
+        proto: &DescriptorProto,
+        features: &ResolvedFeatures,
+        building: &FileDescriptorBuilding,
+    ) -> MessageIndex {
         let mut index_by_name = HashMap::new();
         let mut index_by_name_or_json_name = HashMap::new();
         let mut index_by_number = HashMap::new();
@@ -20,7 +25,7 @@ impl MessageIndex {
         let fields: Vec<FieldIndex> = proto
             .field
             .iter()
-            .map(|f| FieldIndex::index(f, building))
+            .map(|f| FieldIndex::index(f, proto, features, building))
             .collect();
         for (i, f) in proto.field.iter().enumerate() {
             let field_index = &fields[i];
@@ -0,0 +1,84 @@
+use std::fmt;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::message_dyn::MessageDyn;
+use crate::reflect::value::value_ref::WriteBack;
+use crate::reflect::ReflectTypeError;
+use crate::reflect::ReflectValueBox;
+
+enum MessageMutImpl<'a> {
+    Message(&'a mut dyn MessageDyn),
+    // Message converted from a custom type, converted back on drop
+    Copy(WriteBack<'a>),
+}
+
+/// Wrapper around mutable [`Message`](crate::Message) reference
+/// or a copy of a message stored as a custom type.
+///
+/// A message field stored as a custom type (see
+/// [`MessageConvert`](crate::MessageConvert)) is converted to a message,
+/// and the message is converted back when this object is dropped or
+/// [`finish`](MessageMut::finish)ed.
+pub struct MessageMut<'a> {
+    imp: MessageMutImpl<'a>,
+}
+
+impl<'a> MessageMut<'a> {
+    /// Wrap a message.
+    pub fn new(message: &'a mut dyn MessageDyn) -> MessageMut<'a> {
+        MessageMut {
+            imp: MessageMutImpl::Message(message),
+        }
+    }
+
+    pub(crate) fn copy(write_back: WriteBack<'a>) -> MessageMut<'a> {
+        MessageMut {
+            imp: MessageMutImpl::Copy(write_back),
+        }
+    }
+
+    /// Convert the message back if it is a copy,
+    /// and return conversion error if any.
+    ///
+    /// Dropping this object also converts the message back, but the error
+    /// is ignored, and the field keeps its previous value.
+    pub fn finish(self) -> Result<(), ReflectTypeError> {
+        match self.imp {
+            MessageMutImpl::Message(..) => Ok(()),
+            MessageMutImpl::Copy(c) => c.finish(),
+        }
+    }
+}
+
+impl<'a> Deref for MessageMut<'a> {
+    type Target = dyn MessageDyn;
+
+    fn deref(&self) -> &dyn MessageDyn {
+        match &self.imp {
+            MessageMutImpl::Message(m) => &**m,
+            MessageMutImpl::Copy(c) => match &c.value {
+                ReflectValueBox::Message(m) => &**m,
+                _ => unreachable!(),
+            },
+        }
+    }
+}
+
+impl<'a> DerefMut for MessageMut<'a> {
+    fn deref_mut(&mut self) -> &mut dyn MessageDyn {
+        match &mut self.imp {
+            MessageMutImpl::Message(m) => &mut **m,
+            MessageMutImpl::Copy(c) => match &mut c.value {
+                ReflectValueBox::Message(m) => &mut **m,
+                _ => unreachable!(),
+            },
+        }
+    }
+}
+
+impl<'a> fmt::Debug for MessageMut<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
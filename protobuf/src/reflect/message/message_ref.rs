@@ -5,10 +5,24 @@ use crate::reflect::MessageDescriptor;
 use crate::Message;
 use std::ops::Deref;
 
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 enum MessageRefImpl<'a> {
     Message(&'a dyn MessageDyn),
     EmptyDynamic(DynamicMessage),
+    // Message converted from a custom type
+    Owned(Box<dyn MessageDyn>),
+}
+
+impl<'a> Clone for MessageRefImpl<'a> {
+    fn clone(&self) -> Self {
+        match self {
+            MessageRefImpl::Message(m) => MessageRefImpl::Message(*m),
+            MessageRefImpl::EmptyDynamic(e) => MessageRefImpl::EmptyDynamic(e.clone()),
+            MessageRefImpl::Owned(m) => {
+                MessageRefImpl::Owned(m.descriptor_dyn().clone_message(&**m))
+            }
+        }
+    }
 }
 
 /// Wrapper around either [`Message`] reference or a container for an empty dynamic message.
@@ -49,6 +63,23 @@ impl<'a> MessageRef<'a> {
         }
     }
 
+    /// Wrap a message which is not stored anywhere,
+    /// e. g. a message converted from a custom type.
+    pub(crate) fn new_owned(message: Box<dyn MessageDyn>) -> MessageRef<'static> {
+        MessageRef {
+            imp: MessageRefImpl::Owned(message),
+        }
+    }
+
+    /// Message reference with the lifetime of the referenced message,
+    /// or `None` if the message is owned by this object.
+    pub(crate) fn as_borrowed(&self) -> Option<&'a dyn MessageDyn> {
+        match &self.imp {
+            MessageRefImpl::Message(m) => Some(*m),
+            MessageRefImpl::EmptyDynamic(..) | MessageRefImpl::Owned(..) => None,
+        }
+    }
+
     /// Default (empty) instance of given message type.
     pub fn default_instance(message: &MessageDescriptor) -> MessageRef<'static> {
         // Note we create a native generated instance for generated types
@@ -69,6 +100,7 @@ impl<'a> Deref for MessageRef<'a> {
         match &self.imp {
             MessageRefImpl::Message(m) => *m,
             MessageRefImpl::EmptyDynamic(e) => e,
+            MessageRefImpl::Owned(m) => &**m,
         }
     }
 }
@@ -5,21 +5,29 @@ use crate::message::Message;
 use crate::descriptor::DescriptorProto;
 use crate::descriptor::FileDescriptorProto;
 
+use crate::descriptor::MessageOptions;
+use crate::ext::ExtFieldOptional;
+use crate::ext::ExtFieldRepeated;
 use crate::message_dyn::MessageDyn;
 use crate::reflect::dynamic::DynamicMessage;
+use crate::reflect::features::ResolvedFeatures;
 use crate::reflect::file::index::FileIndexMessageEntry;
 use crate::reflect::file::FileDescriptorImpl;
 use crate::reflect::message::dynamic::DynamicMessageDescriptor;
 use crate::reflect::message::generated::GeneratedMessageDescriptor;
 use crate::reflect::message::index::MessageIndex;
+use crate::reflect::options;
 use crate::reflect::reflect_eq::ReflectEq;
 use crate::reflect::reflect_eq::ReflectEqMode;
+use crate::reflect::types::ProtobufType;
 use crate::reflect::FileDescriptor;
+use crate::reflect::ReflectValueBox;
 use crate::reflect::{EnumDescriptor, FieldDescriptor, OneofDescriptor};
 
 pub(crate) mod dynamic;
 pub(crate) mod generated;
 pub(crate) mod index;
+pub(crate) mod message_mut;
 pub(crate) mod message_ref;
 pub(crate) mod path;
 
@@ -132,7 +140,62 @@ impl MessageDescriptor {
         self.get_proto().options.get_or_default().get_map_entry()
     }
 
-    fn assert_not_map_entry(&self) {
+    /// Features of this message, inherited from file and containing messages.
+    pub fn features(&self) -> ResolvedFeatures {
+        let file = self.file_descriptor_proto();
+        ResolvedFeatures::for_messages(file, &self.get_index_entry().path.eval_path(file))
+    }
+
+    /// Options of this message, default instance if not specified in `.proto` file.
+    pub fn options(&self) -> &MessageOptions {
+        self.get_proto().options.get_or_default()
+    }
+
+    /// Get custom option of this message by extension generated by `protoc`.
+    pub fn get_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldOptional<MessageOptions, T>,
+    ) -> Option<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get custom option of this message by extension fully-qualified name,
+    /// e. g. `acme.sensitive`.
+    ///
+    /// Extension is looked up in the file of this message and its dependencies.
+    /// Returns `None` if option is not set, extension is not found
+    /// or extension is repeated.
+    pub fn get_option_by_name(&self, name: &str) -> Option<ReflectValueBox> {
+        options::custom_option(
+            self.file_descriptor(),
+            ".google.protobuf.MessageOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    /// Get repeated custom option of this message by extension generated by `protoc`.
+    pub fn get_repeated_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldRepeated<MessageOptions, T>,
+    ) -> Vec<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get repeated custom option of this message by extension fully-qualified name.
+    ///
+    /// Returns empty vec if option is not set, extension is not found
+    /// or extension is not repeated.
+    pub fn get_repeated_option_by_name(&self, name: &str) -> Vec<ReflectValueBox> {
+        options::custom_repeated_option(
+            self.file_descriptor(),
+            ".google.protobuf.MessageOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    pub(crate) fn assert_not_map_entry(&self) {
         assert!(
             !self.is_map_entry(),
             "message is map entry: {}",
@@ -184,11 +247,13 @@ impl MessageDescriptor {
     ///
     /// # Panics
     ///
-    /// Is any message has different type than this descriptor.
+    /// If any message has different type than this descriptor.
     pub fn eq(&self, a: &dyn MessageDyn, b: &dyn MessageDyn) -> bool {
         match self.get_impl() {
             MessageDescriptorImplRef::Generated(g) => g.non_map().factory.eq(a, b),
-            MessageDescriptorImplRef::Dynamic(..) => unimplemented!(),
+            MessageDescriptorImplRef::Dynamic(..) => {
+                DynamicMessage::downcast_ref(a) == DynamicMessage::downcast_ref(b)
+            }
         }
     }
 
@@ -196,7 +261,7 @@ impl MessageDescriptor {
     ///
     /// # Panics
     ///
-    /// Is any message has different type than this descriptor.
+    /// If any message has different type than this descriptor.
     pub(crate) fn reflect_eq(
         &self,
         a: &dyn MessageDyn,
@@ -227,6 +292,28 @@ impl MessageDescriptor {
         ad == bd && ad.reflect_eq(a, b, mode)
     }
 
+    /// Merge `source` into `target` like parsing serialized `source` would:
+    /// set singular fields overwrite, repeated fields are appended,
+    /// map entries are overwritten by key, set message fields are merged
+    /// recursively, set oneof fields switch the oneof, and unknown fields
+    /// are appended.
+    ///
+    /// # Panics
+    ///
+    /// If any message has different type than this descriptor.
+    pub(crate) fn merge(&self, target: &mut dyn MessageDyn, source: &dyn MessageDyn) {
+        assert_eq!(self, &target.descriptor_dyn());
+        assert_eq!(self, &source.descriptor_dyn());
+
+        for field in self.fields() {
+            field.merge_field(target, source);
+        }
+
+        target
+            .mut_unknown_fields_dyn()
+            .merge_from(source.get_unknown_fields_dyn());
+    }
+
     /// Message name as given in `.proto` file
     pub fn name(&self) -> &str {
         self.get_proto().get_name()
@@ -245,7 +332,7 @@ impl MessageDescriptor {
         })
     }
 
-    /// Nested oneofs
+    /// Nested oneofs, including [synthetic](OneofDescriptor::is_synthetic) oneofs
     pub fn oneofs<'a>(&'a self) -> impl ExactSizeIterator<Item = OneofDescriptor> + 'a {
         self.get_proto()
             .oneof_decl
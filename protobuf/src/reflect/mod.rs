@@ -9,13 +9,21 @@ mod find_message_or_enum;
 mod map;
 pub(crate) mod message;
 mod oneof;
+mod options;
 mod repeated;
 mod runtime_type_box;
+mod type_error;
 mod type_dynamic;
 pub(crate) mod value;
 
+pub mod diff;
+pub mod features;
+pub mod field_path;
+#[cfg(feature = "with-arbitrary")]
+pub mod random;
 pub mod runtime_types;
 pub mod types;
+pub mod visit;
 
 pub(crate) mod reflect_eq;
 
@@ -24,12 +32,16 @@ pub mod rt;
 pub(crate) mod name;
 
 pub use self::value::value_box::ReflectValueBox;
+pub use self::value::value_ref::ReflectValueGuard;
 pub use self::value::value_ref::ReflectValueRef;
 pub use self::value::ProtobufValue;
 
 pub use self::repeated::ReflectRepeatedMut;
 pub use self::repeated::ReflectRepeatedRef;
 
+pub use self::dynamic::DynamicFieldError;
+pub use self::dynamic::DynamicMessage;
+
 pub use self::map::ReflectMapMut;
 pub use self::map::ReflectMapRef;
 
@@ -40,6 +52,7 @@ pub use self::enums::EnumValueDescriptor;
 
 #[doc(hidden)]
 pub use self::message::generated::GeneratedMessageDescriptorData;
+pub use self::message::message_mut::MessageMut;
 pub use self::message::message_ref::MessageRef;
 pub use self::message::MessageDescriptor;
 
@@ -55,5 +68,7 @@ pub use self::file::FileDescriptor;
 
 pub use self::runtime_type_box::RuntimeTypeBox;
 
+pub use self::type_error::ReflectTypeError;
+
 pub use self::reflect_eq::ReflectEq;
 pub use self::reflect_eq::ReflectEqMode;
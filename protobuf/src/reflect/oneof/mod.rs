@@ -1,6 +1,16 @@
 use crate::descriptor::OneofDescriptorProto;
+use crate::descriptor::OneofOptions;
+use crate::ext::ExtFieldOptional;
+use crate::ext::ExtFieldRepeated;
+use crate::message::Message;
+use crate::message_dyn::MessageDyn;
+use crate::reflect::features::OptionsWithFeatures;
+use crate::reflect::features::ResolvedFeatures;
+use crate::reflect::options;
+use crate::reflect::types::ProtobufType;
 use crate::reflect::FieldDescriptor;
 use crate::reflect::MessageDescriptor;
+use crate::reflect::ReflectValueBox;
 
 /// Oneof descriptor.
 #[derive(Eq, PartialEq, Clone)]
@@ -20,10 +30,179 @@ impl OneofDescriptor {
         self.get_proto().get_name()
     }
 
+    /// Features of this oneof, inherited from containing message.
+    pub fn features(&self) -> ResolvedFeatures {
+        self.message_descriptor
+            .features()
+            .merge(&self.get_proto().options.get_or_default().features())
+    }
+
+    /// Options of this oneof, default instance if not specified in `.proto` file.
+    pub fn options(&self) -> &OneofOptions {
+        self.get_proto().options.get_or_default()
+    }
+
+    /// Get custom option of this oneof by extension generated by `protoc`.
+    pub fn get_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldOptional<OneofOptions, T>,
+    ) -> Option<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get custom option of this oneof by extension fully-qualified name,
+    /// e. g. `acme.sensitive`.
+    ///
+    /// Extension is looked up in the file of this oneof and its dependencies.
+    /// Returns `None` if option is not set, extension is not found
+    /// or extension is repeated.
+    pub fn get_option_by_name(&self, name: &str) -> Option<ReflectValueBox> {
+        options::custom_option(
+            self.message_descriptor.file_descriptor(),
+            ".google.protobuf.OneofOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
+    /// Get repeated custom option of this oneof by extension generated by `protoc`.
+    pub fn get_repeated_option<T: ProtobufType>(
+        &self,
+        ext: &ExtFieldRepeated<OneofOptions, T>,
+    ) -> Vec<T::ProtobufValue> {
+        ext.get(self.options())
+    }
+
+    /// Get repeated custom option of this oneof by extension fully-qualified name.
+    ///
+    /// Returns empty vec if option is not set, extension is not found
+    /// or extension is not repeated.
+    pub fn get_repeated_option_by_name(&self, name: &str) -> Vec<ReflectValueBox> {
+        options::custom_repeated_option(
+            self.message_descriptor.file_descriptor(),
+            ".google.protobuf.OneofOptions",
+            self.options().get_unknown_fields(),
+            name,
+        )
+    }
+
     /// Fields in this oneof.
     pub fn fields<'a>(&'a self) -> impl Iterator<Item = FieldDescriptor> + 'a {
         self.message_descriptor
             .fields()
             .filter(move |f| f.containing_oneof().as_ref() == Some(self))
     }
+
+    /// Oneof is synthetic: generated by `protoc` for a proto3 `optional` field.
+    ///
+    /// Synthetic oneof contains exactly one field, and is not
+    /// a oneof in `.proto` file.
+    pub fn is_synthetic(&self) -> bool {
+        let mut fields = self.fields();
+        match (fields.next(), fields.next()) {
+            (Some(f), None) => f.get_proto().get_proto3_optional(),
+            _ => false,
+        }
+    }
+
+    /// Field of this oneof which is set in the message.
+    ///
+    /// # Panics
+    ///
+    /// If this oneof belongs to a different message type.
+    pub fn active_field(&self, m: &dyn MessageDyn) -> Option<FieldDescriptor> {
+        assert_eq!(self.message_descriptor, m.descriptor_dyn());
+        self.fields().find(|f| f.has_field(m))
+    }
+
+    /// Unset the field of this oneof which is set in the message.
+    ///
+    /// # Panics
+    ///
+    /// If this oneof belongs to a different message type.
+    pub fn clear(&self, m: &mut dyn MessageDyn) {
+        if let Some(field) = self.active_field(m) {
+            field.clear_field(m);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::descriptor::field_descriptor_proto::Label;
+    use crate::descriptor::field_descriptor_proto::Type;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::descriptor::OneofDescriptorProto;
+    use crate::reflect::FileDescriptor;
+    use crate::reflect::ReflectValueBox;
+    use crate::well_known_types::Value;
+    use crate::Message;
+    use crate::MessageDyn;
+
+    #[test]
+    fn active_field() {
+        let file = FileDescriptor::new_dynamic(
+            Value::descriptor_static().file_descriptor_proto().clone(),
+            Vec::new(),
+        );
+        let dynamic = file.message_by_package_relative_name("Value").unwrap();
+        for mut m in vec![
+            Box::new(Value::new()) as Box<dyn MessageDyn>,
+            dynamic.new_instance(),
+        ] {
+            let d = m.descriptor_dyn();
+            let kind = d.oneofs().next().unwrap();
+            assert!(!kind.is_synthetic());
+            assert!(kind.active_field(&*m).is_none());
+
+            let number_value = d.get_field_by_name("number_value").unwrap();
+            let bool_value = d.get_field_by_name("bool_value").unwrap();
+            assert!(Some(kind.clone()) == bool_value.containing_real_oneof());
+            number_value.set_singular_field(&mut *m, ReflectValueBox::F64(1.0));
+            assert!(Some(number_value.clone()) == kind.active_field(&*m));
+            bool_value.set_singular_field(&mut *m, ReflectValueBox::Bool(false));
+            assert!(Some(bool_value) == kind.active_field(&*m));
+            assert!(!number_value.has_field(&*m));
+
+            kind.clear(&mut *m);
+            assert!(kind.active_field(&*m).is_none());
+        }
+    }
+
+    #[test]
+    fn synthetic() {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("a".to_owned());
+        field.set_number(1);
+        field.set_label(Label::LABEL_OPTIONAL);
+        field.set_field_type(Type::TYPE_INT32);
+        field.set_oneof_index(0);
+        field.set_proto3_optional(true);
+        let mut oneof = OneofDescriptorProto::new();
+        oneof.set_name("_a".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        message.field.push(field);
+        message.oneof_decl.push(oneof);
+        let mut proto = FileDescriptorProto::new();
+        proto.set_name("m.proto".to_owned());
+        proto.set_syntax("proto3".to_owned());
+        proto.message_type.push(message);
+
+        let file = FileDescriptor::new_dynamic(proto, Vec::new());
+        let d = file.message_by_package_relative_name("M").unwrap();
+        let oneof = d.oneofs().next().unwrap();
+        let a = d.get_field_by_name("a").unwrap();
+        assert!(oneof.is_synthetic());
+        assert!(Some(oneof.clone()) == a.containing_oneof());
+        assert!(a.containing_real_oneof().is_none());
+
+        let mut m = d.new_instance();
+        a.set_singular_field(&mut *m, ReflectValueBox::I32(0));
+        assert!(Some(a.clone()) == oneof.active_field(&*m));
+        oneof.clear(&mut *m);
+        assert!(!a.has_field(&*m));
+    }
 }
@@ -0,0 +1,551 @@
+//! Custom options lookup by extension name.
+
+use std::collections::HashMap;
+use std::iter;
+
+use crate::descriptor::field_descriptor_proto::Type;
+use crate::descriptor::FieldDescriptorProto;
+use crate::reflect::dynamic::wire;
+use crate::reflect::field::index::ForwardRuntimeFieldType;
+use crate::reflect::file::building::FileDescriptorBuilding;
+use crate::reflect::name::concat_paths;
+use crate::reflect::FileDescriptor;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::unknown::UnknownFields;
+use crate::wire_format::WireType;
+use crate::CodedInputStream;
+use crate::CodedOutputStream;
+use crate::ProtobufResult;
+
+/// Extensions defined in a file and its dependencies,
+/// built once when the file descriptor is created.
+#[derive(Debug)]
+pub(crate) struct ExtensionIndex {
+    by_full_name: HashMap<String, ExtensionIndexEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct ExtensionIndexEntry {
+    /// File where the extension is defined, `None` for the indexed file.
+    file: Option<FileDescriptor>,
+    /// Index of the message the extension is nested in.
+    message: Option<usize>,
+    /// Index of the extension in the file or in the message.
+    index: usize,
+    field_type: ForwardRuntimeFieldType,
+}
+
+impl ExtensionIndex {
+    pub(crate) fn index(
+        building: &FileDescriptorBuilding,
+        dependencies: &[FileDescriptor],
+    ) -> ExtensionIndex {
+        let mut by_full_name = HashMap::new();
+        for dep in dependencies {
+            for (name, entry) in &dep.extension_index().by_full_name {
+                let mut entry = entry.clone();
+                if entry.file.is_none() {
+                    entry.file = Some(dep.clone());
+                }
+                by_full_name.insert(name.clone(), entry);
+            }
+        }
+
+        let file = building.current_file_descriptor;
+        let scopes = iter::once((None, file.get_package(), &file.extension)).chain(
+            building
+                .current_file_index
+                .messages
+                .iter()
+                .enumerate()
+                .map(|(i, m)| {
+                    let proto = m.path.eval(file).unwrap();
+                    (Some(i), m.full_name.as_str(), &proto.extension)
+                }),
+        );
+        for (message, scope, extensions) in scopes {
+            for (index, extension) in extensions.iter().enumerate() {
+                let name = format!(".{}", concat_paths(scope, extension.get_name()));
+                let entry = ExtensionIndexEntry {
+                    file: None,
+                    message,
+                    index,
+                    field_type: building.resolve_field_type(extension),
+                };
+                by_full_name.insert(name, entry);
+            }
+        }
+
+        ExtensionIndex { by_full_name }
+    }
+}
+
+/// Find extension of `extendee` by fully-qualified name
+/// in `file` and its dependencies.
+fn find_extension<'a>(
+    file: &'a FileDescriptor,
+    extendee: &str,
+    name: &str,
+) -> Option<(&'a FieldDescriptorProto, RuntimeFieldType)> {
+    let entry = if name.starts_with('.') {
+        file.extension_index().by_full_name.get(name)?
+    } else {
+        file.extension_index()
+            .by_full_name
+            .get(&format!(".{}", name))?
+    };
+    let defined_in = entry.file.as_ref().unwrap_or(file);
+    let extensions = match entry.message {
+        Some(message) => &defined_in.message_proto(message).extension,
+        None => &defined_in.proto().extension,
+    };
+    let extension = &extensions[entry.index];
+    if extension.get_extendee() != extendee {
+        return None;
+    }
+    Some((extension, entry.field_type.resolve_in_file(defined_in)))
+}
+
+/// Serialize unknown values of given field number.
+fn unknown_values_to_bytes(
+    unknown_fields: &UnknownFields,
+    number: u32,
+) -> ProtobufResult<Option<Vec<u8>>> {
+    let values = match unknown_fields.get(number) {
+        Some(values) => values,
+        None => return Ok(None),
+    };
+
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        for value in values {
+            os.write_unknown(number, value)?;
+        }
+        os.flush()?;
+    }
+    Ok(Some(bytes))
+}
+
+/// Read a value of given type, groups are read up to the end group tag.
+fn read_value(
+    is: &mut CodedInputStream,
+    number: u32,
+    t: Type,
+    runtime_type: &RuntimeTypeBox,
+) -> ProtobufResult<ReflectValueBox> {
+    match (t, runtime_type) {
+        (Type::TYPE_GROUP, RuntimeTypeBox::Message(m)) => {
+            let mut message = m.new_instance();
+            wire::merge_group(is, number, &mut *message)?;
+            Ok(ReflectValueBox::Message(message))
+        }
+        _ => wire::read_value(is, t, runtime_type),
+    }
+}
+
+/// Decode singular extension value from unknown fields.
+///
+/// Last value wins, message values are merged.
+fn read_singular(
+    unknown_fields: &UnknownFields,
+    number: u32,
+    t: Type,
+    runtime_type: &RuntimeTypeBox,
+) -> ProtobufResult<Option<ReflectValueBox>> {
+    let bytes = match unknown_values_to_bytes(unknown_fields, number)? {
+        Some(bytes) => bytes,
+        None => return Ok(None),
+    };
+
+    let mut result = None;
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    while !is.eof()? {
+        let (_, wire_type) = is.read_tag_unpack()?;
+        if wire_type == WireType::WireTypeLengthDelimited && wire::is_packable(t) {
+            let len = is.read_raw_varint64()?;
+            let old_limit = is.push_limit(len)?;
+            while !is.eof()? {
+                result = Some(wire::read_value(&mut is, t, runtime_type)?);
+            }
+            is.pop_limit(old_limit);
+        } else if wire_type != wire::wire_type(t) {
+            return Ok(None);
+        } else {
+            match &mut result {
+                Some(ReflectValueBox::Message(m)) if t == Type::TYPE_GROUP => {
+                    wire::merge_group(&mut is, number, &mut **m)?
+                }
+                Some(ReflectValueBox::Message(m)) => wire::merge_message(&mut is, &mut **m)?,
+                _ => result = Some(read_value(&mut is, number, t, runtime_type)?),
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Decode repeated extension values from unknown fields.
+///
+/// Both packed and non-packed values are accepted.
+fn read_repeated(
+    unknown_fields: &UnknownFields,
+    number: u32,
+    t: Type,
+    runtime_type: &RuntimeTypeBox,
+) -> ProtobufResult<Vec<ReflectValueBox>> {
+    let bytes = match unknown_values_to_bytes(unknown_fields, number)? {
+        Some(bytes) => bytes,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut result = Vec::new();
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    while !is.eof()? {
+        let (_, wire_type) = is.read_tag_unpack()?;
+        if wire_type == WireType::WireTypeLengthDelimited && wire::is_packable(t) {
+            let len = is.read_raw_varint64()?;
+            let old_limit = is.push_limit(len)?;
+            while !is.eof()? {
+                result.push(wire::read_value(&mut is, t, runtime_type)?);
+            }
+            is.pop_limit(old_limit);
+        } else if wire_type != wire::wire_type(t) {
+            return Ok(Vec::new());
+        } else {
+            result.push(read_value(&mut is, number, t, runtime_type)?);
+        }
+    }
+    Ok(result)
+}
+
+/// Find custom option of options message `extendee` (e. g.
+/// `.google.protobuf.FieldOptions`) by extension name.
+///
+/// Extension is searched in `file` and its dependencies.
+/// `None` is returned if extension is not found, it is repeated,
+/// option is not set or its value cannot be decoded.
+pub(crate) fn custom_option(
+    file: &FileDescriptor,
+    extendee: &str,
+    unknown_fields: &UnknownFields,
+    name: &str,
+) -> Option<ReflectValueBox> {
+    let (ext, field_type) = find_extension(file, extendee, name)?;
+    let runtime_type = match field_type {
+        RuntimeFieldType::Singular(t) => t,
+        _ => return None,
+    };
+    read_singular(
+        unknown_fields,
+        ext.get_number() as u32,
+        ext.get_field_type(),
+        &runtime_type,
+    )
+    .ok()
+    .flatten()
+}
+
+/// Find repeated custom option of options message `extendee` by extension name.
+///
+/// Empty vec is returned if extension is not found, it is not repeated,
+/// option is not set or its values cannot be decoded.
+pub(crate) fn custom_repeated_option(
+    file: &FileDescriptor,
+    extendee: &str,
+    unknown_fields: &UnknownFields,
+    name: &str,
+) -> Vec<ReflectValueBox> {
+    let (ext, runtime_type) = match find_extension(file, extendee, name) {
+        Some((ext, RuntimeFieldType::Repeated(t))) => (ext, t),
+        _ => return Vec::new(),
+    };
+    read_repeated(
+        unknown_fields,
+        ext.get_number() as u32,
+        ext.get_field_type(),
+        &runtime_type,
+    )
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use std::marker::PhantomData;
+
+    use crate::descriptor::field_descriptor_proto::Type;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::descriptor::FieldOptions;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::ext::ExtFieldRepeated;
+    use crate::reflect::types::ProtobufTypeInt32;
+    use crate::reflect::types::ProtobufTypeString;
+    use crate::reflect::FileDescriptor;
+    use crate::reflect::ReflectValueBox;
+    use crate::rustproto;
+    use crate::text_format;
+    use crate::Message;
+    use crate::UnknownFields;
+
+    const ACME: &str = r#"
+        name: "acme.proto"
+        package: "acme"
+        message_type {
+            name: "Route"
+            field { name: "path" number: 1 label: LABEL_OPTIONAL type: TYPE_STRING }
+            field { name: "method" number: 2 label: LABEL_OPTIONAL type: TYPE_STRING }
+        }
+        message_type {
+            name: "Account"
+            field { name: "id" number: 1 label: LABEL_OPTIONAL type: TYPE_INT64 }
+            field { name: "password" number: 2 label: LABEL_OPTIONAL type: TYPE_STRING }
+            extension {
+                name: "weight" number: 50004 label: LABEL_OPTIONAL type: TYPE_INT32
+                extendee: ".google.protobuf.FieldOptions"
+            }
+        }
+        message_type {
+            name: "Quota"
+            field { name: "limit" number: 1 label: LABEL_OPTIONAL type: TYPE_INT32 }
+        }
+        enum_type {
+            name: "Level"
+            value { name: "PUBLIC" number: 0 }
+            value { name: "SECRET" number: 2 }
+        }
+        extension {
+            name: "sensitive" number: 50000 label: LABEL_OPTIONAL type: TYPE_BOOL
+            extendee: ".google.protobuf.FieldOptions"
+        }
+        extension {
+            name: "level" number: 50001 label: LABEL_OPTIONAL type: TYPE_ENUM
+            type_name: ".acme.Level" extendee: ".google.protobuf.FieldOptions"
+        }
+        extension {
+            name: "route" number: 50002 label: LABEL_OPTIONAL type: TYPE_MESSAGE
+            type_name: ".acme.Route" extendee: ".google.protobuf.MessageOptions"
+        }
+        extension {
+            name: "tags" number: 50003 label: LABEL_REPEATED type: TYPE_STRING
+            extendee: ".google.protobuf.FieldOptions"
+        }
+        extension {
+            name: "limits" number: 50005 label: LABEL_REPEATED type: TYPE_INT32
+            extendee: ".google.protobuf.FieldOptions"
+        }
+        extension {
+            name: "quota" number: 50006 label: LABEL_OPTIONAL type: TYPE_GROUP
+            type_name: ".acme.Quota" extendee: ".google.protobuf.FieldOptions"
+        }
+    "#;
+
+    fn acme() -> FileDescriptor {
+        let mut proto: FileDescriptorProto = text_format::parse_from_str(ACME).unwrap();
+        let account = &mut proto.message_type[1];
+        let password = account.field[1].options.mut_or_default();
+        password.mut_unknown_fields().add_varint(50000, 1);
+        password.mut_unknown_fields().add_varint(50001, 2);
+        password.mut_unknown_fields().add_varint(50004, 3);
+        password.mut_unknown_fields().add_varint(50004, 7);
+        password
+            .mut_unknown_fields()
+            .add_length_delimited(50003, b"x".to_vec());
+        password
+            .mut_unknown_fields()
+            .add_length_delimited(50003, b"y".to_vec());
+        password.mut_unknown_fields().add_varint(50005, 1);
+        password
+            .mut_unknown_fields()
+            .add_length_delimited(50005, b"\x02\x03".to_vec());
+        let options = account.options.mut_or_default();
+        options
+            .mut_unknown_fields()
+            .add_length_delimited(50002, b"\x0a\x02/a".to_vec());
+        options
+            .mut_unknown_fields()
+            .add_length_delimited(50002, b"\x12\x03GET".to_vec());
+        proto
+            .options
+            .mut_or_default()
+            .mut_unknown_fields()
+            .add_varint(17001, 1);
+        FileDescriptor::new_dynamic(proto, Vec::new())
+    }
+
+    #[test]
+    fn by_name() {
+        let file = acme();
+        let account = file.message_by_package_relative_name("Account").unwrap();
+        let id = account.get_field_by_name("id").unwrap();
+        let password = account.get_field_by_name("password").unwrap();
+
+        assert_eq!(
+            Some(ReflectValueBox::Bool(true)),
+            password.get_option_by_name("acme.sensitive")
+        );
+        assert_eq!(
+            Some(ReflectValueBox::Bool(true)),
+            password.get_option_by_name(".acme.sensitive")
+        );
+        assert_eq!(None, id.get_option_by_name("acme.sensitive"));
+
+        match password.get_option_by_name("acme.level") {
+            Some(ReflectValueBox::Enum(e, 2)) => assert_eq!("Level", e.get_name()),
+            r => panic!("{:?}", r),
+        }
+
+        // Last value wins
+        assert_eq!(
+            Some(ReflectValueBox::I32(7)),
+            password.get_option_by_name("acme.Account.weight")
+        );
+        // Repeated extension
+        assert_eq!(None, password.get_option_by_name("acme.tags"));
+        // Unknown extension or wrong extendee
+        assert_eq!(None, password.get_option_by_name("acme.unknown"));
+        assert_eq!(None, account.get_option_by_name("acme.sensitive"));
+    }
+
+    #[test]
+    fn repeated() {
+        let file = acme();
+        let account = file.message_by_package_relative_name("Account").unwrap();
+        let id = account.get_field_by_name("id").unwrap();
+        let password = account.get_field_by_name("password").unwrap();
+
+        assert_eq!(
+            vec![
+                ReflectValueBox::String("x".to_owned()),
+                ReflectValueBox::String("y".to_owned()),
+            ],
+            password.get_repeated_option_by_name("acme.tags")
+        );
+        assert_eq!(
+            vec![
+                ReflectValueBox::I32(1),
+                ReflectValueBox::I32(2),
+                ReflectValueBox::I32(3),
+            ],
+            password.get_repeated_option_by_name("acme.limits")
+        );
+        assert!(id.get_repeated_option_by_name("acme.tags").is_empty());
+        // Singular extension
+        assert!(password
+            .get_repeated_option_by_name("acme.sensitive")
+            .is_empty());
+
+        let tags: ExtFieldRepeated<FieldOptions, ProtobufTypeString> = ExtFieldRepeated {
+            field_number: 50003,
+            phantom: PhantomData,
+        };
+        assert_eq!(vec!["x", "y"], password.get_repeated_option(&tags));
+        let limits: ExtFieldRepeated<FieldOptions, ProtobufTypeInt32> = ExtFieldRepeated {
+            field_number: 50005,
+            phantom: PhantomData,
+        };
+        assert_eq!(vec![1, 2, 3], password.get_repeated_option(&limits));
+    }
+
+    #[test]
+    fn dependency() {
+        let acme = acme();
+        let mut proto = FileDescriptorProto::new();
+        proto.set_name("app.proto".to_owned());
+        proto.dependency.push("acme.proto".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("User".to_owned());
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("secret".to_owned());
+        field.set_number(1);
+        field.set_field_type(Type::TYPE_STRING);
+        field
+            .options
+            .mut_or_default()
+            .mut_unknown_fields()
+            .add_varint(50000, 1);
+        message.field.push(field);
+        proto.message_type.push(message);
+        let file = FileDescriptor::new_dynamic(proto, vec![acme]);
+
+        let user = file.message_by_package_relative_name("User").unwrap();
+        let secret = user.get_field_by_name("secret").unwrap();
+        assert_eq!(
+            Some(ReflectValueBox::Bool(true)),
+            secret.get_option_by_name("acme.sensitive")
+        );
+        assert_eq!(None, secret.get_option_by_name("acme.level"));
+    }
+
+    #[test]
+    fn message_merged() {
+        let file = acme();
+        let account = file.message_by_package_relative_name("Account").unwrap();
+        let route = match account.get_option_by_name("acme.route") {
+            Some(ReflectValueBox::Message(m)) => m,
+            r => panic!("{:?}", r),
+        };
+        let d = route.descriptor_dyn();
+        assert_eq!(
+            "/a",
+            d.get_field_by_name("path")
+                .unwrap()
+                .get_singular_field_or_default(&*route)
+                .to_str()
+                .unwrap()
+        );
+        assert_eq!(
+            "GET",
+            d.get_field_by_name("method")
+                .unwrap()
+                .get_singular_field_or_default(&*route)
+                .to_str()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn group_round_trip() {
+        let mut proto: FileDescriptorProto = text_format::parse_from_str(ACME).unwrap();
+        let mut quota = UnknownFields::new();
+        quota.add_varint(1, 10);
+        proto.message_type[1].field[1]
+            .options
+            .mut_or_default()
+            .mut_unknown_fields()
+            .add_group(50006, quota);
+        let bytes = proto.write_to_bytes().unwrap();
+        let proto = FileDescriptorProto::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(bytes, proto.write_to_bytes().unwrap());
+
+        let file = FileDescriptor::new_dynamic(proto, Vec::new());
+        let account = file.message_by_package_relative_name("Account").unwrap();
+        let password = account.get_field_by_name("password").unwrap();
+        let quota = match password.get_option_by_name("acme.quota") {
+            Some(ReflectValueBox::Message(m)) => m,
+            r => panic!("{:?}", r),
+        };
+        assert_eq!(
+            Some(10),
+            quota
+                .descriptor_dyn()
+                .get_field_by_name("limit")
+                .unwrap()
+                .get_singular_field_or_default(&*quota)
+                .to_i32()
+        );
+    }
+
+    #[test]
+    fn generated_extension() {
+        let file = acme();
+        assert_eq!(
+            Some(true),
+            file.get_option(&rustproto::exts::expose_oneof_all)
+        );
+        assert_eq!(None, file.get_option(&rustproto::exts::serde_derive_all));
+        let account = file.message_by_package_relative_name("Account").unwrap();
+        assert_eq!(None, account.get_option(&rustproto::exts::expose_oneof));
+    }
+}
@@ -0,0 +1,448 @@
+//! Generate random messages of any type from its descriptor.
+//!
+//! Randomness is taken from [`arbitrary::Unstructured`], so generators
+//! can be used in fuzz targets directly, and in property tests by
+//! feeding random bytes (e. g. `proptest` strategy for `Vec<u8>`)
+//! into `Unstructured`.
+//!
+//! This module is available with `with-arbitrary` feature.
+//!
+//! ```
+//! # use arbitrary::Unstructured;
+//! # use protobuf::descriptor::FileDescriptorProto;
+//! # use protobuf::reflect::random::random_message;
+//! # use protobuf::reflect::random::RandomMessageOptions;
+//! # use protobuf::Message;
+//! let mut u = Unstructured::new(&[7; 1000]);
+//! let descriptor = FileDescriptorProto::descriptor_static();
+//! let options = RandomMessageOptions::default();
+//! let message = random_message(&descriptor, &options, &mut u).unwrap();
+//! assert!(message.is_initialized_dyn());
+//! ```
+
+use arbitrary::Arbitrary;
+use arbitrary::Unstructured;
+
+use crate::message::Message;
+use crate::message_dyn::MessageDyn;
+use crate::reflect::EnumDescriptor;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::unknown::UnknownValue;
+
+/// Largest valid field number.
+const MAX_FIELD_NUMBER: u32 = 536_870_911;
+
+/// Parameters of random message generation.
+#[derive(Debug, Clone)]
+pub struct RandomMessageOptions {
+    /// Maximum nesting depth of messages.
+    ///
+    /// Deeper message fields are left unset unless they are required.
+    pub max_depth: u32,
+    /// Maximum number of elements in repeated fields.
+    pub max_repeated_len: usize,
+    /// Maximum number of entries in map fields.
+    pub max_map_len: usize,
+    /// Maximum length of strings in chars.
+    pub max_string_len: usize,
+    /// Maximum length of bytes fields.
+    pub max_bytes_len: usize,
+    /// Characters strings are made of, any `char` if empty.
+    pub string_alphabet: Vec<char>,
+    /// Generate values not declared in enum for open enums.
+    pub unknown_enum_values: bool,
+    /// Maximum number of unknown fields added to each message.
+    pub max_unknown_fields: usize,
+    /// Prevent initializing `RandomMessageOptions` enumerating all field.
+    pub _future_options: (),
+}
+
+impl Default for RandomMessageOptions {
+    fn default() -> Self {
+        RandomMessageOptions {
+            max_depth: 4,
+            max_repeated_len: 4,
+            max_map_len: 4,
+            max_string_len: 16,
+            max_bytes_len: 16,
+            string_alphabet: Vec::new(),
+            unknown_enum_values: false,
+            max_unknown_fields: 0,
+            _future_options: (),
+        }
+    }
+}
+
+/// Generate random message of given type.
+///
+/// Required fields are always set, so generated message is initialized
+/// unless required message fields form a cycle deeper than
+/// [`max_depth`](RandomMessageOptions::max_depth).
+/// Floating point fields are never NaN, so generated messages
+/// are equal to themselves after serialization roundtrip.
+///
+/// Generated messages are instances of generated types for generated
+/// descriptors and [`DynamicMessage`](crate::reflect::DynamicMessage)
+/// for dynamic descriptors.
+pub fn random_message(
+    descriptor: &MessageDescriptor,
+    options: &RandomMessageOptions,
+    u: &mut Unstructured,
+) -> arbitrary::Result<Box<dyn MessageDyn>> {
+    Generator { options, u }.message(descriptor, options.max_depth)
+}
+
+/// Wrapper to generate messages with [`Arbitrary`] using default options.
+///
+/// ```
+/// # use arbitrary::Arbitrary;
+/// # use arbitrary::Unstructured;
+/// # use protobuf::descriptor::DescriptorProto;
+/// # use protobuf::reflect::random::ArbitraryMessage;
+/// let mut u = Unstructured::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+/// let message: DescriptorProto = ArbitraryMessage::arbitrary(&mut u).unwrap().0;
+/// # drop(message);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArbitraryMessage<M: Message>(pub M);
+
+impl<'a, M: Message> Arbitrary<'a> for ArbitraryMessage<M> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let options = RandomMessageOptions::default();
+        let message = random_message(&M::descriptor_static(), &options, u)?;
+        Ok(ArbitraryMessage(*message.downcast_box().unwrap()))
+    }
+}
+
+struct Generator<'o, 'u, 'a> {
+    options: &'o RandomMessageOptions,
+    u: &'u mut Unstructured<'a>,
+}
+
+impl<'o, 'u, 'a> Generator<'o, 'u, 'a> {
+    fn len(&mut self, max: usize) -> arbitrary::Result<usize> {
+        self.u.int_in_range(0..=max)
+    }
+
+    fn message(
+        &mut self,
+        descriptor: &MessageDescriptor,
+        depth: u32,
+    ) -> arbitrary::Result<Box<dyn MessageDyn>> {
+        let mut message = descriptor.new_instance();
+
+        for oneof in descriptor.oneofs().filter(|o| !o.is_synthetic()) {
+            let fields: Vec<FieldDescriptor> = oneof.fields().collect();
+            let index = self.len(fields.len())?;
+            if let Some(field) = fields.get(index) {
+                if let Some(value) = self.singular(field, depth)? {
+                    field.set_singular_field(&mut *message, value);
+                }
+            }
+        }
+
+        for field in descriptor.fields() {
+            if field.containing_real_oneof().is_some() {
+                continue;
+            }
+            match field.runtime_field_type() {
+                RuntimeFieldType::Singular(..) => {
+                    if field.is_required() || self.u.arbitrary()? {
+                        if let Some(value) = self.singular(&field, depth)? {
+                            field.set_singular_field(&mut *message, value);
+                        }
+                    }
+                }
+                RuntimeFieldType::Repeated(t) => {
+                    if !self.can_generate(&t, depth) {
+                        continue;
+                    }
+                    let len = self.len(self.options.max_repeated_len)?;
+                    for _ in 0..len {
+                        let value = self.value(&t, depth)?;
+                        field.mut_repeated(&mut *message).push(value);
+                    }
+                }
+                RuntimeFieldType::Map(k, v) => {
+                    if !self.can_generate(&v, depth) {
+                        continue;
+                    }
+                    let len = self.len(self.options.max_map_len)?;
+                    for _ in 0..len {
+                        let key = self.value(&k, depth)?;
+                        let value = self.value(&v, depth)?;
+                        field.mut_map(&mut *message).insert(key, value);
+                    }
+                }
+            }
+        }
+
+        self.unknown_fields(descriptor, &mut *message)?;
+        Ok(message)
+    }
+
+    /// Messages are not generated beyond the depth limit.
+    fn can_generate(&self, t: &RuntimeTypeBox, depth: u32) -> bool {
+        match t {
+            RuntimeTypeBox::Message(..) => depth > 0,
+            _ => true,
+        }
+    }
+
+    /// Value of singular field or `None` if field should be left unset.
+    fn singular(
+        &mut self,
+        field: &FieldDescriptor,
+        depth: u32,
+    ) -> arbitrary::Result<Option<ReflectValueBox>> {
+        let t = field.singular_runtime_type();
+        match &t {
+            // Required message is still generated, but only with required fields
+            RuntimeTypeBox::Message(m) if depth == 0 => {
+                if field.is_required() {
+                    Ok(Some(ReflectValueBox::Message(self.required_only(m)?)))
+                } else {
+                    Ok(None)
+                }
+            }
+            t => Ok(Some(self.value(t, depth)?)),
+        }
+    }
+
+    /// Message with only required fields set, used beyond depth limit.
+    fn required_only(
+        &mut self,
+        descriptor: &MessageDescriptor,
+    ) -> arbitrary::Result<Box<dyn MessageDyn>> {
+        let mut message = descriptor.new_instance();
+        for field in descriptor.fields().filter(|f| f.is_required()) {
+            match field.singular_runtime_type() {
+                // Stop here to not loop forever on recursive required fields
+                RuntimeTypeBox::Message(..) => {}
+                t => {
+                    let value = self.value(&t, 0)?;
+                    field.set_singular_field(&mut *message, value);
+                }
+            }
+        }
+        Ok(message)
+    }
+
+    fn value(&mut self, t: &RuntimeTypeBox, depth: u32) -> arbitrary::Result<ReflectValueBox> {
+        Ok(match t {
+            RuntimeTypeBox::I32 => ReflectValueBox::I32(self.u.arbitrary()?),
+            RuntimeTypeBox::I64 => ReflectValueBox::I64(self.u.arbitrary()?),
+            RuntimeTypeBox::U32 => ReflectValueBox::U32(self.u.arbitrary()?),
+            RuntimeTypeBox::U64 => ReflectValueBox::U64(self.u.arbitrary()?),
+            RuntimeTypeBox::F32 => {
+                let v: f32 = self.u.arbitrary()?;
+                ReflectValueBox::F32(if v.is_nan() { 0.0 } else { v })
+            }
+            RuntimeTypeBox::F64 => {
+                let v: f64 = self.u.arbitrary()?;
+                ReflectValueBox::F64(if v.is_nan() { 0.0 } else { v })
+            }
+            RuntimeTypeBox::Bool => ReflectValueBox::Bool(self.u.arbitrary()?),
+            RuntimeTypeBox::String => ReflectValueBox::String(self.string()?),
+            RuntimeTypeBox::VecU8 => {
+                let len = self.len(self.options.max_bytes_len)?;
+                let mut bytes = Vec::with_capacity(len);
+                for _ in 0..len {
+                    bytes.push(self.u.arbitrary()?);
+                }
+                ReflectValueBox::Bytes(bytes)
+            }
+            RuntimeTypeBox::Enum(e) => ReflectValueBox::Enum(e.clone(), self.enum_value(e)?),
+            RuntimeTypeBox::Message(m) => ReflectValueBox::Message(self.message(m, depth - 1)?),
+        })
+    }
+
+    fn string(&mut self) -> arbitrary::Result<String> {
+        let len = self.len(self.options.max_string_len)?;
+        let mut s = String::new();
+        for _ in 0..len {
+            let c = if self.options.string_alphabet.is_empty() {
+                self.u.arbitrary()?
+            } else {
+                *self.u.choose(&self.options.string_alphabet)?
+            };
+            s.push(c);
+        }
+        Ok(s)
+    }
+
+    fn enum_value(&mut self, e: &EnumDescriptor) -> arbitrary::Result<i32> {
+        if self.options.unknown_enum_values && e.is_open() && self.u.ratio(1u8, 4)? {
+            let value: i32 = self.u.arbitrary()?;
+            if e.get_value_by_number(value).is_none() {
+                return Ok(value);
+            }
+        }
+        let values: Vec<i32> = e.values().map(|v| v.value()).collect();
+        Ok(*self.u.choose(&values)?)
+    }
+
+    /// Add unknown fields with numbers not declared in the message.
+    fn unknown_fields(
+        &mut self,
+        descriptor: &MessageDescriptor,
+        message: &mut dyn MessageDyn,
+    ) -> arbitrary::Result<()> {
+        let count = self.len(self.options.max_unknown_fields)?;
+        for _ in 0..count {
+            let number = self.u.int_in_range(1..=MAX_FIELD_NUMBER)?;
+            if descriptor.get_field_by_number(number).is_some() || (19000..=19999).contains(&number)
+            {
+                continue;
+            }
+            let value = match self.u.int_in_range(0..=3u8)? {
+                0 => UnknownValue::Varint(self.u.arbitrary()?),
+                1 => UnknownValue::Fixed32(self.u.arbitrary()?),
+                2 => UnknownValue::Fixed64(self.u.arbitrary()?),
+                _ => {
+                    let len = self.len(self.options.max_bytes_len)?;
+                    let mut bytes = Vec::with_capacity(len);
+                    for _ in 0..len {
+                        bytes.push(self.u.arbitrary()?);
+                    }
+                    UnknownValue::LengthDelimited(bytes)
+                }
+            };
+            message.mut_unknown_fields_dyn().add_value(number, value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arbitrary::Unstructured;
+
+    use super::random_message;
+    use super::RandomMessageOptions;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::reflect::FileDescriptor;
+    use crate::reflect::ReflectValueRef;
+    use crate::text_format;
+    use crate::well_known_types::Struct;
+    use crate::Message;
+
+    fn bytes(seed: u8) -> Vec<u8> {
+        // Simple LCG, enough to feed `Unstructured`
+        let mut state = seed as u32;
+        (0..4096)
+            .map(|_| {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                (state >> 16) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn generated_roundtrip() {
+        let mut options = RandomMessageOptions::default();
+        options.max_unknown_fields = 2;
+        let descriptor = FileDescriptorProto::descriptor_static();
+        for seed in 0..20 {
+            let data = bytes(seed);
+            let mut u = Unstructured::new(&data);
+            let m = random_message(&descriptor, &options, &mut u).unwrap();
+            assert!(m.is_initialized_dyn());
+            let bytes = m.write_to_bytes_dyn().unwrap();
+            let parsed = FileDescriptorProto::parse_from_bytes(&bytes).unwrap();
+            assert!(descriptor.eq(&*m, &parsed));
+        }
+    }
+
+    #[test]
+    fn dynamic() {
+        let file = FileDescriptor::new_dynamic(
+            Struct::descriptor_static().file_descriptor_proto().clone(),
+            Vec::new(),
+        );
+        let descriptor = file.message_by_package_relative_name("Struct").unwrap();
+        let mut options = RandomMessageOptions::default();
+        options.string_alphabet = vec!['a', 'b'];
+        options.unknown_enum_values = true;
+        for seed in 0..20 {
+            let data = bytes(seed);
+            let mut u = Unstructured::new(&data);
+            let m = random_message(&descriptor, &options, &mut u).unwrap();
+            assert!(descriptor == m.descriptor_dyn());
+            let s = Struct::parse_from_bytes(&m.write_to_bytes_dyn().unwrap()).unwrap();
+            for k in s.fields.keys() {
+                assert!(k.chars().all(|c| c == 'a' || c == 'b'));
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_enum_values() {
+        let proto: FileDescriptorProto = text_format::parse_from_str(
+            r#"
+                name: "e.proto"
+                syntax: "proto3"
+                enum_type {
+                    name: "E"
+                    value { name: "A" number: 0 }
+                    value { name: "B" number: 1 }
+                }
+                message_type {
+                    name: "M"
+                    field {
+                        name: "e" number: 1 label: LABEL_REPEATED type: TYPE_ENUM
+                        type_name: ".E"
+                    }
+                }
+            "#,
+        )
+        .unwrap();
+        let file = FileDescriptor::new_dynamic(proto, Vec::new());
+        let descriptor = file.message_by_package_relative_name("M").unwrap();
+        assert!(file.enums()[0].is_open());
+        let field = descriptor.get_field_by_name("e").unwrap();
+
+        let unknown = |options: &RandomMessageOptions| {
+            let mut unknown = 0;
+            for seed in 0..20 {
+                let data = bytes(seed);
+                let mut u = Unstructured::new(&data);
+                let m = random_message(&descriptor, options, &mut u).unwrap();
+                for v in &field.get_repeated(&*m) {
+                    match v {
+                        ReflectValueRef::Enum(e, v) => {
+                            if e.get_value_by_number(v).is_none() {
+                                unknown += 1;
+                            }
+                        }
+                        v => panic!("{:?}", v),
+                    }
+                }
+            }
+            unknown
+        };
+        let mut options = RandomMessageOptions::default();
+        options.max_repeated_len = 16;
+        assert_eq!(0, unknown(&options));
+        options.unknown_enum_values = true;
+        assert_ne!(0, unknown(&options));
+    }
+
+    #[test]
+    fn depth() {
+        let mut options = RandomMessageOptions::default();
+        options.max_depth = 0;
+        let data = bytes(1);
+        let mut u = Unstructured::new(&data);
+        let descriptor = DescriptorProto::descriptor_static();
+        let m = random_message(&descriptor, &options, &mut u).unwrap();
+        let m: Box<DescriptorProto> = m.downcast_box().unwrap();
+        assert!(m.nested_type.is_empty());
+        assert!(m.options.is_none());
+    }
+}
@@ -1,11 +1,18 @@
 use std::fmt;
+use std::marker;
 use std::slice;
 
+use crate::message::Message;
+use crate::message_convert;
+use crate::message_convert::MessageConvert;
 use crate::reflect::dynamic::repeated::DynamicRepeated;
 use crate::reflect::reflect_eq::ReflectEq;
 use crate::reflect::reflect_eq::ReflectEqMode;
+use crate::reflect::runtime_types::RuntimeType;
+use crate::reflect::value::value_ref::ReflectValueGuard;
 use crate::reflect::value::value_ref::ReflectValueRef;
 use crate::reflect::ProtobufValue;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::RuntimeTypeBox;
 
@@ -13,8 +20,14 @@ pub(crate) trait ReflectRepeated: Sync + 'static + fmt::Debug {
     fn reflect_iter(&self) -> ReflectRepeatedIter;
     fn len(&self) -> usize;
     fn get(&self, index: usize) -> ReflectValueRef;
-    fn set(&mut self, index: usize, value: ReflectValueBox);
-    fn push(&mut self, value: ReflectValueBox);
+    fn get_mut(&mut self, index: usize) -> ReflectValueGuard<'_>;
+    fn set(&mut self, index: usize, value: ReflectValueBox) -> Result<(), ReflectTypeError>;
+    fn push(&mut self, value: ReflectValueBox) -> Result<(), ReflectTypeError>;
+    fn insert(&mut self, index: usize, value: ReflectValueBox) -> Result<(), ReflectTypeError>;
+    fn remove(&mut self, index: usize) -> ReflectValueBox;
+    fn truncate(&mut self, len: usize);
+    fn swap(&mut self, a: usize, b: usize);
+    fn retain(&mut self, f: &mut dyn FnMut(ReflectValueRef) -> bool);
     fn clear(&mut self);
     fn element_type(&self) -> RuntimeTypeBox;
 }
@@ -34,14 +47,39 @@ impl<V: ProtobufValue> ReflectRepeated for Vec<V> {
         V::as_ref(&self[index])
     }
 
-    fn set(&mut self, index: usize, value: ReflectValueBox) {
-        let value = value.downcast().expect("wrong type");
-        self[index] = value;
+    fn get_mut(&mut self, index: usize) -> ReflectValueGuard<'_> {
+        V::RuntimeType::as_guard(&mut self[index])
     }
 
-    fn push(&mut self, value: ReflectValueBox) {
-        let value = value.downcast().expect("wrong type");
-        self.push(value)
+    fn set(&mut self, index: usize, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        self[index] = downcast(value)?;
+        Ok(())
+    }
+
+    fn push(&mut self, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        self.push(downcast(value)?);
+        Ok(())
+    }
+
+    fn insert(&mut self, index: usize, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        self.insert(index, downcast(value)?);
+        Ok(())
+    }
+
+    fn remove(&mut self, index: usize) -> ReflectValueBox {
+        V::RuntimeType::into_value_box(self.remove(index))
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate(len)
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        <[V]>::swap(self, a, b)
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(ReflectValueRef) -> bool) {
+        self.retain(|v| f(V::as_ref(v)))
     }
 
     fn clear(&mut self) {
@@ -69,15 +107,39 @@ impl<V: ProtobufValue> ReflectRepeated for [V] {
         V::as_ref(&self[index])
     }
 
-    fn set(&mut self, index: usize, value: ReflectValueBox) {
-        let value = value.downcast().expect("wrong type");
-        self[index] = value;
+    fn get_mut(&mut self, index: usize) -> ReflectValueGuard<'_> {
+        V::RuntimeType::as_guard(&mut self[index])
     }
 
-    fn push(&mut self, _value: ReflectValueBox) {
+    fn set(&mut self, index: usize, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        self[index] = downcast(value)?;
+        Ok(())
+    }
+
+    fn push(&mut self, _value: ReflectValueBox) -> Result<(), ReflectTypeError> {
         panic!("push is not possible for [V]");
     }
 
+    fn insert(&mut self, _index: usize, _value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        panic!("insert is not possible for [V]");
+    }
+
+    fn remove(&mut self, _index: usize) -> ReflectValueBox {
+        panic!("remove is not possible for [V]");
+    }
+
+    fn truncate(&mut self, _len: usize) {
+        panic!("truncate is not possible for [V]");
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        <[V]>::swap(self, a, b)
+    }
+
+    fn retain(&mut self, _f: &mut dyn FnMut(ReflectValueRef) -> bool) {
+        panic!("retain is not possible for [V]");
+    }
+
     fn clear(&mut self) {
         panic!("clear is not possible for [V]");
     }
@@ -87,6 +149,106 @@ impl<V: ProtobufValue> ReflectRepeated for [V] {
     }
 }
 
+/// Downcast value to element type.
+fn downcast<V: ProtobufValue>(value: ReflectValueBox) -> Result<V, ReflectTypeError> {
+    V::try_from_value_box(value)
+}
+
+/// `Vec` of custom type values stored instead of messages `V`.
+#[derive(Debug)]
+#[repr(transparent)]
+pub(crate) struct VecOfConverted<V, T>(Vec<T>, marker::PhantomData<V>);
+
+impl<V, T> VecOfConverted<V, T>
+where
+    V: Message + ProtobufValue,
+    T: MessageConvert<V>,
+{
+    pub(crate) fn from_vec(vec: &Vec<T>) -> &VecOfConverted<V, T> {
+        // Safe because of `repr(transparent)`
+        unsafe { &*(vec as *const Vec<T> as *const VecOfConverted<V, T>) }
+    }
+
+    pub(crate) fn from_vec_mut(vec: &mut Vec<T>) -> &mut VecOfConverted<V, T> {
+        // Safe because of `repr(transparent)`
+        unsafe { &mut *(vec as *mut Vec<T> as *mut VecOfConverted<V, T>) }
+    }
+}
+
+impl<V, T> ReflectRepeated for VecOfConverted<V, T>
+where
+    V: Message + ProtobufValue,
+    T: MessageConvert<V>,
+{
+    fn reflect_iter<'a>(&'a self) -> ReflectRepeatedIter<'a> {
+        ReflectRepeatedIter {
+            imp: Box::new(ReflectRepeatedIterImplConverted::<'a, V, T> {
+                iter: self.0.iter(),
+                _marker: marker::PhantomData,
+            }),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get(&self, index: usize) -> ReflectValueRef<'_> {
+        message_convert::to_value_ref::<V, T>(&self.0[index])
+    }
+
+    fn get_mut(&mut self, index: usize) -> ReflectValueGuard<'_> {
+        let value = &mut self.0[index];
+        let message: V = value.to_message();
+        ReflectValueGuard::copy(ReflectValueBox::Message(Box::new(message)), move |v| {
+            *value = message_convert::from_value_box::<V, T>(v)?;
+            Ok(())
+        })
+    }
+
+    fn set(&mut self, index: usize, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        self.0[index] = message_convert::from_value_box::<V, T>(value)?;
+        Ok(())
+    }
+
+    fn push(&mut self, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        self.0.push(message_convert::from_value_box::<V, T>(value)?);
+        Ok(())
+    }
+
+    fn insert(&mut self, index: usize, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        self.0
+            .insert(index, message_convert::from_value_box::<V, T>(value)?);
+        Ok(())
+    }
+
+    fn remove(&mut self, index: usize) -> ReflectValueBox {
+        let message: V = self.0.remove(index).to_message();
+        ReflectValueBox::Message(Box::new(message))
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len)
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.0.swap(a, b)
+    }
+
+    fn retain(&mut self, f: &mut dyn FnMut(ReflectValueRef) -> bool) {
+        self.0
+            .retain(|v| f(message_convert::to_value_ref::<V, T>(v)))
+    }
+
+    fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    fn element_type(&self) -> RuntimeTypeBox {
+        V::runtime_type_box()
+    }
+}
+
 trait ReflectRepeatedIterTrait<'a> {
     fn next(&mut self) -> Option<ReflectValueRef<'a>>;
 }
@@ -103,6 +265,21 @@ impl<'a, V: ProtobufValue + 'static> ReflectRepeatedIterTrait<'a>
     }
 }
 
+struct ReflectRepeatedIterImplConverted<'a, V, T> {
+    iter: slice::Iter<'a, T>,
+    _marker: marker::PhantomData<V>,
+}
+
+impl<'a, V, T> ReflectRepeatedIterTrait<'a> for ReflectRepeatedIterImplConverted<'a, V, T>
+where
+    V: Message + ProtobufValue,
+    T: MessageConvert<V>,
+{
+    fn next(&mut self) -> Option<ReflectValueRef<'a>> {
+        self.iter.next().map(message_convert::to_value_ref::<V, T>)
+    }
+}
+
 pub struct ReflectRepeatedIter<'a> {
     imp: Box<dyn ReflectRepeatedIterTrait<'a> + 'a>,
 }
@@ -226,7 +403,7 @@ impl<'a> PartialEq for ReflectRepeatedRef<'a> {
             }
         }
 
-        return true;
+        true
     }
 }
 
@@ -236,13 +413,13 @@ impl<'a> PartialEq<[ReflectValueBox]> for ReflectRepeatedRef<'a> {
             return false;
         }
 
-        for i in 0..self.len() {
-            if self.get(i) != other[i] {
+        for (i, value) in other.iter().enumerate() {
+            if self.get(i) != *value {
                 return false;
             }
         }
 
-        return true;
+        true
     }
 }
 
@@ -286,10 +463,19 @@ impl<'a> ReflectRepeatedMut<'a> {
     /// Get an item by index
     ///
     /// Note: return immutable reference.
-    pub fn get(&'a self, index: usize) -> ReflectValueRef<'a> {
+    pub fn get(&self, index: usize) -> ReflectValueRef<'_> {
         self.repeated.get(index)
     }
 
+    /// Get a mutable reference to an item by index.
+    ///
+    /// # Panics
+    ///
+    /// If index if out of range
+    pub fn get_mut(&mut self, index: usize) -> ReflectValueGuard<'_> {
+        self.repeated.get_mut(index)
+    }
+
     /// Runtime type of element
     pub fn element_type(&self) -> RuntimeTypeBox {
         self.repeated.element_type()
@@ -301,7 +487,23 @@ impl<'a> ReflectRepeatedMut<'a> {
     ///
     /// If index if out of range or value type does not match container element type
     pub fn set(&mut self, index: usize, value: ReflectValueBox) {
-        self.repeated.set(index, value);
+        if let Err(e) = self.repeated.set(index, value) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Set a value at given index, or return an error if value type
+    /// does not match container element type.
+    ///
+    /// # Panics
+    ///
+    /// If index if out of range
+    pub fn try_set(
+        &mut self,
+        index: usize,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
+        self.repeated.set(index, value)
     }
 
     /// Push an item to repeated field.
@@ -310,7 +512,68 @@ impl<'a> ReflectRepeatedMut<'a> {
     ///
     /// If index if out of range or value type does not match container element type
     pub fn push(&mut self, value: ReflectValueBox) {
-        self.repeated.push(value);
+        if let Err(e) = self.repeated.push(value) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Push an item to repeated field, or return an error if value type
+    /// does not match container element type.
+    pub fn try_push(&mut self, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        self.repeated.push(value)
+    }
+
+    /// Insert an item at given index, shifting following elements.
+    ///
+    /// # Panics
+    ///
+    /// If index is greater than length or value type does not match container element type
+    pub fn insert(&mut self, index: usize, value: ReflectValueBox) {
+        if let Err(e) = self.repeated.insert(index, value) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Insert an item at given index, or return an error if value type
+    /// does not match container element type.
+    ///
+    /// # Panics
+    ///
+    /// If index is greater than length
+    pub fn try_insert(
+        &mut self,
+        index: usize,
+        value: ReflectValueBox,
+    ) -> Result<(), ReflectTypeError> {
+        self.repeated.insert(index, value)
+    }
+
+    /// Remove and return an item at given index, shifting following elements.
+    ///
+    /// # Panics
+    ///
+    /// If index is out of range
+    pub fn remove(&mut self, index: usize) -> ReflectValueBox {
+        self.repeated.remove(index)
+    }
+
+    /// Keep first `len` elements and drop the rest.
+    pub fn truncate(&mut self, len: usize) {
+        self.repeated.truncate(len);
+    }
+
+    /// Swap two elements.
+    ///
+    /// # Panics
+    ///
+    /// If either index is out of range
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.repeated.swap(a, b);
+    }
+
+    /// Keep only elements for which the predicate returns `true`.
+    pub fn retain<F: FnMut(ReflectValueRef) -> bool>(&mut self, mut f: F) {
+        self.repeated.retain(&mut f);
     }
 
     /// Self-explanatory
@@ -7,9 +7,11 @@ use std::marker;
 use bytes::Bytes;
 
 use crate::reflect::runtime_type_box::RuntimeTypeBox;
+use crate::reflect::value::value_ref::ReflectValueGuard;
 use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::MessageRef;
 use crate::reflect::ProtobufValue;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::ReflectValueBox;
 use crate::reflect::ReflectValueRef;
 
@@ -47,6 +49,18 @@ pub trait RuntimeType: fmt::Debug + Send + Sync + 'static {
     /// If reflective value is of incompatible type.
     fn from_value_box(value_box: ReflectValueBox) -> Result<Self::Value, ReflectValueBox>;
 
+    /// Construct a value from given reflective value, or return an error
+    /// if value is of different type or cannot be represented by this type.
+    fn try_from_value_box(value_box: ReflectValueBox) -> Result<Self::Value, ReflectTypeError>
+    where
+        Self: Sized,
+    {
+        Self::from_value_box(value_box).map_err(|value| ReflectTypeError::Mismatch {
+            expected: Self::runtime_type_box(),
+            actual: value.get_type(),
+        })
+    }
+
     /// Convert a value into a refletive box value.
     fn into_value_box(value: Self::Value) -> ReflectValueBox;
 
@@ -64,6 +78,8 @@ pub trait RuntimeType: fmt::Debug + Send + Sync + 'static {
     fn as_ref(value: &Self::Value) -> ReflectValueRef;
     /// Mutable pointer to a dynamic mutable reference.
     fn as_mut(value: &mut Self::Value) -> ReflectValueMut;
+    /// Mutable reference to a repeated element or a map value.
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_>;
 
     /// Value is non-default?
     fn is_non_zero(value: &Self::Value) -> bool;
@@ -181,6 +197,10 @@ impl RuntimeType for RuntimeTypeF32 {
         unimplemented!()
     }
 
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::f32(value)
+    }
+
     fn is_non_zero(value: &f32) -> bool {
         *value != 0.0
     }
@@ -226,6 +246,10 @@ impl RuntimeType for RuntimeTypeF64 {
     fn as_mut(_value: &mut Self::Value) -> ReflectValueMut {
         unimplemented!()
     }
+
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::f64(value)
+    }
 }
 
 impl RuntimeType for RuntimeTypeI32 {
@@ -268,6 +292,10 @@ impl RuntimeType for RuntimeTypeI32 {
     fn as_mut(_value: &mut Self::Value) -> ReflectValueMut {
         unimplemented!()
     }
+
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::i32(value)
+    }
 }
 impl RuntimeTypeHashable for RuntimeTypeI32 {
     fn hash_map_get<'a, V>(map: &'a HashMap<i32, V>, key: ReflectValueRef) -> Option<&'a V> {
@@ -318,6 +346,10 @@ impl RuntimeType for RuntimeTypeI64 {
     fn as_mut(_value: &mut Self::Value) -> ReflectValueMut {
         unimplemented!()
     }
+
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::i64(value)
+    }
 }
 impl RuntimeTypeHashable for RuntimeTypeI64 {
     fn hash_map_get<'a, V>(map: &'a HashMap<i64, V>, key: ReflectValueRef) -> Option<&'a V> {
@@ -365,6 +397,10 @@ impl RuntimeType for RuntimeTypeU32 {
         unimplemented!()
     }
 
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::u32(value)
+    }
+
     fn is_non_zero(value: &u32) -> bool {
         *value != 0
     }
@@ -418,6 +454,10 @@ impl RuntimeType for RuntimeTypeU64 {
     fn as_mut(_value: &mut Self::Value) -> ReflectValueMut {
         unimplemented!()
     }
+
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::u64(value)
+    }
 }
 impl RuntimeTypeHashable for RuntimeTypeU64 {
     fn hash_map_get<'a, V>(map: &'a HashMap<u64, V>, key: ReflectValueRef) -> Option<&'a V> {
@@ -468,6 +508,10 @@ impl RuntimeType for RuntimeTypeBool {
     fn as_mut(_value: &mut Self::Value) -> ReflectValueMut {
         unimplemented!()
     }
+
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::bool(value)
+    }
 }
 impl RuntimeTypeHashable for RuntimeTypeBool {
     fn hash_map_get<'a, V>(map: &'a HashMap<bool, V>, key: ReflectValueRef) -> Option<&'a V> {
@@ -511,6 +555,10 @@ impl RuntimeType for RuntimeTypeString {
         unimplemented!()
     }
 
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::string(value)
+    }
+
     fn is_non_zero(value: &String) -> bool {
         !value.is_empty()
     }
@@ -564,6 +612,10 @@ impl RuntimeType for RuntimeTypeVecU8 {
         unimplemented!()
     }
 
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::bytes(value)
+    }
+
     fn is_non_zero(value: &Vec<u8>) -> bool {
         !value.is_empty()
     }
@@ -614,6 +666,13 @@ impl RuntimeType for RuntimeTypeCarllercheBytes {
     fn as_mut(_value: &mut Self::Value) -> ReflectValueMut {
         unimplemented!()
     }
+
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::copy(ReflectValueBox::Bytes(value.to_vec()), move |v| {
+            *value = Self::from_value_box(v).expect("type checked");
+            Ok(())
+        })
+    }
 }
 #[cfg(feature = "bytes")]
 impl RuntimeTypeWithDeref for RuntimeTypeCarllercheBytes {
@@ -661,6 +720,13 @@ impl RuntimeType for RuntimeTypeCarllercheChars {
     fn as_mut(_value: &mut Self::Value) -> ReflectValueMut {
         unimplemented!()
     }
+
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::copy(ReflectValueBox::String(value.to_string()), move |v| {
+            *value = Self::from_value_box(v).expect("type checked");
+            Ok(())
+        })
+    }
 }
 #[cfg(feature = "bytes")]
 impl RuntimeTypeWithDeref for RuntimeTypeCarllercheChars {
@@ -710,6 +776,23 @@ where
         }
     }
 
+    fn try_from_value_box(value_box: ReflectValueBox) -> Result<E, ReflectTypeError> {
+        let d = E::enum_descriptor_static();
+        match value_box {
+            ReflectValueBox::Enum(vd, v) if vd == d => E::from_i32(v).ok_or_else(|| {
+                ReflectTypeError::Convert(format!(
+                    "unknown value {} of closed enum {}",
+                    v,
+                    d.full_name()
+                ))
+            }),
+            b => Err(ReflectTypeError::Mismatch {
+                expected: RuntimeTypeBox::Enum(d),
+                actual: b.get_type(),
+            }),
+        }
+    }
+
     fn into_value_box(value: E) -> ReflectValueBox {
         ReflectValueBox::Enum(E::enum_descriptor_static(), value.value())
     }
@@ -726,6 +809,14 @@ where
         unimplemented!()
     }
 
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        let d = E::enum_descriptor_static();
+        ReflectValueGuard::copy(ReflectValueBox::Enum(d, value.value()), move |v| {
+            *value = Self::try_from_value_box(v)?;
+            Ok(())
+        })
+    }
+
     fn is_non_zero(value: &E) -> bool {
         value.value() != 0
     }
@@ -778,6 +869,10 @@ where
         unimplemented!()
     }
 
+    fn as_guard(value: &mut Self::Value) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::enum_value(E::enum_descriptor_static(), value.value_mut())
+    }
+
     fn is_non_zero(value: &ProtobufEnumOrUnknown<E>) -> bool {
         value.value() != 0
     }
@@ -821,6 +916,10 @@ where
         ReflectValueMut::Message(value)
     }
 
+    fn as_guard(value: &mut M) -> ReflectValueGuard<'_> {
+        ReflectValueGuard::message(value)
+    }
+
     fn is_non_zero(_value: &M) -> bool {
         true
     }
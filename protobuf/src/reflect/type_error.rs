@@ -0,0 +1,49 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::reflect::ReflectValueBox;
+use crate::reflect::RuntimeTypeBox;
+
+/// Value passed to a reflective setter cannot be stored in the field,
+/// repeated field element, map key or map value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReflectTypeError {
+    /// Value is of a type different from the container type.
+    Mismatch {
+        /// Type expected by the container.
+        expected: RuntimeTypeBox,
+        /// Type of the value.
+        actual: RuntimeTypeBox,
+    },
+    /// Value cannot be converted to the Rust type the field is stored as,
+    /// e. g. [`MessageConvert`](crate::MessageConvert) failed
+    /// or value is unknown for a closed enum.
+    Convert(String),
+}
+
+impl ReflectTypeError {
+    /// Return the value back if it is of expected type.
+    pub(crate) fn check(
+        expected: RuntimeTypeBox,
+        value: ReflectValueBox,
+    ) -> Result<ReflectValueBox, ReflectTypeError> {
+        let actual = value.get_type();
+        if actual != expected {
+            return Err(ReflectTypeError::Mismatch { expected, actual });
+        }
+        Ok(value)
+    }
+}
+
+impl fmt::Display for ReflectTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReflectTypeError::Mismatch { expected, actual } => {
+                write!(f, "expecting {}, got {}", expected, actual)
+            }
+            ReflectTypeError::Convert(e) => write!(f, "conversion failed: {}", e),
+        }
+    }
+}
+
+impl Error for ReflectTypeError {}
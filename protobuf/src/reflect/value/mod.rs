@@ -23,6 +23,7 @@ use crate::reflect::runtime_types::RuntimeTypeVecU8;
 use crate::reflect::value::value_box::ReflectValueBox;
 use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::value::value_ref::ReflectValueRef;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::RuntimeTypeBox;
 
 pub(crate) mod value_box;
@@ -64,6 +65,12 @@ pub trait ProtobufValue:
         Self::RuntimeType::from_value_box(value_box)
     }
 
+    /// Construct a value from given reflective value, or return an error
+    /// if value is of different type or cannot be represented by this type.
+    fn try_from_value_box(value_box: ReflectValueBox) -> Result<Self, ReflectTypeError> {
+        Self::RuntimeType::try_from_value_box(value_box)
+    }
+
     /// Write the value.
     fn set_from_value_box(target: &mut Self, value_box: ReflectValueBox) {
         Self::RuntimeType::set_from_value_box(target, value_box)
@@ -1,4 +1,5 @@
 use crate::reflect::message::message_ref::MessageRef;
+use crate::reflect::value::value_ref::ReflectValueGuard;
 use crate::reflect::value::value_ref::ReflectValueMut;
 use crate::reflect::value::value_ref::ReflectValueRef;
 use crate::reflect::EnumDescriptor;
@@ -82,6 +83,12 @@ impl From<String> for ReflectValueBox {
     }
 }
 
+impl<'a> From<&'a str> for ReflectValueBox {
+    fn from(v: &'a str) -> Self {
+        ReflectValueBox::String(v.to_owned())
+    }
+}
+
 impl From<Vec<u8>> for ReflectValueBox {
     fn from(v: Vec<u8>) -> Self {
         ReflectValueBox::Bytes(v)
@@ -145,6 +152,22 @@ impl ReflectValueBox {
         }
     }
 
+    pub(crate) fn as_value_guard(&mut self) -> ReflectValueGuard<'_> {
+        match self {
+            ReflectValueBox::U32(v) => ReflectValueGuard::u32(v),
+            ReflectValueBox::U64(v) => ReflectValueGuard::u64(v),
+            ReflectValueBox::I32(v) => ReflectValueGuard::i32(v),
+            ReflectValueBox::I64(v) => ReflectValueGuard::i64(v),
+            ReflectValueBox::F32(v) => ReflectValueGuard::f32(v),
+            ReflectValueBox::F64(v) => ReflectValueGuard::f64(v),
+            ReflectValueBox::Bool(v) => ReflectValueGuard::bool(v),
+            ReflectValueBox::String(v) => ReflectValueGuard::string(v),
+            ReflectValueBox::Bytes(v) => ReflectValueGuard::bytes(v),
+            ReflectValueBox::Enum(d, v) => ReflectValueGuard::enum_value(d.clone(), v),
+            ReflectValueBox::Message(m) => ReflectValueGuard::message(&mut **m),
+        }
+    }
+
     /// Downcast to real typed value.
     ///
     /// For `enum` `V` can be either `V: ProtobufEnum` or `V: ProtobufEnumOrUnknown<E>`.
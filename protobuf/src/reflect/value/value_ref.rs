@@ -1,3 +1,4 @@
+use crate::reflect::message::message_mut::MessageMut;
 use crate::reflect::value::value_box::ReflectValueBox;
 use crate::reflect::EnumDescriptor;
 use crate::reflect::EnumValueDescriptor;
@@ -6,8 +7,10 @@ use crate::reflect::MessageRef;
 use crate::reflect::ProtobufValue;
 use crate::reflect::ReflectEq;
 use crate::reflect::ReflectEqMode;
+use crate::reflect::ReflectTypeError;
 use crate::reflect::RuntimeTypeBox;
 use crate::MessageDyn;
+use std::fmt;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::mem;
@@ -58,7 +61,7 @@ impl<'a> ReflectValueRef<'a> {
     }
 
     /// Value is "non-zero"?
-    fn _is_non_zero(&self) -> bool {
+    pub(crate) fn is_non_zero(&self) -> bool {
         match self {
             ReflectValueRef::U32(v) => *v != 0,
             ReflectValueRef::U64(v) => *v != 0,
@@ -181,6 +184,251 @@ pub enum ReflectValueMut<'a> {
     Message(&'a mut dyn MessageDyn),
 }
 
+/// Mutable reference to a repeated field element or a map value.
+///
+/// Values not stored as their reflective representation (messages stored
+/// as custom types, see [`MessageConvert`](crate::MessageConvert), or `bytes`
+/// crate types) are copied, and the copy is written back when this object
+/// is dropped or [`finish`](ReflectValueGuard::finish)ed.
+pub struct ReflectValueGuard<'a> {
+    imp: ReflectValueGuardImpl<'a>,
+}
+
+enum ReflectValueGuardImpl<'a> {
+    U32(&'a mut u32),
+    U64(&'a mut u64),
+    I32(&'a mut i32),
+    I64(&'a mut i64),
+    F32(&'a mut f32),
+    F64(&'a mut f64),
+    Bool(&'a mut bool),
+    String(&'a mut String),
+    Bytes(&'a mut Vec<u8>),
+    Enum(EnumDescriptor, &'a mut i32),
+    Message(&'a mut dyn MessageDyn),
+    Copy(WriteBack<'a>),
+}
+
+impl<'a> ReflectValueGuard<'a> {
+    pub(crate) fn u32(v: &'a mut u32) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::U32(v),
+        }
+    }
+
+    pub(crate) fn u64(v: &'a mut u64) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::U64(v),
+        }
+    }
+
+    pub(crate) fn i32(v: &'a mut i32) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::I32(v),
+        }
+    }
+
+    pub(crate) fn i64(v: &'a mut i64) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::I64(v),
+        }
+    }
+
+    pub(crate) fn f32(v: &'a mut f32) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::F32(v),
+        }
+    }
+
+    pub(crate) fn f64(v: &'a mut f64) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::F64(v),
+        }
+    }
+
+    pub(crate) fn bool(v: &'a mut bool) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::Bool(v),
+        }
+    }
+
+    pub(crate) fn string(v: &'a mut String) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::String(v),
+        }
+    }
+
+    pub(crate) fn bytes(v: &'a mut Vec<u8>) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::Bytes(v),
+        }
+    }
+
+    pub(crate) fn enum_value(d: EnumDescriptor, v: &'a mut i32) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::Enum(d, v),
+        }
+    }
+
+    pub(crate) fn message(m: &'a mut dyn MessageDyn) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::Message(m),
+        }
+    }
+
+    /// Mutable reference to a copy of the value, `write` is called
+    /// with the copy when the reference is dropped.
+    pub(crate) fn copy(
+        value: ReflectValueBox,
+        write: impl FnOnce(ReflectValueBox) -> Result<(), ReflectTypeError> + 'a,
+    ) -> ReflectValueGuard<'a> {
+        ReflectValueGuard {
+            imp: ReflectValueGuardImpl::Copy(WriteBack::new(value, write)),
+        }
+    }
+
+    /// Get the value.
+    pub fn get(&self) -> ReflectValueRef<'_> {
+        match &self.imp {
+            ReflectValueGuardImpl::U32(v) => ReflectValueRef::U32(**v),
+            ReflectValueGuardImpl::U64(v) => ReflectValueRef::U64(**v),
+            ReflectValueGuardImpl::I32(v) => ReflectValueRef::I32(**v),
+            ReflectValueGuardImpl::I64(v) => ReflectValueRef::I64(**v),
+            ReflectValueGuardImpl::F32(v) => ReflectValueRef::F32(**v),
+            ReflectValueGuardImpl::F64(v) => ReflectValueRef::F64(**v),
+            ReflectValueGuardImpl::Bool(v) => ReflectValueRef::Bool(**v),
+            ReflectValueGuardImpl::String(v) => ReflectValueRef::String(v.as_str()),
+            ReflectValueGuardImpl::Bytes(v) => ReflectValueRef::Bytes(v.as_slice()),
+            ReflectValueGuardImpl::Enum(d, v) => ReflectValueRef::Enum(d.clone(), **v),
+            ReflectValueGuardImpl::Message(m) => ReflectValueRef::Message(MessageRef::new(&**m)),
+            ReflectValueGuardImpl::Copy(c) => c.value.as_value_ref(),
+        }
+    }
+
+    /// Runtime type of the value.
+    pub fn get_type(&self) -> RuntimeTypeBox {
+        self.get().get_type()
+    }
+
+    /// Replace the value.
+    ///
+    /// # Panics
+    ///
+    /// If value type is different from this value type.
+    pub fn set(&mut self, value: ReflectValueBox) {
+        if let Err(e) = self.try_set(value) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Replace the value, or return an error if value type
+    /// is different from this value type.
+    pub fn try_set(&mut self, value: ReflectValueBox) -> Result<(), ReflectTypeError> {
+        let value = ReflectTypeError::check(self.get_type(), value)?;
+        match (&mut self.imp, value) {
+            (ReflectValueGuardImpl::U32(v), ReflectValueBox::U32(n)) => **v = n,
+            (ReflectValueGuardImpl::U64(v), ReflectValueBox::U64(n)) => **v = n,
+            (ReflectValueGuardImpl::I32(v), ReflectValueBox::I32(n)) => **v = n,
+            (ReflectValueGuardImpl::I64(v), ReflectValueBox::I64(n)) => **v = n,
+            (ReflectValueGuardImpl::F32(v), ReflectValueBox::F32(n)) => **v = n,
+            (ReflectValueGuardImpl::F64(v), ReflectValueBox::F64(n)) => **v = n,
+            (ReflectValueGuardImpl::Bool(v), ReflectValueBox::Bool(n)) => **v = n,
+            (ReflectValueGuardImpl::String(v), ReflectValueBox::String(n)) => **v = n,
+            (ReflectValueGuardImpl::Bytes(v), ReflectValueBox::Bytes(n)) => **v = n,
+            (ReflectValueGuardImpl::Enum(_, v), ReflectValueBox::Enum(_, n)) => **v = n,
+            (ReflectValueGuardImpl::Message(m), ReflectValueBox::Message(n)) => {
+                m.clear_dyn();
+                m.merge_dyn(&*n);
+            }
+            (ReflectValueGuardImpl::Copy(c), value) => c.value = value,
+            _ => unreachable!("value type is checked"),
+        }
+        Ok(())
+    }
+
+    /// Mutable reference to the message if this value is a message.
+    pub fn as_message_mut(&mut self) -> Option<&mut dyn MessageDyn> {
+        match &mut self.imp {
+            ReflectValueGuardImpl::Message(m) => Some(&mut **m),
+            ReflectValueGuardImpl::Copy(c) => match &mut c.value {
+                ReflectValueBox::Message(m) => Some(&mut **m),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Convert to a message reference if this value is a message.
+    pub fn into_message(self) -> Option<MessageMut<'a>> {
+        match self.imp {
+            ReflectValueGuardImpl::Message(m) => Some(MessageMut::new(m)),
+            ReflectValueGuardImpl::Copy(c) => match &c.value {
+                ReflectValueBox::Message(..) => Some(MessageMut::copy(c)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Write the value back to the container if the value is a copy
+    /// and return conversion error if any.
+    ///
+    /// Dropping this object also writes the value back, but the error
+    /// is ignored, and the container keeps its previous value.
+    pub fn finish(self) -> Result<(), ReflectTypeError> {
+        match self.imp {
+            ReflectValueGuardImpl::Copy(c) => c.finish(),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> fmt::Debug for ReflectValueGuard<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ReflectValueGuard")
+            .field(&self.get())
+            .finish()
+    }
+}
+
+type WriteBackFn<'a> = Box<dyn FnOnce(ReflectValueBox) -> Result<(), ReflectTypeError> + 'a>;
+
+/// Copy of a value which is written back to the container on drop.
+pub(crate) struct WriteBack<'a> {
+    pub(crate) value: ReflectValueBox,
+    write: Option<WriteBackFn<'a>>,
+}
+
+impl<'a> WriteBack<'a> {
+    fn new(
+        value: ReflectValueBox,
+        write: impl FnOnce(ReflectValueBox) -> Result<(), ReflectTypeError> + 'a,
+    ) -> WriteBack<'a> {
+        WriteBack {
+            value,
+            write: Some(Box::new(write)),
+        }
+    }
+
+    fn write(&mut self) -> Result<(), ReflectTypeError> {
+        match self.write.take() {
+            Some(write) => write(mem::replace(&mut self.value, ReflectValueBox::Bool(false))),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> Result<(), ReflectTypeError> {
+        self.write()
+    }
+}
+
+impl<'a> Drop for WriteBack<'a> {
+    fn drop(&mut self) {
+        // Error cannot be reported from drop, container keeps previous value
+        let _ = self.write();
+    }
+}
+
 impl<'a> ReflectEq for ReflectValueRef<'a> {
     fn reflect_eq(&self, that: &Self, mode: &ReflectEqMode) -> bool {
         use crate::reflect::value::value_ref::ReflectValueRef::*;
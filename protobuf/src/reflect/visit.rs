@@ -0,0 +1,699 @@
+//! Walk message trees with reflection.
+//!
+//! [`walk`] and [`walk_mut`] recurse into a message of any type, generated
+//! or dynamic, and call visitor hooks for each message, set field,
+//! repeated field element, map entry and unknown field. Hooks receive
+//! the [`FieldPath`] from the root message, and `enter_*` hooks can
+//! prune the walk by returning [`Visit::Prune`].
+//!
+//! Fields are visited in declaration order, map entries in key order,
+//! unknown fields in field number order, after known fields.
+//!
+//! ```
+//! # use protobuf::descriptor::DescriptorProto;
+//! # use protobuf::descriptor::FileDescriptorProto;
+//! # use protobuf::reflect::field_path::FieldPath;
+//! # use protobuf::reflect::visit::walk;
+//! # use protobuf::reflect::visit::MessageVisitor;
+//! # use protobuf::reflect::visit::Visit;
+//! # use protobuf::reflect::FieldDescriptor;
+//! # use protobuf::reflect::ReflectFieldRef;
+//! struct Paths(Vec<String>);
+//!
+//! impl MessageVisitor for Paths {
+//!     fn enter_field(
+//!         &mut self,
+//!         path: &FieldPath,
+//!         _field: &FieldDescriptor,
+//!         _value: &ReflectFieldRef,
+//!     ) -> Visit {
+//!         self.0.push(path.to_string());
+//!         Visit::Continue
+//!     }
+//! }
+//!
+//! let mut file = FileDescriptorProto::new();
+//! file.set_name("a.proto".to_owned());
+//! let mut message = DescriptorProto::new();
+//! message.set_name("M".to_owned());
+//! file.message_type.push(message);
+//!
+//! let mut paths = Paths(Vec::new());
+//! walk(&file, &mut paths);
+//! assert_eq!(vec!["name", "message_type", "message_type[0].name"], paths.0);
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::message_dyn::MessageDyn;
+use crate::reflect::field_path::FieldPath;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueBox;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::unknown::UnknownValues;
+use crate::UnknownFields;
+
+/// What to do after an `enter_*` hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visit {
+    /// Walk the children.
+    Continue,
+    /// Skip the children. Matching `leave_*` hook is still called.
+    Prune,
+}
+
+/// Hooks called by [`walk`].
+///
+/// All hooks do nothing by default.
+pub trait MessageVisitor {
+    /// Called for each message before its fields.
+    ///
+    /// `path` is empty for the root message.
+    fn enter_message(&mut self, _path: &FieldPath, _message: &dyn MessageDyn) -> Visit {
+        Visit::Continue
+    }
+
+    /// Called for each message after its fields.
+    fn leave_message(&mut self, _path: &FieldPath, _message: &dyn MessageDyn) {}
+
+    /// Called for each set singular field and non-empty repeated or map field.
+    ///
+    /// Singular message value is walked after this hook.
+    fn enter_field(
+        &mut self,
+        _path: &FieldPath,
+        _field: &FieldDescriptor,
+        _value: &ReflectFieldRef,
+    ) -> Visit {
+        Visit::Continue
+    }
+
+    /// Called after the field value is walked.
+    fn leave_field(&mut self, _path: &FieldPath, _field: &FieldDescriptor) {}
+
+    /// Called for each repeated field element, `path` ends with the index.
+    fn enter_element(
+        &mut self,
+        _path: &FieldPath,
+        _field: &FieldDescriptor,
+        _value: &ReflectValueRef,
+    ) -> Visit {
+        Visit::Continue
+    }
+
+    /// Called after the element is walked.
+    fn leave_element(&mut self, _path: &FieldPath, _field: &FieldDescriptor) {}
+
+    /// Called for each map entry, `path` ends with the key.
+    fn enter_map_entry(
+        &mut self,
+        _path: &FieldPath,
+        _field: &FieldDescriptor,
+        _key: &ReflectValueRef,
+        _value: &ReflectValueRef,
+    ) -> Visit {
+        Visit::Continue
+    }
+
+    /// Called after the map value is walked.
+    fn leave_map_entry(&mut self, _path: &FieldPath, _field: &FieldDescriptor) {}
+
+    /// Called for each unknown field number of the message at `path`.
+    fn visit_unknown_field(&mut self, _path: &FieldPath, _number: u32, _values: &UnknownValues) {}
+}
+
+/// Hooks called by [`walk_mut`].
+///
+/// Field, element and map entry hooks receive the message containing
+/// the field, so the visitor can replace or clear the value in place.
+/// Values are walked after `enter_*` hook returns, so the walk continues
+/// into the replaced value. Elements must not be added to or removed from
+/// the repeated field being walked, map entries may be removed.
+///
+/// All hooks do nothing by default.
+pub trait MessageVisitorMut {
+    /// Called for each message before its fields.
+    ///
+    /// `path` is empty for the root message.
+    fn enter_message(&mut self, _path: &FieldPath, _message: &mut dyn MessageDyn) -> Visit {
+        Visit::Continue
+    }
+
+    /// Called for each message after its fields.
+    fn leave_message(&mut self, _path: &FieldPath, _message: &mut dyn MessageDyn) {}
+
+    /// Called for each set singular field and non-empty repeated or map field
+    /// of `message`.
+    fn enter_field(
+        &mut self,
+        _path: &FieldPath,
+        _field: &FieldDescriptor,
+        _message: &mut dyn MessageDyn,
+    ) -> Visit {
+        Visit::Continue
+    }
+
+    /// Called after the field value is walked.
+    fn leave_field(
+        &mut self,
+        _path: &FieldPath,
+        _field: &FieldDescriptor,
+        _message: &mut dyn MessageDyn,
+    ) {
+    }
+
+    /// Called for each element of repeated `field` of `message`,
+    /// `path` ends with the index.
+    fn enter_element(
+        &mut self,
+        _path: &FieldPath,
+        _field: &FieldDescriptor,
+        _index: usize,
+        _message: &mut dyn MessageDyn,
+    ) -> Visit {
+        Visit::Continue
+    }
+
+    /// Called after the element is walked.
+    fn leave_element(
+        &mut self,
+        _path: &FieldPath,
+        _field: &FieldDescriptor,
+        _index: usize,
+        _message: &mut dyn MessageDyn,
+    ) {
+    }
+
+    /// Called for each entry of map `field` of `message`,
+    /// `path` ends with the key.
+    fn enter_map_entry(
+        &mut self,
+        _path: &FieldPath,
+        _field: &FieldDescriptor,
+        _key: &ReflectValueBox,
+        _message: &mut dyn MessageDyn,
+    ) -> Visit {
+        Visit::Continue
+    }
+
+    /// Called after the map value is walked.
+    fn leave_map_entry(
+        &mut self,
+        _path: &FieldPath,
+        _field: &FieldDescriptor,
+        _key: &ReflectValueBox,
+        _message: &mut dyn MessageDyn,
+    ) {
+    }
+
+    /// Called for each unknown field number of the message at `path`.
+    fn visit_unknown_field(
+        &mut self,
+        _path: &FieldPath,
+        _number: u32,
+        _unknown_fields: &mut UnknownFields,
+    ) {
+    }
+}
+
+/// Walk the message tree calling `visitor` hooks.
+pub fn walk<V: MessageVisitor + ?Sized>(message: &dyn MessageDyn, visitor: &mut V) {
+    walk_message(message, &mut FieldPath::root(), visitor);
+}
+
+/// Walk the message tree calling `visitor` hooks which may modify
+/// the messages.
+pub fn walk_mut<V: MessageVisitorMut + ?Sized>(message: &mut dyn MessageDyn, visitor: &mut V) {
+    walk_message_mut(message, &mut FieldPath::root(), visitor);
+}
+
+fn is_set(value: &ReflectFieldRef) -> bool {
+    match value {
+        ReflectFieldRef::Optional(v) => v.is_some(),
+        ReflectFieldRef::Repeated(r) => !r.is_empty(),
+        ReflectFieldRef::Map(m) => !m.is_empty(),
+    }
+}
+
+fn is_message(t: &RuntimeTypeBox) -> bool {
+    matches!(t, RuntimeTypeBox::Message(..))
+}
+
+/// Map keys in key order, map iteration order is unspecified.
+fn sorted_keys<'a>(keys: impl Iterator<Item = ReflectValueRef<'a>>) -> Vec<ReflectValueRef<'a>> {
+    let mut keys: Vec<ReflectValueRef> = keys.collect();
+    keys.sort_by(compare_keys);
+    keys
+}
+
+fn compare_keys(a: &ReflectValueRef, b: &ReflectValueRef) -> Ordering {
+    match (a, b) {
+        (ReflectValueRef::U32(a), ReflectValueRef::U32(b)) => a.cmp(b),
+        (ReflectValueRef::U64(a), ReflectValueRef::U64(b)) => a.cmp(b),
+        (ReflectValueRef::I32(a), ReflectValueRef::I32(b)) => a.cmp(b),
+        (ReflectValueRef::I64(a), ReflectValueRef::I64(b)) => a.cmp(b),
+        (ReflectValueRef::Bool(a), ReflectValueRef::Bool(b)) => a.cmp(b),
+        (ReflectValueRef::String(a), ReflectValueRef::String(b)) => a.cmp(b),
+        (a, b) => panic!("not comparable map keys: {:?} and {:?}", a, b),
+    }
+}
+
+fn sorted_unknown_numbers(unknown_fields: &UnknownFields) -> Vec<u32> {
+    let mut numbers: Vec<u32> = unknown_fields.iter().map(|(n, _)| n).collect();
+    numbers.sort();
+    numbers
+}
+
+fn walk_message<V: MessageVisitor + ?Sized>(
+    message: &dyn MessageDyn,
+    path: &mut FieldPath,
+    visitor: &mut V,
+) {
+    if visitor.enter_message(path, message) == Visit::Continue {
+        for field in message.descriptor_dyn().fields() {
+            let value = field.get_reflect(message);
+            if !is_set(&value) {
+                continue;
+            }
+            path.push_field(field.get_name());
+            if visitor.enter_field(path, &field, &value) == Visit::Continue {
+                walk_field(&field, &value, path, visitor);
+            }
+            visitor.leave_field(path, &field);
+            path.pop();
+        }
+
+        let unknown_fields = message.get_unknown_fields_dyn();
+        for number in sorted_unknown_numbers(unknown_fields) {
+            let values = unknown_fields.get(number).unwrap();
+            visitor.visit_unknown_field(path, number, values);
+        }
+    }
+    visitor.leave_message(path, message);
+}
+
+fn walk_field<V: MessageVisitor + ?Sized>(
+    field: &FieldDescriptor,
+    value: &ReflectFieldRef,
+    path: &mut FieldPath,
+    visitor: &mut V,
+) {
+    match value {
+        ReflectFieldRef::Optional(Some(ReflectValueRef::Message(m))) => {
+            walk_message(&**m, path, visitor)
+        }
+        ReflectFieldRef::Optional(_) => {}
+        ReflectFieldRef::Repeated(repeated) => {
+            for i in 0..repeated.len() {
+                let element = repeated.get(i);
+                path.set_index(i);
+                if visitor.enter_element(path, field, &element) == Visit::Continue {
+                    if let ReflectValueRef::Message(m) = &element {
+                        walk_message(&**m, path, visitor);
+                    }
+                }
+                visitor.leave_element(path, field);
+            }
+            path.clear_selector();
+        }
+        ReflectFieldRef::Map(map) => {
+            for key in sorted_keys(map.into_iter().map(|(k, _)| k)) {
+                let value = map.get(key.clone()).unwrap();
+                path.set_key(&key);
+                if visitor.enter_map_entry(path, field, &key, &value) == Visit::Continue {
+                    if let ReflectValueRef::Message(m) = &value {
+                        walk_message(&**m, path, visitor);
+                    }
+                }
+                visitor.leave_map_entry(path, field);
+            }
+            path.clear_selector();
+        }
+    }
+}
+
+fn walk_message_mut<V: MessageVisitorMut + ?Sized>(
+    message: &mut dyn MessageDyn,
+    path: &mut FieldPath,
+    visitor: &mut V,
+) {
+    if visitor.enter_message(path, message) == Visit::Continue {
+        for field in message.descriptor_dyn().fields() {
+            if !is_set(&field.get_reflect(message)) {
+                continue;
+            }
+            path.push_field(field.get_name());
+            if visitor.enter_field(path, &field, message) == Visit::Continue {
+                walk_field_mut(&field, message, path, visitor);
+            }
+            visitor.leave_field(path, &field, message);
+            path.pop();
+        }
+
+        for number in sorted_unknown_numbers(message.get_unknown_fields_dyn()) {
+            let unknown_fields = message.mut_unknown_fields_dyn();
+            // Previous hooks may have removed the field
+            if unknown_fields.get(number).is_some() {
+                visitor.visit_unknown_field(path, number, unknown_fields);
+            }
+        }
+    }
+    visitor.leave_message(path, message);
+}
+
+fn walk_field_mut<V: MessageVisitorMut + ?Sized>(
+    field: &FieldDescriptor,
+    message: &mut dyn MessageDyn,
+    path: &mut FieldPath,
+    visitor: &mut V,
+) {
+    match field.runtime_field_type() {
+        RuntimeFieldType::Singular(t) => {
+            if is_message(&t) && field.has_field(message) {
+                walk_message_mut(&mut *field.mut_converted_message(message), path, visitor);
+            }
+        }
+        RuntimeFieldType::Repeated(t) => {
+            let mut i = 0;
+            while i < field.get_repeated(message).len() {
+                path.set_index(i);
+                if visitor.enter_element(path, field, i, message) == Visit::Continue
+                    && is_message(&t)
+                {
+                    let mut repeated = field.mut_repeated(message);
+                    let mut element = repeated.get_mut(i);
+                    if let Some(m) = element.as_message_mut() {
+                        walk_message_mut(m, path, visitor);
+                    }
+                }
+                visitor.leave_element(path, field, i, message);
+                i += 1;
+            }
+            path.clear_selector();
+        }
+        RuntimeFieldType::Map(_, v) => {
+            let map = field.get_map(message);
+            let keys: Vec<ReflectValueBox> = sorted_keys((&map).into_iter().map(|(k, _)| k))
+                .into_iter()
+                .map(|k| k.to_box())
+                .collect();
+            for key in keys {
+                // Previous hooks may have removed the entry
+                if field.get_map(message).get(key.as_value_ref()).is_none() {
+                    continue;
+                }
+                path.set_key(&key.as_value_ref());
+                if visitor.enter_map_entry(path, field, &key, message) == Visit::Continue
+                    && is_message(&v)
+                {
+                    let mut map = field.mut_map(message);
+                    if let Some(mut v) = map.get_mut(key.as_value_ref()) {
+                        if let Some(m) = v.as_message_mut() {
+                            walk_message_mut(m, path, visitor);
+                        }
+                    };
+                }
+                visitor.leave_map_entry(path, field, &key, message);
+            }
+            path.clear_selector();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::sorted_keys;
+    use super::walk;
+    use super::walk_mut;
+    use super::MessageVisitor;
+    use super::MessageVisitorMut;
+    use super::Visit;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FieldDescriptorProto;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::message_dyn::MessageDyn;
+    use crate::reflect::field_path::FieldPath;
+    use crate::reflect::FieldDescriptor;
+    use crate::reflect::FileDescriptor;
+    use crate::reflect::ReflectFieldRef;
+    use crate::reflect::ReflectValueBox;
+    use crate::reflect::ReflectValueRef;
+    use crate::unknown::UnknownValues;
+    use crate::well_known_types::Struct;
+    use crate::well_known_types::Value;
+    use crate::Message;
+    use crate::UnknownFields;
+
+    /// Records hooks as lines.
+    #[derive(Default)]
+    struct Log {
+        lines: Vec<String>,
+        prune: Option<String>,
+    }
+
+    impl Log {
+        fn enter(&mut self, what: &str, path: &FieldPath) -> Visit {
+            self.lines.push(format!("{} {}", what, path));
+            if self.prune.as_deref() == Some(path.to_string().as_str()) {
+                Visit::Prune
+            } else {
+                Visit::Continue
+            }
+        }
+    }
+
+    impl MessageVisitor for Log {
+        fn enter_message(&mut self, path: &FieldPath, _message: &dyn MessageDyn) -> Visit {
+            self.enter("message", path)
+        }
+
+        fn leave_message(&mut self, path: &FieldPath, _message: &dyn MessageDyn) {
+            self.lines.push(format!("/message {}", path));
+        }
+
+        fn enter_field(
+            &mut self,
+            path: &FieldPath,
+            _field: &FieldDescriptor,
+            _value: &ReflectFieldRef,
+        ) -> Visit {
+            self.enter("field", path)
+        }
+
+        fn enter_element(
+            &mut self,
+            path: &FieldPath,
+            _field: &FieldDescriptor,
+            _value: &ReflectValueRef,
+        ) -> Visit {
+            self.enter("element", path)
+        }
+
+        fn enter_map_entry(
+            &mut self,
+            path: &FieldPath,
+            _field: &FieldDescriptor,
+            _key: &ReflectValueRef,
+            _value: &ReflectValueRef,
+        ) -> Visit {
+            self.enter("entry", path)
+        }
+
+        fn visit_unknown_field(&mut self, path: &FieldPath, number: u32, values: &UnknownValues) {
+            self.lines.push(format!(
+                "unknown {} {} x{}",
+                path,
+                number,
+                values.iter().count()
+            ));
+        }
+    }
+
+    fn file() -> FileDescriptorProto {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("f".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        message.field.push(field);
+        message.mut_unknown_fields().add_varint(1000, 1);
+        message.mut_unknown_fields().add_varint(1000, 2);
+        let mut file = FileDescriptorProto::new();
+        file.message_type.push(message);
+        file.options
+            .mut_or_default()
+            .set_java_package("p".to_owned());
+        file
+    }
+
+    fn log(message: &dyn MessageDyn, prune: Option<&str>) -> Vec<String> {
+        let mut log = Log::default();
+        log.prune = prune.map(|p| p.to_owned());
+        walk(message, &mut log);
+        log.lines
+    }
+
+    #[test]
+    fn order() {
+        let expected = vec![
+            "message ",
+            "field message_type",
+            "element message_type[0]",
+            "message message_type[0]",
+            "field message_type[0].name",
+            "field message_type[0].field",
+            "element message_type[0].field[0]",
+            "message message_type[0].field[0]",
+            "field message_type[0].field[0].name",
+            "/message message_type[0].field[0]",
+            "unknown message_type[0] 1000 x2",
+            "/message message_type[0]",
+            "field options",
+            "message options",
+            "field options.java_package",
+            "/message options",
+            "/message ",
+        ];
+        let file = file();
+        assert_eq!(expected, log(&file, None));
+
+        // Same for dynamic message
+        let dynamic = FileDescriptor::new_dynamic(
+            FileDescriptorProto::descriptor_static()
+                .file_descriptor_proto()
+                .clone(),
+            Vec::new(),
+        );
+        let d = dynamic
+            .message_by_package_relative_name("FileDescriptorProto")
+            .unwrap();
+        let mut copy = d.new_instance();
+        copy.merge_from_bytes_dyn(&file.write_to_bytes().unwrap())
+            .unwrap();
+        assert_eq!(expected, log(&*copy, None));
+    }
+
+    #[test]
+    fn prune() {
+        let file = file();
+        assert_eq!(
+            vec![
+                "message ",
+                "field message_type",
+                "element message_type[0]",
+                "field options",
+                "message options",
+                "field options.java_package",
+                "/message options",
+                "/message ",
+            ],
+            log(&file, Some("message_type[0]"))
+        );
+        assert_eq!(vec!["message ", "/message "], log(&file, Some("")));
+    }
+
+    #[test]
+    fn map() {
+        let mut s = Struct::new();
+        let mut value = Value::new();
+        value.set_number_value(1.0);
+        s.fields.insert("b".to_owned(), value.clone());
+        s.fields.insert("a".to_owned(), value);
+        assert_eq!(
+            vec![
+                "message ",
+                "field fields",
+                "entry fields[\"a\"]",
+                "message fields[\"a\"]",
+                "field fields[\"a\"].number_value",
+                "/message fields[\"a\"]",
+                "entry fields[\"b\"]",
+                "message fields[\"b\"]",
+                "field fields[\"b\"].number_value",
+                "/message fields[\"b\"]",
+                "/message ",
+            ],
+            log(&s, None)
+        );
+    }
+
+    #[test]
+    fn map_key_order() {
+        let keys = vec![
+            ReflectValueRef::I32(10),
+            ReflectValueRef::I32(-1),
+            ReflectValueRef::I32(9),
+        ];
+        assert_eq!(
+            vec![
+                ReflectValueRef::I32(-1),
+                ReflectValueRef::I32(9),
+                ReflectValueRef::I32(10),
+            ],
+            sorted_keys(keys.into_iter())
+        );
+    }
+
+    /// Replaces all `name` fields and drops unknown fields.
+    struct Redact;
+
+    impl MessageVisitorMut for Redact {
+        fn enter_field(
+            &mut self,
+            _path: &FieldPath,
+            field: &FieldDescriptor,
+            message: &mut dyn MessageDyn,
+        ) -> Visit {
+            if field.get_name() == "name" {
+                field.set_singular_field(message, ReflectValueBox::from("x"));
+            }
+            Visit::Continue
+        }
+
+        fn enter_map_entry(
+            &mut self,
+            _path: &FieldPath,
+            field: &FieldDescriptor,
+            key: &ReflectValueBox,
+            message: &mut dyn MessageDyn,
+        ) -> Visit {
+            if key == &ReflectValueBox::from("secret") {
+                field.mut_map(message).remove(key.as_value_ref());
+                return Visit::Prune;
+            }
+            Visit::Continue
+        }
+
+        fn visit_unknown_field(
+            &mut self,
+            _path: &FieldPath,
+            number: u32,
+            unknown_fields: &mut UnknownFields,
+        ) {
+            unknown_fields.remove(number);
+        }
+    }
+
+    #[test]
+    fn redact() {
+        let mut file = file();
+        file.set_name("f.proto".to_owned());
+        walk_mut(&mut file, &mut Redact);
+        assert_eq!("x", file.get_name());
+        assert_eq!("x", file.message_type[0].get_name());
+        assert_eq!("x", file.message_type[0].field[0].get_name());
+        assert!(file.message_type[0]
+            .get_unknown_fields()
+            .get(1000)
+            .is_none());
+        assert_eq!("p", file.options.get_or_default().get_java_package());
+
+        let mut s = Struct::new();
+        s.fields.insert("secret".to_owned(), Value::new());
+        s.fields.insert("public".to_owned(), Value::new());
+        walk_mut(&mut s, &mut Redact);
+        assert_eq!(vec!["public"], s.fields.keys().collect::<Vec<_>>());
+    }
+}
@@ -16,6 +16,7 @@ use crate::error::ProtobufError;
 use crate::error::ProtobufResult;
 use crate::error::WireError;
 use crate::message::*;
+use crate::message_convert::MessageConvert;
 use crate::reflect::types::*;
 use crate::stream::CodedInputStream;
 use crate::stream::CodedOutputStream;
@@ -24,6 +25,7 @@ use crate::wire_format::WireType;
 use crate::wire_format::WireTypeFixed32;
 use crate::wire_format::WireTypeFixed64;
 use crate::wire_format::WireTypeLengthDelimited;
+use crate::wire_format::WireTypeStartGroup;
 use crate::wire_format::WireTypeVarint;
 use crate::zigzag::*;
 use crate::MessageField;
@@ -294,6 +296,12 @@ pub fn unknown_fields_size(unknown_fields: &UnknownFields) -> u32 {
         for bytes in &values.length_delimited {
             r += bytes_size_no_tag(&bytes);
         }
+
+        // start and end group tags
+        r += tag_size(number) * 2 * values.groups.len() as u32;
+        for group in &values.groups {
+            r += unknown_fields_size(group);
+        }
     }
     r
 }
@@ -658,6 +666,52 @@ pub fn read_proto2_enum_with_unknown_fields_into<E: ProtobufEnum>(
     read_enum_with_unknown_fields_into(is, |e| *target = Some(e), field_number, unknown_fields)
 }
 
+/// Read closed `enum` value.
+///
+/// Values not declared in the enum are stored in unknown fields
+/// and `None` is returned.
+pub fn read_closed_enum_or_unknown<E: ProtobufEnum>(
+    is: &mut CodedInputStream,
+    field_number: u32,
+    unknown_fields: &mut UnknownFields,
+) -> ProtobufResult<Option<ProtobufEnumOrUnknown<E>>> {
+    let mut r = None;
+    read_enum_with_unknown_fields_into(
+        is,
+        |e| r = Some(ProtobufEnumOrUnknown::new(e)),
+        field_number,
+        unknown_fields,
+    )?;
+    Ok(r)
+}
+
+/// Read repeated closed `enum` field into given vec,
+/// values not declared in the enum are stored in unknown fields.
+pub fn read_repeated_closed_enum_or_unknown_into<E: ProtobufEnum>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<ProtobufEnumOrUnknown<E>>,
+    field_number: u32,
+    unknown_fields: &mut UnknownFields,
+) -> ProtobufResult<()> {
+    let mut push = |e| target.push(ProtobufEnumOrUnknown::new(e));
+    match wire_type {
+        WireTypeLengthDelimited => {
+            let len = is.read_raw_varint64()?;
+            let old_limit = is.push_limit(len)?;
+            while !is.eof()? {
+                read_enum_with_unknown_fields_into(is, &mut push, field_number, unknown_fields)?;
+            }
+            is.pop_limit(old_limit);
+            Ok(())
+        }
+        WireTypeVarint => {
+            read_enum_with_unknown_fields_into(is, push, field_number, unknown_fields)
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
 /// Read repeated `string` field into given vec.
 pub fn read_repeated_string_into(
     wire_type: WireType,
@@ -843,9 +897,8 @@ where
     match wire_type {
         WireTypeLengthDelimited => {
             is.incr_recursion()?;
-            let mut m = M::new();
-            let res = is.merge_message(&mut m);
-            *target = MessageField::some(m);
+            // Repeated occurrences of a message field are merged
+            let res = is.merge_message(target.mut_or_default());
             is.decr_recursion();
             res
         }
@@ -853,18 +906,102 @@ where
     }
 }
 
-fn skip_group(is: &mut CodedInputStream) -> ProtobufResult<()> {
-    loop {
-        let (_, wire_type) = is.read_tag_unpack()?;
-        if wire_type == wire_format::WireTypeEndGroup {
-            return Ok(());
+/// Read repeated `message` field with delimited (group) encoding.
+pub fn read_repeated_group_into_vec<M: Message + Default>(
+    field_number: u32,
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<M>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeStartGroup => {
+            is.incr_recursion()?;
+            let res = is.read_group(field_number);
+            is.decr_recursion();
+            target.push(res?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read singular `message` field with delimited (group) encoding.
+pub fn read_singular_group_into_field<M>(
+    field_number: u32,
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut MessageField<M>,
+) -> ProtobufResult<()>
+where
+    M: Message + Default,
+{
+    match wire_type {
+        WireTypeStartGroup => {
+            is.incr_recursion()?;
+            // Repeated occurrences of a message field are merged
+            let res = is.merge_group(field_number, target.mut_or_default());
+            is.decr_recursion();
+            res
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read singular `message` field stored as a custom type.
+///
+/// Repeated occurrences of the field are merged: the current value is
+/// converted to a message, the message is merged with the input and
+/// converted back.
+pub fn read_singular_converted_message_into_option<M, T>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Option<T>,
+) -> ProtobufResult<()>
+where
+    M: Message + ProtobufValue,
+    T: MessageConvert<M>,
+{
+    match wire_type {
+        WireTypeLengthDelimited => {
+            let mut m = match target {
+                Some(v) => v.to_message(),
+                None => M::new(),
+            };
+            is.incr_recursion()?;
+            let res = is.merge_message(&mut m);
+            is.decr_recursion();
+            res?;
+            *target = Some(T::from_message(m)?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read repeated `message` field stored as a custom type.
+pub fn read_repeated_converted_message_into_vec<M, T>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<T>,
+) -> ProtobufResult<()>
+where
+    M: Message + ProtobufValue,
+    T: MessageConvert<M>,
+{
+    match wire_type {
+        WireTypeLengthDelimited => {
+            is.incr_recursion()?;
+            let res = is.read_message::<M>();
+            is.decr_recursion();
+            target.push(T::from_message(res?)?);
+            Ok(())
         }
-        is.skip_field(wire_type)?;
+        _ => Err(unexpected_wire_type(wire_type)),
     }
 }
 
 /// Handle unknown field in generated code.
-/// Either store a value in unknown, or skip a group.
+/// Store a value or a group in unknown fields.
 pub fn read_unknown_or_skip_group(
     field_number: u32,
     wire_type: WireType,
@@ -872,13 +1009,18 @@ pub fn read_unknown_or_skip_group(
     unknown_fields: &mut UnknownFields,
 ) -> ProtobufResult<()> {
     match wire_type {
-        wire_format::WireTypeStartGroup => skip_group(is),
+        WireTypeStartGroup => {
+            is.incr_recursion()?;
+            let res = is.read_unknown_group(field_number);
+            is.decr_recursion();
+            unknown_fields.add_group(field_number, res?);
+        }
         _ => {
             let unknown = is.read_unknown(wire_type)?;
             unknown_fields.add_value(field_number, unknown);
-            Ok(())
         }
     }
+    Ok(())
 }
 
 /// Create an error for unexpected wire type.
@@ -955,6 +1097,50 @@ where
     message.write_to_with_cached_sizes(os)
 }
 
+/// Write `message` field with delimited (group) encoding,
+/// sizes of nested messages must be cached.
+pub fn write_group_field_with_cached_size<M>(
+    field_number: u32,
+    message: &M,
+    os: &mut CodedOutputStream,
+) -> ProtobufResult<()>
+where
+    M: Message,
+{
+    os.write_tag(field_number, WireType::WireTypeStartGroup)?;
+    message.write_to_with_cached_sizes(os)?;
+    os.write_tag(field_number, WireType::WireTypeEndGroup)
+}
+
+/// Size of a `message` field stored as a custom type, including tag.
+///
+/// The value is converted to a message to compute the size,
+/// and converted again by [`write_converted_message_field`].
+pub fn converted_message_size<M, T>(field_number: u32, value: &T) -> u32
+where
+    M: Message + ProtobufValue,
+    T: MessageConvert<M>,
+{
+    let message: M = value.to_message();
+    let len = message.compute_size();
+    tag_size(field_number) + compute_raw_varint32_size(len) + len
+}
+
+/// Write a `message` field stored as a custom type.
+pub fn write_converted_message_field<M, T>(
+    field_number: u32,
+    value: &T,
+    os: &mut CodedOutputStream,
+) -> ProtobufResult<()>
+where
+    M: Message + ProtobufValue,
+    T: MessageConvert<M>,
+{
+    let message: M = value.to_message();
+    message.compute_size();
+    write_message_field_with_cached_size(field_number, &message, os)
+}
+
 /// Read `map` field.
 pub fn read_map_into<K, V>(
     wire_type: WireType,
@@ -970,11 +1156,58 @@ where
         return Err(unexpected_wire_type(wire_type));
     }
 
+    let len = is.read_raw_varint32()?;
+    let old_limit = is.push_limit(len as u64)?;
+    let (key, value) = read_map_entry::<K, V>(is)?;
+    is.pop_limit(old_limit);
+
+    target.insert(key, value);
+
+    Ok(())
+}
+
+/// Read `map` field with closed `enum` values.
+///
+/// Entries with values not declared in the enum are stored in unknown fields.
+pub fn read_closed_enum_map_into<K, E>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut HashMap<K::ProtobufValue, ProtobufEnumOrUnknown<E>>,
+    field_number: u32,
+    unknown_fields: &mut UnknownFields,
+) -> ProtobufResult<()>
+where
+    K: ProtobufType,
+    K::ProtobufValue: Eq + Hash,
+    E: ProtobufEnum,
+{
+    if wire_type != WireType::WireTypeLengthDelimited {
+        return Err(unexpected_wire_type(wire_type));
+    }
+
+    let bytes = is.read_bytes()?;
+    let (key, value) = read_map_entry::<K, ProtobufTypeEnumOrUnknown<E>>(&mut is.nested(&bytes))?;
+    match value.enum_value() {
+        Ok(..) => {
+            target.insert(key, value);
+        }
+        Err(..) => unknown_fields.add_length_delimited(field_number, bytes),
+    }
+
+    Ok(())
+}
+
+/// Read map entry fields until the end of input.
+fn read_map_entry<K, V>(
+    is: &mut CodedInputStream,
+) -> ProtobufResult<(K::ProtobufValue, V::ProtobufValue)>
+where
+    K: ProtobufType,
+    V: ProtobufType,
+{
     let mut key = Default::default();
     let mut value = Default::default();
 
-    let len = is.read_raw_varint32()?;
-    let old_limit = is.push_limit(len as u64)?;
     while !is.eof()? {
         let (field_number, wire_type) = is.read_tag_unpack()?;
         match field_number {
@@ -993,9 +1226,6 @@ where
             _ => is.skip_field(wire_type)?,
         }
     }
-    is.pop_limit(old_limit);
-
-    target.insert(key, value);
 
-    Ok(())
+    Ok((key, value))
 }
@@ -644,6 +644,109 @@ impl<'a> CodedInputStream<'a> {
         r.check_initialized()?;
         Ok(r)
     }
+
+    /// Read message encoded as a group (delimited encoding) after the start group tag,
+    /// do not check if message is initialized.
+    pub fn merge_group<M: Message>(
+        &mut self,
+        field_number: u32,
+        message: &mut M,
+    ) -> ProtobufResult<()> {
+        let bytes = self.read_group_bytes(field_number)?;
+        let mut is = self.nested(&bytes);
+        message.merge_from(&mut is)
+    }
+
+    /// Read message encoded as a group (delimited encoding) after the start group tag.
+    pub fn read_group<M: Message>(&mut self, field_number: u32) -> ProtobufResult<M> {
+        let mut r: M = Message::new();
+        self.merge_group(field_number, &mut r)?;
+        r.check_initialized()?;
+        Ok(r)
+    }
+
+    /// Read content of a group up to and including the end group tag
+    /// as regular message content.
+    ///
+    /// Messages read fields until the end of input, so group content
+    /// is read into a buffer first.
+    pub(crate) fn read_group_bytes(&mut self, field_number: u32) -> ProtobufResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut bytes);
+            self.copy_group(field_number, &mut os)?;
+            os.flush()?;
+        }
+        Ok(bytes)
+    }
+
+    /// Read content of a group up to and including the end group tag
+    /// as unknown fields.
+    pub(crate) fn read_unknown_group(
+        &mut self,
+        field_number: u32,
+    ) -> ProtobufResult<UnknownFields> {
+        let mut group = UnknownFields::new();
+        loop {
+            let (number, wire_type) = self.read_tag_unpack()?;
+            match wire_type {
+                wire_format::WireTypeEndGroup => {
+                    check_end_group(field_number, number)?;
+                    return Ok(group);
+                }
+                wire_format::WireTypeStartGroup => {
+                    self.incr_recursion()?;
+                    let res = self.read_unknown_group(number);
+                    self.decr_recursion();
+                    group.add_group(number, res?);
+                }
+                wire_type => {
+                    let value = self.read_unknown(wire_type)?;
+                    group.add_value(number, value);
+                }
+            }
+        }
+    }
+
+    fn copy_group(&mut self, field_number: u32, os: &mut CodedOutputStream) -> ProtobufResult<()> {
+        loop {
+            let (number, wire_type) = self.read_tag_unpack()?;
+            match wire_type {
+                wire_format::WireTypeEndGroup => return check_end_group(field_number, number),
+                wire_format::WireTypeStartGroup => {
+                    os.write_tag(number, wire_type)?;
+                    self.incr_recursion()?;
+                    let res = self.copy_group(number, os);
+                    self.decr_recursion();
+                    res?;
+                    os.write_tag(number, wire_format::WireTypeEndGroup)?;
+                }
+                wire_type => {
+                    let value = self.read_unknown(wire_type)?;
+                    os.write_unknown(number, value.get_ref())?;
+                }
+            }
+        }
+    }
+
+    /// Stream to read nested data, with recursion level of this stream.
+    pub(crate) fn nested<'b>(&self, bytes: &'b [u8]) -> CodedInputStream<'b> {
+        let mut is = CodedInputStream::from_bytes(bytes);
+        is.recursion_level = self.recursion_level;
+        is.recursion_limit = self.recursion_limit;
+        is
+    }
+}
+
+/// End group tag must have the number of the start group tag.
+pub(crate) fn check_end_group(field_number: u32, end_number: u32) -> ProtobufResult<()> {
+    if field_number == end_number {
+        Ok(())
+    } else {
+        Err(ProtobufError::WireError(WireError::IncorrectTag(
+            end_number,
+        )))
+    }
 }
 
 impl<'a> Read for CodedInputStream<'a> {
@@ -1000,12 +1103,15 @@ impl<'a> CodedOutputStream<'a> {
     }
 
     /// Write unknown value
+    ///
+    /// For groups only group fields are written, without the end group tag.
     pub fn write_unknown_no_tag(&mut self, unknown: UnknownValueRef) -> ProtobufResult<()> {
         match unknown {
             UnknownValueRef::Fixed64(fixed64) => self.write_raw_little_endian64(fixed64),
             UnknownValueRef::Fixed32(fixed32) => self.write_raw_little_endian32(fixed32),
             UnknownValueRef::Varint(varint) => self.write_raw_varint64(varint),
             UnknownValueRef::LengthDelimited(bytes) => self.write_bytes_no_tag(bytes),
+            UnknownValueRef::Group(group) => self.write_unknown_fields(group),
         }
     }
 
@@ -1119,8 +1225,12 @@ impl<'a> CodedOutputStream<'a> {
         field_number: u32,
         value: UnknownValueRef,
     ) -> ProtobufResult<()> {
-        self.write_tag(field_number, value.wire_type())?;
+        let wire_type = value.wire_type();
+        self.write_tag(field_number, wire_type)?;
         self.write_unknown_no_tag(value)?;
+        if wire_type == wire_format::WireTypeStartGroup {
+            self.write_tag(field_number, wire_format::WireTypeEndGroup)?;
+        }
         Ok(())
     }
 
@@ -1273,6 +1383,21 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_input_stream_read_group() {
+        use crate::well_known_types::Int32Value;
+
+        // nested group of unknown field 3 is skipped
+        test_read("08 05 1b 08 01 1c 14", |is| {
+            let m: Int32Value = is.read_group(2).unwrap();
+            assert_eq!(5, m.value);
+        });
+        // end group tag of different field
+        test_read_partial("08 05 1c", |is| {
+            assert!(is.read_group::<Int32Value>(2).is_err());
+        });
+    }
+
     #[test]
     fn test_input_stream_read_raw_vaint_malformed() {
         // varint cannot have length > 10
@@ -5,6 +5,8 @@ use crate::message_dyn::MessageDyn;
 use crate::reflect::MessageRef;
 use crate::reflect::ReflectFieldRef;
 use crate::reflect::ReflectValueRef;
+use crate::UnknownFields;
+use crate::UnknownValueRef;
 
 #[doc(hidden)]
 pub fn quote_bytes_to(bytes: &[u8], buf: &mut String) {
@@ -175,15 +177,69 @@ fn print_to_internal(m: &MessageRef, buf: &mut String, pretty: bool, indent: usi
         }
     }
 
-    let unknown_fields = m.get_unknown_fields_dyn();
-    let mut numbers: Vec<u32> = m.get_unknown_fields_dyn().iter().map(|(n, _)| n).collect();
+    print_unknown_fields(m.get_unknown_fields_dyn(), buf, pretty, indent, &mut first);
+}
+
+fn print_unknown_fields(
+    unknown_fields: &UnknownFields,
+    buf: &mut String,
+    pretty: bool,
+    indent: usize,
+    first: &mut bool,
+) {
+    let mut numbers: Vec<u32> = unknown_fields.iter().map(|(n, _)| n).collect();
     // Sort for stable output
     numbers.sort();
     for &n in &numbers {
         for v in unknown_fields.get(n).unwrap() {
-            // TODO: try decode nested message for length-delimited
-            print_field(buf, pretty, indent, &mut first, n, v.to_reflect_value_ref());
+            match v {
+                UnknownValueRef::Group(group) => {
+                    print_start_field(buf, pretty, indent, first, n);
+                    buf.push_str(" {");
+                    if pretty {
+                        buf.push('\n');
+                    }
+                    let mut group_first = true;
+                    print_unknown_fields(group, buf, pretty, indent + 1, &mut group_first);
+                    do_indent(buf, pretty, indent);
+                    buf.push('}');
+                    print_end_field(buf, pretty);
+                }
+                // TODO: try decode nested message for length-delimited
+                v => {
+                    let value = v.to_reflect_value_ref().unwrap();
+                    print_field(buf, pretty, indent, first, n, value);
+                }
+            }
+        }
+    }
+}
+
+/// Text-format single value, e.g. `"abc"`, `RED` or `{ a: 1 }`.
+pub(crate) fn print_value_to(value: &ReflectValueRef, buf: &mut String) {
+    match value {
+        ReflectValueRef::Message(m) => {
+            let mut fields = String::new();
+            print_to_internal(m, &mut fields, false, 0);
+            if fields.is_empty() {
+                buf.push_str("{}");
+            } else {
+                write!(buf, "{{ {} }}", fields).unwrap();
+            }
         }
+        ReflectValueRef::Enum(d, v) => match d.get_value_by_number(*v) {
+            Some(e) => buf.push_str(e.get_name()),
+            None => write!(buf, "{}", v).unwrap(),
+        },
+        ReflectValueRef::String(s) => print_str_to(s, buf),
+        ReflectValueRef::Bytes(b) => quote_escape_bytes_to(b, buf),
+        ReflectValueRef::I32(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::I64(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::U32(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::U64(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::Bool(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::F32(v) => write!(buf, "{}", v).unwrap(),
+        ReflectValueRef::F64(v) => write!(buf, "{}", v).unwrap(),
     }
 }
 
@@ -203,6 +259,11 @@ pub fn print_to_string(m: &dyn MessageDyn) -> String {
     print_to_string_internal(m, false)
 }
 
+/// Text-format, one field per line with nested messages indented.
+pub fn print_to_string_pretty(m: &dyn MessageDyn) -> String {
+    print_to_string_internal(m, true)
+}
+
 /// Text-format to `fmt::Formatter`.
 pub fn fmt(m: &dyn MessageDyn, f: &mut fmt::Formatter) -> fmt::Result {
     let pretty = f.alternate();
@@ -28,6 +28,8 @@ pub enum UnknownValue {
     Varint(u64),
     /// Length-delimited unknown (e. g. `message` or `string`)
     LengthDelimited(Vec<u8>),
+    /// Group unknown (e. g. `group` or message with delimited encoding)
+    Group(UnknownFields),
 }
 
 impl UnknownValue {
@@ -43,6 +45,7 @@ impl UnknownValue {
             UnknownValue::Fixed64(fixed64) => UnknownValueRef::Fixed64(fixed64),
             UnknownValue::Varint(varint) => UnknownValueRef::Varint(varint),
             UnknownValue::LengthDelimited(ref bytes) => UnknownValueRef::LengthDelimited(&bytes),
+            UnknownValue::Group(ref group) => UnknownValueRef::Group(group),
         }
     }
 
@@ -99,6 +102,8 @@ pub enum UnknownValueRef<'o> {
     Varint(u64),
     /// Length-delimited unknown
     LengthDelimited(&'o [u8]),
+    /// Group unknown
+    Group(&'o UnknownFields),
 }
 
 impl<'o> UnknownValueRef<'o> {
@@ -109,15 +114,18 @@ impl<'o> UnknownValueRef<'o> {
             UnknownValueRef::Fixed64(_) => wire_format::WireTypeFixed64,
             UnknownValueRef::Varint(_) => wire_format::WireTypeVarint,
             UnknownValueRef::LengthDelimited(_) => wire_format::WireTypeLengthDelimited,
+            UnknownValueRef::Group(_) => wire_format::WireTypeStartGroup,
         }
     }
 
-    pub(crate) fn to_reflect_value_ref(&'o self) -> ReflectValueRef<'o> {
+    /// Scalar value as reflect value, `None` for groups.
+    pub(crate) fn to_reflect_value_ref(&'o self) -> Option<ReflectValueRef<'o>> {
         match self {
-            UnknownValueRef::Fixed32(v) => ReflectValueRef::U32(*v),
-            UnknownValueRef::Fixed64(v) => ReflectValueRef::U64(*v),
-            UnknownValueRef::Varint(v) => ReflectValueRef::U64(*v),
-            UnknownValueRef::LengthDelimited(v) => ReflectValueRef::Bytes(v),
+            UnknownValueRef::Fixed32(v) => Some(ReflectValueRef::U32(*v)),
+            UnknownValueRef::Fixed64(v) => Some(ReflectValueRef::U64(*v)),
+            UnknownValueRef::Varint(v) => Some(ReflectValueRef::U64(*v)),
+            UnknownValueRef::LengthDelimited(v) => Some(ReflectValueRef::Bytes(v)),
+            UnknownValueRef::Group(_) => None,
         }
     }
 }
@@ -135,6 +143,8 @@ pub struct UnknownValues {
     pub varint: Vec<u64>,
     /// Length-delimited unknowns
     pub length_delimited: Vec<Vec<u8>>,
+    /// Group unknowns
+    pub groups: Vec<UnknownFields>,
 }
 
 impl UnknownValues {
@@ -147,6 +157,7 @@ impl UnknownValues {
             UnknownValue::LengthDelimited(length_delimited) => {
                 self.length_delimited.push(length_delimited)
             }
+            UnknownValue::Group(group) => self.groups.push(group),
         };
     }
 
@@ -157,6 +168,7 @@ impl UnknownValues {
             fixed64: self.fixed64.iter(),
             varint: self.varint.iter(),
             length_delimited: self.length_delimited.iter(),
+            groups: self.groups.iter(),
         }
     }
 }
@@ -176,6 +188,7 @@ pub struct UnknownValuesIter<'o> {
     fixed64: slice::Iter<'o, u64>,
     varint: slice::Iter<'o, u64>,
     length_delimited: slice::Iter<'o, Vec<u8>>,
+    groups: slice::Iter<'o, UnknownFields>,
 }
 
 impl<'o> Iterator for UnknownValuesIter<'o> {
@@ -198,6 +211,9 @@ impl<'o> Iterator for UnknownValuesIter<'o> {
         if length_delimited.is_some() {
             return Some(UnknownValueRef::LengthDelimited(&length_delimited.unwrap()));
         }
+        if let Some(group) = self.groups.next() {
+            return Some(UnknownValueRef::Group(group));
+        }
         None
     }
 }
@@ -281,11 +297,30 @@ impl UnknownFields {
             .push(length_delimited);
     }
 
+    /// Add unknown group
+    pub fn add_group(&mut self, number: u32, group: UnknownFields) {
+        self.find_field(&number).groups.push(group);
+    }
+
     /// Add unknown value
     pub fn add_value(&mut self, number: u32, value: UnknownValue) {
         self.find_field(&number).add_value(value);
     }
 
+    /// Append unknown fields of other message, e. g. when merging messages
+    pub fn merge_from(&mut self, other: &UnknownFields) {
+        for (number, values) in other {
+            let target = self.find_field(&number);
+            target.fixed32.extend(&values.fixed32);
+            target.fixed64.extend(&values.fixed64);
+            target.varint.extend(&values.varint);
+            target
+                .length_delimited
+                .extend(values.length_delimited.iter().cloned());
+            target.groups.extend(values.groups.iter().cloned());
+        }
+    }
+
     /// Remove unknown field by number
     pub fn remove(&mut self, field_number: u32) {
         if let Some(fields) = &mut self.fields {
@@ -355,6 +390,8 @@ impl<'s> Iterator for UnknownFieldsIter<'s> {
 #[cfg(test)]
 mod test {
     use super::UnknownFields;
+    use super::UnknownValueRef;
+    use crate::CodedInputStream;
     use std::collections::hash_map::DefaultHasher;
     use std::hash::Hash;
     use std::hash::Hasher;
@@ -382,4 +419,39 @@ mod test {
 
         assert_eq!(hash(&unknown_fields_1), hash(&unknown_fields_2));
     }
+
+    #[test]
+    fn groups() {
+        let mut group = UnknownFields::new();
+        group.add_varint(2, 5);
+        let mut unknown_fields = UnknownFields::new();
+        unknown_fields.add_group(1, group);
+        let bytes = unknown_fields.write_to_bytes();
+        assert_eq!(vec![0x0b, 0x10, 5, 0x0c], bytes);
+        assert_eq!(4, crate::rt::unknown_fields_size(&unknown_fields));
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        let (number, wire_type) = is.read_tag_unpack().unwrap();
+        let mut parsed = UnknownFields::new();
+        crate::rt::read_unknown_or_skip_group(number, wire_type, &mut is, &mut parsed).unwrap();
+        assert!(is.eof().unwrap());
+        assert_eq!(unknown_fields, parsed);
+        match parsed.get(1).unwrap().iter().next() {
+            Some(UnknownValueRef::Group(g)) => {
+                assert_eq!(Some(&[5][..]), g.get(2).map(|v| &v.varint[..]))
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn group_end_tag_mismatch() {
+        // start group 1, end group 2
+        let mut is = CodedInputStream::from_bytes(&[0x0b, 0x14]);
+        let (number, wire_type) = is.read_tag_unpack().unwrap();
+        let mut parsed = UnknownFields::new();
+        assert!(
+            crate::rt::read_unknown_or_skip_group(number, wire_type, &mut is, &mut parsed).is_err()
+        );
+    }
 }
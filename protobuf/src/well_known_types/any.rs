@@ -22,7 +22,7 @@
 //! Generated file from `google/protobuf/any.proto`
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Any {
     // message fields
     ///  A URL/resource name that uniquely identifies the type of the serialized
@@ -123,6 +123,16 @@ impl crate::Message for Any {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.type_url.is_empty() {
+            self.type_url = other.type_url.clone();
+        }
+        if !other.value.is_empty() {
+            self.value = other.value.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
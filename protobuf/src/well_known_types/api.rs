@@ -31,7 +31,7 @@
 ///  this message itself. See https://cloud.google.com/apis/design/glossary for
 ///  detailed terminology.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Api {
     // message fields
     ///  The fully qualified name of this interface, including package name
@@ -200,6 +200,25 @@ impl crate::Message for Api {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        self.methods.extend(other.methods.iter().cloned());
+        self.options.extend(other.options.iter().cloned());
+        if !other.version.is_empty() {
+            self.version = other.version.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.source_context.as_ref() {
+            self.source_context.mut_or_default().merge_from_message(v);
+        }
+        self.mixins.extend(other.mixins.iter().cloned());
+        if other.syntax != crate::ProtobufEnumOrUnknown::new(crate::well_known_types::Syntax::SYNTAX_PROTO2) {
+            self.syntax = other.syntax;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -321,7 +340,7 @@ impl crate::reflect::ProtobufValue for Api {
 
 ///  Method represents a method of an API interface.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Method {
     // message fields
     ///  The simple name of this method.
@@ -462,6 +481,29 @@ impl crate::Message for Method {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if !other.request_type_url.is_empty() {
+            self.request_type_url = other.request_type_url.clone();
+        }
+        if other.request_streaming != false {
+            self.request_streaming = other.request_streaming;
+        }
+        if !other.response_type_url.is_empty() {
+            self.response_type_url = other.response_type_url.clone();
+        }
+        if other.response_streaming != false {
+            self.response_streaming = other.response_streaming;
+        }
+        self.options.extend(other.options.iter().cloned());
+        if other.syntax != crate::ProtobufEnumOrUnknown::new(crate::well_known_types::Syntax::SYNTAX_PROTO2) {
+            self.syntax = other.syntax;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -579,7 +621,7 @@ impl crate::reflect::ProtobufValue for Method {
 }
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Mixin {
     // message fields
     ///  The fully qualified name of the interface which is included.
@@ -654,6 +696,16 @@ impl crate::Message for Mixin {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if !other.root.is_empty() {
+            self.root = other.root.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
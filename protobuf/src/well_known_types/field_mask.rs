@@ -22,7 +22,7 @@
 //! Generated file from `google/protobuf/field_mask.proto`
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct FieldMask {
     // message fields
     ///  The set of field mask paths.
@@ -80,6 +80,11 @@ impl crate::Message for FieldMask {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        self.paths.extend(other.paths.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -24,7 +24,7 @@
 ///  `SourceContext` represents information about the source of a
 ///  protobuf element, like the file in which it is defined.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct SourceContext {
     // message fields
     ///  The path-qualified name of the .proto file that contained the associated
@@ -86,6 +86,13 @@ impl crate::Message for SourceContext {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.file_name.is_empty() {
+            self.file_name = other.file_name.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -30,7 +30,7 @@
 ///
 ///  The JSON representation for `Struct` is JSON object.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Struct {
     // message fields
     ///  Unordered map of dynamically typed values.
@@ -89,6 +89,11 @@ impl crate::Message for Struct {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        self.fields.extend(other.fields.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -155,7 +160,7 @@ impl crate::reflect::ProtobufValue for Struct {
 ///
 ///  The JSON representation for `Value` is JSON value.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Value {
     // message oneof groups
     pub kind: ::std::option::Option<value::Kind>,
@@ -401,43 +406,49 @@ impl Value {
 
     fn generated_message_descriptor_data() -> crate::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::new();
-        fields.push(crate::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+        fields.push(crate::reflect::rt::v2::make_oneof_copy_has_get_set_clear_simpler_accessors::<_, _>(
             "null_value",
             Value::has_null_value,
             Value::get_null_value,
             Value::set_null_value,
+            Value::clear_null_value,
         ));
-        fields.push(crate::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+        fields.push(crate::reflect::rt::v2::make_oneof_copy_has_get_set_clear_simpler_accessors::<_, _>(
             "number_value",
             Value::has_number_value,
             Value::get_number_value,
             Value::set_number_value,
+            Value::clear_number_value,
         ));
-        fields.push(crate::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+        fields.push(crate::reflect::rt::v2::make_oneof_deref_has_get_set_clear_simpler_accessor::<_, _>(
             "string_value",
             Value::has_string_value,
             Value::get_string_value,
             Value::set_string_value,
+            Value::clear_string_value,
         ));
-        fields.push(crate::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+        fields.push(crate::reflect::rt::v2::make_oneof_copy_has_get_set_clear_simpler_accessors::<_, _>(
             "bool_value",
             Value::has_bool_value,
             Value::get_bool_value,
             Value::set_bool_value,
+            Value::clear_bool_value,
         ));
-        fields.push(crate::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Struct>(
+        fields.push(crate::reflect::rt::v2::make_oneof_message_has_get_mut_set_clear_accessor::<_, Struct>(
             "struct_value",
             Value::has_struct_value,
             Value::get_struct_value,
             Value::mut_struct_value,
             Value::set_struct_value,
+            Value::clear_struct_value,
         ));
-        fields.push(crate::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ListValue>(
+        fields.push(crate::reflect::rt::v2::make_oneof_message_has_get_mut_set_clear_accessor::<_, ListValue>(
             "list_value",
             Value::has_list_value,
             Value::get_list_value,
             Value::mut_list_value,
             Value::set_list_value,
+            Value::clear_list_value,
         ));
         crate::reflect::GeneratedMessageDescriptorData::new_2::<Value>(
             "Value",
@@ -510,6 +521,30 @@ impl crate::Message for Value {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        match other.kind {
+            ::std::option::Option::Some(value::Kind::struct_value(ref v)) => {
+                if let ::std::option::Option::Some(value::Kind::struct_value(ref mut m)) = self.kind {
+                    m.merge_from_message(v);
+                } else {
+                    self.kind = ::std::option::Option::Some(value::Kind::struct_value(v.clone()));
+                }
+            },
+            ::std::option::Option::Some(value::Kind::list_value(ref v)) => {
+                if let ::std::option::Option::Some(value::Kind::list_value(ref mut m)) = self.kind {
+                    m.merge_from_message(v);
+                } else {
+                    self.kind = ::std::option::Option::Some(value::Kind::list_value(v.clone()));
+                }
+            },
+            ::std::option::Option::Some(ref v) => {
+                self.kind = ::std::option::Option::Some(v.clone());
+            },
+            ::std::option::Option::None => {},
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -644,7 +679,7 @@ pub mod value {
 ///
 ///  The JSON representation for `ListValue` is JSON array.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct ListValue {
     // message fields
     ///  Repeated field of dynamically typed values.
@@ -707,6 +742,11 @@ impl crate::Message for ListValue {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        self.values.extend(other.values.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -820,11 +860,44 @@ impl crate::reflect::ProtobufValue for NullValue {
 }
 
 impl NullValue {
+    /// All enum values in the order of declaration.
+    pub const ALL: &'static [NullValue] = &[
+        NullValue::NULL_VALUE,
+    ];
+
     fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
         crate::reflect::GeneratedEnumDescriptorData::new_2::<NullValue>("NullValue", 0)
     }
 }
 
+impl ::std::str::FromStr for NullValue {
+    type Err = crate::ProtobufEnumParseError;
+
+    fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+        match s {
+            "NULL_VALUE" => ::std::result::Result::Ok(NullValue::NULL_VALUE),
+            _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.NullValue", s)),
+        }
+    }
+}
+
+impl ::std::fmt::Display for NullValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(match *self {
+            NullValue::NULL_VALUE => "NULL_VALUE",
+        })
+    }
+}
+
+impl ::std::convert::TryFrom<i32> for NullValue {
+    type Error = crate::ProtobufEnumFromI32Error;
+
+    fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+        <Self as crate::ProtobufEnum>::from_i32(value)
+            .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.NullValue", value))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1cgoogle/protobuf/struct.proto\x12\x0fgoogle.protobuf\"\x98\x01\n\
     \x06Struct\x12;\n\x06fields\x18\x01\x20\x03(\x0b2#.google.protobuf.Struc\
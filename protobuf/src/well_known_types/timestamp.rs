@@ -22,7 +22,7 @@
 //! Generated file from `google/protobuf/timestamp.proto`
 
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Timestamp {
     // message fields
     ///  Represents seconds of UTC time since Unix epoch
@@ -101,6 +101,16 @@ impl crate::Message for Timestamp {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.seconds != 0 {
+            self.seconds = other.seconds;
+        }
+        if other.nanos != 0 {
+            self.nanos = other.nanos;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
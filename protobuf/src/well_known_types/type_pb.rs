@@ -23,7 +23,7 @@
 
 ///  A protocol buffer message type.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Type {
     // message fields
     ///  The fully qualified message name.
@@ -152,6 +152,22 @@ impl crate::Message for Type {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        self.fields.extend(other.fields.iter().cloned());
+        self.oneofs.extend(other.oneofs.iter().cloned());
+        self.options.extend(other.options.iter().cloned());
+        if let ::std::option::Option::Some(v) = other.source_context.as_ref() {
+            self.source_context.mut_or_default().merge_from_message(v);
+        }
+        if other.syntax != crate::ProtobufEnumOrUnknown::new(Syntax::SYNTAX_PROTO2) {
+            self.syntax = other.syntax;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -264,7 +280,7 @@ impl crate::reflect::ProtobufValue for Type {
 
 ///  A single field of a message type.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Field {
     // message fields
     ///  The field type.
@@ -446,6 +462,38 @@ impl crate::Message for Field {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.kind != crate::ProtobufEnumOrUnknown::new(field::Kind::TYPE_UNKNOWN) {
+            self.kind = other.kind;
+        }
+        if other.cardinality != crate::ProtobufEnumOrUnknown::new(field::Cardinality::CARDINALITY_UNKNOWN) {
+            self.cardinality = other.cardinality;
+        }
+        if other.number != 0 {
+            self.number = other.number;
+        }
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if !other.type_url.is_empty() {
+            self.type_url = other.type_url.clone();
+        }
+        if other.oneof_index != 0 {
+            self.oneof_index = other.oneof_index;
+        }
+        if other.packed != false {
+            self.packed = other.packed;
+        }
+        self.options.extend(other.options.iter().cloned());
+        if !other.json_name.is_empty() {
+            self.json_name = other.json_name.clone();
+        }
+        if !other.default_value.is_empty() {
+            self.default_value = other.default_value.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -684,11 +732,98 @@ pub mod field {
     }
 
     impl Kind {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [Kind] = &[
+            Kind::TYPE_UNKNOWN,
+            Kind::TYPE_DOUBLE,
+            Kind::TYPE_FLOAT,
+            Kind::TYPE_INT64,
+            Kind::TYPE_UINT64,
+            Kind::TYPE_INT32,
+            Kind::TYPE_FIXED64,
+            Kind::TYPE_FIXED32,
+            Kind::TYPE_BOOL,
+            Kind::TYPE_STRING,
+            Kind::TYPE_GROUP,
+            Kind::TYPE_MESSAGE,
+            Kind::TYPE_BYTES,
+            Kind::TYPE_UINT32,
+            Kind::TYPE_ENUM,
+            Kind::TYPE_SFIXED32,
+            Kind::TYPE_SFIXED64,
+            Kind::TYPE_SINT32,
+            Kind::TYPE_SINT64,
+        ];
+
         pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
             crate::reflect::GeneratedEnumDescriptorData::new_2::<Kind>("Field.Kind", 1)
         }
     }
 
+    impl ::std::str::FromStr for Kind {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "TYPE_UNKNOWN" => ::std::result::Result::Ok(Kind::TYPE_UNKNOWN),
+                "TYPE_DOUBLE" => ::std::result::Result::Ok(Kind::TYPE_DOUBLE),
+                "TYPE_FLOAT" => ::std::result::Result::Ok(Kind::TYPE_FLOAT),
+                "TYPE_INT64" => ::std::result::Result::Ok(Kind::TYPE_INT64),
+                "TYPE_UINT64" => ::std::result::Result::Ok(Kind::TYPE_UINT64),
+                "TYPE_INT32" => ::std::result::Result::Ok(Kind::TYPE_INT32),
+                "TYPE_FIXED64" => ::std::result::Result::Ok(Kind::TYPE_FIXED64),
+                "TYPE_FIXED32" => ::std::result::Result::Ok(Kind::TYPE_FIXED32),
+                "TYPE_BOOL" => ::std::result::Result::Ok(Kind::TYPE_BOOL),
+                "TYPE_STRING" => ::std::result::Result::Ok(Kind::TYPE_STRING),
+                "TYPE_GROUP" => ::std::result::Result::Ok(Kind::TYPE_GROUP),
+                "TYPE_MESSAGE" => ::std::result::Result::Ok(Kind::TYPE_MESSAGE),
+                "TYPE_BYTES" => ::std::result::Result::Ok(Kind::TYPE_BYTES),
+                "TYPE_UINT32" => ::std::result::Result::Ok(Kind::TYPE_UINT32),
+                "TYPE_ENUM" => ::std::result::Result::Ok(Kind::TYPE_ENUM),
+                "TYPE_SFIXED32" => ::std::result::Result::Ok(Kind::TYPE_SFIXED32),
+                "TYPE_SFIXED64" => ::std::result::Result::Ok(Kind::TYPE_SFIXED64),
+                "TYPE_SINT32" => ::std::result::Result::Ok(Kind::TYPE_SINT32),
+                "TYPE_SINT64" => ::std::result::Result::Ok(Kind::TYPE_SINT64),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.Field.Kind", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for Kind {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                Kind::TYPE_UNKNOWN => "TYPE_UNKNOWN",
+                Kind::TYPE_DOUBLE => "TYPE_DOUBLE",
+                Kind::TYPE_FLOAT => "TYPE_FLOAT",
+                Kind::TYPE_INT64 => "TYPE_INT64",
+                Kind::TYPE_UINT64 => "TYPE_UINT64",
+                Kind::TYPE_INT32 => "TYPE_INT32",
+                Kind::TYPE_FIXED64 => "TYPE_FIXED64",
+                Kind::TYPE_FIXED32 => "TYPE_FIXED32",
+                Kind::TYPE_BOOL => "TYPE_BOOL",
+                Kind::TYPE_STRING => "TYPE_STRING",
+                Kind::TYPE_GROUP => "TYPE_GROUP",
+                Kind::TYPE_MESSAGE => "TYPE_MESSAGE",
+                Kind::TYPE_BYTES => "TYPE_BYTES",
+                Kind::TYPE_UINT32 => "TYPE_UINT32",
+                Kind::TYPE_ENUM => "TYPE_ENUM",
+                Kind::TYPE_SFIXED32 => "TYPE_SFIXED32",
+                Kind::TYPE_SFIXED64 => "TYPE_SFIXED64",
+                Kind::TYPE_SINT32 => "TYPE_SINT32",
+                Kind::TYPE_SINT64 => "TYPE_SINT64",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for Kind {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.Field.Kind", value))
+        }
+    }
+
     ///  Whether a field is optional, required, or repeated.
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
     #[cfg_attr(serde, derive(Serialize, Deserialize))]
@@ -740,15 +875,57 @@ pub mod field {
     }
 
     impl Cardinality {
+        /// All enum values in the order of declaration.
+        pub const ALL: &'static [Cardinality] = &[
+            Cardinality::CARDINALITY_UNKNOWN,
+            Cardinality::CARDINALITY_OPTIONAL,
+            Cardinality::CARDINALITY_REQUIRED,
+            Cardinality::CARDINALITY_REPEATED,
+        ];
+
         pub(in super) fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
             crate::reflect::GeneratedEnumDescriptorData::new_2::<Cardinality>("Field.Cardinality", 2)
         }
     }
+
+    impl ::std::str::FromStr for Cardinality {
+        type Err = crate::ProtobufEnumParseError;
+
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "CARDINALITY_UNKNOWN" => ::std::result::Result::Ok(Cardinality::CARDINALITY_UNKNOWN),
+                "CARDINALITY_OPTIONAL" => ::std::result::Result::Ok(Cardinality::CARDINALITY_OPTIONAL),
+                "CARDINALITY_REQUIRED" => ::std::result::Result::Ok(Cardinality::CARDINALITY_REQUIRED),
+                "CARDINALITY_REPEATED" => ::std::result::Result::Ok(Cardinality::CARDINALITY_REPEATED),
+                _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.Field.Cardinality", s)),
+            }
+        }
+    }
+
+    impl ::std::fmt::Display for Cardinality {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match *self {
+                Cardinality::CARDINALITY_UNKNOWN => "CARDINALITY_UNKNOWN",
+                Cardinality::CARDINALITY_OPTIONAL => "CARDINALITY_OPTIONAL",
+                Cardinality::CARDINALITY_REQUIRED => "CARDINALITY_REQUIRED",
+                Cardinality::CARDINALITY_REPEATED => "CARDINALITY_REPEATED",
+            })
+        }
+    }
+
+    impl ::std::convert::TryFrom<i32> for Cardinality {
+        type Error = crate::ProtobufEnumFromI32Error;
+
+        fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+            <Self as crate::ProtobufEnum>::from_i32(value)
+                .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.Field.Cardinality", value))
+        }
+    }
 }
 
 ///  Enum type definition.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Enum {
     // message fields
     ///  Enum type name.
@@ -867,6 +1044,21 @@ impl crate::Message for Enum {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        self.enumvalue.extend(other.enumvalue.iter().cloned());
+        self.options.extend(other.options.iter().cloned());
+        if let ::std::option::Option::Some(v) = other.source_context.as_ref() {
+            self.source_context.mut_or_default().merge_from_message(v);
+        }
+        if other.syntax != crate::ProtobufEnumOrUnknown::new(Syntax::SYNTAX_PROTO2) {
+            self.syntax = other.syntax;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -971,7 +1163,7 @@ impl crate::reflect::ProtobufValue for Enum {
 
 ///  Enum value definition.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct EnumValue {
     // message fields
     ///  Enum value name.
@@ -1060,6 +1252,17 @@ impl crate::Message for EnumValue {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if other.number != 0 {
+            self.number = other.number;
+        }
+        self.options.extend(other.options.iter().cloned());
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1147,7 +1350,7 @@ impl crate::reflect::ProtobufValue for EnumValue {
 ///  A protocol buffer option, which can be attached to a message, field,
 ///  enumeration, etc.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Option {
     // message fields
     ///  The option's name. For protobuf built-in options (options defined in
@@ -1229,6 +1432,16 @@ impl crate::Message for Option {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if let ::std::option::Option::Some(v) = other.value.as_ref() {
+            self.value.mut_or_default().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1350,11 +1563,47 @@ impl crate::reflect::ProtobufValue for Syntax {
 }
 
 impl Syntax {
+    /// All enum values in the order of declaration.
+    pub const ALL: &'static [Syntax] = &[
+        Syntax::SYNTAX_PROTO2,
+        Syntax::SYNTAX_PROTO3,
+    ];
+
     fn generated_enum_descriptor_data() -> crate::reflect::GeneratedEnumDescriptorData {
         crate::reflect::GeneratedEnumDescriptorData::new_2::<Syntax>("Syntax", 0)
     }
 }
 
+impl ::std::str::FromStr for Syntax {
+    type Err = crate::ProtobufEnumParseError;
+
+    fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+        match s {
+            "SYNTAX_PROTO2" => ::std::result::Result::Ok(Syntax::SYNTAX_PROTO2),
+            "SYNTAX_PROTO3" => ::std::result::Result::Ok(Syntax::SYNTAX_PROTO3),
+            _ => ::std::result::Result::Err(crate::ProtobufEnumParseError::new("google.protobuf.Syntax", s)),
+        }
+    }
+}
+
+impl ::std::fmt::Display for Syntax {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(match *self {
+            Syntax::SYNTAX_PROTO2 => "SYNTAX_PROTO2",
+            Syntax::SYNTAX_PROTO3 => "SYNTAX_PROTO3",
+        })
+    }
+}
+
+impl ::std::convert::TryFrom<i32> for Syntax {
+    type Error = crate::ProtobufEnumFromI32Error;
+
+    fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+        <Self as crate::ProtobufEnum>::from_i32(value)
+            .ok_or_else(|| crate::ProtobufEnumFromI32Error::new("google.protobuf.Syntax", value))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1agoogle/protobuf/type.proto\x12\x0fgoogle.protobuf\x1a\x19google/pr\
     otobuf/any.proto\x1a$google/protobuf/source_context.proto\"\x8d\x02\n\
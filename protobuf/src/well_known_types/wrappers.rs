@@ -25,7 +25,7 @@
 ///
 ///  The JSON representation for `DoubleValue` is JSON number.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct DoubleValue {
     // message fields
     ///  The double value.
@@ -86,6 +86,13 @@ impl crate::Message for DoubleValue {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0. {
+            self.value = other.value;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -157,7 +164,7 @@ impl crate::reflect::ProtobufValue for DoubleValue {
 ///
 ///  The JSON representation for `FloatValue` is JSON number.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct FloatValue {
     // message fields
     ///  The float value.
@@ -218,6 +225,13 @@ impl crate::Message for FloatValue {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0. {
+            self.value = other.value;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -289,7 +303,7 @@ impl crate::reflect::ProtobufValue for FloatValue {
 ///
 ///  The JSON representation for `Int64Value` is JSON string.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Int64Value {
     // message fields
     ///  The int64 value.
@@ -350,6 +364,13 @@ impl crate::Message for Int64Value {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0 {
+            self.value = other.value;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -421,7 +442,7 @@ impl crate::reflect::ProtobufValue for Int64Value {
 ///
 ///  The JSON representation for `UInt64Value` is JSON string.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct UInt64Value {
     // message fields
     ///  The uint64 value.
@@ -482,6 +503,13 @@ impl crate::Message for UInt64Value {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0 {
+            self.value = other.value;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -553,7 +581,7 @@ impl crate::reflect::ProtobufValue for UInt64Value {
 ///
 ///  The JSON representation for `Int32Value` is JSON number.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct Int32Value {
     // message fields
     ///  The int32 value.
@@ -614,6 +642,13 @@ impl crate::Message for Int32Value {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0 {
+            self.value = other.value;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -685,7 +720,7 @@ impl crate::reflect::ProtobufValue for Int32Value {
 ///
 ///  The JSON representation for `UInt32Value` is JSON number.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct UInt32Value {
     // message fields
     ///  The uint32 value.
@@ -746,6 +781,13 @@ impl crate::Message for UInt32Value {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != 0 {
+            self.value = other.value;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -817,7 +859,7 @@ impl crate::reflect::ProtobufValue for UInt32Value {
 ///
 ///  The JSON representation for `BoolValue` is JSON `true` and `false`.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct BoolValue {
     // message fields
     ///  The bool value.
@@ -878,6 +920,13 @@ impl crate::Message for BoolValue {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.value != false {
+            self.value = other.value;
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -949,7 +998,7 @@ impl crate::reflect::ProtobufValue for BoolValue {
 ///
 ///  The JSON representation for `StringValue` is JSON string.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct StringValue {
     // message fields
     ///  The string value.
@@ -1010,6 +1059,13 @@ impl crate::Message for StringValue {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.value.is_empty() {
+            self.value = other.value.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1081,7 +1137,7 @@ impl crate::reflect::ProtobufValue for StringValue {
 ///
 ///  The JSON representation for `BytesValue` is JSON string.
 #[derive(PartialEq,Clone,Default)]
-#[cfg_attr(serde, derive(Serialize, Deserialize))]
+#[cfg_attr(serde, derive(Serialize, Deserialize), serde(default))]
 pub struct BytesValue {
     // message fields
     ///  The bytes value.
@@ -1142,6 +1198,13 @@ impl crate::Message for BytesValue {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.value.is_empty() {
+            self.value = other.value.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
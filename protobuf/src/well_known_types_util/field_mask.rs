@@ -0,0 +1,558 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::message_dyn::MessageDyn;
+use crate::reflect::FieldDescriptor;
+use crate::reflect::MessageDescriptor;
+use crate::reflect::ReflectEq;
+use crate::reflect::ReflectEqMode;
+use crate::reflect::ReflectFieldRef;
+use crate::reflect::ReflectValueRef;
+use crate::reflect::RuntimeFieldType;
+use crate::reflect::RuntimeTypeBox;
+use crate::well_known_types::FieldMask;
+
+/// Options for [`FieldMask::merge_message`].
+#[derive(Debug, Clone, Default)]
+pub struct FieldMaskMergeOptions {
+    /// When `true`, message fields in the mask are replaced
+    /// rather than merged recursively.
+    pub replace_message_fields: bool,
+    /// When `true`, repeated and map fields in the mask are replaced
+    /// rather than appended to.
+    pub replace_repeated_fields: bool,
+    /// Prevent initializing `FieldMaskMergeOptions` enumerating all field.
+    pub _future_options: (),
+}
+
+/// Error returned by [`FieldMask::merge_message`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldMaskError {
+    /// Source and destination messages have different types.
+    DifferentTypes {
+        /// Source message type.
+        source: String,
+        /// Destination message type.
+        destination: String,
+    },
+    /// Message has no field with such name.
+    UnknownField {
+        /// Mask path up to the offending component.
+        path: String,
+        /// Message type name.
+        message: String,
+    },
+    /// Path continues past a field which is not a singular message field.
+    NotMessage(String),
+}
+
+impl fmt::Display for FieldMaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldMaskError::DifferentTypes {
+                source,
+                destination,
+            } => write!(
+                f,
+                "messages of different types: {} and {}",
+                source, destination
+            ),
+            FieldMaskError::UnknownField { path, message } => {
+                write!(f, "{}: no such field in {}", path, message)
+            }
+            FieldMaskError::NotMessage(path) => write!(f, "{}: not a message field", path),
+        }
+    }
+}
+
+impl Error for FieldMaskError {}
+
+/// Field mask as a tree: a field is either selected entirely,
+/// or only selected subfields of a message field are.
+#[derive(Clone)]
+enum Node {
+    All,
+    Fields(BTreeMap<String, Node>),
+}
+
+fn add_path(fields: &mut BTreeMap<String, Node>, parts: &[&str]) {
+    let child = fields
+        .entry(parts[0].to_owned())
+        .or_insert_with(|| Node::Fields(BTreeMap::new()));
+    if parts.len() == 1 {
+        *child = Node::All;
+        return;
+    }
+    match child {
+        Node::All => {}
+        Node::Fields(fields) => add_path(fields, &parts[1..]),
+    }
+}
+
+fn collect_paths(fields: &BTreeMap<String, Node>, prefix: &str, paths: &mut Vec<String>) {
+    for (name, node) in fields {
+        let path = format!("{}{}", prefix, name);
+        match node {
+            Node::All => paths.push(path),
+            Node::Fields(fields) => collect_paths(fields, &format!("{}.", path), paths),
+        }
+    }
+}
+
+fn intersect(a: &BTreeMap<String, Node>, b: &BTreeMap<String, Node>) -> BTreeMap<String, Node> {
+    let mut r = BTreeMap::new();
+    for (name, a_node) in a {
+        let node = match (a_node, b.get(name)) {
+            (_, None) => continue,
+            (Node::All, Some(node)) | (node, Some(Node::All)) => node.clone(),
+            (Node::Fields(a), Some(Node::Fields(b))) => {
+                let fields = intersect(a, b);
+                if fields.is_empty() {
+                    continue;
+                }
+                Node::Fields(fields)
+            }
+        };
+        r.insert(name.clone(), node);
+    }
+    r
+}
+
+fn remove_path(
+    fields: &mut BTreeMap<String, Node>,
+    parts: &[&str],
+    descriptor: &MessageDescriptor,
+) {
+    let name = parts[0];
+    if !fields.contains_key(name) {
+        return;
+    }
+    if parts.len() == 1 {
+        fields.remove(name);
+        return;
+    }
+    let message = match descriptor
+        .get_field_by_name(name)
+        .and_then(|f| message_field_type(&f))
+    {
+        Some(message) => message,
+        None => return,
+    };
+    let child = fields.get_mut(name).unwrap();
+    if let Node::All = child {
+        // Expand to all subfields to remove one of them
+        *child = Node::Fields(
+            message
+                .fields()
+                .map(|f| (f.get_name().to_owned(), Node::All))
+                .collect(),
+        );
+    }
+    let now_empty = match child {
+        Node::Fields(child_fields) => {
+            remove_path(child_fields, &parts[1..], &message);
+            child_fields.is_empty()
+        }
+        Node::All => unreachable!(),
+    };
+    if now_empty {
+        fields.remove(name);
+    }
+}
+
+/// Message type of singular message field.
+fn message_field_type(field: &FieldDescriptor) -> Option<MessageDescriptor> {
+    match field.runtime_field_type() {
+        RuntimeFieldType::Singular(RuntimeTypeBox::Message(m)) => Some(m),
+        _ => None,
+    }
+}
+
+fn field_by_name(
+    descriptor: &MessageDescriptor,
+    name: &str,
+    path: &str,
+) -> Result<FieldDescriptor, FieldMaskError> {
+    descriptor
+        .get_field_by_name(name)
+        .ok_or_else(|| FieldMaskError::UnknownField {
+            path: path.to_owned(),
+            message: descriptor.full_name().to_owned(),
+        })
+}
+
+fn merge_fields(
+    fields: &BTreeMap<String, Node>,
+    prefix: &str,
+    source: &dyn MessageDyn,
+    destination: &mut dyn MessageDyn,
+    options: &FieldMaskMergeOptions,
+) -> Result<(), FieldMaskError> {
+    let descriptor = source.descriptor_dyn();
+    for (name, node) in fields {
+        let path = format!("{}{}", prefix, name);
+        let field = field_by_name(&descriptor, name, &path)?;
+        match node {
+            Node::All => {
+                let replace = if field.is_repeated_or_map() {
+                    options.replace_repeated_fields
+                } else {
+                    message_field_type(&field).is_none() || options.replace_message_fields
+                };
+                if replace {
+                    field.clear_field(destination);
+                }
+                field.merge_field(destination, source);
+            }
+            Node::Fields(fields) => {
+                if message_field_type(&field).is_none() {
+                    return Err(FieldMaskError::NotMessage(path));
+                }
+                if !field.has_field(source) && !field.has_field(destination) {
+                    continue;
+                }
+                let source = field.get_message(source);
+                merge_fields(
+                    fields,
+                    &format!("{}.", path),
+                    &*source,
+                    &mut *field.mut_converted_message(destination),
+                    options,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn trim_fields(fields: &BTreeMap<String, Node>, message: &mut dyn MessageDyn) {
+    for field in message.descriptor_dyn().fields() {
+        match fields.get(field.get_name()) {
+            None => field.clear_field(message),
+            Some(Node::All) => {}
+            Some(Node::Fields(fields)) => {
+                if message_field_type(&field).is_some() && field.has_field(message) {
+                    trim_fields(fields, &mut *field.mut_converted_message(message));
+                }
+            }
+        }
+    }
+}
+
+fn diff_paths(a: &dyn MessageDyn, b: &dyn MessageDyn, prefix: &str, paths: &mut Vec<String>) {
+    for field in a.descriptor_dyn().fields() {
+        let path = format!("{}{}", prefix, field.get_name());
+        match (field.get_reflect(a), field.get_reflect(b)) {
+            (
+                ReflectFieldRef::Optional(Some(ReflectValueRef::Message(am))),
+                ReflectFieldRef::Optional(Some(ReflectValueRef::Message(bm))),
+            ) => diff_paths(&*am, &*bm, &format!("{}.", path), paths),
+            (af, bf) => {
+                if !af.reflect_eq(&bf, &ReflectEqMode::default()) {
+                    paths.push(path);
+                }
+            }
+        }
+    }
+}
+
+impl FieldMask {
+    fn tree(&self) -> BTreeMap<String, Node> {
+        let mut fields = BTreeMap::new();
+        for path in &self.paths {
+            let parts: Vec<&str> = path.split('.').collect();
+            add_path(&mut fields, &parts);
+        }
+        fields
+    }
+
+    fn from_tree(fields: &BTreeMap<String, Node>) -> FieldMask {
+        let mut paths = Vec::new();
+        collect_paths(fields, "", &mut paths);
+        FieldMask {
+            paths,
+            ..Default::default()
+        }
+    }
+
+    /// Check all paths of this mask refer to fields of given message type.
+    ///
+    /// Each path component except the last must be a singular message field.
+    pub fn is_valid_for(&self, descriptor: &MessageDescriptor) -> bool {
+        self.paths.iter().all(|path| {
+            let mut message = Some(descriptor.clone());
+            for part in path.split('.') {
+                let field = match message.and_then(|m| m.get_field_by_name(part)) {
+                    Some(field) => field,
+                    None => return false,
+                };
+                message = message_field_type(&field);
+            }
+            true
+        })
+    }
+
+    /// Canonical form of this mask: paths are sorted, duplicates are removed,
+    /// and paths covered by other paths (e. g. `a.b` when `a` is present)
+    /// are removed.
+    pub fn canonical(&self) -> FieldMask {
+        FieldMask::from_tree(&self.tree())
+    }
+
+    /// Mask which selects fields selected by either mask, in canonical form.
+    pub fn union(&self, other: &FieldMask) -> FieldMask {
+        let mut fields = self.tree();
+        for path in &other.paths {
+            let parts: Vec<&str> = path.split('.').collect();
+            add_path(&mut fields, &parts);
+        }
+        FieldMask::from_tree(&fields)
+    }
+
+    /// Mask which selects fields selected by both masks, in canonical form.
+    pub fn intersection(&self, other: &FieldMask) -> FieldMask {
+        FieldMask::from_tree(&intersect(&self.tree(), &other.tree()))
+    }
+
+    /// Mask which selects fields selected by this mask but not by `other`,
+    /// in canonical form.
+    ///
+    /// Message type is needed to expand a path when a subfield of it
+    /// is subtracted: `a` minus `a.b` is all the other fields of `a`.
+    pub fn subtract(&self, other: &FieldMask, descriptor: &MessageDescriptor) -> FieldMask {
+        let mut fields = self.tree();
+        for path in &other.paths {
+            let parts: Vec<&str> = path.split('.').collect();
+            remove_path(&mut fields, &parts, descriptor);
+        }
+        FieldMask::from_tree(&fields)
+    }
+
+    /// Merge fields selected by this mask from `source` into `destination`.
+    ///
+    /// Selected singular fields unset in `source` are cleared in `destination`.
+    /// Selected message fields are merged, and repeated fields are appended
+    /// unless replacement is requested in `options`.
+    ///
+    /// Error is returned if messages have different types or the mask is not valid
+    /// for the message type (see [`is_valid_for`](FieldMask::is_valid_for)).
+    /// Fields preceding the offending path in the mask may be already merged then.
+    pub fn merge_message(
+        &self,
+        source: &dyn MessageDyn,
+        destination: &mut dyn MessageDyn,
+        options: &FieldMaskMergeOptions,
+    ) -> Result<(), FieldMaskError> {
+        let d = source.descriptor_dyn();
+        let dd = destination.descriptor_dyn();
+        if d != dd {
+            return Err(FieldMaskError::DifferentTypes {
+                source: d.full_name().to_owned(),
+                destination: dd.full_name().to_owned(),
+            });
+        }
+        merge_fields(&self.tree(), "", source, destination, options)
+    }
+
+    /// Clear all fields of `message` not selected by this mask.
+    ///
+    /// Unknown fields are kept.
+    pub fn trim_message(&self, message: &mut dyn MessageDyn) {
+        trim_fields(&self.tree(), message);
+    }
+
+    /// Mask of fields which differ in two messages.
+    ///
+    /// Message fields set in both messages are compared recursively,
+    /// repeated and map fields are compared as a whole.
+    ///
+    /// # Panics
+    ///
+    /// If messages have different types.
+    pub fn from_message_diff(a: &dyn MessageDyn, b: &dyn MessageDyn) -> FieldMask {
+        let ad = a.descriptor_dyn();
+        let bd = b.descriptor_dyn();
+        assert!(ad == bd, "messages of different types: {} and {}", ad, bd);
+        let mut paths = Vec::new();
+        diff_paths(a, b, "", &mut paths);
+        FieldMask {
+            paths,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::descriptor::DescriptorProto;
+    use crate::descriptor::FileDescriptorProto;
+    use crate::well_known_types::Value;
+    use crate::Message;
+
+    fn mask(paths: &[&str]) -> FieldMask {
+        FieldMask {
+            paths: paths.iter().map(|p| p.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn file() -> FileDescriptorProto {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.set_package("a".to_owned());
+        file.dependency.push("x.proto".to_owned());
+        file.options
+            .set_default()
+            .set_java_package("a.java".to_owned());
+        file.options
+            .get_mut_ref()
+            .set_java_outer_classname("A".to_owned());
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        file.message_type.push(message);
+        file
+    }
+
+    #[test]
+    fn is_valid_for() {
+        let d = FileDescriptorProto::descriptor_static();
+        assert!(mask(&["name", "options.java_package", "message_type"]).is_valid_for(&d));
+        assert!(!mask(&["nam"]).is_valid_for(&d));
+        assert!(!mask(&["name.x"]).is_valid_for(&d));
+        assert!(!mask(&["message_type.name"]).is_valid_for(&d));
+        assert!(!mask(&[""]).is_valid_for(&d));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let d = FileDescriptorProto::descriptor_static();
+        assert_eq!(
+            mask(&["name", "options", "package"]),
+            mask(&["package", "options.java_package", "name", "options", "name"]).canonical()
+        );
+        assert_eq!(
+            mask(&["name", "options", "package"]),
+            mask(&["name", "options.java_package"]).union(&mask(&["package", "options"]))
+        );
+        assert_eq!(
+            mask(&["options.java_package"]),
+            mask(&["name", "options"]).intersection(&mask(&["package", "options.java_package"]))
+        );
+        assert_eq!(
+            mask(&["name"]),
+            mask(&["name", "options.java_package"]).subtract(&mask(&["options"]), &d)
+        );
+
+        let rest = mask(&["options"]).subtract(&mask(&["options.java_package"]), &d);
+        assert!(!rest.paths.is_empty());
+        assert!(!rest.paths.contains(&"options.java_package".to_owned()));
+        assert!(rest.paths.contains(&"options.go_package".to_owned()));
+        assert!(rest.paths.iter().all(|p| p.starts_with("options.")));
+    }
+
+    #[test]
+    fn merge_message() {
+        let source = file();
+        let mut destination = FileDescriptorProto::new();
+        destination.set_package("b".to_owned());
+        destination.set_syntax("proto3".to_owned());
+        destination.dependency.push("y.proto".to_owned());
+        destination
+            .options
+            .set_default()
+            .set_go_package("b.go".to_owned());
+
+        let mut merged = destination.clone();
+        mask(&["name", "dependency", "options.java_package", "syntax"])
+            .merge_message(&source, &mut merged, &FieldMaskMergeOptions::default())
+            .unwrap();
+        assert_eq!("a.proto", merged.get_name());
+        assert_eq!("b", merged.get_package());
+        assert!(!merged.has_syntax());
+        assert_eq!(vec!["y.proto", "x.proto"], merged.dependency);
+        let options = merged.options.get_ref();
+        assert_eq!("a.java", options.get_java_package());
+        assert_eq!("b.go", options.get_go_package());
+        assert!(!options.has_java_outer_classname());
+
+        let mut merged = destination.clone();
+        mask(&["dependency", "options"])
+            .merge_message(
+                &source,
+                &mut merged,
+                &FieldMaskMergeOptions {
+                    replace_message_fields: true,
+                    replace_repeated_fields: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(vec!["x.proto"], merged.dependency);
+        assert_eq!(source.options, merged.options);
+    }
+
+    #[test]
+    fn merge_message_invalid_mask() {
+        let source = file();
+        let mut destination = FileDescriptorProto::new();
+        let options = FieldMaskMergeOptions::default();
+        assert_eq!(
+            Err(FieldMaskError::UnknownField {
+                path: "options.java_pkg".to_owned(),
+                message: "google.protobuf.FileOptions".to_owned(),
+            }),
+            mask(&["options.java_pkg"]).merge_message(&source, &mut destination, &options)
+        );
+        assert_eq!(
+            Err(FieldMaskError::NotMessage("name".to_owned())),
+            mask(&["name.x"]).merge_message(&source, &mut destination, &options)
+        );
+        assert!(mask(&["name"])
+            .merge_message(&source, &mut Value::new(), &options)
+            .is_err());
+    }
+
+    #[test]
+    fn trim_message() {
+        let mut m = file();
+        m.mut_unknown_fields().add_varint(1000, 1);
+        mask(&["name", "options.java_package"]).trim_message(&mut m);
+
+        let mut expected = FileDescriptorProto::new();
+        expected.set_name("a.proto".to_owned());
+        expected
+            .options
+            .set_default()
+            .set_java_package("a.java".to_owned());
+        expected.mut_unknown_fields().add_varint(1000, 1);
+        assert_eq!(expected, m);
+    }
+
+    #[test]
+    fn trim_oneof() {
+        let mut v = Value::new();
+        v.set_string_value("s".to_owned());
+        mask(&["number_value"]).trim_message(&mut v);
+        assert_eq!(Value::new(), v);
+
+        v.set_string_value("s".to_owned());
+        mask(&["string_value"]).trim_message(&mut v);
+        assert_eq!("s", v.get_string_value());
+    }
+
+    #[test]
+    fn from_message_diff() {
+        let a = file();
+        let mut b = file();
+        b.set_package("b".to_owned());
+        b.options.get_mut_ref().set_go_package("b.go".to_owned());
+        b.message_type[0].set_name("N".to_owned());
+        assert_eq!(
+            mask(&["package", "message_type", "options.go_package"]),
+            FieldMask::from_message_diff(&a, &b)
+        );
+        assert_eq!(mask(&[]), FieldMask::from_message_diff(&a, &a.clone()));
+    }
+}
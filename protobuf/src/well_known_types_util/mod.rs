@@ -1,3 +1,10 @@
+//! Utilities for [well-known types](crate::well_known_types)
+//! implemented as inherent methods of the types.
+
 mod any;
 mod duration;
+mod field_mask;
 mod timestamp;
+
+pub use self::field_mask::FieldMaskError;
+pub use self::field_mask::FieldMaskMergeOptions;
@@ -40,6 +40,7 @@ pub use protoc::Result;
 use protobuf::descriptor::FileDescriptorSet;
 use protobuf::Message;
 pub use protobuf_codegen::Customize;
+pub use protobuf_codegen::CustomizeConfig;
 use protoc::Protoc;
 use std::ffi::OsString;
 
@@ -54,6 +55,9 @@ pub struct Codegen {
     inputs: Vec<PathBuf>,
     /// Customize code generation
     customize: Customize,
+    /// Config file with per-file and per-element customization
+    #[cfg(feature = "with-customize-config")]
+    customize_config_file: Option<PathBuf>,
     /// Protoc command path
     protoc: Option<Protoc>,
     /// Extra `protoc` args
@@ -130,6 +134,16 @@ impl Codegen {
         self
     }
 
+    /// Load [`CustomizeConfig`] from a `.toml` or `.json` file.
+    ///
+    /// Config assigns customize options to files, messages and fields by globs,
+    /// which is useful when `.proto` files cannot be annotated with `rustproto` options.
+    #[cfg(feature = "with-customize-config")]
+    pub fn customize_config_file(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        self.customize_config_file = Some(path.as_ref().to_owned());
+        self
+    }
+
     /// Extra command line flags for `protoc` invocation.
     ///
     /// For example, `--experimental_allow_proto3_optional` option.
@@ -140,6 +154,13 @@ impl Codegen {
 
     /// Like `protoc --rust_out=...` but without requiring `protoc-gen-rust` command in `$PATH`.
     pub fn run(&self) -> Result<()> {
+        #[cfg(feature = "with-customize-config")]
+        let customize = self
+            .customize
+            .with_config_file(self.customize_config_file.as_deref())?;
+        #[cfg(not(feature = "with-customize-config"))]
+        let customize = self.customize.clone();
+
         let protoc = match self.protoc.clone() {
             Some(protoc) => protoc,
             None => Protoc::from_env_path(),
@@ -192,7 +213,7 @@ impl Codegen {
             &format!("protoc {}", protoc.version()?),
             &files_to_generate,
             &self.out_dir,
-            &self.customize,
+            &customize,
         )
     }
 }